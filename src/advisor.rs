@@ -0,0 +1,87 @@
+//! The advisor: a handful of rules run over the company's state that
+//! turn into plain-language hints, "your only engineering team is
+//! idle", "cash runs out in two months". Each hint carries a severity
+//! and a topic a frontend can link to the screen that fixes it. Built
+//! on demand by `GameState::advisor_hints`; nothing here is saved.
+
+use std::fmt;
+
+use crate::company::MonthlyFinancials;
+use crate::contract::ContractId;
+use crate::rocket_project::RocketProjectId;
+
+/// How urgently a hint wants reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Worth knowing.
+    Info,
+    /// Will cost the company if left alone.
+    Warning,
+    /// Costing the company now, or about to.
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// What a hint is about.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HintTopic {
+    /// Engineering teams with no project.
+    IdleTeams,
+    /// A rocket design that can barely, or can't, leave the pad.
+    LowTwr(RocketProjectId),
+    /// Money running out at the current burn.
+    Runway,
+    /// An accepted contract coming due with no rocket in stock.
+    ContractDeadline(ContractId),
+    /// A rocket in stock past its shelf life.
+    ExpiredRocket,
+}
+
+/// One piece of advice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hint {
+    pub severity: Severity,
+    pub topic: HintTopic,
+    pub message: String,
+}
+
+/// Average monthly net outflow over up to `months` of the books,
+/// leaving out the month in progress (`current`). None with no full
+/// month on the books yet.
+pub fn monthly_burn<'a>(
+    books: impl DoubleEndedIterator<Item = &'a MonthlyFinancials>,
+    current: (u32, u32),
+    months: usize,
+) -> Option<f64> {
+    let closed: Vec<f64> = books.rev()
+        .filter(|f| (f.year, f.month) != current)
+        .take(months)
+        .map(|f| f.expenses - f.income)
+        .collect();
+    (!closed.is_empty()).then(|| closed.iter().sum::<f64>() / closed.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn month(month: u32, income: f64, expenses: f64) -> MonthlyFinancials {
+        MonthlyFinancials { year: 2030, month, income, expenses, depreciation: 0.0 }
+    }
+
+    #[test]
+    fn test_burn_averages_closed_months() {
+        let books = [month(1, 0.0, 9.0), month(2, 2.0, 6.0), month(3, 0.0, 8.0), month(4, 0.0, 100.0)];
+        assert_eq!(monthly_burn(books.iter(), (2030, 4), 2), Some(6.0), "April is still open");
+        assert_eq!(monthly_burn(books[3..].iter(), (2030, 4), 2), None);
+    }
+}
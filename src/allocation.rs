@@ -0,0 +1,123 @@
+//! Spreading engineering teams over design projects. Teams on one
+//! project add work as √n, so the first team on a project does more
+//! than the third; the best spread weighs each project by what its
+//! work is worth (finishing a design or a revision over open-ended
+//! flaw hunting) and hands out teams one at a time to wherever the
+//! next one adds the most. With returns that only diminish, that
+//! greedy spread is the best there is. Built on demand by
+//! `GameState::suggest_team_allocation`; nothing here is saved.
+
+use std::fmt;
+
+use crate::depot_project::DepotProjectId;
+use crate::engine_project::EngineProjectId;
+use crate::reactor_project::ReactorProjectId;
+use crate::rocket_project::RocketProjectId;
+use crate::software_project::SoftwareProjectId;
+use crate::team::effective_work_rate;
+
+/// A project teams can work on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectRef {
+    Engine(EngineProjectId),
+    Rocket(RocketProjectId),
+    Reactor(ReactorProjectId),
+    Software(SoftwareProjectId),
+    Depot(DepotProjectId),
+}
+
+/// What the work on a project is doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkPhase {
+    Design,
+    Revision,
+    Testing,
+}
+
+impl fmt::Display for WorkPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkPhase::Design => write!(f, "design"),
+            WorkPhase::Revision => write!(f, "revision"),
+            WorkPhase::Testing => write!(f, "testing"),
+        }
+    }
+}
+
+/// One project and the teams it has and should have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationSlot {
+    pub project: ProjectRef,
+    pub name: String,
+    pub phase: WorkPhase,
+    /// What a unit of work on it is worth.
+    pub weight: f64,
+    pub current: u32,
+    pub suggested: u32,
+}
+
+/// A suggested spread of the company's free engineering teams.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Allocation {
+    pub slots: Vec<AllocationSlot>,
+    /// Teams being spread: every active team not preparing a flight.
+    pub teams: u32,
+    /// Today's share of a full day's work the teams get done, from
+    /// morale and any ramp-up after a furlough.
+    pub efficiency: f64,
+}
+
+impl Allocation {
+    /// Weighted work per day as things stand.
+    pub fn current_value(&self) -> f64 {
+        self.value(|s| s.current)
+    }
+
+    /// Weighted work per day with the suggested spread.
+    pub fn suggested_value(&self) -> f64 {
+        self.value(|s| s.suggested)
+    }
+
+    fn value(&self, teams: impl Fn(&AllocationSlot) -> u32) -> f64 {
+        self.efficiency * self.slots.iter().map(|s| s.weight * effective_work_rate(teams(s))).sum::<f64>()
+    }
+
+    /// True if the suggestion moves any team.
+    pub fn changes(&self) -> bool {
+        self.slots.iter().any(|s| s.current != s.suggested)
+    }
+}
+
+/// Hand out `teams` one at a time to the project whose weighted work
+/// gains most from one more. Ties go to a project that already has the
+/// team, so an allocation that's already best stays as it is.
+pub fn spread(weights: &[f64], current: &[u32], teams: u32) -> Vec<u32> {
+    let mut counts = vec![0u32; weights.len()];
+    for _ in 0..teams {
+        let gain = |i: usize| weights[i] * (effective_work_rate(counts[i] + 1) - effective_work_rate(counts[i]));
+        let best = (0..weights.len())
+            .filter(|&i| weights[i] > 0.0)
+            .max_by(|&a, &b| gain(a).total_cmp(&gain(b))
+                .then_with(|| (counts[a] < current[a]).cmp(&(counts[b] < current[b])))
+                .then_with(|| b.cmp(&a)));
+        match best {
+            Some(i) => counts[i] += 1,
+            None => break,
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spread_follows_weight_with_diminishing_returns() {
+        // 4× the weight earns 16× the teams under √n: 4 of 5 here.
+        assert_eq!(spread(&[4.0, 1.0], &[0, 0], 5), vec![4, 1]);
+        assert_eq!(spread(&[1.0, 1.0, 1.0], &[0, 0, 0], 4), vec![2, 1, 1]);
+        assert_eq!(spread(&[1.0, 1.0, 1.0], &[0, 0, 2], 4), vec![1, 1, 2], "ties keep teams where they are");
+        assert_eq!(spread(&[1.0, 0.0], &[0, 3], 3), vec![3, 0], "no team goes where work is worth nothing");
+    }
+}
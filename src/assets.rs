@@ -0,0 +1,143 @@
+//! Fixed assets: the facilities the company owns, carried at book value
+//! apart from cash. Each asset depreciates straight-line from its cost
+//! down to a salvage value, one step per month; selling it returns
+//! what it's still on the books for.
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::DepreciationConfig;
+
+/// Unique identifier for a fixed asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AssetId(pub u64);
+
+/// What a fixed asset is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AssetKind {
+    /// A block of manufacturing floor space.
+    FloorSpace { units: u32 },
+    /// The company's own launch pad.
+    LaunchPad,
+    /// A booster landing zone or drone ship.
+    RecoveryAsset { name: String },
+}
+
+impl AssetKind {
+    pub fn name(&self) -> String {
+        match self {
+            AssetKind::FloorSpace { units } => format!("Floor space ({} units)", units),
+            AssetKind::LaunchPad => "Launch pad".to_string(),
+            AssetKind::RecoveryAsset { name } => name.clone(),
+        }
+    }
+}
+
+/// One asset on the books.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixedAsset {
+    pub id: AssetId,
+    pub kind: AssetKind,
+    pub cost: f64,
+    pub book_value: f64,
+    /// Value it never depreciates below.
+    pub salvage_value: f64,
+    /// Straight-line charge per month until salvage value is reached.
+    pub monthly_depreciation: f64,
+    /// Month-ends it has been on the books.
+    #[serde(default)]
+    pub months_on_books: u32,
+}
+
+impl FixedAsset {
+    /// One month of depreciation. Returns the amount charged.
+    fn depreciate_month(&mut self) -> f64 {
+        let before = self.book_value;
+        self.months_on_books += 1;
+        self.book_value = (before - self.monthly_depreciation).max(self.salvage_value.min(before));
+        before - self.book_value
+    }
+}
+
+/// The company's fixed-asset register.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssetLedger {
+    pub assets: Vec<FixedAsset>,
+    pub next_id: u64,
+    /// Total depreciation charged since the ledger opened.
+    pub accumulated_depreciation: f64,
+}
+
+impl AssetLedger {
+    /// Put a newly paid-for asset on the books at `cost`.
+    pub fn acquire(&mut self, kind: AssetKind, cost: f64, cfg: &DepreciationConfig) -> AssetId {
+        let life_months = match kind {
+            AssetKind::FloorSpace { .. } => cfg.floor_space_life_months,
+            AssetKind::LaunchPad => cfg.pad_life_months,
+            AssetKind::RecoveryAsset { .. } => cfg.recovery_asset_life_months,
+        };
+        let salvage_value = cost * cfg.salvage_fraction;
+        let id = AssetId(self.next_id);
+        self.next_id += 1;
+        self.assets.push(FixedAsset {
+            id,
+            kind,
+            cost,
+            book_value: cost,
+            salvage_value,
+            monthly_depreciation: (cost - salvage_value) / life_months.max(1) as f64,
+            months_on_books: 0,
+        });
+        id
+    }
+
+    /// Total book value of everything on the register.
+    pub fn book_value(&self) -> f64 {
+        self.assets.iter().map(|a| a.book_value).sum()
+    }
+
+    /// Charge one month of depreciation across the register. Returns
+    /// the total charged.
+    pub fn depreciate_month(&mut self) -> f64 {
+        let charged: f64 = self.assets.iter_mut().map(|a| a.depreciate_month()).sum();
+        self.accumulated_depreciation += charged;
+        charged
+    }
+
+    pub fn get(&self, id: AssetId) -> Option<&FixedAsset> {
+        self.assets.iter().find(|a| a.id == id)
+    }
+
+    /// Take an asset off the books. Returns it; its book value is what
+    /// a sale recovers.
+    pub fn dispose(&mut self, id: AssetId) -> Option<FixedAsset> {
+        let idx = self.assets.iter().position(|a| a.id == id)?;
+        Some(self.assets.remove(idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depreciates_to_salvage_and_disposes_at_book_value() {
+        let cfg = DepreciationConfig { floor_space_life_months: 10, salvage_fraction: 0.2, ..Default::default() };
+        let mut ledger = AssetLedger::default();
+        let id = ledger.acquire(AssetKind::FloorSpace { units: 2 }, 1_000.0, &cfg);
+        assert_eq!(ledger.book_value(), 1_000.0);
+
+        assert_eq!(ledger.depreciate_month(), 80.0);
+        assert_eq!(ledger.book_value(), 920.0);
+        for _ in 0..20 {
+            ledger.depreciate_month();
+        }
+        assert_eq!(ledger.book_value(), 200.0, "never below salvage");
+        assert!((ledger.accumulated_depreciation - 800.0).abs() < 1e-9);
+
+        let sold = ledger.dispose(id).unwrap();
+        assert_eq!(sold.book_value, 200.0);
+        assert!(ledger.assets.is_empty());
+        assert!(ledger.dispose(id).is_none());
+    }
+}
@@ -0,0 +1,328 @@
+//! The astronaut corps. Astronauts are hired as candidates, train up
+//! to flight status, and are assigned to a rocket in inventory; the
+//! crew boards when that rocket launches. Every flight adds to an
+//! astronaut's career record, skill and fatigue, and fatigue wears off
+//! with rest. Losing a crew stands the whole company down for a while:
+//! teams work at reduced efficiency until the period ends.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::AstronautConfig;
+use crate::calendar::GameDate;
+use crate::manufacturing::InventoryItemId;
+
+/// Unique identifier for an astronaut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AstronautId(pub u64);
+
+/// Where an astronaut is in their career.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AstronautStatus {
+    /// In candidate training; not yet eligible to fly.
+    Training { days_remaining: u32 },
+    /// Flight-qualified and on the ground.
+    Ready,
+    /// Aboard a flight.
+    Flying,
+    /// Killed on a flight.
+    Lost { date: GameDate },
+}
+
+/// One flight in an astronaut's career.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareerFlight {
+    pub launch_date: GameDate,
+    pub rocket_name: String,
+    pub destination: String,
+    pub survived: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Astronaut {
+    pub id: AstronautId,
+    pub name: String,
+    /// 0..1. Trained crews catch trouble early: the crew's average
+    /// skill scales down flaw activation on their flights.
+    pub skill: f64,
+    /// 0..1. Each flight adds to it; rest wears it off. Too tired to
+    /// fly above [`AstronautConfig::max_fatigue_to_fly`].
+    pub fatigue: f64,
+    pub status: AstronautStatus,
+    pub career: Vec<CareerFlight>,
+}
+
+impl Astronaut {
+    /// Qualified, on the ground and rested enough to fly.
+    pub fn can_fly(&self, cfg: &AstronautConfig) -> bool {
+        self.status == AstronautStatus::Ready && self.fatigue <= cfg.max_fatigue_to_fly
+    }
+}
+
+/// A crew waiting for its rocket to launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrewAssignment {
+    pub rocket_item_id: InventoryItemId,
+    pub crew: Vec<AstronautId>,
+}
+
+/// Why a crew assignment was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrewError {
+    NoCrew,
+    UnknownAstronaut(AstronautId),
+    /// Still training, already flying, or lost.
+    NotQualified(String),
+    TooFatigued(String),
+    /// Already assigned to another rocket.
+    AlreadyAssigned(String),
+}
+
+impl fmt::Display for CrewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrewError::NoCrew => write!(f, "A crew needs at least one astronaut"),
+            CrewError::UnknownAstronaut(id) => write!(f, "No astronaut #{}", id.0),
+            CrewError::NotQualified(name) => write!(f, "{} isn't available to fly", name),
+            CrewError::TooFatigued(name) => write!(f, "{} needs rest before flying again", name),
+            CrewError::AlreadyAssigned(name) => write!(f, "{} is already assigned to another rocket", name),
+        }
+    }
+}
+
+/// The company's astronauts, their pending assignments, and the
+/// stand-down after a crew loss.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AstronautCorps {
+    pub astronauts: Vec<Astronaut>,
+    pub next_id: u64,
+    pub assignments: Vec<CrewAssignment>,
+    /// Teams work at reduced efficiency until this date.
+    pub stand_down_until: Option<GameDate>,
+    /// Fractional workdays banked during a stand-down.
+    pub work_credit: f64,
+}
+
+impl AstronautCorps {
+    /// Take on a new candidate. Returns their id.
+    pub fn hire(&mut self, name: String, cfg: &AstronautConfig) -> AstronautId {
+        let id = AstronautId(self.next_id);
+        self.next_id += 1;
+        self.astronauts.push(Astronaut {
+            id,
+            name,
+            skill: cfg.candidate_skill,
+            fatigue: 0.0,
+            status: AstronautStatus::Training { days_remaining: cfg.training_days },
+            career: Vec::new(),
+        });
+        id
+    }
+
+    pub fn get(&self, id: AstronautId) -> Option<&Astronaut> {
+        self.astronauts.iter().find(|a| a.id == id)
+    }
+
+    /// Astronauts still drawing a salary (everyone not lost).
+    pub fn active_count(&self) -> usize {
+        self.astronauts.iter()
+            .filter(|a| !matches!(a.status, AstronautStatus::Lost { .. }))
+            .count()
+    }
+
+    pub fn monthly_salary(&self, cfg: &AstronautConfig) -> f64 {
+        self.active_count() as f64 * cfg.monthly_salary
+    }
+
+    /// One day of training and rest. Returns the names of candidates
+    /// who qualified today.
+    pub fn tick_day(&mut self, cfg: &AstronautConfig) -> Vec<String> {
+        self.tick_days(1, cfg)
+    }
+
+    /// `days` days of training and rest at once.
+    pub fn tick_days(&mut self, days: u32, cfg: &AstronautConfig) -> Vec<String> {
+        let mut qualified = Vec::new();
+        for a in &mut self.astronauts {
+            match &mut a.status {
+                AstronautStatus::Training { days_remaining } => {
+                    *days_remaining = days_remaining.saturating_sub(days);
+                    if *days_remaining == 0 {
+                        a.status = AstronautStatus::Ready;
+                        a.skill = a.skill.max(cfg.qualified_skill);
+                        qualified.push(a.name.clone());
+                    }
+                }
+                AstronautStatus::Ready => {
+                    a.fatigue = (a.fatigue - cfg.fatigue_recovery_per_day * days as f64).max(0.0);
+                }
+                AstronautStatus::Flying | AstronautStatus::Lost { .. } => {}
+            }
+        }
+        qualified
+    }
+
+    /// Assign a crew to the rocket `rocket_item_id`, replacing any crew
+    /// it already had.
+    pub fn assign(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        crew: Vec<AstronautId>,
+        cfg: &AstronautConfig,
+    ) -> Result<(), CrewError> {
+        if crew.is_empty() {
+            return Err(CrewError::NoCrew);
+        }
+        for id in &crew {
+            let a = self.get(*id).ok_or(CrewError::UnknownAstronaut(*id))?;
+            if a.status != AstronautStatus::Ready {
+                return Err(CrewError::NotQualified(a.name.clone()));
+            }
+            if !a.can_fly(cfg) {
+                return Err(CrewError::TooFatigued(a.name.clone()));
+            }
+            let elsewhere = self.assignments.iter()
+                .any(|c| c.rocket_item_id != rocket_item_id && c.crew.contains(id));
+            if elsewhere {
+                return Err(CrewError::AlreadyAssigned(a.name.clone()));
+            }
+        }
+        self.assignments.retain(|c| c.rocket_item_id != rocket_item_id);
+        self.assignments.push(CrewAssignment { rocket_item_id, crew });
+        Ok(())
+    }
+
+    /// Board the crew assigned to `rocket_item_id` for launch. Returns
+    /// who flies; empty for an uncrewed flight.
+    pub fn board(&mut self, rocket_item_id: InventoryItemId) -> Vec<AstronautId> {
+        let Some(idx) = self.assignments.iter().position(|c| c.rocket_item_id == rocket_item_id) else {
+            return Vec::new();
+        };
+        let crew = self.assignments.remove(idx).crew;
+        for a in self.astronauts.iter_mut().filter(|a| crew.contains(&a.id)) {
+            a.status = AstronautStatus::Flying;
+        }
+        crew
+    }
+
+    /// Average skill of a crew; 0 for none.
+    pub fn crew_skill(&self, crew: &[AstronautId]) -> f64 {
+        if crew.is_empty() {
+            return 0.0;
+        }
+        crew.iter().filter_map(|id| self.get(*id)).map(|a| a.skill).sum::<f64>() / crew.len() as f64
+    }
+
+    /// Bring a crew home from `flight`: logged, more skilled, tired.
+    pub fn land(&mut self, crew: &[AstronautId], flight: CareerFlight, cfg: &AstronautConfig) {
+        for a in self.astronauts.iter_mut().filter(|a| crew.contains(&a.id)) {
+            a.status = AstronautStatus::Ready;
+            a.skill = (a.skill + cfg.skill_per_flight).min(1.0);
+            a.fatigue = (a.fatigue + cfg.fatigue_per_flight).min(1.0);
+            a.career.push(flight.clone());
+        }
+    }
+
+    /// Record a crew lost `today` on `flight` and start the company's
+    /// stand-down. Returns the names of the dead.
+    pub fn lose(
+        &mut self,
+        crew: &[AstronautId],
+        flight: CareerFlight,
+        today: GameDate,
+        cfg: &AstronautConfig,
+    ) -> Vec<String> {
+        let mut names = Vec::new();
+        for a in self.astronauts.iter_mut().filter(|a| crew.contains(&a.id)) {
+            a.status = AstronautStatus::Lost { date: today };
+            a.career.push(CareerFlight { survived: false, ..flight.clone() });
+            names.push(a.name.clone());
+        }
+        if !names.is_empty() {
+            let until = today.add_days(cfg.stand_down_days);
+            self.stand_down_until = Some(self.stand_down_until.map_or(until, |u| u.max(until)));
+        }
+        names
+    }
+
+    /// Whether teams get a day's work done today. Outside a stand-down
+    /// always; during one, often enough to average the stand-down
+    /// efficiency.
+    pub fn teams_work_today(&mut self, today: GameDate, cfg: &AstronautConfig) -> bool {
+        match self.stand_down_until {
+            Some(until) if today < until => {
+                self.work_credit += cfg.stand_down_efficiency;
+                if self.work_credit >= 1.0 {
+                    self.work_credit -= 1.0;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => {
+                self.stand_down_until = None;
+                self.work_credit = 0.0;
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flight(date: GameDate) -> CareerFlight {
+        CareerFlight { launch_date: date, rocket_name: "Capsule".into(), destination: "leo".into(), survived: true }
+    }
+
+    #[test]
+    fn test_train_assign_fly_and_rest() {
+        let cfg = AstronautConfig { training_days: 2, ..Default::default() };
+        let mut corps = AstronautCorps::default();
+        let id = corps.hire("Ride".into(), &cfg);
+        let rocket = InventoryItemId(9);
+        assert!(matches!(corps.assign(rocket, vec![id], &cfg), Err(CrewError::NotQualified(_))));
+
+        corps.tick_day(&cfg);
+        assert_eq!(corps.tick_day(&cfg), vec!["Ride".to_string()]);
+        corps.assign(rocket, vec![id], &cfg).unwrap();
+        assert_eq!(corps.board(InventoryItemId(1)), Vec::new(), "other rockets fly uncrewed");
+        assert_eq!(corps.board(rocket), vec![id]);
+        assert_eq!(corps.get(id).unwrap().status, AstronautStatus::Flying);
+
+        let date = GameDate::new(2001, 1, 1);
+        corps.land(&[id], flight(date), &cfg);
+        let a = corps.get(id).unwrap();
+        assert_eq!(a.career.len(), 1);
+        assert_eq!(a.skill, cfg.qualified_skill + cfg.skill_per_flight);
+        assert!(matches!(corps.assign(rocket, vec![id], &cfg), Err(CrewError::TooFatigued(_))));
+        while !corps.get(id).unwrap().can_fly(&cfg) {
+            corps.tick_day(&cfg);
+        }
+        assert!(corps.assign(rocket, vec![id], &cfg).is_ok());
+    }
+
+    #[test]
+    fn test_crew_loss_stands_the_company_down() {
+        let cfg = AstronautConfig { training_days: 1, stand_down_days: 10, stand_down_efficiency: 0.5, ..Default::default() };
+        let mut corps = AstronautCorps::default();
+        let id = corps.hire("Grissom".into(), &cfg);
+        corps.tick_day(&cfg);
+        let date = GameDate::new(2001, 1, 1);
+        assert_eq!(corps.lose(&[id], flight(date), date, &cfg), vec!["Grissom".to_string()]);
+        assert!(!corps.get(id).unwrap().career[0].survived);
+        assert_eq!(corps.active_count(), 0);
+
+        let mut day = date;
+        let worked = (0..10).filter(|_| {
+            day = day.next_day();
+            corps.teams_work_today(day, &cfg)
+        }).count();
+        assert_eq!(worked, 5, "half efficiency for the stand-down");
+        assert!(corps.teams_work_today(date.add_days(30), &cfg));
+        assert_eq!(corps.stand_down_until, None);
+    }
+}
@@ -64,18 +64,21 @@ pub fn effective_complexity(cycle: EngineCycle, propellants: &[Propellant]) -> u
 
 /// Rocket integration complexity based on design characteristics.
 /// Factors: number of stages, unique engine types, parallel stages.
-/// Range: ~3-8.
+/// Range: ~3-8, plus one per stage group with crossfeed plumbing.
 pub fn rocket_complexity(
     total_stages: u32,
     unique_engine_types: u32,
     max_parallel_stages: u32,
+    crossfeed_groups: u32,
 ) -> u32 {
     let base = 3u32;
     let stage_factor = total_stages.saturating_sub(1); // each extra stage adds 1
     let engine_variety = unique_engine_types.saturating_sub(1); // each extra type adds 1
     let parallel_factor = if max_parallel_stages > 1 { 1 } else { 0 }; // boosters add 1
 
-    (base + stage_factor + engine_variety + parallel_factor).min(8)
+    // Crossfeed plumbing sits on top of the cap: it is extra integration
+    // work and extra places for a leak regardless of how busy the stack is.
+    (base + stage_factor + engine_variety + parallel_factor).min(8) + crossfeed_groups
 }
 
 #[cfg(test)]
@@ -168,25 +171,32 @@ mod tests {
     #[test]
     fn test_rocket_complexity_simple() {
         // 2 stages, 1 engine type, no parallel = 3 + 1 + 0 + 0 = 4
-        assert_eq!(rocket_complexity(2, 1, 1), 4);
+        assert_eq!(rocket_complexity(2, 1, 1, 0), 4);
     }
 
     #[test]
     fn test_rocket_complexity_with_boosters() {
         // 3 stages, 2 engine types, parallel boosters = 3 + 2 + 1 + 1 = 7
-        assert_eq!(rocket_complexity(3, 2, 2), 7);
+        assert_eq!(rocket_complexity(3, 2, 2, 0), 7);
     }
 
     #[test]
     fn test_rocket_complexity_capped() {
         // Even extreme rockets cap at 8
-        assert_eq!(rocket_complexity(6, 4, 3), 8);
+        assert_eq!(rocket_complexity(6, 4, 3, 0), 8);
+    }
+
+    #[test]
+    fn test_rocket_complexity_crossfeed_beyond_cap() {
+        // Crossfeed adds one per plumbed group, even past the cap
+        assert_eq!(rocket_complexity(6, 4, 3, 1), 9);
+        assert_eq!(rocket_complexity(2, 1, 3, 1), 6);
     }
 
     #[test]
     fn test_rocket_complexity_minimum() {
         // Single stage, 1 engine type, no parallel = 3
-        assert_eq!(rocket_complexity(1, 1, 1), 3);
+        assert_eq!(rocket_complexity(1, 1, 1, 0), 3);
     }
 
 }
@@ -3,7 +3,9 @@
 //!
 //! `BalanceConfig::default()` is the single source of truth for the
 //! shipped values — TOML files are partial overrides layered on top
-//! (see [`BalanceConfig::load_layered`]). Deliberately excluded:
+//! (see [`BalanceConfig::load_layered`]); a new game picks up
+//! `balance.toml` from the working directory plus any `--balance`
+//! files ([`BalanceConfig::load_for_game`]). Deliberately excluded:
 //! complexity tables (`balance.rs`), tech/deficiency generation
 //! (seed-entangled), physics constants, and UI mechanics.
 
@@ -14,6 +16,9 @@ use serde::{Serialize, Deserialize};
 use crate::contract::MarketArchetype;
 use crate::resources::Resource;
 
+/// Override file a new game picks up from the working directory.
+pub const GAME_BALANCE_FILE: &str = "balance.toml";
+
 /// All tunable balance parameters. Lives on `GameState` (serialized
 /// into saves, so a save remembers the balance it was played under).
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
@@ -25,6 +30,55 @@ pub struct BalanceConfig {
     pub flaws: FlawsConfig,
     pub reputation: ReputationConfig,
     pub competitor: CompetitorConfig,
+    pub recovery: RecoveryConfig,
+    pub pad_leasing: PadLeasingConfig,
+    pub regulation: RegulationConfig,
+    pub valuation: ValuationConfig,
+    pub espionage: EspionageConfig,
+    pub certification: CertificationConfig,
+    pub software: SoftwareConfig,
+    pub integration: IntegrationConfig,
+    pub storage: StorageConfig,
+    pub retrofit: RetrofitConfig,
+    pub depreciation: DepreciationConfig,
+    pub heritage: HeritageConfig,
+    pub bidding: BiddingConfig,
+    pub gov_programs: GovProgramsConfig,
+    pub astronauts: AstronautConfig,
+    pub launch_abort: LaunchAbortConfig,
+    pub isru: IsruConfig,
+    pub external_review: ExternalReviewConfig,
+    pub morale: MoraleConfig,
+    pub marketing: MarketingConfig,
+    pub era: EraConfig,
+    pub map_variant: MapVariantConfig,
+    pub transit_anomaly: TransitAnomalyConfig,
+    pub servicing: ServicingConfig,
+    pub constellation: ConstellationConfig,
+    pub countdown: CountdownConfig,
+    pub disposal: DisposalConfig,
+    pub market_trend: MarketTrendConfig,
+    pub demand: DemandConfig,
+    pub subcontract: SubcontractConfig,
+    pub payroll: PayrollConfig,
+    pub science: ScienceConfig,
+    pub launch_sites: LaunchSitesConfig,
+    pub docking: DockingConfig,
+    pub propellant_loading: PropellantLoadingConfig,
+    pub insertion: InsertionConfig,
+    pub world_market: WorldMarketConfig,
+    pub pad_failure: PadFailureConfig,
+    pub booster_reuse: BoosterReuseConfig,
+    pub recovery_fleet: RecoveryFleetConfig,
+    pub payload_readiness: PayloadReadinessConfig,
+    pub challenge: ChallengeConfig,
+    pub sandbox: SandboxConfig,
+    pub advisor: AdvisorConfig,
+    pub forecast: ForecastConfig,
+    pub allocation: AllocationConfig,
+    pub depot_design: DepotDesignConfig,
+    pub combustion_instability: CombustionInstabilityConfig,
+    pub commonality: CommonalityConfig,
 }
 
 impl BalanceConfig {
@@ -54,6 +108,24 @@ impl BalanceConfig {
         Ok(config)
     }
 
+    /// The balance a new game starts under: `balance.toml` in the
+    /// working directory if there is one (the modder's override file),
+    /// then each of `extra` in order, layered over the defaults.
+    pub fn load_for_game<P: AsRef<Path>>(extra: &[P]) -> Result<Self, String> {
+        Self::load_over_game_file(Path::new(GAME_BALANCE_FILE), extra)
+    }
+
+    /// `game_file` if it exists, then each of `extra` in order, layered
+    /// over the defaults.
+    pub fn load_over_game_file<P: AsRef<Path>>(game_file: &Path, extra: &[P]) -> Result<Self, String> {
+        let mut paths: Vec<&Path> = Vec::new();
+        if game_file.is_file() {
+            paths.push(game_file);
+        }
+        paths.extend(extra.iter().map(|p| p.as_ref()));
+        Self::load_layered(&paths)
+    }
+
     /// The full effective config as TOML — the generated reference file
     /// (`--dump-balance`), always in sync with the code defaults.
     pub fn to_toml_string(&self) -> Result<String, String> {
@@ -122,6 +194,15 @@ pub struct CostsConfig {
     pub manufacturing_monthly_salary: f64,
     /// One-time hiring cost for a manufacturing team.
     pub manufacturing_hiring_cost: f64,
+    /// A small manufacturing team's headcount, salary and hiring cost
+    /// as a share of a standard (medium) team's.
+    pub small_team_crew: f64,
+    pub small_team_salary: f64,
+    /// The same for a large team.
+    pub large_team_crew: f64,
+    pub large_team_salary: f64,
+    /// Share of salary a furloughed engineering team is still paid.
+    pub furlough_salary_fraction: f64,
     /// Cost per unit of manufacturing floor space.
     pub floor_space_cost: f64,
     /// Days to build one floor-space expansion order.
@@ -142,6 +223,11 @@ impl Default for CostsConfig {
             engineering_hiring_cost: 150_000.0,
             manufacturing_monthly_salary: 300_000.0,
             manufacturing_hiring_cost: 900_000.0,
+            small_team_crew: 0.5,
+            small_team_salary: 0.6,
+            large_team_crew: 2.0,
+            large_team_salary: 1.7,
+            furlough_salary_fraction: 0.3,
             floor_space_cost: 5_000_000.0,
             floor_space_build_days: 30,
             starting_floor_space: 12,
@@ -329,6 +415,80 @@ pub struct MarketsConfig {
     /// cancelled, as a multiplier on the normal expiry hit.
     #[serde(default = "default_campaign_cancel_rep_penalty")]
     pub campaign_cancel_rep_penalty: f64,
+    /// Cost-plus contracts: fee paid on top of reimbursed costs, as a
+    /// fraction of them.
+    #[serde(default = "default_cost_plus_fee")]
+    pub cost_plus_fee: f64,
+    /// Cost-plus indirect-cost rate: overhead (engineering, facilities,
+    /// G&A) the customer accepts on top of the hardware a payload flies
+    /// on, as a multiple of that hardware's cost.
+    #[serde(default = "default_cost_plus_indirect_rate")]
+    pub cost_plus_indirect_rate: f64,
+    /// Cost-plus schedule requirement: the launch must happen within
+    /// this fraction of the delivery window to earn the fee.
+    #[serde(default = "default_cost_plus_schedule_fraction")]
+    pub cost_plus_schedule_fraction: f64,
+    /// Chance the customer audits a cost-plus contract at settlement.
+    #[serde(default = "default_cost_plus_audit_chance")]
+    pub cost_plus_audit_chance: f64,
+    /// Fraction of recorded costs an audit disallows.
+    #[serde(default = "default_cost_plus_audit_disallow")]
+    pub cost_plus_audit_disallow: f64,
+    /// Data-return contracts: share of the price paid when the payload
+    /// arrives; the rest is paid for the data it returns.
+    #[serde(default = "default_science_upfront_share")]
+    pub science_upfront_share: f64,
+    /// Months a data-return contract's data campaign runs.
+    #[serde(default = "default_science_data_months")]
+    pub science_data_months: u32,
+    /// Premium a full data return earns over the price, for carrying
+    /// the risk that the asset dies or goes unheard.
+    #[serde(default = "default_science_data_premium")]
+    pub science_data_premium: f64,
+    /// Share of contracts that specify an orbit-insertion tolerance.
+    #[serde(default = "default_insertion_tolerance_share")]
+    pub insertion_tolerance_share: f64,
+    /// Range a specified tolerance is drawn from, as the insertion
+    /// error (m/s) the customer accepts.
+    #[serde(default = "default_insertion_tolerance_m_s")]
+    pub insertion_tolerance_m_s: (f64, f64),
+    /// Share of the payment withheld per m/s an insertion misses its
+    /// tolerance by.
+    #[serde(default = "default_insertion_penalty_per_m_s")]
+    pub insertion_penalty_per_m_s: f64,
+    /// Most of the payment a missed insertion can cost.
+    #[serde(default = "default_insertion_max_penalty")]
+    pub insertion_max_penalty: f64,
+    /// Weight multiplier for destinations the player has reached, so
+    /// proven routes come up more often.
+    #[serde(default = "default_proven_destination_weight")]
+    pub proven_destination_weight: f64,
+    /// Share of contracts to a reached destination whose payload is
+    /// drawn around the heaviest the player has delivered there; the
+    /// rest span the market's whole range.
+    #[serde(default = "default_capability_cluster_share")]
+    pub capability_cluster_share: f64,
+    /// How far clustered payloads spread either side of the delivered
+    /// mass, as a fraction of it.
+    #[serde(default = "default_capability_payload_spread")]
+    pub capability_payload_spread: f64,
+    /// Contracts beyond the player's demonstrated capability offered
+    /// each month at least, topped up if the markets issue fewer.
+    #[serde(default = "default_stretch_contracts_per_month")]
+    pub stretch_contracts_per_month: u32,
+    /// A stretch contract to a reached destination carries at least
+    /// this much more than the heaviest payload delivered there.
+    #[serde(default = "default_stretch_payload_margin")]
+    pub stretch_payload_margin: f64,
+    /// Price swing across a market's deadline window: the tightest
+    /// deadline pays half this much over the going rate, the loosest
+    /// half this much under it.
+    #[serde(default = "default_deadline_premium")]
+    pub deadline_premium: f64,
+    /// Most a customer adds to its price for a company well above its
+    /// market's reputation target (see `contract::rep_factor`).
+    #[serde(default = "default_relationship_premium")]
+    pub relationship_premium: f64,
     /// Market templates + perturbation specs, realized per seed at
     /// game start (see [`crate::contract::MarketArchetype`]).
     pub archetypes: Vec<MarketArchetype>,
@@ -337,6 +497,25 @@ pub struct MarketsConfig {
 fn default_campaign_miss_rep_penalty() -> f64 { 2.0 }
 fn default_campaign_max_misses() -> u32 { 2 }
 fn default_campaign_cancel_rep_penalty() -> f64 { 4.0 }
+fn default_cost_plus_fee() -> f64 { 0.15 }
+fn default_cost_plus_indirect_rate() -> f64 { 4.5 }
+fn default_cost_plus_schedule_fraction() -> f64 { 0.75 }
+fn default_cost_plus_audit_chance() -> f64 { 0.3 }
+fn default_cost_plus_audit_disallow() -> f64 { 0.1 }
+fn default_science_upfront_share() -> f64 { 0.3 }
+fn default_science_data_months() -> u32 { 12 }
+fn default_science_data_premium() -> f64 { 0.35 }
+fn default_insertion_tolerance_share() -> f64 { 0.4 }
+fn default_insertion_tolerance_m_s() -> (f64, f64) { (15.0, 60.0) }
+fn default_insertion_penalty_per_m_s() -> f64 { 0.005 }
+fn default_insertion_max_penalty() -> f64 { 0.5 }
+fn default_proven_destination_weight() -> f64 { 2.0 }
+fn default_capability_cluster_share() -> f64 { 0.6 }
+fn default_capability_payload_spread() -> f64 { 0.3 }
+fn default_stretch_contracts_per_month() -> u32 { 2 }
+fn default_stretch_payload_margin() -> f64 { 0.25 }
+fn default_deadline_premium() -> f64 { 0.2 }
+fn default_relationship_premium() -> f64 { 0.1 }
 
 impl Default for MarketsConfig {
     fn default() -> Self {
@@ -350,6 +529,25 @@ impl Default for MarketsConfig {
             campaign_miss_rep_penalty: default_campaign_miss_rep_penalty(),
             campaign_max_misses: default_campaign_max_misses(),
             campaign_cancel_rep_penalty: default_campaign_cancel_rep_penalty(),
+            cost_plus_fee: default_cost_plus_fee(),
+            cost_plus_indirect_rate: default_cost_plus_indirect_rate(),
+            cost_plus_schedule_fraction: default_cost_plus_schedule_fraction(),
+            cost_plus_audit_chance: default_cost_plus_audit_chance(),
+            cost_plus_audit_disallow: default_cost_plus_audit_disallow(),
+            science_upfront_share: default_science_upfront_share(),
+            science_data_months: default_science_data_months(),
+            science_data_premium: default_science_data_premium(),
+            insertion_tolerance_share: default_insertion_tolerance_share(),
+            insertion_tolerance_m_s: default_insertion_tolerance_m_s(),
+            insertion_penalty_per_m_s: default_insertion_penalty_per_m_s(),
+            insertion_max_penalty: default_insertion_max_penalty(),
+            proven_destination_weight: default_proven_destination_weight(),
+            capability_cluster_share: default_capability_cluster_share(),
+            capability_payload_spread: default_capability_payload_spread(),
+            stretch_contracts_per_month: default_stretch_contracts_per_month(),
+            stretch_payload_margin: default_stretch_payload_margin(),
+            deadline_premium: default_deadline_premium(),
+            relationship_premium: default_relationship_premium(),
             archetypes: crate::contract::default_archetypes(),
         }
     }
@@ -373,6 +571,15 @@ impl MarketsConfig {
         if self.campaign_max_misses < 1 {
             return Err("campaign_max_misses must be >= 1".into());
         }
+        if self.proven_destination_weight < 0.0 || !(0.0..1.0).contains(&self.capability_payload_spread) {
+            return Err("proven_destination_weight must be >= 0 and capability_payload_spread in [0, 1)".into());
+        }
+        if self.stretch_payload_margin <= 0.0 {
+            return Err("stretch_payload_margin must be positive".into());
+        }
+        if !(0.0..2.0).contains(&self.deadline_premium) || self.relationship_premium < 0.0 {
+            return Err("deadline_premium must be in [0, 2) and relationship_premium >= 0".into());
+        }
         let mut keys = std::collections::HashSet::new();
         let mut ids = std::collections::HashSet::new();
         for a in &self.archetypes {
@@ -582,6 +789,11 @@ pub struct FlawsConfig {
     /// Flat probability that a rocket modification introduces a new
     /// undiscovered flaw.
     pub modification_flaw_prob: f64,
+    /// Near-miss chance on a successful launch, as a multiple of an
+    /// un-triggered flaw's activation chance.
+    pub near_miss_ratio: f64,
+    /// Discovery-probability multiplier for flaws an anomaly has hinted at.
+    pub anomaly_discovery_multiplier: f64,
 }
 
 impl Default for FlawsConfig {
@@ -597,6 +809,8 @@ impl Default for FlawsConfig {
             improvement_discovery_chance: 0.08,
             reactor_improvement_discovery_chance: 0.08,
             modification_flaw_prob: 0.10,
+            near_miss_ratio: 2.0,
+            anomaly_discovery_multiplier: 3.0,
         }
     }
 }
@@ -627,12 +841,46 @@ pub struct ReputationConfig {
     pub expiry_penalty: f64,
     /// Subtracted from the drought factor per year without a launch.
     pub drought_penalty: f64,
-    /// Total reputation required to design a medium-enriched-uranium
-    /// reactor. Naval / research-reactor territory.
+    /// Subtracted from the earnings factor per missed quarter (listed
+    /// companies only).
+    pub earnings_miss_penalty: f64,
+    /// Earnings factor decay multiplier applied each met quarter.
+    pub earnings_decay: f64,
+    /// Government reputation required to design a medium-enriched-
+    /// uranium reactor. Naval / research-reactor territory.
     pub reactor_meu_min_reputation: f64,
-    /// Total reputation required to design a highly-enriched-uranium
-    /// reactor. Kilopower / weapons-grade.
+    /// Government reputation required to design a highly-enriched-
+    /// uranium reactor. Kilopower / weapons-grade.
     pub reactor_heu_min_reputation: f64,
+    /// Subtracted from the crew factor per crew lost.
+    pub crew_loss_penalty: f64,
+    /// Crew factor decay multiplier applied each successful launch.
+    pub crew_decay: f64,
+    /// How commercial customers weigh each reputation factor.
+    pub commercial_weights: SegmentWeights,
+    /// How government agencies weigh each reputation factor.
+    pub government_weights: SegmentWeights,
+    /// How the public weighs each reputation factor.
+    pub public_weights: SegmentWeights,
+}
+
+/// Per-factor multipliers turning `Reputation`'s factors into one
+/// audience's score (1.0 everywhere = the headline total).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SegmentWeights {
+    pub success: f64,
+    pub lost_payload: f64,
+    pub drought: f64,
+    pub expiry: f64,
+    pub earnings: f64,
+    pub crew: f64,
+}
+
+impl Default for SegmentWeights {
+    fn default() -> Self {
+        SegmentWeights { success: 1.0, lost_payload: 1.0, drought: 1.0, expiry: 1.0, earnings: 1.0, crew: 1.0 }
+    }
 }
 
 impl Default for ReputationConfig {
@@ -647,8 +895,27 @@ impl Default for ReputationConfig {
             expiry_decay: 0.8,
             expiry_penalty: 10.0,
             drought_penalty: 10.0,
+            earnings_miss_penalty: 5.0,
+            earnings_decay: 0.5,
             reactor_meu_min_reputation: 60.0,
             reactor_heu_min_reputation: 150.0,
+            crew_loss_penalty: 60.0,
+            crew_decay: 0.9,
+            // Customers with money on the line care about schedule and
+            // a solvent supplier; they shrug at crew accidents.
+            commercial_weights: SegmentWeights {
+                expiry: 1.5, earnings: 1.5, crew: 0.5, ..SegmentWeights::default()
+            },
+            // Agencies forgive a quiet year and ignore the stock price,
+            // but crew safety is their job.
+            government_weights: SegmentWeights {
+                drought: 0.5, earnings: 0.0, crew: 1.5, ..SegmentWeights::default()
+            },
+            // The public never hears about a slipped contract and
+            // never forgets a crew.
+            public_weights: SegmentWeights {
+                lost_payload: 0.5, expiry: 0.0, earnings: 0.5, crew: 3.0, ..SegmentWeights::default()
+            },
         }
     }
 }
@@ -810,57 +1077,2030 @@ impl CompetitorConfig {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// ==========================================
+// Recovery
+// ==========================================
 
-    #[test]
-    fn test_default_round_trips_through_toml() {
-        let config = BalanceConfig::default();
-        let text = config.to_toml_string().expect("serialize");
-        let parsed: BalanceConfig = toml::from_str(&text).expect("parse");
-        assert_eq!(config, parsed);
+/// Hardware recovery operations after launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecoveryConfig {
+    /// Cost of a fairing recovery operation (ship charter, crew),
+    /// charged per launch whether or not the halves are caught.
+    pub fairing_recovery_cost: f64,
+    /// Chance of recovering a fairing in calm seas (sea state 0).
+    pub fairing_calm_sea_success: f64,
+    /// Recovery chance lost per sea-state step.
+    pub fairing_sea_state_penalty: f64,
+    /// Highest sea state rolled at the recovery zone (Douglas scale).
+    pub max_sea_state: u32,
+    /// Material cost of refurbishing a recovered fairing, as a fraction
+    /// of building a new one.
+    pub fairing_refurbish_fraction: f64,
+}
+
+impl Default for RecoveryConfig {
+    fn default() -> Self {
+        RecoveryConfig {
+            fairing_recovery_cost: 100_000.0,
+            fairing_calm_sea_success: 0.90,
+            fairing_sea_state_penalty: 0.12,
+            max_sea_state: 6,
+            fairing_refurbish_fraction: 0.20,
+        }
     }
+}
 
-    #[test]
-    fn test_load_layered_no_files_is_default() {
-        let config = BalanceConfig::load_layered::<&str>(&[]).expect("load");
-        assert_eq!(config, BalanceConfig::default());
+impl RecoveryConfig {
+    /// Chance of recovering a fairing at the given sea state.
+    pub fn fairing_success_chance(&self, sea_state: u32) -> f64 {
+        (self.fairing_calm_sea_success - self.fairing_sea_state_penalty * sea_state as f64)
+            .clamp(0.0, 1.0)
     }
+}
 
-    #[test]
-    fn test_partial_toml_only_overrides_named_fields() {
-        let mut merged = toml::Value::try_from(BalanceConfig::default()).unwrap();
-        let overlay: toml::Value =
-            "[costs]\nstarting_money = 50000000.0\n".parse().unwrap();
-        deep_merge(&mut merged, overlay);
-        let config: BalanceConfig = merged.try_into().unwrap();
-        assert_eq!(config.costs.starting_money, 50_000_000.0);
-        // Everything else untouched
-        let default = BalanceConfig::default();
-        assert_eq!(config.costs.engineering_monthly_salary,
-            default.costs.engineering_monthly_salary);
-        assert_eq!(config.work, default.work);
-        assert_eq!(config.markets, default.markets);
+// ==========================================
+// Pad leasing
+// ==========================================
+
+/// Offers from outside operators to rent the player's pad.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PadLeasingConfig {
+    /// Lease offers posted at the start of each month (replacing any
+    /// unaccepted ones).
+    pub offers_per_month: u32,
+    pub min_days: u32,
+    pub max_days: u32,
+    pub daily_rate_min: f64,
+    pub daily_rate_max: f64,
+    /// Offers start between a week and this many days out.
+    pub max_lead_days: u32,
+}
+
+impl Default for PadLeasingConfig {
+    fn default() -> Self {
+        PadLeasingConfig {
+            offers_per_month: 2,
+            min_days: 2,
+            max_days: 10,
+            daily_rate_min: 15_000.0,
+            daily_rate_max: 40_000.0,
+            max_lead_days: 90,
+        }
+    }
+}
+
+// ==========================================
+// Regulation
+// ==========================================
+
+/// Launch licensing, environmental reviews, and cadence limits.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegulationConfig {
+    /// Application fee and lead time for a cislunar license (Earth
+    /// orbit is held from the start).
+    pub cislunar_license_fee: f64,
+    pub cislunar_license_lead_days: u32,
+    pub deep_space_license_fee: f64,
+    pub deep_space_license_lead_days: u32,
+    /// Solid-motor launches that trigger an environmental review.
+    pub solid_launches_per_review: u32,
+    /// Days the site is closed for a review, and the review's fee.
+    pub review_days: u32,
+    pub review_fee: f64,
+    /// Fine for debris from a failure at launch (also starts a review).
+    pub debris_fine: f64,
+    /// At most `max_launches_per_window` launches per
+    /// `cadence_window_days` rolling window.
+    pub cadence_window_days: u32,
+    pub max_launches_per_window: u32,
+    /// Cost and lead time of the facility security-clearance upgrade
+    /// export-controlled customers require.
+    pub clearance_upgrade_cost: f64,
+    pub clearance_upgrade_days: u32,
+    /// Per-launch export paperwork for a controlled payload: fee, and
+    /// days from filing (on award) until it clears.
+    pub export_paperwork_fee: f64,
+    pub export_paperwork_days: u32,
+    /// Fine for flying a controlled payload before its paperwork
+    /// clears, and the days then spent barred from controlled work.
+    pub export_violation_fine: f64,
+    pub export_ban_days: u32,
+}
+
+impl Default for RegulationConfig {
+    fn default() -> Self {
+        RegulationConfig {
+            cislunar_license_fee: 2_000_000.0,
+            cislunar_license_lead_days: 90,
+            deep_space_license_fee: 5_000_000.0,
+            deep_space_license_lead_days: 180,
+            solid_launches_per_review: 6,
+            review_days: 21,
+            review_fee: 150_000.0,
+            debris_fine: 750_000.0,
+            cadence_window_days: 30,
+            max_launches_per_window: 4,
+            clearance_upgrade_cost: 8_000_000.0,
+            clearance_upgrade_days: 120,
+            export_paperwork_fee: 250_000.0,
+            export_paperwork_days: 45,
+            export_violation_fine: 15_000_000.0,
+            export_ban_days: 365,
+        }
     }
+}
 
-    #[test]
-    fn test_layered_files_later_wins() {
-        let dir = std::env::temp_dir();
-        let base = dir.join("rt_balance_test_base.toml");
-        let over = dir.join("rt_balance_test_over.toml");
-        std::fs::write(&base,
-            "[work]\nengine_design_base_days = 100.0\nrocket_design_base_days = 50.0\n").unwrap();
-        std::fs::write(&over, "[work]\nengine_design_base_days = 80.0\n").unwrap();
-        let config = BalanceConfig::load_layered(&[&base, &over]).expect("load");
-        std::fs::remove_file(&base).ok();
-        std::fs::remove_file(&over).ok();
-        // Later file wins where both set a value
-        assert_eq!(config.work.engine_design_base_days, 80.0);
-        // Earlier file's other override survives
-        assert_eq!(config.work.rocket_design_base_days, 50.0);
-        // Untouched fields keep defaults
-        assert_eq!(config.work.testing_cycle_work, 30.0);
+impl RegulationConfig {
+    /// Application (fee, lead days) for a license class.
+    pub fn license_terms(&self, class: crate::regulation::LicenseClass) -> (f64, u32) {
+        use crate::regulation::LicenseClass;
+        match class {
+            LicenseClass::EarthOrbit => (0.0, 0),
+            LicenseClass::Cislunar => (self.cislunar_license_fee, self.cislunar_license_lead_days),
+            LicenseClass::DeepSpace => (self.deep_space_license_fee, self.deep_space_license_lead_days),
+        }
+    }
+}
+
+// ==========================================
+// Valuation
+// ==========================================
+
+/// Company appraisal weights and the IPO / earnings loop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValuationConfig {
+    /// Fraction of hardware cost and facilities' book value a buyer would pay.
+    pub asset_resale_fraction: f64,
+    /// Fraction of finished designs' NRE counted as value.
+    pub design_value_fraction: f64,
+    /// Fraction of backlog contract payments counted (delivery risk).
+    pub backlog_fraction: f64,
+    /// Valuation per point of total reputation.
+    pub value_per_reputation: f64,
+    /// Months of valuation history kept.
+    pub history_months: usize,
+    /// Minimum valuation to be allowed to IPO.
+    pub min_ipo_valuation: f64,
+    /// Share of the company sold at IPO (cash raised = valuation × this).
+    pub ipo_float_fraction: f64,
+    /// Quarterly net earnings expected, as a fraction of IPO valuation.
+    pub quarterly_target_fraction: f64,
+    /// Sentiment multiplier applied on a missed quarter.
+    pub miss_sentiment_factor: f64,
+    /// Sentiment regained on a met quarter (capped at 1.0).
+    pub beat_sentiment_gain: f64,
+}
+
+impl Default for ValuationConfig {
+    fn default() -> Self {
+        ValuationConfig {
+            asset_resale_fraction: 0.5,
+            design_value_fraction: 0.5,
+            backlog_fraction: 0.8,
+            value_per_reputation: 200_000.0,
+            history_months: 240,
+            min_ipo_valuation: 100_000_000.0,
+            ipo_float_fraction: 0.25,
+            quarterly_target_fraction: 0.01,
+            miss_sentiment_factor: 0.85,
+            beat_sentiment_gain: 0.05,
+        }
+    }
+}
+
+// ==========================================
+// Espionage
+// ==========================================
+
+/// Design copying by competitors and leaked-data offers to the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EspionageConfig {
+    /// Monthly chance a competitor copies a player engine with no
+    /// security spending.
+    pub copy_chance_per_month: f64,
+    /// Copy chance multiplier per security level.
+    pub security_leak_factor: f64,
+    pub max_security_level: u32,
+    /// Monthly cost per security level.
+    pub security_cost_per_level: f64,
+    /// Competitor failure-rate multiplier when it copies an engine.
+    pub copy_failure_rate_factor: f64,
+    /// Reputation lost when a design is copied (the edge is diluted).
+    pub copy_reputation_penalty: f64,
+    /// Monthly chance leaked competitor data comes up for sale.
+    pub leak_offer_chance: f64,
+    pub leak_price_min: f64,
+    pub leak_price_max: f64,
+    /// Days an offer stays open.
+    pub leak_offer_days: u32,
+    /// Testing cycles granted by a test-data leak.
+    pub leak_testing_cycles: u32,
+}
+
+impl Default for EspionageConfig {
+    fn default() -> Self {
+        EspionageConfig {
+            copy_chance_per_month: 0.03,
+            security_leak_factor: 0.4,
+            max_security_level: 3,
+            security_cost_per_level: 50_000.0,
+            copy_failure_rate_factor: 0.7,
+            copy_reputation_penalty: 5.0,
+            leak_offer_chance: 0.10,
+            leak_price_min: 500_000.0,
+            leak_price_max: 2_000_000.0,
+            leak_offer_days: 30,
+            leak_testing_cycles: 3,
+        }
+    }
+}
+
+// ==========================================
+// Certification
+// ==========================================
+
+/// Design certification for crewed and national-security markets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CertificationConfig {
+    /// Consecutive fully successful flights of a revision before it can
+    /// enter review.
+    pub required_successes: u32,
+    /// Team work units for the certification review.
+    pub review_work: f64,
+    /// Documentation cost charged when the review opens.
+    pub documentation_cost: f64,
+}
+
+impl Default for CertificationConfig {
+    fn default() -> Self {
+        CertificationConfig {
+            required_successes: 3,
+            review_work: 90.0,
+            documentation_cost: 1_000_000.0,
+        }
+    }
+}
+
+// ==========================================
+// Flight software
+// ==========================================
+
+/// Flight software lineages and the risk of flying immature code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoftwareConfig {
+    /// Work units to write a new lineage.
+    pub design_work: f64,
+    /// Work units for a rocket to adapt an existing lineage.
+    pub adaptation_work: f64,
+    /// Mean software flaw count for a new lineage.
+    pub flaw_count_mean: f64,
+    /// Multiplier on rolled activation chances for software flaws.
+    pub flaw_activation_scale: f64,
+    /// Cumulative testing work at which a lineage counts as mature.
+    pub maturity_testing_work: f64,
+    /// Per-flight chance of a guidance error with no mature software;
+    /// scales down linearly with maturity.
+    pub immature_guidance_risk: f64,
+    /// Performance loss from an immaturity guidance error.
+    pub immature_degradation: f64,
+}
+
+impl Default for SoftwareConfig {
+    fn default() -> Self {
+        SoftwareConfig {
+            design_work: 60.0,
+            adaptation_work: 10.0,
+            flaw_count_mean: 2.0,
+            flaw_activation_scale: 0.5,
+            maturity_testing_work: 120.0,
+            immature_guidance_risk: 0.10,
+            immature_degradation: 0.06,
+        }
+    }
+}
+
+// ==========================================
+// Pre-launch integration
+// ==========================================
+
+/// Integration campaigns that validate one rocket against one mission.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntegrationConfig {
+    /// Work units for any campaign.
+    pub base_work: f64,
+    /// Extra work units per burn in the mission plan.
+    pub work_per_burn: f64,
+    /// Fractional cut to flaw activation chances on the validated flight.
+    pub risk_reduction: f64,
+}
+
+impl Default for IntegrationConfig {
+    fn default() -> Self {
+        IntegrationConfig {
+            base_work: 8.0,
+            work_per_burn: 3.0,
+            risk_reduction: 0.3,
+        }
+    }
+}
+
+// ==========================================
+// Inventory storage
+// ==========================================
+
+/// Storage fees, shelf life and disposal of built hardware.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    /// Monthly warehouse fee per floor-space unit of stored hardware
+    /// that doesn't fit in the factory's idle floor space.
+    pub monthly_cost_per_unit: f64,
+    /// Floor-space units one stored engine takes.
+    pub engine_units: f64,
+    /// Floor-space units one stored stage (or a rocket's stage) takes.
+    pub stage_units: f64,
+    /// Days a solid motor keeps before it needs recertifying.
+    pub solid_shelf_life_days: u32,
+    /// Days cryogenic-propellant hardware keeps.
+    pub cryogenic_shelf_life_days: u32,
+    /// Per-flight chance an expired vehicle loses a stage.
+    pub expired_failure_risk: f64,
+    /// Manufacturing work units to recertify one item.
+    pub recertification_work: f64,
+    /// Recertification materials as a fraction of the item's build cost.
+    pub recertification_cost_fraction: f64,
+    /// Fraction of build cost recovered by scrapping an item.
+    pub scrap_recovery_fraction: f64,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig {
+            monthly_cost_per_unit: 50_000.0,
+            engine_units: 0.25,
+            stage_units: 1.0,
+            solid_shelf_life_days: 3 * 365,
+            cryogenic_shelf_life_days: 5 * 365,
+            expired_failure_risk: 0.08,
+            recertification_work: 10.0,
+            recertification_cost_fraction: 0.05,
+            scrap_recovery_fraction: 0.15,
+        }
+    }
+}
+
+// ==========================================
+// Retrofits
+// ==========================================
+
+/// Reworking stored rockets up to their design's current revision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetrofitConfig {
+    /// What one fixed flaw's rework would cost on new hardware.
+    pub flaw_rework_cost: f64,
+    /// Fraction of the revision delta's new-build cost a retrofit costs.
+    pub cost_fraction: f64,
+    /// Retrofit work as a fraction of the rocket's integration work.
+    pub work_fraction: f64,
+}
+
+impl Default for RetrofitConfig {
+    fn default() -> Self {
+        RetrofitConfig {
+            flaw_rework_cost: 2_000_000.0,
+            cost_fraction: 0.5,
+            work_fraction: 0.5,
+        }
+    }
+}
+
+// ==========================================
+// Depreciation
+// ==========================================
+
+/// Book values of facilities: straight-line depreciation from cost
+/// down to a salvage value over a useful life.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DepreciationConfig {
+    /// Useful life of a floor-space unit.
+    pub floor_space_life_months: u32,
+    /// What the company's own launch pad cost to build.
+    pub pad_cost: f64,
+    /// Useful life of the launch pad.
+    pub pad_life_months: u32,
+    /// Useful life of a landing zone or drone ship.
+    pub recovery_asset_life_months: u32,
+    /// Fraction of cost an asset is still worth at the end of its life.
+    pub salvage_fraction: f64,
+}
+
+impl Default for DepreciationConfig {
+    fn default() -> Self {
+        DepreciationConfig {
+            floor_space_life_months: 360,
+            pad_cost: 40_000_000.0,
+            pad_life_months: 480,
+            recovery_asset_life_months: 240,
+            salvage_fraction: 0.2,
+        }
+    }
+}
+
+// ==========================================
+// Design heritage
+// ==========================================
+
+/// What a design derived from an existing one carries over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeritageConfig {
+    /// Design work a scaled engine variant needs, as a fraction of a
+    /// clean-sheet design.
+    pub variant_design_work_fraction: f64,
+    /// Fraction of the parent's testing work a variant starts with.
+    pub testing_carryover: f64,
+    /// Fraction of a clean-sheet flaw roll a variant adds on top of the
+    /// flaws it inherits.
+    pub new_flaw_fraction: f64,
+    /// Fraction of a rocket design's testing work a forked branch
+    /// starts with.
+    pub branch_testing_carryover: f64,
+}
+
+impl Default for HeritageConfig {
+    fn default() -> Self {
+        HeritageConfig {
+            variant_design_work_fraction: 0.5,
+            testing_carryover: 0.5,
+            new_flaw_fraction: 0.5,
+            branch_testing_carryover: 0.75,
+        }
+    }
+}
+
+// ==========================================
+// Competitive bidding
+// ==========================================
+
+/// How competing sealed bids are scored and what a losing bid costs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BiddingConfig {
+    /// Award score per unit of launch track record (smoothed success
+    /// rate, 0..1), on top of each market's price and reputation terms.
+    pub record_weight: f64,
+    /// Engineering team-days spent writing a proposal. A winning bid
+    /// folds this into the contract; a losing one writes it off. 0 =
+    /// proposals are free (the default, which the sim bands assume).
+    pub proposal_team_days: f64,
+}
+
+impl Default for BiddingConfig {
+    fn default() -> Self {
+        BiddingConfig {
+            record_weight: 0.2,
+            proposal_team_days: 0.0,
+        }
+    }
+}
+
+// ==========================================
+// Government programs
+// ==========================================
+
+/// Government program announcements, subsidies and clawbacks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GovProgramsConfig {
+    /// Chance per month that an agency announces a new program.
+    pub announce_chance_per_month: f64,
+    /// Days the player has to commit after an announcement.
+    pub response_window_days: u32,
+    /// Multiplier on every program's monthly subsidy.
+    pub subsidy_scale: f64,
+    /// Fraction of the subsidy received that a missed milestone
+    /// claws back.
+    pub clawback_fraction: f64,
+}
+
+impl Default for GovProgramsConfig {
+    fn default() -> Self {
+        GovProgramsConfig {
+            announce_chance_per_month: 0.06,
+            response_window_days: 90,
+            subsidy_scale: 1.0,
+            clawback_fraction: 0.8,
+        }
+    }
+}
+
+// ==========================================
+// Astronaut corps
+// ==========================================
+
+/// Astronaut hiring, training, fatigue, and the stand-down after a
+/// crew is lost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AstronautConfig {
+    pub hiring_cost: f64,
+    pub monthly_salary: f64,
+    /// Days of candidate training before an astronaut can fly.
+    pub training_days: u32,
+    pub candidate_skill: f64,
+    /// Skill on qualifying.
+    pub qualified_skill: f64,
+    pub skill_per_flight: f64,
+    /// Fraction of flaw activation chance a fully skilled crew (skill
+    /// 1.0) heads off; scales linearly with the crew's average skill.
+    pub skill_flaw_reduction: f64,
+    pub fatigue_per_flight: f64,
+    pub fatigue_recovery_per_day: f64,
+    pub max_fatigue_to_fly: f64,
+    /// Astronauts the UI assigns to a crewed flight.
+    pub crew_size: u32,
+    /// Reputation hit for a lost crew, as a launch-failure severity.
+    pub crew_loss_severity: f64,
+    /// Days every team works at reduced efficiency after a crew loss.
+    pub stand_down_days: u32,
+    /// Team efficiency during the stand-down.
+    pub stand_down_efficiency: f64,
+}
+
+impl Default for AstronautConfig {
+    fn default() -> Self {
+        AstronautConfig {
+            hiring_cost: 500_000.0,
+            monthly_salary: 15_000.0,
+            training_days: 365,
+            candidate_skill: 0.2,
+            qualified_skill: 0.5,
+            skill_per_flight: 0.1,
+            skill_flaw_reduction: 0.3,
+            fatigue_per_flight: 0.5,
+            fatigue_recovery_per_day: 0.005,
+            max_fatigue_to_fly: 0.3,
+            crew_size: 3,
+            crew_loss_severity: 5.0,
+            stand_down_days: 180,
+            stand_down_efficiency: 0.5,
+        }
+    }
+}
+
+// ==========================================
+// Launch abort
+// ==========================================
+
+/// Launch abort systems and the pad-abort tests that prove them out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchAbortConfig {
+    /// Chance an untested abort system pulls a crew clear of a booster
+    /// failure.
+    pub abort_success_chance: f64,
+    /// Added to the abort chance per passed pad-abort test.
+    pub pad_abort_gain: f64,
+    pub max_abort_success_chance: f64,
+    /// Manufacturing work units to stack and fly a pad-abort test.
+    pub pad_abort_test_work: f64,
+    /// Boilerplate capsule and range costs, on top of the abort system
+    /// the test expends.
+    pub pad_abort_test_cost: f64,
+}
+
+impl Default for LaunchAbortConfig {
+    fn default() -> Self {
+        LaunchAbortConfig {
+            abort_success_chance: 0.85,
+            pad_abort_gain: 0.03,
+            max_abort_success_chance: 0.97,
+            pad_abort_test_work: 40.0,
+            pad_abort_test_cost: 2_000_000.0,
+        }
+    }
+}
+
+// ==========================================
+// Infrastructure / ISRU
+// ==========================================
+
+/// A location where ISRU plants work, and what they make there (mass
+/// fractions of the daily output).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IsruSite {
+    pub location: String,
+    pub products: Vec<(crate::propellant::Propellant, f64)>,
+}
+
+/// ISRU plants and the depots they fill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IsruConfig {
+    /// Daily output of the stock plant the player launches, in kg.
+    pub plant_output_kg_per_day: f64,
+    /// Plant landed mass per kg/day of output.
+    pub plant_mass_per_daily_kg: f64,
+    /// Plant cost per kg/day of output.
+    pub plant_cost_per_daily_kg: f64,
+    /// Propellant a location's depot holds, all types together.
+    pub depot_capacity_kg: f64,
+    /// Electrical power the stock depot's solar arrays deliver at the
+    /// site they're sized for, in kW.
+    pub depot_power_kw: f64,
+    /// Heat the stock depot's cryocoolers can lift, in kW (thermal).
+    pub depot_cryocooler_kw: f64,
+    /// Plant power draw per kg/day of output, in kW.
+    pub plant_power_kw_per_daily_kg: f64,
+    /// Heat leaking into stored cryogenic propellant, in W per tonne.
+    pub cryo_heat_leak_w_per_tonne: f64,
+    /// Electrical watts a cryocooler needs per thermal watt it lifts.
+    pub cryocooler_w_per_w: f64,
+    /// Daily fraction of cryogenic stores lost with no cooling at all.
+    pub uncooled_boiloff_per_day: f64,
+    pub sites: Vec<IsruSite>,
+}
+
+impl IsruConfig {
+    /// The product mix at `location`, or None if plants can't work there.
+    pub fn products_at(&self, location: &str) -> Option<&[(crate::propellant::Propellant, f64)]> {
+        self.sites.iter().find(|s| s.location == location).map(|s| s.products.as_slice())
+    }
+}
+
+impl Default for IsruConfig {
+    fn default() -> Self {
+        use crate::propellant::Propellant;
+        // Water electrolysis gives oxygen and hydrogen 8:1 by mass; the
+        // Sabatier process on Mars CO2 gives oxygen and methane ~3.6:1.
+        let water = vec![(Propellant::LOX, 8.0 / 9.0), (Propellant::LH2, 1.0 / 9.0)];
+        IsruConfig {
+            plant_output_kg_per_day: 100.0,
+            plant_mass_per_daily_kg: 60.0,
+            plant_cost_per_daily_kg: 400_000.0,
+            depot_capacity_kg: 200_000.0,
+            // One stock plant (40 kW) plus cooling a full depot (4 kW)
+            // fits; a second plant runs power-starved.
+            depot_power_kw: 60.0,
+            depot_cryocooler_kw: 0.2,
+            plant_power_kw_per_daily_kg: 0.4,
+            cryo_heat_leak_w_per_tonne: 1.0,
+            cryocooler_w_per_w: 20.0,
+            uncooled_boiloff_per_day: 0.003,
+            sites: vec![
+                IsruSite { location: "lunar_surface".into(), products: water.clone() },
+                IsruSite {
+                    location: "mars_surface".into(),
+                    products: vec![(Propellant::LOX, 0.78), (Propellant::Methane, 0.22)],
+                },
+                IsruSite { location: "ceres_surface".into(), products: water },
+            ],
+        }
+    }
+}
+
+// ==========================================
+// External design reviews
+// ==========================================
+
+/// Third-party design reviews: cash instead of team time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExternalReviewConfig {
+    /// Fee for reviewing an engine design, per point of its complexity.
+    pub engine_fee_per_complexity: f64,
+    /// Fee for reviewing a rocket design.
+    pub rocket_fee: f64,
+    /// Days from order to findings.
+    pub duration_days: u32,
+    /// Share of the design's hidden flaws the review is guaranteed to
+    /// find (rounded up, so it always finds at least one if any exist).
+    pub discovery_fraction: f64,
+}
+
+impl Default for ExternalReviewConfig {
+    fn default() -> Self {
+        // A complexity-5 engine review costs about what five teams earn
+        // in a testing cycle, without tying any of them up.
+        ExternalReviewConfig {
+            engine_fee_per_complexity: 600_000.0,
+            rocket_fee: 4_000_000.0,
+            duration_days: 30,
+            discovery_fraction: 0.5,
+        }
+    }
+}
+
+// ==========================================
+// Team morale
+// ==========================================
+
+/// Engineering team morale: what moves it, and what it does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoraleConfig {
+    /// Daily pull back toward neutral morale.
+    pub recovery_per_day: f64,
+    pub launch_success: f64,
+    pub launch_failure: f64,
+    /// Hit to everyone left when a team is let go.
+    pub firing: f64,
+    /// Hit when payroll leaves the company in the red.
+    pub missed_payroll: f64,
+    /// Daily drain while the company is in crunch.
+    pub crunch_per_day: f64,
+    /// Extra work crunch buys, as a share of a normal day.
+    pub crunch_bonus: f64,
+    /// Work lost per point of average morale below neutral.
+    pub efficiency_slope: f64,
+    /// Morale below which teams start resigning.
+    pub attrition_threshold: f64,
+    /// Daily chance a team at zero morale resigns.
+    pub attrition_per_day: f64,
+    /// Hit to a team called back from furlough.
+    pub recall: f64,
+    /// Days a recalled team takes to get back up to speed.
+    pub recall_ramp_days: u32,
+    /// Share of a normal day's work a team gets done while ramping up.
+    pub ramp_up_efficiency: f64,
+}
+
+impl Default for MoraleConfig {
+    fn default() -> Self {
+        MoraleConfig {
+            recovery_per_day: 0.005,
+            launch_success: 0.03,
+            launch_failure: -0.08,
+            firing: -0.10,
+            missed_payroll: -0.15,
+            crunch_per_day: -0.004,
+            crunch_bonus: 0.25,
+            efficiency_slope: 1.0,
+            attrition_threshold: 0.3,
+            attrition_per_day: 0.01,
+            recall: -0.10,
+            recall_ramp_days: 20,
+            ramp_up_efficiency: 0.5,
+        }
+    }
+}
+
+// ==========================================
+// Marketing
+// ==========================================
+
+/// PR campaign and press event prices, their fame multipliers, and how
+/// media sentiment moves and feeds contract rates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketingConfig {
+    /// Price of one PR campaign.
+    pub pr_campaign_cost: f64,
+    /// How long a PR campaign runs; ordering another extends it.
+    pub pr_campaign_days: u32,
+    /// Sentiment a running campaign adds per day.
+    pub pr_sentiment_per_day: f64,
+    /// Fame multiplier on successes while a campaign runs.
+    pub pr_success_mult: f64,
+    /// Price of a press briefing for one launch.
+    pub briefing_cost: f64,
+    /// Price of livestreaming one launch.
+    pub livestream_cost: f64,
+    /// Fame multiplier on a briefed launch's success.
+    pub briefing_success_mult: f64,
+    /// Fame multiplier on a livestreamed launch's success.
+    pub livestream_success_mult: f64,
+    /// Reputation penalty multiplier on a briefed launch's failure.
+    pub briefing_failure_mult: f64,
+    /// Reputation penalty multiplier on a livestreamed launch's failure.
+    pub livestream_failure_mult: f64,
+    /// Sentiment gained by a publicized success, before reach.
+    pub success_sentiment: f64,
+    /// Sentiment lost by a failure, before reach.
+    pub failure_sentiment: f64,
+    /// Audience multiplier for a briefed launch (unpublicized = 1).
+    pub briefing_reach: f64,
+    /// Audience multiplier for a livestreamed launch.
+    pub livestream_reach: f64,
+    /// Fraction of sentiment that fades each day.
+    pub sentiment_decay_per_day: f64,
+    /// Contract rate swing at full sentiment (0.1 = ±10%).
+    pub contract_rate_swing: f64,
+}
+
+impl Default for MarketingConfig {
+    fn default() -> Self {
+        MarketingConfig {
+            pr_campaign_cost: 2_000_000.0,
+            pr_campaign_days: 60,
+            pr_sentiment_per_day: 0.006,
+            pr_success_mult: 1.25,
+            briefing_cost: 300_000.0,
+            livestream_cost: 1_200_000.0,
+            briefing_success_mult: 1.5,
+            livestream_success_mult: 2.0,
+            briefing_failure_mult: 1.5,
+            livestream_failure_mult: 2.5,
+            success_sentiment: 0.05,
+            failure_sentiment: -0.05,
+            briefing_reach: 2.0,
+            livestream_reach: 4.0,
+            sentiment_decay_per_day: 0.01,
+            contract_rate_swing: 0.1,
+        }
+    }
+}
+
+// ==========================================
+// Era
+// ==========================================
+
+/// The decade a game starts in. Pick one with `era::preset` and apply
+/// it with `era::apply`, which also rescales prices; editing this
+/// section in a balance file moves the start year and timeline but
+/// leaves prices alone. The default is the 2001 baseline every other
+/// number in this file is written for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EraConfig {
+    pub key: String,
+    pub name: String,
+    /// The game starts on January 1 of this year.
+    pub start_year: u32,
+    /// Price level relative to 2001 dollars, applied to `costs` and
+    /// contract rates by `era::apply`.
+    pub price_level: f64,
+    /// Markets that can't open before a year, whatever the archetype
+    /// table says.
+    pub market_openings: Vec<MarketOpening>,
+    /// Technologies that don't exist yet at the start; each becomes
+    /// available outright in its year.
+    pub tech_arrivals: Vec<TechArrival>,
+}
+
+/// A market held closed until `year`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketOpening {
+    pub market: crate::contract::MarketId,
+    pub year: u32,
+    /// Event-log text when it opens.
+    pub flavor: String,
+}
+
+/// A technology held back until `year`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TechArrival {
+    pub tech: crate::technology::TechnologyId,
+    pub year: u32,
+}
+
+impl Default for EraConfig {
+    fn default() -> Self {
+        EraConfig {
+            key: "commercial_dawn".into(),
+            name: "Commercial Dawn".into(),
+            start_year: 2001,
+            price_level: 1.0,
+            market_openings: Vec::new(),
+            tech_arrivals: Vec::new(),
+        }
+    }
+}
+
+impl EraConfig {
+    /// Year `tech` arrives, if this era holds it back past the start.
+    pub fn tech_arrival(&self, tech: crate::technology::TechnologyId) -> Option<u32> {
+        self.tech_arrivals.iter()
+            .find(|a| a.tech == tech && a.year > self.start_year)
+            .map(|a| a.year)
+    }
+}
+
+// ==========================================
+// Map variant
+// ==========================================
+
+/// Procedural perturbation of the delta-v map at world creation (see
+/// `map_variant`). Off by default: every world flies the reference
+/// map unless a new game opts in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapVariantConfig {
+    pub enabled: bool,
+    /// Each orbital transfer's delta-v is scaled by a seeded factor in
+    /// `1 ± transfer_spread`. Launch and landing legs keep their physics.
+    pub transfer_spread: f64,
+    /// Up to this many extra stations from the station catalog.
+    pub max_stations: u32,
+    /// Between one and this many generated near-Earth asteroids (0 = none).
+    pub max_asteroids: u32,
+}
+
+impl Default for MapVariantConfig {
+    fn default() -> Self {
+        MapVariantConfig {
+            enabled: false,
+            transfer_spread: 0.15,
+            max_stations: 2,
+            max_asteroids: 3,
+        }
+    }
+}
+
+// ==========================================
+// Transit anomalies
+// ==========================================
+
+/// Things going wrong on long transit legs. Each in-transit day of a
+/// leg that takes days rolls once; an anomaly's outcome is drawn from
+/// the weights below.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransitAnomalyConfig {
+    /// Chance of an anomaly per in-transit day.
+    pub daily_chance: f64,
+    pub benign_weight: f64,
+    /// Needs a mid-course correction from the vehicle's reserve.
+    pub correction_weight: f64,
+    /// Needs the engineering team (falls back to a correction when the
+    /// company has none).
+    pub team_weight: f64,
+    pub loss_weight: f64,
+    pub correction_dv_min: f64,
+    pub correction_dv_max: f64,
+    /// Overtime and contractor support for a team response.
+    pub team_response_cost: f64,
+}
+
+impl Default for TransitAnomalyConfig {
+    fn default() -> Self {
+        TransitAnomalyConfig {
+            daily_chance: 0.002,
+            benign_weight: 0.45,
+            correction_weight: 0.35,
+            team_weight: 0.15,
+            loss_weight: 0.05,
+            correction_dv_min: 20.0,
+            correction_dv_max: 120.0,
+            team_response_cost: 250_000.0,
+        }
+    }
+}
+
+// ==========================================
+// On-orbit servicing
+// ==========================================
+
+/// Asset wear and the kits that repair it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServicingConfig {
+    /// Health an ISRU plant loses per day (1.0 is new).
+    pub plant_wear_per_day: f64,
+    /// Health a parked spacecraft loses per day.
+    pub spacecraft_wear_per_day: f64,
+    /// Repair hardware, before any propellant.
+    pub kit_mass_kg: f64,
+    pub kit_cost: f64,
+    /// Kit mass per kg of propellant carried, tanks included.
+    pub tankage_factor: f64,
+    pub propellant_cost_per_kg: f64,
+}
+
+impl Default for ServicingConfig {
+    fn default() -> Self {
+        ServicingConfig {
+            // About five years to wear out.
+            plant_wear_per_day: 1.0 / 1_825.0,
+            // About eight.
+            spacecraft_wear_per_day: 1.0 / 2_920.0,
+            kit_mass_kg: 500.0,
+            kit_cost: 2_000_000.0,
+            tankage_factor: 1.1,
+            propellant_cost_per_kg: 5.0,
+        }
+    }
+}
+
+// ==========================================
+// Constellations
+// ==========================================
+
+/// What constellations earn and how far ahead they plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConstellationConfig {
+    /// Daily revenue per required slot, at full strength.
+    pub revenue_per_satellite_per_day: f64,
+    /// How far ahead a coming wear-out opens a replacement task.
+    pub planning_horizon_days: u32,
+}
+
+impl Default for ConstellationConfig {
+    fn default() -> Self {
+        ConstellationConfig {
+            revenue_per_satellite_per_day: 15_000.0,
+            planning_horizon_days: 180,
+        }
+    }
+}
+
+// ==========================================
+// Launch-day countdown
+// ==========================================
+
+/// The minute-by-minute launch-day operations mode. Times are minutes
+/// before T-0.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CountdownConfig {
+    /// When the count (and propellant loading) starts.
+    pub tanking_start_min: u32,
+    /// When loading finishes and terminal count begins.
+    pub tanking_end_min: u32,
+    /// Chance a loading fault aborts tanking, over the whole load.
+    pub tanking_abort_chance: f64,
+    /// T-minus points where the weather is polled.
+    pub weather_checks_min: Vec<u32>,
+    pub weather_no_go_chance: f64,
+    /// How often a weather hold re-checks.
+    pub weather_recheck_min: u32,
+    /// Holds inside this point recycle back to it before counting on.
+    pub recycle_to_min: u32,
+    /// Minutes from the start of the count before the window closes.
+    pub window_min: u32,
+}
+
+impl Default for CountdownConfig {
+    fn default() -> Self {
+        CountdownConfig {
+            tanking_start_min: 120,
+            tanking_end_min: 40,
+            tanking_abort_chance: 0.03,
+            weather_checks_min: vec![90, 10],
+            weather_no_go_chance: 0.15,
+            weather_recheck_min: 15,
+            recycle_to_min: 10,
+            window_min: 180,
+        }
+    }
+}
+
+// ==========================================
+// Stage disposal
+// ==========================================
+
+/// Spent-stage disposal and the regulator's view of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisposalConfig {
+    /// Name of the site's first-stage drop zone.
+    pub downrange_zone: String,
+    /// Clearing the zone for one multi-stage launch.
+    pub downrange_clearance_fee: f64,
+    /// Chance a first stage lands outside the cleared zone, and the
+    /// fine for it (the stray stage also starts an environmental
+    /// review).
+    pub stray_stage_chance: f64,
+    pub stray_stage_fine: f64,
+    /// Delta-v (m/s) a controlled deorbit burn takes.
+    pub deorbit_delta_v: f64,
+    /// Stages the company can leave in Earth orbit before each further
+    /// one is fined.
+    pub debris_allowance: u32,
+    pub orbital_debris_fine: f64,
+}
+
+impl Default for DisposalConfig {
+    fn default() -> Self {
+        DisposalConfig {
+            downrange_zone: "downrange zone".into(),
+            downrange_clearance_fee: 25_000.0,
+            stray_stage_chance: 0.01,
+            stray_stage_fine: 1_000_000.0,
+            deorbit_delta_v: 120.0,
+            debris_allowance: 10,
+            orbital_debris_fine: 500_000.0,
+        }
+    }
+}
+
+// ==========================================
+// Market evolution
+// ==========================================
+
+/// How customers' payloads and price expectations drift over the
+/// years of a game, from its start.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketTrendConfig {
+    /// Compounding yearly growth of contract payload masses.
+    pub payload_growth_per_year: f64,
+    /// Compounding yearly change in the price per kg customers expect
+    /// to pay (negative = launch gets cheaper).
+    pub rate_per_kg_change_per_year: f64,
+    /// How far ahead the market report looks, in years.
+    pub report_years: u32,
+}
+
+impl Default for MarketTrendConfig {
+    fn default() -> Self {
+        MarketTrendConfig {
+            payload_growth_per_year: 0.02,
+            rate_per_kg_change_per_year: -0.01,
+            report_years: 3,
+        }
+    }
+}
+
+// ==========================================
+// Market demand
+// ==========================================
+
+/// How deliveries saturate a market segment, and what saturation and
+/// neglect do to its contracts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DemandConfig {
+    /// Months a delivery is remembered for; a month's worth fades
+    /// each month.
+    pub memory_months: f64,
+    /// Share of a segment's contracts its customers can absorb being
+    /// flown. Delivering more saturates it.
+    pub served_share: f64,
+    /// Price premium of a segment nobody is flying to.
+    pub underserved_premium: f64,
+    /// Price cut per unit of saturation past balanced.
+    pub saturated_discount: f64,
+    /// Volume cut per unit of saturation past balanced.
+    pub saturated_volume_cut: f64,
+    /// Floor on the price multiplier however saturated.
+    pub min_rate_mult: f64,
+}
+
+impl Default for DemandConfig {
+    fn default() -> Self {
+        DemandConfig {
+            memory_months: 12.0,
+            served_share: 0.75,
+            underserved_premium: 0.1,
+            saturated_discount: 0.15,
+            saturated_volume_cut: 0.5,
+            min_rate_mult: 0.6,
+        }
+    }
+}
+
+// ==========================================
+// Subcontracting
+// ==========================================
+
+/// Outside launch providers' pricing, and the credit the company gets
+/// for a payload someone else flew.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubcontractConfig {
+    /// Rise in a provider's price share per booking.
+    pub price_step: f64,
+    /// Fraction of a provider's premium over its base price that eases
+    /// off each month.
+    pub monthly_price_recovery: f64,
+    /// Share of a launch success's reputation gain a subcontracted
+    /// delivery earns.
+    pub fame_share: f64,
+}
+
+impl Default for SubcontractConfig {
+    fn default() -> Self {
+        SubcontractConfig {
+            price_step: 0.08,
+            monthly_price_recovery: 0.2,
+            fame_share: 0.2,
+        }
+    }
+}
+
+// ==========================================
+// Payroll
+// ==========================================
+
+/// Wage inflation, and the raises engineering teams ask for as they
+/// gain experience.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PayrollConfig {
+    /// Yearly rise in salaries and hiring costs, applied each January.
+    pub salary_inflation: f64,
+    /// Years on the job between a team's pay talks.
+    pub renegotiation_years: u32,
+    /// Raise asked for per year of experience, as a share of salary.
+    pub raise_per_experience_year: f64,
+    /// Most a team asks for at once, as a share of salary.
+    pub max_raise: f64,
+    /// Days the player has to answer before silence counts as a no.
+    pub response_days: u32,
+    /// Hit to a team whose raise is refused.
+    pub refused_raise_morale: f64,
+    /// Chance a team walks out on the spot when its raise is refused.
+    pub refused_raise_quit_chance: f64,
+}
+
+impl Default for PayrollConfig {
+    fn default() -> Self {
+        PayrollConfig {
+            salary_inflation: 0.02,
+            renegotiation_years: 3,
+            raise_per_experience_year: 0.02,
+            max_raise: 0.1,
+            response_days: 30,
+            refused_raise_morale: -0.15,
+            refused_raise_quit_chance: 0.2,
+        }
+    }
+}
+
+// ==========================================
+// Science missions
+// ==========================================
+
+/// How long science assets last once they arrive, and how much of
+/// their data gets heard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScienceConfig {
+    /// Shortest an asset lasts after arrival, in months.
+    pub min_lifetime_months: u32,
+    /// Longest an asset lasts after arrival, in months.
+    pub max_lifetime_months: u32,
+    /// Share of the data heard from low Earth orbits, which are only in
+    /// contact during ground-station passes.
+    pub low_orbit_coverage: f64,
+    /// Share of the data heard from anywhere else.
+    pub deep_space_coverage: f64,
+    /// Share of the data heard where the company runs a constellation
+    /// to relay it.
+    pub relay_coverage: f64,
+}
+
+impl Default for ScienceConfig {
+    fn default() -> Self {
+        ScienceConfig {
+            min_lifetime_months: 6,
+            max_lifetime_months: 48,
+            low_orbit_coverage: 0.7,
+            deep_space_coverage: 0.85,
+            relay_coverage: 1.0,
+        }
+    }
+}
+
+// ==========================================
+// Launch sites
+// ==========================================
+
+/// What a launch site's latitude and range cost in plane changes,
+/// what moving the company's pad costs, and how its integration
+/// facility paces launches (see [`crate::launch_site::IntegrationMode`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchSitesConfig {
+    /// Delta-v per degree of dogleg steering on the way to orbit.
+    pub dogleg_dv_per_deg: f64,
+    /// Delta-v per degree of plane change at a transfer orbit's apogee.
+    pub apogee_plane_change_dv_per_deg: f64,
+    /// Cost of moving the company's pad to another site.
+    pub relocation_cost: f64,
+    /// Heaviest vehicle at liftoff each facility can handle.
+    pub vertical_max_liftoff_kg: f64,
+    pub horizontal_max_liftoff_kg: f64,
+    /// Days the pad is tied up readying a vehicle after a launch: a
+    /// base plus a share per stage.
+    pub vertical_pad_days_base: u32,
+    pub vertical_pad_days_per_stage: u32,
+    pub horizontal_pad_days_base: u32,
+    pub horizontal_pad_days_per_stage: u32,
+    /// Pad crew cost per day of pad flow.
+    pub pad_crew_cost_per_day: f64,
+    /// Cost of converting the pad to each mode, and how long the pad
+    /// is closed for it.
+    pub vertical_conversion_cost: f64,
+    pub horizontal_conversion_cost: f64,
+    pub conversion_days: u32,
+}
+
+impl Default for LaunchSitesConfig {
+    fn default() -> Self {
+        LaunchSitesConfig {
+            dogleg_dv_per_deg: 10.0,
+            apogee_plane_change_dv_per_deg: 12.0,
+            relocation_cost: 40_000_000.0,
+            vertical_max_liftoff_kg: 5_000_000.0,
+            horizontal_max_liftoff_kg: 600_000.0,
+            vertical_pad_days_base: 5,
+            vertical_pad_days_per_stage: 1,
+            horizontal_pad_days_base: 1,
+            horizontal_pad_days_per_stage: 2,
+            pad_crew_cost_per_day: 10_000.0,
+            vertical_conversion_cost: 60_000_000.0,
+            horizontal_conversion_cost: 15_000_000.0,
+            conversion_days: 120,
+        }
+    }
+}
+
+// ==========================================
+// Rendezvous and docking
+// ==========================================
+
+/// The risk of the approach a servicing kit or berthing plant flies on
+/// arrival.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DockingConfig {
+    /// Chance an approach goes wrong on flight software with no
+    /// maturity at all.
+    pub base_failure_chance: f64,
+    /// Share of that risk fully mature software removes.
+    pub maturity_mitigation: f64,
+    /// Added chance per hidden avionics or guidance flaw aboard.
+    pub hidden_flaw_risk: f64,
+    /// Share of failed approaches that end in a collision rather than
+    /// an abort.
+    pub collision_share: f64,
+    /// Health a collision knocks off the target.
+    pub collision_damage: f64,
+}
+
+impl Default for DockingConfig {
+    fn default() -> Self {
+        DockingConfig {
+            base_failure_chance: 0.12,
+            maturity_mitigation: 0.75,
+            hidden_flaw_risk: 0.04,
+            collision_share: 0.3,
+            collision_damage: 0.3,
+        }
+    }
+}
+
+// ==========================================
+// Propellant loading
+// ==========================================
+
+/// How far a rocket's tanks can be short-filled for a light payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PropellantLoadingConfig {
+    /// Least share of its liquid tanks a rocket can launch with: below
+    /// it the engines can't settle or feed their propellant.
+    pub min_load_fraction: f64,
+}
+
+impl Default for PropellantLoadingConfig {
+    fn default() -> Self {
+        PropellantLoadingConfig {
+            min_load_fraction: 0.3,
+        }
+    }
+}
+
+// ==========================================
+// Orbit insertion
+// ==========================================
+
+/// How accurately a launch puts its payload where it was aimed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InsertionConfig {
+    /// Typical insertion error (m/s, one sigma) on flight software with
+    /// no maturity at all.
+    pub base_dispersion_m_s: f64,
+    /// Share of that error fully mature software removes.
+    pub maturity_mitigation: f64,
+    /// Added error (m/s) per hidden avionics or guidance flaw aboard.
+    pub hidden_flaw_dispersion_m_s: f64,
+}
+
+impl Default for InsertionConfig {
+    fn default() -> Self {
+        InsertionConfig {
+            base_dispersion_m_s: 40.0,
+            maturity_mitigation: 0.8,
+            hidden_flaw_dispersion_m_s: 8.0,
+        }
+    }
+}
+
+// ==========================================
+// World market
+// ==========================================
+
+/// How shared prices respond to every company's activity (see
+/// [`crate::market`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldMarketConfig {
+    /// Monthly activity across all companies at which prices sit at 1.0.
+    pub baseline_launches_per_month: f64,
+    pub baseline_propellant_kg_per_month: f64,
+    pub baseline_engines_per_month: f64,
+    /// Months activity is averaged over.
+    pub memory_months: f64,
+    /// How far launch prices fall per doubling of launch activity.
+    pub launch_price_elasticity: f64,
+    /// How far customer demand rises per doubling of launch activity.
+    pub demand_elasticity: f64,
+    /// How far propellant prices rise per doubling of propellant flown.
+    pub propellant_price_elasticity: f64,
+    /// How far vendor engine prices rise per doubling of purchases.
+    pub engine_price_elasticity: f64,
+    /// Bounds on every index.
+    pub min_index: f64,
+    pub max_index: f64,
+    /// Share of the gap to its settling price an index closes each day.
+    pub daily_price_adjustment: f64,
+    /// Months a new world's prices hold at baseline while activity
+    /// builds up, so the opening year's offering is the seed's alone.
+    pub settling_months: u32,
+}
+
+impl Default for WorldMarketConfig {
+    fn default() -> Self {
+        WorldMarketConfig {
+            baseline_launches_per_month: 1.0,
+            baseline_propellant_kg_per_month: 300_000.0,
+            baseline_engines_per_month: 1.0,
+            memory_months: 6.0,
+            launch_price_elasticity: 0.1,
+            demand_elasticity: 0.08,
+            propellant_price_elasticity: 0.15,
+            engine_price_elasticity: 0.1,
+            min_index: 0.75,
+            max_index: 1.3,
+            daily_price_adjustment: 0.03,
+            settling_months: 12,
+        }
+    }
+}
+
+// ==========================================
+// Pad failures
+// ==========================================
+
+/// The consequences of a heavy rocket destroying its pad (see
+/// [`crate::pad_failure`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PadFailureConfig {
+    /// Propellant loaded (kg) at which a failure on the pad takes the
+    /// pad with it.
+    pub heavy_propellant_kg: f64,
+    /// Rebuild order for a destroyed pad: cost and days closed.
+    pub rebuild_cost: f64,
+    pub rebuild_days: u32,
+    /// Days the regulator stands launches down for the investigation.
+    pub stand_down_days: u32,
+    /// Media sentiment and reputation the disaster costs outright.
+    pub crisis_sentiment_hit: f64,
+    pub crisis_reputation_penalty: f64,
+    /// Days the company has to answer the press.
+    pub response_days: u32,
+    /// A transparent briefing: cost, sentiment regained, reputation
+    /// regained.
+    pub transparent_cost: f64,
+    pub transparent_sentiment: f64,
+    pub transparent_reputation: f64,
+    /// Spin: cost, sentiment regained, and the chance it backfires for
+    /// `spin_backfire_sentiment` instead.
+    pub spin_cost: f64,
+    pub spin_sentiment: f64,
+    pub spin_backfire_chance: f64,
+    pub spin_backfire_sentiment: f64,
+    /// Sentiment lost when the deadline passes unanswered.
+    pub silence_sentiment: f64,
+    /// Monthly pad insurance premium, and the share of the rebuild a
+    /// claim pays.
+    pub insurance_premium_per_month: f64,
+    pub insurance_coverage: f64,
+}
+
+impl Default for PadFailureConfig {
+    fn default() -> Self {
+        PadFailureConfig {
+            heavy_propellant_kg: 300_000.0,
+            rebuild_cost: 25_000_000.0,
+            rebuild_days: 270,
+            stand_down_days: 120,
+            crisis_sentiment_hit: -0.3,
+            crisis_reputation_penalty: 20.0,
+            response_days: 14,
+            transparent_cost: 1_500_000.0,
+            transparent_sentiment: 0.2,
+            transparent_reputation: 10.0,
+            spin_cost: 400_000.0,
+            spin_sentiment: 0.25,
+            spin_backfire_chance: 0.4,
+            spin_backfire_sentiment: -0.15,
+            silence_sentiment: -0.1,
+            insurance_premium_per_month: 150_000.0,
+            insurance_coverage: 0.8,
+        }
+    }
+}
+
+// ==========================================
+// Booster reuse
+// ==========================================
+
+/// Landing, refurbishing and wearing out reusable first stages (see
+/// [`crate::booster_reuse`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoosterReuseConfig {
+    /// Cost of a landing attempt (recovery crew, range), charged per
+    /// booster whether or not it comes home.
+    pub landing_cost: f64,
+    /// Chance a booster with a landing kit sticks its landing.
+    pub landing_success_chance: f64,
+    /// Refurbishing a landed booster, as a fraction of its share of the
+    /// rocket's build cost.
+    pub refurbish_cost_fraction: f64,
+    /// Early-life failure chance of a booster's first reflight, and the
+    /// factor it shrinks by with each refurbishment after that.
+    pub infant_mortality: f64,
+    pub infant_decay: f64,
+    /// Flights a booster of nominal wear is rated for before wear-out
+    /// sets in, and the failure chance each flight of wear past that
+    /// adds, squared.
+    pub design_life_flights: f64,
+    pub wearout_per_flight_sq: f64,
+    /// Range of the hidden wear rate a booster is drawn with on its
+    /// first landing: 1.0 wears out on schedule, higher wears faster.
+    pub wear_rate_min: f64,
+    pub wear_rate_max: f64,
+    /// Per-flight failure chance above which a booster should retire.
+    pub retirement_risk: f64,
+    /// Teardown inspection order: work and material cost (as a fraction
+    /// of the booster's build cost).
+    pub inspection_work: f64,
+    pub inspection_cost_fraction: f64,
+}
+
+impl Default for BoosterReuseConfig {
+    fn default() -> Self {
+        BoosterReuseConfig {
+            landing_cost: 300_000.0,
+            landing_success_chance: 0.85,
+            refurbish_cost_fraction: 0.15,
+            infant_mortality: 0.02,
+            infant_decay: 0.5,
+            design_life_flights: 10.0,
+            wearout_per_flight_sq: 0.002,
+            wear_rate_min: 0.6,
+            wear_rate_max: 1.6,
+            retirement_risk: 0.05,
+            inspection_work: 20.0,
+            inspection_cost_fraction: 0.05,
+        }
+    }
+}
+
+// ==========================================
+// Recovery fleet
+// ==========================================
+
+/// Landing zones and drone ships that boosters land on (see
+/// [`crate::recovery_fleet`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecoveryFleetConfig {
+    /// Purchase price and monthly upkeep of a landing zone by the pad.
+    pub landing_zone_cost: f64,
+    pub landing_zone_upkeep_per_month: f64,
+    /// Days a landing zone needs to clear a landed booster.
+    pub landing_zone_turnaround_days: u32,
+    /// Chance high winds close a landing zone on launch day.
+    pub landing_zone_weather_scrub: f64,
+    /// Purchase price and monthly upkeep of a drone ship.
+    pub drone_ship_cost: f64,
+    pub drone_ship_upkeep_per_month: f64,
+    /// Days a drone ship takes to sail out to the landing area.
+    pub drone_ship_transit_days: u32,
+    /// Days a drone ship takes to bring a booster home and offload it.
+    pub drone_ship_turnaround_days: u32,
+    /// Roughest sea state a drone ship can take a landing in, and the
+    /// landing chance lost per sea-state step up to it.
+    pub drone_ship_max_sea_state: u32,
+    pub drone_ship_sea_state_penalty: f64,
+}
+
+impl Default for RecoveryFleetConfig {
+    fn default() -> Self {
+        RecoveryFleetConfig {
+            landing_zone_cost: 15_000_000.0,
+            landing_zone_upkeep_per_month: 50_000.0,
+            landing_zone_turnaround_days: 5,
+            landing_zone_weather_scrub: 0.1,
+            drone_ship_cost: 35_000_000.0,
+            drone_ship_upkeep_per_month: 250_000.0,
+            drone_ship_transit_days: 4,
+            drone_ship_turnaround_days: 12,
+            drone_ship_max_sea_state: 4,
+            drone_ship_sea_state_penalty: 0.03,
+        }
+    }
+}
+
+// ==========================================
+// Customer payload readiness
+// ==========================================
+
+/// When customers' payloads are ready to fly and how they slip (see
+/// [`crate::payload_readiness`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PayloadReadinessConfig {
+    /// The promised ready day falls this share of the way from
+    /// signing to the deadline.
+    pub ready_fraction_min: f64,
+    pub ready_fraction_max: f64,
+    /// Daily chance a payload not yet shipped slips, and by how many days.
+    pub daily_slip_chance: f64,
+    pub slip_days_min: u32,
+    pub slip_days_max: u32,
+    /// What the customer pays per day of slip, capped at a share of
+    /// the contract's payment.
+    pub compensation_per_day: f64,
+    pub max_compensation_fraction: f64,
+    /// A slip that leaves less than this between the ready day and the
+    /// deadline gets the deadline pushed back to restore it.
+    pub renegotiation_margin_days: u32,
+}
+
+impl Default for PayloadReadinessConfig {
+    fn default() -> Self {
+        PayloadReadinessConfig {
+            ready_fraction_min: 0.1,
+            ready_fraction_max: 0.4,
+            daily_slip_chance: 0.004,
+            slip_days_min: 14,
+            slip_days_max: 90,
+            compensation_per_day: 20_000.0,
+            max_compensation_fraction: 0.1,
+            renegotiation_margin_days: 30,
+        }
+    }
+}
+
+// ==========================================
+// Challenge scoring
+// ==========================================
+
+/// Points for a challenge run (see [`crate::challenge`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeConfig {
+    /// For each objective met.
+    pub objective_points: f64,
+    /// For each day an objective was met ahead of its deadline.
+    pub points_per_day_early: f64,
+    /// For each day left before the day limit when the last objective
+    /// is met.
+    pub finish_points_per_day: f64,
+}
+
+impl Default for ChallengeConfig {
+    fn default() -> Self {
+        ChallengeConfig {
+            objective_points: 1000.0,
+            points_per_day_early: 10.0,
+            finish_points_per_day: 5.0,
+        }
+    }
+}
+
+// ==========================================
+// Sandbox
+// ==========================================
+
+/// Whether this game allows sandbox edits (see [`crate::sandbox`]).
+/// Off unless a balance file turns it on.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SandboxConfig {
+    pub enabled: bool,
+}
+
+// ==========================================
+// Advisor
+// ==========================================
+
+/// Thresholds the advisor's rules fire at (see [`crate::advisor`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdvisorConfig {
+    /// Liftoff thrust-to-weight a design is warned under.
+    pub min_liftoff_twr: f64,
+    /// Closed months averaged for the burn rate.
+    pub burn_months: usize,
+    /// Months of cash left that bring a warning.
+    pub runway_warning_months: f64,
+    /// Months of cash left that make it critical.
+    pub runway_critical_months: f64,
+    /// Days before an accepted contract is due that an empty inventory
+    /// is flagged.
+    pub deadline_warning_days: u32,
+}
+
+impl Default for AdvisorConfig {
+    fn default() -> Self {
+        AdvisorConfig {
+            min_liftoff_twr: 1.2,
+            burn_months: 3,
+            runway_warning_months: 3.0,
+            runway_critical_months: 1.0,
+            deadline_warning_days: 60,
+        }
+    }
+}
+
+// ==========================================
+// Cash-flow forecast
+// ==========================================
+
+/// How far the cash-flow forecast looks and how it weighs payments
+/// (see [`crate::forecast`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForecastConfig {
+    pub horizon_days: u32,
+    /// Chance a contract already in flight gets paid for.
+    pub in_transit_success: f64,
+}
+
+impl Default for ForecastConfig {
+    fn default() -> Self {
+        ForecastConfig {
+            horizon_days: 180,
+            in_transit_success: 0.95,
+        }
+    }
+}
+
+// ==========================================
+// Team allocation
+// ==========================================
+
+/// What a unit of engineering work is worth in each phase, for
+/// spreading teams over projects (see [`crate::allocation`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllocationConfig {
+    pub design_weight: f64,
+    /// Above design: a revising design can't be built until it's done.
+    pub revision_weight: f64,
+    /// Testing never finishes; it only turns up flaws.
+    pub testing_weight: f64,
+}
+
+impl Default for AllocationConfig {
+    fn default() -> Self {
+        AllocationConfig {
+            design_weight: 1.0,
+            revision_weight: 1.5,
+            testing_weight: 0.5,
+        }
+    }
+}
+
+// ==========================================
+// Depot design
+// ==========================================
+
+/// Player-designed propellant depots: what each spec costs in design
+/// work, mass and money (see [`crate::depot_project`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DepotDesignConfig {
+    /// Work units to design a depot with one docking port and no
+    /// insulation.
+    pub design_work: f64,
+    /// Extra design work per docking port past the first.
+    pub design_work_per_port: f64,
+    /// Extra design work at full insulation, pro rata below.
+    pub design_work_per_insulation: f64,
+    /// Mean flaw count for a new depot design.
+    pub flaw_count_mean: f64,
+    /// Extra mean flaws per docking port past the first.
+    pub flaws_per_port: f64,
+    /// Bus, avionics and structure, whatever the specs.
+    pub base_mass_kg: f64,
+    /// Tank mass per kg of capacity.
+    pub tank_mass_per_kg: f64,
+    /// Insulation mass per kg of capacity at full insulation.
+    pub insulation_mass_per_kg: f64,
+    /// Solar array mass per kW at 1 AU.
+    pub array_mass_per_kw: f64,
+    /// Cryocooler mass per kW (thermal) lifted.
+    pub cryocooler_mass_per_kw: f64,
+    pub port_mass_kg: f64,
+    pub base_cost: f64,
+    pub tank_cost_per_kg: f64,
+    /// Insulation cost per kg of capacity at full insulation.
+    pub insulation_cost_per_kg: f64,
+    pub array_cost_per_kw: f64,
+    pub cryocooler_cost_per_kw: f64,
+    pub port_cost: f64,
+    /// Largest share of the heat leak insulation can block.
+    pub max_insulation: f64,
+    pub max_docking_ports: u32,
+}
+
+impl Default for DepotDesignConfig {
+    fn default() -> Self {
+        DepotDesignConfig {
+            design_work: 60.0,
+            design_work_per_port: 10.0,
+            design_work_per_insulation: 30.0,
+            flaw_count_mean: 2.0,
+            flaws_per_port: 0.5,
+            // The stock depot (200 t, 60 kW, 0.2 kW cooling, two ports)
+            // comes to about 16 t and $48M.
+            base_mass_kg: 3_000.0,
+            tank_mass_per_kg: 0.06,
+            insulation_mass_per_kg: 0.02,
+            array_mass_per_kw: 10.0,
+            cryocooler_mass_per_kw: 500.0,
+            port_mass_kg: 400.0,
+            base_cost: 20_000_000.0,
+            tank_cost_per_kg: 100.0,
+            insulation_cost_per_kg: 150.0,
+            array_cost_per_kw: 50_000.0,
+            cryocooler_cost_per_kw: 5_000_000.0,
+            port_cost: 2_000_000.0,
+            max_insulation: 0.9,
+            max_docking_ports: 6,
+        }
+    }
+}
+
+// ==========================================
+// Combustion instability
+// ==========================================
+
+/// What scaling an engine far past its baseline costs. Chambers don't
+/// scale cleanly: past `onset_scale` the risk grows with the square of
+/// the excess, adding design work, instability flaws and a chance per
+/// testing cycle that the design turns out unworkable as drawn.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CombustionInstabilityConfig {
+    /// Scale an engine can reach before instability sets in.
+    pub onset_scale: f64,
+    /// Extra design work per unit of risk, as a fraction of the
+    /// engine's normal design work.
+    pub work_per_risk: f64,
+    /// Mean instability flaws per unit of risk, on top of the engine's
+    /// usual roll.
+    pub flaws_per_risk: f64,
+    /// Chance per testing cycle and unit of risk of a fundamental
+    /// issue: testing stalls until the engine is scaled back to
+    /// `onset_scale` or changes cycle.
+    pub fundamental_issue_chance_per_risk: f64,
+}
+
+impl CombustionInstabilityConfig {
+    /// Instability risk at `scale`: the squared excess over the onset,
+    /// 0 at or below it.
+    pub fn risk(&self, scale: f64) -> f64 {
+        (scale - self.onset_scale).max(0.0).powi(2)
+    }
+}
+
+impl Default for CombustionInstabilityConfig {
+    fn default() -> Self {
+        // At the 4× maximum: twice the design work, four extra flaws
+        // on average, and a 20% chance per testing cycle.
+        CombustionInstabilityConfig {
+            onset_scale: 2.0,
+            work_per_risk: 0.25,
+            flaws_per_risk: 1.0,
+            fundamental_issue_chance_per_risk: 0.05,
+        }
+    }
+}
+
+// ==========================================
+// Manufacturing commonality
+// ==========================================
+
+/// What a stage shared across rocket designs saves on the line. A
+/// stage family is every stage with the same engine lineage and tank
+/// diameter; once it spans `min_designs` designs its stages build for
+/// less and learn from each other's builds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommonalityConfig {
+    /// Distinct designs a family needs before the line treats it as one.
+    pub min_designs: usize,
+    /// Fraction off a family stage's build work.
+    pub work_discount: f64,
+    /// Fraction off a family stage's tank and assembly material.
+    pub material_discount: f64,
+}
+
+impl Default for CommonalityConfig {
+    fn default() -> Self {
+        CommonalityConfig {
+            min_designs: 2,
+            work_discount: 0.15,
+            material_discount: 0.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_round_trips_through_toml() {
+        let config = BalanceConfig::default();
+        let text = config.to_toml_string().expect("serialize");
+        let parsed: BalanceConfig = toml::from_str(&text).expect("parse");
+        assert_eq!(config, parsed);
+    }
+
+    #[test]
+    fn test_load_layered_no_files_is_default() {
+        let config = BalanceConfig::load_layered::<&str>(&[]).expect("load");
+        assert_eq!(config, BalanceConfig::default());
+    }
+
+    #[test]
+    fn test_partial_toml_only_overrides_named_fields() {
+        let mut merged = toml::Value::try_from(BalanceConfig::default()).unwrap();
+        let overlay: toml::Value =
+            "[costs]\nstarting_money = 50000000.0\n".parse().unwrap();
+        deep_merge(&mut merged, overlay);
+        let config: BalanceConfig = merged.try_into().unwrap();
+        assert_eq!(config.costs.starting_money, 50_000_000.0);
+        // Everything else untouched
+        let default = BalanceConfig::default();
+        assert_eq!(config.costs.engineering_monthly_salary,
+            default.costs.engineering_monthly_salary);
+        assert_eq!(config.work, default.work);
+        assert_eq!(config.markets, default.markets);
+    }
+
+    #[test]
+    fn test_layered_files_later_wins() {
+        let dir = std::env::temp_dir();
+        let base = dir.join("rt_balance_test_base.toml");
+        let over = dir.join("rt_balance_test_over.toml");
+        std::fs::write(&base,
+            "[work]\nengine_design_base_days = 100.0\nrocket_design_base_days = 50.0\n").unwrap();
+        std::fs::write(&over, "[work]\nengine_design_base_days = 80.0\n").unwrap();
+        let config = BalanceConfig::load_layered(&[&base, &over]).expect("load");
+        std::fs::remove_file(&base).ok();
+        std::fs::remove_file(&over).ok();
+        // Later file wins where both set a value
+        assert_eq!(config.work.engine_design_base_days, 80.0);
+        // Earlier file's other override survives
+        assert_eq!(config.work.rocket_design_base_days, 50.0);
+        // Untouched fields keep defaults
+        assert_eq!(config.work.testing_cycle_work, 30.0);
+    }
+
+    #[test]
+    fn test_game_balance_layers_extra_files() {
+        let dir = std::env::temp_dir();
+        let game = dir.join("rt_balance_test_game.toml");
+        let extra = dir.join("rt_balance_test_game_extra.toml");
+        std::fs::write(&game,
+            "[costs]\nengineering_hiring_cost = 123.0\nmanufacturing_hiring_cost = 45.0\n").unwrap();
+        std::fs::write(&extra, "[costs]\nengineering_hiring_cost = 456.0\n").unwrap();
+        let config = BalanceConfig::load_over_game_file(&game, &[&extra]).expect("load");
+        let missing = BalanceConfig::load_over_game_file(&dir.join("rt_balance_test_missing.toml"), &[&extra])
+            .expect("a missing game file is skipped");
+        std::fs::remove_file(&game).ok();
+        std::fs::remove_file(&extra).ok();
+        // Extra files win over the game file, which wins over defaults.
+        assert_eq!(config.costs.engineering_hiring_cost, 456.0);
+        assert_eq!(config.costs.manufacturing_hiring_cost, 45.0);
+        assert_eq!(config.work, BalanceConfig::default().work);
+        assert_eq!(missing.costs.engineering_hiring_cost, 456.0);
+        assert_eq!(missing.costs.manufacturing_hiring_cost, BalanceConfig::default().costs.manufacturing_hiring_cost);
     }
 
     #[test]
@@ -0,0 +1,115 @@
+//! Batched player actions. A frontend can string several commands
+//! into one click — revise a design, order a build of it, put three
+//! teams on it — and hand them over as a list. `GameState::run_batch`
+//! runs them in order and either all of them happen or none do: the
+//! first one refused undoes the ones before it. Actions are plain data
+//! and (de)serialize as `{"action": "order_rocket_build", "rocket": 3}`
+//! so a list can come straight from a script or another process.
+//!
+//! Projects are named by id rather than list position, so an action
+//! still finds its project after an earlier one in the batch adds to
+//! the list.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine_project::EngineProjectId;
+use crate::rocket_project::RocketProjectId;
+
+/// One player command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    /// Cut a revision fixing the engine's known flaws.
+    ReviseEngine { engine: EngineProjectId },
+    /// Cut a revision fixing the rocket's known flaws.
+    ReviseRocket { rocket: RocketProjectId },
+    /// Order `count` engines built.
+    OrderEngines { engine: EngineProjectId, count: u32 },
+    /// Order a rocket built; its engines and stages are ordered with it.
+    OrderRocket { rocket: RocketProjectId },
+    /// Keep `target` rockets of the design in stock (0 stops).
+    SetAutoBuild { rocket: RocketProjectId, target: u32 },
+    HireTeam,
+    HireManufacturingTeam,
+    /// Put `teams` idle engineering teams on an engine design.
+    AssignEngineTeams { engine: EngineProjectId, teams: u32 },
+    /// Put `teams` idle engineering teams on a rocket design.
+    AssignRocketTeams { rocket: RocketProjectId, teams: u32 },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::ReviseEngine { .. } => write!(f, "revise engine"),
+            Action::ReviseRocket { .. } => write!(f, "revise rocket"),
+            Action::OrderEngines { count, .. } => write!(f, "order {} engines", count),
+            Action::OrderRocket { .. } => write!(f, "order rocket"),
+            Action::SetAutoBuild { target, .. } => write!(f, "keep {} in stock", target),
+            Action::HireTeam => write!(f, "hire team"),
+            Action::HireManufacturingTeam => write!(f, "hire manufacturing team"),
+            Action::AssignEngineTeams { teams, .. } | Action::AssignRocketTeams { teams, .. } =>
+                write!(f, "assign {} teams", teams),
+        }
+    }
+}
+
+/// Why an action was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionError {
+    NoSuchProject,
+    /// No flaws found to fix, or the design isn't in testing.
+    NothingToRevise,
+    /// Builds need a design in testing and room in the budget.
+    OrderRefused,
+    /// The department's budget won't cover the hire.
+    OverBudget,
+    NoIdleTeam,
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionError::NoSuchProject => write!(f, "no such design"),
+            ActionError::NothingToRevise => write!(f, "there is nothing to revise"),
+            ActionError::OrderRefused => write!(f, "the design must be in testing and the build within budget"),
+            ActionError::OverBudget => write!(f, "the hire would break the budget"),
+            ActionError::NoIdleTeam => write!(f, "no engineering team is idle"),
+        }
+    }
+}
+
+/// A batch that was rolled back: the action at `step` was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchError {
+    pub step: usize,
+    pub action: Action,
+    pub error: ActionError,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "step {} ({}) failed: {}; nothing was done", self.step + 1, self.action, self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actions_read_from_json() {
+        let json = r#"[
+            {"action": "revise_rocket", "rocket": 2},
+            {"action": "order_rocket", "rocket": 2},
+            {"action": "hire_team"},
+            {"action": "assign_rocket_teams", "rocket": 2, "teams": 3}
+        ]"#;
+        let actions: Vec<Action> = serde_json::from_str(json).unwrap();
+        assert_eq!(actions[0], Action::ReviseRocket { rocket: RocketProjectId(2) });
+        assert_eq!(actions[2], Action::HireTeam);
+        assert_eq!(actions[3], Action::AssignRocketTeams { rocket: RocketProjectId(2), teams: 3 });
+        assert_eq!(serde_json::to_value(&actions[1]).unwrap(), serde_json::json!({"action": "order_rocket", "rocket": 2}));
+    }
+}
@@ -0,0 +1,135 @@
+//! Sealed-bid award resolution. On a solicitation's decision day every
+//! bid on the table is scored on price against the customer's hidden
+//! ceiling, the bidder's reputation, and its launch track record; the
+//! best score wins. Writing a proposal takes engineering time, and a
+//! losing bidder eats it.
+
+use crate::balance_config::BiddingConfig;
+use crate::contract::{self, Market};
+use crate::launch::{LaunchOutcome, LaunchRecord};
+
+/// Who placed a bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bidder {
+    Player,
+    /// Index into the game's competitors.
+    Competitor(usize),
+}
+
+/// One sealed bid, with what the customer knows about the bidder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SealedBid {
+    pub bidder: Bidder,
+    pub amount: f64,
+    pub reputation: f64,
+    /// Smoothed launch success rate (see [`track_record`]).
+    pub track_record: f64,
+}
+
+/// The winning bid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Award {
+    pub bidder: Bidder,
+    pub amount: f64,
+}
+
+/// A company's launch success rate with one success and one failure
+/// assumed up front, so a newcomer scores 0.5 and one flight doesn't
+/// swing it to either extreme.
+pub fn track_record(history: &[LaunchRecord]) -> f64 {
+    let successes = history.iter()
+        .filter(|r| matches!(r.outcome, LaunchOutcome::Success))
+        .count();
+    (successes as f64 + 1.0) / (history.len() as f64 + 2.0)
+}
+
+/// Score a bid: the market's price and reputation terms plus the
+/// track-record term. Higher wins.
+pub fn score(
+    bid: &SealedBid,
+    budget_ceiling: f64,
+    market: &Market,
+    rep_scale: f64,
+    cfg: &BiddingConfig,
+) -> f64 {
+    contract::bid_score(bid.amount, budget_ceiling, bid.reputation, market, rep_scale)
+        + cfg.record_weight * bid.track_record
+}
+
+/// Decide a solicitation. Bids over the ceiling never win; among the
+/// rest the best score takes it, ties going to the earlier bid (the
+/// player is entered first). None if no bid fits the budget.
+pub fn resolve(
+    bids: &[SealedBid],
+    budget_ceiling: f64,
+    market: Option<&Market>,
+    rep_scale: f64,
+    cfg: &BiddingConfig,
+) -> Option<Award> {
+    let mut best: Option<(f64, Award)> = None;
+    for bid in bids.iter().filter(|b| b.amount <= budget_ceiling) {
+        let s = market.map_or(0.0, |m| score(bid, budget_ceiling, m, rep_scale, cfg));
+        if best.as_ref().is_none_or(|(top, _)| s > *top) {
+            best = Some((s, Award { bidder: bid.bidder, amount: bid.amount }));
+        }
+    }
+    best.map(|(_, award)| award)
+}
+
+/// Engineering cost of writing one proposal.
+pub fn proposal_cost(cfg: &BiddingConfig, engineering_monthly_salary: f64) -> f64 {
+    cfg.proposal_team_days * engineering_monthly_salary / 30.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::GameDate;
+
+    fn market() -> Market {
+        crate::contract::initial_markets().remove(0)
+    }
+
+    fn bid(bidder: Bidder, amount: f64, track_record: f64) -> SealedBid {
+        SealedBid { bidder, amount, reputation: 0.0, track_record }
+    }
+
+    #[test]
+    fn test_price_record_and_ceiling_decide_the_award() {
+        let cfg = BiddingConfig::default();
+        let m = market();
+        let award = |bids: &[SealedBid]| resolve(bids, 100.0, Some(&m), 1.0, &cfg);
+
+        // Equal records: the cheaper bid wins; a tie goes to the player.
+        let cheap = award(&[bid(Bidder::Player, 90.0, 0.5), bid(Bidder::Competitor(0), 80.0, 0.5)]);
+        assert_eq!(cheap.unwrap().bidder, Bidder::Competitor(0));
+        let tie = award(&[bid(Bidder::Player, 90.0, 0.5), bid(Bidder::Competitor(0), 90.0, 0.5)]);
+        assert_eq!(tie.unwrap().bidder, Bidder::Player);
+
+        // A proven flyer beats a slightly cheaper newcomer.
+        let proven = award(&[bid(Bidder::Player, 90.0, 0.95), bid(Bidder::Competitor(0), 89.0, 0.5)]);
+        assert_eq!(proven.unwrap().bidder, Bidder::Player);
+
+        // Over the ceiling never wins, even alone.
+        assert!(award(&[bid(Bidder::Player, 101.0, 1.0)]).is_none());
+    }
+
+    #[test]
+    fn test_track_record_is_smoothed() {
+        assert_eq!(track_record(&[]), 0.5);
+        let flight = |outcome| LaunchRecord {
+            launch_date: GameDate { year: 2001, month: 1, day: 1 },
+            rocket_name: "R".into(),
+            contract_id: None,
+            destination: "leo".into(),
+            payload_kg: 0.0,
+            outcome,
+            flaws_activated: Vec::new(),
+            transit_anomalies: Vec::new(),
+            stage_disposals: Vec::new(),
+        };
+        let history = vec![flight(LaunchOutcome::Success), flight(LaunchOutcome::Success),
+            flight(LaunchOutcome::Failure { reason: String::new() })];
+        assert_eq!(track_record(&history), 0.6);
+    }
+}
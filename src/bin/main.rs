@@ -1,4 +1,5 @@
 use std::io;
+use std::path::Path;
 
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use crossterm::execute;
@@ -8,7 +9,12 @@ use crossterm::terminal::{
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
+use rocket_tycoon::balance_config::{BalanceConfig, EraConfig};
+use rocket_tycoon::content;
+use rocket_tycoon::challenge::{self, Challenge};
+use rocket_tycoon::era;
 use rocket_tycoon::game_state::GameState;
+use rocket_tycoon::map_variant;
 use rocket_tycoon::save;
 use rocket_tycoon::ui::App;
 
@@ -18,29 +24,124 @@ enum StartupState {
 }
 
 fn main() -> io::Result<()> {
-    let game = if std::env::args().len() >= 2 {
-        let args: Vec<String> = std::env::args().collect();
-        let name = args[1].clone();
+    // `--balance FILE` (repeatable) layers balance overrides on top of
+    // `balance.toml`; `--era KEY` picks the start era; `--random-map`
+    // rolls a procedural map for a new world; `--challenge KEY` plays a
+    // new world as a challenge; `--no-tutorial` starts a new world
+    // without the tutorial; the rest are the positional name and seed.
+    let mut args: Vec<String> = Vec::new();
+    let mut balance_files: Vec<String> = Vec::new();
+    // No `--era` keeps whatever era the balance files set.
+    let presets = era::presets();
+    let mut era_choice: Option<usize> = None;
+    let mut random_map = false;
+    let mut challenge_choice: Option<Challenge> = None;
+    let mut tutorial = true;
+    let mut raw = std::env::args().skip(1);
+    while let Some(arg) = raw.next() {
+        if arg == "--balance" {
+            match raw.next() {
+                Some(path) => balance_files.push(path),
+                None => {
+                    eprintln!("error: --balance needs a file");
+                    std::process::exit(2);
+                }
+            }
+        } else if arg == "--random-map" {
+            random_map = true;
+        } else if arg == "--no-tutorial" {
+            tutorial = false;
+        } else if arg == "--era" {
+            let key = raw.next().unwrap_or_default();
+            match presets.iter().position(|e| e.key == key) {
+                Some(i) => era_choice = Some(i),
+                None => {
+                    let keys: Vec<&str> = presets.iter().map(|e| e.key.as_str()).collect();
+                    eprintln!("error: --era needs one of {}", keys.join(", "));
+                    std::process::exit(2);
+                }
+            }
+        } else if arg == "--challenge" {
+            let key = raw.next().unwrap_or_default();
+            match challenge::preset(&key) {
+                Some(c) => challenge_choice = Some(c),
+                None => {
+                    let keys: Vec<String> = challenge::presets().into_iter().map(|c| c.key).collect();
+                    eprintln!("error: --challenge needs one of {}", keys.join(", "));
+                    std::process::exit(2);
+                }
+            }
+        } else {
+            args.push(arg);
+        }
+    }
+    let mut balance = match BalanceConfig::load_for_game(&balance_files) {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    };
+    // Content packs in `content/` degrade gracefully: a bad file is
+    // reported and skipped, never fatal.
+    let (content, warnings) = content::load_dir(Path::new(content::CONTENT_DIR), &balance);
+    for w in &warnings {
+        eprintln!("warning: content {w}");
+    }
+    content.apply_to_balance(&mut balance);
+    if let Err(e) = content::install(content) {
+        eprintln!("warning: content {e}");
+    }
+    balance.map_variant.enabled |= random_map;
+
+    let game = if let Some(name) = args.first() {
         let seed = args
-            .get(2)
+            .get(1)
             .and_then(|s| s.parse::<u64>().ok())
             .unwrap_or_else(rand::random);
-        GameState::with_balance(name, seed, rocket_tycoon::balance_config::BalanceConfig::default())
+        if let Some(i) = era_choice {
+            era::apply(presets[i].clone(), &mut balance);
+        }
+        if let Err(e) = map_variant::prepare_new_world(seed, &balance.map_variant) {
+            eprintln!("warning: map variant {e}; using the standard map");
+            balance.map_variant.enabled = false;
+        }
+        let mut game = GameState::with_balance(name.clone(), seed, balance);
+        if let Some(c) = challenge_choice {
+            game.start_challenge(c);
+        }
+        if tutorial {
+            game.start_tutorial();
+        }
+        game
     } else {
-        run_startup_screen()?
+        run_startup_screen(balance, era_choice, challenge_choice, tutorial)?
     };
+    // A loaded world brings its own map variant.
+    if let Some(variant) = &game.map_variant {
+        if map_variant::installed() != Some(variant) {
+            if let Err(e) = map_variant::install(variant.clone()) {
+                eprintln!("warning: map variant {e}");
+            }
+        }
+    }
     let mut app = App::new(game);
     app.run()
 }
 
-fn run_startup_screen() -> io::Result<GameState> {
+fn run_startup_screen(
+    balance: BalanceConfig,
+    era_choice: Option<usize>,
+    challenge_choice: Option<Challenge>,
+    tutorial: bool,
+) -> io::Result<GameState> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = startup_loop(&mut terminal);
+    let result = startup_loop(&mut terminal, balance, era_choice, challenge_choice, tutorial);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -49,7 +150,15 @@ fn run_startup_screen() -> io::Result<GameState> {
     result
 }
 
-fn startup_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<GameState> {
+fn startup_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    mut balance: BalanceConfig,
+    mut era_choice: Option<usize>,
+    mut challenge_choice: Option<Challenge>,
+    mut tutorial: bool,
+) -> io::Result<GameState> {
+    let presets = era::presets();
+    let challenges = challenge::presets();
     let mut state = StartupState::Menu;
     let mut selected: usize = 0;
     let mut saves = save::list_saves();
@@ -60,7 +169,10 @@ fn startup_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Re
 
         terminal.draw(|frame| match &state {
             StartupState::Menu => draw_menu(frame, &saves, selected),
-            StartupState::NameInput => draw_name_input(frame, &company_name),
+            StartupState::NameInput => draw_name_input(
+                frame, &company_name, era_choice.map_or(&balance.era, |i| &presets[i]),
+                balance.map_variant.enabled, challenge_choice.as_ref(), tutorial,
+            ),
         })?;
 
         if let Event::Key(key) = event::read()? {
@@ -104,7 +216,38 @@ fn startup_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Re
                             company_name.trim().to_string()
                         };
                         let seed: u64 = rand::random();
-                        return Ok(GameState::with_balance(name, seed, rocket_tycoon::balance_config::BalanceConfig::default()));
+                        if let Some(i) = era_choice {
+                            era::apply(presets[i].clone(), &mut balance);
+                        }
+                        if map_variant::prepare_new_world(seed, &balance.map_variant).is_err() {
+                            balance.map_variant.enabled = false;
+                        }
+                        let mut game = GameState::with_balance(name, seed, balance);
+                        if let Some(c) = challenge_choice {
+                            game.start_challenge(c);
+                        }
+                        if tutorial {
+                            game.start_tutorial();
+                        }
+                        return Ok(game);
+                    }
+                    KeyCode::F(2) => {
+                        balance.map_variant.enabled = !balance.map_variant.enabled;
+                    }
+                    KeyCode::F(3) => {
+                        // Cycle through the challenges and back to a free game.
+                        let next = challenge_choice.as_ref()
+                            .and_then(|c| challenges.iter().position(|p| p.key == c.key))
+                            .map_or(0, |i| i + 1);
+                        challenge_choice = challenges.get(next).cloned();
+                    }
+                    KeyCode::F(4) => {
+                        tutorial = !tutorial;
+                    }
+                    KeyCode::Tab => {
+                        let current = era_choice
+                            .or_else(|| presets.iter().position(|e| e.key == balance.era.key));
+                        era_choice = Some(current.map_or(0, |i| (i + 1) % presets.len()));
                     }
                     KeyCode::Esc => {
                         state = StartupState::Menu;
@@ -192,11 +335,18 @@ fn draw_menu(frame: &mut Frame, saves: &[(String, std::path::PathBuf)], selected
     }
 }
 
-fn draw_name_input(frame: &mut Frame, name: &str) {
+fn draw_name_input(
+    frame: &mut Frame,
+    name: &str,
+    era: &EraConfig,
+    random_map: bool,
+    challenge: Option<&Challenge>,
+    tutorial: bool,
+) {
     let area = frame.area();
 
-    let content_width = 40u16;
-    let content_height = 5u16;
+    let content_width = 76u16;
+    let content_height = 9u16;
     let x = area.width.saturating_sub(content_width) / 2;
     let y = area.height.saturating_sub(content_height) / 3;
     let content_area = Rect::new(x, y, content_width.min(area.width), content_height);
@@ -205,6 +355,10 @@ fn draw_name_input(frame: &mut Frame, name: &str) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // label + input
+            Constraint::Length(1), // era
+            Constraint::Length(1), // map
+            Constraint::Length(1), // challenge
+            Constraint::Length(1), // tutorial
             Constraint::Length(1), // blank
             Constraint::Length(1), // hint
         ])
@@ -214,7 +368,28 @@ fn draw_name_input(frame: &mut Frame, name: &str) {
     let input = Paragraph::new(input_text).style(Style::default().fg(Color::White));
     frame.render_widget(input, chunks[0]);
 
-    let hint = Paragraph::new("[Enter] Start  [Esc] Back")
+    let era_line = Paragraph::new(format!("Era: {} ({})", era.name, era.start_year))
+        .style(Style::default().fg(Color::Cyan));
+    frame.render_widget(era_line, chunks[1]);
+
+    let map = if random_map { "Procedural" } else { "Standard" };
+    let map_line = Paragraph::new(format!("Map: {map}"))
+        .style(Style::default().fg(Color::Cyan));
+    frame.render_widget(map_line, chunks[2]);
+
+    let mode = match challenge {
+        Some(c) => format!("Challenge: {} ({} days)", c.name, c.day_limit),
+        None => "Challenge: none".to_string(),
+    };
+    let challenge_line = Paragraph::new(mode)
+        .style(Style::default().fg(Color::Cyan));
+    frame.render_widget(challenge_line, chunks[3]);
+
+    let tutorial_line = Paragraph::new(format!("Tutorial: {}", if tutorial { "on" } else { "off" }))
+        .style(Style::default().fg(Color::Cyan));
+    frame.render_widget(tutorial_line, chunks[4]);
+
+    let hint = Paragraph::new("[Enter] Start  [Tab] Era  [F2] Map  [F3] Challenge  [F4] Tutorial  [Esc] Back")
         .style(Style::default().fg(Color::DarkGray));
-    frame.render_widget(hint, chunks[2]);
+    frame.render_widget(hint, chunks[6]);
 }
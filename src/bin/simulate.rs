@@ -13,6 +13,8 @@ use std::path::PathBuf;
 use std::process::ExitCode;
 
 use rocket_tycoon::balance_config::BalanceConfig;
+use rocket_tycoon::era;
+use rocket_tycoon::map_variant;
 use rocket_tycoon::policy::{policy_by_name, POLICY_NAMES};
 use rocket_tycoon::sim::{run_seed, CSV_HEADER};
 
@@ -25,6 +27,8 @@ Options:
   --years Y           Years to simulate per seed (default: 5)
   --policy NAME       Company policy (default: none)
   --balance FILE      Balance TOML override; repeatable, merged in order
+  --era KEY           Start era (space_race, shuttle_era, commercial_dawn, new_space)
+  --random-map        Roll a procedural delta-v map from the seed (single seed only)
   --dump-balance      Print the effective balance TOML and exit
   --csv PATH          Write monthly metric rows to PATH as CSV
   --summary-only      Suppress monthly rows on stdout (summaries still print)
@@ -36,6 +40,8 @@ struct Args {
     years: u32,
     policy: String,
     balance_files: Vec<PathBuf>,
+    era: Option<String>,
+    random_map: bool,
     dump_balance: bool,
     csv: Option<PathBuf>,
     summary_only: bool,
@@ -47,6 +53,8 @@ fn parse_args() -> Result<Args, String> {
         years: 5,
         policy: "none".into(),
         balance_files: Vec::new(),
+        era: None,
+        random_map: false,
         dump_balance: false,
         csv: None,
         summary_only: false,
@@ -79,6 +87,8 @@ fn parse_args() -> Result<Args, String> {
             }
             "--policy" => args.policy = value("--policy")?,
             "--balance" => args.balance_files.push(PathBuf::from(value("--balance")?)),
+            "--era" => args.era = Some(value("--era")?),
+            "--random-map" => args.random_map = true,
             "--dump-balance" => args.dump_balance = true,
             "--csv" => args.csv = Some(PathBuf::from(value("--csv")?)),
             "--summary-only" => args.summary_only = true,
@@ -101,13 +111,22 @@ fn main() -> ExitCode {
         }
     };
 
-    let balance = match BalanceConfig::load_layered(&args.balance_files) {
+    let mut balance = match BalanceConfig::load_layered(&args.balance_files) {
         Ok(b) => b,
         Err(e) => {
             eprintln!("error: {e}");
             return ExitCode::FAILURE;
         }
     };
+    if let Some(key) = &args.era {
+        match era::preset(key) {
+            Some(e) => era::apply(e, &mut balance),
+            None => {
+                eprintln!("error: unknown era `{key}`");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
 
     if args.dump_balance {
         match balance.to_toml_string() {
@@ -122,6 +141,20 @@ fn main() -> ExitCode {
         }
     }
 
+    // The delta-v map is process-wide, so a procedural map can only be
+    // rolled for one world per run.
+    balance.map_variant.enabled |= args.random_map;
+    if balance.map_variant.enabled {
+        let [seed] = args.seeds[..] else {
+            eprintln!("error: a procedural map needs a single --seed");
+            return ExitCode::FAILURE;
+        };
+        if let Err(e) = map_variant::prepare_new_world(seed, &balance.map_variant) {
+            eprintln!("error: map variant {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
     // Validate the policy name up front; a FRESH policy instance is
     // created per seed (policies carry run state).
     if policy_by_name(&args.policy).is_none() {
@@ -0,0 +1,149 @@
+//! Reusable boosters. A first stage fitted with a landing kit can fly
+//! home after launch, be refurbished, and go back into stock for the
+//! next rocket of its design. Each landed booster carries a serial and
+//! its history: the failure chance of a reflight follows a bathtub
+//! curve — early reflights shake out latent damage, each refurbishment
+//! makes that less likely, and past its design life the wear adds up
+//! fast. How fast a given serial wears is hidden until a teardown
+//! inspection reveals it.
+
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::BoosterReuseConfig;
+use crate::calendar::GameDate;
+use crate::flaw::{Flaw, FlawConsequence, FlawId, FlawTrigger, Subsystem};
+
+/// The life of one landed booster.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoosterLife {
+    pub serial: u64,
+    /// Flights flown, including the first.
+    pub flights: u32,
+    /// Days it was refurbished: after each landing, and at each
+    /// teardown inspection.
+    pub refurbished_on: Vec<GameDate>,
+    /// How fast it wears relative to its design life. Hidden until
+    /// inspected.
+    pub wear_rate: f64,
+    pub wear_revealed: bool,
+}
+
+impl BoosterLife {
+    /// A booster back from its first flight, drawn with its wear rate.
+    pub fn first_landing(serial: u64, rng: &mut impl Rng, cfg: &BoosterReuseConfig) -> Self {
+        BoosterLife {
+            serial,
+            flights: 1,
+            refurbished_on: Vec::new(),
+            wear_rate: rng.gen_range(cfg.wear_rate_min..=cfg.wear_rate_max),
+            wear_revealed: false,
+        }
+    }
+
+    /// Failure chance of the next flight if the booster wears at `rate`.
+    fn failure_chance_at(&self, rate: f64, cfg: &BoosterReuseConfig) -> f64 {
+        let refurbishments = self.refurbished_on.len().saturating_sub(1) as i32;
+        let infant = cfg.infant_mortality * cfg.infant_decay.powi(refurbishments);
+        let worn = (self.flights as f64 * rate - cfg.design_life_flights).max(0.0);
+        (infant + cfg.wearout_per_flight_sq * worn * worn).min(1.0)
+    }
+
+    /// True failure chance of the next flight.
+    pub fn failure_chance(&self, cfg: &BoosterReuseConfig) -> f64 {
+        self.failure_chance_at(self.wear_rate, cfg)
+    }
+
+    /// Failure chance of the next flight as far as the company knows:
+    /// nominal wear until an inspection reveals the real rate.
+    pub fn estimated_failure_chance(&self, cfg: &BoosterReuseConfig) -> f64 {
+        let rate = if self.wear_revealed { self.wear_rate } else { 1.0 };
+        self.failure_chance_at(rate, cfg)
+    }
+
+    /// What to do with this booster before it flies again.
+    pub fn retirement_advice(&self, cfg: &BoosterReuseConfig) -> RetirementAdvice {
+        if self.estimated_failure_chance(cfg) >= cfg.retirement_risk {
+            RetirementAdvice::Retire
+        } else if !self.wear_revealed && self.failure_chance_at(cfg.wear_rate_max, cfg) >= cfg.retirement_risk {
+            RetirementAdvice::Inspect
+        } else {
+            RetirementAdvice::Fly
+        }
+    }
+}
+
+/// The retirement recommendation for a booster in stock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetirementAdvice {
+    Fly,
+    /// It may be worn past the retirement risk; only a teardown
+    /// inspection will tell.
+    Inspect,
+    Retire,
+}
+
+impl fmt::Display for RetirementAdvice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetirementAdvice::Fly => write!(f, "fit to fly"),
+            RetirementAdvice::Inspect => write!(f, "inspect"),
+            RetirementAdvice::Retire => write!(f, "retire"),
+        }
+    }
+}
+
+/// The pseudo-flaw a reflown booster carries into the launch risk model.
+pub fn wear_flaw(life: &BoosterLife, cfg: &BoosterReuseConfig) -> Flaw {
+    Flaw {
+        id: FlawId(u64::MAX - 2),
+        description: format!("Wear on reflown booster B{}", life.serial),
+        consequence: FlawConsequence::StageLoss,
+        activation_chance: life.failure_chance(cfg),
+        discovery_probability: 0.0,
+        discovered: true,
+        trigger: FlawTrigger::PerFlight,
+        subsystem: Subsystem::TankStructure,
+        anomaly: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn booster(flights: u32, refurbishments: usize, wear_rate: f64) -> BoosterLife {
+        BoosterLife {
+            serial: 1,
+            flights,
+            refurbished_on: vec![GameDate::new(2030, 1, 1); refurbishments],
+            wear_rate,
+            wear_revealed: false,
+        }
+    }
+
+    #[test]
+    fn test_failure_chance_follows_a_bathtub_curve() {
+        let cfg = BoosterReuseConfig::default();
+        let early = booster(1, 1, 1.0).failure_chance(&cfg);
+        let mid = booster(6, 6, 1.0).failure_chance(&cfg);
+        let late = booster(14, 14, 1.0).failure_chance(&cfg);
+        assert!(early > mid, "infant mortality fades: {early} vs {mid}");
+        assert!(late > mid, "wear-out sets in: {late} vs {mid}");
+        // A fast-wearing serial reaches the wear-out wall sooner.
+        assert!(booster(8, 8, 1.6).failure_chance(&cfg) > booster(8, 8, 1.0).failure_chance(&cfg));
+    }
+
+    #[test]
+    fn test_retirement_advice_asks_for_inspection_until_wear_is_known() {
+        let cfg = BoosterReuseConfig::default();
+        assert_eq!(booster(2, 2, 1.0).retirement_advice(&cfg), RetirementAdvice::Fly);
+        let mut worn = booster(10, 10, 1.6);
+        assert_eq!(worn.retirement_advice(&cfg), RetirementAdvice::Inspect);
+        worn.wear_revealed = true;
+        assert_eq!(worn.retirement_advice(&cfg), RetirementAdvice::Retire);
+        assert_eq!(booster(20, 20, 1.0).retirement_advice(&cfg), RetirementAdvice::Retire);
+    }
+}
@@ -0,0 +1,152 @@
+//! Department budgets. The player caps what each department may spend
+//! in a month; discretionary spending (hiring, build orders, floor
+//! space) that would break a cap is refused, while committed costs —
+//! salaries, storage, fees — are always paid and simply counted. Each
+//! month closes with actual vs budget on the books.
+
+use std::collections::VecDeque;
+
+use serde::{Serialize, Deserialize};
+
+/// Months of closed budgets kept for reporting.
+const HISTORY_MONTHS: usize = 12;
+
+/// A spending department.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Department {
+    /// Engineering hires and salaries.
+    Engineering,
+    /// Manufacturing hires, salaries and build orders.
+    Manufacturing,
+    /// Certification, launch licensing and recovery operations.
+    Testing,
+    /// Floor space, storage and security.
+    Facilities,
+}
+
+impl Department {
+    pub const ALL: [Department; 4] = [
+        Department::Engineering,
+        Department::Manufacturing,
+        Department::Testing,
+        Department::Facilities,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Department::Engineering => "Engineering",
+            Department::Manufacturing => "Manufacturing",
+            Department::Testing => "Testing",
+            Department::Facilities => "Facilities",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// One closed month of department spending.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetMonth {
+    pub year: u32,
+    pub month: u32,
+    pub limits: [Option<f64>; 4],
+    pub spent: [f64; 4],
+}
+
+impl BudgetMonth {
+    /// Departments that spent past their cap: (department, spent, budget).
+    pub fn overruns(&self) -> Vec<(Department, f64, f64)> {
+        Department::ALL.iter()
+            .filter_map(|&d| {
+                let limit = self.limits[d.index()]?;
+                let spent = self.spent[d.index()];
+                (spent > limit).then_some((d, spent, limit))
+            })
+            .collect()
+    }
+}
+
+/// Monthly caps and this month's spending per department. A department
+/// with no cap spends freely.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Budgets {
+    pub limits: [Option<f64>; 4],
+    /// Spent so far this month.
+    pub spent: [f64; 4],
+    /// Closed months, oldest first (rolling 12).
+    pub history: VecDeque<BudgetMonth>,
+}
+
+impl Budgets {
+    pub fn limit(&self, d: Department) -> Option<f64> {
+        self.limits[d.index()]
+    }
+
+    pub fn set_limit(&mut self, d: Department, limit: Option<f64>) {
+        self.limits[d.index()] = limit;
+    }
+
+    pub fn spent(&self, d: Department) -> f64 {
+        self.spent[d.index()]
+    }
+
+    /// True if `d` can spend `amount` more this month without
+    /// passing its cap.
+    pub fn allows(&self, d: Department, amount: f64) -> bool {
+        self.limit(d).is_none_or(|limit| self.spent(d) + amount <= limit)
+    }
+
+    /// Count `amount` against `d`'s month, cap or not.
+    pub fn record(&mut self, d: Department, amount: f64) {
+        self.spent[d.index()] += amount;
+    }
+
+    /// Close the month that just ended: file its spending against the
+    /// caps and start the next month from zero. Returns the closed month.
+    pub fn close_month(&mut self, year: u32, month: u32) -> BudgetMonth {
+        let closed = BudgetMonth { year, month, limits: self.limits, spent: self.spent };
+        self.spent = [0.0; 4];
+        self.history.push_back(closed.clone());
+        while self.history.len() > HISTORY_MONTHS {
+            self.history.pop_front();
+        }
+        closed
+    }
+
+    /// The most recently closed month, if any.
+    pub fn last_month(&self) -> Option<&BudgetMonth> {
+        self.history.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caps_block_and_close_reports_overruns() {
+        let mut b = Budgets::default();
+        assert!(b.allows(Department::Manufacturing, 1e12), "uncapped by default");
+
+        b.set_limit(Department::Manufacturing, Some(1_000.0));
+        b.record(Department::Manufacturing, 800.0);
+        assert!(b.allows(Department::Manufacturing, 200.0));
+        assert!(!b.allows(Department::Manufacturing, 201.0));
+
+        // Committed costs still count, past the cap.
+        b.record(Department::Manufacturing, 500.0);
+        b.record(Department::Engineering, 50.0);
+        let closed = b.close_month(2001, 3);
+        assert_eq!(closed.overruns(), vec![(Department::Manufacturing, 1_300.0, 1_000.0)]);
+        assert_eq!(b.spent(Department::Manufacturing), 0.0);
+        assert_eq!(b.last_month(), Some(&closed));
+
+        for m in 0..20 {
+            b.close_month(2002, m % 12 + 1);
+        }
+        assert_eq!(b.history.len(), HISTORY_MONTHS);
+    }
+}
@@ -78,6 +78,43 @@ impl GameDate {
     pub fn month_name(&self) -> &'static str {
         MONTH_NAMES[(self.month - 1) as usize]
     }
+
+    /// Fiscal quarter (1-4). The fiscal year is the calendar year.
+    pub fn quarter(&self) -> u32 {
+        (self.month - 1) / 3 + 1
+    }
+
+    /// True on the first day of a quarter.
+    pub fn is_first_of_quarter(&self) -> bool {
+        self.day == 1 && matches!(self.month, 1 | 4 | 7 | 10)
+    }
+
+    /// The quarter before this date's, as (year, quarter).
+    pub fn previous_quarter(&self) -> (u32, u32) {
+        match self.quarter() {
+            1 => (self.year - 1, 4),
+            q => (self.year, q - 1),
+        }
+    }
+
+    /// The month before this date's, as (year, month).
+    pub fn previous_month(&self) -> (u32, u32) {
+        match self.month {
+            1 => (self.year - 1, 12),
+            m => (self.year, m - 1),
+        }
+    }
+}
+
+/// The (year, month) pairs of a fiscal quarter, oldest first.
+pub fn quarter_months(year: u32, quarter: u32) -> [(u32, u32); 3] {
+    let first = (quarter - 1) * 3 + 1;
+    [(year, first), (year, first + 1), (year, first + 2)]
+}
+
+/// Display label for a quarter, e.g. "Q3 2004".
+pub fn quarter_label(year: u32, quarter: u32) -> String {
+    format!("Q{} {}", quarter, year)
 }
 
 const MONTH_NAMES: [&str; 12] = [
@@ -127,6 +164,21 @@ mod tests {
         assert_eq!(d.day, 1);
     }
 
+    #[test]
+    fn test_quarters() {
+        let d = GameDate::new(2004, 1, 1);
+        assert_eq!(d.quarter(), 1);
+        assert!(d.is_first_of_quarter());
+        assert_eq!(d.previous_quarter(), (2003, 4));
+        assert_eq!(quarter_months(2003, 4), [(2003, 10), (2003, 11), (2003, 12)]);
+
+        let d = GameDate::new(2004, 9, 30);
+        assert_eq!(d.quarter(), 3);
+        assert!(!d.is_first_of_quarter());
+        assert!(d.next_day().is_first_of_quarter());
+        assert_eq!(quarter_label(d.year, d.quarter()), "Q3 2004");
+    }
+
     #[test]
     fn test_next_day_normal() {
         let d = GameDate::new(2001, 1, 15);
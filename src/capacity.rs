@@ -0,0 +1,97 @@
+//! Production capacity planning. Given a design and a target cadence,
+//! work out what the factory needs to sustain it: manufacturing teams,
+//! floor space, engine production rates and how long one vehicle takes
+//! from order to rollout — and how far short of that the company is
+//! today. Work figures average the learning curve over a year at the
+//! target cadence, and assume one team per order, the most efficient
+//! way to staff the line.
+
+/// One engine type's production line at the target cadence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineLine {
+    pub engine_name: String,
+    pub per_month: f64,
+    /// Team-days of work per engine; zero for bought-in engines.
+    pub work_days_each: f64,
+    /// Delivered by a supplier rather than built in-house.
+    pub bought_in: bool,
+}
+
+/// What a cadence takes, against what the company has.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacityPlan {
+    pub rocket_name: String,
+    pub rockets_per_month: f64,
+    /// Team-days of manufacturing work in one vehicle.
+    pub work_per_rocket: f64,
+    pub teams_needed: u32,
+    pub teams_have: u32,
+    /// Floor space units kept busy by the line on average.
+    pub floor_space_needed: u32,
+    /// Built and under construction.
+    pub floor_space_have: u32,
+    pub engine_lines: Vec<EngineLine>,
+    /// Days from order to a finished vehicle on a line staffed one
+    /// team per order.
+    pub lead_time_days: f64,
+    /// Materials and bought-in engines per month.
+    pub material_cost_per_month: f64,
+    /// Hiring and floor space needed to close the gaps.
+    pub expansion_cost: f64,
+    /// Salary for the extra teams.
+    pub extra_monthly_salary: f64,
+}
+
+impl CapacityPlan {
+    pub fn team_gap(&self) -> u32 {
+        self.teams_needed.saturating_sub(self.teams_have)
+    }
+
+    pub fn floor_space_gap(&self) -> u32 {
+        self.floor_space_needed.saturating_sub(self.floor_space_have)
+    }
+
+    /// The company can sustain the cadence as it stands.
+    pub fn is_covered(&self) -> bool {
+        self.team_gap() == 0 && self.floor_space_gap() == 0
+    }
+}
+
+/// Mean of the learning-curve multiplier over `count` builds following
+/// `prior` earlier ones.
+pub fn mean_learning(prior: u32, count: u32, learning: impl Fn(u32) -> f64) -> f64 {
+    let count = count.max(1);
+    (prior..prior + count).map(learning).sum::<f64>() / count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_config::WorkConfig;
+
+    #[test]
+    fn test_learning_averages_down_over_a_run_and_gaps_saturate() {
+        let work = WorkConfig::default();
+        let first = mean_learning(0, 1, |n| work.learning_curve_multiplier(n));
+        let run = mean_learning(0, 12, |n| work.learning_curve_multiplier(n));
+        assert_eq!(first, 1.0);
+        assert!(run < first);
+
+        let plan = CapacityPlan {
+            rocket_name: "X".into(),
+            rockets_per_month: 1.0,
+            work_per_rocket: 100.0,
+            teams_needed: 4,
+            teams_have: 6,
+            floor_space_needed: 12,
+            floor_space_have: 10,
+            engine_lines: Vec::new(),
+            lead_time_days: 60.0,
+            material_cost_per_month: 0.0,
+            expansion_cost: 0.0,
+            extra_monthly_salary: 0.0,
+        };
+        assert_eq!((plan.team_gap(), plan.floor_space_gap()), (0, 2));
+        assert!(!plan.is_covered());
+    }
+}
@@ -0,0 +1,292 @@
+//! Challenge mode. A challenge is a scenario with a hard day limit and
+//! a scripted sequence of objectives, each with its own deadline:
+//! "reach orbit by day 300, then land a booster by day 600". Objectives
+//! are met in order; missing one's deadline, or the day limit, ends the
+//! run. Meeting them all wins, scored on how much time was left over.
+//!
+//! A challenge is plain data so scenarios can be written outside the
+//! code; `presets` holds the built-in ones. The run rides in the save
+//! and is checked at the end of every day by `GameState::tick_challenge`.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::ChallengeConfig;
+use crate::calendar::GameDate;
+
+/// Something the company has to have done.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Goal {
+    /// A fully successful launch to `destination`.
+    Reach { destination: String },
+    /// `count` fully successful launches.
+    SuccessfulLaunches { count: u32 },
+    /// `count` fully successful contract launches.
+    DeliverContracts { count: u32 },
+    /// `count` different boosters landed.
+    LandBoosters { count: u32 },
+    /// At least `amount` in the bank.
+    Cash { amount: f64 },
+    /// A constellation formed.
+    FormConstellation,
+}
+
+impl fmt::Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Goal::Reach { destination } => {
+                let name = crate::location::DELTA_V_MAP.location(destination)
+                    .map_or(destination.as_str(), |l| l.display_name);
+                write!(f, "Reach {}", name)
+            }
+            Goal::SuccessfulLaunches { count } => write!(f, "Fly {} successful launches", count),
+            Goal::DeliverContracts { count } => write!(f, "Deliver {} contracts", count),
+            Goal::LandBoosters { count: 1 } => write!(f, "Land a booster"),
+            Goal::LandBoosters { count } => write!(f, "Land {} boosters", count),
+            Goal::Cash { amount } => write!(f, "Hold {}", crate::resources::format_money(*amount)),
+            Goal::FormConstellation => write!(f, "Form a constellation"),
+        }
+    }
+}
+
+/// How far along the company is on each kind of goal; the game fills
+/// this in each day for `Goal::met` to check against.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Progress {
+    /// Destinations reached by fully successful launches.
+    pub reached: Vec<String>,
+    pub successful_launches: u32,
+    pub contracts_delivered: u32,
+    pub boosters_landed: u32,
+    pub money: f64,
+    pub constellations: u32,
+}
+
+impl Goal {
+    pub fn met(&self, p: &Progress) -> bool {
+        match self {
+            Goal::Reach { destination } => p.reached.contains(destination),
+            Goal::SuccessfulLaunches { count } => p.successful_launches >= *count,
+            Goal::DeliverContracts { count } => p.contracts_delivered >= *count,
+            Goal::LandBoosters { count } => p.boosters_landed >= *count,
+            Goal::Cash { amount } => p.money >= *amount,
+            Goal::FormConstellation => p.constellations > 0,
+        }
+    }
+}
+
+/// One step of a challenge: a goal to meet by day `by_day` of the run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Objective {
+    pub goal: Goal,
+    pub by_day: u32,
+}
+
+/// A challenge scenario.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Challenge {
+    pub key: String,
+    pub name: String,
+    pub description: String,
+    /// The run ends on this day whatever happens.
+    pub day_limit: u32,
+    /// Met in this order.
+    pub objectives: Vec<Objective>,
+}
+
+/// How a run ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChallengeOutcome {
+    Won { day: u32, score: u32 },
+    Failed { day: u32, reason: String },
+}
+
+/// What a day's check turned up.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChallengeUpdate {
+    ObjectiveMet { index: usize, goal: String, day: u32 },
+    Ended(ChallengeOutcome),
+}
+
+/// A challenge being played.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeRun {
+    pub challenge: Challenge,
+    pub started: GameDate,
+    /// Days played since the start.
+    pub day: u32,
+    /// Day each objective was met on, in order; the next objective is
+    /// `completed_on.len()`.
+    pub completed_on: Vec<u32>,
+    pub outcome: Option<ChallengeOutcome>,
+}
+
+impl ChallengeRun {
+    pub fn new(challenge: Challenge, started: GameDate) -> Self {
+        ChallengeRun { challenge, started, day: 0, completed_on: Vec::new(), outcome: None }
+    }
+
+    /// The objective being worked on, if the run is still going.
+    pub fn current(&self) -> Option<&Objective> {
+        if self.outcome.is_some() {
+            return None;
+        }
+        self.challenge.objectives.get(self.completed_on.len())
+    }
+
+    /// Count a day played and check objectives against `progress`.
+    /// Several objectives can be met on the same day. Does nothing once
+    /// the run has ended.
+    pub fn advance(&mut self, progress: &Progress, cfg: &ChallengeConfig) -> Vec<ChallengeUpdate> {
+        let mut updates = Vec::new();
+        if self.outcome.is_some() {
+            return updates;
+        }
+        self.day += 1;
+        while let Some(obj) = self.current() {
+            if !obj.goal.met(progress) {
+                break;
+            }
+            updates.push(ChallengeUpdate::ObjectiveMet {
+                index: self.completed_on.len(),
+                goal: obj.goal.to_string(),
+                day: self.day,
+            });
+            self.completed_on.push(self.day);
+        }
+        let outcome = match self.current() {
+            None => Some(ChallengeOutcome::Won { day: self.day, score: self.score(cfg) }),
+            Some(obj) if self.day >= obj.by_day => Some(ChallengeOutcome::Failed {
+                day: self.day,
+                reason: format!("{} not done by day {}", obj.goal, obj.by_day),
+            }),
+            Some(_) if self.day >= self.challenge.day_limit => Some(ChallengeOutcome::Failed {
+                day: self.day,
+                reason: format!("day limit of {} reached", self.challenge.day_limit),
+            }),
+            Some(_) => None,
+        };
+        if let Some(outcome) = outcome {
+            self.outcome = Some(outcome.clone());
+            updates.push(ChallengeUpdate::Ended(outcome));
+        }
+        updates
+    }
+
+    /// Points for the objectives met so far: a flat award for each plus
+    /// a bonus for every day it beat its deadline by, and once all are
+    /// met a bonus for every day left before the limit.
+    pub fn score(&self, cfg: &ChallengeConfig) -> u32 {
+        let mut points = 0.0;
+        for (obj, &day) in self.challenge.objectives.iter().zip(&self.completed_on) {
+            points += cfg.objective_points + cfg.points_per_day_early * obj.by_day.saturating_sub(day) as f64;
+        }
+        if self.completed_on.len() == self.challenge.objectives.len() {
+            let last = self.completed_on.last().copied().unwrap_or(0);
+            points += cfg.finish_points_per_day * self.challenge.day_limit.saturating_sub(last) as f64;
+        }
+        points.round() as u32
+    }
+}
+
+impl fmt::Display for ChallengeRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.outcome, self.current()) {
+            (Some(ChallengeOutcome::Won { day, score }), _) =>
+                write!(f, "{}: won on day {} — {} points", self.challenge.name, day, score),
+            (Some(ChallengeOutcome::Failed { reason, .. }), _) =>
+                write!(f, "{}: failed — {}", self.challenge.name, reason),
+            (None, Some(obj)) => write!(f, "{}: day {}/{} — {} by day {} ({}/{})",
+                self.challenge.name, self.day, self.challenge.day_limit, obj.goal, obj.by_day,
+                self.completed_on.len() + 1, self.challenge.objectives.len()),
+            (None, None) => write!(f, "{}", self.challenge.name),
+        }
+    }
+}
+
+/// The built-in challenges.
+pub fn presets() -> Vec<Challenge> {
+    let obj = |goal, by_day| Objective { goal, by_day };
+    let reach = |d: &str| Goal::Reach { destination: d.into() };
+    vec![
+        Challenge {
+            key: "orbit_and_back".into(),
+            name: "Orbit and Back".into(),
+            description: "Reach orbit, then bring a booster home.".into(),
+            day_limit: 900,
+            objectives: vec![
+                obj(reach("leo"), 300),
+                obj(Goal::LandBoosters { count: 1 }, 600),
+            ],
+        },
+        Challenge {
+            key: "launch_business".into(),
+            name: "Launch Business".into(),
+            description: "Turn a rocket into a business: fly for customers and bank the profits.".into(),
+            day_limit: 1825,
+            objectives: vec![
+                obj(Goal::DeliverContracts { count: 1 }, 730),
+                obj(Goal::DeliverContracts { count: 5 }, 1460),
+                obj(Goal::Cash { amount: 500_000_000.0 }, 1825),
+            ],
+        },
+        Challenge {
+            key: "moonshot".into(),
+            name: "Moonshot".into(),
+            description: "Get to the Moon before the decade is out.".into(),
+            day_limit: 3650,
+            objectives: vec![
+                obj(reach("leo"), 730),
+                obj(reach("geo"), 1825),
+                obj(reach("lunar_orbit"), 3650),
+            ],
+        },
+    ]
+}
+
+/// The built-in challenge with `key`.
+pub fn preset(key: &str) -> Option<Challenge> {
+    presets().into_iter().find(|c| c.key == key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run() -> ChallengeRun {
+        ChallengeRun::new(preset("orbit_and_back").unwrap(), GameDate::new(2030, 1, 1))
+    }
+
+    #[test]
+    fn test_objectives_are_met_in_order_and_scored_on_time_left() {
+        let cfg = ChallengeConfig::default();
+        let mut r = run();
+        let mut progress = Progress { boosters_landed: 1, ..Default::default() };
+        for _ in 0..99 {
+            assert!(r.advance(&progress, &cfg).is_empty(), "landing first doesn't count");
+        }
+        progress.reached.push("leo".into());
+        let updates = r.advance(&progress, &cfg);
+        assert_eq!(r.completed_on, vec![100, 100], "the later objective was already met");
+        let score = (2.0 * cfg.objective_points + cfg.points_per_day_early * (200.0 + 500.0)
+            + cfg.finish_points_per_day * 800.0) as u32;
+        assert_eq!(updates.last(), Some(&ChallengeUpdate::Ended(ChallengeOutcome::Won { day: 100, score })));
+        assert!(r.advance(&progress, &cfg).is_empty(), "a finished run stays finished");
+        assert_eq!(r.day, 100);
+    }
+
+    #[test]
+    fn test_missing_an_objective_deadline_fails_the_run() {
+        let cfg = ChallengeConfig::default();
+        let mut r = run();
+        let progress = Progress::default();
+        for _ in 0..299 {
+            assert!(r.advance(&progress, &cfg).is_empty());
+        }
+        let updates = r.advance(&progress, &cfg);
+        assert!(matches!(updates.as_slice(), [ChallengeUpdate::Ended(ChallengeOutcome::Failed { day: 300, .. })]));
+        assert_eq!(r.score(&cfg), 0);
+        assert!(r.current().is_none());
+    }
+}
@@ -0,0 +1,242 @@
+//! The in-game encyclopedia's content. Everything with a number in it
+//! is read from the code the game runs on — engine baselines, the
+//! delta-v map, flaw subsystems, the loss models — so the reference
+//! can't drift from what actually happens. Only the prose is written
+//! here. Built on demand by `codex()`; the UI decides how to lay it out.
+
+use crate::balance::cycle_complexity;
+use crate::engine::{EngineCycle, G0};
+use crate::engine_project::{engine_baseline, PropellantPreset};
+use crate::flaw::Subsystem;
+use crate::location::{self, DELTA_V_MAP};
+
+/// Every engine cycle, in order of complexity.
+pub const CYCLES: [EngineCycle; 8] = [
+    EngineCycle::PressureFed,
+    EngineCycle::GasGenerator,
+    EngineCycle::Expander,
+    EngineCycle::StagedCombustion,
+    EngineCycle::FullFlow,
+    EngineCycle::ElectricPropulsion,
+    EngineCycle::SolarSail,
+    EngineCycle::NuclearThermal,
+];
+
+/// Liftoff mass the destination table's drag losses are quoted for.
+pub const REFERENCE_LIFTOFF_KG: f64 = location::AERO_DRAG_REFERENCE_MASS_KG;
+
+/// What an engine of one cycle and propellant is at scale 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleVariant {
+    pub preset: PropellantPreset,
+    pub thrust_n: f64,
+    pub isp_vac_s: f64,
+    /// 0 for vacuum-only engines.
+    pub isp_sl_s: f64,
+    pub mass_kg: f64,
+    /// Engine thrust over its own weight at 1 g.
+    pub twr: f64,
+    pub vacuum_only: bool,
+}
+
+/// One engine cycle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleEntry {
+    pub cycle: EngineCycle,
+    pub name: &'static str,
+    pub summary: &'static str,
+    /// Drives design work, cost and how many flaws a design carries.
+    pub complexity: u32,
+    /// The propellants it burns.
+    pub variants: Vec<CycleVariant>,
+}
+
+/// Getting to one destination from Earth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DestinationEntry {
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Cheapest delta-v from Earth's surface, drag included for a
+    /// rocket of `REFERENCE_LIFTOFF_KG`.
+    pub delta_v: f64,
+    /// Locations passed through on the way, Earth's surface first.
+    pub route: Vec<&'static str>,
+    pub transit_days: u32,
+}
+
+/// One subsystem flaws can live in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlawCategory {
+    pub subsystem: Subsystem,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The flight event where its flaws bite.
+    pub launch_event: &'static str,
+    /// What a near miss looks like in telemetry.
+    pub symptom: &'static str,
+    /// Fix work against the baseline flaw fix.
+    pub fix_work_factor: f64,
+}
+
+/// A formula the simulation uses, with a worked example from the
+/// code itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formula {
+    pub name: &'static str,
+    pub expression: String,
+    pub explanation: &'static str,
+    pub example: String,
+}
+
+/// The whole reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Codex {
+    pub cycles: Vec<CycleEntry>,
+    pub destinations: Vec<DestinationEntry>,
+    pub flaw_categories: Vec<FlawCategory>,
+    pub formulas: Vec<Formula>,
+}
+
+pub fn codex() -> Codex {
+    Codex {
+        cycles: CYCLES.iter().map(|&c| cycle_entry(c)).collect(),
+        destinations: destinations(),
+        flaw_categories: Subsystem::ALL.iter().map(|&s| flaw_category(s)).collect(),
+        formulas: formulas(),
+    }
+}
+
+fn cycle_entry(cycle: EngineCycle) -> CycleEntry {
+    let (name, summary) = match cycle {
+        EngineCycle::PressureFed => ("Pressure Fed",
+            "Tank pressure pushes propellant into the chamber. No pumps to fail, but low chamber pressure means modest thrust and Isp."),
+        EngineCycle::GasGenerator => ("Gas Generator",
+            "A small burner drives the turbopumps and dumps its exhaust overboard. Simple, light and forgiving; the workhorse cycle."),
+        EngineCycle::Expander => ("Expander",
+            "Fuel warmed in the nozzle walls drives the pumps. Efficient and gentle, but thrust is capped and it only runs in vacuum."),
+        EngineCycle::StagedCombustion => ("Staged Combustion",
+            "The preburner's exhaust goes on into the main chamber, so nothing is wasted. High pressure, high Isp, hard to get right."),
+        EngineCycle::FullFlow => ("Full Flow",
+            "Both propellants run through their own preburners. The best chemical performance available, and the hardest to develop."),
+        EngineCycle::NuclearThermal => ("Nuclear Thermal",
+            "A reactor heats hydrogen to exhaust speeds no combustion reaches. Heavy, vacuum-only and needs reactor research."),
+        EngineCycle::ElectricPropulsion => ("Electric Propulsion",
+            "Ion or Hall thrusters accelerate xenon electrically. Enormous Isp, tiny thrust and a large power bill; spirals rather than burns."),
+        EngineCycle::SolarSail => ("Solar Sail",
+            "Sunlight pushes a vast thin sail. No propellant at all, and thrust fades with distance from the Sun."),
+    };
+    let variants = PropellantPreset::ALL.iter()
+        .filter_map(|&preset| engine_baseline(cycle, preset).map(|b| CycleVariant {
+            preset,
+            thrust_n: b.thrust_n,
+            isp_vac_s: b.isp_vac_s,
+            isp_sl_s: if b.vacuum_only { 0.0 } else { b.isp_sl_s },
+            mass_kg: b.mass_kg,
+            twr: b.thrust_n / (b.mass_kg * G0),
+            vacuum_only: b.vacuum_only,
+        }))
+        .collect();
+    CycleEntry { cycle, name, summary, complexity: cycle_complexity(cycle), variants }
+}
+
+fn destinations() -> Vec<DestinationEntry> {
+    let mut entries: Vec<DestinationEntry> = DELTA_V_MAP.locations().iter()
+        .filter(|l| l.id != "earth_surface")
+        .filter_map(|l| {
+            let (route, delta_v) = DELTA_V_MAP.shortest_path("earth_surface", l.id, REFERENCE_LIFTOFF_KG)?;
+            let transit_days = route.windows(2)
+                .filter_map(|leg| DELTA_V_MAP.transfer(leg[0], leg[1]))
+                .map(|t| t.transit_days)
+                .sum();
+            Some(DestinationEntry { id: l.id, name: l.display_name, delta_v, route, transit_days })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.delta_v.total_cmp(&b.delta_v));
+    entries
+}
+
+fn flaw_category(subsystem: Subsystem) -> FlawCategory {
+    let description = match subsystem {
+        Subsystem::Turbopump => "Pumps, turbines and bearings spinning at tens of thousands of rpm. Failures here tend to take the engine with them.",
+        Subsystem::Combustion => "Injectors, chambers and nozzles. Instabilities and burn-throughs cost performance or the engine.",
+        Subsystem::Avionics => "Flight computers, sensors and wiring harnesses.",
+        Subsystem::Separation => "Pyros, pushers and the joints between stages.",
+        Subsystem::GncSoftware => "Guidance, navigation and control code. Cheap to patch once it's found.",
+        Subsystem::TankStructure => "Tanks, intertanks and the loads they carry through max-Q.",
+        Subsystem::Power => "Reactors, arrays, batteries and power processing.",
+    };
+    FlawCategory {
+        subsystem,
+        name: subsystem.display_name(),
+        description,
+        launch_event: subsystem.launch_event(),
+        symptom: subsystem.anomaly_symptom(),
+        fix_work_factor: subsystem.fix_work_factor(),
+    }
+}
+
+fn formulas() -> Vec<Formula> {
+    let earth = DELTA_V_MAP.surface_properties("earth_surface");
+    let (g, radius) = earth.map_or((G0, 6_371_000.0), |p| (p.gravity_m_s2, p.radius_m));
+    // A 100 t single stage: 80 t of propellant at Isp 300 s.
+    let gravity_example = |twr: f64| {
+        let mass = 100_000.0;
+        let thrust = twr * mass * g;
+        location::simulate_gravity_losses(g, radius, &[(thrust, thrust / (300.0 * G0), 80_000.0)], mass)[0]
+    };
+    vec![
+        Formula {
+            name: "Thrust-to-weight ratio",
+            expression: "TWR = F / (m × g)".into(),
+            explanation: "Below 1 a rocket can't leave the pad. Higher TWR climbs out of the gravity well faster and loses less to gravity.",
+            example: format!("1,000 kN on a 70 t rocket at Earth's g = {:.2} m/s²: TWR {:.2}", g, 1_000_000.0 / (70_000.0 * g)),
+        },
+        Formula {
+            name: "Rocket equation",
+            expression: format!("Δv = Isp × g₀ × ln(m_wet / m_dry), g₀ = {} m/s²", G0),
+            explanation: "The velocity a stage can give, before losses. Upper stages count everything above them as payload.",
+            example: format!("Isp 300 s, 100 t wet, 20 t dry: {:.0} m/s", 300.0 * G0 * 5.0_f64.ln()),
+        },
+        Formula {
+            name: "Gravity loss",
+            expression: "loss = ∫ g × sin(pitch) dt".into(),
+            explanation: "Thrust spent holding the rocket up rather than speeding it along. Simulated as a gravity turn from vertical, tipping over once past the kick-over speed.",
+            example: format!("Kick-over at {:.0} m/s. 100 t Isp-300 stage from Earth: {:.0} m/s at TWR 1.3, {:.0} m/s at TWR 2.0",
+                location::KICK_OVER_VELOCITY, gravity_example(1.3), gravity_example(2.0)),
+        },
+        Formula {
+            name: "Drag loss",
+            expression: format!("loss = {:.0} m/s × ({:.0} t / m)^{}",
+                location::AERO_DRAG_BASE_LOSS, location::AERO_DRAG_REFERENCE_MASS_KG / 1000.0, location::AERO_DRAG_MASS_EXPONENT),
+            explanation: "Paid on the leg up through an atmosphere. Bigger rockets punch through air more efficiently.",
+            example: format!("50 t: {:.0} m/s, 500 t: {:.0} m/s, 5,000 t: {:.0} m/s",
+                location::aero_drag_loss(50_000.0), location::aero_drag_loss(500_000.0), location::aero_drag_loss(5_000_000.0)),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codex_reads_the_game_constants() {
+        let codex = codex();
+        assert_eq!(codex.cycles.len(), CYCLES.len());
+        let gg = codex.cycles.iter().find(|c| c.cycle == EngineCycle::GasGenerator).unwrap();
+        let kerolox = gg.variants.iter().find(|v| v.preset == PropellantPreset::Kerolox).unwrap();
+        let baseline = engine_baseline(EngineCycle::GasGenerator, PropellantPreset::Kerolox).unwrap();
+        assert_eq!((kerolox.thrust_n, kerolox.isp_vac_s), (baseline.thrust_n, baseline.isp_vac_s));
+        assert!(codex.cycles.iter().all(|c| !c.variants.is_empty()), "every cycle burns something");
+
+        let leo = codex.destinations.iter().find(|d| d.id == "leo").unwrap();
+        let (_, dv) = DELTA_V_MAP.shortest_path("earth_surface", "leo", REFERENCE_LIFTOFF_KG).unwrap();
+        assert_eq!(leo.delta_v, dv);
+        assert_eq!(leo.route, vec!["earth_surface", "leo"]);
+        assert!(codex.destinations.windows(2).all(|w| w[0].delta_v <= w[1].delta_v));
+
+        assert_eq!(codex.flaw_categories.len(), Subsystem::ALL.len());
+        let drag = codex.formulas.iter().find(|f| f.name == "Drag loss").unwrap();
+        assert!(drag.example.contains(&format!("500 t: {:.0} m/s", location::AERO_DRAG_BASE_LOSS)));
+    }
+}
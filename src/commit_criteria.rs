@@ -0,0 +1,233 @@
+//! Launch commit criteria. Each rocket design carries a checklist the
+//! launch director runs before committing a vehicle: engine testing,
+//! the estimate of flaws testing hasn't found yet, today's weather
+//! forecast, and the delta-v left over after the mission. Criteria are
+//! opt-in; an enforced checklist refuses a launch that fails any of
+//! them, an advisory one only warns.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A design's commit criteria. The defaults check nothing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LaunchCommitCriteria {
+    /// Fewest testing cycles any of the company's own engines on the
+    /// vehicle may have. 0 = not checked.
+    #[serde(default)]
+    pub min_engine_testing_cycles: u32,
+    /// Most flaws testing is estimated to have missed, vehicle and
+    /// engines together.
+    #[serde(default)]
+    pub max_hidden_flaws: Option<f64>,
+    /// Highest weather no-go chance in today's forecast.
+    #[serde(default)]
+    pub max_weather_no_go: Option<f64>,
+    /// Least share of the vehicle's delta-v left after the mission.
+    #[serde(default)]
+    pub min_flight_reserve: Option<f64>,
+    /// Refuse a launch that fails the checklist; otherwise only warn.
+    #[serde(default)]
+    pub enforced: bool,
+}
+
+impl LaunchCommitCriteria {
+    pub fn is_empty(&self) -> bool {
+        self.min_engine_testing_cycles == 0
+            && self.max_hidden_flaws.is_none()
+            && self.max_weather_no_go.is_none()
+            && self.min_flight_reserve.is_none()
+    }
+
+    /// Turn `criterion` on at a typical limit, or off.
+    pub fn toggle(&mut self, criterion: Criterion) {
+        fn flip(limit: &mut Option<f64>, typical: f64) {
+            *limit = if limit.is_some() { None } else { Some(typical) };
+        }
+        match criterion {
+            Criterion::EngineTesting => {
+                self.min_engine_testing_cycles = if self.min_engine_testing_cycles > 0 { 0 } else { 3 };
+            }
+            Criterion::HiddenFlaws => flip(&mut self.max_hidden_flaws, 2.0),
+            Criterion::Weather => flip(&mut self.max_weather_no_go, 0.2),
+            Criterion::FlightReserve => flip(&mut self.min_flight_reserve, 0.02),
+        }
+    }
+
+    /// Nudge an active criterion's limit one step up or down.
+    pub fn adjust(&mut self, criterion: Criterion, up: bool) {
+        fn nudge(limit: &mut Option<f64>, step: f64, max: f64) {
+            if let Some(v) = limit {
+                // Rounded so repeated steps don't drift off the grid.
+                *v = (((*v + step).max(0.0) * 1000.0).round() / 1000.0).min(max);
+            }
+        }
+        let sign = if up { 1.0 } else { -1.0 };
+        match criterion {
+            Criterion::EngineTesting if self.min_engine_testing_cycles > 0 => {
+                self.min_engine_testing_cycles = if up {
+                    self.min_engine_testing_cycles + 1
+                } else {
+                    (self.min_engine_testing_cycles - 1).max(1)
+                };
+            }
+            Criterion::EngineTesting => {}
+            Criterion::HiddenFlaws => nudge(&mut self.max_hidden_flaws, 0.5 * sign, f64::MAX),
+            Criterion::Weather => nudge(&mut self.max_weather_no_go, 0.05 * sign, 1.0),
+            Criterion::FlightReserve => nudge(&mut self.min_flight_reserve, 0.01 * sign, 1.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criterion {
+    EngineTesting,
+    HiddenFlaws,
+    Weather,
+    FlightReserve,
+}
+
+impl Criterion {
+    pub const ALL: [Criterion; 4] = [
+        Criterion::EngineTesting, Criterion::HiddenFlaws, Criterion::Weather, Criterion::FlightReserve,
+    ];
+}
+
+impl fmt::Display for Criterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Criterion::EngineTesting => write!(f, "Engine testing"),
+            Criterion::HiddenFlaws => write!(f, "Unfound flaws"),
+            Criterion::Weather => write!(f, "Weather"),
+            Criterion::FlightReserve => write!(f, "Flight reserve"),
+        }
+    }
+}
+
+/// One line of the checklist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriterionCheck {
+    pub criterion: Criterion,
+    pub pass: bool,
+    /// Measured value against the limit, for display.
+    pub detail: String,
+}
+
+/// What the vehicle measures at against each criterion. Unchecked
+/// criteria are skipped, so their measurements may be left at zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LaunchReadings {
+    /// Testing cycles of the least-tested own engine; None if the
+    /// vehicle flies only bought-in engines.
+    pub engine_testing_cycles: Option<u32>,
+    pub hidden_flaws: f64,
+    pub weather_no_go: f64,
+    /// None if the vehicle can't reach the destination at all.
+    pub flight_reserve: Option<f64>,
+}
+
+/// The checklist run against one launch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchChecklist {
+    pub checks: Vec<CriterionCheck>,
+    pub enforced: bool,
+}
+
+impl LaunchChecklist {
+    /// Run `criteria` against `readings`.
+    pub fn evaluate(criteria: &LaunchCommitCriteria, readings: &LaunchReadings) -> Self {
+        let mut checks = Vec::new();
+        if criteria.min_engine_testing_cycles > 0 {
+            let min = criteria.min_engine_testing_cycles;
+            checks.push(match readings.engine_testing_cycles {
+                Some(cycles) => CriterionCheck {
+                    criterion: Criterion::EngineTesting,
+                    pass: cycles >= min,
+                    detail: format!("{} cycles (min {})", cycles, min),
+                },
+                None => CriterionCheck {
+                    criterion: Criterion::EngineTesting,
+                    pass: true,
+                    detail: "bought-in engines only".into(),
+                },
+            });
+        }
+        if let Some(max) = criteria.max_hidden_flaws {
+            checks.push(CriterionCheck {
+                criterion: Criterion::HiddenFlaws,
+                pass: readings.hidden_flaws <= max,
+                detail: format!("~{:.1} estimated (max {:.1})", readings.hidden_flaws, max),
+            });
+        }
+        if let Some(max) = criteria.max_weather_no_go {
+            checks.push(CriterionCheck {
+                criterion: Criterion::Weather,
+                pass: readings.weather_no_go <= max,
+                detail: format!("{:.0}% no-go (max {:.0}%)", readings.weather_no_go * 100.0, max * 100.0),
+            });
+        }
+        if let Some(min) = criteria.min_flight_reserve {
+            checks.push(match readings.flight_reserve {
+                Some(reserve) => CriterionCheck {
+                    criterion: Criterion::FlightReserve,
+                    pass: reserve >= min,
+                    detail: format!("{:.1}% left (min {:.1}%)", reserve * 100.0, min * 100.0),
+                },
+                None => CriterionCheck {
+                    criterion: Criterion::FlightReserve,
+                    pass: false,
+                    detail: "destination out of reach".into(),
+                },
+            });
+        }
+        LaunchChecklist { checks, enforced: criteria.enforced }
+    }
+
+    pub fn all_go(&self) -> bool {
+        self.checks.iter().all(|c| c.pass)
+    }
+
+    /// The criteria that came up no-go.
+    pub fn failures(&self) -> Vec<Criterion> {
+        self.checks.iter().filter(|c| !c.pass).map(|c| c.criterion).collect()
+    }
+
+    /// An enforced checklist with a no-go.
+    pub fn blocks_launch(&self) -> bool {
+        self.enforced && !self.all_go()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_configured_criteria_are_checked_and_enforcement_blocks() {
+        let readings = LaunchReadings {
+            engine_testing_cycles: Some(2),
+            hidden_flaws: 1.5,
+            weather_no_go: 0.1,
+            flight_reserve: Some(0.05),
+        };
+        let none = LaunchChecklist::evaluate(&LaunchCommitCriteria::default(), &readings);
+        assert!(none.checks.is_empty() && none.all_go());
+
+        let mut criteria = LaunchCommitCriteria {
+            min_engine_testing_cycles: 3,
+            max_hidden_flaws: Some(2.0),
+            max_weather_no_go: Some(0.2),
+            min_flight_reserve: Some(0.02),
+            enforced: false,
+        };
+        let advisory = LaunchChecklist::evaluate(&criteria, &readings);
+        assert_eq!(advisory.checks.len(), 4);
+        assert_eq!(advisory.failures(), vec![Criterion::EngineTesting]);
+        assert!(!advisory.blocks_launch(), "advisory criteria only warn");
+
+        criteria.enforced = true;
+        assert!(LaunchChecklist::evaluate(&criteria, &readings).blocks_launch());
+        let unreachable = LaunchReadings { flight_reserve: None, engine_testing_cycles: Some(5), ..readings };
+        assert_eq!(LaunchChecklist::evaluate(&criteria, &unreachable).failures(), vec![Criterion::FlightReserve]);
+    }
+}
@@ -9,19 +9,34 @@ use std::collections::{HashMap, VecDeque};
 
 use serde::{Serialize, Deserialize};
 
+use crate::assets::{AssetKind, AssetLedger};
+use crate::astronaut::AstronautCorps;
+use crate::budget::{Budgets, Department};
+use crate::capacity::{self, CapacityPlan, EngineLine};
 use crate::contract::{self, Contract};
+use crate::design_file::{DesignFile, EngineOrigin, EngineSnapshot};
 use crate::engine::{EngineCycle, EngineId};
 use crate::engine_project::{EngineDesignStatus, EngineProject, EngineProjectId, EngineSource, PropellantPreset, WorkEvent};
 use crate::calendar::GameDate;
 use crate::event::GameEvent;
-use crate::manufacturing::{Manufacturing, ManufacturingOrder, InventoryEngine};
+use crate::external_review::{ExternalReview, ReviewTarget};
+use crate::flaw::{Flaw, Subsystem};
+use crate::integration::{FlightPreparation, MissionPlan};
+use crate::manufacturing::{
+    CommonStage, CommonalityAnalysis, CommonalityKey, Manufacturing, ManufacturingOrder, InventoryEngine, InventoryItemId,
+};
 use crate::launch::LaunchRecord;
+use crate::launch_site::LaunchSite;
+use crate::regulation::Compliance;
+use crate::valuation::{PublicListing, ValuationRecord};
 use crate::reputation::Reputation;
 use crate::rocket::{RocketDesign, RocketDesignId};
 use crate::rocket_project::{RocketProject, RocketProjectId, RocketWorkEvent};
 use crate::seed::GameSeed;
-use crate::balance_config::BalanceConfig;
-use crate::team::{EngineeringTeam, ManufacturingTeam, TeamId};
+use crate::software_project::{SoftwareDesign, SoftwareLink, SoftwareProjectId, SoftwareWorkEvent};
+use crate::depot_project::{DepotDesignStatus, DepotProject, DepotProjectId, DepotWorkEvent};
+use crate::balance_config::{BalanceConfig, MoraleConfig, StorageConfig};
+use crate::team::{EngineeringTeam, ManufacturingTeam, MoraleCause, TeamId, TeamSize};
 use crate::third_party::{self, ContractedEngine, ContractedEngineId, ThirdPartyEngine};
 
 /// Monthly income/expense record.
@@ -31,6 +46,9 @@ pub struct MonthlyFinancials {
     pub month: u32,
     pub income: f64,
     pub expenses: f64,
+    /// Book value written off the fixed assets this month (non-cash).
+    #[serde(default)]
+    pub depreciation: f64,
 }
 
 /// Which engineering pool a project lives in. Used by the
@@ -78,6 +96,10 @@ pub struct Company {
     /// Contracts accepted by the player.
     #[serde(default)]
     pub active_contracts: Vec<Contract>,
+    /// Contracts handed to outside launch providers, awaiting their
+    /// launch.
+    #[serde(default)]
+    pub subcontracts: Vec<crate::subcontract::Subcontract>,
     /// Reputation tracker.
     #[serde(default)]
     pub reputation: Reputation,
@@ -117,6 +139,70 @@ pub struct Company {
     /// market's solicitations, gated on free stock.
     #[serde(default)]
     pub bid_rules: HashMap<contract::MarketId, BidRule>,
+    /// The company's own pad and its lease calendar.
+    #[serde(default)]
+    pub launch_site: LaunchSite,
+    /// Launch licenses and environmental review state.
+    #[serde(default)]
+    pub compliance: Compliance,
+    /// Month-start valuations, oldest first (capped).
+    #[serde(default)]
+    pub valuation_history: Vec<ValuationRecord>,
+    /// Set once the company has gone public.
+    #[serde(default)]
+    pub listing: Option<PublicListing>,
+    /// Counter-espionage spending tier (0 = none); each level costs
+    /// `espionage.security_cost_per_level` a month.
+    #[serde(default)]
+    pub security_level: u32,
+    /// Allocator for `SoftwareProjectId`.
+    #[serde(default)]
+    pub next_software_project_id: u64,
+    /// Flight software lineages, shared by the designs linked to them.
+    #[serde(default)]
+    pub software_projects: Vec<SoftwareDesign>,
+    /// Allocator for `DepotProjectId`.
+    #[serde(default)]
+    pub next_depot_project_id: u64,
+    /// Propellant depot designs.
+    #[serde(default)]
+    pub depot_projects: Vec<DepotProject>,
+    /// Per-flight preparation: integration campaigns on rockets in
+    /// inventory, at most one per vehicle.
+    #[serde(default)]
+    pub flight_preparations: Vec<FlightPreparation>,
+    /// Paid third-party design reviews awaiting findings.
+    #[serde(default)]
+    pub external_reviews: Vec<ExternalReview>,
+    /// Engineering is in crunch: more work gets done, morale drains.
+    #[serde(default)]
+    pub crunch: bool,
+    /// Wage inflation to date: salaries and hiring costs for new teams
+    /// are the configured ones times this.
+    #[serde(default = "default_wage_index")]
+    pub wage_index: f64,
+    /// PR campaigns, booked press, and media sentiment.
+    #[serde(default)]
+    pub marketing: crate::marketing::Marketing,
+    /// Monthly department spending caps and actuals.
+    #[serde(default)]
+    pub budgets: Budgets,
+    /// Facilities on the books at depreciated value.
+    #[serde(default)]
+    pub assets: AssetLedger,
+    /// Astronauts, their flight assignments, and any stand-down.
+    #[serde(default)]
+    pub astronauts: AstronautCorps,
+    /// Landing zones and drone ships for booster recovery.
+    #[serde(default)]
+    pub recovery_fleet: crate::recovery_fleet::RecoveryFleet,
+    /// Readiness of customers' payloads for accepted contracts.
+    #[serde(default)]
+    pub customer_payloads: crate::payload_readiness::CustomerPayloads,
+    /// QA-injected engine work events for the next R&D tick.
+    #[cfg(any(feature = "qa", debug_assertions))]
+    #[serde(skip)]
+    pub qa_work_events: crate::qa::InjectedWork,
 }
 
 /// A standing bid rule for one market. The player (or a policy) sets
@@ -134,7 +220,22 @@ impl Default for BidRule {
     }
 }
 
+fn default_wage_index() -> f64 {
+    1.0
+}
+
+/// The game event for a team-morale work event; None for project work.
+pub fn morale_game_event(we: WorkEvent) -> Option<GameEvent> {
+    match we {
+        WorkEvent::MoraleShift { cause, average } =>
+            Some(GameEvent::MoraleChanged { cause, average_morale: average }),
+        WorkEvent::TeamResigned { team_name } => Some(GameEvent::TeamResigned { team_name }),
+        _ => None,
+    }
+}
+
 /// What one day of R&D produced — see [`Company::tick_daily_research`].
+#[derive(Default)]
 pub struct ResearchTick {
     pub events: Vec<GameEvent>,
     /// Indices into `engine_projects` whose design completed today.
@@ -145,6 +246,20 @@ pub struct ResearchTick {
     pub newly_designed_reactors: Vec<usize>,
     /// (reactor_project_index, deficiency_id) revision attempts.
     pub reactor_tech_def_attempts: Vec<(usize, crate::technology::TechDeficiencyId)>,
+    /// Work events the projects raised, counting the bookkeeping ones
+    /// (testing cycles, deficiency attempts) that aren't news.
+    pub milestones: u32,
+}
+
+/// Pull the QA-injected work events for engine project `id`.
+#[cfg(any(feature = "qa", debug_assertions))]
+fn take_injected_work(
+    queue: &mut crate::qa::InjectedWork,
+    id: crate::engine_project::EngineProjectId,
+) -> Vec<WorkEvent> {
+    let (mine, rest) = std::mem::take(queue).into_iter().partition(|(pid, _)| *pid == id);
+    *queue = rest;
+    mine.into_iter().map(|(_, we)| we).collect()
 }
 
 impl Company {
@@ -172,6 +287,7 @@ impl Company {
             manufacturing: Manufacturing::new(&balance_cfg.costs),
             notified_manufacturing_idle: false,
             active_contracts: Vec::new(),
+            subcontracts: Vec::new(),
             reputation: Reputation::new(),
             launch_history: Vec::new(),
             monthly_financials: VecDeque::new(),
@@ -183,18 +299,53 @@ impl Company {
             contracted_engine_build_counts: HashMap::new(),
             auto_build_targets: HashMap::new(),
             bid_rules: HashMap::new(),
+            launch_site: LaunchSite::default(),
+            compliance: Compliance::default(),
+            valuation_history: Vec::new(),
+            listing: None,
+            security_level: 0,
+            next_software_project_id: 1,
+            software_projects: Vec::new(),
+            next_depot_project_id: 1,
+            depot_projects: Vec::new(),
+            flight_preparations: Vec::new(),
+            external_reviews: Vec::new(),
+            crunch: false,
+            wage_index: 1.0,
+            marketing: Default::default(),
+            budgets: Budgets::default(),
+            assets: AssetLedger::default(),
+            astronauts: AstronautCorps::default(),
+            recovery_fleet: Default::default(),
+            customer_payloads: Default::default(),
+            #[cfg(any(feature = "qa", debug_assertions))]
+            qa_work_events: Vec::new(),
         };
+        // Open the books with the facilities the company starts with.
+        let starting_floor = balance_cfg.costs.starting_floor_space;
+        company.assets.acquire(
+            AssetKind::FloorSpace { units: starting_floor },
+            starting_floor as f64 * balance_cfg.costs.floor_space_cost,
+            &balance_cfg.depreciation,
+        );
+        company.assets.acquire(AssetKind::LaunchPad, balance_cfg.depreciation.pad_cost, &balance_cfg.depreciation);
         // Start with one engineering team
         company.hire_team("Team 1".into(), balance_cfg);
         company
     }
 
-    /// Hire a new engineering team. Returns the event if successful.
+    /// Hire a new engineering team. Returns the event if successful,
+    /// or None if the hiring cost would break Engineering's budget.
     pub fn hire_team(&mut self, name: String, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
-        self.money -= balance_cfg.costs.engineering_hiring_cost;
+        let cost = balance_cfg.costs.engineering_hiring_cost * self.wage_index;
+        if !self.budgets.allows(Department::Engineering, cost) {
+            return None;
+        }
+        self.money -= cost;
+        self.budgets.record(Department::Engineering, cost);
         let id = TeamId(self.next_team_id);
         self.next_team_id += 1;
-        let team = EngineeringTeam::new(id, name.clone(), balance_cfg.costs.engineering_monthly_salary);
+        let team = EngineeringTeam::new(id, name.clone(), balance_cfg.costs.engineering_monthly_salary * self.wage_index);
         self.teams.push(team);
         Some(GameEvent::TeamHired { name })
     }
@@ -204,9 +355,20 @@ impl Company {
         self.teams.len()
     }
 
-    /// Number of engineering teams not assigned to any project.
+    /// Number of engineering teams not assigned to any project or
+    /// furloughed.
     pub fn unassigned_team_count(&self) -> u32 {
-        let assigned: u32 = self.engine_projects.iter()
+        self.active_team_count().saturating_sub(self.assigned_team_count())
+    }
+
+    /// Engineering teams at work rather than on furlough.
+    pub fn active_team_count(&self) -> u32 {
+        self.teams.iter().filter(|t| !t.furloughed).count() as u32
+    }
+
+    /// Engineering team assignments across every project list.
+    pub fn assigned_team_count(&self) -> u32 {
+        self.engine_projects.iter()
             .map(|p| p.teams_assigned)
             .sum::<u32>()
             + self.rocket_projects.iter()
@@ -214,8 +376,16 @@ impl Company {
                 .sum::<u32>()
             + self.reactor_projects.iter()
                 .map(|p| p.teams_assigned)
-                .sum::<u32>();
-        (self.teams.len() as u32).saturating_sub(assigned)
+                .sum::<u32>()
+            + self.software_projects.iter()
+                .map(|p| p.teams_assigned)
+                .sum::<u32>()
+            + self.depot_projects.iter()
+                .map(|p| p.teams_assigned)
+                .sum::<u32>()
+            + self.flight_preparations.iter()
+                .map(|p| p.teams_assigned)
+                .sum::<u32>()
     }
 
     /// Number of manufacturing teams not assigned to any order.
@@ -224,28 +394,175 @@ impl Company {
         (self.manufacturing_teams.len() as u32).saturating_sub(assigned)
     }
 
+    /// Number of manufacturing teams of `size` not assigned to any order.
+    pub fn unassigned_manufacturing_teams_of_size(&self, size: TeamSize) -> u32 {
+        let hired = self.manufacturing_teams.iter().filter(|t| t.size == size).count() as u32;
+        hired.saturating_sub(self.manufacturing.teams_of_size_assigned(size))
+    }
+
+    /// The largest size with a team free, if any team is.
+    fn largest_idle_team_size(&self) -> Option<TeamSize> {
+        TeamSize::ALL.into_iter().find(|&s| self.unassigned_manufacturing_teams_of_size(s) > 0)
+    }
+
+    /// Combined manufacturing headcount, in standard crews.
+    pub fn manufacturing_crew(&self, costs: &crate::balance_config::CostsConfig) -> f64 {
+        self.manufacturing_teams.iter().map(|t| t.size.crew(costs)).sum()
+    }
+
+    /// Income and expenses over a fiscal quarter, from the rolling
+    /// monthly books. None if none of its months are on the books.
+    pub fn quarter_financials(&self, year: u32, quarter: u32) -> Option<(f64, f64)> {
+        let months = crate::calendar::quarter_months(year, quarter);
+        let mut booked = self.monthly_financials.iter()
+            .filter(|f| months.contains(&(f.year, f.month)))
+            .peekable();
+        booked.peek()?;
+        Some(booked.fold((0.0, 0.0), |(i, e), f| (i + f.income, e + f.expenses)))
+    }
+
+    /// Program P&L for the rocket project at `index`.
+    pub fn design_profitability(&self, index: usize) -> Option<crate::rocket_project::DesignProfitability> {
+        let rp = self.rocket_projects.get(index)?;
+        let history = self.rocket_cost_history.get(&rp.design.id);
+        Some(crate::rocket_project::DesignProfitability {
+            rocket_project_id: rp.project_id,
+            rocket_name: rp.design.name.clone(),
+            revenue: rp.flight_revenue,
+            nre: rp.nre_cost,
+            production_cost: history.map_or(0.0, |h| h.iter().sum()),
+            rockets_built: history.map_or(0, |h| h.len() as u32),
+            flights: rp.flights,
+            flown_build_cost: rp.flown_build_cost,
+        })
+    }
+
+    /// Program P&L for every rocket project, in project order.
+    pub fn program_profitability(&self) -> Vec<crate::rocket_project::DesignProfitability> {
+        (0..self.rocket_projects.len())
+            .filter_map(|i| self.design_profitability(i))
+            .collect()
+    }
+
+    /// Where the company has flown and how much it has delivered there,
+    /// from fully successful launches.
+    pub fn capability_profile(&self) -> contract::CapabilityProfile {
+        let mut profile = contract::CapabilityProfile::default();
+        for r in self.launch_history.iter().filter(|r| matches!(r.outcome, crate::launch::LaunchOutcome::Success)) {
+            match profile.proven.iter_mut().find(|(id, _)| *id == r.destination) {
+                Some((_, kg)) => *kg = kg.max(r.payload_kg),
+                None => profile.proven.push((r.destination.clone(), r.payload_kg)),
+            }
+        }
+        profile
+    }
+
     /// Total monthly salary cost for all teams (engineering + manufacturing).
-    pub fn monthly_salary_cost(&self) -> f64 {
-        let eng: f64 = self.teams.iter().map(|t| t.monthly_salary).sum();
-        let mfg: f64 = self.manufacturing_teams.iter().map(|t| t.monthly_salary).sum();
+    pub fn monthly_salary_cost(&self, costs: &crate::balance_config::CostsConfig) -> f64 {
+        let (eng, mfg) = self.monthly_salary_split(costs);
         eng + mfg
     }
 
-    /// Hire a manufacturing team.
+    /// Monthly salaries as (engineering, manufacturing). Furloughed
+    /// teams draw their reduced pay.
+    pub fn monthly_salary_split(&self, costs: &crate::balance_config::CostsConfig) -> (f64, f64) {
+        let eng: f64 = self.teams.iter().map(|t| t.payroll(costs)).sum();
+        let mfg: f64 = self.manufacturing_teams.iter().map(|t| t.monthly_salary).sum();
+        (eng, mfg)
+    }
+
+    /// Hire a manufacturing team, unless the hiring cost would break
+    /// Manufacturing's budget.
     pub fn hire_manufacturing_team(&mut self, name: String, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
-        self.money -= balance_cfg.costs.manufacturing_hiring_cost;
+        self.hire_sized_manufacturing_team(name, TeamSize::Medium, balance_cfg)
+    }
+
+    /// Hire a manufacturing team of `size`, unless its hiring cost would
+    /// break Manufacturing's budget.
+    pub fn hire_sized_manufacturing_team(&mut self, name: String, size: TeamSize, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
+        let cost = size.hiring_cost(&balance_cfg.costs) * self.wage_index;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
         let id = TeamId(self.next_team_id);
         self.next_team_id += 1;
-        let team = ManufacturingTeam::new(id, name.clone(), balance_cfg.costs.manufacturing_monthly_salary);
+        let mut team = ManufacturingTeam::sized(id, name.clone(), size, &balance_cfg.costs);
+        team.monthly_salary *= self.wage_index;
         self.manufacturing_teams.push(team);
         Some(GameEvent::ManufacturingTeamHired { name })
     }
 
-    /// Order a floor-space expansion and pay for it. Returns the cost.
-    pub fn buy_floor_space(&mut self, units: u32, balance_cfg: &BalanceConfig) -> f64 {
-        let cost = self.manufacturing.floor_space.order_expansion(units, &balance_cfg.costs);
+    /// Resize the idle manufacturing team at `index`. Growing a team
+    /// pays the difference in hiring cost; shrinking one refunds
+    /// nothing. Returns the cost, or None if the team is busy on an
+    /// order or the cost would break Manufacturing's budget.
+    pub fn convert_manufacturing_team(&mut self, index: usize, size: TeamSize, balance_cfg: &BalanceConfig) -> Option<f64> {
+        let from = self.manufacturing_teams.get(index)?.size;
+        if from == size || self.unassigned_manufacturing_teams_of_size(from) == 0 {
+            return None;
+        }
+        let costs = &balance_cfg.costs;
+        let cost = (size.hiring_cost(costs) - from.hiring_cost(costs)).max(0.0) * self.wage_index;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
+        let team = &mut self.manufacturing_teams[index];
+        team.size = size;
+        team.monthly_salary = size.monthly_salary(costs) * self.wage_index;
+        Some(cost)
+    }
+
+    /// Merge two idle manufacturing teams of the same size into one of
+    /// the next size up, which keeps the first team's name. Returns the
+    /// merged team's name, or None if the teams can't merge.
+    pub fn merge_manufacturing_teams(&mut self, a: usize, b: usize, balance_cfg: &BalanceConfig) -> Option<String> {
+        if a == b || a.max(b) >= self.manufacturing_teams.len() {
+            return None;
+        }
+        let size = self.manufacturing_teams[a].size;
+        let merged = size.larger()?;
+        if self.manufacturing_teams[b].size != size || self.unassigned_manufacturing_teams_of_size(size) < 2 {
+            return None;
+        }
+        self.manufacturing_teams.remove(b);
+        let keep = if b < a { a - 1 } else { a };
+        let wage_index = self.wage_index;
+        let team = &mut self.manufacturing_teams[keep];
+        team.size = merged;
+        team.monthly_salary = merged.monthly_salary(&balance_cfg.costs) * wage_index;
+        Some(team.name.clone())
+    }
+
+    /// Hire an astronaut candidate, unless the hiring cost would break
+    /// Testing's budget.
+    pub fn hire_astronaut(&mut self, name: String, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
+        let cfg = &balance_cfg.astronauts;
+        if !self.budgets.allows(Department::Testing, cfg.hiring_cost) {
+            return None;
+        }
+        self.money -= cfg.hiring_cost;
+        self.budgets.record(Department::Testing, cfg.hiring_cost);
+        self.astronauts.hire(name.clone(), cfg);
+        Some(GameEvent::AstronautHired { name })
+    }
+
+    /// Order a floor-space expansion, pay for it and put it on the
+    /// books. Returns the cost, or None if it would break the
+    /// Facilities budget.
+    pub fn buy_floor_space(&mut self, units: u32, balance_cfg: &BalanceConfig) -> Option<f64> {
+        let cost = units as f64 * balance_cfg.costs.floor_space_cost;
+        if !self.budgets.allows(Department::Facilities, cost) {
+            return None;
+        }
+        self.manufacturing.floor_space.order_expansion(units, &balance_cfg.costs);
         self.money -= cost;
-        cost
+        self.budgets.record(Department::Facilities, cost);
+        self.assets.acquire(AssetKind::FloorSpace { units }, cost, &balance_cfg.depreciation);
+        Some(cost)
     }
 
     /// Start a revision on the engine project at `index`. Returns the
@@ -359,6 +676,26 @@ impl Company {
         Some(GameEvent::EngineDesignStarted { engine_name: name })
     }
 
+    /// Start a scaled variant of the engine project at `parent_index`,
+    /// derived from its lineage. Returns the event, or None if the
+    /// parent's design isn't finished.
+    pub fn start_engine_variant(
+        &mut self,
+        parent_index: usize,
+        scale: f64,
+        balance_cfg: &BalanceConfig,
+    ) -> Option<GameEvent> {
+        let parent = self.engine_projects.get(parent_index)?;
+        let name = format!("{} {}x", parent.design.name, scale);
+        let project_id = EngineProjectId(self.next_project_id);
+        let engine_id = EngineId(self.next_engine_id);
+        let project = EngineProject::derive_variant(parent, project_id, engine_id, name.clone(), scale, balance_cfg)?;
+        self.next_project_id += 1;
+        self.next_engine_id += 1;
+        self.engine_projects.push(project);
+        Some(GameEvent::EngineDesignStarted { engine_name: name })
+    }
+
     /// Start a tentative engine design in `Proposed` status. Used by the
     /// rocket designer; the engine doesn't enter the regular project
     /// queue until the parent rocket is finalised. Returns the new
@@ -579,6 +916,122 @@ impl Company {
         Some(GameEvent::RocketDesignStarted { rocket_name: name })
     }
 
+    /// Fork the rocket project at `index` into a new variant branch
+    /// named "<design> Block N". Returns the event, or None unless the
+    /// design is in Testing.
+    pub fn fork_rocket_project(&mut self, index: usize, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
+        let parent = self.rocket_projects.get(index)?;
+        let block = self.rocket_branches(parent.project_id).len() + 2;
+        let branch_name = format!("{} Block {}", parent.design.name, block);
+        let project_id = RocketProjectId(self.next_rocket_project_id);
+        let branch = parent.fork(project_id, branch_name.clone(), balance_cfg.heritage.branch_testing_carryover)?;
+        let rocket_name = parent.design.name.clone();
+        self.next_rocket_project_id += 1;
+        self.rocket_projects.push(branch);
+        Some(GameEvent::RocketBranchForked { rocket_name, branch_name })
+    }
+
+    /// Indices of the projects branched from `project_id`, in fork order.
+    pub fn rocket_branches(&self, project_id: RocketProjectId) -> Vec<usize> {
+        self.rocket_projects.iter().enumerate()
+            .filter(|(_, rp)| rp.branch.as_ref().is_some_and(|b| b.parent == project_id))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// True if any hardware of `project_id` is in stock or on order.
+    fn has_rocket_hardware(&self, project_id: RocketProjectId) -> bool {
+        use crate::manufacturing::ManufacturingOrderType;
+        let inv = &self.manufacturing.inventory;
+        inv.rockets.iter().any(|r| r.rocket_project_id == project_id)
+            || inv.stages.iter().any(|s| s.rocket_project_id == project_id)
+            || self.manufacturing.orders.iter().any(|o| match &o.order_type {
+                ManufacturingOrderType::Stage { rocket_project_id, .. }
+                | ManufacturingOrderType::RocketIntegration { rocket_project_id, .. } => *rocket_project_id == project_id,
+                _ => false,
+            })
+    }
+
+    /// Drop a branch project, freeing its teams. Callers have checked
+    /// it has no hardware or branches of its own.
+    fn remove_rocket_branch(&mut self, index: usize) -> RocketProject {
+        let branch = self.rocket_projects.remove(index);
+        self.auto_build_targets.remove(&branch.project_id);
+        branch
+    }
+
+    /// Fold the branch at `index` back into its parent: the parent takes
+    /// the branch's design and flaw state as its next revision, and the
+    /// branch is closed. None unless both are in Testing and the branch
+    /// has no hardware in stock or on order and no branches of its own.
+    pub fn merge_rocket_branch(&mut self, index: usize) -> Option<GameEvent> {
+        let branch = self.rocket_projects.get(index)?;
+        let parent_id = branch.branch.as_ref()?.parent;
+        let parent_index = self.rocket_projects.iter().position(|rp| rp.project_id == parent_id)?;
+        let testing = |rp: &RocketProject| matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. });
+        if !testing(branch) || !testing(&self.rocket_projects[parent_index])
+            || self.has_rocket_hardware(branch.project_id)
+            || !self.rocket_branches(branch.project_id).is_empty()
+        {
+            return None;
+        }
+        let branch = self.remove_rocket_branch(index);
+        let parent = self.rocket_projects.iter_mut().find(|rp| rp.project_id == parent_id)?;
+        let (design_id, rocket_name) = (parent.design.id, parent.design.name.clone());
+        parent.design = RocketDesign { id: design_id, name: rocket_name.clone(), ..branch.design };
+        parent.flaws = branch.flaws;
+        parent.revision += 1;
+        parent.complexity = branch.complexity;
+        parent.cumulative_testing_work = parent.cumulative_testing_work.max(branch.cumulative_testing_work);
+        parent.nre_cost += branch.nre_cost;
+        parent.flights += branch.flights;
+        parent.flight_revenue += branch.flight_revenue;
+        parent.flown_build_cost += branch.flown_build_cost;
+        parent.success_streak = 0;
+        Some(GameEvent::RocketBranchMerged {
+            branch_name: branch.design.name,
+            rocket_name,
+            revision: parent.revision,
+        })
+    }
+
+    /// Abandon the branch at `index`. None if it isn't a branch, has
+    /// hardware in stock or on order, or has branches of its own.
+    pub fn retire_rocket_branch(&mut self, index: usize) -> Option<GameEvent> {
+        let branch = self.rocket_projects.get(index)?;
+        branch.branch.as_ref()?;
+        if self.has_rocket_hardware(branch.project_id) || !self.rocket_branches(branch.project_id).is_empty() {
+            return None;
+        }
+        let branch = self.remove_rocket_branch(index);
+        Some(GameEvent::RocketBranchRetired { branch_name: branch.design.name })
+    }
+
+    /// Package the rocket design at `index` as a shareable design file,
+    /// with a snapshot of each engine it flies. None if a stage flies an
+    /// engine the company no longer has.
+    pub fn export_design(&self, index: usize) -> Option<DesignFile> {
+        let design = &self.rocket_projects.get(index)?.design;
+        let mut engines: Vec<EngineSnapshot> = Vec::new();
+        for stage in design.stage_groups.iter().flatten() {
+            if engines.iter().any(|e| e.design.id == stage.engine.id) {
+                continue;
+            }
+            let origin = match self.engine_source_for_id(stage.engine.id)? {
+                EngineSource::PlayerDesign(id) => {
+                    let ep = self.find_engine_project(id)?;
+                    EngineOrigin::Designed { preset: ep.preset, scale: ep.scale, technology_id: ep.technology_id }
+                }
+                EngineSource::Contracted(id) => {
+                    let ce = self.contracted_engines.iter().find(|ce| ce.id == id)?;
+                    EngineOrigin::Contracted { preset: ce.preset }
+                }
+            };
+            engines.push(EngineSnapshot { design: stage.engine.clone(), origin });
+        }
+        Some(DesignFile::new(design.clone(), engines))
+    }
+
     /// Add an engineering team to a rocket project. Returns true if successful.
     pub fn add_team_to_rocket_project(&mut self, project_index: usize) -> bool {
         if self.unassigned_team_count() == 0 || project_index >= self.rocket_projects.len() {
@@ -600,10 +1053,238 @@ impl Company {
         true
     }
 
-    /// Add a manufacturing team to a manufacturing order. Returns true if successful.
+    /// Start a new flight software lineage. Returns its id and event.
+    pub fn start_software_project(&mut self, name: String, balance_cfg: &BalanceConfig) -> (SoftwareProjectId, GameEvent) {
+        let project_id = SoftwareProjectId(self.next_software_project_id);
+        self.next_software_project_id += 1;
+        self.software_projects.push(SoftwareDesign::new(project_id, name.clone(), &balance_cfg.software));
+        (project_id, GameEvent::SoftwareDesignStarted { software_name: name })
+    }
+
+    /// Link a rocket project to a software lineage. The rocket's teams
+    /// owe the adaptation work before the link counts. False if either
+    /// doesn't exist or the rocket already flies that lineage.
+    pub fn link_rocket_software(
+        &mut self, rocket_index: usize, software_id: SoftwareProjectId, balance_cfg: &BalanceConfig,
+    ) -> bool {
+        if !self.software_projects.iter().any(|p| p.project_id == software_id) {
+            return false;
+        }
+        let Some(rp) = self.rocket_projects.get_mut(rocket_index) else {
+            return false;
+        };
+        if rp.software.as_ref().is_some_and(|l| l.software_id == software_id) {
+            return false;
+        }
+        rp.software = Some(SoftwareLink {
+            software_id,
+            adaptation_remaining: balance_cfg.software.adaptation_work,
+        });
+        true
+    }
+
+    /// Give the rocket at `rocket_index` flight software: adapt the most
+    /// mature existing lineage, or start a new one named after the
+    /// rocket when there is none. Returns the lineage and, for a new
+    /// one, its started event. None if the rocket already has software.
+    pub fn adopt_flight_software(
+        &mut self, rocket_index: usize, balance_cfg: &BalanceConfig,
+    ) -> Option<(SoftwareProjectId, Option<GameEvent>)> {
+        let rp = self.rocket_projects.get(rocket_index)?;
+        if rp.software.is_some() {
+            return None;
+        }
+        let existing = self.software_projects.iter()
+            .max_by(|a, b| a.maturity(&balance_cfg.software).total_cmp(&b.maturity(&balance_cfg.software)))
+            .map(|sw| sw.project_id);
+        let (software_id, evt) = match existing {
+            Some(id) => (id, None),
+            None => {
+                let name = format!("{} FSW", rp.design.name);
+                let (id, evt) = self.start_software_project(name, balance_cfg);
+                (id, Some(evt))
+            }
+        };
+        self.link_rocket_software(rocket_index, software_id, balance_cfg);
+        Some((software_id, evt))
+    }
+
+    /// Start patching discovered bugs on the software lineage at
+    /// `index`. Returns the number of bugs being fixed.
+    pub fn start_software_revision(&mut self, index: usize) -> Option<usize> {
+        let project = self.software_projects.get_mut(index)?;
+        if !project.start_revision() {
+            return None;
+        }
+        match &project.status {
+            crate::software_project::SoftwareDesignStatus::Revising { remaining_indices, .. } =>
+                Some(remaining_indices.len()),
+            _ => Some(0),
+        }
+    }
+
+    /// Index into `software_projects` of the lineage the rocket at
+    /// `rocket_index` is linked to.
+    pub fn rocket_software_index(&self, rocket_index: usize) -> Option<usize> {
+        let link = self.rocket_projects.get(rocket_index)?.software.as_ref()?;
+        self.software_projects.iter().position(|sw| sw.project_id == link.software_id)
+    }
+
+    /// Software maturity a rocket project flies with: 0 with no adapted
+    /// lineage.
+    pub fn flight_software_maturity(&self, rp: &RocketProject, balance_cfg: &BalanceConfig) -> f64 {
+        rp.flight_software()
+            .and_then(|id| self.software_projects.iter().find(|p| p.project_id == id))
+            .map_or(0.0, |sw| sw.maturity(&balance_cfg.software))
+    }
+
+    /// Add an engineering team to a software lineage. True on success.
+    pub fn add_team_to_software_project(&mut self, project_index: usize) -> bool {
+        if self.unassigned_team_count() == 0 || project_index >= self.software_projects.len() {
+            return false;
+        }
+        self.software_projects[project_index].teams_assigned += 1;
+        true
+    }
+
+    /// Remove an engineering team from a software lineage. True on success.
+    pub fn remove_team_from_software_project(&mut self, project_index: usize) -> bool {
+        let Some(p) = self.software_projects.get_mut(project_index) else {
+            return false;
+        };
+        if p.teams_assigned == 0 {
+            return false;
+        }
+        p.teams_assigned -= 1;
+        true
+    }
+
+    // ── Depot design lifecycle (mirrors the reactor helpers) ──
+
+    /// Spawn a `Proposed` depot design for the editor to work on.
+    /// Promoted via `promote_proposed_depot` when the player commits;
+    /// deleted via `delete_proposed_depot` on cancel.
+    pub fn start_proposed_depot(&mut self, name: String, balance_cfg: &BalanceConfig) -> DepotProjectId {
+        let project_id = DepotProjectId(self.next_depot_project_id);
+        self.next_depot_project_id += 1;
+        self.depot_projects.push(DepotProject::new_proposed(project_id, name, balance_cfg));
+        project_id
+    }
+
+    pub fn find_depot_project(&self, id: DepotProjectId) -> Option<&DepotProject> {
+        self.depot_projects.iter().find(|p| p.project_id == id)
+    }
+
+    pub fn find_depot_project_mut(&mut self, id: DepotProjectId) -> Option<&mut DepotProject> {
+        self.depot_projects.iter_mut().find(|p| p.project_id == id)
+    }
+
+    /// Promote a `Proposed` depot to `InDesign`. Returns the started
+    /// event, or None if it isn't a draft.
+    pub fn promote_proposed_depot(&mut self, id: DepotProjectId, balance_cfg: &BalanceConfig) -> Option<GameEvent> {
+        let p = self.find_depot_project_mut(id)?;
+        if !matches!(p.status, DepotDesignStatus::Proposed) {
+            return None;
+        }
+        p.promote_to_in_design(&balance_cfg.depot_design);
+        Some(GameEvent::DepotDesignStarted { depot_name: p.design.name.clone() })
+    }
+
+    /// Delete a `Proposed` depot. No-op on anything past the draft.
+    pub fn delete_proposed_depot(&mut self, id: DepotProjectId) {
+        self.depot_projects.retain(|p| p.project_id != id || !matches!(p.status, DepotDesignStatus::Proposed));
+    }
+
+    /// Add an engineering team to a depot design. True on success.
+    pub fn add_team_to_depot_project(&mut self, project_index: usize) -> bool {
+        if self.unassigned_team_count() == 0 {
+            return false;
+        }
+        match self.depot_projects.get_mut(project_index) {
+            Some(p) if !matches!(p.status, DepotDesignStatus::Proposed) => {
+                p.teams_assigned += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove an engineering team from a depot design. True on success.
+    pub fn remove_team_from_depot_project(&mut self, project_index: usize) -> bool {
+        let Some(p) = self.depot_projects.get_mut(project_index) else {
+            return false;
+        };
+        if p.teams_assigned == 0 {
+            return false;
+        }
+        p.teams_assigned -= 1;
+        true
+    }
+
+    /// Start fixing the discovered flaws on the depot design at `index`.
+    /// Returns the number of flaws being fixed.
+    pub fn start_depot_revision(&mut self, index: usize) -> Option<usize> {
+        let project = self.depot_projects.get_mut(index)?;
+        if !project.start_revision() {
+            return None;
+        }
+        match &project.status {
+            DepotDesignStatus::Revising { remaining_indices, .. } => Some(remaining_indices.len()),
+            _ => Some(0),
+        }
+    }
+
+    /// Start an integration campaign validating the rocket `item_id`
+    /// against `plan`, with one free engineering team. Replaces any
+    /// campaign already on that vehicle. Returns the started event, or
+    /// None if the rocket isn't in inventory or no team is free.
+    pub fn start_integration_campaign(
+        &mut self, item_id: InventoryItemId, plan: MissionPlan, balance_cfg: &BalanceConfig,
+    ) -> Option<GameEvent> {
+        let rocket_name = self.manufacturing.inventory.rockets.iter()
+            .find(|r| r.item_id == item_id)?
+            .rocket_name.clone();
+        self.flight_preparations.retain(|p| p.rocket_item_id != item_id);
+        if self.unassigned_team_count() == 0 {
+            return None;
+        }
+        let mut prep = FlightPreparation::new(item_id, plan, &balance_cfg.integration);
+        prep.teams_assigned = 1;
+        let evt = GameEvent::IntegrationCampaignStarted {
+            rocket_name,
+            destination: prep.plan.destination.clone(),
+            burn_count: prep.plan.burn_count,
+        };
+        self.flight_preparations.push(prep);
+        Some(evt)
+    }
+
+    /// The preparation state for the rocket `item_id`, if any.
+    pub fn flight_preparation(&self, item_id: InventoryItemId) -> Option<&FlightPreparation> {
+        self.flight_preparations.iter().find(|p| p.rocket_item_id == item_id)
+    }
+
+    /// Remove and return the preparation for the rocket `item_id`;
+    /// called when it launches, since a campaign covers one flight.
+    pub fn take_flight_preparation(&mut self, item_id: InventoryItemId) -> Option<FlightPreparation> {
+        let idx = self.flight_preparations.iter().position(|p| p.rocket_item_id == item_id)?;
+        Some(self.flight_preparations.remove(idx))
+    }
+
+    /// Add the largest idle manufacturing team to a manufacturing order.
+    /// Returns true if successful.
     pub fn add_team_to_manufacturing_order(&mut self, order_index: usize) -> bool {
-        let available = self.unassigned_manufacturing_team_count();
-        self.manufacturing.add_team_to_order(order_index, available)
+        match self.largest_idle_team_size() {
+            Some(size) => self.add_sized_team_to_manufacturing_order(order_index, size),
+            None => false,
+        }
+    }
+
+    /// Add an idle manufacturing team of `size` to a manufacturing order.
+    /// Returns true if successful.
+    pub fn add_sized_team_to_manufacturing_order(&mut self, order_index: usize, size: TeamSize) -> bool {
+        let available = self.unassigned_manufacturing_teams_of_size(size);
+        self.manufacturing.add_sized_team_to_order(order_index, size, available)
     }
 
     /// Remove a manufacturing team from a manufacturing order. Returns true if successful.
@@ -611,12 +1292,157 @@ impl Company {
         self.manufacturing.remove_team_from_order(order_index)
     }
 
+    /// Material cost of a first unit of `design` at current prices — what
+    /// `order_rocket_build` would charge before any learning-curve
+    /// discount or recovered fairings.
+    pub fn estimated_unit_cost(&self, design: &RocketDesign, balance_cfg: &BalanceConfig) -> f64 {
+        let prices = &balance_cfg.costs.resource_prices;
+        let stages: f64 = design.stage_groups.iter().flatten()
+            .map(|stage| {
+                let engine = match self.engine_source_for_id(stage.engine.id) {
+                    Some(EngineSource::PlayerDesign(id)) => self.find_engine_project(id)
+                        .map_or(0.0, |ep| crate::resources::engine_material_cost(ep.preset, stage.engine.mass_kg, prices)),
+                    Some(EngineSource::Contracted(id)) => self.contracted_engines.iter()
+                        .find(|ce| ce.id == id)
+                        .map_or(0.0, |ce| ce.purchase_cost_per_unit),
+                    None => 0.0,
+                };
+                stage.material_cost(engine, prices)
+            })
+            .sum();
+        stages + crate::resources::rocket_integration_cost(prices)
+    }
+
+    /// What building rocket project `project_index` at
+    /// `rockets_per_month` takes, against the factory as it stands.
+    /// Mirrors the orders `order_rocket_build` queues, with learning
+    /// averaged over a year at that cadence. None unless the design is
+    /// flight-ready and the cadence positive.
+    pub fn capacity_plan(
+        &self,
+        project_index: usize,
+        rockets_per_month: f64,
+        balance_cfg: &BalanceConfig,
+    ) -> Option<CapacityPlan> {
+        let rp = self.rocket_projects.get(project_index)?;
+        if !matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }) || rockets_per_month <= 0.0 {
+            return None;
+        }
+        let commonality = self.commonality_analysis(balance_cfg);
+        let work = &balance_cfg.work;
+        let prices = &balance_cfg.costs.resource_prices;
+        let year_of_builds = (rockets_per_month * 12.0).round() as u32;
+        let rocket_prior = *self.rocket_build_counts.get(&rp.design.id).unwrap_or(&0);
+        let rocket_learning = capacity::mean_learning(rocket_prior, year_of_builds, |n| work.learning_curve_multiplier(n));
+
+        let mut engine_lines: Vec<EngineLine> = Vec::new();
+        let (mut work_per_rocket, mut floor_days, mut material, mut longest_stage) = (0.0, 0.0, 0.0, 0.0_f64);
+        let total_stages: u32 = rp.design.stage_groups.iter().map(|g| g.len() as u32).sum();
+        let stages = rp.design.stage_groups.iter().enumerate()
+            .flat_map(|(gi, group)| group.iter().enumerate().map(move |(si, stage)| (gi, si, stage)));
+        for (gi, si, stage) in stages {
+            let (engine_days, engine_cost, bought_in) = match self.engine_source_for_id(stage.engine.id) {
+                Some(EngineSource::PlayerDesign(id)) => match self.find_engine_project(id) {
+                    Some(ep) => {
+                        let per_rocket: u32 = rp.design.stage_groups.iter().flatten()
+                            .filter(|s| s.engine.id == stage.engine.id)
+                            .map(|s| s.engine_count)
+                            .sum();
+                        let prior = *self.engine_build_counts.get(&id).unwrap_or(&0);
+                        let learning = capacity::mean_learning(
+                            prior, year_of_builds * per_rocket, |n| work.learning_curve_multiplier(n),
+                        );
+                        (
+                            work.engine_build_work(ep.complexity) * learning,
+                            crate::resources::engine_material_cost(ep.preset, stage.engine.mass_kg, prices) * learning,
+                            false,
+                        )
+                    }
+                    None => (0.0, 0.0, false),
+                },
+                Some(EngineSource::Contracted(id)) => (
+                    0.0,
+                    self.contracted_engines.iter().find(|ce| ce.id == id).map_or(0.0, |ce| ce.purchase_cost_per_unit),
+                    true,
+                ),
+                None => (0.0, 0.0, false),
+            };
+            let engines = stage.engine_count as f64;
+            work_per_rocket += engine_days * engines;
+            floor_days += engine_days * engines;
+            material += engine_cost * engines;
+            match engine_lines.iter_mut().find(|l| l.engine_name == stage.engine.name) {
+                Some(line) => line.per_month += engines * rockets_per_month,
+                None => engine_lines.push(EngineLine {
+                    engine_name: stage.engine.name.clone(),
+                    per_month: engines * rockets_per_month,
+                    work_days_each: engine_days,
+                    bought_in,
+                }),
+            }
+
+            let stage_mass = stage.structural_mass_kg + stage.long_coast_kit_mass_kg() + stage.landing_kit_mass_kg();
+            let (stage_learning, work_share, material_share) = match commonality.family_of(rp.project_id, gi, si) {
+                Some(family) => {
+                    let cfg = &balance_cfg.commonality;
+                    (
+                        capacity::mean_learning(family.builds, year_of_builds, |n| work.learning_curve_multiplier(n)),
+                        1.0 - cfg.work_discount,
+                        1.0 - cfg.material_discount,
+                    )
+                }
+                None => (rocket_learning, 1.0, 1.0),
+            };
+            let stage_days = work.stage_build_work(stage_mass) * stage_learning * work_share;
+            work_per_rocket += stage_days;
+            floor_days += stage_days;
+            material += (crate::resources::tank_material_cost(stage_mass, prices)
+                + crate::resources::stage_assembly_cost(prices)) * stage_learning * material_share
+                + stage.fairing.as_ref().map_or(0.0, |f| crate::resources::fairing_material_cost(f.mass_kg, prices))
+                + crate::resources::abort_system_material_cost(stage.launch_abort_system_mass_kg(), prices);
+            // A stage waits on its engines, which are built in parallel.
+            longest_stage = longest_stage.max(engine_days + stage_days);
+        }
+        let integration_days = work.rocket_integration_work(total_stages) * rocket_learning;
+        work_per_rocket += integration_days;
+        floor_days += integration_days * total_stages as f64;
+        material += crate::resources::rocket_integration_cost(prices) * rocket_learning;
+
+        let rockets_per_day = rockets_per_month / 30.0;
+        let teams_needed = (work_per_rocket * rockets_per_day).ceil() as u32;
+        let floor_space_needed = (floor_days * rockets_per_day).ceil() as u32;
+        // Work scales with headcount, so count teams as standard crews.
+        let teams_have = self.manufacturing_crew(&balance_cfg.costs).floor() as u32;
+        let floor = &self.manufacturing.floor_space;
+        let floor_space_have = floor.total_units + floor.under_construction.iter().map(|o| o.units).sum::<u32>();
+        let costs = &balance_cfg.costs;
+        let team_gap = teams_needed.saturating_sub(teams_have);
+        let floor_gap = floor_space_needed.saturating_sub(floor_space_have);
+        Some(CapacityPlan {
+            rocket_name: rp.design.name.clone(),
+            rockets_per_month,
+            work_per_rocket,
+            teams_needed,
+            teams_have,
+            floor_space_needed,
+            floor_space_have,
+            engine_lines,
+            lead_time_days: longest_stage + integration_days,
+            material_cost_per_month: material * rockets_per_month,
+            expansion_cost: team_gap as f64 * costs.manufacturing_hiring_cost * self.wage_index
+                + floor_gap as f64 * costs.floor_space_cost,
+            extra_monthly_salary: team_gap as f64 * costs.manufacturing_monthly_salary * self.wage_index,
+        })
+    }
+
     /// Order construction of a rocket. Auto-queues engine, stage, and integration orders.
-    /// Returns the total material cost and event, or None if the rocket project isn't complete.
+    /// Returns the total material cost and event, or None if the rocket project isn't complete
+    /// or the build would break the Manufacturing budget.
     pub fn order_rocket_build(&mut self, rocket_project_index: usize, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
         if rocket_project_index >= self.rocket_projects.len() {
             return None;
         }
+        let commonality = self.commonality_analysis(balance_cfg);
         let rp = &self.rocket_projects[rocket_project_index];
         if !matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }) {
             return None;
@@ -627,12 +1453,25 @@ impl Company {
         let design_id = rp.design.id;
         let mut total_cost = 0.0;
 
+        // The cost is only known once every sub-order is priced, so under
+        // a Manufacturing cap keep what's needed to back the orders out.
+        let rollback = self.budgets.limit(Department::Manufacturing).is_some().then(|| (
+            self.manufacturing.clone(),
+            self.engine_build_counts.clone(),
+            self.contracted_engine_build_counts.clone(),
+        ));
+
         // Get current build count for this rocket design (for learning curve)
         let rocket_prior = *self.rocket_build_counts.get(&design_id).unwrap_or(&0);
 
         // Queue engine build orders for each engine needed
         for (gi, group) in rp.design.stage_groups.iter().enumerate() {
             for (si, stage) in group.iter().enumerate() {
+                // A landed booster in stock flies again in place of a
+                // new stage.
+                if gi == 0 && self.manufacturing.has_spare_booster(rocket_project_id, si) {
+                    continue;
+                }
                 let source = self.engine_source_for_id(stage.engine.id);
                 for _e in 0..stage.engine_count {
                     match source {
@@ -653,7 +1492,7 @@ impl Company {
                                     ep.preset,
                                     engine_prior,
                                     ep.revision,
-                                    ep.flaws.clone(),
+                                    ep.flaws.iter().map(|f| f.id).collect(),
                                     ep.improvements.iter().filter(|i| i.actualized).cloned().collect(),
                                     balance_cfg,
                                 );
@@ -676,8 +1515,10 @@ impl Company {
                                     engine_name: stage.engine.name.clone(),
                                     build_cost: ce.purchase_cost_per_unit,
                                     revision: 0,
-                                    flaws: ce.flaws.clone(),
+                                    flaw_ids: ce.flaws.iter().map(|f| f.id).collect(),
                                     improvements: Vec::new(),
+                                    built_on: None,
+                                    expired: false,
                                 });
                                 *self.contracted_engine_build_counts.entry(ce_id).or_insert(0) += 1;
                             }
@@ -695,15 +1536,33 @@ impl Company {
                     format!("{}{}", gi + 1, suffix)
                 };
                 let stage_name = format!("{} S{}", rocket_name, stage_label);
-                let order = ManufacturingOrder::new_stage(
+                // A stage shared with other designs learns from all of
+                // their builds.
+                let family = commonality.family_of(rocket_project_id, gi, si);
+                let mut order = ManufacturingOrder::new_stage(
                     order_id,
                     rocket_project_id,
                     gi, si,
                     stage_name,
-                    stage.structural_mass_kg,
-                    rocket_prior,
+                    stage.structural_mass_kg + stage.long_coast_kit_mass_kg() + stage.landing_kit_mass_kg(),
+                    family.map_or(rocket_prior, |f| f.builds),
                     balance_cfg,
                 );
+                if family.is_some() {
+                    order.apply_commonality(&balance_cfg.commonality);
+                }
+                // The fairing rides on its stage's order; a recovered one
+                // only needs refurbishing.
+                if let Some(fairing) = &stage.fairing {
+                    let mut fairing_cost = crate::resources::fairing_material_cost(
+                        fairing.mass_kg, &balance_cfg.costs.resource_prices);
+                    if self.manufacturing.take_recovered_fairing(rocket_project_id) {
+                        fairing_cost *= balance_cfg.recovery.fairing_refurbish_fraction;
+                    }
+                    order.material_cost += fairing_cost;
+                }
+                order.material_cost += crate::resources::abort_system_material_cost(
+                    stage.launch_abort_system_mass_kg(), &balance_cfg.costs.resource_prices);
                 total_cost += order.material_cost;
                 self.manufacturing.orders.push(order);
             }
@@ -723,11 +1582,21 @@ impl Company {
             rocket_prior,
             rp.revision,
             rp.flaws.clone(),
+            Some(rp.design.clone()),
             balance_cfg,
         );
         total_cost += integration_order.material_cost;
         self.manufacturing.orders.push(integration_order);
 
+        if !self.budgets.allows(Department::Manufacturing, total_cost) {
+            if let Some((manufacturing, engine_counts, contracted_counts)) = rollback {
+                self.manufacturing = manufacturing;
+                self.engine_build_counts = engine_counts;
+                self.contracted_engine_build_counts = contracted_counts;
+            }
+            return None;
+        }
+
         // Increment rocket build count
         *self.rocket_build_counts.entry(design_id).or_insert(0) += 1;
 
@@ -737,6 +1606,7 @@ impl Company {
 
         // Deduct costs
         self.money -= total_cost;
+        self.budgets.record(Department::Manufacturing, total_cost);
 
         // Reset idle notification since new orders were placed
         self.notified_manufacturing_idle = false;
@@ -747,7 +1617,232 @@ impl Company {
         }))
     }
 
+    /// Order recertification of an expired item in storage. Returns
+    /// the material cost and event, or None if the item isn't expired,
+    /// is already being recertified, or the Manufacturing budget is spent.
+    pub fn order_recertification(&mut self, item_id: InventoryItemId, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
+        let (item_name, build_cost, expired) = self.manufacturing.inventory.item_summary(item_id)?;
+        let pending = self.manufacturing.orders.iter().any(|o| matches!(&o.order_type,
+            crate::manufacturing::ManufacturingOrderType::Recertification { item_id: id, .. } if *id == item_id));
+        if !expired || pending {
+            return None;
+        }
+        let item_name = item_name.to_string();
+        let order_id = self.manufacturing.next_order_id();
+        let order = ManufacturingOrder::new_recertification(
+            order_id, item_id, item_name.clone(), build_cost, &balance_cfg.storage,
+        );
+        let cost = order.material_cost;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        self.manufacturing.orders.push(order);
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
+        self.notified_manufacturing_idle = false;
+        Some((cost, GameEvent::RecertificationOrdered { item_name, cost }))
+    }
+
+    /// Order a teardown inspection of the landed booster `item_id`.
+    /// Returns the material cost and event, or None if it isn't a
+    /// stored booster, its wear is already known, it's already queued,
+    /// or the Manufacturing budget is spent.
+    pub fn order_booster_inspection(&mut self, item_id: InventoryItemId, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
+        let stage = self.manufacturing.inventory.stages.iter().find(|s| s.item_id == item_id)?;
+        let pending = self.manufacturing.orders.iter().any(|o| matches!(&o.order_type,
+            crate::manufacturing::ManufacturingOrderType::BoosterInspection { item_id: id, .. } if *id == item_id));
+        if stage.booster.as_ref().is_none_or(|b| b.wear_revealed) || pending {
+            return None;
+        }
+        let (item_name, build_cost) = (stage.stage_name.clone(), stage.build_cost);
+        let order_id = self.manufacturing.next_order_id();
+        let order = ManufacturingOrder::new_booster_inspection(
+            order_id, item_id, item_name.clone(), build_cost, &balance_cfg.booster_reuse,
+        );
+        let cost = order.material_cost;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        self.manufacturing.orders.push(order);
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
+        self.notified_manufacturing_idle = false;
+        Some((cost, GameEvent::BoosterInspectionOrdered { item_name, cost }))
+    }
+
+    /// Order a pad-abort test of the abort system on rocket project
+    /// `project_index`. It costs the abort system it expends plus the
+    /// boilerplate and range. Returns the cost and event, or None if
+    /// the design has no abort system, isn't in testing, already has a
+    /// test queued, or the cost would break the Manufacturing budget.
+    pub fn order_pad_abort_test(&mut self, project_index: usize, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
+        let rp = self.rocket_projects.get(project_index)?;
+        if !rp.design.has_launch_abort_system()
+            || !matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. })
+        {
+            return None;
+        }
+        let rocket_project_id = rp.project_id;
+        let pending = self.manufacturing.orders.iter().any(|o| matches!(&o.order_type,
+            crate::manufacturing::ManufacturingOrderType::PadAbortTest { rocket_project_id: id, .. } if *id == rocket_project_id));
+        if pending {
+            return None;
+        }
+        let cfg = &balance_cfg.launch_abort;
+        let cost = cfg.pad_abort_test_cost + crate::resources::abort_system_material_cost(
+            crate::stage::LAUNCH_ABORT_SYSTEM_KG, &balance_cfg.costs.resource_prices);
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        let rocket_name = rp.design.name.clone();
+        let order_id = self.manufacturing.next_order_id();
+        self.manufacturing.orders.push(ManufacturingOrder::new_pad_abort_test(
+            order_id, rocket_project_id, rocket_name.clone(), cost, cfg,
+        ));
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
+        self.notified_manufacturing_idle = false;
+        Some((cost, GameEvent::PadAbortTestOrdered { rocket_name, cost }))
+    }
+
+    /// Order a retrofit bringing the stored rocket `item_id` up to its
+    /// project's current revision and design: the flaws fixed since it
+    /// was built, and any stages whose hardware changed. It costs
+    /// `retrofit.cost_fraction` of what those changes would cost made
+    /// new. Returns the cost and event, or None if the rocket is
+    /// current, already being retrofitted, its stage layout changed, or
+    /// the cost would break the Manufacturing budget.
+    pub fn order_retrofit(&mut self, item_id: InventoryItemId, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
+        use crate::manufacturing::ManufacturingOrderType;
+        let rocket = self.manufacturing.inventory.rockets.iter().find(|r| r.item_id == item_id)?;
+        let rp = self.rocket_projects.iter().find(|rp| rp.project_id == rocket.rocket_project_id)?;
+        if !matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }) {
+            return None;
+        }
+        let pending = self.manufacturing.orders.iter().any(|o| matches!(&o.order_type,
+            ManufacturingOrderType::Retrofit { item_id: id, .. } if *id == item_id));
+        let diff = rocket.design.as_ref().unwrap_or(&rp.design).diff(&rp.design)?;
+        let fixed_flaws = rocket.rocket_flaws.iter()
+            .filter(|f| !rp.flaws.iter().any(|cur| cur.id == f.id))
+            .count();
+        if pending || (diff.is_empty() && fixed_flaws == 0) {
+            return None;
+        }
+
+        let cfg = &balance_cfg.retrofit;
+        let prices = &balance_cfg.costs.resource_prices;
+        let stage_cost: f64 = diff.changed_stages.iter()
+            .map(|&(gi, si)| &rp.design.stage_groups[gi][si])
+            .map(|s| crate::resources::tank_material_cost(s.structural_mass_kg, prices)
+                + crate::resources::stage_assembly_cost(prices))
+            .sum();
+        let delta = stage_cost + fixed_flaws as f64 * cfg.flaw_rework_cost;
+        let cost = delta * cfg.cost_fraction;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
+        let total_stages = rp.design.stage_groups.iter().map(|g| g.len() as u32).sum();
+        let work = balance_cfg.work.rocket_integration_work(total_stages) * cfg.work_fraction;
+
+        let rocket_name = rocket.rocket_name.clone();
+        let event = GameEvent::RetrofitOrdered {
+            rocket_name: rocket_name.clone(),
+            from_revision: rocket.revision,
+            to_revision: rp.revision,
+            cost,
+        };
+        let (revision, flaws, design) = (rp.revision, rp.flaws.clone(), rp.design.clone());
+        let order_id = self.manufacturing.next_order_id();
+        let order = ManufacturingOrder::new_retrofit(
+            order_id, item_id, rocket_name, revision, flaws, design, work, cost,
+        );
+        self.manufacturing.orders.push(order);
+        self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
+        self.notified_manufacturing_idle = false;
+        Some((cost, event))
+    }
+
+    /// Drop anything queued against a stored item that's leaving
+    /// inventory: its recertification or inspection order and flight
+    /// preparation.
+    fn forget_inventory_item(&mut self, item_id: InventoryItemId) {
+        use crate::manufacturing::ManufacturingOrderType;
+        self.manufacturing.orders.retain(|o| !matches!(&o.order_type,
+            ManufacturingOrderType::Recertification { item_id: id, .. }
+            | ManufacturingOrderType::BoosterInspection { item_id: id, .. }
+            | ManufacturingOrderType::Retrofit { item_id: id, .. } if *id == item_id));
+        self.flight_preparations.retain(|p| p.rocket_item_id != item_id);
+    }
+
+    /// Scrap a stored engine, stage or rocket for a fraction of its
+    /// build cost. Returns the item's name and the money recovered
+    /// (not yet credited), or None if no such item is stored.
+    pub fn scrap_inventory_item(&mut self, item_id: InventoryItemId, cfg: &StorageConfig) -> Option<(String, f64)> {
+        let (name, build_cost) = self.manufacturing.inventory.remove_item(item_id)?;
+        self.forget_inventory_item(item_id);
+        Some((name, build_cost * cfg.scrap_recovery_fraction))
+    }
+
+    /// Take a stored rocket apart: its engines go back into inventory
+    /// and the rest is scrapped. The rocket kept no per-engine record,
+    /// so the engines carry their lineage's current revision, flaws and
+    /// improvements, and are valued at its latest unit cost. Returns
+    /// the rocket's name, the engines recovered and the scrap money
+    /// (not yet credited).
+    pub fn strip_rocket_engines(&mut self, item_id: InventoryItemId, cfg: &StorageConfig) -> Option<(String, u32, f64)> {
+        let rocket = self.manufacturing.inventory.rockets.iter().find(|r| r.item_id == item_id)?;
+        let rp = self.rocket_projects.iter().find(|rp| rp.project_id == rocket.rocket_project_id)?;
+        let mut engines = Vec::new();
+        for stage in rp.design.stage_groups.iter().flatten() {
+            let Some(source) = self.engine_source_for_id(stage.engine.id) else {
+                continue;
+            };
+            let (revision, improvements, unit_cost) = match source {
+                EngineSource::PlayerDesign(ep_id) => {
+                    let Some(ep) = self.find_engine_project(ep_id) else { continue };
+                    let cost = self.engine_cost_history.get(&ep_id)
+                        .and_then(|h| h.last().copied())
+                        .unwrap_or(0.0);
+                    (ep.revision, ep.improvements.iter().filter(|i| i.actualized).cloned().collect(), cost)
+                }
+                EngineSource::Contracted(ce_id) => {
+                    let Some(ce) = self.contracted_engines.iter().find(|ce| ce.id == ce_id) else { continue };
+                    (0, Vec::new(), ce.purchase_cost_per_unit)
+                }
+            };
+            for _ in 0..stage.engine_count {
+                engines.push((source, stage.engine.id, stage.engine.name.clone(),
+                    revision, improvements.clone(), unit_cost));
+            }
+        }
+
+        let rocket = self.manufacturing.inventory.take_rocket(item_id)?;
+        self.forget_inventory_item(item_id);
+        let engine_value: f64 = engines.iter().map(|e| e.5).sum();
+        let count = engines.len() as u32;
+        for (source, engine_id, engine_name, revision, improvements, build_cost) in engines {
+            let new_id = self.manufacturing.next_inventory_id();
+            let flaw_ids = self.engine_flaws(engine_id).iter().map(|f| f.id).collect();
+            self.manufacturing.inventory.engines.push(InventoryEngine {
+                item_id: new_id,
+                source,
+                engine_id,
+                engine_name,
+                build_cost,
+                revision,
+                flaw_ids,
+                improvements,
+                built_on: rocket.built_on,
+                expired: false,
+            });
+        }
+        let scrap = (rocket.build_cost - engine_value).max(0.0) * cfg.scrap_recovery_fraction;
+        Some((rocket.rocket_name, count, scrap))
+    }
+
     /// Order a standalone engine build for a player-designed engine project.
+    /// None if the project isn't Testing or the Manufacturing budget is spent.
     pub fn order_engine_build(&mut self, engine_project_index: usize, balance_cfg: &BalanceConfig) -> Option<(f64, GameEvent)> {
         if engine_project_index >= self.engine_projects.len() {
             return None;
@@ -764,7 +1859,7 @@ impl Company {
         let complexity = ep.complexity;
         let preset = ep.preset;
         let revision = ep.revision;
-        let flaws = ep.flaws.clone();
+        let flaw_ids = ep.flaws.iter().map(|f| f.id).collect();
         let improvements: Vec<_> = ep.improvements.iter().filter(|i| i.actualized).cloned().collect();
         let engine_prior = *self.engine_build_counts.get(&ep_id).unwrap_or(&0);
 
@@ -779,16 +1874,20 @@ impl Company {
             preset,
             engine_prior,
             revision,
-            flaws,
+            flaw_ids,
             improvements,
             balance_cfg,
         );
         let cost = order.material_cost;
+        if !self.budgets.allows(Department::Manufacturing, cost) {
+            return None;
+        }
         self.manufacturing.orders.push(order);
         *self.engine_build_counts.entry(ep_id).or_insert(0) += 1;
         // engine_cost_history is populated at engine-build completion so the
         // recorded cost includes labor in addition to materials.
         self.money -= cost;
+        self.budgets.record(Department::Manufacturing, cost);
         self.notified_manufacturing_idle = false;
 
         Some((cost, GameEvent::EngineBuildOrdered { engine_name }))
@@ -834,6 +1933,10 @@ impl Company {
             }
             None
         };
+        let oldest = |a: Option<GameDate>, b: Option<GameDate>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
 
         for order in &mut self.manufacturing.orders {
             if !order.waiting_for_prerequisites {
@@ -862,6 +1965,7 @@ impl Company {
                                     for _ in 0..stage.engine_count {
                                         if let Some(eng) = self.manufacturing.inventory.take_engine(source) {
                                             order.material_cost += eng.build_cost;
+                                            order.oldest_component = oldest(order.oldest_component, eng.built_on);
                                         }
                                     }
                                 }
@@ -872,25 +1976,33 @@ impl Company {
                 crate::manufacturing::ManufacturingOrderType::RocketIntegration {
                     rocket_project_id, ..
                 } => {
+                    let rocket_project_id = *rocket_project_id;
                     // Integration needs all stages
                     if let Some(rp) = self.rocket_projects.iter()
-                        .find(|rp| rp.project_id == *rocket_project_id)
+                        .find(|rp| rp.project_id == rocket_project_id)
                     {
                         let all_stages_ready = rp.design.stage_groups.iter().enumerate().all(|(gi, group)| {
                             group.iter().enumerate().all(|(si, _stage)| {
-                                self.manufacturing.inventory.stage_count(*rocket_project_id, gi, si) >= 1
+                                self.manufacturing.inventory.stage_count(rocket_project_id, gi, si) >= 1
                             })
                         });
                         if all_stages_ready {
                             order.waiting_for_prerequisites = false;
-                            // Consume stages from inventory, accumulating their build cost
+                            // Consume stages from inventory, accumulating their
+                            // build cost; landed boosters bring their history.
+                            let mut flown = Vec::new();
                             for (gi, group) in rp.design.stage_groups.iter().enumerate() {
                                 for (si, _stage) in group.iter().enumerate() {
-                                    if let Some(stg) = self.manufacturing.inventory.take_stage(*rocket_project_id, gi, si) {
+                                    if let Some(stg) = self.manufacturing.inventory.take_stage(rocket_project_id, gi, si) {
                                         order.material_cost += stg.build_cost;
+                                        order.oldest_component = oldest(order.oldest_component, stg.built_on);
+                                        flown.extend(stg.booster.map(|life| (si, life)));
                                     }
                                 }
                             }
+                            if let crate::manufacturing::ManufacturingOrderType::RocketIntegration { boosters, .. } = &mut order.order_type {
+                                *boosters = flown;
+                            }
                         }
                     }
                 }
@@ -939,21 +2051,18 @@ impl Company {
         self.manufacturing.orders.iter().any(|o| !o.waiting_for_prerequisites)
     }
 
-    /// Auto-assign idle manufacturing teams to the order with the fewest teams.
-    pub fn auto_assign_idle_manufacturing_teams(&mut self) {
-        loop {
-            if self.unassigned_manufacturing_team_count() == 0 {
-                break;
-            }
-            // Find the non-waiting order with the fewest teams assigned
+    /// Auto-assign idle manufacturing teams, largest first, each to the
+    /// order with the smallest crew so headcount evens out across orders.
+    pub fn auto_assign_idle_manufacturing_teams(&mut self, costs: &crate::balance_config::CostsConfig) {
+        while let Some(size) = self.largest_idle_team_size() {
+            // Find the non-waiting order with the smallest crew assigned
             let best = self.manufacturing.orders.iter().enumerate()
                 .filter(|(_, o)| !o.waiting_for_prerequisites)
-                .min_by_key(|(_, o)| o.teams_assigned)
+                .min_by(|(_, a), (_, b)| a.team_mix().crew(costs).total_cmp(&b.team_mix().crew(costs)))
                 .map(|(i, _)| i);
             match best {
                 Some(idx) => {
-                    let available = self.unassigned_manufacturing_team_count();
-                    self.manufacturing.add_team_to_order(idx, available);
+                    self.add_sized_team_to_manufacturing_order(idx, size);
                 }
                 None => break,
             }
@@ -1058,11 +2167,50 @@ impl Company {
             .map(|(i, o)| (i, o.order_type.display_name()));
 
         let (idx, name) = best?;
-        self.manufacturing.orders[idx].teams_assigned -= 1;
-        self.manufacturing.orders[target].teams_assigned += 1;
+        let size = self.manufacturing.orders[idx].release_team()?;
+        self.manufacturing.orders[target].add_team(size);
         Some(name)
     }
 
+    /// The lineage `source` belongs to: the engine its chain of scaled
+    /// variants started from, or `source` itself.
+    pub fn engine_lineage(&self, source: EngineSource) -> EngineSource {
+        let EngineSource::PlayerDesign(mut id) = source else { return source };
+        // Bounded, so a save that links a variant back on itself can't
+        // hang the walk.
+        for _ in 0..self.engine_projects.len() {
+            match self.find_engine_project(id).and_then(|ep| ep.heritage.as_ref()) {
+                Some(heritage) => id = heritage.parent,
+                None => break,
+            }
+        }
+        EngineSource::PlayerDesign(id)
+    }
+
+    /// Stage families shared across the company's rocket designs: the
+    /// stages that build for less and learn together (see
+    /// `manufacturing::CommonalityAnalysis`).
+    pub fn commonality_analysis(&self, balance_cfg: &BalanceConfig) -> CommonalityAnalysis {
+        let mut stages = Vec::new();
+        for rp in &self.rocket_projects {
+            let builds = *self.rocket_build_counts.get(&rp.design.id).unwrap_or(&0);
+            for (gi, group) in rp.design.stage_groups.iter().enumerate() {
+                for (si, stage) in group.iter().enumerate() {
+                    let Some(source) = self.engine_source_for_id(stage.engine.id) else { continue };
+                    stages.push(CommonStage {
+                        rocket_project_id: rp.project_id,
+                        group_index: gi,
+                        stage_index: si,
+                        key: CommonalityKey::new(self.engine_lineage(source), stage.diameter_m()),
+                        engine_name: stage.engine.name.clone(),
+                        builds,
+                    });
+                }
+            }
+        }
+        CommonalityAnalysis::of(stages, balance_cfg.commonality.min_designs)
+    }
+
     /// Look up the EngineSource for an engine by its EngineId.
     pub fn engine_source_for_id(&self, engine_id: EngineId) -> Option<EngineSource> {
         // Check player engine projects first
@@ -1080,6 +2228,231 @@ impl Company {
         None
     }
 
+    /// Shelf life of stored hardware built around `engine_id`.
+    fn engine_shelf_life(&self, engine_id: EngineId, cfg: &StorageConfig) -> Option<u32> {
+        self.engine_projects.iter()
+            .find(|ep| ep.design.id == engine_id)
+            .map(|ep| &ep.design)
+            .or_else(|| self.contracted_engines.iter()
+                .find(|ce| ce.design.id == engine_id)
+                .map(|ce| &ce.design))
+            .and_then(|design| crate::manufacturing::shelf_life_days(design, cfg))
+    }
+
+    /// Shelf life of a rocket project's hardware: that of its
+    /// shortest-lived stage, or of one stage if `stage` is given.
+    fn rocket_shelf_life(
+        &self, project_id: RocketProjectId, stage: Option<(usize, usize)>, cfg: &StorageConfig,
+    ) -> Option<u32> {
+        let rp = self.rocket_projects.iter().find(|rp| rp.project_id == project_id)?;
+        rp.design.stage_groups.iter().enumerate()
+            .flat_map(|(gi, g)| g.iter().enumerate().map(move |(si, s)| ((gi, si), s)))
+            .filter(|(at, _)| stage.is_none_or(|want| want == *at))
+            .filter_map(|(_, s)| crate::manufacturing::shelf_life_days(&s.engine, cfg))
+            .min()
+    }
+
+    /// The first day a piece of stored hardware not yet flagged passes
+    /// its shelf life, if any will.
+    pub fn next_hardware_expiry(&self, cfg: &StorageConfig) -> Option<GameDate> {
+        let inventory = &self.manufacturing.inventory;
+        let engines = inventory.engines.iter()
+            .map(|e| (e.built_on, e.expired, self.engine_shelf_life(e.engine_id, cfg)));
+        let stages = inventory.stages.iter().map(|s| (
+            s.built_on, s.expired,
+            self.rocket_shelf_life(s.rocket_project_id, Some((s.group_index, s.stage_index)), cfg),
+        ));
+        let rockets = inventory.rockets.iter()
+            .map(|r| (r.built_on, r.expired, self.rocket_shelf_life(r.rocket_project_id, None, cfg)));
+        engines.chain(stages).chain(rockets)
+            .filter(|(_, expired, _)| !expired)
+            .filter_map(|(built_on, _, life)| Some(built_on?.add_days(life? + 1)))
+            .min()
+    }
+
+    /// Age stored hardware by one day: stamp new arrivals, and flag
+    /// (once) whatever has passed its shelf life.
+    pub fn tick_inventory_aging(&mut self, date: GameDate, cfg: &StorageConfig) -> Vec<GameEvent> {
+        let engine_lives: Vec<Option<u32>> = self.manufacturing.inventory.engines.iter()
+            .map(|e| self.engine_shelf_life(e.engine_id, cfg))
+            .collect();
+        let stage_lives: Vec<Option<u32>> = self.manufacturing.inventory.stages.iter()
+            .map(|s| self.rocket_shelf_life(s.rocket_project_id, Some((s.group_index, s.stage_index)), cfg))
+            .collect();
+        let rocket_lives: Vec<Option<u32>> = self.manufacturing.inventory.rockets.iter()
+            .map(|r| self.rocket_shelf_life(r.rocket_project_id, None, cfg))
+            .collect();
+
+        let mut events = Vec::new();
+        let mut age = |name: &str, built_on: &mut Option<GameDate>, expired: &mut bool, life: Option<u32>| {
+            let age_days = built_on.get_or_insert(date).days_until(&date);
+            if !*expired && life.is_some_and(|life| age_days > life) {
+                *expired = true;
+                events.push(GameEvent::HardwareExpired { item_name: name.to_string(), age_days });
+            }
+        };
+        let inventory = &mut self.manufacturing.inventory;
+        for (e, life) in inventory.engines.iter_mut().zip(engine_lives) {
+            age(&e.engine_name, &mut e.built_on, &mut e.expired, life);
+        }
+        for (s, life) in inventory.stages.iter_mut().zip(stage_lives) {
+            age(&s.stage_name, &mut s.built_on, &mut s.expired, life);
+        }
+        for (r, life) in inventory.rockets.iter_mut().zip(rocket_lives) {
+            age(&r.rocket_name, &mut r.built_on, &mut r.expired, life);
+        }
+        events
+    }
+
+    /// Floor-space units taken up by hardware in storage.
+    pub fn storage_units(&self, cfg: &StorageConfig) -> f64 {
+        let inventory = &self.manufacturing.inventory;
+        let rocket_stages: usize = inventory.rockets.iter()
+            .map(|r| self.rocket_projects.iter()
+                .find(|rp| rp.project_id == r.rocket_project_id)
+                .map_or(1, |rp| rp.design.stage_groups.iter().map(|g| g.len()).sum()))
+            .sum();
+        inventory.engines.len() as f64 * cfg.engine_units
+            + (inventory.stages.len() + rocket_stages) as f64 * cfg.stage_units
+    }
+
+    /// Monthly storage fee: hardware is kept free in idle factory floor
+    /// space, and the overflow goes to a rented warehouse.
+    pub fn monthly_storage_cost(&self, cfg: &StorageConfig) -> f64 {
+        let idle = self.manufacturing.floor_space_available() as f64;
+        (self.storage_units(cfg) - idle).max(0.0) * cfg.monthly_cost_per_unit
+    }
+
+    /// The flaws of an engine lineage (player project or contracted
+    /// engine). Every design flying the engine shares this one list, so
+    /// a discovery or fix on it applies to all of them.
+    pub fn engine_flaws(&self, engine_id: EngineId) -> &[Flaw] {
+        self.engine_projects.iter()
+            .find(|ep| ep.design.id == engine_id)
+            .map(|ep| ep.flaws.as_slice())
+            .or_else(|| self.contracted_engines.iter()
+                .find(|ce| ce.design.id == engine_id)
+                .map(|ce| ce.flaws.as_slice()))
+            .unwrap_or(&[])
+    }
+
+    /// Names of the rocket designs that fly `engine_id`.
+    pub fn designs_using_engine(&self, engine_id: EngineId) -> Vec<String> {
+        self.rocket_projects.iter()
+            .filter(|rp| rp.design.uses_engine(engine_id))
+            .map(|rp| rp.design.name.clone())
+            .collect()
+    }
+
+    /// Discovered engine flaws on every distinct engine a design flies,
+    /// as (engine name, flaw) pairs.
+    pub fn known_engine_flaws(&self, design: &RocketDesign) -> Vec<(String, &Flaw)> {
+        let mut seen: Vec<EngineId> = Vec::new();
+        let mut out = Vec::new();
+        for stage in design.stage_groups.iter().flatten() {
+            if seen.contains(&stage.engine.id) {
+                continue;
+            }
+            seen.push(stage.engine.id);
+            out.extend(self.engine_flaws(stage.engine.id).iter()
+                .filter(|f| f.discovered)
+                .map(|f| (stage.engine.name.clone(), f)));
+        }
+        out
+    }
+
+    /// Per-subsystem launch risk from the known (discovered, unfixed)
+    /// engine flaws on a design, scaled by engine count per stage, plus
+    /// `rocket_flaws`' discovered ones. Undiscovered flaws stay hidden.
+    pub fn known_flaw_risk(
+        &self, design: &RocketDesign, rocket_flaws: &[Flaw],
+    ) -> Vec<(Subsystem, f64)> {
+        let mut chances = Vec::new();
+        for stage in design.stage_groups.iter().flatten() {
+            for flaw in self.engine_flaws(stage.engine.id).iter().filter(|f| f.discovered) {
+                let p = 1.0 - (1.0 - flaw.activation_chance).powi(stage.engine_count as i32);
+                chances.push((flaw.subsystem, p));
+            }
+        }
+        chances.extend(rocket_flaws.iter()
+            .filter(|f| f.discovered)
+            .map(|f| (f.subsystem, f.activation_chance)));
+        crate::flaw::risk_by_subsystem(chances)
+    }
+
+    /// Estimated per-flight risk at each launch event for `design`,
+    /// with how much of each estimate rests on evidence. Counts known
+    /// flaws, flaws telemetry has hinted at, and — for the company's
+    /// own engines and for `project`, the design's rocket project if
+    /// it has one — the flaws testing hasn't found yet. A design with
+    /// no project is estimated as if it were starting testing.
+    pub fn estimated_flaw_risk(
+        &self,
+        design: &RocketDesign,
+        project: Option<&RocketProject>,
+        balance_cfg: &BalanceConfig,
+    ) -> Vec<crate::flaw::EventRisk> {
+        let cycles = |work: f64| (work / balance_cfg.work.testing_cycle_work) as u32;
+        let mut components = Vec::new();
+        for stage in design.stage_groups.iter().flatten() {
+            let engine_project = self.engine_projects.iter().find(|ep| ep.design.id == stage.engine.id);
+            let (prior, tested) = match engine_project {
+                Some(ep) => (
+                    Some(crate::balance::effective_complexity(ep.design.cycle, &ep.preset.propellants())),
+                    cycles(ep.cumulative_testing_work),
+                ),
+                None => (None, 0),
+            };
+            components.extend(crate::flaw::risk_components(
+                self.engine_flaws(stage.engine.id), stage.engine_count, prior, tested,
+            ));
+        }
+        components.extend(match project {
+            Some(rp) => crate::flaw::risk_components(
+                &rp.flaws, 1, Some(rp.complexity), cycles(rp.cumulative_testing_work),
+            ),
+            None => crate::flaw::risk_components(
+                &[], 1, Some(crate::rocket_project::design_complexity(design)), 0,
+            ),
+        });
+        // Each hot-staged boundary brings a separation flaw of its own.
+        let tested = project.map_or(0, |rp| cycles(rp.cumulative_testing_work));
+        let hot_prior = crate::flaw::expected_hidden_activation(tested);
+        components.extend((0..design.hot_staged_boundaries()).map(|_| {
+            (crate::flaw::Subsystem::Separation, hot_prior, crate::flaw::RiskEvidence::Prior)
+        }));
+        crate::flaw::estimate_event_risks(components)
+    }
+
+    /// Expected number of flaws testing hasn't found yet on `design`:
+    /// each of the company's own engine designs on it once, plus the
+    /// vehicle itself (`project`, or as if starting testing without
+    /// one). Bought-in engines come with no prior.
+    pub fn estimated_hidden_flaws(
+        &self,
+        design: &RocketDesign,
+        project: Option<&RocketProject>,
+        balance_cfg: &BalanceConfig,
+    ) -> f64 {
+        let mut engines: Vec<&EngineProject> = design.stage_groups.iter().flatten()
+            .filter_map(|stage| self.engine_projects.iter().find(|ep| ep.design.id == stage.engine.id))
+            .collect();
+        engines.sort_by_key(|ep| ep.project_id.0);
+        engines.dedup_by_key(|ep| ep.project_id);
+        let engine_flaws: f64 = engines.iter()
+            .map(|ep| {
+                let complexity = crate::balance::effective_complexity(ep.design.cycle, &ep.preset.propellants());
+                complexity as f64 * crate::flaw::hidden_survival(ep.testing_cycles(balance_cfg))
+            })
+            .sum();
+        let (complexity, cycles) = match project {
+            Some(rp) => (rp.complexity, (rp.cumulative_testing_work / balance_cfg.work.testing_cycle_work) as u32),
+            None => (crate::rocket_project::design_complexity(design), 0),
+        };
+        let hot_staging = design.hot_staged_boundaries() as f64;
+        engine_flaws + (complexity as f64 + hot_staging) * crate::flaw::hidden_survival(cycles)
+    }
+
     /// One day of R&D across this company's engine / rocket / reactor
     /// project lists: daily work, flaw discovery, revisions, and NRE
     /// accrual. Extracted from `advance_day` (M3 hygiene) so scripted
@@ -1090,6 +2463,19 @@ impl Company {
         &mut self,
         rng: &mut rand::rngs::StdRng,
         balance_cfg: &BalanceConfig,
+    ) -> ResearchTick {
+        self.tick_research(1, rng, balance_cfg)
+    }
+
+    /// `days` days of R&D at today's assignments and efficiencies in
+    /// one go. Only exact across a stretch no project reaches a
+    /// milestone in (see [`Self::research_quiet_for`]): past one, the
+    /// rest of the work lands as if on the day it was reached.
+    pub fn tick_research(
+        &mut self,
+        days: u32,
+        rng: &mut rand::rngs::StdRng,
+        balance_cfg: &BalanceConfig,
     ) -> ResearchTick {
         let mut events: Vec<GameEvent> = Vec::new();
         let mut newly_designed_engines: Vec<usize> = Vec::new();
@@ -1098,12 +2484,23 @@ impl Company {
         // Reactor equivalents (mirror the engine tech-deficiency flow).
         let mut newly_designed_reactors: Vec<usize> = Vec::new();
         let mut reactor_tech_def_attempts: Vec<(usize, crate::technology::TechDeficiencyId)> = Vec::new();
+        let mut milestones = 0;
+        let mut slots = self.assignment_efficiencies(&balance_cfg.morale).into_iter();
+        // A project works at the average efficiency of the teams on it.
+        let mut efficiency = |teams: u32| if teams == 0 {
+            0.0
+        } else {
+            slots.by_ref().take(teams as usize).sum::<f64>() / teams as f64 * days as f64
+        };
         let next_flaw_id = &mut self.next_flaw_id;
-        
 
         for (pi, project) in self.engine_projects.iter_mut().enumerate() {
             let engine_name = project.design.name.clone();
-            let work_events = project.apply_daily_work(rng, next_flaw_id, balance_cfg);
+            #[allow(unused_mut)]
+            let mut work_events = project.apply_daily_work(efficiency(project.teams_assigned), rng, next_flaw_id, balance_cfg);
+            #[cfg(any(feature = "qa", debug_assertions))]
+            work_events.extend(take_injected_work(&mut self.qa_work_events, project.project_id));
+            milestones += work_events.len() as u32;
             for we in work_events {
                 let evt = match we {
                     WorkEvent::DesignComplete { flaw_count } => {
@@ -1123,6 +2520,15 @@ impl Company {
                         tech_def_attempts.push((pi, deficiency_id));
                         continue;
                     }
+                    WorkEvent::AnomalyDetected { hints } =>
+                        GameEvent::AnomalyDetected { vehicle_name: engine_name.clone(), hints },
+                    WorkEvent::FundamentalIssue { description } =>
+                        GameEvent::EngineFundamentalIssue { engine_name: engine_name.clone(), description },
+                    we @ (WorkEvent::MoraleShift { .. } | WorkEvent::TeamResigned { .. }) =>
+                        match morale_game_event(we) {
+                            Some(evt) => evt,
+                            None => continue,
+                        },
                 };
                                     events.push(evt);
             }
@@ -1130,7 +2536,8 @@ impl Company {
 
         for project in &mut self.rocket_projects {
             let rocket_name = project.design.name.clone();
-            let work_events = project.apply_daily_work(rng, next_flaw_id, balance_cfg);
+            let work_events = project.apply_daily_work(efficiency(project.teams_assigned), rng, next_flaw_id, balance_cfg);
+            milestones += work_events.len() as u32;
             for we in work_events {
                 let evt = match we {
                     RocketWorkEvent::DesignComplete { flaw_count } =>
@@ -1140,6 +2547,12 @@ impl Company {
                         GameEvent::RocketFlawDiscovered { rocket_name: rocket_name.clone(), flaw_description },
                     RocketWorkEvent::RevisionComplete =>
                         GameEvent::RocketRevisionComplete { rocket_name: rocket_name.clone() },
+                    RocketWorkEvent::CertificationComplete =>
+                        GameEvent::CertificationGranted { rocket_name: rocket_name.clone() },
+                    RocketWorkEvent::SoftwareAdapted =>
+                        GameEvent::SoftwareAdapted { rocket_name: rocket_name.clone() },
+                    RocketWorkEvent::AnomalyDetected { hints } =>
+                        GameEvent::AnomalyDetected { vehicle_name: rocket_name.clone(), hints },
                 };
                                     events.push(evt);
             }
@@ -1150,7 +2563,8 @@ impl Company {
         // arrive in Phase 3.
         for (pi, project) in self.reactor_projects.iter_mut().enumerate() {
             let reactor_name = project.design.name.clone();
-            let work_events = project.apply_daily_work(rng, next_flaw_id, balance_cfg);
+            let work_events = project.apply_daily_work(efficiency(project.teams_assigned), rng, next_flaw_id, balance_cfg);
+            milestones += work_events.len() as u32;
             for we in work_events {
                 let evt = match we {
                     crate::reactor_project::ReactorWorkEvent::DesignComplete { flaw_count } => {
@@ -1175,8 +2589,62 @@ impl Company {
             }
         }
 
+        for project in &mut self.software_projects {
+            let software_name = project.name.clone();
+            let work_events = project.apply_daily_work(efficiency(project.teams_assigned), rng, next_flaw_id, balance_cfg);
+            milestones += work_events.len() as u32;
+            for we in work_events {
+                let evt = match we {
+                    SoftwareWorkEvent::DesignComplete { flaw_count } =>
+                        GameEvent::SoftwareDesignComplete { software_name: software_name.clone(), flaw_count },
+                    SoftwareWorkEvent::TestingCycleComplete => continue,
+                    SoftwareWorkEvent::FlawDiscovered { flaw_description } =>
+                        GameEvent::SoftwareFlawDiscovered { software_name: software_name.clone(), flaw_description },
+                    SoftwareWorkEvent::RevisionComplete =>
+                        GameEvent::SoftwarePatched { software_name: software_name.clone() },
+                };
+                events.push(evt);
+            }
+        }
+
+        for project in &mut self.depot_projects {
+            let depot_name = project.design.name.clone();
+            let work_events = project.apply_daily_work(efficiency(project.teams_assigned), rng, next_flaw_id, balance_cfg);
+            milestones += work_events.len() as u32;
+            for we in work_events {
+                let evt = match we {
+                    DepotWorkEvent::DesignComplete { flaw_count } =>
+                        GameEvent::DepotDesignComplete { depot_name: depot_name.clone(), flaw_count },
+                    DepotWorkEvent::TestingCycleComplete => continue,
+                    DepotWorkEvent::FlawDiscovered { flaw_description } =>
+                        GameEvent::DepotFlawDiscovered { depot_name: depot_name.clone(), flaw_description },
+                    DepotWorkEvent::RevisionComplete =>
+                        GameEvent::DepotRevisionComplete { depot_name: depot_name.clone() },
+                };
+                events.push(evt);
+            }
+        }
+
+        // Integration campaigns; drop any whose rocket has left inventory.
+        let rockets = &self.manufacturing.inventory.rockets;
+        self.flight_preparations.retain(|p| rockets.iter().any(|r| r.item_id == p.rocket_item_id));
+        for prep in &mut self.flight_preparations {
+            if prep.apply_daily_work(efficiency(prep.teams_assigned)) {
+                let rocket_name = rockets.iter()
+                    .find(|r| r.item_id == prep.rocket_item_id)
+                    .map(|r| r.rocket_name.clone())
+                    .unwrap_or_default();
+                events.push(GameEvent::IntegrationCampaignComplete {
+                    rocket_name,
+                    destination: prep.plan.destination.clone(),
+                });
+            }
+        }
+
+        events.extend(self.tick_external_reviews(days, rng, balance_cfg));
+
         // Accumulate NRE (engineering salary) on active projects
-        let daily_salary = balance_cfg.costs.engineering_monthly_salary / 30.0;
+        let daily_salary = balance_cfg.costs.engineering_monthly_salary / 30.0 * days as f64;
         for project in &mut self.engine_projects {
             if project.teams_assigned > 0 {
                 project.nre_cost += project.teams_assigned as f64 * daily_salary;
@@ -1192,6 +2660,16 @@ impl Company {
                 project.nre_cost += project.teams_assigned as f64 * daily_salary;
             }
         }
+        for project in &mut self.software_projects {
+            if project.teams_assigned > 0 {
+                project.nre_cost += project.teams_assigned as f64 * daily_salary;
+            }
+        }
+        for project in &mut self.depot_projects {
+            if project.teams_assigned > 0 {
+                project.nre_cost += project.teams_assigned as f64 * daily_salary;
+            }
+        }
 
         ResearchTick {
             events,
@@ -1199,7 +2677,253 @@ impl Company {
             tech_def_attempts,
             newly_designed_reactors,
             reactor_tech_def_attempts,
+            milestones,
+        }
+    }
+
+    /// Whether `days` days of R&D at today's rates cross no milestone:
+    /// no design completes, no testing cycle or revision finishes, no
+    /// campaign or external review wraps up. Checked on a copy with a
+    /// day's work to spare, so rounding can't slip one past.
+    pub fn research_quiet_for(&self, days: u32, balance_cfg: &BalanceConfig) -> bool {
+        use rand::SeedableRng;
+        let mut probe = self.clone();
+        // The draws only happen at a milestone, which already means no.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let tick = probe.tick_research(days + 1, &mut rng, balance_cfg);
+        tick.milestones == 0 && tick.events.is_empty()
+    }
+
+    /// Average engineering team morale; neutral with no teams.
+    pub fn average_morale(&self) -> f64 {
+        if self.teams.is_empty() {
+            return crate::team::NEUTRAL_MORALE;
+        }
+        self.teams.iter().map(|t| t.morale).sum::<f64>() / self.teams.len() as f64
+    }
+
+    /// Move every engineering team's morale by `cause`'s configured amount.
+    pub fn shift_morale(&mut self, cause: MoraleCause, cfg: &MoraleConfig) -> WorkEvent {
+        let delta = match cause {
+            MoraleCause::LaunchSuccess => cfg.launch_success,
+            MoraleCause::LaunchFailure => cfg.launch_failure,
+            MoraleCause::Crunch => cfg.crunch_per_day,
+            MoraleCause::Firing => cfg.firing,
+            MoraleCause::MissedPayroll => cfg.missed_payroll,
+        };
+        for team in &mut self.teams {
+            team.morale = (team.morale + delta).clamp(0.0, 1.0);
+        }
+        WorkEvent::MoraleShift { cause, average: self.average_morale() }
+    }
+
+    /// How much of a full day's work each engineering assignment gets
+    /// done, in the order `tick_daily_research` walks the projects.
+    /// Teams at work fill the assignments in roster order (furlough
+    /// takes from the back, so the idle teams are the last ones), which
+    /// keeps idle teams' morale off everyone's work. Crunch lifts them
+    /// all.
+    pub fn assignment_efficiencies(&self, cfg: &MoraleConfig) -> Vec<f64> {
+        let crunch = if self.crunch { 1.0 + cfg.crunch_bonus } else { 1.0 };
+        self.teams.iter()
+            .filter(|t| !t.furloughed)
+            .take(self.assigned_team_count() as usize)
+            .map(|t| t.efficiency(cfg) * crunch)
+            .collect()
+    }
+
+    /// A day of morale: drift toward neutral, the crunch drain, and a
+    /// resignation roll for each team below the attrition threshold.
+    pub fn tick_morale(&mut self, rng: &mut rand::rngs::StdRng, cfg: &MoraleConfig) -> Vec<WorkEvent> {
+        use rand::Rng;
+        for team in &mut self.teams {
+            team.recover_morale(1, cfg);
+            if team.furloughed {
+                continue;
+            }
+            team.ramp_up_days = team.ramp_up_days.saturating_sub(1);
+            if self.crunch {
+                team.morale = (team.morale + cfg.crunch_per_day).clamp(0.0, 1.0);
+            }
+        }
+        let mut events = Vec::new();
+        let mut i = 0;
+        while i < self.teams.len() {
+            let chance = self.teams[i].attrition_chance(cfg);
+            if chance > 0.0 && rng.gen::<f64>() < chance {
+                let team = self.teams.remove(i);
+                events.push(WorkEvent::TeamResigned { team_name: team.name });
+            } else {
+                i += 1;
+            }
+        }
+        if !events.is_empty() {
+            self.release_orphaned_assignments();
+        }
+        events
+    }
+
+    /// Whether morale can drift for days without a draw or a change in
+    /// anyone's work: no crunch, nobody at risk of resigning, and
+    /// the teams at work up to speed and at or above neutral, where
+    /// drifting doesn't touch efficiency.
+    pub fn morale_steady(&self, cfg: &MoraleConfig) -> bool {
+        !self.crunch && self.teams.iter().all(|t| {
+            t.attrition_chance(cfg) == 0.0
+                && (t.furloughed || (t.ramp_up_days == 0 && t.morale >= crate::team::NEUTRAL_MORALE))
+        })
+    }
+
+    /// Let the newest engineering team go. If that leaves more teams
+    /// assigned than employed, the most-staffed project loses one. The
+    /// rest take a morale hit.
+    pub fn fire_team(&mut self, cfg: &MoraleConfig) -> Option<(String, WorkEvent)> {
+        if self.teams.is_empty() {
+            return None;
+        }
+        let team = self.teams.pop()?;
+        self.release_orphaned_assignments();
+        Some((team.name, self.shift_morale(MoraleCause::Firing, cfg)))
+    }
+
+    /// A year of wage inflation: every team's pay, and what new teams
+    /// cost, rise by `rate`.
+    pub fn inflate_salaries(&mut self, rate: f64) {
+        self.wage_index *= 1.0 + rate;
+        for team in &mut self.teams {
+            team.monthly_salary *= 1.0 + rate;
+        }
+        for team in &mut self.manufacturing_teams {
+            team.monthly_salary *= 1.0 + rate;
         }
     }
 
+    /// Give the raise team `index` asked for. Returns its name and the
+    /// raise, or None if it hasn't asked.
+    pub fn accept_raise(&mut self, index: usize) -> Option<(String, f64)> {
+        let team = self.teams.get_mut(index)?;
+        let demand = team.raise_demand.take()?;
+        team.monthly_salary += demand.monthly_raise;
+        Some((team.name.clone(), demand.monthly_raise))
+    }
+
+    /// Turn down the raise team `index` asked for. The team takes it
+    /// badly and may walk out; returns its name and whether it quit,
+    /// or None if it hasn't asked.
+    pub fn refuse_raise(
+        &mut self,
+        index: usize,
+        rng: &mut rand::rngs::StdRng,
+        cfg: &crate::balance_config::PayrollConfig,
+    ) -> Option<(String, bool)> {
+        use rand::Rng;
+        let team = self.teams.get_mut(index)?;
+        team.raise_demand.take()?;
+        team.morale = (team.morale + cfg.refused_raise_morale).clamp(0.0, 1.0);
+        let name = team.name.clone();
+        let quit = rng.gen::<f64>() < cfg.refused_raise_quit_chance;
+        if quit {
+            self.teams.remove(index);
+            self.release_orphaned_assignments();
+        }
+        Some((name, quit))
+    }
+
+    /// Send the newest idle engineering team home on reduced pay. It
+    /// keeps its place on the roster, unlike a team that's let go.
+    /// Returns its name, or None if no team is idle.
+    pub fn furlough_team(&mut self) -> Option<String> {
+        if self.unassigned_team_count() == 0 {
+            return None;
+        }
+        let team = self.teams.iter_mut().rev().find(|t| !t.furloughed)?;
+        team.furloughed = true;
+        team.ramp_up_days = 0;
+        Some(team.name.clone())
+    }
+
+    /// Bring the longest-furloughed team back. It returns unhappy and
+    /// needs a while to get back up to speed. Returns its name, or None
+    /// if nobody is on furlough.
+    pub fn recall_team(&mut self, cfg: &MoraleConfig) -> Option<String> {
+        let team = self.teams.iter_mut().find(|t| t.furloughed)?;
+        team.furloughed = false;
+        team.morale = (team.morale + cfg.recall).clamp(0.0, 1.0);
+        team.ramp_up_days = cfg.recall_ramp_days;
+        Some(team.name.clone())
+    }
+
+    /// After teams leave, take assignments off the most-staffed projects
+    /// until no more teams are assigned than the company has at work.
+    fn release_orphaned_assignments(&mut self) {
+        while self.assigned_team_count() > self.active_team_count() {
+            let slots = self.engine_projects.iter_mut().map(|p| &mut p.teams_assigned)
+                .chain(self.rocket_projects.iter_mut().map(|p| &mut p.teams_assigned))
+                .chain(self.reactor_projects.iter_mut().map(|p| &mut p.teams_assigned))
+                .chain(self.software_projects.iter_mut().map(|p| &mut p.teams_assigned))
+                .chain(self.depot_projects.iter_mut().map(|p| &mut p.teams_assigned))
+                .chain(self.flight_preparations.iter_mut().map(|p| &mut p.teams_assigned));
+            match slots.max_by_key(|n| **n) {
+                Some(n) if *n > 0 => *n -= 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// Days until the external review of `target` reports, if one is
+    /// under way.
+    pub fn external_review_days(&self, target: ReviewTarget) -> Option<u32> {
+        self.external_reviews.iter().find(|r| r.target == target).map(|r| r.days_remaining)
+    }
+
+    /// Count down external reviews and deliver the findings of any that
+    /// are done. A design scrapped meanwhile gets an empty report.
+    fn tick_external_reviews(
+        &mut self,
+        days: u32,
+        rng: &mut rand::rngs::StdRng,
+        balance_cfg: &BalanceConfig,
+    ) -> Vec<GameEvent> {
+        let share = balance_cfg.external_review.discovery_fraction;
+        let mut events = Vec::new();
+        let mut done = Vec::new();
+        for review in &mut self.external_reviews {
+            review.days_remaining = review.days_remaining.saturating_sub(days);
+            if review.days_remaining == 0 {
+                done.push(review.clone());
+            }
+        }
+        self.external_reviews.retain(|r| r.days_remaining > 0);
+        for review in done {
+            let name = review.target_name;
+            let found: Vec<GameEvent> = match review.target {
+                ReviewTarget::Engine(id) => self.find_engine_project_mut(id)
+                    .map(|p| {
+                        let idx = crate::flaw::reveal_share(&mut p.flaws, share, rng);
+                        idx.into_iter().map(|i| GameEvent::FlawDiscovered {
+                            engine_name: name.clone(),
+                            flaw_description: p.flaws[i].description.clone(),
+                        }).collect()
+                    })
+                    .unwrap_or_default(),
+                ReviewTarget::Rocket(id) => self.rocket_projects.iter_mut()
+                    .find(|p| p.project_id == id)
+                    .map(|p| {
+                        let idx = crate::flaw::reveal_share(&mut p.flaws, share, rng);
+                        idx.into_iter().map(|i| GameEvent::RocketFlawDiscovered {
+                            rocket_name: name.clone(),
+                            flaw_description: p.flaws[i].description.clone(),
+                        }).collect()
+                    })
+                    .unwrap_or_default(),
+            };
+            events.push(GameEvent::ExternalReviewComplete {
+                target_name: name,
+                flaws_found: found.len() as u32,
+            });
+            events.extend(found);
+        }
+        events
+    }
+
 }
@@ -19,7 +19,7 @@ use crate::calendar::GameDate;
 use crate::contract::{Contract, ContractId};
 use crate::engine::{EngineDesign, EngineCycle, EngineId, PropellantFraction};
 use crate::engine_project::{EngineProject, EngineDesignStatus, EngineProjectId, PropellantPreset};
-use crate::flaw::{Flaw, FlawId, FlawConsequence, FlawTrigger};
+use crate::flaw::{Flaw, FlawId, FlawConsequence, FlawTrigger, Subsystem};
 use crate::game_state::Company;
 use crate::manufacturing::InventoryRocket;
 use crate::propellant::Propellant;
@@ -51,6 +51,9 @@ pub struct Competitor {
     pub failure_rate: f64,
     /// Awarded contracts awaiting their launch day.
     pub scheduled_launches: Vec<ScheduledLaunch>,
+    /// Player engine designs this competitor has copied (by name).
+    #[serde(default)]
+    pub copied_engines: Vec<String>,
 }
 
 impl Competitor {
@@ -61,6 +64,27 @@ impl Competitor {
         built.saturating_sub(self.scheduled_launches.len() as u32)
     }
 
+    /// Fold a copied player engine into the catalog vehicle: its
+    /// failure rate drops by `factor`, for the project's flaw and for
+    /// vehicles already on the shelf.
+    pub fn absorb_copied_engine(&mut self, engine_name: String, factor: f64) {
+        self.failure_rate *= factor;
+        let rate = self.failure_rate;
+        if let Some(rp) = self.company.rocket_projects.iter_mut()
+            .find(|rp| rp.project_id == self.rocket_project_id)
+        {
+            for flaw in &mut rp.flaws {
+                flaw.activation_chance = rate;
+            }
+        }
+        for rocket in &mut self.company.manufacturing.inventory.rockets {
+            for flaw in &mut rocket.rocket_flaws {
+                flaw.activation_chance = rate;
+            }
+        }
+        self.copied_engines.push(engine_name);
+    }
+
     /// Marginal cost of the catalog vehicle: mean of the last few real
     /// builds, falling back to the configured catalog estimate until
     /// manufacturing has produced one.
@@ -224,6 +248,8 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
             cumulative_testing_work: 0.0,
             tech_deficiency_ids: Vec::new(),
             technology_id: None,
+            heritage: None,
+            fundamental_issue: None,
         });
         // Mature product line: the learning curve starts well down.
         let ep_id = company.engine_projects.last().unwrap().project_id;
@@ -244,6 +270,11 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
                 structural_mass_kg: 26_000.0,
                 fairing: None,
                 power_sources: Vec::new(),
+                crossfeed: false,
+                long_coast_kit: false,
+                landing_kit: false,
+                launch_abort_system: false,
+                staging: Default::default(),
             }],
             vec![Stage {
                 id: StageId(20_002),
@@ -252,10 +283,16 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
                 engine_count: 1,
                 propellant_mass_kg: 27_000.0,
                 structural_mass_kg: 3_500.0,
-                fairing: Some(Fairing { mass_kg: 2_500.0, diameter_m: 5.1 }),
+                fairing: Some(Fairing { mass_kg: 2_500.0, diameter_m: 5.1, recover: false }),
                 power_sources: Vec::new(),
+                crossfeed: false,
+                long_coast_kit: true,
+                landing_kit: false,
+                launch_abort_system: false,
+                staging: Default::default(),
             }],
         ],
+        performance_reserve: Some(0.0),
     };
 
     // Exactly one permanent loss-of-vehicle flaw carrying the seeded
@@ -269,6 +306,8 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
         discovery_probability: 0.0,
         discovered: false,
         trigger: FlawTrigger::PerFlight,
+        subsystem: Subsystem::Separation,
+        anomaly: None,
     };
     company.next_flaw_id += 1;
 
@@ -299,6 +338,11 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
             build_cost: cfg.catalog_cost,
             revision: 0,
             rocket_flaws: vec![flaw.clone()],
+            design: None,
+            built_on: None,
+            expired: false,
+            propellant_load: Default::default(),
+            boosters: Vec::new(),
         });
     }
 
@@ -308,6 +352,7 @@ pub fn realize_dinosoar(seed: &GameSeed, balance: &BalanceConfig) -> Competitor
         design_id,
         failure_rate,
         scheduled_launches: Vec::new(),
+        copied_engines: Vec::new(),
     }
 }
 
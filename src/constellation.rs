@@ -0,0 +1,139 @@
+//! Satellite constellations. A constellation is the set of spacecraft
+//! of one design parked at one location, with a required count. It
+//! earns in proportion to how many of the required slots working
+//! satellites fill, and plans replacements from each member's
+//! projected wear-out date: a "replacement needed by" task for every
+//! slot that will fall empty inside the planning horizon. With
+//! auto-replace on, a due task launches a satellite from inventory.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::ConstellationConfig;
+use crate::calendar::GameDate;
+use crate::rocket_project::RocketProjectId;
+
+/// Unique identifier for a constellation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConstellationId(pub u64);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Constellation {
+    pub id: ConstellationId,
+    pub name: String,
+    pub location: String,
+    /// The satellite design; spacecraft of this lineage parked at
+    /// `location` are the members.
+    pub rocket_project_id: RocketProjectId,
+    pub required: u32,
+    /// Launch a replacement from inventory when a task is open.
+    #[serde(default)]
+    pub auto_replace: bool,
+    /// Lifetime revenue.
+    #[serde(default)]
+    pub revenue_earned: f64,
+    /// Replacement tasks open as of yesterday, to spot new ones.
+    #[serde(default)]
+    pub open_tasks: usize,
+}
+
+impl Constellation {
+    /// Share of the required slots `operational` satellites fill.
+    pub fn completeness(&self, operational: usize) -> f64 {
+        if self.required == 0 {
+            return 1.0;
+        }
+        (operational as f64 / self.required as f64).min(1.0)
+    }
+
+    /// A day's revenue with `operational` satellites working.
+    pub fn daily_revenue(&self, operational: usize, cfg: &ConstellationConfig) -> f64 {
+        cfg.revenue_per_satellite_per_day * self.required as f64 * self.completeness(operational)
+    }
+}
+
+/// One planned replacement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplacementTask {
+    pub constellation_id: ConstellationId,
+    pub constellation_name: String,
+    pub needed_by: GameDate,
+}
+
+/// Why a constellation couldn't be formed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstellationError {
+    NoSuchDesign,
+    UnknownLocation(String),
+    NoSlots,
+    /// That design at that location already makes up a constellation.
+    AlreadyFormed(String),
+}
+
+impl fmt::Display for ConstellationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstellationError::NoSuchDesign => write!(f, "No such satellite design"),
+            ConstellationError::UnknownLocation(loc) => write!(f, "Unknown location {}", loc),
+            ConstellationError::NoSlots => write!(f, "A constellation needs at least one satellite"),
+            ConstellationError::AlreadyFormed(name) => write!(f, "Already flying as {}", name),
+        }
+    }
+}
+
+/// The dates replacements are needed by, earliest first, for a
+/// constellation of `required` slots whose working members have
+/// `healths` and with `inbound` satellites already on their way.
+/// Slots already empty are needed today; after that each member's
+/// wear-out date counts once the spares run out. Dates past
+/// `horizon_days` aren't planned yet.
+pub fn replacement_dates(
+    today: GameDate,
+    healths: &[f64],
+    inbound: usize,
+    required: u32,
+    wear_per_day: f64,
+    horizon_days: u32,
+) -> Vec<GameDate> {
+    let working = healths.iter().filter(|&&h| h > 0.0).count();
+    // Without wear, working members never need replacing.
+    let mut days_left: Vec<u32> = if wear_per_day > 0.0 {
+        healths.iter()
+            .filter(|&&h| h > 0.0)
+            .map(|h| (h / wear_per_day).ceil() as u32)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    days_left.sort_unstable();
+    let supply = working + inbound;
+    let required = required as usize;
+    let mut dates = vec![today; required.saturating_sub(supply)];
+    dates.extend(days_left.into_iter()
+        .skip(supply.saturating_sub(required))
+        .take_while(|&d| d <= horizon_days)
+        .map(|d| today.add_days(d)));
+    dates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replacements_cover_empty_slots_then_wear_outs_past_the_spares() {
+        let today = GameDate::new(2001, 1, 1);
+        // Three slots, two working: one replacement now, then the next
+        // member due to wear out inside the horizon.
+        let dates = replacement_dates(today, &[0.01, 0.5], 0, 3, 0.001, 30);
+        assert_eq!(dates, vec![today, today.add_days(10)]);
+        // An inbound satellite fills the empty slot.
+        let dates = replacement_dates(today, &[0.01, 0.5], 1, 3, 0.001, 30);
+        assert_eq!(dates, vec![today.add_days(10)]);
+        // A spare covers the first wear-out.
+        let dates = replacement_dates(today, &[0.01, 0.02, 0.5], 1, 3, 0.001, 30);
+        assert_eq!(dates, vec![today.add_days(20)]);
+        assert!(replacement_dates(today, &[0.5, 0.5], 0, 2, 0.0, 30).is_empty());
+    }
+}
@@ -0,0 +1,463 @@
+//! Data-driven content packs: extra delta-v map nodes and edges,
+//! contract templates, flaw catalog entries, and propellant stat
+//! overrides, read from `content/*.toml` at startup.
+//!
+//! Each pack file is validated against the built-in content plus the
+//! packs accepted before it (files load in name order). A file that
+//! fails to parse or validate is skipped whole with a warning, so a
+//! broken mod leaves the game playing the vanilla content rather than
+//! refusing to start.
+//!
+//! Propellants are a closed set — engine cycles hard-code their
+//! mixtures — so a pack retunes an existing propellant's stats rather
+//! than adding a new one.
+//!
+//! Map and flaw content is global: [`install`] it once, before anything
+//! touches [`crate::location::DELTA_V_MAP`]. Contract templates are
+//! appended to the balance's market archetypes instead
+//! ([`ContentPack::apply_to_balance`]), so a save keeps the markets it
+//! was started with.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::BalanceConfig;
+use crate::contract::MarketArchetype;
+use crate::flaw::{FlawConsequence, FlawTrigger, Subsystem};
+use crate::location::{self, DeltaVMap, Location, LocationType, SurfaceProperties};
+use crate::propellant::Propellant;
+
+/// Directory (relative to the working directory) scanned for pack files.
+pub const CONTENT_DIR: &str = "content";
+
+/// One content file's worth of additions. Every section is optional.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContentPack {
+    pub propellants: Vec<PropellantStats>,
+    pub locations: Vec<LocationDef>,
+    pub transfers: Vec<TransferDef>,
+    /// Market archetypes, in the same shape as `[[markets.archetypes]]`
+    /// in a balance file.
+    pub contracts: Vec<MarketArchetype>,
+    pub flaws: Vec<FlawEntry>,
+}
+
+/// Stat overrides for a built-in propellant. Unset fields keep the
+/// built-in value; when several packs retune the same propellant the
+/// last one loaded wins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PropellantStats {
+    pub propellant: Propellant,
+    #[serde(default)]
+    pub density_kg_per_l: Option<f64>,
+    #[serde(default)]
+    pub cost_per_kg: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationKind {
+    Orbit,
+    LagrangePoint,
+    Surface,
+}
+
+/// A new node in the delta-v graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocationDef {
+    pub id: String,
+    pub display_name: String,
+    pub short_name: String,
+    /// Body the node belongs to (`"sun"` for heliocentric nodes).
+    pub parent_body: String,
+    pub kind: LocationKind,
+    /// Required for (and only allowed on) surfaces.
+    #[serde(default)]
+    pub surface: Option<SurfaceDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurfaceDef {
+    pub gravity_m_s2: f64,
+    pub radius_m: f64,
+    /// Surface air density in kg/m³; 0 = airless.
+    #[serde(default)]
+    pub atmosphere_density: f64,
+    #[serde(default)]
+    pub ambient_pressure_pa: f64,
+}
+
+/// How a content edge behaves, mirroring the built-in graph helpers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferKind {
+    /// High-thrust only.
+    Impulsive,
+    /// Usable by low-thrust vehicles too (at `low_thrust_delta_v`).
+    Spiral,
+    /// Launch/landing between a surface (`from`) and its orbit (`to`).
+    Ground,
+}
+
+/// A new symmetric edge pair in the delta-v graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferDef {
+    pub from: String,
+    pub to: String,
+    pub delta_v: f64,
+    #[serde(default)]
+    pub transit_days: u32,
+    pub kind: TransferKind,
+    /// Spiral: the low-thrust cost (unset = same as `delta_v`).
+    /// Ground: setting it opens the edge to low-thrust vehicles.
+    #[serde(default)]
+    pub low_thrust_delta_v: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlawSeverity {
+    Performance,
+    EngineLoss,
+    StageLoss,
+}
+
+impl FlawSeverity {
+    fn of(consequence: &FlawConsequence) -> Self {
+        match consequence {
+            FlawConsequence::PerformanceDegradation(_) => FlawSeverity::Performance,
+            FlawConsequence::EngineLoss => FlawSeverity::EngineLoss,
+            FlawConsequence::StageLoss => FlawSeverity::StageLoss,
+        }
+    }
+}
+
+/// An extra description in the chemical-engine flaw catalog:
+/// per-flight flaws, or endurance flaws with `trigger = "PerDay"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FlawEntry {
+    pub description: String,
+    pub subsystem: Subsystem,
+    pub severity: FlawSeverity,
+    #[serde(default)]
+    pub trigger: FlawTrigger,
+}
+
+impl ContentPack {
+    pub fn is_empty(&self) -> bool {
+        *self == ContentPack::default()
+    }
+
+    /// Parse one pack file's text.
+    pub fn from_toml_str(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Append `other`'s entries after this pack's.
+    pub fn merge(&mut self, other: ContentPack) {
+        self.propellants.extend(other.propellants);
+        self.locations.extend(other.locations);
+        self.transfers.extend(other.transfers);
+        self.contracts.extend(other.contracts);
+        self.flaws.extend(other.flaws);
+    }
+
+    /// Check the pack against the built-in map and `balance`'s markets.
+    pub fn validate(&self, balance: &BalanceConfig) -> Result<(), String> {
+        let base = DeltaVMap::earth_moon();
+
+        for p in &self.propellants {
+            for (name, value) in [("density_kg_per_l", p.density_kg_per_l), ("cost_per_kg", p.cost_per_kg)] {
+                if let Some(v) = value {
+                    if !(v.is_finite() && v > 0.0) {
+                        return Err(format!("propellant {:?}: {name} {v} must be positive", p.propellant));
+                    }
+                }
+            }
+        }
+
+        let mut new_ids = HashSet::new();
+        for l in &self.locations {
+            if l.id.is_empty() || l.short_name.is_empty() {
+                return Err("location with an empty id or short_name".into());
+            }
+            if base.location(&l.id).is_some() || !new_ids.insert(l.id.as_str()) {
+                return Err(format!("location `{}` already exists", l.id));
+            }
+            match (l.kind, &l.surface) {
+                (LocationKind::Surface, Some(s)) => {
+                    if !(s.gravity_m_s2 > 0.0 && s.radius_m > 0.0) {
+                        return Err(format!("location `{}`: gravity and radius must be positive", l.id));
+                    }
+                    if s.atmosphere_density < 0.0 || s.ambient_pressure_pa < 0.0 {
+                        return Err(format!("location `{}`: atmosphere must be >= 0", l.id));
+                    }
+                }
+                (LocationKind::Surface, None) => {
+                    return Err(format!("location `{}`: surfaces need a [surface] table", l.id));
+                }
+                (_, Some(_)) => {
+                    return Err(format!("location `{}`: only surfaces take a [surface] table", l.id));
+                }
+                (_, None) => {}
+            }
+        }
+
+        let is_surface = |id: &str| match self.locations.iter().find(|l| l.id == id) {
+            Some(l) => l.kind == LocationKind::Surface,
+            None => base.surface_properties(id).is_some(),
+        };
+        let mut linked = HashSet::new();
+        for t in &self.transfers {
+            for end in [&t.from, &t.to] {
+                if base.location(end).is_none() && !new_ids.contains(end.as_str()) {
+                    return Err(format!("transfer {} → {}: unknown location `{end}`", t.from, t.to));
+                }
+                linked.insert(end.as_str());
+            }
+            if t.from == t.to {
+                return Err(format!("transfer {} → {}: loops back on itself", t.from, t.to));
+            }
+            if base.transfer(&t.from, &t.to).is_some() {
+                return Err(format!("transfer {} → {}: already exists", t.from, t.to));
+            }
+            let dvs = std::iter::once(t.delta_v).chain(t.low_thrust_delta_v);
+            if dvs.into_iter().any(|dv| !(dv.is_finite() && dv >= 0.0)) {
+                return Err(format!("transfer {} → {}: delta-v must be >= 0", t.from, t.to));
+            }
+            let ground = is_surface(&t.from) || is_surface(&t.to);
+            if (t.kind == TransferKind::Ground) != ground
+                || (ground && (!is_surface(&t.from) || is_surface(&t.to)))
+            {
+                return Err(format!(
+                    "transfer {} → {}: ground edges (and only they) run from a surface to an orbit",
+                    t.from, t.to,
+                ));
+            }
+        }
+        if let Some(orphan) = new_ids.iter().find(|id| !linked.contains(*id)) {
+            return Err(format!("location `{orphan}` has no transfers"));
+        }
+
+        let mut markets = balance.markets.clone();
+        markets.archetypes.extend(self.contracts.iter().cloned());
+        markets.validate()?;
+        for a in &self.contracts {
+            for d in &a.template.destinations {
+                if base.location(&d.location_id).is_none() && !new_ids.contains(d.location_id.as_str()) {
+                    return Err(format!(
+                        "archetype `{}`: unknown destination `{}`", a.key, d.location_id,
+                    ));
+                }
+            }
+        }
+
+        if let Some(f) = self.flaws.iter().find(|f| f.description.trim().is_empty()) {
+            return Err(format!("flaw entry for {:?} has an empty description", f.subsystem));
+        }
+        Ok(())
+    }
+
+    /// Add this pack's locations and edges to `map`. Strings are leaked
+    /// to match the map's `&'static str` ids — packs are applied once,
+    /// when the global map is built.
+    pub fn apply_to_map(&self, map: &mut DeltaVMap) {
+        for l in &self.locations {
+            let location_type = match (l.kind, &l.surface) {
+                (LocationKind::Surface, Some(s)) => LocationType::Surface(SurfaceProperties {
+                    gravity_m_s2: s.gravity_m_s2,
+                    radius_m: s.radius_m,
+                    has_atmosphere: s.atmosphere_density > 0.0,
+                    atmosphere_density: s.atmosphere_density,
+                    ambient_pressure_pa: s.ambient_pressure_pa,
+                }),
+                (LocationKind::LagrangePoint, _) => LocationType::LagrangePoint,
+                _ => LocationType::Orbit,
+            };
+            map.add_location(Location {
+                id: leak(&l.id),
+                display_name: leak(&l.display_name),
+                short_name: leak(&l.short_name),
+                location_type,
+                parent_body: leak(&l.parent_body),
+            });
+        }
+        for t in &self.transfers {
+            let (from, to) = (leak(&t.from), leak(&t.to));
+            match t.kind {
+                TransferKind::Impulsive => map.add_impulsive_pair(from, to, t.delta_v, t.transit_days),
+                TransferKind::Spiral => {
+                    map.add_spiral_pair(from, to, t.delta_v, t.low_thrust_delta_v, t.transit_days)
+                }
+                TransferKind::Ground => {
+                    let has_atm = map.surface_properties(from).is_some_and(|s| s.has_atmosphere);
+                    map.add_ground_pair(from, to, t.delta_v, t.transit_days, has_atm, t.low_thrust_delta_v)
+                }
+            }
+        }
+    }
+
+    /// Append the pack's contract templates to `balance`'s markets.
+    pub fn apply_to_balance(&self, balance: &mut BalanceConfig) {
+        balance.markets.archetypes.extend(self.contracts.iter().cloned());
+    }
+}
+
+fn leak(s: &str) -> &'static str {
+    Box::leak(s.to_owned().into_boxed_str())
+}
+
+/// Load every `*.toml` in `dir` (in name order), skipping — with a
+/// warning each — files that fail to read, parse, or validate. A
+/// missing directory is just "no mods".
+pub fn load_dir(dir: &Path, balance: &BalanceConfig) -> (ContentPack, Vec<String>) {
+    let mut pack = ContentPack::default();
+    let mut warnings = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (pack, warnings);
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|x| x == "toml"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| ContentPack::from_toml_str(&text));
+        let candidate = match parsed {
+            Ok(p) => p,
+            Err(e) => {
+                warnings.push(format!("{}: {e}; skipped", path.display()));
+                continue;
+            }
+        };
+        let mut combined = pack.clone();
+        combined.merge(candidate);
+        match combined.validate(balance) {
+            Ok(()) => pack = combined,
+            Err(e) => warnings.push(format!("{}: {e}; skipped", path.display())),
+        }
+    }
+    (pack, warnings)
+}
+
+static INSTALLED: OnceLock<ContentPack> = OnceLock::new();
+
+/// Make `pack` the process-wide content. Fails if content is already
+/// installed or the delta-v map has already been built without it.
+pub fn install(pack: ContentPack) -> Result<(), String> {
+    if location::delta_v_map_built() {
+        return Err("content must be installed before the delta-v map is first used".into());
+    }
+    INSTALLED.set(pack).map_err(|_| "content is already installed".to_string())
+}
+
+/// The installed content, if any.
+pub fn installed() -> Option<&'static ContentPack> {
+    INSTALLED.get()
+}
+
+/// The installed stat overrides for `propellant` (last pack wins).
+pub fn propellant_stats(propellant: Propellant) -> Option<&'static PropellantStats> {
+    installed()?.propellants.iter().rev().find(|p| p.propellant == propellant)
+}
+
+/// Installed flaw descriptions for one catalog slot.
+pub fn flaw_entries(trigger: FlawTrigger, consequence: &FlawConsequence) -> Vec<&'static FlawEntry> {
+    let severity = FlawSeverity::of(consequence);
+    installed()
+        .map(|c| c.flaws.iter().filter(|f| f.trigger == trigger && f.severity == severity).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACK: &str = r#"
+[[propellants]]
+propellant = "Methane"
+cost_per_kg = 0.5
+
+[[locations]]
+id = "ceres_station"
+display_name = "Ceres Station"
+short_name = "CSTN"
+parent_body = "ceres"
+kind = "orbit"
+
+[[transfers]]
+from = "ceres_station"
+to = "ceres_orbit_20km"
+delta_v = 40.0
+kind = "spiral"
+
+[[flaws]]
+description = "Slag buildup in the aft closure"
+subsystem = "Combustion"
+severity = "engine_loss"
+"#;
+
+    #[test]
+    fn test_pack_parses_validates_and_extends_the_map() {
+        let pack = ContentPack::from_toml_str(PACK).unwrap();
+        pack.validate(&BalanceConfig::default()).unwrap();
+
+        let mut map = DeltaVMap::earth_moon();
+        let before = map.location_count();
+        pack.apply_to_map(&mut map);
+        assert_eq!(map.location_count(), before + 1);
+        let t = map.transfer("ceres_orbit_20km", "ceres_station").expect("edges are symmetric");
+        assert!(t.low_thrust_ok);
+        assert!(map.shortest_path("leo", "ceres_station", 500_000.0).is_some());
+    }
+
+    #[test]
+    fn test_invalid_packs_are_rejected() {
+        let balance = BalanceConfig::default();
+        let bad = [
+            // Unknown endpoint.
+            "[[transfers]]\nfrom = \"leo\"\nto = \"nowhere\"\ndelta_v = 1.0\nkind = \"impulsive\"",
+            // Duplicates a built-in node.
+            "[[locations]]\nid = \"leo\"\ndisplay_name = \"x\"\nshort_name = \"x\"\nparent_body = \"earth\"\nkind = \"orbit\"",
+            // Surface without surface properties.
+            "[[locations]]\nid = \"x\"\ndisplay_name = \"x\"\nshort_name = \"x\"\nparent_body = \"earth\"\nkind = \"surface\"",
+            // Non-positive propellant stat.
+            "[[propellants]]\npropellant = \"LOX\"\ndensity_kg_per_l = 0.0",
+        ];
+        for text in bad {
+            let pack = ContentPack::from_toml_str(text).unwrap();
+            assert!(pack.validate(&balance).is_err(), "should reject:\n{text}");
+        }
+        assert!(ContentPack::from_toml_str("[[engines]]\nname = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_load_dir_skips_broken_files() {
+        let dir = std::env::temp_dir().join(format!("rocket_content_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a_good.toml"), PACK).unwrap();
+        std::fs::write(dir.join("b_broken.toml"), "[[locations]\n").unwrap();
+        std::fs::write(dir.join("c_clash.toml"), PACK).unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let (pack, warnings) = load_dir(&dir, &BalanceConfig::default());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(pack.locations.len(), 1);
+        assert_eq!(pack.flaws.len(), 1);
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings[0].contains("b_broken") && warnings[1].contains("c_clash"));
+        assert!(load_dir(Path::new("/nonexistent/content"), &BalanceConfig::default()).0.is_empty());
+    }
+}
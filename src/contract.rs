@@ -4,7 +4,10 @@ use serde::{Serialize, Deserialize};
 
 use crate::balance_config::MarketsConfig;
 use crate::calendar::GameDate;
+use crate::locale::{slug, Text};
+use crate::market_trend::MarketTrend;
 use crate::seed::GameSeed;
+use crate::reputation::ReputationSegment;
 
 /// Unique identifier for a contract.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -56,14 +59,203 @@ pub struct Contract {
     /// The player's sealed bid, revisable until `bid_deadline`.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub player_bid: Option<f64>,
+    /// How the customer pays: the agreed price, or costs plus a fee.
+    #[serde(default)]
+    pub pricing: ContractPricing,
+    /// `name` as a translation key and arguments (see `name_text`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    /// The insertion error (m/s) the customer accepts; a worse insertion
+    /// costs part of the payment (see [`crate::insertion`]). None = any
+    /// insertion the launch makes will do.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion_tolerance: Option<f64>,
+    /// The payload is export-controlled: only a company with a security
+    /// clearance may bid, and its launch needs export paperwork (see
+    /// [`crate::regulation`]).
+    #[serde(default)]
+    pub export_controlled: bool,
+    /// How the customer arrived at `payment`. None for campaign
+    /// missions, which fly at the won block price, and older saves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward: Option<RewardBreakdown>,
+}
+
+/// How a market contract's price is built up, per kg: the destination's
+/// going rate, moved by how tight the deadline is, by market demand and
+/// by the customer's relationship with the player, then by the
+/// customer's own budget. The payment is the product times the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RewardBreakdown {
+    /// The destination's $/kg today (see `MarketTrend::rate_per_kg`).
+    pub base_per_kg: f64,
+    /// Above 1 for a deadline tighter than the market's usual, below
+    /// for a looser one.
+    pub deadline_mult: f64,
+    /// The economy, market events, how well served the destination is
+    /// and world launch prices, together.
+    pub demand_mult: f64,
+    /// What the customer adds for a company with a good name in its
+    /// segment.
+    pub relationship_mult: f64,
+    /// This customer's own budget. Hidden while bids are open, like
+    /// the budget ceiling.
+    pub customer_mult: f64,
+}
+
+impl RewardBreakdown {
+    /// The $/kg the market pays for this job, before the customer's
+    /// own budget: what a bid can be reasoned from.
+    pub fn market_per_kg(&self) -> f64 {
+        self.base_per_kg * self.deadline_mult * self.demand_mult * self.relationship_mult
+    }
+
+    pub fn per_kg(&self) -> f64 {
+        self.market_per_kg() * self.customer_mult
+    }
+}
+
+/// A contract's pricing structure.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ContractPricing {
+    /// The agreed price, half of it for a partial delivery.
+    #[default]
+    FixedPrice,
+    /// The customer reimburses the recorded cost of the hardware the
+    /// payload flew on, plus a fee. In exchange it audits the books at
+    /// settlement and holds the fee back unless the launch makes
+    /// `launch_by` and the delivery is clean. `payment` stays the award
+    /// price and the estimate shown to the player.
+    CostPlus {
+        fee_fraction: f64,
+        launch_by: GameDate,
+        /// Costs charged to the contract so far.
+        incurred_cost: f64,
+    },
+    /// A science mission: `upfront_share` of the price on arrival, then
+    /// the data is paid for as it comes down over `months`, for as long
+    /// as the asset survives and can be heard from (see
+    /// [`crate::science`]).
+    DataReturn {
+        upfront_share: f64,
+        months: u32,
+    },
+}
+
+/// What a delivered contract pays out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settlement {
+    pub payment: f64,
+    /// Costs an audit refused to reimburse.
+    pub disallowed: f64,
+    /// Cost-plus fee withheld for a late launch or partial delivery.
+    pub fee_forfeited: bool,
 }
 
 impl Contract {
+    /// What the contract pays per kg of payload.
+    pub fn payment_per_kg(&self) -> f64 {
+        if self.payload_kg > 0.0 { self.payment / self.payload_kg } else { 0.0 }
+    }
+
+    /// Move the price by `mult` for market demand, keeping the
+    /// breakdown in step. The caller resets the budget ceiling.
+    pub fn apply_demand(&mut self, mult: f64) {
+        self.payment = (self.payment * mult / 10_000.0).round() * 10_000.0;
+        if let Some(r) = &mut self.reward {
+            r.demand_mult *= mult;
+        }
+    }
+
+    /// Move the price by `mult` for the customer's relationship with
+    /// the player.
+    pub fn apply_relationship(&mut self, mult: f64, budget_tolerance: f64) {
+        self.payment = (self.payment * mult / 10_000.0).round() * 10_000.0;
+        self.budget_ceiling = self.payment * budget_tolerance;
+        if let Some(r) = &mut self.reward {
+            r.relationship_mult *= mult;
+        }
+    }
+
+    /// Localizable name; contracts from pre-localization saves render
+    /// their stored name verbatim.
+    pub fn name_text(&self) -> Text {
+        self.title.clone().unwrap_or_else(|| Text::literal(&self.name))
+    }
+
     /// Solicitations are priced by sealed bid; pre-priced contracts
     /// (campaign missions, pre-M3 saves) keep the legacy accept flow.
     pub fn is_solicitation(&self) -> bool {
         self.bid_deadline.is_some()
     }
+
+    pub fn is_cost_plus(&self) -> bool {
+        matches!(self.pricing, ContractPricing::CostPlus { .. })
+    }
+
+    pub fn is_data_return(&self) -> bool {
+        matches!(self.pricing, ContractPricing::DataReturn { .. })
+    }
+
+    /// What a data-return contract's full data stream is worth with
+    /// perfect coverage, and the months it runs. A partial delivery
+    /// leaves a degraded instrument returning half the data. None for
+    /// other pricing.
+    pub fn data_return(&self, partial: bool, cfg: &MarketsConfig) -> Option<(f64, u32)> {
+        match self.pricing {
+            ContractPricing::DataReturn { upfront_share, months } => {
+                let full = self.payment * (1.0 - upfront_share) * (1.0 + cfg.science_data_premium);
+                Some((if partial { full * 0.5 } else { full }, months))
+            }
+            _ => None,
+        }
+    }
+
+    /// Share of the payment withheld for delivering with
+    /// `insertion_error` (m/s) against this contract's tolerance.
+    pub fn insertion_penalty(&self, insertion_error: f64, cfg: &MarketsConfig) -> f64 {
+        match self.insertion_tolerance {
+            Some(tolerance) if insertion_error > tolerance =>
+                ((insertion_error - tolerance) * cfg.insertion_penalty_per_m_s).min(cfg.insertion_max_penalty),
+            _ => 0.0,
+        }
+    }
+
+    /// Charge `cost` to the contract. Only cost-plus contracts keep
+    /// the books.
+    pub fn record_cost(&mut self, cost: f64) {
+        if let ContractPricing::CostPlus { incurred_cost, .. } = &mut self.pricing {
+            *incurred_cost += cost;
+        }
+    }
+
+    /// Settle a delivery launched on `launched`. `audited` is whether
+    /// the customer's auditors went through the books (cost-plus only),
+    /// disallowing `cfg.cost_plus_audit_disallow` of the recorded costs.
+    pub fn settle(&self, partial: bool, launched: GameDate, audited: bool, cfg: &MarketsConfig) -> Settlement {
+        match self.pricing {
+            ContractPricing::FixedPrice => Settlement {
+                payment: if partial { self.payment * 0.5 } else { self.payment },
+                disallowed: 0.0,
+                fee_forfeited: false,
+            },
+            ContractPricing::CostPlus { fee_fraction, launch_by, incurred_cost } => {
+                let disallowed = if audited { incurred_cost * cfg.cost_plus_audit_disallow } else { 0.0 };
+                let allowed = incurred_cost - disallowed;
+                let fee_forfeited = partial || launched > launch_by;
+                let fee = if fee_forfeited { 0.0 } else { allowed * fee_fraction };
+                Settlement { payment: allowed + fee, disallowed, fee_forfeited }
+            }
+            ContractPricing::DataReturn { upfront_share, .. } => {
+                let upfront = self.payment * upfront_share;
+                Settlement {
+                    payment: if partial { upfront * 0.5 } else { upfront },
+                    disallowed: 0.0,
+                    fee_forfeited: false,
+                }
+            }
+        }
+    }
 }
 
 /// One observed award outcome — the player's price-discovery data.
@@ -119,6 +311,11 @@ pub mod test_support {
             bid_deadline: Some(GameDate { year: 2001, month: 6, day: 1 }),
             budget_ceiling: 24_000_000.0,
             player_bid: None,
+            pricing: ContractPricing::FixedPrice,
+            title: None,
+            insertion_tolerance: None,
+            export_controlled: false,
+            reward: None,
         }
     }
 }
@@ -276,6 +473,25 @@ pub struct Market {
     /// (opening-floor markets are required to be Steady).
     #[serde(default)]
     pub volume_accumulator: f64,
+    /// Crewed and national-security customers only award to a company
+    /// holding a certified design (see `RocketProject::certification`).
+    #[serde(default)]
+    pub requires_certification: bool,
+    /// Fraction of this market's solicitations issued cost-plus rather
+    /// than fixed-price.
+    #[serde(default)]
+    pub cost_plus_share: f64,
+    /// Fraction of this market's fixed-price solicitations paid by the
+    /// data a science payload returns rather than on arrival.
+    #[serde(default)]
+    pub science_share: f64,
+    /// Whose opinion of the company award scoring reads.
+    #[serde(default)]
+    pub reputation_segment: ReputationSegment,
+    /// Fraction of this market's solicitations carrying
+    /// export-controlled payloads (see `Contract::export_controlled`).
+    #[serde(default)]
+    pub export_controlled_share: f64,
 }
 
 fn default_severity() -> f64 {
@@ -316,6 +532,14 @@ pub fn bid_score(
 }
 
 impl Market {
+    pub fn name_text(&self) -> Text {
+        Text::new(format!("market.{}.name", self.id.0), &self.name)
+    }
+
+    pub fn description_text(&self) -> Text {
+        Text::new(format!("market.{}.description", self.id.0), &self.description)
+    }
+
     /// Compounding growth multiplier accumulated since activation
     /// (1.0 before activation or with zero growth).
     pub fn growth_factor(&self, current_date: GameDate) -> f64 {
@@ -335,6 +559,20 @@ impl Market {
         self.base_volume * self.growth_factor(current_date) * mod_mult * econ
     }
 
+    /// Effective monthly volume to `location_id`: the market's volume
+    /// split by destination weight.
+    pub fn destination_volume(&self, location_id: &str, economy_modifier: f64, current_date: GameDate) -> f64 {
+        let total_weight: f64 = self.destinations.iter().map(|d| d.weight).sum();
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        let weight: f64 = self.destinations.iter()
+            .filter(|d| d.location_id == location_id)
+            .map(|d| d.weight)
+            .sum();
+        self.effective_volume(economy_modifier, current_date) * weight / total_weight
+    }
+
     /// Effective rate multiplier from all modifiers.
     pub fn rate_multiplier(&self, economy_modifier: f64) -> f64 {
         let econ = self.economy_sensitivity.apply(economy_modifier);
@@ -357,16 +595,50 @@ impl Market {
     }
 }
 
+/// What the player has shown they can fly, for weighting the contracts
+/// offered (see `Company::capability_profile`). Empty until the first
+/// fully successful launch, and generation is then unweighted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CapabilityProfile {
+    /// Destinations reached by fully successful launches, with the
+    /// heaviest payload delivered to each.
+    pub proven: Vec<(String, f64)>,
+}
+
+impl CapabilityProfile {
+    pub fn is_empty(&self) -> bool {
+        self.proven.is_empty()
+    }
+
+    /// Heaviest payload delivered to `location_id`, if it's been reached.
+    pub fn lift_to(&self, location_id: &str) -> Option<f64> {
+        self.proven.iter().find(|(id, _)| id == location_id).map(|&(_, kg)| kg)
+    }
+
+    /// Whether `contract` asks for more than has been shown: a
+    /// destination never reached, or a heavier payload than any
+    /// delivered there.
+    pub fn is_stretch(&self, contract: &Contract) -> bool {
+        self.lift_to(&contract.destination).is_none_or(|kg| contract.payload_kg > kg)
+    }
+}
+
 /// Generate contracts for a single market for one month. Every
 /// active market generates regardless of player reputation — the
 /// reputation question moved from visibility to award scoring (M3).
+/// Payloads and rates follow `trend` (see [`crate::market_trend`]).
+/// Destinations the player has reached come up more often, and their
+/// payloads cluster around what the player has delivered there.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_market_contracts(
     market: &mut Market,
     rng: &mut StdRng,
     next_contract_id: &mut u64,
     current_date: GameDate,
     economy_modifier: f64,
+    trend: MarketTrend,
     markets_cfg: &MarketsConfig,
+    capability: &CapabilityProfile,
 ) -> Vec<Contract> {
     if !market.active {
         return Vec::new();
@@ -395,7 +667,7 @@ pub fn generate_market_contracts(
     let mut contracts = Vec::new();
     for _ in 0..count {
         if let Some(c) = generate_single_contract(
-            market, rng, next_contract_id, current_date, rate_mult, markets_cfg,
+            market, rng, next_contract_id, current_date, rate_mult, trend, markets_cfg, capability,
         ) {
             contracts.push(c);
         }
@@ -403,59 +675,172 @@ pub fn generate_market_contracts(
     contracts
 }
 
-/// Pick a destination by weight (None if the market has no positive
-/// weights).
-fn pick_destination<'a>(market: &'a Market, rng: &mut StdRng) -> Option<&'a MarketDestination> {
-    let total_weight: f64 = market.destinations.iter().map(|d| d.weight).sum();
+/// Pick a destination by `weight` (None if no destination has a
+/// positive one).
+fn pick_destination<'a>(
+    destinations: impl Iterator<Item = &'a MarketDestination> + Clone,
+    weight: impl Fn(&MarketDestination) -> f64,
+    rng: &mut StdRng,
+) -> Option<&'a MarketDestination> {
+    let total_weight: f64 = destinations.clone().map(&weight).sum();
     if total_weight <= 0.0 {
         return None;
     }
     let mut roll = rng.gen::<f64>() * total_weight;
-    let mut dest = market.destinations.first()?;
-    for d in &market.destinations {
-        roll -= d.weight;
+    let mut dest = None;
+    for d in destinations {
+        dest = dest.or(Some(d));
+        roll -= weight(d);
         if roll <= 0.0 {
-            dest = d;
+            dest = Some(d);
             break;
         }
     }
-    Some(dest)
+    dest
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_single_contract(
     market: &Market,
     rng: &mut StdRng,
     next_contract_id: &mut u64,
     current_date: GameDate,
     rate_mult: f64,
+    trend: MarketTrend,
     markets_cfg: &MarketsConfig,
+    capability: &CapabilityProfile,
 ) -> Option<Contract> {
     if market.destinations.is_empty() || market.name_prefixes.is_empty() {
         return None;
     }
 
-    let dest = pick_destination(market, rng)?;
+    let dest = pick_destination(market.destinations.iter(), |d| {
+        match capability.lift_to(&d.location_id) {
+            Some(_) => d.weight * markets_cfg.proven_destination_weight,
+            None => d.weight,
+        }
+    }, rng)?;
+
+    let (min_payload_kg, max_payload_kg) = trend.payload_range(dest);
+    let lift = capability.lift_to(&dest.location_id).filter(|&kg| kg > 0.0);
+    let payload_kg = match lift {
+        Some(kg) if rng.gen::<f64>() < markets_cfg.capability_cluster_share => {
+            // Two draws averaged pile up around the demonstrated lift.
+            let offset = rng.gen::<f64>() + rng.gen::<f64>() - 1.0;
+            (kg * (1.0 + offset * markets_cfg.capability_payload_spread)).clamp(min_payload_kg, max_payload_kg)
+        }
+        _ => rng.gen_range(min_payload_kg..=max_payload_kg),
+    };
+    Some(build_contract(market, dest, payload_kg, rng, next_contract_id, current_date, rate_mult, trend, markets_cfg))
+}
 
-    let payload_kg = rng.gen_range(dest.min_payload_kg..=dest.max_payload_kg);
+/// One contract beyond the player's demonstrated capability: to a
+/// destination they haven't reached, or heavier than they've delivered
+/// there by at least `stretch_payload_margin`. None if the market has
+/// nothing of the kind.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_stretch_contract(
+    market: &Market,
+    rng: &mut StdRng,
+    next_contract_id: &mut u64,
+    current_date: GameDate,
+    economy_modifier: f64,
+    trend: MarketTrend,
+    markets_cfg: &MarketsConfig,
+    capability: &CapabilityProfile,
+) -> Option<Contract> {
+    if !market.active || market.name_prefixes.is_empty() {
+        return None;
+    }
+    // Whole hundreds, so rounding can't pull it back under what's
+    // been flown.
+    let floor = |d: &MarketDestination| {
+        let (min, max) = trend.payload_range(d);
+        match capability.lift_to(&d.location_id) {
+            None => Some(min),
+            Some(kg) => Some((min.max(kg * (1.0 + markets_cfg.stretch_payload_margin)) / 100.0).ceil() * 100.0)
+                .filter(|&kg| kg <= max),
+        }
+    };
+    let candidates = market.destinations.iter().filter(|d| floor(d).is_some());
+    let dest = pick_destination(candidates, |d| d.weight, rng)?;
+    let min_payload_kg = floor(dest)?;
+    let payload_kg = rng.gen_range(min_payload_kg..=trend.payload_range(dest).1);
+    let rate_mult = market.rate_multiplier(economy_modifier);
+    Some(build_contract(market, dest, payload_kg, rng, next_contract_id, current_date, rate_mult, trend, markets_cfg))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_contract(
+    market: &Market,
+    dest: &MarketDestination,
+    payload_kg: f64,
+    rng: &mut StdRng,
+    next_contract_id: &mut u64,
+    current_date: GameDate,
+    rate_mult: f64,
+    trend: MarketTrend,
+    markets_cfg: &MarketsConfig,
+) -> Contract {
+    let (min_payload_kg, _) = trend.payload_range(dest);
     let payload_kg = (payload_kg / 100.0).round() * 100.0;
-    let payload_kg = payload_kg.max(dest.min_payload_kg);
+    let payload_kg = payload_kg.max(min_payload_kg);
 
-    let base_payment = payload_kg * dest.rate_per_kg;
     let variance = rng.gen_range(markets_cfg.payment_variance_min..=markets_cfg.payment_variance_max);
-    let payment = (base_payment * variance * rate_mult / 10_000.0).round() * 10_000.0;
-
     let (deadline_min, deadline_max) = market.deadline_days
         .unwrap_or((markets_cfg.deadline_min_days, markets_cfg.deadline_max_days));
     let deadline_days = rng.gen_range(deadline_min..=deadline_max);
     let deadline = current_date.add_days(deadline_days);
+    // Where the deadline falls in the market's window: 0 the tightest.
+    let slack = if deadline_max > deadline_min {
+        (deadline_days - deadline_min) as f64 / (deadline_max - deadline_min) as f64
+    } else {
+        0.5
+    };
+    let reward = RewardBreakdown {
+        base_per_kg: trend.rate_per_kg(dest),
+        deadline_mult: 1.0 + markets_cfg.deadline_premium * (0.5 - slack),
+        demand_mult: rate_mult,
+        relationship_mult: 1.0,
+        customer_mult: variance,
+    };
+    let payment = (payload_kg * reward.per_kg() / 10_000.0).round() * 10_000.0;
 
     let prefix = &market.name_prefixes[rng.gen_range(0..market.name_prefixes.len())];
     let name = format!("{} to {}", prefix, dest.display_name);
+    let title = Text::new("contract.name", &name)
+        .keyed_arg("prefix", format!("contract.prefix.{}", slug(prefix)), prefix)
+        .keyed_arg("destination", format!("location.{}", dest.location_id), &dest.display_name);
+
+    // Only markets that issue cost-plus or data-return work draw for
+    // it, so fixed-price markets generate exactly as before.
+    let pricing = if market.cost_plus_share > 0.0 && rng.gen::<f64>() < market.cost_plus_share {
+        ContractPricing::CostPlus {
+            fee_fraction: markets_cfg.cost_plus_fee,
+            launch_by: current_date.add_days(
+                (deadline_days as f64 * markets_cfg.cost_plus_schedule_fraction) as u32,
+            ),
+            incurred_cost: 0.0,
+        }
+    } else if market.science_share > 0.0 && rng.gen::<f64>() < market.science_share {
+        ContractPricing::DataReturn {
+            upfront_share: markets_cfg.science_upfront_share,
+            months: markets_cfg.science_data_months,
+        }
+    } else {
+        ContractPricing::FixedPrice
+    };
+    let insertion_tolerance = (rng.gen::<f64>() < markets_cfg.insertion_tolerance_share).then(|| {
+        let (lo, hi) = markets_cfg.insertion_tolerance_m_s;
+        (rng.gen_range(lo..=hi) / 5.0).round() * 5.0
+    });
+    let export_controlled = market.export_controlled_share > 0.0
+        && rng.gen::<f64>() < market.export_controlled_share;
 
     let id = ContractId(*next_contract_id);
     *next_contract_id += 1;
 
-    Some(Contract {
+    Contract {
         id,
         name,
         destination: dest.location_id.clone(),
@@ -468,7 +853,12 @@ fn generate_single_contract(
         bid_deadline: Some(current_date.add_days(markets_cfg.bid_window_days)),
         budget_ceiling: payment * market.budget_tolerance,
         player_bid: None,
-    })
+        pricing,
+        title: Some(title),
+        insertion_tolerance,
+        export_controlled,
+        reward: Some(reward),
+    }
 }
 
 // ==========================================
@@ -578,7 +968,7 @@ pub fn spawn_campaign(
     if spec.program_names.is_empty() {
         return None;
     }
-    let dest = pick_destination(market, rng)?;
+    let dest = pick_destination(market.destinations.iter(), |d| d.weight, rng)?;
 
     let payload_kg = rng.gen_range(dest.min_payload_kg..=dest.max_payload_kg);
     let payload_kg = ((payload_kg / 100.0).round() * 100.0).max(dest.min_payload_kg);
@@ -630,14 +1020,20 @@ pub fn campaign_contract(
     let id = ContractId(*next_contract_id);
     *next_contract_id += 1;
 
+    let name = format!(
+        "{} Flight {} to {}",
+        campaign.name,
+        campaign.missions_issued + 1,
+        campaign.destination_display,
+    );
+    let title = Text::new("contract.campaign_flight", &name)
+        .arg("campaign", &campaign.name)
+        .arg("flight", campaign.missions_issued + 1)
+        .keyed_arg("destination", format!("location.{}", campaign.destination), &campaign.destination_display);
+
     Contract {
         id,
-        name: format!(
-            "{} Flight {} to {}",
-            campaign.name,
-            campaign.missions_issued + 1,
-            campaign.destination_display,
-        ),
+        name,
         destination: campaign.destination.clone(),
         payload_kg: campaign.payload_kg,
         payment: campaign.payment_per_mission,
@@ -651,6 +1047,11 @@ pub fn campaign_contract(
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: ContractPricing::FixedPrice,
+        title: Some(title),
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     }
 }
 
@@ -708,6 +1109,11 @@ pub fn initial_markets() -> Vec<Market> {
             failure_severity: 1.2,
             cadence: Cadence::Steady,
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Commercial,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
         Market {
             id: MARKET_GOV_SCIENCE,
@@ -755,6 +1161,11 @@ pub fn initial_markets() -> Vec<Market> {
             failure_severity: 0.7,
             cadence: Cadence::Steady,
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Government,
+            cost_plus_share: 0.5,
+            science_share: 0.5,
+            export_controlled_share: 0.0,
         },
         Market {
             id: MARKET_RIDESHARE,
@@ -787,6 +1198,11 @@ pub fn initial_markets() -> Vec<Market> {
             failure_severity: 1.0,
             cadence: Cadence::Steady,
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Commercial,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
     ]
 }
@@ -821,6 +1237,11 @@ pub fn event_market_templates() -> Vec<Market> {
             failure_severity: 2.0,
             cadence: Cadence::Steady,
             volume_accumulator: 0.0,
+            requires_certification: true,
+            reputation_segment: ReputationSegment::Public,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
         Market {
             id: MARKET_LEO_CONSTELLATION,
@@ -853,6 +1274,11 @@ pub fn event_market_templates() -> Vec<Market> {
             failure_severity: 1.0,
             cadence: Cadence::Burst { burst_chance: 0.2 },
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Commercial,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
         Market {
             id: MARKET_MEO_CONSTELLATION,
@@ -880,6 +1306,11 @@ pub fn event_market_templates() -> Vec<Market> {
             failure_severity: 1.0,
             cadence: Cadence::Burst { burst_chance: 0.2 },
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Commercial,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
         Market {
             id: MARKET_NSSL,
@@ -923,6 +1354,11 @@ pub fn event_market_templates() -> Vec<Market> {
             failure_severity: 1.5,
             cadence: Cadence::Lumpy { quiet_chance: 0.5 },
             volume_accumulator: 0.0,
+            requires_certification: true,
+            reputation_segment: ReputationSegment::Government,
+            cost_plus_share: 0.5,
+            science_share: 0.0,
+            export_controlled_share: 0.6,
         },
         Market {
             id: MARKET_EARTH_OBS,
@@ -955,6 +1391,11 @@ pub fn event_market_templates() -> Vec<Market> {
             failure_severity: 1.0,
             cadence: Cadence::Lumpy { quiet_chance: 0.4 },
             volume_accumulator: 0.0,
+            requires_certification: false,
+            reputation_segment: ReputationSegment::Commercial,
+            cost_plus_share: 0.0,
+            science_share: 0.0,
+            export_controlled_share: 0.0,
         },
     ]
 }
@@ -1341,7 +1782,7 @@ mod tests {
         let mut next_id = 1u64;
 
         let mut geo = markets.iter().find(|m| m.id == MARKET_GEO_COMSATS).unwrap().clone();
-        let cs = generate_market_contracts(&mut geo, &mut rng, &mut next_id, date, 1.0, &mcfg());
+        let cs = generate_market_contracts(&mut geo, &mut rng, &mut next_id, date, 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default());
         // GEO base_volume 1.5: generates at least one most months.
         assert!(
             !cs.is_empty(),
@@ -1359,7 +1800,7 @@ mod tests {
         let cfg = mcfg();
 
         let mut geo = markets.iter().find(|m| m.id == MARKET_GEO_COMSATS).unwrap().clone();
-        let cs = generate_market_contracts(&mut geo, &mut rng, &mut next_id, date, 1.0, &cfg);
+        let cs = generate_market_contracts(&mut geo, &mut rng, &mut next_id, date, 1.0, MarketTrend::default(), &cfg, &CapabilityProfile::default());
         for c in &cs {
             assert!(c.is_solicitation());
             assert_eq!(c.bid_deadline, Some(date.add_days(cfg.bid_window_days)));
@@ -1371,6 +1812,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generated_contract_names_are_localizable() {
+        let markets = initial_markets();
+        let mut rng = make_rng();
+        let mut next_id = 1u64;
+        let mut geo = markets.iter().find(|m| m.id == MARKET_GEO_COMSATS).unwrap().clone();
+        let cs = generate_market_contracts(
+            &mut geo, &mut rng, &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default(),
+        );
+        let c = cs.first().expect("GEO generates most months");
+        let text = c.name_text();
+        assert_eq!(text.key, "contract.name");
+        assert_eq!(text.fallback, c.name);
+        let dest = text.args.iter().find(|a| a.name == "destination").unwrap();
+        assert_eq!(dest.key.as_deref(), Some(format!("location.{}", c.destination).as_str()));
+
+        let table = crate::locale::LocaleTable::from_toml_str(
+            "[contract]\nname = \"{destination}: {prefix}\"",
+        ).unwrap();
+        assert!(table.render(&text).ends_with(&format!(": {}", text.args[0].value)));
+    }
+
     #[test]
     fn test_rep_factor_shape() {
         let scale = 10.0;
@@ -1455,6 +1918,76 @@ mod tests {
         assert!((vol_after - vol_before * 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_generation_leans_on_demonstrated_capability() {
+        let mut market = initial_markets().into_iter().find(|m| m.id == MARKET_GOV_SCIENCE).unwrap();
+        market.base_volume = 200.0;
+        market.cadence = Cadence::Steady;
+        let generate = |market: &mut Market, capability: &CapabilityProfile| {
+            let mut next_id = 1u64;
+            generate_market_contracts(
+                market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), capability,
+            )
+        };
+        let to_sso = |cs: &[Contract]| cs.iter().filter(|c| c.destination == "sso").count();
+        let near = |cs: &[Contract]| cs.iter()
+            .filter(|c| c.destination == "sso" && (1_400.0..=2_600.0).contains(&c.payload_kg))
+            .count();
+        let uniform = generate(&mut market.clone(), &CapabilityProfile::default());
+        let capability = CapabilityProfile { proven: vec![("sso".into(), 2_000.0)] };
+        let weighted = generate(&mut market.clone(), &capability);
+        assert!(to_sso(&weighted) > to_sso(&uniform), "the proven destination comes up more");
+        let near_share = |cs: &[Contract]| near(cs) as f64 / to_sso(cs) as f64;
+        assert!(near_share(&weighted) > near_share(&uniform) + 0.15, "payloads cluster around the delivered mass");
+
+        let mut next_id = 1u64;
+        for _ in 0..20 {
+            let c = generate_stretch_contract(
+                &market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), &capability,
+            ).expect("unreached destinations to stretch for");
+            assert!(capability.is_stretch(&c));
+        }
+        let everywhere = CapabilityProfile {
+            proven: market.destinations.iter().map(|d| (d.location_id.clone(), d.max_payload_kg)).collect(),
+        };
+        assert!(generate_stretch_contract(
+            &market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), &everywhere,
+        ).is_none(), "nothing left to stretch for");
+    }
+
+    #[test]
+    fn test_reward_breakdown_explains_the_price() {
+        let mut market = initial_markets().into_iter().find(|m| m.id == MARKET_GOV_SCIENCE).unwrap();
+        market.base_volume = 100.0;
+        market.cadence = Cadence::Steady;
+        let cfg = mcfg();
+        let mut next_id = 1u64;
+        let mut cs = generate_market_contracts(
+            &mut market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &cfg,
+            &CapabilityProfile::default(),
+        );
+        for c in &cs {
+            let r = c.reward.expect("market contracts carry their breakdown");
+            assert!((c.payment - c.payload_kg * r.per_kg()).abs() <= 5_000.0);
+            let dest = market.destinations.iter().find(|d| d.location_id == c.destination).unwrap();
+            assert_eq!(r.base_per_kg, dest.rate_per_kg);
+        }
+        // Tighter deadlines pay more per kg of going rate.
+        let days = |c: &Contract| GameDate::new(2001, 1, 1).days_until(&c.deadline);
+        cs.sort_by_key(days);
+        let (tight, loose) = (cs.first().unwrap(), cs.last().unwrap());
+        assert!(tight.reward.unwrap().deadline_mult > 1.0 && loose.reward.unwrap().deadline_mult < 1.0);
+        assert!((tight.reward.unwrap().deadline_mult - loose.reward.unwrap().deadline_mult - cfg.deadline_premium).abs() < 0.01);
+
+        let mut c = cs[0].clone();
+        let before = c.reward.unwrap();
+        c.apply_relationship(1.1, market.budget_tolerance);
+        c.apply_demand(0.5);
+        let after = c.reward.unwrap();
+        assert!((after.market_per_kg() - before.market_per_kg() * 0.55).abs() < 1e-6);
+        assert!((c.payment_per_kg() - after.per_kg()).abs() * c.payload_kg <= 10_000.0);
+    }
+
     /// Generate `months` of contracts for a synthetic market with the
     /// given cadence and return the per-month counts.
     fn monthly_counts(cadence: Cadence, months: u32) -> Vec<usize> {
@@ -1467,7 +2000,7 @@ mod tests {
         for m in 0..months {
             let date = GameDate::new(2001 + m / 12, m % 12 + 1, 1);
             let cs = generate_market_contracts(
-                &mut market, &mut rng, &mut next_id, date, 1.0, &mcfg(),
+                &mut market, &mut rng, &mut next_id, date, 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default(),
             );
             counts.push(cs.len());
         }
@@ -1587,7 +2120,7 @@ mod tests {
         let mut market = initial_markets()[2].clone(); // Rideshare
         let mut rng = make_rng();
         let mut next_id = 1u64;
-        let cs = generate_market_contracts(&mut market, &mut rng, &mut next_id, GameDate::new(2001, 1, 1), 1.0, &mcfg());
+        let cs = generate_market_contracts(&mut market, &mut rng, &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default());
         for c in &cs {
             assert_eq!(c.market_id, MARKET_RIDESHARE);
         }
@@ -1598,7 +2131,73 @@ mod tests {
         let mut market = event_market_templates()[0].clone(); // COTS, inactive
         let mut rng = make_rng();
         let mut next_id = 1u64;
-        let cs = generate_market_contracts(&mut market, &mut rng, &mut next_id, GameDate::new(2001, 1, 1), 1.0, &mcfg());
+        let cs = generate_market_contracts(&mut market, &mut rng, &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default());
         assert!(cs.is_empty());
     }
+
+    #[test]
+    fn test_market_trend_scales_payloads_and_rates() {
+        let mut market = initial_markets().into_iter().find(|m| m.id == MARKET_GEO_COMSATS).unwrap();
+        market.destinations.truncate(1);
+        let dest = market.destinations[0].clone();
+        let trend = MarketTrend { payload_mult: 3.0, rate_per_kg_mult: 0.5 };
+        let mut next_id = 1u64;
+        let cs = generate_market_contracts(
+            &mut market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, trend, &mcfg(), &CapabilityProfile::default(),
+        );
+        assert!(!cs.is_empty());
+        let cfg = mcfg();
+        for c in &cs {
+            assert!(c.payload_kg >= dest.min_payload_kg * 3.0 && c.payload_kg <= dest.max_payload_kg * 3.0 + 50.0);
+            assert!(c.payment <= c.payload_kg * dest.rate_per_kg * 0.5 * cfg.payment_variance_max + 5_000.0);
+        }
+    }
+
+    #[test]
+    fn test_cost_plus_reimburses_costs_plus_fee_on_time() {
+        let mut market = initial_markets().remove(0);
+        market.cost_plus_share = 1.0;
+        let mut rng = make_rng();
+        let mut next_id = 1u64;
+        let today = GameDate::new(2001, 1, 1);
+        let cs = generate_market_contracts(&mut market, &mut rng, &mut next_id, today, 1.0, MarketTrend::default(), &mcfg(), &CapabilityProfile::default());
+        let mut c = cs.into_iter().next().expect("market generates a contract");
+        assert!(c.is_cost_plus());
+        c.record_cost(1_000.0);
+        c.record_cost(1_000.0);
+        let on_time = GameDate::new(2001, 1, 2);
+
+        let full = c.settle(false, on_time, false, &mcfg());
+        assert!((full.payment - 2_300.0).abs() < 1e-6);
+        assert!(!full.fee_forfeited);
+
+        let audited = c.settle(false, on_time, true, &mcfg());
+        assert!((audited.disallowed - 200.0).abs() < 1e-6);
+        assert!((audited.payment - 1_800.0 * 1.15).abs() < 1e-6);
+
+        let late = c.settle(false, GameDate::new(2099, 1, 1), false, &mcfg());
+        assert!(late.fee_forfeited);
+        assert!((late.payment - 2_000.0).abs() < 1e-6);
+        assert!(c.settle(true, on_time, false, &mcfg()).fee_forfeited);
+    }
+
+    #[test]
+    fn test_insertion_tolerance_drawn_per_contract_and_misses_discounted() {
+        let mut market = initial_markets().remove(0);
+        let mut cfg = mcfg();
+        cfg.insertion_tolerance_share = 1.0;
+        let mut next_id = 1u64;
+        let cs = generate_market_contracts(
+            &mut market, &mut make_rng(), &mut next_id, GameDate::new(2001, 1, 1), 1.0, MarketTrend::default(), &cfg, &CapabilityProfile::default(),
+        );
+        assert!(!cs.is_empty());
+        let (lo, hi) = cfg.insertion_tolerance_m_s;
+        assert!(cs.iter().all(|c| c.insertion_tolerance.is_some_and(|t| t >= lo - 2.5 && t <= hi + 2.5)));
+
+        let c = Contract { insertion_tolerance: Some(20.0), ..test_support::solicitation_fixture() };
+        assert_eq!(c.insertion_penalty(20.0, &cfg), 0.0);
+        assert!((c.insertion_penalty(30.0, &cfg) - 10.0 * cfg.insertion_penalty_per_m_s).abs() < 1e-12);
+        assert_eq!(c.insertion_penalty(1e6, &cfg), cfg.insertion_max_penalty);
+        assert_eq!(test_support::solicitation_fixture().insertion_penalty(1e6, &cfg), 0.0);
+    }
 }
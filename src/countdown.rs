@@ -0,0 +1,316 @@
+//! Launch-day operations, minute by minute. Instead of an instant roll,
+//! a countdown tanks the vehicle (a leak or sensor fault can abort the
+//! load), re-checks the weather at set points, and lets the launch
+//! director hold, resume, recycle or scrub. A hold inside the recycle
+//! point can't simply resume: the count goes back to the recycle point
+//! first. Running out of window scrubs the attempt; nothing is spent
+//! until liftoff.
+
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::CountdownConfig;
+use crate::contract::ContractId;
+use crate::event::GameEvent;
+use crate::launch::LaunchRecord;
+use crate::manufacturing::InventoryItemId;
+
+/// Why the clock is stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoldReason {
+    /// The launch director called it.
+    Director,
+    /// Weather no-go; re-checked until it clears.
+    Weather,
+}
+
+impl fmt::Display for HoldReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HoldReason::Director => write!(f, "director's hold"),
+            HoldReason::Weather => write!(f, "weather hold"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CountdownPhase {
+    /// Loading propellant.
+    Tanking,
+    /// Vehicle loaded, counting to T-0.
+    Terminal,
+    Hold(HoldReason),
+    Liftoff,
+    Scrubbed { reason: String },
+}
+
+/// What happened on the count, for the launch UI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CountdownSignal {
+    TankingComplete,
+    WeatherCheck { go: bool },
+    Held(HoldReason),
+    Resumed,
+    Recycled { t_minus_min: u32 },
+    Liftoff,
+    Scrubbed { reason: String },
+}
+
+impl fmt::Display for CountdownSignal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountdownSignal::TankingComplete => write!(f, "Tanking complete, vehicle in terminal count"),
+            CountdownSignal::WeatherCheck { go: true } => write!(f, "Weather is go"),
+            CountdownSignal::WeatherCheck { go: false } => write!(f, "Weather is no-go"),
+            CountdownSignal::Held(reason) => write!(f, "Holding: {}", reason),
+            CountdownSignal::Resumed => write!(f, "Count resumed"),
+            CountdownSignal::Recycled { t_minus_min } => write!(f, "Recycled to T-{}", t_minus_min),
+            CountdownSignal::Liftoff => write!(f, "Liftoff"),
+            CountdownSignal::Scrubbed { reason } => write!(f, "Scrubbed: {}", reason),
+        }
+    }
+}
+
+/// A launch attempt in progress, with the manifest it will fly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Countdown {
+    pub rocket_item_id: InventoryItemId,
+    pub rocket_name: String,
+    pub destination: String,
+    pub contract_ids: Vec<ContractId>,
+    pub spacecraft_item_ids: Vec<InventoryItemId>,
+    pub persist: bool,
+    pub phase: CountdownPhase,
+    /// Minutes to T-0; stands still during holds.
+    pub t_minus_min: u32,
+    /// Minutes of the launch window used.
+    pub elapsed_min: u32,
+    /// Minutes to the next re-check while held for weather.
+    pub weather_recheck_in_min: u32,
+    /// Chance each weather check comes up no-go; the day's forecast.
+    #[serde(default)]
+    pub weather_no_go_chance: f64,
+    /// (T-minus, signal), oldest first.
+    pub log: Vec<(u32, CountdownSignal)>,
+}
+
+impl Countdown {
+    pub fn new(
+        rocket_item_id: InventoryItemId,
+        rocket_name: String,
+        destination: String,
+        contract_ids: Vec<ContractId>,
+        spacecraft_item_ids: Vec<InventoryItemId>,
+        persist: bool,
+        cfg: &CountdownConfig,
+    ) -> Self {
+        Countdown {
+            rocket_item_id,
+            rocket_name,
+            destination,
+            contract_ids,
+            spacecraft_item_ids,
+            persist,
+            phase: CountdownPhase::Tanking,
+            t_minus_min: cfg.tanking_start_min,
+            elapsed_min: 0,
+            weather_recheck_in_min: 0,
+            weather_no_go_chance: cfg.weather_no_go_chance,
+            log: Vec::new(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.phase, CountdownPhase::Liftoff | CountdownPhase::Scrubbed { .. })
+    }
+
+    fn signal(&mut self, signal: CountdownSignal) -> CountdownSignal {
+        self.log.push((self.t_minus_min, signal.clone()));
+        signal
+    }
+
+    /// The counting phase for the current T-minus.
+    fn counting_phase(&self, cfg: &CountdownConfig) -> CountdownPhase {
+        if self.t_minus_min > cfg.tanking_end_min {
+            CountdownPhase::Tanking
+        } else {
+            CountdownPhase::Terminal
+        }
+    }
+
+    /// One minute of launch operations.
+    pub fn step(&mut self, rng: &mut impl Rng, cfg: &CountdownConfig) -> Vec<CountdownSignal> {
+        let mut signals = Vec::new();
+        if self.is_finished() {
+            return signals;
+        }
+        self.elapsed_min += 1;
+        if self.elapsed_min > cfg.window_min {
+            signals.push(self.scrub("launch window closed".into()));
+            return signals;
+        }
+        match self.phase {
+            CountdownPhase::Hold(HoldReason::Director) => return signals,
+            CountdownPhase::Hold(HoldReason::Weather) => {
+                self.weather_recheck_in_min = self.weather_recheck_in_min.saturating_sub(1);
+                if self.weather_recheck_in_min > 0 {
+                    return signals;
+                }
+                let go = rng.gen::<f64>() >= self.weather_no_go_chance;
+                signals.push(self.signal(CountdownSignal::WeatherCheck { go }));
+                if !go {
+                    self.weather_recheck_in_min = cfg.weather_recheck_min;
+                    return signals;
+                }
+                // Clear skies inside the recycle point still mean a recycle.
+                signals.extend(self.recycle(cfg).or_else(|| self.resume(cfg)));
+                return signals;
+            }
+            _ => {}
+        }
+
+        if cfg.weather_checks_min.contains(&self.t_minus_min) {
+            let go = rng.gen::<f64>() >= self.weather_no_go_chance;
+            signals.push(self.signal(CountdownSignal::WeatherCheck { go }));
+            if !go {
+                self.phase = CountdownPhase::Hold(HoldReason::Weather);
+                self.weather_recheck_in_min = cfg.weather_recheck_min;
+                signals.push(self.signal(CountdownSignal::Held(HoldReason::Weather)));
+                return signals;
+            }
+        }
+        if self.phase == CountdownPhase::Tanking && cfg.tanking_abort_chance > 0.0 {
+            let minutes = cfg.tanking_start_min.saturating_sub(cfg.tanking_end_min).max(1);
+            let per_minute = 1.0 - (1.0 - cfg.tanking_abort_chance).powf(1.0 / minutes as f64);
+            if rng.gen::<f64>() < per_minute {
+                signals.push(self.scrub("tanking abort: propellant loading fault".into()));
+                return signals;
+            }
+        }
+
+        self.t_minus_min = self.t_minus_min.saturating_sub(1);
+        if self.t_minus_min == 0 {
+            self.phase = CountdownPhase::Liftoff;
+            signals.push(self.signal(CountdownSignal::Liftoff));
+        } else if self.phase == CountdownPhase::Tanking && self.t_minus_min <= cfg.tanking_end_min {
+            self.phase = CountdownPhase::Terminal;
+            signals.push(self.signal(CountdownSignal::TankingComplete));
+        }
+        signals
+    }
+
+    /// Stop the clock. None unless the count is running.
+    pub fn hold(&mut self) -> Option<CountdownSignal> {
+        if !matches!(self.phase, CountdownPhase::Tanking | CountdownPhase::Terminal) {
+            return None;
+        }
+        self.phase = CountdownPhase::Hold(HoldReason::Director);
+        Some(self.signal(CountdownSignal::Held(HoldReason::Director)))
+    }
+
+    /// Pick the count back up from a director's hold. None when not in
+    /// one, or when the hold is inside the recycle point.
+    pub fn resume(&mut self, cfg: &CountdownConfig) -> Option<CountdownSignal> {
+        let held = matches!(self.phase, CountdownPhase::Hold(_));
+        if !held || self.t_minus_min < cfg.recycle_to_min {
+            return None;
+        }
+        self.phase = self.counting_phase(cfg);
+        Some(self.signal(CountdownSignal::Resumed))
+    }
+
+    /// Reset a hold inside the recycle point back to it and count on.
+    /// None unless the count is held there.
+    pub fn recycle(&mut self, cfg: &CountdownConfig) -> Option<CountdownSignal> {
+        let held = matches!(self.phase, CountdownPhase::Hold(_));
+        if !held || self.t_minus_min >= cfg.recycle_to_min {
+            return None;
+        }
+        self.t_minus_min = cfg.recycle_to_min;
+        self.phase = self.counting_phase(cfg);
+        Some(self.signal(CountdownSignal::Recycled { t_minus_min: self.t_minus_min }))
+    }
+
+    /// Call the attempt off.
+    pub fn scrub(&mut self, reason: String) -> CountdownSignal {
+        self.phase = CountdownPhase::Scrubbed { reason: reason.clone() };
+        self.signal(CountdownSignal::Scrubbed { reason })
+    }
+}
+
+/// How a stretch of countdown ended.
+#[derive(Debug)]
+pub enum CountdownOutcome {
+    /// Still counting (or holding).
+    Counting,
+    /// Liftoff: the launch's events and, for flights decided on the
+    /// pad, its record.
+    Launched(Vec<GameEvent>, Option<LaunchRecord>),
+    Scrubbed(GameEvent),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn countdown(cfg: &CountdownConfig) -> Countdown {
+        Countdown::new(InventoryItemId(1), "Test".into(), "leo".into(), Vec::new(), Vec::new(), false, cfg)
+    }
+
+    #[test]
+    fn test_clear_count_tanks_then_lifts_off_at_t_zero() {
+        let cfg = CountdownConfig { weather_no_go_chance: 0.0, tanking_abort_chance: 0.0, ..Default::default() };
+        let mut cd = countdown(&cfg);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let mut signals = Vec::new();
+        while !cd.is_finished() {
+            signals.extend(cd.step(&mut rng, &cfg));
+        }
+        assert_eq!(cd.phase, CountdownPhase::Liftoff);
+        assert_eq!(cd.elapsed_min, cfg.tanking_start_min);
+        assert!(signals.contains(&CountdownSignal::TankingComplete));
+        assert_eq!(signals.last(), Some(&CountdownSignal::Liftoff));
+    }
+
+    #[test]
+    fn test_late_holds_recycle_and_long_holds_run_out_the_window() {
+        let cfg = CountdownConfig { weather_no_go_chance: 0.0, tanking_abort_chance: 0.0, ..Default::default() };
+        let mut cd = countdown(&cfg);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        while cd.t_minus_min >= cfg.recycle_to_min {
+            cd.step(&mut rng, &cfg);
+        }
+        assert!(cd.hold().is_some());
+        assert_eq!(cd.resume(&cfg), None, "inside the recycle point");
+        assert_eq!(cd.recycle(&cfg), Some(CountdownSignal::Recycled { t_minus_min: cfg.recycle_to_min }));
+        assert_eq!(cd.phase, CountdownPhase::Terminal);
+
+        cd.hold();
+        while !cd.is_finished() {
+            cd.step(&mut rng, &cfg);
+        }
+        assert!(matches!(cd.phase, CountdownPhase::Scrubbed { .. }));
+    }
+
+    #[test]
+    fn test_certain_weather_no_go_holds_and_tanking_faults_scrub() {
+        let cfg = CountdownConfig { weather_no_go_chance: 1.0, tanking_abort_chance: 0.0, ..Default::default() };
+        let mut cd = countdown(&cfg);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let first_check = *cfg.weather_checks_min.iter().max().unwrap();
+        while cd.t_minus_min > first_check {
+            cd.step(&mut rng, &cfg);
+        }
+        let signals = cd.step(&mut rng, &cfg);
+        assert!(signals.contains(&CountdownSignal::Held(HoldReason::Weather)));
+        assert_eq!(cd.t_minus_min, first_check, "the clock stops on a hold");
+
+        let cfg = CountdownConfig { tanking_abort_chance: 1.0, ..cfg };
+        let mut cd = countdown(&cfg);
+        let signals = cd.step(&mut rng, &cfg);
+        assert!(matches!(signals.as_slice(), [CountdownSignal::Scrubbed { .. }]));
+    }
+}
@@ -0,0 +1,149 @@
+//! Per-destination demand. Every company's contract deliveries fill a
+//! market segment — one market's customers at one destination — and
+//! that memory fades month by month. Contract generation reads the
+//! model: a segment served faster than its customers need sees fewer,
+//! cheaper solicitations; one nobody flies to pays a premium.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::DemandConfig;
+use crate::contract::{Contract, MarketId};
+
+/// Deliveries to one market's customers at one destination.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+    pub market_id: MarketId,
+    pub location_id: String,
+    /// Recent deliveries, fading by `DemandConfig::memory_months`.
+    pub deliveries: f64,
+}
+
+/// How a segment's demand shapes the contracts it issues.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentOutlook {
+    /// Recent monthly deliveries against what the segment normally
+    /// absorbs; 1.0 is balanced, above it saturated.
+    pub saturation: f64,
+    /// Share of the segment's contracts still issued.
+    pub volume_mult: f64,
+    /// Multiplier on the segment's contract prices.
+    pub rate_mult: f64,
+}
+
+impl SegmentOutlook {
+    /// The outlook for a segment at `saturation`.
+    pub fn at(saturation: f64, cfg: &DemandConfig) -> Self {
+        let glut = (saturation - 1.0).max(0.0);
+        let shortfall = (1.0 - saturation).max(0.0);
+        SegmentOutlook {
+            saturation,
+            volume_mult: 1.0 / (1.0 + cfg.saturated_volume_cut * glut),
+            rate_mult: (1.0 + cfg.underserved_premium * shortfall - cfg.saturated_discount * glut)
+                .max(cfg.min_rate_mult),
+        }
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.saturation > 1.0
+    }
+}
+
+/// Deliveries by segment, shared by every company.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DemandModel {
+    pub segments: Vec<Segment>,
+}
+
+impl DemandModel {
+    /// A contract to `location_id` for `market_id` was delivered.
+    pub fn record_delivery(&mut self, market_id: MarketId, location_id: &str) {
+        match self.segments.iter_mut().find(|s| s.market_id == market_id && s.location_id == location_id) {
+            Some(segment) => segment.deliveries += 1.0,
+            None => self.segments.push(Segment {
+                market_id,
+                location_id: location_id.to_string(),
+                deliveries: 1.0,
+            }),
+        }
+    }
+
+    /// Month start: a month's worth of deliveries fades from memory.
+    pub fn decay_month(&mut self, cfg: &DemandConfig) {
+        let keep = 1.0 - 1.0 / cfg.memory_months.max(1.0);
+        for segment in &mut self.segments {
+            segment.deliveries *= keep;
+        }
+        self.segments.retain(|s| s.deliveries >= 0.01);
+    }
+
+    pub fn deliveries(&self, market_id: MarketId, location_id: &str) -> f64 {
+        self.segments.iter()
+            .find(|s| s.market_id == market_id && s.location_id == location_id)
+            .map_or(0.0, |s| s.deliveries)
+    }
+
+    /// The outlook for a segment that issues `monthly_volume` contracts
+    /// a month.
+    pub fn outlook(&self, market_id: MarketId, location_id: &str, monthly_volume: f64, cfg: &DemandConfig) -> SegmentOutlook {
+        let absorbed = monthly_volume * cfg.served_share;
+        let delivered = self.deliveries(market_id, location_id) / cfg.memory_months.max(1.0);
+        let saturation = if absorbed > 0.0 { delivered / absorbed } else { 0.0 };
+        SegmentOutlook::at(saturation, cfg)
+    }
+
+    /// Shape a month of one market's freshly generated contracts by
+    /// demand: saturated segments lose some outright, and every
+    /// price moves by its segment's rate multiplier. `volume_by_dest`
+    /// gives each destination's share of the market's monthly volume.
+    pub fn shape_contracts(
+        &self,
+        contracts: &mut Vec<Contract>,
+        budget_tolerance: f64,
+        volume_by_dest: impl Fn(&str) -> f64,
+        rng: &mut StdRng,
+        cfg: &DemandConfig,
+    ) {
+        contracts.retain_mut(|c| {
+            let outlook = self.outlook(c.market_id, &c.destination, volume_by_dest(&c.destination), cfg);
+            if outlook.volume_mult < 1.0 && rng.gen::<f64>() >= outlook.volume_mult {
+                return false;
+            }
+            c.apply_demand(outlook.rate_mult);
+            c.budget_ceiling = c.payment * budget_tolerance;
+            true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturation_cuts_volume_and_price_and_fades() {
+        let cfg = DemandConfig::default();
+        let balanced = SegmentOutlook::at(1.0, &cfg);
+        assert_eq!((balanced.volume_mult, balanced.rate_mult), (1.0, 1.0));
+        let idle = SegmentOutlook::at(0.0, &cfg);
+        assert_eq!(idle.volume_mult, 1.0);
+        assert!((idle.rate_mult - 1.0 - cfg.underserved_premium).abs() < 1e-9);
+        let glut = SegmentOutlook::at(3.0, &cfg);
+        assert!(glut.is_saturated() && glut.volume_mult < 1.0 && glut.rate_mult < 1.0);
+        assert!(glut.rate_mult >= cfg.min_rate_mult);
+
+        let mut model = DemandModel::default();
+        let geo = MarketId(1);
+        for _ in 0..10 {
+            model.record_delivery(geo, "geo");
+        }
+        let full = model.outlook(geo, "geo", 0.5, &cfg);
+        assert!(full.is_saturated());
+        assert_eq!(model.outlook(MarketId(2), "geo", 0.5, &cfg).saturation, 0.0, "segments are per market");
+        for _ in 0..120 {
+            model.decay_month(&cfg);
+        }
+        assert!(model.segments.is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! Propellant depot designs. Every location starts with a fixed stock
+//! depot; a player design picks its own specs — capacity, insulation,
+//! docking ports, solar arrays and cryocoolers — and runs the same
+//! `Proposed → InDesign → Testing ⇄ Revising` workflow as a reactor.
+//! Specs can change until the design leaves `InDesign`, and its mass
+//! and unit cost follow from them (`DepotDesign::mass_kg`,
+//! `DepotDesign::unit_cost`). Flaws still in the design fly with every
+//! depot built from it and roll daily once it's in place.
+
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::{BalanceConfig, DepotDesignConfig};
+use crate::flaw::{self, Flaw, FlawId};
+use crate::infrastructure::DepotDesign;
+
+/// Unique identifier for a depot design project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DepotProjectId(pub u64);
+
+/// Workflow status of a depot design.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DepotDesignStatus {
+    /// A draft still in the editor. No work accrues; promoted to
+    /// `InDesign` when the player commits to it.
+    Proposed,
+    InDesign { work_completed: f64, work_required: f64 },
+    Testing { work_completed: f64 },
+    Revising { remaining_indices: Vec<usize>, work_completed: f64 },
+}
+
+/// A depot design project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepotProject {
+    pub project_id: DepotProjectId,
+    pub design: DepotDesign,
+    pub status: DepotDesignStatus,
+    pub flaws: Vec<Flaw>,
+    pub revision: u32,
+    pub teams_assigned: u32,
+    /// Cumulative engineering salary spent on this design (NRE).
+    pub nre_cost: f64,
+    /// Cumulative work spent in testing (persists across revisions).
+    pub cumulative_testing_work: f64,
+    /// Depots of this design launched so far.
+    #[serde(default)]
+    pub launched: u32,
+}
+
+/// Events generated by depot design work.
+#[derive(Debug, Clone)]
+pub enum DepotWorkEvent {
+    DesignComplete { flaw_count: u32 },
+    TestingCycleComplete,
+    FlawDiscovered { flaw_description: String },
+    RevisionComplete,
+}
+
+/// Design work for `design`: more ports and more insulation take longer.
+pub fn depot_design_work_required(design: &DepotDesign, cfg: &DepotDesignConfig) -> f64 {
+    cfg.design_work
+        + design.docking_ports.saturating_sub(1) as f64 * cfg.design_work_per_port
+        + design.insulation * cfg.design_work_per_insulation
+}
+
+/// Pull `design`'s specs back inside what can be built.
+pub fn clamp_specs(design: &mut DepotDesign, cfg: &DepotDesignConfig) {
+    design.capacity_kg = design.capacity_kg.max(0.0);
+    design.solar_array_kw_at_1au = design.solar_array_kw_at_1au.max(0.0);
+    design.cryocooler_kw = design.cryocooler_kw.max(0.0);
+    design.insulation = design.insulation.clamp(0.0, cfg.max_insulation);
+    design.docking_ports = design.docking_ports.clamp(1, cfg.max_docking_ports);
+}
+
+impl DepotProject {
+    /// A draft starting from the stock depot's specs, plumbed for
+    /// anything.
+    pub fn new_proposed(project_id: DepotProjectId, name: String, balance_cfg: &BalanceConfig) -> Self {
+        let mut design = DepotDesign::stock(&balance_cfg.isru, 1.0, Vec::new());
+        design.name = name;
+        DepotProject {
+            project_id,
+            design,
+            status: DepotDesignStatus::Proposed,
+            flaws: Vec::new(),
+            revision: 0,
+            teams_assigned: 0,
+            nre_cost: 0.0,
+            cumulative_testing_work: 0.0,
+            launched: 0,
+        }
+    }
+
+    /// Promote a `Proposed` draft to `InDesign` with no work completed.
+    /// No-op if not Proposed.
+    pub fn promote_to_in_design(&mut self, cfg: &DepotDesignConfig) {
+        if matches!(self.status, DepotDesignStatus::Proposed) {
+            self.status = DepotDesignStatus::InDesign {
+                work_completed: 0.0,
+                work_required: depot_design_work_required(&self.design, cfg),
+            };
+        }
+    }
+
+    /// True while the specs can still change.
+    pub fn editable(&self) -> bool {
+        matches!(self.status, DepotDesignStatus::Proposed | DepotDesignStatus::InDesign { .. })
+    }
+
+    /// Replace the specs, clamped to what can be built. The design work
+    /// is re-derived and work already done is kept, up to the new
+    /// total. False once the design has left `InDesign`.
+    pub fn apply_edit(&mut self, mut design: DepotDesign, cfg: &DepotDesignConfig) -> bool {
+        if !self.editable() {
+            return false;
+        }
+        clamp_specs(&mut design, cfg);
+        self.design = design;
+        if let DepotDesignStatus::InDesign { work_completed, work_required } = &mut self.status {
+            *work_required = depot_design_work_required(&self.design, cfg);
+            *work_completed = work_completed.min(*work_required);
+        }
+        true
+    }
+
+    /// Ready to be built and flown.
+    pub fn is_flight_ready(&self) -> bool {
+        matches!(self.status, DepotDesignStatus::Testing { .. })
+    }
+
+    /// Apply one day of work, done at `efficiency` of a full day's.
+    /// Returns any completed work events.
+    pub fn apply_daily_work(
+        &mut self, efficiency: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig,
+    ) -> Vec<DepotWorkEvent> {
+        if self.teams_assigned == 0 {
+            return Vec::new();
+        }
+        let work = crate::team::effective_work_rate(self.teams_assigned) * efficiency;
+        let mut events = Vec::new();
+
+        match &mut self.status {
+            DepotDesignStatus::Proposed => {}
+            DepotDesignStatus::InDesign { work_completed, work_required } => {
+                *work_completed += work;
+                if *work_completed >= *work_required {
+                    let cfg = &balance_cfg.depot_design;
+                    let mean = cfg.flaw_count_mean
+                        + self.design.docking_ports.saturating_sub(1) as f64 * cfg.flaws_per_port;
+                    self.flaws = flaw::generate_depot_flaws(mean, rng, next_flaw_id, &balance_cfg.flaws);
+                    let flaw_count = self.flaws.len() as u32;
+                    self.status = DepotDesignStatus::Testing { work_completed: 0.0 };
+                    events.push(DepotWorkEvent::DesignComplete { flaw_count });
+                }
+            }
+            DepotDesignStatus::Testing { work_completed } => {
+                *work_completed += work;
+                self.cumulative_testing_work += work;
+                while *work_completed >= balance_cfg.work.testing_cycle_work {
+                    *work_completed -= balance_cfg.work.testing_cycle_work;
+                    let discovered = flaw::roll_discoveries_with_rng(
+                        &mut self.flaws, rng, balance_cfg.flaws.anomaly_discovery_multiplier,
+                    );
+                    for idx in discovered {
+                        events.push(DepotWorkEvent::FlawDiscovered {
+                            flaw_description: self.flaws[idx].description.clone(),
+                        });
+                    }
+                    events.push(DepotWorkEvent::TestingCycleComplete);
+                }
+            }
+            DepotDesignStatus::Revising { remaining_indices, work_completed } => {
+                *work_completed += work;
+                while let Some(&fi) = remaining_indices.first() {
+                    let fix_work = self.flaws[fi].fix_work(balance_cfg.work.flaw_revision_work);
+                    if *work_completed < fix_work {
+                        break;
+                    }
+                    *work_completed -= fix_work;
+                    remaining_indices.remove(0);
+                    self.flaws.remove(fi);
+                    events.push(DepotWorkEvent::RevisionComplete);
+                    for idx in remaining_indices.iter_mut() {
+                        if *idx > fi {
+                            *idx -= 1;
+                        }
+                    }
+                }
+                if remaining_indices.is_empty() {
+                    let leftover = *work_completed;
+                    self.status = DepotDesignStatus::Testing { work_completed: leftover };
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Start fixing all discovered flaws.
+    pub fn start_revision(&mut self) -> bool {
+        if !matches!(self.status, DepotDesignStatus::Testing { .. }) {
+            return false;
+        }
+        let discovered_indices: Vec<usize> = self.flaws.iter()
+            .enumerate()
+            .filter(|(_, f)| f.discovered)
+            .map(|(i, _)| i)
+            .collect();
+        if discovered_indices.is_empty() {
+            return false;
+        }
+        self.revision += 1;
+        self.status = DepotDesignStatus::Revising {
+            remaining_indices: discovered_indices,
+            work_completed: 0.0,
+        };
+        true
+    }
+
+    /// Number of discovered flaws.
+    pub fn discovered_flaw_count(&self) -> usize {
+        self.flaws.iter().filter(|f| f.discovered).count()
+    }
+
+    /// Mark flaw `id` found, as when it bites a depot in service. True
+    /// if it was still hidden here.
+    pub fn reveal_flaw(&mut self, id: FlawId) -> bool {
+        match self.flaws.iter_mut().find(|f| f.id == id && !f.discovered) {
+            Some(f) => {
+                f.discovered = true;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_specs_edit_until_design_completes() {
+        let bal = BalanceConfig::default();
+        let cfg = &bal.depot_design;
+        let mut p = DepotProject::new_proposed(DepotProjectId(1), "Haven".into(), &bal);
+        let stock_cost = p.design.unit_cost(cfg);
+        assert!((stock_cost - 48_000_000.0).abs() < 1.0);
+
+        let mut bigger = p.design.clone();
+        bigger.insulation = 2.0;
+        bigger.docking_ports = 4;
+        assert!(p.apply_edit(bigger, cfg));
+        assert_eq!(p.design.insulation, cfg.max_insulation, "insulation is capped");
+        assert!(p.design.unit_cost(cfg) > stock_cost && p.design.mass_kg(cfg) > 0.0);
+
+        p.promote_to_in_design(cfg);
+        let DepotDesignStatus::InDesign { work_required, .. } = p.status else { panic!() };
+        assert!((work_required - (cfg.design_work + 3.0 * cfg.design_work_per_port
+            + cfg.max_insulation * cfg.design_work_per_insulation)).abs() < 1e-9);
+
+        p.teams_assigned = 4;
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut next_flaw_id = 0u64;
+        let mut days = 0;
+        while !p.is_flight_ready() && days < 500 {
+            p.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal);
+            days += 1;
+        }
+        assert!(p.is_flight_ready());
+        assert!(p.flaws.iter().all(|f| f.trigger == crate::flaw::FlawTrigger::PerDay));
+        assert!(!p.apply_edit(p.design.clone(), cfg), "specs freeze once testing starts");
+    }
+}
@@ -0,0 +1,168 @@
+//! Shareable design files. A rocket design is exported with a snapshot
+//! of every engine it flies, so another game can take it in: engines
+//! the player designed come back as fresh engine projects re-derived
+//! from the importing game's baselines, and contracted engines are
+//! matched to that game's third-party catalog. Import is checked
+//! against the importing game's unlocked technology.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::engine::{EngineDesign, EngineId};
+use crate::engine_project::PropellantPreset;
+use crate::rocket::RocketDesign;
+use crate::technology::TechnologyId;
+
+/// Version written into every design file. Files from a newer version
+/// are refused.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Where an exported engine came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EngineOrigin {
+    /// Designed in-house: the choices needed to design it again.
+    Designed {
+        preset: PropellantPreset,
+        scale: f64,
+        technology_id: Option<TechnologyId>,
+    },
+    /// Bought from a third-party supplier, matched by name on import.
+    Contracted { preset: PropellantPreset },
+}
+
+/// An engine as it stood when its rocket was exported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    pub design: EngineDesign,
+    pub origin: EngineOrigin,
+}
+
+/// A portable rocket design.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesignFile {
+    pub format_version: u32,
+    pub design: RocketDesign,
+    /// One snapshot per distinct engine the design flies.
+    pub engines: Vec<EngineSnapshot>,
+}
+
+impl DesignFile {
+    pub fn new(design: RocketDesign, engines: Vec<EngineSnapshot>) -> Self {
+        DesignFile { format_version: FORMAT_VERSION, design, engines }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("design files always serialize")
+    }
+
+    /// Parse a design file, refusing newer versions and files whose
+    /// stages fly an engine with no snapshot.
+    pub fn from_json(json: &str) -> Result<Self, DesignImportError> {
+        let file: DesignFile = serde_json::from_str(json)
+            .map_err(|e| DesignImportError::Malformed(e.to_string()))?;
+        if file.format_version > FORMAT_VERSION {
+            return Err(DesignImportError::UnsupportedVersion(file.format_version));
+        }
+        for stage in file.design.stage_groups.iter().flatten() {
+            if file.engine(stage.engine.id).is_none() {
+                return Err(DesignImportError::MissingEngine(stage.engine.name.clone()));
+            }
+        }
+        Ok(file)
+    }
+
+    /// The snapshot for the engine with `id`, as exported.
+    pub fn engine(&self, id: EngineId) -> Option<&EngineSnapshot> {
+        self.engines.iter().find(|e| e.design.id == id)
+    }
+}
+
+/// Why a design file can't be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DesignImportError {
+    /// Not a design file.
+    Malformed(String),
+    /// Written by a newer version of the game.
+    UnsupportedVersion(u32),
+    /// A stage flies an engine the file has no snapshot of.
+    MissingEngine(String),
+    /// An engine needs a technology this game hasn't unlocked.
+    TechnologyLocked { engine: String, technology: String },
+    /// A contracted engine this game's suppliers don't offer (yet).
+    EngineUnavailable(String),
+    /// The design doesn't hold together with this game's engines.
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for DesignImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DesignImportError::Malformed(e) => write!(f, "Not a design file: {}", e),
+            DesignImportError::UnsupportedVersion(v) =>
+                write!(f, "Design file version {} is newer than this game supports ({})", v, FORMAT_VERSION),
+            DesignImportError::MissingEngine(name) => write!(f, "Design file is missing engine {}", name),
+            DesignImportError::TechnologyLocked { engine, technology } =>
+                write!(f, "{} needs {}, which isn't unlocked", engine, technology),
+            DesignImportError::EngineUnavailable(name) => write!(f, "{} isn't available from any supplier", name),
+            DesignImportError::Invalid(problems) => write!(f, "Design doesn't validate: {}", problems.join("; ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{EngineCycle, PropellantFraction};
+    use crate::propellant::Propellant;
+    use crate::rocket::RocketDesignId;
+    use crate::stage::{Stage, StageId};
+
+    fn file() -> DesignFile {
+        let engine = EngineDesign {
+            id: EngineId(7), name: "Kestrel".into(),
+            cycle: EngineCycle::GasGenerator,
+            thrust_n: 1.0, mass_kg: 1.0, isp_s: 300.0,
+            exit_pressure_pa: 1.0, needs_atmosphere: true,
+            propellant_mix: vec![PropellantFraction { propellant: Propellant::LOX, mass_fraction: 1.0 }],
+            power_draw_w: 0.0,
+        };
+        let stage = Stage {
+            id: StageId(1), name: "S1".into(),
+            engine: engine.clone(), engine_count: 1,
+            propellant_mass_kg: 100.0, structural_mass_kg: 10.0,
+            fairing: None, power_sources: Vec::new(),
+            crossfeed: false, long_coast_kit: false, landing_kit: false, launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let design = RocketDesign {
+            id: RocketDesignId(3), name: "Falcon".into(),
+            stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.02),
+        };
+        DesignFile::new(design, vec![EngineSnapshot {
+            design: engine,
+            origin: EngineOrigin::Designed { preset: PropellantPreset::Kerolox, scale: 1.0, technology_id: None },
+        }])
+    }
+
+    #[test]
+    fn test_roundtrip_and_rejections() {
+        let json = file().to_json();
+        let back = DesignFile::from_json(&json).unwrap();
+        assert_eq!(back.design.name, "Falcon");
+        assert_eq!(back.engine(EngineId(7)).unwrap().origin, file().engines[0].origin);
+
+        assert!(matches!(DesignFile::from_json("{}"), Err(DesignImportError::Malformed(_))));
+
+        let mut newer = file();
+        newer.format_version = FORMAT_VERSION + 1;
+        assert_eq!(DesignFile::from_json(&newer.to_json()).unwrap_err(),
+            DesignImportError::UnsupportedVersion(FORMAT_VERSION + 1));
+
+        let mut stripped = file();
+        stripped.engines.clear();
+        assert_eq!(DesignFile::from_json(&stripped.to_json()).unwrap_err(),
+            DesignImportError::MissingEngine("Kestrel".into()));
+    }
+}
@@ -0,0 +1,232 @@
+//! Rocket designs as plain nested data for a scene to draw. A 3D
+//! preview wants the whole vehicle at once — every stage with its
+//! engines, propellant and size — rather than a getter per property,
+//! so a design is exported as one tree of records that serializes to
+//! nested dictionaries, outline included (see [`crate::silhouette`]).
+//! A `DesignWatcher` re-exports the design the
+//! designer is editing and says when it changed, so the scene rebuilds
+//! only then. Nothing here is saved.
+
+use serde::Serialize;
+
+use crate::engine::EngineCycle;
+use crate::propellant::Propellant;
+use crate::rocket::RocketDesign;
+use crate::silhouette::Silhouette;
+use crate::stage::{Stage, StagingMode};
+
+/// The engine a stage flies.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EngineResource {
+    pub name: String,
+    pub cycle: EngineCycle,
+    pub propellants: Vec<Propellant>,
+    pub thrust_n: f64,
+    pub isp_s: f64,
+    pub mass_kg: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FairingResource {
+    pub diameter_m: f64,
+    pub mass_kg: f64,
+    pub recover: bool,
+}
+
+/// One stage, sized.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageResource {
+    pub name: String,
+    /// Strapped to the side of its group's core rather than stacked.
+    pub booster: bool,
+    pub engine: EngineResource,
+    pub engine_count: u32,
+    pub propellant_mass_kg: f64,
+    pub dry_mass_kg: f64,
+    pub diameter_m: f64,
+    pub length_m: f64,
+    pub fairing: Option<FairingResource>,
+    pub crossfeed: bool,
+    pub landing_kit: bool,
+    pub launch_abort_system: bool,
+    pub staging: StagingMode,
+}
+
+/// A whole design. `stage_groups` keeps the design's shape: group 0
+/// fires first, and the first stage of each group is its core.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DesignResource {
+    pub name: String,
+    pub stage_groups: Vec<Vec<StageResource>>,
+    /// The outline to scale.
+    pub silhouette: Silhouette,
+    /// Widest core or fairing.
+    pub max_diameter_m: f64,
+    pub wet_mass_kg: f64,
+    pub dry_mass_kg: f64,
+}
+
+impl StageResource {
+    fn from_stage(stage: &Stage, booster: bool) -> Self {
+        let engine = &stage.engine;
+        StageResource {
+            name: stage.name.clone(),
+            booster,
+            engine: EngineResource {
+                name: engine.name.clone(),
+                cycle: engine.cycle,
+                propellants: engine.propellant_mix.iter().map(|f| f.propellant).collect(),
+                thrust_n: engine.thrust_n,
+                isp_s: engine.isp_s,
+                mass_kg: engine.mass_kg,
+            },
+            engine_count: stage.engine_count,
+            propellant_mass_kg: stage.propellant_mass_kg,
+            dry_mass_kg: stage.dry_mass_kg(),
+            diameter_m: stage.diameter_m(),
+            length_m: stage.length_m(),
+            fairing: stage.fairing.as_ref().map(|f| FairingResource {
+                diameter_m: f.diameter_m,
+                mass_kg: f.mass_kg,
+                recover: f.recover,
+            }),
+            crossfeed: stage.crossfeed,
+            landing_kit: stage.landing_kit,
+            launch_abort_system: stage.launch_abort_system,
+            staging: stage.staging,
+        }
+    }
+}
+
+impl DesignResource {
+    pub fn from_design(design: &RocketDesign) -> Self {
+        let stage_groups: Vec<Vec<StageResource>> = design.stage_groups.iter()
+            .map(|group| group.iter().enumerate()
+                .map(|(si, stage)| StageResource::from_stage(stage, si > 0))
+                .collect())
+            .collect();
+        let max_diameter_m = stage_groups.iter().filter_map(|g| g.first())
+            .flat_map(|s| std::iter::once(s.diameter_m).chain(s.fairing.as_ref().map(|f| f.diameter_m)))
+            .fold(0.0, f64::max);
+        let dry_mass_kg = design.stage_groups.iter().flatten().map(|s| s.dry_mass_kg()).sum();
+        DesignResource {
+            name: design.name.clone(),
+            silhouette: Silhouette::of(design),
+            max_diameter_m,
+            wet_mass_kg: design.total_mass_kg(),
+            dry_mass_kg,
+            stage_groups,
+        }
+    }
+
+    /// The tree as nested dictionaries, keyed by the field names above.
+    pub fn to_dictionary(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("design resources always serialize")
+    }
+}
+
+/// Watches a design being edited. Each `observe` re-exports it; when
+/// the export differs from the last one the revision goes up and the
+/// new resource is handed back — the signal to redraw.
+#[derive(Debug, Clone, Default)]
+pub struct DesignWatcher {
+    current: Option<DesignResource>,
+    revision: u64,
+}
+
+impl DesignWatcher {
+    /// Export `design`; Some with the new resource if it changed.
+    pub fn observe(&mut self, design: &RocketDesign) -> Option<&DesignResource> {
+        let resource = DesignResource::from_design(design);
+        if self.current.as_ref() == Some(&resource) {
+            return None;
+        }
+        self.current = Some(resource);
+        self.revision += 1;
+        self.current.as_ref()
+    }
+
+    /// The last resource seen, if any.
+    pub fn current(&self) -> Option<&DesignResource> {
+        self.current.as_ref()
+    }
+
+    /// How many times the design has changed since the watch began.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Stop watching; the next `observe` counts as a change.
+    pub fn clear(&mut self) {
+        self.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{EngineDesign, EngineId, PropellantFraction};
+    use crate::rocket::RocketDesignId;
+    use crate::stage::{Fairing, StageId};
+
+    fn stage(id: u64, propellant_mass_kg: f64) -> Stage {
+        Stage {
+            id: StageId(id),
+            name: format!("S{}", id),
+            engine: EngineDesign {
+                id: EngineId(1),
+                name: "Kestrel".into(),
+                cycle: EngineCycle::GasGenerator,
+                thrust_n: 1_000_000.0,
+                mass_kg: 500.0,
+                isp_s: 300.0,
+                exit_pressure_pa: 100_000.0,
+                needs_atmosphere: false,
+                propellant_mix: vec![
+                    PropellantFraction { propellant: Propellant::LOX, mass_fraction: 0.725 },
+                    PropellantFraction { propellant: Propellant::RP1, mass_fraction: 0.275 },
+                ],
+                power_draw_w: 0.0,
+            },
+            engine_count: 1,
+            propellant_mass_kg,
+            structural_mass_kg: 1_500.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: StagingMode::Cold,
+        }
+    }
+
+    #[test]
+    fn test_export_nests_stages_and_signals_changes() {
+        let mut upper = stage(3, 5_000.0);
+        upper.fairing = Some(Fairing { mass_kg: 200.0, diameter_m: 4.0, recover: false });
+        let mut design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Falcon".into(),
+            stage_groups: vec![vec![stage(1, 20_000.0), stage(2, 20_000.0)], vec![upper]],
+            performance_reserve: Some(0.0),
+        };
+
+        let resource = DesignResource::from_design(&design);
+        let core = &resource.stage_groups[0][0];
+        assert!(!core.booster && resource.stage_groups[0][1].booster);
+        assert_eq!(resource.silhouette.attachments.len(), 1);
+        assert_eq!(resource.max_diameter_m, 4.0, "the fairing is the widest part");
+        let dict = resource.to_dictionary();
+        assert_eq!(dict["stage_groups"][0][1]["engine"]["cycle"], "GasGenerator");
+        assert_eq!(dict["stage_groups"][1][0]["fairing"]["diameter_m"], 4.0);
+        assert_eq!(dict["silhouette"]["segments"][0]["kind"], "Tank");
+
+        let mut watcher = DesignWatcher::default();
+        assert!(watcher.observe(&design).is_some());
+        assert!(watcher.observe(&design).is_none(), "nothing changed");
+        design.stage_groups[0][0].engine_count = 3;
+        assert_eq!(watcher.observe(&design).map(|r| r.stage_groups[0][0].engine_count), Some(3));
+        assert_eq!(watcher.revision(), 2);
+    }
+}
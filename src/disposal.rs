@@ -0,0 +1,112 @@
+//! Where spent stages end up. The first stage of a multi-stage vehicle
+//! falls into the site's downrange zone, cleared with the regulator for
+//! each launch. Middle stages burn up on the way. The stage still
+//! attached when a flight ends is disposed of by the design's choice:
+//! a controlled deorbit, if enough delta-v is left for the burn, or
+//! abandonment. Stages abandoned in Earth orbit count as the company's
+//! orbital debris.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::regulation::LicenseClass;
+
+/// What a design does with its last stage after the mission.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpperStageDisposal {
+    /// Burn the leftovers to bring it down; debris anyway if they don't
+    /// cover the burn.
+    #[default]
+    Deorbit,
+    /// Leave it where it ends up.
+    Abandon,
+}
+
+impl UpperStageDisposal {
+    pub fn toggled(self) -> Self {
+        match self {
+            UpperStageDisposal::Deorbit => UpperStageDisposal::Abandon,
+            UpperStageDisposal::Abandon => UpperStageDisposal::Deorbit,
+        }
+    }
+}
+
+impl fmt::Display for UpperStageDisposal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpperStageDisposal::Deorbit => write!(f, "deorbit"),
+            UpperStageDisposal::Abandon => write!(f, "abandon"),
+        }
+    }
+}
+
+/// Where one spent stage ended up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StageFate {
+    /// Fell into the downrange zone. `cleared` is false when it landed
+    /// outside the cleared area.
+    Downrange { zone: String, cleared: bool },
+    /// Staged on the way and burned up on reentry.
+    Reentered,
+    /// Deorbited under control at the end of the mission.
+    Deorbited,
+    /// Left in Earth orbit.
+    Debris { location: String },
+    /// Left beyond Earth orbit, where the regulator doesn't count it.
+    LeftBehind { location: String },
+}
+
+impl fmt::Display for StageFate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StageFate::Downrange { zone, cleared: true } => write!(f, "fell in the {}", zone),
+            StageFate::Downrange { zone, cleared: false } => write!(f, "fell outside the {}", zone),
+            StageFate::Reentered => write!(f, "burned up on reentry"),
+            StageFate::Deorbited => write!(f, "deorbited"),
+            StageFate::Debris { location } => write!(f, "left as debris in {}", location),
+            StageFate::LeftBehind { location } => write!(f, "left at {}", location),
+        }
+    }
+}
+
+/// One stage group's disposal on a flight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageDisposal {
+    /// Index into the design's stage groups.
+    pub stage_group: usize,
+    pub fate: StageFate,
+}
+
+/// Fate of the stage still attached when a flight ends at
+/// `destination`. `remaining_dv` is what it has left for a deorbit
+/// burn costing `deorbit_dv`.
+pub fn final_stage_fate(
+    disposal: UpperStageDisposal,
+    destination: &str,
+    location_name: &str,
+    remaining_dv: f64,
+    deorbit_dv: f64,
+) -> StageFate {
+    if LicenseClass::for_destination(destination) != LicenseClass::EarthOrbit {
+        return StageFate::LeftBehind { location: location_name.to_string() };
+    }
+    match disposal {
+        UpperStageDisposal::Deorbit if remaining_dv >= deorbit_dv => StageFate::Deorbited,
+        _ => StageFate::Debris { location: location_name.to_string() },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deorbit_needs_the_delta_v_and_only_earth_orbit_leaves_debris() {
+        let fate = |disposal, dest, dv| final_stage_fate(disposal, dest, "Somewhere", dv, 120.0);
+        assert_eq!(fate(UpperStageDisposal::Deorbit, "leo", 200.0), StageFate::Deorbited);
+        assert!(matches!(fate(UpperStageDisposal::Deorbit, "leo", 50.0), StageFate::Debris { .. }));
+        assert!(matches!(fate(UpperStageDisposal::Abandon, "leo", 500.0), StageFate::Debris { .. }));
+        assert!(matches!(fate(UpperStageDisposal::Deorbit, "lunar_orbit", 0.0), StageFate::LeftBehind { .. }));
+    }
+}
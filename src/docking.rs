@@ -0,0 +1,71 @@
+//! Rendezvous and docking. A payload that has to meet something
+//! already in space — a servicing kit its asset, a plant the depot it
+//! berths at — flies one more risky event on arrival, apart from the
+//! launch: the approach. Its risk comes from the flight's avionics and
+//! software: immature flight software and hidden avionics or guidance
+//! flaws make a botched approach likelier.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::balance_config::DockingConfig;
+use crate::flaw::{Flaw, Subsystem};
+
+/// How an approach went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockingOutcome {
+    Docked,
+    /// The approach was called off; the payload never made it aboard.
+    Aborted,
+    /// The payload hit its target, damaging it.
+    Collision,
+}
+
+/// Hidden flaws among `flaws` that would show up on an approach.
+pub fn hidden_approach_flaws(flaws: &[Flaw]) -> usize {
+    flaws.iter()
+        .filter(|f| !f.discovered && matches!(f.subsystem, Subsystem::Avionics | Subsystem::GncSoftware))
+        .count()
+}
+
+/// Chance an approach goes wrong for a flight whose software was at
+/// `software_maturity` with `hidden_flaws` approach flaws aboard.
+pub fn failure_chance(software_maturity: f64, hidden_flaws: usize, cfg: &DockingConfig) -> f64 {
+    let immature = cfg.base_failure_chance * (1.0 - cfg.maturity_mitigation * software_maturity.clamp(0.0, 1.0));
+    (immature + cfg.hidden_flaw_risk * hidden_flaws as f64).clamp(0.0, 1.0)
+}
+
+/// Fly an approach with `failure_chance` of going wrong.
+pub fn roll(failure_chance: f64, rng: &mut StdRng, cfg: &DockingConfig) -> DockingOutcome {
+    let draw = rng.gen::<f64>();
+    if draw >= failure_chance {
+        DockingOutcome::Docked
+    } else if draw < failure_chance * cfg.collision_share {
+        DockingOutcome::Collision
+    } else {
+        DockingOutcome::Aborted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_mature_software_and_clean_avionics_dock_more_reliably() {
+        let cfg = DockingConfig::default();
+        let raw = failure_chance(0.0, 0, &cfg);
+        let mature = failure_chance(1.0, 0, &cfg);
+        assert!(mature < raw);
+        assert!(failure_chance(1.0, 2, &cfg) > mature);
+        assert_eq!(failure_chance(0.0, 1_000, &cfg), 1.0);
+
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(roll(0.0, &mut rng, &cfg), DockingOutcome::Docked);
+        let outcomes: Vec<_> = (0..200).map(|_| roll(1.0, &mut rng, &cfg)).collect();
+        assert!(outcomes.contains(&DockingOutcome::Aborted));
+        assert!(outcomes.contains(&DockingOutcome::Collision));
+        assert!(!outcomes.contains(&DockingOutcome::Docked));
+    }
+}
@@ -144,6 +144,18 @@ impl EngineDesign {
         matches!(self.cycle, EngineCycle::SolarSail)
     }
 
+    /// Whether this engine is a solid rocket motor.
+    pub fn is_solid(&self) -> bool {
+        self.propellant_mix.iter().any(|f| f.propellant == Propellant::SolidMix)
+    }
+
+    /// Whether this engine can throttle down (e.g. near burnout or
+    /// through max-Q). Solid motors burn at their grain's fixed rate;
+    /// sails have nothing to throttle.
+    pub fn can_throttle(&self) -> bool {
+        !self.is_solar_sail() && !self.is_solid()
+    }
+
     /// Propellant cost per kg of total propellant consumed.
     pub fn propellant_cost_per_kg(&self) -> f64 {
         self.propellant_mix.iter()
@@ -300,6 +300,24 @@ pub const MAX_SCALE: f64 = 4.0;
 pub const DEFAULT_SCALE: f64 = 1.0;
 pub const SCALE_STEP: f64 = 0.25;
 
+/// Combustion instability risk for an engine of `cycle` at `scale`
+/// (see `CombustionInstabilityConfig`). Only engines with a combustion
+/// chamber carry it.
+pub fn instability_risk(cycle: EngineCycle, scale: f64, balance_cfg: &BalanceConfig) -> f64 {
+    match cycle {
+        EngineCycle::NuclearThermal | EngineCycle::ElectricPropulsion | EngineCycle::SolarSail => 0.0,
+        _ => balance_cfg.combustion_instability.risk(scale),
+    }
+}
+
+/// Design work for an engine of `effective` complexity, stretched by
+/// any instability risk at its scale.
+fn design_work(effective: u32, cycle: EngineCycle, scale: f64, balance_cfg: &BalanceConfig) -> f64 {
+    let risk = instability_risk(cycle, scale, balance_cfg);
+    balance_cfg.work.design_work_required(effective)
+        * (1.0 + risk * balance_cfg.combustion_instability.work_per_risk)
+}
+
 /// Status of an engine design project.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EngineDesignStatus {
@@ -357,6 +375,32 @@ pub struct EngineProject {
     /// Which technology this engine uses (if experimental).
     #[serde(default)]
     pub technology_id: Option<crate::technology::TechnologyId>,
+    /// Set on a scaled variant of another engine: its parent link and
+    /// the heritage it carries over.
+    #[serde(default)]
+    pub heritage: Option<EngineHeritage>,
+    /// A combustion instability testing found that no revision can
+    /// fix. Holds the design in revision until it's edited down to the
+    /// onset scale or onto another cycle.
+    #[serde(default)]
+    pub fundamental_issue: Option<FundamentalIssue>,
+}
+
+/// A fundamental issue found in testing an oversized engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundamentalIssue {
+    pub description: String,
+    /// The cycle it was found on; a different cycle clears it.
+    pub cycle: EngineCycle,
+}
+
+/// A variant's link to the engine it was scaled from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineHeritage {
+    pub parent: EngineProjectId,
+    /// The parent's flaws at derivation, discovered ones still known.
+    /// They join the variant's own flaws when its design completes.
+    pub inherited_flaws: Vec<Flaw>,
 }
 
 impl EngineProject {
@@ -376,7 +420,7 @@ impl EngineProject {
         let propellants = preset.propellants();
         let complexity = balance::combined_complexity(cycle, &propellants);
         let effective = balance::effective_complexity(cycle, &propellants);
-        let work_required = balance_cfg.work.design_work_required(effective);
+        let work_required = design_work(effective, cycle, scale, balance_cfg);
 
         let thrust = baseline.thrust_n * scale;
         let mass = baseline.mass_kg * scale;
@@ -418,9 +462,45 @@ impl EngineProject {
             cumulative_testing_work: 0.0,
             tech_deficiency_ids: Vec::new(),
             technology_id: None,
+            heritage: None,
+            fundamental_issue: None,
         })
     }
 
+    /// Derive a variant of `parent` at a new `scale`. It shares the
+    /// parent's cycle, propellants and nozzle, so it needs only part of
+    /// a clean-sheet design effort, starts with part of the parent's
+    /// test history, and carries the parent's flaws (known ones stay
+    /// known) in place of some of its own. None if the parent's design
+    /// isn't finished.
+    pub fn derive_variant(
+        parent: &EngineProject,
+        project_id: EngineProjectId,
+        engine_id: EngineId,
+        name: String,
+        scale: f64,
+        balance_cfg: &BalanceConfig,
+    ) -> Option<Self> {
+        if !matches!(parent.status, EngineDesignStatus::Testing { .. } | EngineDesignStatus::Revising { .. }) {
+            return None;
+        }
+        let cfg = &balance_cfg.heritage;
+        let use_vacuum = !parent.design.needs_atmosphere;
+        let mut p = Self::new(
+            project_id, engine_id, name, parent.design.cycle, parent.preset, scale, use_vacuum, balance_cfg,
+        )?;
+        if let EngineDesignStatus::InDesign { work_required, .. } = &mut p.status {
+            *work_required *= cfg.variant_design_work_fraction;
+        }
+        p.cumulative_testing_work = parent.cumulative_testing_work * cfg.testing_carryover;
+        p.technology_id = parent.technology_id;
+        p.heritage = Some(EngineHeritage {
+            parent: parent.project_id,
+            inherited_flaws: parent.flaws.clone(),
+        });
+        Some(p)
+    }
+
     /// Create a tentative engine project in `Proposed` status. Used by
     /// the rocket designer to spawn a draft engine that can be iterated
     /// on; promoted to InDesign when the parent rocket is finalised.
@@ -448,7 +528,8 @@ impl EngineProject {
     /// the engine editor for non-linear editing. Recomputes complexity
     /// and work_required; for InDesign/Revising statuses, work_completed
     /// is clamped to the new work_required so a player can't appear to
-    /// have over-completed a now-cheaper design.
+    /// have over-completed a now-cheaper design. An edit that clears a
+    /// fundamental issue sends the engine back to the drawing board.
     pub fn apply_edit(
         &mut self,
         name: String,
@@ -465,7 +546,7 @@ impl EngineProject {
         let propellants = preset.propellants();
         let complexity = balance::combined_complexity(cycle, &propellants);
         let effective = balance::effective_complexity(cycle, &propellants);
-        let work_required = balance_cfg.work.design_work_required(effective);
+        let work_required = design_work(effective, cycle, scale, balance_cfg);
 
         let use_vacuum = if baseline.vacuum_only { true } else { use_vacuum_isp };
         let isp = if use_vacuum { baseline.isp_vac_s } else { baseline.isp_sl_s };
@@ -488,6 +569,15 @@ impl EngineProject {
         self.scale = scale;
         self.complexity = complexity;
 
+        let cleared = self.fundamental_issue.as_ref().is_some_and(|issue| {
+            scale <= balance_cfg.combustion_instability.onset_scale || cycle != issue.cycle
+        });
+        if cleared {
+            self.fundamental_issue = None;
+            self.status = EngineDesignStatus::InDesign { work_completed: 0.0, work_required };
+            return true;
+        }
+
         match &mut self.status {
             EngineDesignStatus::Proposed { work_required: wr } => { *wr = work_required; }
             EngineDesignStatus::InDesign { work_completed, work_required: wr } => {
@@ -519,13 +609,20 @@ impl EngineProject {
         }
     }
 
-    /// Apply one day of work. Returns any completed work events.
-    pub fn apply_daily_work(&mut self, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<WorkEvent> {
+    /// Apply one day of work, done at `efficiency` of a full day's.
+    /// Returns any completed work events.
+    pub fn apply_daily_work(&mut self, efficiency: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<WorkEvent> {
         if self.teams_assigned == 0 {
             return Vec::new();
         }
-        let work = crate::team::effective_work_rate(self.teams_assigned);
+        let work = crate::team::effective_work_rate(self.teams_assigned) * efficiency;
+        self.apply_work(work, rng, next_flaw_id, balance_cfg)
+    }
+
+    /// Apply `work` units of work to whatever stage the project is in.
+    pub fn apply_work(&mut self, work: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<WorkEvent> {
         let mut events = Vec::new();
+        let mut fundamental: Option<FundamentalIssue> = None;
 
         match &mut self.status {
             EngineDesignStatus::Proposed { .. } => {
@@ -539,6 +636,21 @@ impl EngineProject {
                     let propellants = self.preset.propellants();
                     let eff = balance::effective_complexity(self.design.cycle, &propellants);
                     self.flaws = flaw::generate_flaws_for_cycle(eff, rng, next_flaw_id, Some(self.design.cycle), &balance_cfg.flaws);
+                    if let Some(heritage) = &mut self.heritage {
+                        // A variant only adds the flaws its scaling
+                        // introduces; the rest it shares with the parent.
+                        let own = (self.flaws.len() as f64 * balance_cfg.heritage.new_flaw_fraction).round() as usize;
+                        self.flaws.truncate(own);
+                        for mut f in heritage.inherited_flaws.drain(..) {
+                            f.id = flaw::FlawId(*next_flaw_id);
+                            *next_flaw_id += 1;
+                            self.flaws.push(f);
+                        }
+                    }
+                    let risk = instability_risk(self.design.cycle, self.scale, balance_cfg);
+                    self.flaws.extend(flaw::generate_instability_flaws(
+                        risk * balance_cfg.combustion_instability.flaws_per_risk, rng, next_flaw_id, &balance_cfg.flaws,
+                    ));
                     let flaw_count = self.flaws.len() as u32;
                     self.status = EngineDesignStatus::Testing { work_completed: 0.0 };
                     events.push(WorkEvent::DesignComplete { flaw_count });
@@ -547,10 +659,14 @@ impl EngineProject {
             EngineDesignStatus::Testing { work_completed } => {
                 *work_completed += work;
                 self.cumulative_testing_work += work;
+                let risk = instability_risk(self.design.cycle, self.scale, balance_cfg);
+                let issue_chance = risk * balance_cfg.combustion_instability.fundamental_issue_chance_per_risk;
                 // Check for testing cycle completion
-                while *work_completed >= balance_cfg.work.testing_cycle_work {
+                while fundamental.is_none() && *work_completed >= balance_cfg.work.testing_cycle_work {
                     *work_completed -= balance_cfg.work.testing_cycle_work;
-                    let discovered = flaw::roll_discoveries_with_rng(&mut self.flaws, rng);
+                    let discovered = flaw::roll_discoveries_with_rng(
+                        &mut self.flaws, rng, balance_cfg.flaws.anomaly_discovery_multiplier,
+                    );
                     for idx in discovered {
                         events.push(WorkEvent::FlawDiscovered {
                             flaw_description: self.flaws[idx].description.clone(),
@@ -564,15 +680,27 @@ impl EngineProject {
                         });
                         self.improvements.push(improvement);
                     }
+                    // Only oversized engines roll, so everything else keeps
+                    // its draws.
+                    if issue_chance > 0.0 && self.fundamental_issue.is_none() && rng.gen::<f64>() < issue_chance {
+                        fundamental = Some(FundamentalIssue {
+                            description: flaw::fundamental_instability_description(rng),
+                            cycle: self.design.cycle,
+                        });
+                    }
                     events.push(WorkEvent::TestingCycleComplete);
                 }
             }
             EngineDesignStatus::Revising { remaining_flaw_indices, remaining_improvement_indices, remaining_tech_deficiency_ids, work_completed } => {
                 *work_completed += work;
                 // Process flaws first
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_flaw_indices.is_empty() {
-                    *work_completed -= balance_cfg.work.flaw_revision_work;
-                    let fi = remaining_flaw_indices.remove(0);
+                while let Some(&fi) = remaining_flaw_indices.first() {
+                    let fix_work = self.flaws[fi].fix_work(balance_cfg.work.flaw_revision_work);
+                    if *work_completed < fix_work {
+                        break;
+                    }
+                    *work_completed -= fix_work;
+                    remaining_flaw_indices.remove(0);
                     self.flaws.remove(fi);
                     events.push(WorkEvent::RevisionComplete);
                     for idx in remaining_flaw_indices.iter_mut() {
@@ -582,7 +710,10 @@ impl EngineProject {
                     }
                 }
                 // Then actualize improvements
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_improvement_indices.is_empty() {
+                while remaining_flaw_indices.is_empty()
+                    && *work_completed >= balance_cfg.work.flaw_revision_work
+                    && !remaining_improvement_indices.is_empty()
+                {
                     *work_completed -= balance_cfg.work.flaw_revision_work;
                     let ii = remaining_improvement_indices.remove(0);
                     if let Some(imp) = self.improvements.get_mut(ii) {
@@ -605,13 +736,18 @@ impl EngineProject {
                     }
                 }
                 // Then attempt tech deficiency fixes
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_tech_deficiency_ids.is_empty() {
+                while remaining_flaw_indices.is_empty()
+                    && *work_completed >= balance_cfg.work.flaw_revision_work
+                    && !remaining_tech_deficiency_ids.is_empty()
+                {
                     *work_completed -= balance_cfg.work.flaw_revision_work;
                     let def_id = remaining_tech_deficiency_ids.remove(0);
                     events.push(WorkEvent::TechDeficiencyAttempted { deficiency_id: def_id });
                 }
+                // A fundamental issue holds the design here until an
+                // edit clears it.
                 if remaining_flaw_indices.is_empty() && remaining_improvement_indices.is_empty()
-                    && remaining_tech_deficiency_ids.is_empty()
+                    && remaining_tech_deficiency_ids.is_empty() && self.fundamental_issue.is_none()
                 {
                     let leftover = *work_completed;
                     self.status = EngineDesignStatus::Testing { work_completed: leftover };
@@ -619,10 +755,18 @@ impl EngineProject {
             }
         }
 
+        if let Some(issue) = fundamental {
+            events.push(WorkEvent::FundamentalIssue { description: issue.description.clone() });
+            self.fundamental_issue = Some(issue);
+            self.start_revision();
+        }
+
         events
     }
 
     /// Start revising all discovered flaws and pending improvements.
+    /// An engine with a fundamental issue always can, to get at the
+    /// editor.
     pub fn start_revision(&mut self) -> bool {
         if !matches!(self.status, EngineDesignStatus::Testing { .. }) {
             return false;
@@ -638,7 +782,9 @@ impl EngineProject {
             .map(|(i, _)| i)
             .collect();
         let tech_def_ids = self.tech_deficiency_ids.clone();
-        if flaw_indices.is_empty() && improvement_indices.is_empty() && tech_def_ids.is_empty() {
+        if flaw_indices.is_empty() && improvement_indices.is_empty() && tech_def_ids.is_empty()
+            && self.fundamental_issue.is_none()
+        {
             return false;
         }
         self.revision += 1;
@@ -651,6 +797,12 @@ impl EngineProject {
         true
     }
 
+    /// Record near-miss hints (flaw index, hint) from a flight.
+    pub fn record_anomalies(&mut self, hints: Vec<(usize, String)>) -> Option<WorkEvent> {
+        let hints = flaw::record_anomalies(&mut self.flaws, hints);
+        (!hints.is_empty()).then_some(WorkEvent::AnomalyDetected { hints })
+    }
+
     /// Number of discovered flaws.
     pub fn discovered_flaw_count(&self) -> usize {
         self.flaws.iter().filter(|f| f.discovered).count()
@@ -661,10 +813,14 @@ impl EngineProject {
         self.flaws.len()
     }
 
+    /// Whole testing cycles of work put in so far.
+    pub fn testing_cycles(&self, balance_cfg: &BalanceConfig) -> u32 {
+        (self.cumulative_testing_work / balance_cfg.work.testing_cycle_work) as u32
+    }
+
     /// Testing level description based on cumulative work in testing.
     pub fn testing_level(&self, balance_cfg: &BalanceConfig) -> &'static str {
-        let cycles = (self.cumulative_testing_work / balance_cfg.work.testing_cycle_work) as u32;
-        match cycles {
+        match self.testing_cycles(balance_cfg) {
             0 => "Untested",
             1..=2 => "Lightly Tested",
             3..=5 => "Moderately Tested",
@@ -672,6 +828,11 @@ impl EngineProject {
             _ => "Thoroughly Tested",
         }
     }
+
+    /// `testing_level` as a localizable `testing.<tier>` label.
+    pub fn testing_level_text(&self, balance_cfg: &BalanceConfig) -> crate::locale::Text {
+        crate::locale::Text::label("testing", self.testing_level(balance_cfg))
+    }
 }
 
 /// A potential engine improvement discovered during testing. The
@@ -822,6 +983,16 @@ pub enum WorkEvent {
     ImprovementActualized { description: String },
     /// A tech deficiency revision was attempted — caller must resolve with technology state.
     TechDeficiencyAttempted { deficiency_id: crate::technology::TechDeficiencyId },
+    /// A near-miss in flight left telemetry hints on hidden flaws.
+    AnomalyDetected { hints: Vec<String> },
+    /// Testing found a combustion instability only a scale-down or a
+    /// new cycle will fix; the engine has gone into revision.
+    FundamentalIssue { description: String },
+    /// Something moved every engineering team's morale; `average` is
+    /// where it landed.
+    MoraleShift { cause: crate::team::MoraleCause, average: f64 },
+    /// A demoralized team quit.
+    TeamResigned { team_name: String },
 }
 
 #[cfg(test)]
@@ -935,7 +1106,7 @@ mod tests {
         // Apply enough days
         let mut all_events = Vec::new();
         for _ in 0..(work_needed.ceil() as u32 + 1) {
-            let events = proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            let events = proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
             all_events.extend(events);
         }
 
@@ -952,7 +1123,7 @@ mod tests {
         let mut next_flaw_id = 0u64;
 
         for _ in 0..100 {
-            let events = proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            let events = proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
             assert!(events.is_empty());
         }
         // Should still be in design at 0 work
@@ -978,8 +1149,8 @@ mod tests {
 
         // After 10 days, proj2 should have more work done
         for _ in 0..10 {
-            proj1.apply_daily_work(&mut rng1, &mut id1, &bal());
-            proj2.apply_daily_work(&mut rng2, &mut id2, &bal());
+            proj1.apply_daily_work(1.0, &mut rng1, &mut id1, &bal());
+            proj2.apply_daily_work(1.0, &mut rng2, &mut id2, &bal());
         }
 
         let work1 = match &proj1.status {
@@ -1004,7 +1175,7 @@ mod tests {
 
         // Fast-forward to testing
         for _ in 0..300 {
-            proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
         }
 
         // Manually add a discovered flaw for testing
@@ -1018,6 +1189,8 @@ mod tests {
                 discovery_probability: 0.5,
                 discovered: true,
                 trigger: crate::flaw::FlawTrigger::PerFlight,
+                subsystem: crate::flaw::Subsystem::Combustion,
+                anomaly: None,
             });
         }
 
@@ -1027,9 +1200,10 @@ mod tests {
         assert!(proj.start_revision());
         assert!(matches!(proj.status, EngineDesignStatus::Revising { .. }));
 
-        // Work through all revisions (30 work units each, sqrt(4) = 2/day)
-        for _ in 0..50 {
-            proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+        // Work through all revisions (30 work units each scaled by subsystem,
+        // sqrt(4) = 2/day)
+        for _ in 0..80 {
+            proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
         }
 
         assert_eq!(proj.flaws.len(), count_before - discovered_count);
@@ -1070,4 +1244,81 @@ mod tests {
         // GG Kerolox: cycle=6, fuel=4 → max(6,4)=6
         assert_eq!(proj.complexity, 6);
     }
+
+    #[test]
+    fn test_variant_inherits_parent_heritage() {
+        let bal = bal();
+        let mut rng = test_rng();
+        let mut next_flaw_id = 1u64;
+        let mut parent = create_test_project();
+        assert!(EngineProject::derive_variant(&parent, EngineProjectId(2), EngineId(2), "V".into(), 1.5, &bal).is_none(),
+            "no variants before the parent's design is done");
+
+        parent.teams_assigned = 4;
+        while matches!(parent.status, EngineDesignStatus::InDesign { .. }) {
+            parent.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal);
+        }
+        parent.cumulative_testing_work = 200.0;
+        parent.flaws[0].discovered = true;
+        let known = parent.flaws[0].description.clone();
+
+        let mut variant = EngineProject::derive_variant(&parent, EngineProjectId(2), EngineId(2), "V".into(), 1.5, &bal).unwrap();
+        assert_eq!(variant.heritage.as_ref().unwrap().parent, parent.project_id);
+        assert_eq!(variant.cumulative_testing_work, 200.0 * bal.heritage.testing_carryover);
+        assert!((variant.design.thrust_n / parent.design.thrust_n - 1.5).abs() < 1e-9);
+        let work_required = |p: &EngineProject| match p.status {
+            EngineDesignStatus::InDesign { work_required, .. } => work_required,
+            _ => panic!("not in design"),
+        };
+        assert_eq!(work_required(&variant),
+            work_required(&create_test_project()) * bal.heritage.variant_design_work_fraction);
+
+        variant.teams_assigned = 4;
+        while matches!(variant.status, EngineDesignStatus::InDesign { .. }) {
+            variant.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal);
+        }
+        assert!(variant.flaws.iter().any(|f| f.discovered && f.description == known),
+            "the parent's known flaw stays known");
+        assert!(variant.heritage.as_ref().unwrap().inherited_flaws.is_empty());
+        assert!(variant.flaws.iter().all(|f| parent.flaws.iter().all(|pf| pf.id != f.id)),
+            "inherited flaws get fresh ids");
+    }
+
+    #[test]
+    fn test_oversized_engine_risks_combustion_instability() {
+        let mut bal = bal();
+        let work_required = |p: &EngineProject| match p.status {
+            EngineDesignStatus::InDesign { work_required, .. } => work_required,
+            _ => panic!("not in design"),
+        };
+        let at = |scale: f64, cycle: EngineCycle, bal: &BalanceConfig| EngineProject::new(
+            EngineProjectId(1), EngineId(1), "Big".into(), cycle, PropellantPreset::Kerolox, scale, true, bal,
+        ).unwrap();
+        let base = work_required(&at(1.0, EngineCycle::GasGenerator, &bal));
+        assert_eq!(work_required(&at(2.0, EngineCycle::GasGenerator, &bal)), base, "no risk up to the onset");
+        assert!((work_required(&at(4.0, EngineCycle::GasGenerator, &bal)) - 2.0 * base).abs() < 1e-9);
+        assert_eq!(instability_risk(EngineCycle::NuclearThermal, 4.0, &bal), 0.0);
+        let mut rng = test_rng();
+        let mut next_flaw_id = 0u64;
+        assert_eq!(flaw::generate_instability_flaws(4.0, &mut rng, &mut next_flaw_id, &bal.flaws).len(), 4);
+        assert!(flaw::generate_instability_flaws(0.0, &mut rng, &mut next_flaw_id, &bal.flaws).is_empty());
+
+        // Testing turns up a fundamental issue that holds the engine in
+        // revision until it's scaled back.
+        bal.combustion_instability.fundamental_issue_chance_per_risk = 1.0;
+        let mut p = at(4.0, EngineCycle::GasGenerator, &bal);
+        p.status = EngineDesignStatus::Testing { work_completed: 0.0 };
+        let events = p.apply_work(bal.work.testing_cycle_work, &mut rng, &mut next_flaw_id, &bal);
+        assert!(events.iter().any(|e| matches!(e, WorkEvent::FundamentalIssue { .. })));
+        assert!(p.fundamental_issue.is_some());
+        p.apply_work(10.0 * bal.work.flaw_revision_work, &mut rng, &mut next_flaw_id, &bal);
+        assert!(matches!(p.status, EngineDesignStatus::Revising { .. }), "revision alone can't fix it");
+
+        let name = p.design.name.clone();
+        p.apply_edit(name.clone(), EngineCycle::GasGenerator, PropellantPreset::Kerolox, 3.0, true, &bal);
+        assert!(p.fundamental_issue.is_some(), "still too big");
+        p.apply_edit(name, EngineCycle::GasGenerator, PropellantPreset::Kerolox, 2.0, true, &bal);
+        assert!(p.fundamental_issue.is_none());
+        assert_eq!(work_required(&p), base, "back to the drawing board at the new size");
+    }
 }
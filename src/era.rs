@@ -0,0 +1,137 @@
+//! Historical start eras.
+//!
+//! A game can start in a different decade: earlier eras hold back
+//! markets and technologies that hadn't arrived yet and price
+//! everything in the dollars of the day. An era is an `EraConfig` in
+//! the balance; `apply` rewrites the rest of the balance to match
+//! before the game is created, and the game reads the timeline from
+//! `balance.era` as it runs.
+
+use crate::balance_config::{BalanceConfig, EraConfig, MarketOpening, TechArrival};
+use crate::contract::{
+    EmergenceSpec, MARKET_COTS, MARKET_EARTH_OBS, MARKET_GEO_COMSATS, MARKET_RIDESHARE,
+};
+use crate::technology::TECH_METHALOX;
+
+/// The built-in eras, earliest first.
+pub fn presets() -> Vec<EraConfig> {
+    let methalox = TechArrival { tech: TECH_METHALOX, year: 2005 };
+    let opening = |market, year, flavor: &str| MarketOpening { market, year, flavor: flavor.into() };
+    vec![
+        EraConfig {
+            key: "space_race".into(),
+            name: "Space Race".into(),
+            start_year: 1961,
+            price_level: 0.17,
+            market_openings: vec![
+                opening(MARKET_GEO_COMSATS, 1965,
+                    "Early Bird proves geostationary relays — commercial comsat orders follow"),
+                opening(MARKET_EARTH_OBS, 1972, "Civil land imaging satellites enter service"),
+                opening(MARKET_RIDESHARE, 1990, "Secondary payload adapters open launches to small satellites"),
+                opening(MARKET_COTS, 2006, "The space agency starts buying station cargo commercially"),
+            ],
+            tech_arrivals: vec![methalox.clone()],
+        },
+        EraConfig {
+            key: "shuttle_era".into(),
+            name: "Shuttle Era".into(),
+            start_year: 1981,
+            price_level: 0.51,
+            market_openings: vec![
+                opening(MARKET_RIDESHARE, 1990, "Secondary payload adapters open launches to small satellites"),
+                opening(MARKET_COTS, 2006, "The space agency starts buying station cargo commercially"),
+            ],
+            tech_arrivals: vec![methalox],
+        },
+        EraConfig::default(),
+        EraConfig {
+            key: "new_space".into(),
+            name: "New Space".into(),
+            start_year: 2021,
+            price_level: 1.53,
+            market_openings: Vec::new(),
+            tech_arrivals: Vec::new(),
+        },
+    ]
+}
+
+/// The built-in era with `key`.
+pub fn preset(key: &str) -> Option<EraConfig> {
+    presets().into_iter().find(|e| e.key == key)
+}
+
+/// Rewrite `balance` for `era`: scale `costs` and every market's
+/// contract rates to the era's price level, hold each listed market
+/// closed until its opening year, and record the era for the running
+/// game. Apply once, before the game is created.
+pub fn apply(era: EraConfig, balance: &mut BalanceConfig) {
+    let p = era.price_level;
+    let costs = &mut balance.costs;
+    costs.starting_money *= p;
+    costs.engineering_monthly_salary *= p;
+    costs.engineering_hiring_cost *= p;
+    costs.manufacturing_monthly_salary *= p;
+    costs.manufacturing_hiring_cost *= p;
+    costs.floor_space_cost *= p;
+    costs.reactor_ref_material_cost *= p;
+    let prices = &mut costs.resource_prices;
+    for price in [
+        &mut prices.aluminium, &mut prices.steel, &mut prices.superalloys, &mut prices.composites,
+        &mut prices.wiring, &mut prices.electronics, &mut prices.plumbing,
+        &mut prices.solid_propellant, &mut prices.heu,
+    ] {
+        *price *= p;
+    }
+
+    for arch in &mut balance.markets.archetypes {
+        for dest in &mut arch.template.destinations {
+            dest.rate_per_kg *= p;
+        }
+        let Some(opening) = era.market_openings.iter()
+            .find(|o| o.market == arch.template.id && o.year > era.start_year)
+        else { continue };
+        // A start-active market becomes one that emerges in its
+        // opening year; an emerging one can't emerge before it.
+        match &mut arch.emergence {
+            Some(e) => {
+                e.year_range.0 = e.year_range.0.max(opening.year);
+                e.year_range.1 = e.year_range.1.max(e.year_range.0);
+            }
+            None => {
+                arch.template.active = false;
+                arch.emergence = Some(EmergenceSpec {
+                    year_range: (opening.year, opening.year),
+                    flavor: opening.flavor.clone(),
+                    cross_effects: Vec::new(),
+                });
+            }
+        }
+    }
+    balance.era = era;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_early_era_reprices_and_holds_markets_back() {
+        let base = BalanceConfig::default();
+        let mut balance = base.clone();
+        apply(preset("space_race").unwrap(), &mut balance);
+
+        assert_eq!(balance.era.start_year, 1961);
+        assert!((balance.costs.starting_money - 0.17 * base.costs.starting_money).abs() < 1e-6);
+        assert!((balance.costs.resource_prices.steel - 0.17 * base.costs.resource_prices.steel).abs() < 1e-9);
+
+        let geo = balance.markets.archetypes.iter()
+            .find(|a| a.template.id == MARKET_GEO_COMSATS).unwrap();
+        assert!(!geo.template.active);
+        assert_eq!(geo.emergence.as_ref().unwrap().year_range, (1965, 1965));
+
+        // The default era changes nothing.
+        let mut same = base.clone();
+        apply(EraConfig::default(), &mut same);
+        assert_eq!(same, base);
+    }
+}
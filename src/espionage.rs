@@ -0,0 +1,62 @@
+//! Industrial espionage between companies.
+//!
+//! Competitors occasionally copy one of the player's finished engine
+//! designs; a security budget makes that rarer. The traffic runs both
+//! ways: leaked competitor data turns up for sale, granting testing
+//! work or early access to a technology.
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::EspionageConfig;
+use crate::calendar::GameDate;
+use crate::technology::TechnologyId;
+
+/// What a leaked data package contains.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LeakKind {
+    /// Test data worth this many testing cycles on an engine in testing.
+    TestingWork { cycles: u32 },
+    /// Enough to unlock a technology outright.
+    Technology { technology_id: TechnologyId, name: String },
+}
+
+/// Leaked competitor data on offer to the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeakOffer {
+    /// The competitor the data came from.
+    pub source: String,
+    pub kind: LeakKind,
+    pub price: f64,
+    /// Last day the offer can be taken.
+    pub expires: GameDate,
+}
+
+impl LeakOffer {
+    pub fn description(&self) -> String {
+        match &self.kind {
+            LeakKind::TestingWork { cycles } =>
+                format!("{} engine test data ({} testing cycles)", self.source, cycles),
+            LeakKind::Technology { name, .. } =>
+                format!("{} research on {}", self.source, name),
+        }
+    }
+}
+
+/// Monthly chance a competitor copies one of the player's engines, at
+/// the given security level.
+pub fn copy_chance(cfg: &EspionageConfig, security_level: u32) -> f64 {
+    cfg.copy_chance_per_month * cfg.security_leak_factor.powi(security_level as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_security_cuts_copy_chance() {
+        let cfg = EspionageConfig::default();
+        assert_eq!(copy_chance(&cfg, 0), cfg.copy_chance_per_month);
+        assert!(copy_chance(&cfg, 1) < copy_chance(&cfg, 0));
+        assert!(copy_chance(&cfg, cfg.max_security_level) < 0.01);
+    }
+}
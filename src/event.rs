@@ -4,6 +4,7 @@ use std::fmt;
 use serde::{Serialize, Deserialize};
 
 use crate::calendar::GameDate;
+use crate::locale::Text;
 
 /// Game events — informational records of things that happened.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,12 +17,24 @@ pub enum GameEvent {
     EngineDesignStarted { engine_name: String },
     EngineDesignComplete { engine_name: String, flaw_count: u32 },
     FlawDiscovered { engine_name: String, flaw_description: String },
+    /// Testing found an oversized engine's combustion unstable past
+    /// revising; it needs a scale-down or a new cycle.
+    EngineFundamentalIssue { engine_name: String, description: String },
     RevisionComplete { engine_name: String },
     SalariesPaid { amount: f64 },
     InsufficientFunds { shortfall: f64 },
     EngineContracted { engine_name: String },
     // Phase 3: Rocket design events
     RocketDesignStarted { rocket_name: String },
+    /// A rocket design was forked into a variant branch.
+    RocketBranchForked { rocket_name: String, branch_name: String },
+    /// A branch's design was folded back into its parent as a new revision.
+    RocketBranchMerged { branch_name: String, rocket_name: String, revision: u32 },
+    /// A branch was abandoned.
+    RocketBranchRetired { branch_name: String },
+    /// A design file was imported as a new rocket project; `unit_cost`
+    /// is its first-unit material cost at this game's prices.
+    RocketDesignImported { rocket_name: String, new_engines: u32, unit_cost: f64 },
     RocketDesignComplete { rocket_name: String, flaw_count: u32 },
     RocketFlawDiscovered { rocket_name: String, flaw_description: String },
     RocketRevisionComplete { rocket_name: String },
@@ -55,6 +68,29 @@ pub enum GameEvent {
     BidPlaced { contract_name: String, amount: f64 },
     ContractAwarded { contract_name: String, amount: f64 },
     BidRejected { contract_name: String },
+    /// A cost-plus customer audited the books at settlement.
+    ContractAudited { contract_name: String, disallowed: f64 },
+    /// A cost-plus fee was held back for a late launch or partial delivery.
+    ContractFeeWithheld { contract_name: String },
+    /// A flight burned `dv` of its upper-stage reserve to trim its
+    /// insertion into a customer's tolerance.
+    InsertionCorrected { rocket_name: String, dv: f64 },
+    /// A payload was delivered `error` m/s off target against a
+    /// tolerance of `tolerance`; the customer held back `withheld`.
+    InsertionOutOfTolerance { contract_name: String, error: f64, tolerance: f64, withheld: f64 },
+    /// A science payload arrived and began its data campaign.
+    ScienceCampaignStarted { contract_name: String, months: u32 },
+    /// A science asset finished its data campaign.
+    ScienceCampaignComplete { contract_name: String, returned: f64 },
+    /// A science asset failed before its data campaign finished.
+    ScienceAssetLost { contract_name: String, returned: f64 },
+    /// A contract's launch was handed to an outside provider.
+    ContractSubcontracted { contract_name: String, provider: String, fee: f64 },
+    /// An outside provider flew a subcontracted payload and the
+    /// customer paid.
+    SubcontractDelivered { contract_name: String, provider: String, payment: f64 },
+    /// The engineering time spent on a losing proposal, written off.
+    ProposalWrittenOff { contract_name: String, cost: f64 },
     /// A competitor won the award. The winning price is public
     /// market news — losing teaches the player the going rate.
     ContractAwardedToCompetitor {
@@ -72,11 +108,129 @@ pub enum GameEvent {
     LaunchSuccess { rocket_name: String, destination: String },
     LaunchPartialFailure { rocket_name: String, reason: String },
     LaunchFailure { rocket_name: String, reason: String },
+    /// Outcome of a fairing recovery operation after launch.
+    FairingRecovery { rocket_name: String, sea_state: u32, recovered: bool },
+    /// A booster with a landing kit tried to fly home; `flights` counts
+    /// its flights so far.
+    BoosterLanding { rocket_name: String, serial: u64, flights: u32, landed: bool },
+    /// A booster with a landing kit had nowhere to land.
+    BoosterExpended { rocket_name: String, reason: String },
+    RecoveryAssetPurchased { name: String, cost: f64 },
+    /// A drone ship put to sea; it reaches the landing area on `on_station`.
+    DroneShipSailed { name: String, on_station: crate::calendar::GameDate },
+    RecoveryFleetUpkeepPaid { amount: f64 },
+    /// A customer's payload slipped `days`; it's now ready on `ready_on`.
+    PayloadSlipped { contract_name: String, days: u32, ready_on: crate::calendar::GameDate },
+    /// A customer shipped its payload to the pad.
+    PayloadReady { contract_name: String },
+    /// The customer paid for a payload slip.
+    SlipCompensationPaid { contract_name: String, amount: f64 },
+    /// A payload slip pushed the contract's deadline back.
+    ContractRenegotiated { contract_name: String, deadline: crate::calendar::GameDate },
+    /// The pad is tied up readying the next vehicle until `until`.
+    PadFlowStarted { until: crate::calendar::GameDate, cost: f64 },
+    /// The pad closed for conversion to `mode` integration.
+    PadConversionStarted { mode: String, ready_on: crate::calendar::GameDate, cost: f64 },
+    PadConverted { mode: String },
+    /// A challenge objective was met on `day` of the run.
+    ChallengeObjectiveMet { goal: String, day: u32 },
+    ChallengeWon { name: String, day: u32, score: u32 },
+    ChallengeFailed { name: String, reason: String },
+    /// The tutorial's `step` was done.
+    TutorialStepDone { step: String },
+    TutorialFinished,
+    /// A sandbox edit was made; the game is marked as sandbox.
+    SandboxEdit { description: String },
+    /// A rocket launched with its liquid tanks only `share` full;
+    /// `saved` is what the propellant left off would have cost.
+    PropellantOffloaded { rocket_name: String, share: f64, saved: f64 },
+    /// The company moved its pad to another site.
+    LaunchSiteRelocated { site: String, cost: f64 },
+    /// The player rented pad days to another operator.
+    PadLeaseSigned { lessee: String, start: crate::calendar::GameDate, days: u32, daily_rate: f64 },
+    /// A pad lease ran its course; `income` is the total it paid.
+    PadLeaseCompleted { lessee: String, income: f64 },
+    LicenseApplied { class: crate::regulation::LicenseClass, fee: f64, ready_on: crate::calendar::GameDate },
+    LicenseGranted { class: crate::regulation::LicenseClass },
+    /// The launch site is closed for an environmental review.
+    EnvironmentalReview { reason: String, until: crate::calendar::GameDate },
+    RegulatoryFine { reason: String, amount: f64 },
+    /// The facility security-clearance upgrade began.
+    SecurityClearanceStarted { cost: f64, ready_on: crate::calendar::GameDate },
+    /// The company may now compete for export-controlled contracts.
+    SecurityClearanceGranted,
+    /// Export paperwork filed for a controlled contract's launch.
+    ExportPaperworkFiled { contract_name: String, fee: f64, ready_on: crate::calendar::GameDate },
+    /// A controlled payload flew before its paperwork cleared: fined,
+    /// and barred from controlled work until `banned_until`.
+    ExportViolation { contract_name: String, fine: f64, banned_until: crate::calendar::GameDate },
+    /// A failed launch destroyed the pad; it's closed until `ready_on`.
+    PadDestroyed { rocket_name: String, rebuild_cost: f64, ready_on: crate::calendar::GameDate },
+    PadRebuilt,
+    /// The insurer paid out on a destroyed pad.
+    InsuranceClaimPaid { amount: f64 },
+    PadInsurancePaid { amount: f64 },
+    /// The regulator grounded launches for a mishap investigation.
+    RegulatoryStandDown { until: crate::calendar::GameDate },
+    /// The press wants answers by `respond_by`.
+    PressCrisis { rocket_name: String, respond_by: crate::calendar::GameDate },
+    /// The company answered the press; `sentiment` is the change.
+    PressCrisisAnswered { response: crate::pad_failure::CrisisResponse, cost: f64, sentiment: f64, backfired: bool },
+    /// The press deadline passed without an answer.
+    PressCrisisIgnored { sentiment: f64 },
+    IpoCompleted { raised: f64, valuation: f64 },
+    /// A competitor copied one of the player's engine designs.
+    DesignCopied { engine_name: String, company: String },
+    LeakOffered { description: String, price: f64 },
+    LeakPurchased { description: String, price: f64 },
+    /// A fixed asset was sold off at book value.
+    AssetSold { name: String, proceeds: f64 },
+    CertificationStarted { rocket_name: String, cost: f64 },
+    CertificationGranted { rocket_name: String },
+    /// A flight failure voided a design's certification.
+    CertificationRevoked { rocket_name: String },
+    /// An engine flaw found in flight also affects the other designs
+    /// built on that engine.
+    CommonCauseFlaw { engine_name: String, flaw_description: String, designs: Vec<String> },
+    /// A successful flight showed telemetry anomalies from hidden flaws.
+    AnomalyDetected { vehicle_name: String, hints: Vec<String> },
+    SoftwareDesignStarted { software_name: String },
+    SoftwareDesignComplete { software_name: String, flaw_count: u32 },
+    SoftwareFlawDiscovered { software_name: String, flaw_description: String },
+    SoftwarePatched { software_name: String },
+    /// A rocket finished adapting its linked flight software.
+    SoftwareAdapted { rocket_name: String },
+    /// Engineers began checking a built rocket against a mission plan.
+    IntegrationCampaignStarted { rocket_name: String, destination: String, burn_count: u32 },
+    IntegrationCampaignComplete { rocket_name: String, destination: String },
+    /// Monthly fee for hardware sitting in storage.
+    StorageFeesPaid { amount: f64 },
+    /// A stored item passed its shelf life.
+    HardwareExpired { item_name: String, age_days: u32 },
+    RecertificationOrdered { item_name: String, cost: f64 },
+    HardwareRecertified { item_name: String },
+    BoosterInspectionOrdered { item_name: String, cost: f64 },
+    /// A booster teardown revealed its wear: `failure_chance` is the
+    /// risk of its next flight.
+    BoosterInspected { item_name: String, failure_chance: f64 },
+    InventoryScrapped { item_name: String, recovered: f64 },
+    RetrofitOrdered { rocket_name: String, from_revision: u32, to_revision: u32, cost: f64 },
+    RocketRetrofitted { rocket_name: String, revision: u32 },
+    /// A stored rocket was taken apart for its engines.
+    EnginesStripped { rocket_name: String, engines: u32, recovered: f64 },
+    /// The books closed on a fiscal quarter.
+    QuarterClosed { year: u32, quarter: u32, income: f64, expenses: f64 },
+    /// A department spent past its monthly budget.
+    BudgetOverrun { department: crate::budget::Department, spent: f64, budget: f64 },
+    /// Quarterly earnings against the listing's target.
+    EarningsReported { net: f64, target: f64, met: bool },
     PaymentReceived { amount: f64, contract_name: String },
     EngineBuildOrdered { engine_name: String },
     // Phase 5: Flight events
     FlightDeparted { rocket_name: String, destination: String },
     FlightArrived { rocket_name: String, destination: String },
+    /// A multi-leg flight finished one leg and started the next.
+    FlightLegComplete { rocket_name: String, location: String, leg: u32, legs: u32 },
     SpacecraftDeployed { spacecraft_name: String, location: String },
     SpacecraftDocked { small: String, large: String, location: String },
     SpacecraftUndocked { payload: String, carrier: String, location: String },
@@ -86,6 +240,8 @@ pub enum GameEvent {
     SpacecraftLost { rocket_name: String, location: String, reason: String },
     PowerLost { rocket_name: String, location: String },
     MidFlightFlawActivated { rocket_name: String, flaw_description: String, consequence: String },
+    /// Something went wrong on a transit leg (see `flight::AnomalyKind`).
+    TransitAnomaly { rocket_name: String, anomaly: String, response: String },
     /// Improvement discovered during testing.
     ImprovementDiscovered { engine_name: String, description: String },
     /// Improvement actualized via revision.
@@ -146,6 +302,121 @@ pub enum GameEvent {
         by_player: bool,
         missions_remaining: u32,
     },
+    /// An astronaut candidate joined the corps.
+    AstronautHired { name: String },
+    /// A candidate finished training and can fly.
+    AstronautQualified { name: String },
+    /// A crew was assigned to a rocket on the ground.
+    CrewAssigned { rocket_name: String, crew: Vec<String> },
+    /// A crew came home from a flight.
+    CrewReturned { rocket_name: String, crew: Vec<String> },
+    /// A crew died on a flight. Fame takes a heavy hit and every team
+    /// works at reduced efficiency until `stand_down_until`.
+    CrewLost {
+        rocket_name: String,
+        crew: Vec<String>,
+        stand_down_until: crate::calendar::GameDate,
+    },
+    /// The abort system pulled a crew clear of a failing booster.
+    CrewAborted { rocket_name: String, crew: Vec<String> },
+    /// A pad-abort test of a design's abort system was ordered.
+    PadAbortTestOrdered { rocket_name: String, cost: f64 },
+    /// A pad-abort test fired. A pass proves the abort system out
+    /// further and counts toward the design's certification.
+    PadAbortTested { rocket_name: String, passed: bool },
+    /// A government agency announced a subsidized program, open for
+    /// commitment until `respond_by`.
+    GovProgramAnnounced {
+        program: String,
+        monthly_subsidy: f64,
+        term_months: u32,
+        milestones: u32,
+        respond_by: crate::calendar::GameDate,
+    },
+    /// The player signed on to a government program.
+    GovProgramCommitted { program: String },
+    /// A month of program subsidy arrived.
+    GovSubsidyReceived { program: String, amount: f64 },
+    /// A successful delivery met a program milestone.
+    GovMilestoneMet { program: String, destination: String },
+    /// Every milestone of a program met.
+    GovProgramCompleted { program: String },
+    /// A milestone deadline passed unmet: the program ends and part of
+    /// the subsidy is clawed back.
+    GovProgramFailed { program: String, clawback: f64 },
+    /// An ISRU plant was set down and began producing propellant.
+    IsruPlantDeployed { plant_name: String, location: String },
+    /// A depot filled up; its ISRU plants idle until it's drawn down.
+    DepotFull { location: String },
+    DepotDesignStarted { depot_name: String },
+    DepotDesignComplete { depot_name: String, flaw_count: u32 },
+    DepotFlawDiscovered { depot_name: String, flaw_description: String },
+    DepotRevisionComplete { depot_name: String },
+    /// A player-designed depot took over a location; the stores
+    /// carried over up to its capacity.
+    DepotDeployed { depot_name: String, location: String },
+    /// A flaw in a depot in service bit and propellant was lost.
+    DepotFlawActivated { location: String, flaw_description: String, lost_kg: f64 },
+    /// An asset wore down to zero health: plants stop producing and a
+    /// spacecraft can't fly until it's serviced.
+    AssetWornOut { asset: String, location: String },
+    /// A servicing kit restored an asset to full health, and loaded
+    /// any propellant it carried.
+    AssetServiced { asset: String, location: String, propellant_kg: f64 },
+    /// A servicing kit arrived to find its asset gone.
+    ServicingMissed { asset: String, location: String },
+    /// An arriving payload's approach was called off; it never docked.
+    DockingAborted { payload: String, location: String },
+    /// An arriving payload hit what it was docking with.
+    DockingCollision { payload: String, target: String, location: String },
+    /// Spacecraft of one design at one location were grouped into a
+    /// constellation.
+    ConstellationFormed { name: String, location: String, required: u32 },
+    /// A constellation opened a new replacement task.
+    ReplacementNeeded { constellation: String, needed_by: GameDate },
+    /// Auto-replace launched a satellite for a constellation.
+    ReplacementLaunched { constellation: String, rocket_name: String },
+    /// A launch-day countdown was called off before liftoff.
+    LaunchScrubbed { rocket_name: String, reason: String },
+    /// A spacecraft took on propellant from a depot.
+    SpacecraftRefueled { spacecraft_name: String, location: String, propellant_kg: f64 },
+    /// A standing transfer order moved propellant between a spacecraft
+    /// and a depot.
+    PropellantTransferred { spacecraft_name: String, location: String, propellant_kg: f64, to_depot: bool },
+    /// An expended carrier's leftover propellant was pumped into the
+    /// depot where it arrived.
+    PropellantSalvaged { rocket_name: String, location: String, propellant_kg: f64 },
+    /// Something moved engineering morale; `average_morale` is where it landed.
+    MoraleChanged { cause: crate::team::MoraleCause, average_morale: f64 },
+    /// A demoralized engineering team quit.
+    TeamResigned { team_name: String },
+    /// The player let an engineering team go.
+    TeamFired { team_name: String },
+    /// An idle engineering team was sent home on reduced pay.
+    TeamFurloughed { team_name: String },
+    /// A furloughed engineering team came back to work.
+    TeamRecalled { team_name: String },
+    /// A year of wage inflation raised every salary by `rate`.
+    SalariesInflated { rate: f64 },
+    /// An experienced engineering team asked for a raise, to be
+    /// answered by `deadline`.
+    RaiseDemanded { team_name: String, monthly_raise: f64, deadline: GameDate },
+    /// The player gave a team the raise it asked for.
+    RaiseGranted { team_name: String, monthly_raise: f64 },
+    /// A team's raise was turned down; `quit` if it walked out.
+    RaiseRefused { team_name: String, quit: bool },
+    /// A PR campaign was bought.
+    PrCampaignStarted { cost: f64, days: u32 },
+    /// Press was booked for a rocket's launch.
+    PressEventScheduled { rocket_name: String, kind: crate::marketing::PressKind, cost: f64 },
+    /// A publicized launch made the news; `sentiment` is where media
+    /// sentiment landed.
+    PressCoverage { rocket_name: String, kind: crate::marketing::PressKind, success: bool, sentiment: f64 },
+    /// An outside firm was paid to review a design.
+    ExternalReviewOrdered { target_name: String, fee: f64, days: u32 },
+    /// An external review reported back; its flaws arrive as the usual
+    /// flaw-discovered events.
+    ExternalReviewComplete { target_name: String, flaws_found: u32 },
 }
 
 impl fmt::Display for GameEvent {
@@ -168,6 +439,8 @@ impl fmt::Display for GameEvent {
                 write!(f, "Design complete: {} ({} flaws)", engine_name, flaw_count),
             GameEvent::FlawDiscovered { engine_name, flaw_description } =>
                 write!(f, "Flaw found in {}: {}", engine_name, flaw_description),
+            GameEvent::EngineFundamentalIssue { engine_name, description } =>
+                write!(f, "Fundamental issue in {}: {} — scale it down or change its cycle", engine_name, description),
             GameEvent::RevisionComplete { engine_name } =>
                 write!(f, "Revision complete: {}", engine_name),
             GameEvent::SalariesPaid { amount } =>
@@ -176,6 +449,15 @@ impl fmt::Display for GameEvent {
                 write!(f, "Warning: ${:.0} in debt", shortfall),
             GameEvent::EngineContracted { engine_name } =>
                 write!(f, "Contracted engine: {}", engine_name),
+            GameEvent::RocketBranchForked { rocket_name, branch_name } =>
+                write!(f, "{} forked from {}", branch_name, rocket_name),
+            GameEvent::RocketBranchMerged { branch_name, rocket_name, revision } =>
+                write!(f, "{} merged into {} as revision {}", branch_name, rocket_name, revision),
+            GameEvent::RocketBranchRetired { branch_name } =>
+                write!(f, "{} retired", branch_name),
+            GameEvent::RocketDesignImported { rocket_name, new_engines, unit_cost } =>
+                write!(f, "Imported rocket design: {} ({} new engine design(s), ~{} per unit)",
+                    rocket_name, new_engines, crate::resources::format_money(*unit_cost)),
             GameEvent::RocketDesignStarted { rocket_name } =>
                 write!(f, "Started rocket design: {}", rocket_name),
             GameEvent::RocketDesignComplete { rocket_name, flaw_count } =>
@@ -231,6 +513,33 @@ impl fmt::Display for GameEvent {
                 write!(f, "Contract awarded: {} at {}", contract_name, crate::resources::format_money(*amount)),
             GameEvent::BidRejected { contract_name } =>
                 write!(f, "No award on {}: the bid exceeded the customer's budget", contract_name),
+            GameEvent::ContractAudited { contract_name, disallowed } =>
+                write!(f, "{} audited: {} of costs disallowed",
+                    contract_name, crate::resources::format_money(*disallowed)),
+            GameEvent::ContractFeeWithheld { contract_name } =>
+                write!(f, "{}: fee withheld (schedule missed or delivery short)", contract_name),
+            GameEvent::InsertionCorrected { rocket_name, dv } =>
+                write!(f, "{} burned {:.0} m/s to correct its insertion", rocket_name, dv),
+            GameEvent::InsertionOutOfTolerance { contract_name, error, tolerance, withheld } =>
+                write!(f, "{}: inserted {:.0} m/s off target (tolerance {:.0} m/s), {} withheld",
+                    contract_name, error, tolerance, crate::resources::format_money(*withheld)),
+            GameEvent::ScienceCampaignStarted { contract_name, months } =>
+                write!(f, "{}: returning data, paid as it comes down over {} months", contract_name, months),
+            GameEvent::ScienceCampaignComplete { contract_name, returned } =>
+                write!(f, "{}: data campaign complete, {} returned",
+                    contract_name, crate::resources::format_money(*returned)),
+            GameEvent::ScienceAssetLost { contract_name, returned } =>
+                write!(f, "{}: asset lost, data stopped after {}",
+                    contract_name, crate::resources::format_money(*returned)),
+            GameEvent::ContractSubcontracted { contract_name, provider, fee } =>
+                write!(f, "{} subcontracted to {} for {}",
+                    contract_name, provider, crate::resources::format_money(*fee)),
+            GameEvent::SubcontractDelivered { contract_name, provider, payment } =>
+                write!(f, "{} flew {}: customer paid {}",
+                    provider, contract_name, crate::resources::format_money(*payment)),
+            GameEvent::ProposalWrittenOff { contract_name, cost } =>
+                write!(f, "Proposal effort on {} written off ({})",
+                    contract_name, crate::resources::format_money(*cost)),
             GameEvent::ContractAwardedToCompetitor { contract_name, company, amount, player_bid } => {
                 match player_bid {
                     Some(b) => write!(f, "Outbid: {} goes to {} at {} (you bid {})",
@@ -256,6 +565,175 @@ impl fmt::Display for GameEvent {
                 write!(f, "Launch partial failure: {} ({})", rocket_name, reason),
             GameEvent::LaunchFailure { rocket_name, reason } =>
                 write!(f, "Launch failed: {} ({})", rocket_name, reason),
+            GameEvent::LaunchSiteRelocated { site, cost } =>
+                write!(f, "Pad moved to {} for {}", site, crate::resources::format_money(*cost)),
+            GameEvent::PadLeaseSigned { lessee, start, days, daily_rate } =>
+                write!(f, "Pad leased to {} for {} days from {} ({}/day)",
+                    lessee, days, start, crate::resources::format_money(*daily_rate)),
+            GameEvent::PadLeaseCompleted { lessee, income } =>
+                write!(f, "{} vacated the pad ({} lease income)", lessee, crate::resources::format_money(*income)),
+            GameEvent::LicenseApplied { class, fee, ready_on } =>
+                write!(f, "Applied for {} launch license ({}, ready {})",
+                    class, crate::resources::format_money(*fee), ready_on),
+            GameEvent::LicenseGranted { class } =>
+                write!(f, "{} launch license granted", class),
+            GameEvent::EnvironmentalReview { reason, until } =>
+                write!(f, "Environmental review ({}): launches suspended until {}", reason, until),
+            GameEvent::RegulatoryFine { reason, amount } =>
+                write!(f, "Fined {} for {}", crate::resources::format_money(*amount), reason),
+            GameEvent::SecurityClearanceStarted { cost, ready_on } =>
+                write!(f, "Security clearance upgrade started ({}, ready {})",
+                    crate::resources::format_money(*cost), ready_on),
+            GameEvent::SecurityClearanceGranted =>
+                write!(f, "Security clearance granted — export-controlled contracts open"),
+            GameEvent::ExportPaperworkFiled { contract_name, fee, ready_on } =>
+                write!(f, "Export paperwork filed for {} ({}, clears {})",
+                    contract_name, crate::resources::format_money(*fee), ready_on),
+            GameEvent::PadDestroyed { rocket_name, rebuild_cost, ready_on } =>
+                write!(f, "{} destroyed the pad — rebuild costs {}, ready {}",
+                    rocket_name, crate::resources::format_money(*rebuild_cost), ready_on),
+            GameEvent::PadRebuilt => write!(f, "Launch pad rebuilt"),
+            GameEvent::InsuranceClaimPaid { amount } =>
+                write!(f, "Insurer paid {} on the pad claim", crate::resources::format_money(*amount)),
+            GameEvent::PadInsurancePaid { amount } =>
+                write!(f, "Pad insurance premium: {}", crate::resources::format_money(*amount)),
+            GameEvent::RegulatoryStandDown { until } =>
+                write!(f, "Regulator stood launches down for the mishap investigation until {}", until),
+            GameEvent::PressCrisis { rocket_name, respond_by } =>
+                write!(f, "Press crisis over the {} disaster — answer by {}", rocket_name, respond_by),
+            GameEvent::PressCrisisAnswered { response, cost, sentiment, backfired } =>
+                write!(f, "Answered the press with a {} ({}): sentiment {:+.0}%{}",
+                    response, crate::resources::format_money(*cost), sentiment * 100.0,
+                    if *backfired { " — it backfired" } else { "" }),
+            GameEvent::PressCrisisIgnored { sentiment } =>
+                write!(f, "Silence on the pad disaster: sentiment {:+.0}%", sentiment * 100.0),
+            GameEvent::ExportViolation { contract_name, fine, banned_until } =>
+                write!(f, "Export violation on {}: fined {}, barred from controlled contracts until {}",
+                    contract_name, crate::resources::format_money(*fine), banned_until),
+            GameEvent::IpoCompleted { raised, valuation } =>
+                write!(f, "IPO raised {} at a {} valuation",
+                    crate::resources::format_money(*raised), crate::resources::format_money(*valuation)),
+            GameEvent::BudgetOverrun { department, spent, budget } =>
+                write!(f, "{} over budget: spent {} of {}",
+                    department.name(),
+                    crate::resources::format_money(*spent),
+                    crate::resources::format_money(*budget)),
+            GameEvent::QuarterClosed { year, quarter, income, expenses } =>
+                write!(f, "{} closed: income {}, expenses {}, net {}",
+                    crate::calendar::quarter_label(*year, *quarter),
+                    crate::resources::format_money(*income),
+                    crate::resources::format_money(*expenses),
+                    crate::resources::format_money(income - expenses)),
+            GameEvent::EarningsReported { net, target, met } => {
+                let verdict = if *met { "met" } else { "missed" };
+                write!(f, "Quarterly earnings {} {} target ({})",
+                    crate::resources::format_money(*net), verdict, crate::resources::format_money(*target))
+            }
+            GameEvent::DesignCopied { engine_name, company } =>
+                write!(f, "{} copied the {} engine design", company, engine_name),
+            GameEvent::LeakOffered { description, price } =>
+                write!(f, "Leaked data for sale: {} ({})", description, crate::resources::format_money(*price)),
+            GameEvent::AssetSold { name, proceeds } =>
+                write!(f, "Sold {} for {}", name, crate::resources::format_money(*proceeds)),
+            GameEvent::LeakPurchased { description, price } =>
+                write!(f, "Bought {} for {}", description, crate::resources::format_money(*price)),
+            GameEvent::CertificationStarted { rocket_name, cost } =>
+                write!(f, "{} certification review opened ({} documentation)",
+                    rocket_name, crate::resources::format_money(*cost)),
+            GameEvent::CertificationGranted { rocket_name } =>
+                write!(f, "{} certified for crewed and national-security missions", rocket_name),
+            GameEvent::CertificationRevoked { rocket_name } =>
+                write!(f, "{} certification revoked after a failure", rocket_name),
+            GameEvent::CommonCauseFlaw { engine_name, flaw_description, designs } =>
+                write!(f, "{} flaw ({}) also affects {}", engine_name, flaw_description, designs.join(", ")),
+            GameEvent::AnomalyDetected { vehicle_name, hints } =>
+                write!(f, "Telemetry anomaly on {}: {}", vehicle_name, hints.join("; ")),
+            GameEvent::SoftwareDesignStarted { software_name } =>
+                write!(f, "Started flight software: {}", software_name),
+            GameEvent::SoftwareDesignComplete { software_name, flaw_count } =>
+                write!(f, "Flight software complete: {} ({} flaws)", software_name, flaw_count),
+            GameEvent::SoftwareFlawDiscovered { software_name, flaw_description } =>
+                write!(f, "Software bug found in {}: {}", software_name, flaw_description),
+            GameEvent::SoftwarePatched { software_name } =>
+                write!(f, "Patch complete: {}", software_name),
+            GameEvent::SoftwareAdapted { rocket_name } =>
+                write!(f, "{} flight software adapted", rocket_name),
+            GameEvent::IntegrationCampaignStarted { rocket_name, destination, burn_count } =>
+                write!(f, "Integration campaign started: {} for {} ({} burns)", rocket_name, destination, burn_count),
+            GameEvent::IntegrationCampaignComplete { rocket_name, destination } =>
+                write!(f, "{} validated for {}", rocket_name, destination),
+            GameEvent::StorageFeesPaid { amount } =>
+                write!(f, "Storage fees paid: ${:.0}", amount),
+            GameEvent::HardwareExpired { item_name, age_days } =>
+                write!(f, "{} past shelf life after {} days in storage", item_name, age_days),
+            GameEvent::RecertificationOrdered { item_name, cost } =>
+                write!(f, "Recertification ordered: {} (${:.0})", item_name, cost),
+            GameEvent::HardwareRecertified { item_name } =>
+                write!(f, "{} recertified", item_name),
+            GameEvent::BoosterInspectionOrdered { item_name, cost } =>
+                write!(f, "Booster inspection ordered: {} (${:.0})", item_name, cost),
+            GameEvent::BoosterInspected { item_name, failure_chance } =>
+                write!(f, "{} inspected: {:.1}% risk on its next flight", item_name, failure_chance * 100.0),
+            GameEvent::RetrofitOrdered { rocket_name, from_revision, to_revision, cost } =>
+                write!(f, "Retrofit ordered: {} Rev {} → Rev {} (${:.0})", rocket_name, from_revision, to_revision, cost),
+            GameEvent::RocketRetrofitted { rocket_name, revision } =>
+                write!(f, "{} retrofitted to Rev {}", rocket_name, revision),
+            GameEvent::InventoryScrapped { item_name, recovered } =>
+                write!(f, "Scrapped {} (recovered ${:.0})", item_name, recovered),
+            GameEvent::EnginesStripped { rocket_name, engines, recovered } =>
+                write!(f, "Stripped {} engines from {} (scrap ${:.0})", engines, rocket_name, recovered),
+            GameEvent::FairingRecovery { rocket_name, sea_state, recovered } => {
+                if *recovered {
+                    write!(f, "Recovered {} fairing (sea state {})", rocket_name, sea_state)
+                } else {
+                    write!(f, "Lost {} fairing at sea (sea state {})", rocket_name, sea_state)
+                }
+            }
+            GameEvent::BoosterLanding { rocket_name, serial, flights, landed } => {
+                if *landed {
+                    write!(f, "{} booster B{} landed after flight {}", rocket_name, serial, flights)
+                } else {
+                    write!(f, "{} booster lost on landing", rocket_name)
+                }
+            }
+            GameEvent::BoosterExpended { rocket_name, reason } =>
+                write!(f, "{} booster expended: {}", rocket_name, reason),
+            GameEvent::RecoveryAssetPurchased { name, cost } =>
+                write!(f, "Bought {} for {}", name, crate::resources::format_money(*cost)),
+            GameEvent::DroneShipSailed { name, on_station } =>
+                write!(f, "{} sailed, on station {}", name, on_station),
+            GameEvent::RecoveryFleetUpkeepPaid { amount } =>
+                write!(f, "Recovery fleet upkeep: {}", crate::resources::format_money(*amount)),
+            GameEvent::PayloadSlipped { contract_name, days, ready_on } =>
+                write!(f, "{} payload slipped {} days, now ready {}", contract_name, days, ready_on),
+            GameEvent::PayloadReady { contract_name } =>
+                write!(f, "{} payload delivered to the pad", contract_name),
+            GameEvent::SlipCompensationPaid { contract_name, amount } =>
+                write!(f, "Slip compensation for {}: {}", contract_name, crate::resources::format_money(*amount)),
+            GameEvent::ContractRenegotiated { contract_name, deadline } =>
+                write!(f, "{} renegotiated, new deadline {}", contract_name, deadline),
+            GameEvent::PadFlowStarted { until, cost } =>
+                write!(f, "Pad readying next vehicle until {} ({})", until, crate::resources::format_money(*cost)),
+            GameEvent::PadConversionStarted { mode, ready_on, cost } =>
+                write!(f, "Pad closed for conversion to {} integration until {} ({})",
+                    mode, ready_on, crate::resources::format_money(*cost)),
+            GameEvent::PadConverted { mode } =>
+                write!(f, "Pad converted to {} integration", mode),
+            GameEvent::ChallengeObjectiveMet { goal, day } =>
+                write!(f, "Challenge objective met on day {}: {}", day, goal),
+            GameEvent::ChallengeWon { name, day, score } =>
+                write!(f, "Challenge {} won on day {} with {} points", name, day, score),
+            GameEvent::ChallengeFailed { name, reason } =>
+                write!(f, "Challenge {} failed: {}", name, reason),
+            GameEvent::TutorialStepDone { step } =>
+                write!(f, "Tutorial: {} — done", step),
+            GameEvent::TutorialFinished =>
+                write!(f, "Tutorial complete: your company has flown its first rocket"),
+            GameEvent::SandboxEdit { description } =>
+                write!(f, "Sandbox: {}", description),
+            GameEvent::PropellantOffloaded { rocket_name, share, saved } =>
+                write!(f, "{} loaded to {:.0}% propellant (saved {})",
+                    rocket_name, share * 100.0, crate::resources::format_money(*saved)),
             GameEvent::PaymentReceived { amount, contract_name } =>
                 write!(f, "Payment received: {} for {}",
                     crate::resources::format_money_exact(*amount), contract_name),
@@ -265,6 +743,8 @@ impl fmt::Display for GameEvent {
                 write!(f, "Flight departed: {} → {}", rocket_name, destination),
             GameEvent::FlightArrived { rocket_name, destination } =>
                 write!(f, "Flight arrived: {} at {}", rocket_name, destination),
+            GameEvent::FlightLegComplete { rocket_name, location, leg, legs } =>
+                write!(f, "{} reached {} (leg {}/{} done)", rocket_name, location, leg, legs),
             GameEvent::SpacecraftDeployed { spacecraft_name, location } =>
                 write!(f, "Deployed: {} at {}", spacecraft_name, location),
             GameEvent::SpacecraftDocked { small, large, location } =>
@@ -280,6 +760,8 @@ impl fmt::Display for GameEvent {
                     rocket_name, location),
             GameEvent::MidFlightFlawActivated { rocket_name, flaw_description, consequence } =>
                 write!(f, "In-flight flaw on {}: {} ({})", rocket_name, flaw_description, consequence),
+            GameEvent::TransitAnomaly { rocket_name, anomaly, response } =>
+                write!(f, "Transit anomaly on {}: {} — {}", rocket_name, anomaly, response),
             GameEvent::ImprovementDiscovered { engine_name, description } =>
                 write!(f, "Improvement found for {}: {}", engine_name, description),
             GameEvent::ImprovementActualized { engine_name, description } =>
@@ -320,6 +802,119 @@ impl fmt::Display for GameEvent {
             GameEvent::CampaignCancelled { program, company, missions_remaining, .. } =>
                 write!(f, "Program cancelled: the customer pulled {} from {} after repeated misses ({} missions forfeited)",
                     program, company, missions_remaining),
+            GameEvent::AstronautHired { name } =>
+                write!(f, "Astronaut candidate hired: {}", name),
+            GameEvent::AstronautQualified { name } =>
+                write!(f, "{} finished training and is qualified to fly", name),
+            GameEvent::CrewAssigned { rocket_name, crew } =>
+                write!(f, "Crew assigned to {}: {}", rocket_name, crew.join(", ")),
+            GameEvent::CrewReturned { rocket_name, crew } =>
+                write!(f, "{} crew home safe: {}", rocket_name, crew.join(", ")),
+            GameEvent::CrewLost { rocket_name, crew, stand_down_until } =>
+                write!(f, "Crew lost aboard {}: {}. The company stands down until {}",
+                    rocket_name, crew.join(", "), stand_down_until),
+            GameEvent::CrewAborted { rocket_name, crew } =>
+                write!(f, "{} abort system fired: crew recovered safely ({})", rocket_name, crew.join(", ")),
+            GameEvent::PadAbortTestOrdered { rocket_name, cost } =>
+                write!(f, "Pad-abort test ordered for {} ({})", rocket_name, crate::resources::format_money(*cost)),
+            GameEvent::PadAbortTested { rocket_name, passed } => if *passed {
+                write!(f, "{} pad-abort test passed", rocket_name)
+            } else {
+                write!(f, "{} pad-abort test failed: the capsule didn't clear the pad safely", rocket_name)
+            },
+            GameEvent::GovProgramAnnounced { program, monthly_subsidy, term_months, milestones, respond_by } =>
+                write!(f, "Government program announced: {} — {}/month for {} months, {} milestone(s); commit by {}",
+                    program, crate::resources::format_money(*monthly_subsidy), term_months, milestones, respond_by),
+            GameEvent::GovProgramCommitted { program } =>
+                write!(f, "Committed to {}", program),
+            GameEvent::GovSubsidyReceived { program, amount } =>
+                write!(f, "{} subsidy received: {}", program, crate::resources::format_money(*amount)),
+            GameEvent::GovMilestoneMet { program, destination } =>
+                write!(f, "{}: milestone met at {}", program, destination),
+            GameEvent::GovProgramCompleted { program } =>
+                write!(f, "{}: every milestone met", program),
+            GameEvent::IsruPlantDeployed { plant_name, location } =>
+                write!(f, "{} set down at {} and producing propellant", plant_name, location),
+            GameEvent::DepotFull { location } =>
+                write!(f, "Depot at {} is full; ISRU production idles", location),
+            GameEvent::DepotDesignStarted { depot_name } =>
+                write!(f, "Started depot design: {}", depot_name),
+            GameEvent::DepotDesignComplete { depot_name, flaw_count } =>
+                write!(f, "Depot design complete: {} ({} flaws)", depot_name, flaw_count),
+            GameEvent::DepotFlawDiscovered { depot_name, flaw_description } =>
+                write!(f, "Flaw found in {}: {}", depot_name, flaw_description),
+            GameEvent::DepotRevisionComplete { depot_name } =>
+                write!(f, "Revision complete: {}", depot_name),
+            GameEvent::DepotDeployed { depot_name, location } =>
+                write!(f, "{} in service at {}", depot_name, location),
+            GameEvent::DepotFlawActivated { location, flaw_description, lost_kg } =>
+                write!(f, "Depot at {}: {} — {:.0} kg lost", location, flaw_description, lost_kg),
+            GameEvent::AssetWornOut { asset, location } =>
+                write!(f, "{} at {} has worn out and needs servicing", asset, location),
+            GameEvent::AssetServiced { asset, location, propellant_kg } if *propellant_kg > 0.0 =>
+                write!(f, "{} serviced at {}: full health, {:.0} kg loaded", asset, location, propellant_kg),
+            GameEvent::AssetServiced { asset, location, .. } =>
+                write!(f, "{} serviced at {}: back to full health", asset, location),
+            GameEvent::ServicingMissed { asset, location } =>
+                write!(f, "Servicing kit reached {} but {} is gone", location, asset),
+            GameEvent::DockingAborted { payload, location } =>
+                write!(f, "{} aborted its approach at {} and was lost", payload, location),
+            GameEvent::DockingCollision { payload, target, location } =>
+                write!(f, "{} collided with {} at {} while docking", payload, target, location),
+            GameEvent::ConstellationFormed { name, location, required } =>
+                write!(f, "{} formed at {}: {} satellites required", name, location, required),
+            GameEvent::ReplacementNeeded { constellation, needed_by } =>
+                write!(f, "{}: replacement needed by {}", constellation, needed_by),
+            GameEvent::ReplacementLaunched { constellation, rocket_name } =>
+                write!(f, "{} launched to replenish {}", rocket_name, constellation),
+            GameEvent::LaunchScrubbed { rocket_name, reason } =>
+                write!(f, "{} launch scrubbed: {}", rocket_name, reason),
+            GameEvent::SpacecraftRefueled { spacecraft_name, location, propellant_kg } =>
+                write!(f, "{} refueled at {}: {:.0} kg loaded", spacecraft_name, location, propellant_kg),
+            GameEvent::PrCampaignStarted { cost, days } =>
+                write!(f, "PR campaign running for {} days ({})", days, crate::resources::format_money(*cost)),
+            GameEvent::PressEventScheduled { rocket_name, kind, cost } =>
+                write!(f, "Booked a {} for {}'s launch ({})", kind, rocket_name, crate::resources::format_money(*cost)),
+            GameEvent::PressCoverage { rocket_name, kind, success, sentiment } => write!(
+                f, "{} {} on the {}; media sentiment {:+.0}%",
+                rocket_name, if *success { "shone" } else { "failed" }, kind, 100.0 * sentiment,
+            ),
+            GameEvent::MoraleChanged { cause, average_morale } =>
+                write!(f, "Team morale after {}: {:.0}%", cause, 100.0 * average_morale),
+            GameEvent::TeamResigned { team_name } =>
+                write!(f, "{} resigned over low morale", team_name),
+            GameEvent::TeamFired { team_name } => write!(f, "{} let go", team_name),
+            GameEvent::TeamFurloughed { team_name } => write!(f, "{} furloughed", team_name),
+            GameEvent::TeamRecalled { team_name } => write!(f, "{} recalled from furlough", team_name),
+            GameEvent::SalariesInflated { rate } =>
+                write!(f, "Salaries rose {:.1}% with inflation", rate * 100.0),
+            GameEvent::RaiseDemanded { team_name, monthly_raise, deadline } =>
+                write!(f, "{} asks for a ${:.0}/month raise (answer by {})", team_name, monthly_raise, deadline),
+            GameEvent::RaiseGranted { team_name, monthly_raise } =>
+                write!(f, "{} got a ${:.0}/month raise", team_name, monthly_raise),
+            GameEvent::RaiseRefused { team_name, quit: false } => write!(f, "{} was refused a raise", team_name),
+            GameEvent::RaiseRefused { team_name, quit: true } =>
+                write!(f, "{} quit after being refused a raise", team_name),
+            GameEvent::ExternalReviewOrdered { target_name, fee, days } =>
+                write!(f, "External review of {} ordered for {}; findings in {} days",
+                    target_name, crate::resources::format_money(*fee), days),
+            GameEvent::ExternalReviewComplete { target_name, flaws_found } => match flaws_found {
+                0 => write!(f, "External review of {} found no hidden flaws", target_name),
+                n => write!(f, "External review of {} found {} hidden flaw(s)", target_name, n),
+            },
+            GameEvent::PropellantSalvaged { rocket_name, location, propellant_kg } =>
+                write!(f, "{} salvaged {:.0} kg of leftover propellant into the depot at {}",
+                    rocket_name, propellant_kg, location),
+            GameEvent::PropellantTransferred { spacecraft_name, location, propellant_kg, to_depot } => {
+                if *to_depot {
+                    write!(f, "{} offloaded {:.0} kg to the depot at {}", spacecraft_name, propellant_kg, location)
+                } else {
+                    write!(f, "{} topped up {:.0} kg at {}", spacecraft_name, propellant_kg, location)
+                }
+            }
+            GameEvent::GovProgramFailed { program, clawback } =>
+                write!(f, "{} failed: a milestone deadline passed; {} of subsidy clawed back",
+                    program, crate::resources::format_money(*clawback)),
         }
     }
 }
@@ -336,10 +931,46 @@ pub enum EventImportance {
 }
 
 impl GameEvent {
+    /// Localizable form: key `event.<variant>` with the variant's
+    /// fields as arguments, and this event's `Display` as the fallback.
+    pub fn text(&self) -> Text {
+        let (variant, fields) = match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(variant)) => (variant, serde_json::Map::new()),
+            Ok(serde_json::Value::Object(map)) => match map.into_iter().next() {
+                Some((variant, serde_json::Value::Object(fields))) => (variant, fields),
+                Some((variant, _)) => (variant, serde_json::Map::new()),
+                None => return Text::literal(self.to_string()),
+            },
+            _ => return Text::literal(self.to_string()),
+        };
+        let mut text = Text::new(format!("event.{}", snake_case(&variant)), self.to_string());
+        for (name, value) in fields {
+            text = match value {
+                serde_json::Value::String(s) => text.arg(&name, s),
+                serde_json::Value::Null => text.arg(&name, ""),
+                other => text.arg(&name, other),
+            };
+        }
+        text
+    }
+
     pub fn importance(&self) -> EventImportance {
         match self {
             GameEvent::DayAdvanced | GameEvent::MonthStart | GameEvent::SalariesPaid { .. }
-            | GameEvent::CompetitorRocketBuilt { .. } =>
+            | GameEvent::StorageFeesPaid { .. }
+            | GameEvent::PadInsurancePaid { .. }
+            | GameEvent::RecoveryFleetUpkeepPaid { .. }
+            | GameEvent::DroneShipSailed { .. }
+            | GameEvent::PayloadReady { .. }
+            | GameEvent::SlipCompensationPaid { .. }
+            | GameEvent::PadFlowStarted { .. }
+            | GameEvent::ProposalWrittenOff { .. }
+            | GameEvent::GovSubsidyReceived { .. }
+            | GameEvent::FlightLegComplete { .. }
+            | GameEvent::CompetitorRocketBuilt { .. }
+            | GameEvent::PropellantTransferred { .. }
+            | GameEvent::PropellantOffloaded { .. }
+            | GameEvent::InsertionCorrected { .. } =>
                 EventImportance::Routine,
             GameEvent::ContractAwardedToCompetitor { player_bid, .. } => {
                 if player_bid.is_some() { EventImportance::Notable } else { EventImportance::Routine }
@@ -348,15 +979,30 @@ impl GameEvent {
                 if *success { EventImportance::Routine } else { EventImportance::Notable }
             }
             GameEvent::GameStarted
+            | GameEvent::SpacecraftRefueled { .. }
+            | GameEvent::MoraleChanged { .. }
+            | GameEvent::TeamFired { .. }
+            | GameEvent::TeamFurloughed { .. }
+            | GameEvent::TeamRecalled { .. }
+            | GameEvent::SalariesInflated { .. }
+            | GameEvent::RaiseGranted { .. }
+            | GameEvent::PrCampaignStarted { .. }
+            | GameEvent::PressEventScheduled { .. }
+            | GameEvent::PropellantSalvaged { .. }
             | GameEvent::MoneyChanged { .. }
             | GameEvent::TeamHired { .. }
             | GameEvent::EngineDesignStarted { .. }
             | GameEvent::EngineDesignComplete { .. }
             | GameEvent::FlawDiscovered { .. }
+            | GameEvent::EngineFundamentalIssue { .. }
             | GameEvent::RevisionComplete { .. }
             | GameEvent::InsufficientFunds { .. }
             | GameEvent::EngineContracted { .. }
             | GameEvent::RocketDesignStarted { .. }
+            | GameEvent::RocketBranchForked { .. }
+            | GameEvent::RocketBranchMerged { .. }
+            | GameEvent::RocketBranchRetired { .. }
+            | GameEvent::RocketDesignImported { .. }
             | GameEvent::RocketDesignComplete { .. }
             | GameEvent::RocketFlawDiscovered { .. }
             | GameEvent::RocketRevisionComplete { .. }
@@ -381,9 +1027,79 @@ impl GameEvent {
             | GameEvent::BidPlaced { .. }
             | GameEvent::ContractAwarded { .. }
             | GameEvent::BidRejected { .. }
+            | GameEvent::ContractAudited { .. }
+            | GameEvent::ContractFeeWithheld { .. }
+            | GameEvent::InsertionOutOfTolerance { .. }
+            | GameEvent::ScienceCampaignStarted { .. }
+            | GameEvent::ScienceCampaignComplete { .. }
+            | GameEvent::ScienceAssetLost { .. }
+            | GameEvent::ContractSubcontracted { .. }
+            | GameEvent::SubcontractDelivered { .. }
             | GameEvent::LaunchSuccess { .. }
             | GameEvent::LaunchPartialFailure { .. }
             | GameEvent::LaunchFailure { .. }
+            | GameEvent::FairingRecovery { .. }
+            | GameEvent::BoosterLanding { .. }
+            | GameEvent::BoosterExpended { .. }
+            | GameEvent::RecoveryAssetPurchased { .. }
+            | GameEvent::PayloadSlipped { .. }
+            | GameEvent::ContractRenegotiated { .. }
+            | GameEvent::PadConversionStarted { .. }
+            | GameEvent::PadConverted { .. }
+            | GameEvent::ChallengeObjectiveMet { .. }
+            | GameEvent::TutorialStepDone { .. }
+            | GameEvent::TutorialFinished
+            | GameEvent::SandboxEdit { .. }
+            | GameEvent::LaunchSiteRelocated { .. }
+            | GameEvent::PadLeaseSigned { .. }
+            | GameEvent::PadLeaseCompleted { .. }
+            | GameEvent::LicenseApplied { .. }
+            | GameEvent::LicenseGranted { .. }
+            | GameEvent::EnvironmentalReview { .. }
+            | GameEvent::RegulatoryFine { .. }
+            | GameEvent::SecurityClearanceStarted { .. }
+            | GameEvent::SecurityClearanceGranted
+            | GameEvent::ExportPaperworkFiled { .. }
+            | GameEvent::ExportViolation { .. }
+            | GameEvent::PadRebuilt
+            | GameEvent::InsuranceClaimPaid { .. }
+            | GameEvent::RegulatoryStandDown { .. }
+            | GameEvent::PressCrisisAnswered { .. }
+            | GameEvent::PressCrisisIgnored { .. }
+            | GameEvent::IpoCompleted { .. }
+            | GameEvent::QuarterClosed { .. }
+            | GameEvent::BudgetOverrun { .. }
+            | GameEvent::EarningsReported { .. }
+            | GameEvent::LeakOffered { .. }
+            | GameEvent::LeakPurchased { .. }
+            | GameEvent::AssetSold { .. }
+            | GameEvent::CertificationStarted { .. }
+            | GameEvent::CertificationGranted { .. }
+            | GameEvent::CertificationRevoked { .. }
+            | GameEvent::CommonCauseFlaw { .. }
+            | GameEvent::AnomalyDetected { .. }
+            | GameEvent::SoftwareDesignStarted { .. }
+            | GameEvent::SoftwareDesignComplete { .. }
+            | GameEvent::SoftwareFlawDiscovered { .. }
+            | GameEvent::SoftwarePatched { .. }
+            | GameEvent::SoftwareAdapted { .. }
+            | GameEvent::IntegrationCampaignStarted { .. }
+            | GameEvent::IntegrationCampaignComplete { .. }
+            | GameEvent::ExternalReviewOrdered { .. }
+            | GameEvent::TeamResigned { .. }
+            | GameEvent::RaiseDemanded { .. }
+            | GameEvent::RaiseRefused { .. }
+            | GameEvent::PressCoverage { .. }
+            | GameEvent::ExternalReviewComplete { .. }
+            | GameEvent::HardwareExpired { .. }
+            | GameEvent::RecertificationOrdered { .. }
+            | GameEvent::HardwareRecertified { .. }
+            | GameEvent::BoosterInspectionOrdered { .. }
+            | GameEvent::BoosterInspected { .. }
+            | GameEvent::InventoryScrapped { .. }
+            | GameEvent::RetrofitOrdered { .. }
+            | GameEvent::RocketRetrofitted { .. }
+            | GameEvent::EnginesStripped { .. }
             | GameEvent::PaymentReceived { .. }
             | GameEvent::EngineBuildOrdered { .. }
             | GameEvent::FlightDeparted { .. }
@@ -394,6 +1110,7 @@ impl GameEvent {
             | GameEvent::SpacecraftStranded { .. }
             | GameEvent::PowerLost { .. }
             | GameEvent::MidFlightFlawActivated { .. }
+            | GameEvent::TransitAnomaly { .. }
             | GameEvent::ImprovementDiscovered { .. }
             | GameEvent::ImprovementActualized { .. }
             | GameEvent::TechDeficienciesFound { .. }
@@ -405,7 +1122,35 @@ impl GameEvent {
             // competitor awards.
             | GameEvent::CampaignAwardedToCompetitor { .. }
             | GameEvent::CampaignMissionIssued { .. }
-            | GameEvent::CampaignMissionMissed { .. } => EventImportance::Notable,
+            | GameEvent::CampaignMissionMissed { .. }
+            | GameEvent::AstronautHired { .. }
+            | GameEvent::AstronautQualified { .. }
+            | GameEvent::CrewAssigned { .. }
+            | GameEvent::CrewReturned { .. }
+            | GameEvent::CrewAborted { .. }
+            | GameEvent::PadAbortTestOrdered { .. }
+            | GameEvent::PadAbortTested { .. }
+            | GameEvent::GovProgramAnnounced { .. }
+            | GameEvent::GovProgramCommitted { .. }
+            | GameEvent::GovMilestoneMet { .. }
+            | GameEvent::GovProgramCompleted { .. }
+            | GameEvent::IsruPlantDeployed { .. }
+            | GameEvent::DepotFull { .. }
+            | GameEvent::DepotDesignStarted { .. }
+            | GameEvent::DepotDesignComplete { .. }
+            | GameEvent::DepotFlawDiscovered { .. }
+            | GameEvent::DepotRevisionComplete { .. }
+            | GameEvent::DepotDeployed { .. }
+            | GameEvent::DepotFlawActivated { .. }
+            | GameEvent::AssetWornOut { .. }
+            | GameEvent::AssetServiced { .. }
+            | GameEvent::ServicingMissed { .. }
+            | GameEvent::DockingAborted { .. }
+            | GameEvent::DockingCollision { .. }
+            | GameEvent::ConstellationFormed { .. }
+            | GameEvent::ReplacementNeeded { .. }
+            | GameEvent::ReplacementLaunched { .. }
+            | GameEvent::LaunchScrubbed { .. } => EventImportance::Notable,
             // Losing your own program is a Critical stop-the-presses
             // moment; a competitor fumbling theirs is market news.
             GameEvent::CampaignCancelled { by_player, .. } => {
@@ -418,9 +1163,62 @@ impl GameEvent {
                 if *liftable { EventImportance::Critical } else { EventImportance::Notable }
             }
             GameEvent::SpacecraftLost { .. }
-            | GameEvent::EconomicShift { .. } => EventImportance::Critical,
+            | GameEvent::GovProgramFailed { .. }
+            | GameEvent::CrewLost { .. }
+            | GameEvent::DesignCopied { .. }
+            | GameEvent::PadDestroyed { .. }
+            | GameEvent::PressCrisis { .. }
+            | GameEvent::EconomicShift { .. }
+            | GameEvent::ChallengeWon { .. }
+            | GameEvent::ChallengeFailed { .. } => EventImportance::Critical,
+        }
+    }
+}
+
+/// Auto-pause triggers the player opts into, on top of the pauses the
+/// game always makes (critical events, launches, market shifts, idle
+/// factories). All off by default.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PauseRules {
+    pub flight_arrived: bool,
+    pub flaw_discovered: bool,
+    /// A manufacturing order finished: a build, integration, recert or
+    /// retrofit.
+    pub order_complete: bool,
+    /// Pause the day money drops below this.
+    pub money_below: Option<f64>,
+}
+
+impl PauseRules {
+    /// True if `event` is one the player asked to stop for.
+    pub fn matches(&self, event: &GameEvent) -> bool {
+        match event {
+            GameEvent::FlightArrived { .. } => self.flight_arrived,
+            GameEvent::FlawDiscovered { .. }
+            | GameEvent::RocketFlawDiscovered { .. }
+            | GameEvent::ReactorFlawDiscovered { .. }
+            | GameEvent::SoftwareFlawDiscovered { .. }
+            | GameEvent::DepotFlawDiscovered { .. }
+            | GameEvent::EngineFundamentalIssue { .. } => self.flaw_discovered,
+            GameEvent::EngineBuilt { .. }
+            | GameEvent::StageBuilt { .. }
+            | GameEvent::RocketIntegrated { .. }
+            | GameEvent::HardwareRecertified { .. }
+            | GameEvent::BoosterInspected { .. }
+            | GameEvent::RocketRetrofitted { .. } => self.order_complete,
+            _ => false,
         }
     }
+
+    /// True if a day that raised `events` and moved money from
+    /// `money_before` to `money_after` should end paused. The money
+    /// rule fires on crossing the threshold, not every day below it.
+    pub fn should_pause(&self, events: &[GameEvent], money_before: f64, money_after: f64) -> bool {
+        let crossed = self.money_below
+            .is_some_and(|floor| money_before >= floor && money_after < floor);
+        crossed || events.iter().any(|e| self.matches(e))
+    }
 }
 
 /// A timestamped event log with a maximum size (ring buffer).
@@ -477,6 +1275,22 @@ impl EventLog {
     }
 }
 
+/// `LaunchSuccess` → `launch_success`.
+fn snake_case(camel: &str) -> String {
+    let mut out = String::with_capacity(camel.len() + 4);
+    for (i, c) in camel.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,6 +1315,34 @@ mod tests {
         assert_eq!(recent[1].0, date(1));
     }
 
+    #[test]
+    fn test_pause_rules() {
+        let arrived = GameEvent::FlightArrived { rocket_name: "R".into(), destination: "LEO".into() };
+        let mut rules = PauseRules::default();
+        assert!(!rules.should_pause(std::slice::from_ref(&arrived), 10.0, 5.0));
+
+        rules.flight_arrived = true;
+        assert!(rules.should_pause(std::slice::from_ref(&arrived), 10.0, 5.0));
+
+        let rules = PauseRules { money_below: Some(8.0), ..Default::default() };
+        assert!(rules.should_pause(&[], 10.0, 5.0));
+        assert!(!rules.should_pause(&[], 7.0, 5.0), "already below: no repeat pause");
+    }
+
+    #[test]
+    fn test_event_text_keys_and_args() {
+        let evt = GameEvent::LaunchSuccess { rocket_name: "Kestrel".into(), destination: "leo".into() };
+        let text = evt.text();
+        assert_eq!(text.key, "event.launch_success");
+        assert_eq!(text.fallback, evt.to_string());
+        let args: Vec<_> = text.args.iter().map(|a| (a.name.as_str(), a.value.as_str())).collect();
+        assert!(args.contains(&("rocket_name", "Kestrel")) && args.contains(&("destination", "leo")));
+
+        let unit = GameEvent::GameStarted.text();
+        assert_eq!(unit.key, "event.game_started");
+        assert!(unit.args.is_empty());
+    }
+
     #[test]
     fn test_ring_buffer() {
         let mut log = EventLog::new(3);
@@ -0,0 +1,59 @@
+//! External design reviews.
+//!
+//! Instead of tying teams up in testing cycles, a company can pay an
+//! outside firm to review an engine or rocket design. The firm needs no
+//! team time; after a fixed number of days it hands back findings that
+//! are guaranteed to include a share of the design's hidden flaws. It
+//! costs far more per flaw than internal testing, so it suits a company
+//! with more cash than engineers.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::ExternalReviewConfig;
+use crate::engine_project::EngineProjectId;
+use crate::rocket_project::RocketProjectId;
+
+/// The design under review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewTarget {
+    Engine(EngineProjectId),
+    Rocket(RocketProjectId),
+}
+
+/// A paid review in progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalReview {
+    pub target: ReviewTarget,
+    pub target_name: String,
+    pub fee: f64,
+    pub days_remaining: u32,
+}
+
+/// Why a review couldn't be ordered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReviewError {
+    NoSuchProject,
+    /// Only a design in testing has flaws to find.
+    NotInTesting,
+    AlreadyUnderReview,
+    CantAfford { cost: f64 },
+}
+
+impl fmt::Display for ReviewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReviewError::NoSuchProject => write!(f, "No design selected"),
+            ReviewError::NotInTesting => write!(f, "Only a design in testing can be reviewed"),
+            ReviewError::AlreadyUnderReview => write!(f, "Already under external review"),
+            ReviewError::CantAfford { cost } =>
+                write!(f, "An external review costs {}", crate::resources::format_money(*cost)),
+        }
+    }
+}
+
+/// The firm's fee for an engine of `complexity`.
+pub fn engine_fee(complexity: u32, cfg: &ExternalReviewConfig) -> f64 {
+    complexity.max(1) as f64 * cfg.engine_fee_per_complexity
+}
@@ -1,13 +1,30 @@
 use rand::Rng;
+use rand::seq::SliceRandom;
 use rand::rngs::StdRng;
 use serde::{Serialize, Deserialize};
 
 use crate::balance_config::FlawsConfig;
+use crate::locale::Text;
 
 /// Unique identifier for a flaw.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FlawId(pub u64);
 
+/// Read a list of flaw ids that older saves stored as whole flaws.
+pub fn ids_or_flaws<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<FlawId>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum FlawRef {
+        Id(FlawId),
+        Flaw(Box<Flaw>),
+    }
+    let refs: Vec<FlawRef> = Deserialize::deserialize(deserializer)?;
+    Ok(refs.into_iter().map(|r| match r {
+        FlawRef::Id(id) => id,
+        FlawRef::Flaw(flaw) => flaw.id,
+    }).collect())
+}
+
 /// When a flaw can trigger.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[derive(Default)]
@@ -25,8 +42,97 @@ impl FlawTrigger {
     const REFERENCE_DAYS: f64 = 365.0;
 }
 
+/// The hardware or software a flaw lives in. Decides which launch event
+/// it shows up at and how much work it takes to fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Default)]
+pub enum Subsystem {
+    Turbopump,
+    /// Injectors, chambers, nozzles, and thruster discharge hardware.
+    /// Pre-taxonomy saves load their flaws here.
+    #[default]
+    Combustion,
+    Avionics,
+    Separation,
+    GncSoftware,
+    TankStructure,
+    /// Reactors, arrays, batteries, and power processing.
+    Power,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 7] = [
+        Subsystem::Turbopump, Subsystem::Combustion, Subsystem::Avionics,
+        Subsystem::Separation, Subsystem::GncSoftware, Subsystem::TankStructure,
+        Subsystem::Power,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Subsystem::Turbopump => "Turbopump",
+            Subsystem::Combustion => "Combustion",
+            Subsystem::Avionics => "Avionics",
+            Subsystem::Separation => "Separation",
+            Subsystem::GncSoftware => "GNC software",
+            Subsystem::TankStructure => "Tank structure",
+            Subsystem::Power => "Power",
+        }
+    }
+
+    pub fn text(&self) -> Text {
+        Text::label("subsystem", self.display_name())
+    }
+
+    /// The flight event where flaws in this subsystem bite.
+    pub fn launch_event(&self) -> &'static str {
+        match self {
+            Subsystem::Turbopump => "engine start",
+            Subsystem::Combustion => "main-engine burn",
+            Subsystem::Avionics => "ascent",
+            Subsystem::Separation => "stage separation",
+            Subsystem::GncSoftware => "guidance and insertion",
+            Subsystem::TankStructure => "max-Q",
+            Subsystem::Power => "power-up",
+        }
+    }
+
+    /// Telemetry signature of a near-miss in this subsystem.
+    pub fn anomaly_symptom(&self) -> &'static str {
+        match self {
+            Subsystem::Turbopump => "Vibration spike",
+            Subsystem::Combustion => "Chamber pressure oscillation",
+            Subsystem::Avionics => "Telemetry dropout",
+            Subsystem::Separation => "Separation shock transient",
+            Subsystem::GncSoftware => "Guidance residual excursion",
+            Subsystem::TankStructure => "Strain gauge excursion",
+            Subsystem::Power => "Bus voltage sag",
+        }
+    }
+
+    /// Multiplier on `flaw_revision_work` for fixing a flaw here:
+    /// rotating machinery and structure need hardware redesign and
+    /// requalification, software is a patch and a regression run.
+    pub fn fix_work_factor(&self) -> f64 {
+        match self {
+            Subsystem::Turbopump => 1.5,
+            Subsystem::Combustion => 1.2,
+            Subsystem::TankStructure => 1.2,
+            Subsystem::Power => 1.0,
+            Subsystem::Separation => 0.8,
+            Subsystem::Avionics => 0.7,
+            Subsystem::GncSoftware => 0.5,
+        }
+    }
+}
+
+impl std::fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
 /// What happens when a flaw activates during flight.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FlawConsequence {
     /// Fraction of thrust/isp lost (e.g. 0.05 = 5% loss).
     PerformanceDegradation(f64),
@@ -36,6 +142,17 @@ pub enum FlawConsequence {
     StageLoss,
 }
 
+impl FlawConsequence {
+    pub fn text(&self) -> Text {
+        match self {
+            FlawConsequence::PerformanceDegradation(frac) =>
+                Text::new("consequence.performance_loss", self.to_string())
+                    .arg("percent", format!("{:.0}", frac * 100.0)),
+            _ => Text::label("consequence", &self.to_string()),
+        }
+    }
+}
+
 impl std::fmt::Display for FlawConsequence {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -48,7 +165,7 @@ impl std::fmt::Display for FlawConsequence {
 }
 
 /// A flaw in an engine design that may activate during flight.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Flaw {
     pub id: FlawId,
     pub description: String,
@@ -62,9 +179,30 @@ pub struct Flaw {
     /// When this flaw can trigger.
     #[serde(default)]
     pub trigger: FlawTrigger,
+    #[serde(default)]
+    pub subsystem: Subsystem,
+    /// Telemetry hint left by a near-miss. The flaw is still hidden, but
+    /// testing pins it down faster.
+    #[serde(default)]
+    pub anomaly: Option<String>,
 }
 
 impl Flaw {
+    /// Work units to fix this flaw in a revision.
+    pub fn fix_work(&self, flaw_revision_work: f64) -> f64 {
+        flaw_revision_work * self.subsystem.fix_work_factor()
+    }
+
+    /// Localizable description, keyed by the catalog entry it came from.
+    pub fn description_text(&self) -> Text {
+        Text::label("flaw", &self.description)
+    }
+
+    /// What a near-miss of this flaw looks like from the ground.
+    pub fn anomaly_hint(&self, stage_number: usize) -> String {
+        format!("{} in stage {}, cause unknown", self.subsystem.anomaly_symptom(), stage_number)
+    }
+
     /// For PerDay flaws, convert activation_chance to a daily rate.
     /// For PerFlight flaws, returns activation_chance unchanged.
     pub fn daily_rate(&self) -> f64 {
@@ -136,6 +274,223 @@ pub fn generate_rocket_flaws(
     }).collect()
 }
 
+/// One separation flaw per hot-staged boundary: the upper engines
+/// light against the stage below, and the interstage has to take it.
+/// Draws nothing for a cold-staged stack.
+pub fn generate_hot_staging_flaws(
+    boundaries: usize,
+    rng: &mut StdRng,
+    next_flaw_id: &mut u64,
+    cfg: &FlawsConfig,
+) -> Vec<Flaw> {
+    const DESCRIPTIONS: [&str; 4] = [
+        "Hot-staging plume erodes the interstage",
+        "Upper-stage ignition overpressure in the interstage",
+        "Interstage vent blockage during hot staging",
+        "Lower stage tumbles into the upper plume at separation",
+    ];
+    (0..boundaries).map(|_| {
+        let id = FlawId(*next_flaw_id);
+        *next_flaw_id += 1;
+        let (consequence, activation_chance, discovery_probability) = roll_flaw_core(rng, cfg);
+        Flaw {
+            id,
+            description: DESCRIPTIONS[rng.gen_range(0..DESCRIPTIONS.len())].to_string(),
+            consequence,
+            activation_chance,
+            discovery_probability,
+            discovered: false,
+            trigger: FlawTrigger::PerFlight,
+            subsystem: Subsystem::Separation,
+            anomaly: None,
+        }
+    }).collect()
+}
+
+/// Combustion instability flaws for an engine scaled past its onset
+/// (see `CombustionInstabilityConfig`): `mean` whole flaws, the
+/// fraction drawn as a chance of one more, so a small overreach
+/// usually gets away clean. All per-flight, all in the chamber.
+pub fn generate_instability_flaws(
+    mean: f64,
+    rng: &mut StdRng,
+    next_flaw_id: &mut u64,
+    cfg: &FlawsConfig,
+) -> Vec<Flaw> {
+    if mean <= 0.0 {
+        return Vec::new();
+    }
+    let count = mean.floor() as u32 + u32::from(rng.gen::<f64>() < mean.fract());
+    (0..count).map(|_| {
+        let id = FlawId(*next_flaw_id);
+        *next_flaw_id += 1;
+        let (consequence, activation_chance, discovery_probability) = roll_flaw_core(rng, cfg);
+        let descriptions: &[&str] = match consequence {
+            FlawConsequence::PerformanceDegradation(_) => &[
+                "Low-frequency chugging at full thrust",
+                "Injector-coupled pressure oscillation",
+                "Acoustic resonance in the enlarged chamber",
+            ],
+            FlawConsequence::EngineLoss => &[
+                "High-frequency screech burns through the chamber wall",
+                "Tangential acoustic mode erodes the injector face",
+                "Injector baffle fails under chamber resonance",
+            ],
+            FlawConsequence::StageLoss => &[
+                "Chamber oscillation couples into the feed lines",
+                "Hard start from a pressure spike in the oversized chamber",
+            ],
+        };
+        Flaw {
+            id,
+            description: descriptions[rng.gen_range(0..descriptions.len())].to_string(),
+            consequence,
+            activation_chance,
+            discovery_probability,
+            discovered: false,
+            trigger: FlawTrigger::PerFlight,
+            subsystem: Subsystem::Combustion,
+            anomaly: None,
+        }
+    }).collect()
+}
+
+/// What testing found wrong with an oversized chamber, past fixing by
+/// revision.
+pub fn fundamental_instability_description(rng: &mut StdRng) -> String {
+    const DESCRIPTIONS: [&str; 4] = [
+        "Chamber acoustic modes can't be damped at this size",
+        "Injector can't hold a stable flame across the wider chamber",
+        "Combustion oscillation drives the turbopump to resonance",
+        "Chamber pressure spikes grow faster than baffles can absorb",
+    ];
+    DESCRIPTIONS[rng.gen_range(0..DESCRIPTIONS.len())].to_string()
+}
+
+/// Generate flaws for a flight software lineage: guidance errors and
+/// commanding bugs, all per-flight. Count ~ gaussian around `mean`;
+/// activation chances are scaled by `activation_scale`.
+pub fn generate_software_flaws(
+    mean: f64,
+    activation_scale: f64,
+    rng: &mut StdRng,
+    next_flaw_id: &mut u64,
+    cfg: &FlawsConfig,
+) -> Vec<Flaw> {
+    let count_f = gaussian_sample(mean, cfg.count_stddev, rng);
+    let count = count_f.round().max(0.0) as u32;
+
+    (0..count).map(|_| {
+        let id = FlawId(*next_flaw_id);
+        *next_flaw_id += 1;
+        let (consequence, activation_chance, discovery_probability) = roll_flaw_core(rng, cfg);
+        let (description, subsystem) = generate_software_flaw_description(&consequence, rng);
+        Flaw {
+            id,
+            description,
+            consequence,
+            activation_chance: activation_chance * activation_scale,
+            discovery_probability,
+            discovered: false,
+            trigger: FlawTrigger::PerFlight,
+            subsystem,
+            anomaly: None,
+        }
+    }).collect()
+}
+
+fn generate_software_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
+    let descriptions = match consequence {
+        // Guidance errors: the vehicle flies, just not where it should.
+        FlawConsequence::PerformanceDegradation(_) => &[
+            ("Navigation filter drifts during ascent", Subsystem::GncSoftware),
+            ("Steering law overcorrects in gravity turn", Subsystem::GncSoftware),
+            ("Wrong atmosphere model in trajectory planner", Subsystem::GncSoftware),
+            ("Throttle table off by one row", Subsystem::GncSoftware),
+            ("IMU alignment error goes uncorrected", Subsystem::GncSoftware),
+        ][..],
+        // Commanding bugs that cost an engine.
+        FlawConsequence::EngineLoss => &[
+            ("Engine shutdown command sent early", Subsystem::Avionics),
+            ("Ignition sequence timing race", Subsystem::Avionics),
+            ("Fault detection shuts down a healthy engine", Subsystem::Avionics),
+            ("Valve command dropped on a busy data bus", Subsystem::Avionics),
+        ][..],
+        FlawConsequence::StageLoss => &[
+            ("Separation sequencer fires out of order", Subsystem::Separation),
+            ("Integer overflow in guidance loop", Subsystem::GncSoftware),
+            ("Flight termination logic trips on valid data", Subsystem::Avionics),
+            ("Mission clock resets mid-ascent", Subsystem::Avionics),
+        ][..],
+    };
+
+    let idx = rng.gen_range(0..descriptions.len());
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
+}
+
+/// Generate flaws for a propellant depot design. A depot sits in
+/// place for years, so every flaw is `PerDay`: a loss of some stores,
+/// of the cryogenics when cooling fails, or of everything when a tank
+/// gives way. Count ~ gaussian around `mean`.
+pub fn generate_depot_flaws(
+    mean: f64,
+    rng: &mut StdRng,
+    next_flaw_id: &mut u64,
+    cfg: &FlawsConfig,
+) -> Vec<Flaw> {
+    let count_f = gaussian_sample(mean, cfg.count_stddev, rng);
+    let count = count_f.round().max(0.0) as u32;
+
+    (0..count).map(|_| {
+        let id = FlawId(*next_flaw_id);
+        *next_flaw_id += 1;
+        let (consequence, activation_chance, discovery_probability) = roll_flaw_core(rng, cfg);
+        let (description, subsystem) = generate_depot_flaw_description(&consequence, rng);
+        Flaw {
+            id,
+            description,
+            consequence,
+            activation_chance,
+            discovery_probability,
+            discovered: false,
+            trigger: FlawTrigger::PerDay,
+            subsystem,
+            anomaly: None,
+        }
+    }).collect()
+}
+
+fn generate_depot_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
+    let descriptions = match consequence {
+        // Some of the stores leak away.
+        FlawConsequence::PerformanceDegradation(_) => &[
+            ("Fill-drain valve seat leaks", Subsystem::TankStructure),
+            ("Transfer line quick-disconnect weeps", Subsystem::TankStructure),
+            ("Pressurant regulator vents propellant", Subsystem::Avionics),
+            ("Micrometeoroid pitting on a tank dome", Subsystem::TankStructure),
+            ("Docking port seal lets vapor escape", Subsystem::Separation),
+        ][..],
+        // Cooling fails and the cryogenics boil away.
+        FlawConsequence::EngineLoss => &[
+            ("Cryocooler compressor seizes", Subsystem::Power),
+            ("Radiator loop freezes solid", Subsystem::Power),
+            ("Cryocooler controller latches off", Subsystem::Avionics),
+            ("Array drive motor stalls in shadow", Subsystem::Power),
+        ][..],
+        FlawConsequence::StageLoss => &[
+            ("Tank weld cracks under thermal cycling", Subsystem::TankStructure),
+            ("Burst disc fails to relieve overpressure", Subsystem::TankStructure),
+            ("Common bulkhead fatigue crack", Subsystem::TankStructure),
+            ("Docking collar strike ruptures a tank", Subsystem::Separation),
+        ][..],
+    };
+
+    let idx = rng.gen_range(0..descriptions.len());
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
+}
+
 /// Roll the domain-agnostic core of a flaw: its consequence, activation
 /// chance, and discovery probability. Shared by engine, rocket, and
 /// reactor flaw generation so the probability model stays in one place.
@@ -196,7 +551,7 @@ pub fn generate_reactor_flaws(
 /// text (gradual wear) and `PerFlight` ones get event-flavored text.
 pub fn generate_single_reactor_flaw(id: FlawId, trigger: FlawTrigger, rng: &mut StdRng, cfg: &FlawsConfig) -> Flaw {
     let (consequence, activation_chance, discovery_probability) = roll_flaw_core(rng, cfg);
-    let description = match trigger {
+    let (description, subsystem) = match trigger {
         FlawTrigger::PerDay => generate_reactor_endurance_flaw_description(&consequence, rng),
         FlawTrigger::PerFlight => generate_reactor_flaw_description(&consequence, rng),
     };
@@ -208,77 +563,81 @@ pub fn generate_single_reactor_flaw(id: FlawId, trigger: FlawTrigger, rng: &mut
         discovery_probability,
         discovered: false,
         trigger,
+        subsystem,
+        anomaly: None,
     }
 }
 
-fn generate_reactor_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_reactor_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         // Reads as a power-output loss on a reactor.
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Coolant loop flow restriction",
-            "Radiator fin degradation reduces heat rejection",
-            "Control drum drift derates output",
-            "Fuel element swelling reduces thermal transfer",
-            "Thermoelectric converter efficiency loss",
-            "Partial coolant channel blockage",
+            ("Coolant loop flow restriction", Subsystem::Power),
+            ("Radiator fin degradation reduces heat rejection", Subsystem::Power),
+            ("Control drum drift derates output", Subsystem::Power),
+            ("Fuel element swelling reduces thermal transfer", Subsystem::Power),
+            ("Thermoelectric converter efficiency loss", Subsystem::Power),
+            ("Partial coolant channel blockage", Subsystem::Power),
         ][..],
         // Reads as a reactor shutdown (the "part" is lost, not the stage).
         FlawConsequence::EngineLoss => &[
-            "Control drum actuator seizure triggers SCRAM",
-            "Coolant pump failure forces reactor shutdown",
-            "Fuel element cladding breach",
-            "Reactor overheats and trips offline",
-            "Neutron poison buildup stalls the core",
-            "Primary coolant loop leak",
+            ("Control drum actuator seizure triggers SCRAM", Subsystem::Power),
+            ("Coolant pump failure forces reactor shutdown", Subsystem::Power),
+            ("Fuel element cladding breach", Subsystem::Power),
+            ("Reactor overheats and trips offline", Subsystem::Power),
+            ("Neutron poison buildup stalls the core", Subsystem::Power),
+            ("Primary coolant loop leak", Subsystem::Power),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Reactor pressure vessel rupture",
-            "Uncontrolled criticality excursion",
-            "Radiation shielding structural failure",
-            "Coolant flash-boil breaches the stage",
-            "Thermal runaway destroys the stage",
-            "Reactor debris severs stage structure",
+            ("Reactor pressure vessel rupture", Subsystem::TankStructure),
+            ("Uncontrolled criticality excursion", Subsystem::Power),
+            ("Radiation shielding structural failure", Subsystem::TankStructure),
+            ("Coolant flash-boil breaches the stage", Subsystem::TankStructure),
+            ("Thermal runaway destroys the stage", Subsystem::Power),
+            ("Reactor debris severs stage structure", Subsystem::TankStructure),
         ][..],
     };
 
     let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
 }
 
 /// Endurance (`PerDay`) reactor flaw text — gradual, cumulative failure
 /// modes that develop over a long mission rather than at ignition.
-fn generate_reactor_endurance_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_reactor_endurance_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         // Gradual power loss over the mission.
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Radiator coating erosion degrades heat rejection",
-            "Fuel burnup lowers reactivity over time",
-            "Neutron embrittlement of core structure",
-            "Coolant loop fouling accumulates",
-            "Thermoelectric junction degradation",
-            "Control drum bearing wear derates output",
+            ("Radiator coating erosion degrades heat rejection", Subsystem::Power),
+            ("Fuel burnup lowers reactivity over time", Subsystem::Power),
+            ("Neutron embrittlement of core structure", Subsystem::Power),
+            ("Coolant loop fouling accumulates", Subsystem::Power),
+            ("Thermoelectric junction degradation", Subsystem::Power),
+            ("Control drum bearing wear derates output", Subsystem::Power),
         ][..],
         // Reactor trips offline after prolonged operation.
         FlawConsequence::EngineLoss => &[
-            "Fuel cladding creep-ruptures after prolonged heat",
-            "Coolant pump bearing wears out and seizes",
-            "Cumulative xenon poisoning stalls the core",
-            "Control-drum actuator fails from thermal cycling",
-            "Primary loop develops a slow coolant leak",
-            "Reactor trips offline on degraded shielding sensors",
+            ("Fuel cladding creep-ruptures after prolonged heat", Subsystem::Power),
+            ("Coolant pump bearing wears out and seizes", Subsystem::Power),
+            ("Cumulative xenon poisoning stalls the core", Subsystem::Power),
+            ("Control-drum actuator fails from thermal cycling", Subsystem::Power),
+            ("Primary loop develops a slow coolant leak", Subsystem::Power),
+            ("Reactor trips offline on degraded shielding sensors", Subsystem::Avionics),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Coolant embrittlement leads to pressure-vessel failure",
-            "Long-term radiation damage collapses the structure",
-            "Cumulative thermal fatigue cracks the reactor mount",
-            "Shielding degradation triggers a runaway excursion",
-            "Radiator manifold fatigue ruptures the coolant loop",
-            "Structural creep severs the stage under load",
+            ("Coolant embrittlement leads to pressure-vessel failure", Subsystem::TankStructure),
+            ("Long-term radiation damage collapses the structure", Subsystem::TankStructure),
+            ("Cumulative thermal fatigue cracks the reactor mount", Subsystem::TankStructure),
+            ("Shielding degradation triggers a runaway excursion", Subsystem::Power),
+            ("Radiator manifold fatigue ruptures the coolant loop", Subsystem::Power),
+            ("Structural creep severs the stage under load", Subsystem::TankStructure),
         ][..],
     };
 
     let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
 }
 
 pub fn generate_single_flaw(id: FlawId, trigger: FlawTrigger, rng: &mut StdRng, cycle: Option<crate::engine::EngineCycle>, cfg: &FlawsConfig) -> Flaw {
@@ -288,7 +647,7 @@ pub fn generate_single_flaw(id: FlawId, trigger: FlawTrigger, rng: &mut StdRng,
     let use_nuclear = matches!(cycle, Some(crate::engine::EngineCycle::NuclearThermal));
     let use_solar_sail = matches!(cycle, Some(crate::engine::EngineCycle::SolarSail));
 
-    let description = match trigger {
+    let (description, subsystem) = match trigger {
         FlawTrigger::PerFlight if use_solar_sail =>
             generate_solar_sail_flaw_description(&consequence, rng),
         FlawTrigger::PerFlight if use_electric =>
@@ -307,177 +666,338 @@ pub fn generate_single_flaw(id: FlawId, trigger: FlawTrigger, rng: &mut StdRng,
         discovery_probability,
         discovered: false,
         trigger,
+        subsystem,
+        anomaly: None,
     }
 }
 
-fn generate_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+/// Draw from a built-in description table plus any content-pack entries
+/// for the same slot. Without content the draw is identical to indexing
+/// the table alone.
+fn pick_description(
+    builtin: &[(&str, Subsystem)],
+    consequence: &FlawConsequence,
+    trigger: FlawTrigger,
+    rng: &mut StdRng,
+) -> (String, Subsystem) {
+    let modded = crate::content::flaw_entries(trigger, consequence);
+    let idx = rng.gen_range(0..builtin.len() + modded.len());
+    match builtin.get(idx) {
+        Some(&(text, subsystem)) => (text.to_string(), subsystem),
+        None => {
+            let entry = modded[idx - builtin.len()];
+            (entry.description.clone(), entry.subsystem)
+        }
+    }
+}
+
+fn generate_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Turbopump seal leak",
-            "Injector pattern inefficiency",
-            "Nozzle cooling channel restriction",
-            "Valve response lag",
-            "Combustion instability at partial throttle",
-            "Propellant feed pressure oscillation",
+            ("Turbopump seal leak", Subsystem::Turbopump),
+            ("Injector pattern inefficiency", Subsystem::Combustion),
+            ("Nozzle cooling channel restriction", Subsystem::Combustion),
+            ("Valve response lag", Subsystem::Avionics),
+            ("Combustion instability at partial throttle", Subsystem::Combustion),
+            ("Propellant feed pressure oscillation", Subsystem::Turbopump),
         ][..],
         FlawConsequence::EngineLoss => &[
-            "Turbopump bearing fatigue",
-            "Combustion chamber hot spot",
-            "Igniter reliability issue",
-            "Oxidizer-rich preburner instability",
-            "Thermal stress cracking in nozzle",
-            "Main injector face erosion",
+            ("Turbopump bearing fatigue", Subsystem::Turbopump),
+            ("Combustion chamber hot spot", Subsystem::Combustion),
+            ("Igniter reliability issue", Subsystem::Avionics),
+            ("Oxidizer-rich preburner instability", Subsystem::Turbopump),
+            ("Thermal stress cracking in nozzle", Subsystem::Combustion),
+            ("Main injector face erosion", Subsystem::Combustion),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Propellant feed line vibration failure",
-            "Stage separation bolt stress fracture",
-            "Thrust structure resonance mode",
-            "Ullage gas contamination risk",
-            "Inter-stage electrical harness fault",
-            "Catastrophic combustion instability",
+            ("Propellant feed line vibration failure", Subsystem::TankStructure),
+            ("Stage separation bolt stress fracture", Subsystem::Separation),
+            ("Thrust structure resonance mode", Subsystem::TankStructure),
+            ("Ullage gas contamination risk", Subsystem::TankStructure),
+            ("Inter-stage electrical harness fault", Subsystem::Separation),
+            ("Catastrophic combustion instability", Subsystem::Combustion),
         ][..],
     };
 
-    let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    pick_description(descriptions, consequence, FlawTrigger::PerFlight, rng)
 }
 
-fn generate_endurance_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_endurance_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Thermal cycling degradation",
-            "Sensor drift accumulation",
-            "Propellant line seal wear",
-            "Attitude control thruster fouling",
-            "Radiator coating degradation",
-            "Reaction wheel bearing wear",
+            ("Thermal cycling degradation", Subsystem::TankStructure),
+            ("Sensor drift accumulation", Subsystem::GncSoftware),
+            ("Propellant line seal wear", Subsystem::TankStructure),
+            ("Attitude control thruster fouling", Subsystem::GncSoftware),
+            ("Radiator coating degradation", Subsystem::Power),
+            ("Reaction wheel bearing wear", Subsystem::GncSoftware),
         ][..],
         FlawConsequence::EngineLoss => &[
-            "Turbopump bearing wear",
-            "Igniter electrode erosion",
-            "Fuel valve seat degradation",
-            "Oxidizer seal embrittlement",
-            "Engine controller memory corruption",
-            "Regenerative cooling tube fatigue",
+            ("Turbopump bearing wear", Subsystem::Turbopump),
+            ("Igniter electrode erosion", Subsystem::Avionics),
+            ("Fuel valve seat degradation", Subsystem::Turbopump),
+            ("Oxidizer seal embrittlement", Subsystem::Turbopump),
+            ("Engine controller memory corruption", Subsystem::GncSoftware),
+            ("Regenerative cooling tube fatigue", Subsystem::Combustion),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Avionics thermal failure",
-            "Battery capacity degradation",
-            "Structural fatigue crack propagation",
-            "Guidance computer memory fault",
-            "Wiring harness insulation breakdown",
-            "Pressurization system leak",
+            ("Avionics thermal failure", Subsystem::Avionics),
+            ("Battery capacity degradation", Subsystem::Power),
+            ("Structural fatigue crack propagation", Subsystem::TankStructure),
+            ("Guidance computer memory fault", Subsystem::GncSoftware),
+            ("Wiring harness insulation breakdown", Subsystem::Avionics),
+            ("Pressurization system leak", Subsystem::TankStructure),
         ][..],
     };
 
-    let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    pick_description(descriptions, consequence, FlawTrigger::PerDay, rng)
 }
 
-fn generate_electric_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_electric_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Ion grid erosion rate higher than expected",
-            "Beam neutralizer current drift",
-            "Discharge chamber magnetic field asymmetry",
-            "Xenon flow controller calibration offset",
-            "Thruster plume divergence angle excessive",
-            "Power processing unit efficiency loss",
+            ("Ion grid erosion rate higher than expected", Subsystem::Combustion),
+            ("Beam neutralizer current drift", Subsystem::Combustion),
+            ("Discharge chamber magnetic field asymmetry", Subsystem::Combustion),
+            ("Xenon flow controller calibration offset", Subsystem::GncSoftware),
+            ("Thruster plume divergence angle excessive", Subsystem::Combustion),
+            ("Power processing unit efficiency loss", Subsystem::Power),
         ][..],
         FlawConsequence::EngineLoss => &[
-            "Grid short circuit from sputtered material",
-            "Cathode heater element failure",
-            "Xenon isolator valve seizure",
-            "High-voltage breakdown in PPU",
-            "Discharge chamber wall sputter-through",
-            "Neutralizer keeper electrode erosion",
+            ("Grid short circuit from sputtered material", Subsystem::Combustion),
+            ("Cathode heater element failure", Subsystem::Combustion),
+            ("Xenon isolator valve seizure", Subsystem::Turbopump),
+            ("High-voltage breakdown in PPU", Subsystem::Power),
+            ("Discharge chamber wall sputter-through", Subsystem::Combustion),
+            ("Neutralizer keeper electrode erosion", Subsystem::Combustion),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Xenon tank pressure regulator failure",
-            "Solar array connection arc fault",
-            "Thruster gimbal mechanism binding",
-            "Power bus overcurrent shutdown",
-            "Propellant management unit leak",
-            "Electromagnetic interference with avionics",
+            ("Xenon tank pressure regulator failure", Subsystem::TankStructure),
+            ("Solar array connection arc fault", Subsystem::Power),
+            ("Thruster gimbal mechanism binding", Subsystem::GncSoftware),
+            ("Power bus overcurrent shutdown", Subsystem::Power),
+            ("Propellant management unit leak", Subsystem::TankStructure),
+            ("Electromagnetic interference with avionics", Subsystem::Avionics),
         ][..],
     };
 
     let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
 }
 
-fn generate_nuclear_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_nuclear_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Fuel element hydrogen corrosion",
-            "Reactor power distribution imbalance",
-            "Turbopump hydrogen bearing wear",
-            "Nozzle skirt hydrogen embrittlement",
-            "Moderator element swelling",
-            "Reflector drum actuator lag",
+            ("Fuel element hydrogen corrosion", Subsystem::Power),
+            ("Reactor power distribution imbalance", Subsystem::Power),
+            ("Turbopump hydrogen bearing wear", Subsystem::Turbopump),
+            ("Nozzle skirt hydrogen embrittlement", Subsystem::Combustion),
+            ("Moderator element swelling", Subsystem::Power),
+            ("Reflector drum actuator lag", Subsystem::Avionics),
         ][..],
         FlawConsequence::EngineLoss => &[
-            "Fuel element mid-section break",
-            "Control drum servo mechanism failure",
-            "Reactor thermal runaway risk",
-            "Hydrogen leak in reactor pressure vessel",
-            "Neutron poison buildup in fuel elements",
-            "Turbopump seal failure from radiation damage",
+            ("Fuel element mid-section break", Subsystem::Power),
+            ("Control drum servo mechanism failure", Subsystem::Avionics),
+            ("Reactor thermal runaway risk", Subsystem::Power),
+            ("Hydrogen leak in reactor pressure vessel", Subsystem::TankStructure),
+            ("Neutron poison buildup in fuel elements", Subsystem::Power),
+            ("Turbopump seal failure from radiation damage", Subsystem::Turbopump),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Radiation shielding structural failure",
-            "Reactor SCRAM system false trigger",
-            "Hydrogen tank embrittlement fracture",
-            "Reactor coolant channel blockage",
-            "Uncontrolled criticality excursion risk",
-            "Nozzle detachment from thermal cycling",
+            ("Radiation shielding structural failure", Subsystem::TankStructure),
+            ("Reactor SCRAM system false trigger", Subsystem::GncSoftware),
+            ("Hydrogen tank embrittlement fracture", Subsystem::TankStructure),
+            ("Reactor coolant channel blockage", Subsystem::Power),
+            ("Uncontrolled criticality excursion risk", Subsystem::Power),
+            ("Nozzle detachment from thermal cycling", Subsystem::Combustion),
         ][..],
     };
 
     let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
 }
 
-fn generate_solar_sail_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> String {
+fn generate_solar_sail_flaw_description(consequence: &FlawConsequence, rng: &mut StdRng) -> (String, Subsystem) {
     let descriptions = match consequence {
         FlawConsequence::PerformanceDegradation(_) => &[
-            "Sail reflectivity degradation",
-            "Micrometeorite puncture damage",
-            "Sail deployment mechanism binding",
-            "Attitude control vane misalignment",
-            "Sail surface wrinkling",
-            "Solar radiation pressure modeling error",
+            ("Sail reflectivity degradation", Subsystem::TankStructure),
+            ("Micrometeorite puncture damage", Subsystem::TankStructure),
+            ("Sail deployment mechanism binding", Subsystem::Separation),
+            ("Attitude control vane misalignment", Subsystem::GncSoftware),
+            ("Sail surface wrinkling", Subsystem::TankStructure),
+            ("Solar radiation pressure modeling error", Subsystem::GncSoftware),
         ][..],
         FlawConsequence::EngineLoss => &[
-            "Sail boom structural failure",
-            "Complete sail deployment failure",
-            "Sail tearing from thermal stress",
-            "Attitude control system failure",
-            "Sail furling mechanism jam",
-            "Boom hinge seizure",
+            ("Sail boom structural failure", Subsystem::TankStructure),
+            ("Complete sail deployment failure", Subsystem::Separation),
+            ("Sail tearing from thermal stress", Subsystem::TankStructure),
+            ("Attitude control system failure", Subsystem::GncSoftware),
+            ("Sail furling mechanism jam", Subsystem::Separation),
+            ("Boom hinge seizure", Subsystem::Separation),
         ][..],
         FlawConsequence::StageLoss => &[
-            "Sail catastrophic tear propagation",
-            "Boom collapse from impact",
-            "Sail jettison mechanism malfunction",
-            "Thermal deformation beyond recovery",
-            "Complete attitude loss from sail asymmetry",
-            "Sail connection point failure",
+            ("Sail catastrophic tear propagation", Subsystem::TankStructure),
+            ("Boom collapse from impact", Subsystem::TankStructure),
+            ("Sail jettison mechanism malfunction", Subsystem::Separation),
+            ("Thermal deformation beyond recovery", Subsystem::TankStructure),
+            ("Complete attitude loss from sail asymmetry", Subsystem::GncSoftware),
+            ("Sail connection point failure", Subsystem::TankStructure),
         ][..],
     };
 
     let idx = rng.gen_range(0..descriptions.len());
-    descriptions[idx].to_string()
+    let (text, subsystem) = descriptions[idx];
+    (text.to_string(), subsystem)
 }
 
-/// Roll for flaw discovery during a testing cycle.
+/// Combine per-flight activation chances by subsystem (1 - ∏(1 - p)
+/// within each). Subsystems without entries are omitted; sorted worst
+/// first.
+pub fn risk_by_subsystem(chances: impl IntoIterator<Item = (Subsystem, f64)>) -> Vec<(Subsystem, f64)> {
+    let mut survive = std::collections::BTreeMap::new();
+    for (subsystem, p) in chances {
+        *survive.entry(subsystem).or_insert(1.0) *= 1.0 - p;
+    }
+    let mut risks: Vec<(Subsystem, f64)> = survive.into_iter()
+        .map(|(sub, s)| (sub, 1.0 - s))
+        .collect();
+    risks.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    risks
+}
+
+/// What a risk estimate rests on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskEvidence {
+    /// A discovered flaw: its activation chance is on file.
+    Known,
+    /// An undiscovered flaw telemetry has hinted at: where it is, not
+    /// how bad.
+    Telemetry,
+    /// Flaws testing hasn't turned up yet, estimated from how many a
+    /// design like this starts with and how long it has been tested.
+    Prior,
+}
+
+/// Estimated per-flight chance that a flaw bites at one launch event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventRisk {
+    pub subsystem: Subsystem,
+    pub risk: f64,
+    /// 0..1: the share of the estimate backed by evidence — discovered
+    /// flaws fully, telemetry hints half, the prior not at all.
+    pub confidence: f64,
+}
+
+impl EventRisk {
+    /// The launch event this risk applies to.
+    pub fn event(&self) -> &'static str {
+        self.subsystem.launch_event()
+    }
+}
+
+/// Expected activation chance still hidden, per flaw a design was born
+/// with, after `cycles` testing cycles. Flaws are generated with
+/// activation a = x² and discovery u·x per cycle (x, u uniform); flaws
+/// that bite often are found early, so the survivors skew mild.
+pub fn expected_hidden_activation(cycles: u32) -> f64 {
+    let n = cycles as f64;
+    (0.5 - 1.0 / ((n + 2.0) * (n + 3.0))) / (n + 1.0)
+}
+
+/// Chance a flaw is still undiscovered after `cycles` testing cycles,
+/// averaged over the generation prior.
+pub fn hidden_survival(cycles: u32) -> f64 {
+    let harmonic: f64 = (1..=cycles + 1).map(|k| 1.0 / k as f64).sum();
+    harmonic / (cycles + 1) as f64
+}
+
+/// Expected activation chance of a flaw known to have survived
+/// `cycles` testing cycles undiscovered.
+pub fn expected_survivor_activation(cycles: u32) -> f64 {
+    expected_hidden_activation(cycles) / hidden_survival(cycles)
+}
+
+/// Per-vehicle evidence for the risk estimate: the flaws the player
+/// can see, anomaly hints on the ones they can't, and the prior for
+/// the rest. `count` parallel copies (engines on a stage) roll
+/// separately. `prior_complexity` is the vehicle's mean starting flaw
+/// count, None where no prior applies (bought-in hardware).
+pub fn risk_components(
+    flaws: &[Flaw],
+    count: u32,
+    prior_complexity: Option<u32>,
+    cycles: u32,
+) -> Vec<(Subsystem, f64, RiskEvidence)> {
+    let scale = |p: f64| 1.0 - (1.0 - p.min(1.0)).powi(count as i32);
+    let mut out = Vec::new();
+    let mut hinted_activation = 0.0;
+    for flaw in flaws {
+        if flaw.discovered {
+            out.push((flaw.subsystem, scale(flaw.activation_chance), RiskEvidence::Known));
+        } else if flaw.anomaly.is_some() {
+            let estimate = expected_survivor_activation(cycles);
+            hinted_activation += estimate;
+            out.push((flaw.subsystem, scale(estimate), RiskEvidence::Telemetry));
+        }
+    }
+    if let Some(complexity) = prior_complexity {
+        let hidden = (complexity as f64 * expected_hidden_activation(cycles) - hinted_activation).max(0.0);
+        let per_event = hidden / Subsystem::ALL.len() as f64;
+        if per_event > 0.0 {
+            out.extend(Subsystem::ALL.iter().map(|&sub| (sub, scale(per_event), RiskEvidence::Prior)));
+        }
+    }
+    out
+}
+
+/// Combine risk components into one estimate per launch event,
+/// worst first. Every event is listed, including those with no risk.
+pub fn estimate_event_risks(
+    components: impl IntoIterator<Item = (Subsystem, f64, RiskEvidence)>,
+) -> Vec<EventRisk> {
+    // Per event: survival product, total and evidence-weighted chance.
+    let mut acc: std::collections::BTreeMap<Subsystem, (f64, f64, f64)> =
+        Subsystem::ALL.iter().map(|&sub| (sub, (1.0, 0.0, 0.0))).collect();
+    for (subsystem, p, evidence) in components {
+        let weight = match evidence {
+            RiskEvidence::Known => 1.0,
+            RiskEvidence::Telemetry => 0.5,
+            RiskEvidence::Prior => 0.0,
+        };
+        let entry = acc.entry(subsystem).or_insert((1.0, 0.0, 0.0));
+        entry.0 *= 1.0 - p;
+        entry.1 += p;
+        entry.2 += weight * p;
+    }
+    let mut risks: Vec<EventRisk> = acc.into_iter()
+        .map(|(subsystem, (survive, total, backed))| EventRisk {
+            subsystem,
+            risk: 1.0 - survive,
+            confidence: if total > 0.0 { backed / total } else { 1.0 },
+        })
+        .collect();
+    risks.sort_by(|a, b| b.risk.partial_cmp(&a.risk).unwrap_or(std::cmp::Ordering::Equal));
+    risks
+}
+
+/// Roll for flaw discovery during a testing cycle. Flaws with an
+/// anomaly hint get `anomaly_multiplier` times their discovery chance.
 /// Returns indices of newly discovered flaws.
-pub fn roll_discoveries_with_rng(flaws: &mut [Flaw], rng: &mut StdRng) -> Vec<usize> {
+pub fn roll_discoveries_with_rng(
+    flaws: &mut [Flaw], rng: &mut StdRng, anomaly_multiplier: f64,
+) -> Vec<usize> {
     let mut discovered = Vec::new();
     for (i, flaw) in flaws.iter_mut().enumerate() {
         if !flaw.discovered {
             let roll: f64 = rng.gen();
-            if roll < flaw.discovery_probability {
+            let chance = if flaw.anomaly.is_some() {
+                flaw.discovery_probability * anomaly_multiplier
+            } else {
+                flaw.discovery_probability
+            };
+            if roll < chance {
                 flaw.discovered = true;
                 discovered.push(i);
             }
@@ -486,8 +1006,38 @@ pub fn roll_discoveries_with_rng(flaws: &mut [Flaw], rng: &mut StdRng) -> Vec<us
     discovered
 }
 
+/// Discover a random `share` of the still-hidden flaws, rounded up so
+/// at least one turns up if any remain. Returns their indices.
+pub fn reveal_share(flaws: &mut [Flaw], share: f64, rng: &mut StdRng) -> Vec<usize> {
+    let mut hidden: Vec<usize> = (0..flaws.len()).filter(|&i| !flaws[i].discovered).collect();
+    let count = ((hidden.len() as f64 * share).ceil() as usize).min(hidden.len());
+    hidden.shuffle(rng);
+    hidden.truncate(count);
+    hidden.sort_unstable();
+    for &i in &hidden {
+        flaws[i].discovered = true;
+    }
+    hidden
+}
+
+/// Attach near-miss hints (flaw index, hint) to still-hidden flaws.
+/// Already discovered or already hinted flaws are skipped. Returns the
+/// hints that landed.
+pub fn record_anomalies(flaws: &mut [Flaw], hints: Vec<(usize, String)>) -> Vec<String> {
+    let mut applied = Vec::new();
+    for (idx, hint) in hints {
+        if let Some(flaw) = flaws.get_mut(idx) {
+            if !flaw.discovered && flaw.anomaly.is_none() {
+                flaw.anomaly = Some(hint.clone());
+                applied.push(hint);
+            }
+        }
+    }
+    applied
+}
+
 /// Sample from a gaussian distribution using Box-Muller transform.
-fn gaussian_sample(mean: f64, stddev: f64, rng: &mut StdRng) -> f64 {
+pub(crate) fn gaussian_sample(mean: f64, stddev: f64, rng: &mut StdRng) -> f64 {
     let u1: f64 = rng.gen();
     let u2: f64 = rng.gen();
     let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
@@ -616,7 +1166,7 @@ mod tests {
         let mut discovered_first = false;
         for seed in 0..100 {
             let mut roll_rng = StdRng::seed_from_u64(seed + 1000);
-            let newly = roll_discoveries_with_rng(&mut flaws, &mut roll_rng);
+            let newly = roll_discoveries_with_rng(&mut flaws, &mut roll_rng, 1.0);
             if newly.contains(&0) {
                 discovered_first = true;
                 break;
@@ -656,6 +1206,8 @@ mod tests {
             discovery_probability: 0.3,
             discovered: false,
             trigger: FlawTrigger::PerFlight,
+            subsystem: Subsystem::Combustion,
+            anomaly: None,
         };
         assert_eq!(flaw.daily_rate(), 0.5);
     }
@@ -669,6 +1221,8 @@ mod tests {
             discovery_probability: 0.3,
             discovered: false,
             trigger: FlawTrigger::PerDay,
+            subsystem: Subsystem::Combustion,
+            anomaly: None,
         };
         let rate = flaw.daily_rate();
         // 1 - (1 - 0.30)^(1/365) ≈ 0.000977
@@ -752,4 +1306,115 @@ mod tests {
                 "Engine flaws should all be PerFlight");
         }
     }
+
+    #[test]
+    fn test_flaws_carry_subsystem_and_fix_work() {
+        let mut rng = test_rng();
+        let mut next_id = 0u64;
+        let flaws = generate_flaws(40, &mut rng, &mut next_id, &cfg());
+        // Chemical engine tables cover more than one subsystem.
+        let subsystems: std::collections::HashSet<Subsystem> =
+            flaws.iter().map(|f| f.subsystem).collect();
+        assert!(subsystems.len() > 1);
+        for f in &flaws {
+            assert_eq!(f.fix_work(30.0), 30.0 * f.subsystem.fix_work_factor());
+        }
+        assert!(Subsystem::Turbopump.fix_work_factor() > Subsystem::GncSoftware.fix_work_factor());
+    }
+
+    #[test]
+    fn test_risk_by_subsystem_combines_and_sorts() {
+        let risks = risk_by_subsystem([
+            (Subsystem::Avionics, 0.1),
+            (Subsystem::Turbopump, 0.5),
+            (Subsystem::Avionics, 0.1),
+        ]);
+        assert_eq!(risks.len(), 2);
+        assert_eq!(risks[0].0, Subsystem::Turbopump);
+        assert!((risks[1].1 - 0.19).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hidden_flaw_prior_matches_generation() {
+        // Untested: every flaw is hidden, activation averages E[x²] = 1/3.
+        assert!((expected_hidden_activation(0) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(hidden_survival(0), 1.0);
+
+        // Monte Carlo over the generator's own rolls after 4 cycles.
+        let mut rng = StdRng::seed_from_u64(11);
+        let (mut hidden, mut survivors, trials) = (0.0, 0.0, 200_000);
+        for _ in 0..trials {
+            let x: f64 = rng.gen();
+            let u: f64 = rng.gen();
+            if (0..4).all(|_| rng.gen::<f64>() >= u * x) {
+                hidden += x * x;
+                survivors += 1.0;
+            }
+        }
+        assert!((hidden / trials as f64 - expected_hidden_activation(4)).abs() < 0.005);
+        assert!((survivors / trials as f64 - hidden_survival(4)).abs() < 0.005);
+        assert!(expected_survivor_activation(10) < expected_survivor_activation(1));
+    }
+
+    #[test]
+    fn test_event_risk_estimates_weigh_evidence() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut next_id = 0u64;
+        let mut flaws = generate_rocket_flaws(3, &mut rng, &mut next_id, &cfg());
+        flaws.truncate(2);
+        flaws[0].subsystem = Subsystem::Turbopump;
+        flaws[0].discovered = true;
+        flaws[0].activation_chance = 0.4;
+        flaws[1].subsystem = Subsystem::Separation;
+        flaws[1].anomaly = Some("hint".into());
+
+        // Fully tested and nothing hidden but the hint: the known flaw
+        // leads with full confidence, the hinted one at half.
+        let risks = estimate_event_risks(risk_components(&flaws, 1, None, 8));
+        assert_eq!(risks.len(), Subsystem::ALL.len());
+        assert_eq!(risks[0].subsystem, Subsystem::Turbopump);
+        assert!((risks[0].risk - 0.4).abs() < 1e-12);
+        assert_eq!(risks[0].confidence, 1.0);
+        let sep = risks.iter().find(|r| r.subsystem == Subsystem::Separation).unwrap();
+        assert_eq!(sep.confidence, 0.5);
+        assert_eq!(risks.last().unwrap().risk, 0.0);
+
+        // The untested prior spreads over every event at no confidence.
+        let untested = estimate_event_risks(risk_components(&[], 1, Some(7), 0));
+        assert!(untested.iter().all(|r| r.risk > 0.0 && r.confidence == 0.0));
+        let tested = estimate_event_risks(risk_components(&[], 1, Some(7), 10));
+        assert!(tested[0].risk < untested[0].risk);
+    }
+
+    #[test]
+    fn test_anomaly_hint_speeds_discovery() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut next_id = 0u64;
+        let mut flaws = generate_rocket_flaws(10, &mut rng, &mut next_id, &cfg());
+        for f in &mut flaws {
+            f.discovery_probability = 0.2;
+        }
+        let applied = record_anomalies(&mut flaws, vec![(0, "hint".into()), (0, "again".into())]);
+        assert_eq!(applied, vec!["hint".to_string()], "a flaw takes one hint");
+
+        // With a 5x multiplier the hinted flaw is certain to turn up.
+        let newly = roll_discoveries_with_rng(&mut flaws, &mut rng, 5.0);
+        assert!(newly.contains(&0));
+    }
+
+    #[test]
+    fn test_hot_staging_flaws_sit_at_separation() {
+        let mut rng = test_rng();
+        let mut next_id = 1;
+        let flaws = generate_hot_staging_flaws(2, &mut rng, &mut next_id, &cfg());
+        assert_eq!(flaws.len(), 2);
+        assert!(flaws.iter().all(|f| f.subsystem == Subsystem::Separation));
+        assert_eq!(next_id, 3);
+
+        // A cold-staged stack leaves the stream untouched.
+        let mut a = test_rng();
+        let mut b = test_rng();
+        assert!(generate_hot_staging_flaws(0, &mut a, &mut next_id, &cfg()).is_empty());
+        assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+    }
 }
@@ -0,0 +1,191 @@
+//! One register of everything the company owns: rockets and reflown
+//! boosters in stock, spacecraft and satellites in space, depots and
+//! ISRU plants, facilities, the pad and the recovery fleet. Each entry
+//! carries its kind, where it is, how it stands, its book value and
+//! age, so a fleet overview can list, filter and total the lot without
+//! knowing which module keeps what. Built on demand by
+//! `GameState::fleet_registry`; nothing here is saved.
+
+use std::fmt;
+
+use crate::calendar::GameDate;
+
+/// What kind of thing an entry is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FleetCategory {
+    /// An integrated rocket in stock.
+    Rocket,
+    /// A landed booster waiting to fly again.
+    Booster,
+    /// A spacecraft parked somewhere or on its way there.
+    Spacecraft,
+    /// A spacecraft serving in a constellation.
+    Satellite,
+    Depot,
+    IsruPlant,
+    /// Manufacturing floor space.
+    Facility,
+    LaunchPad,
+    /// A landing zone or drone ship.
+    RecoveryAsset,
+}
+
+impl FleetCategory {
+    pub const ALL: [FleetCategory; 9] = [
+        FleetCategory::Rocket,
+        FleetCategory::Booster,
+        FleetCategory::Spacecraft,
+        FleetCategory::Satellite,
+        FleetCategory::Depot,
+        FleetCategory::IsruPlant,
+        FleetCategory::Facility,
+        FleetCategory::LaunchPad,
+        FleetCategory::RecoveryAsset,
+    ];
+}
+
+impl fmt::Display for FleetCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FleetCategory::Rocket => write!(f, "Rocket"),
+            FleetCategory::Booster => write!(f, "Booster"),
+            FleetCategory::Spacecraft => write!(f, "Spacecraft"),
+            FleetCategory::Satellite => write!(f, "Satellite"),
+            FleetCategory::Depot => write!(f, "Depot"),
+            FleetCategory::IsruPlant => write!(f, "ISRU plant"),
+            FleetCategory::Facility => write!(f, "Facility"),
+            FleetCategory::LaunchPad => write!(f, "Launch pad"),
+            FleetCategory::RecoveryAsset => write!(f, "Recovery asset"),
+        }
+    }
+}
+
+/// How an entry stands today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FleetStatus {
+    /// Free to be used: a rocket or booster to fly, a spacecraft to
+    /// send on, a pad or recovery asset to launch with.
+    Ready,
+    /// Doing its job: a satellite earning, a depot storing, a plant
+    /// producing, floor space in use.
+    InService,
+    /// Tied up for now: in flight, turning around, being converted.
+    Busy,
+    /// Needs something done before it's useful again: worn out, past
+    /// its shelf life, due for inspection or retirement.
+    NeedsAttention,
+}
+
+impl fmt::Display for FleetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FleetStatus::Ready => write!(f, "ready"),
+            FleetStatus::InService => write!(f, "in service"),
+            FleetStatus::Busy => write!(f, "busy"),
+            FleetStatus::NeedsAttention => write!(f, "needs attention"),
+        }
+    }
+}
+
+/// One thing the company owns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetEntry {
+    pub category: FleetCategory,
+    pub name: String,
+    /// Where it is: a location id in space, or the pad's site.
+    pub location: String,
+    pub status: FleetStatus,
+    /// What the status means for this entry, for display.
+    pub detail: String,
+    /// What it's carried at: fixed assets at book value, stock at build
+    /// cost. Hardware in space was expensed when it flew and is 0.
+    pub book_value: f64,
+    /// Months since it was built, deployed or put on the books, where
+    /// that's known.
+    pub age_months: Option<u32>,
+}
+
+/// Months from `since` to `today`.
+pub fn months_between(since: GameDate, today: GameDate) -> u32 {
+    since.days_until(&today) / 30
+}
+
+/// Everything the company owns as of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetRegistry {
+    pub as_of: GameDate,
+    pub entries: Vec<FleetEntry>,
+}
+
+impl FleetRegistry {
+    /// Entries of one kind.
+    pub fn of(&self, category: FleetCategory) -> impl Iterator<Item = &FleetEntry> {
+        self.entries.iter().filter(move |e| e.category == category)
+    }
+
+    /// Entries at one location.
+    pub fn at<'a>(&'a self, location: &'a str) -> impl Iterator<Item = &'a FleetEntry> {
+        self.entries.iter().filter(move |e| e.location == location)
+    }
+
+    /// Entries in one status.
+    pub fn with_status(&self, status: FleetStatus) -> impl Iterator<Item = &FleetEntry> {
+        self.entries.iter().filter(move |e| e.status == status)
+    }
+
+    pub fn count(&self, category: FleetCategory) -> usize {
+        self.of(category).count()
+    }
+
+    pub fn book_value(&self) -> f64 {
+        self.entries.iter().map(|e| e.book_value).sum()
+    }
+
+    /// Count and book value per kind, for kinds the company has any of.
+    pub fn summary(&self) -> Vec<(FleetCategory, usize, f64)> {
+        FleetCategory::ALL.iter()
+            .map(|&c| (c, self.count(c), self.of(c).map(|e| e.book_value).sum()))
+            .filter(|&(_, n, _)| n > 0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(category: FleetCategory, location: &str, status: FleetStatus, book_value: f64) -> FleetEntry {
+        FleetEntry {
+            category,
+            name: format!("{}", category),
+            location: location.to_string(),
+            status,
+            detail: String::new(),
+            book_value,
+            age_months: None,
+        }
+    }
+
+    #[test]
+    fn test_registry_filters_and_totals() {
+        let registry = FleetRegistry {
+            as_of: GameDate::new(2030, 1, 1),
+            entries: vec![
+                entry(FleetCategory::Rocket, "cape", FleetStatus::Ready, 50.0),
+                entry(FleetCategory::Rocket, "cape", FleetStatus::NeedsAttention, 40.0),
+                entry(FleetCategory::Satellite, "leo", FleetStatus::InService, 0.0),
+                entry(FleetCategory::LaunchPad, "cape", FleetStatus::Busy, 100.0),
+            ],
+        };
+        assert_eq!(registry.count(FleetCategory::Rocket), 2);
+        assert_eq!(registry.at("cape").count(), 3);
+        assert_eq!(registry.with_status(FleetStatus::NeedsAttention).count(), 1);
+        assert_eq!(registry.book_value(), 190.0);
+        assert_eq!(registry.summary(), vec![
+            (FleetCategory::Rocket, 2, 90.0),
+            (FleetCategory::Satellite, 1, 0.0),
+            (FleetCategory::LaunchPad, 1, 100.0),
+        ]);
+        assert_eq!(months_between(GameDate::new(2030, 1, 1), GameDate::new(2030, 4, 1)), 3);
+    }
+}
@@ -43,7 +43,27 @@ pub enum Payload {
         /// inventory rocket at launch time; future work may let the player
         /// customise it per-launch.
         name: String,
+        /// Carried over from the spacecraft when it docks, so wear
+        /// survives a dock and undock.
+        #[serde(default = "crate::servicing::full_health")]
+        health: f64,
     },
+    /// An ISRU plant, set down at `deploy_at` on arrival.
+    IsruPlant {
+        design: crate::infrastructure::IsruPlantDesign,
+        deploy_at: String,
+    },
+    /// A depot built to a player design, replacing the one at
+    /// `deploy_at` on arrival. Carries the design's flaws as built.
+    Depot {
+        design: crate::infrastructure::DepotDesign,
+        flaws: Vec<crate::flaw::Flaw>,
+        mass_kg: f64,
+        deploy_at: String,
+    },
+    /// Repairs (and maybe propellant) for an asset already in place,
+    /// handed over on arrival at the target's location.
+    ServicingKit(crate::servicing::ServicingKit),
 }
 
 impl Payload {
@@ -54,6 +74,9 @@ impl Payload {
         match self {
             Payload::ContractDelivery { payload_kg, .. } => *payload_kg,
             Payload::TestMass { mass_kg } => *mass_kg,
+            Payload::IsruPlant { design, .. } => design.mass_kg,
+            Payload::Depot { mass_kg, .. } => *mass_kg,
+            Payload::ServicingKit(kit) => kit.mass_kg,
             Payload::Spacecraft { design, rocket, nested_payloads, .. } => {
                 let mut spacecraft_mass = 0.0;
                 for (gi, group) in design.stage_groups.iter().enumerate() {
@@ -111,6 +134,111 @@ pub enum CompanyRef {
     Competitor(usize),
 }
 
+/// What went wrong on a transit leg.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnomalyKind {
+    StuckValve,
+    GuidanceDrift,
+    SensorDropout,
+    ThermalExcursion,
+}
+
+impl AnomalyKind {
+    pub const ALL: [AnomalyKind; 4] = [
+        AnomalyKind::StuckValve,
+        AnomalyKind::GuidanceDrift,
+        AnomalyKind::SensorDropout,
+        AnomalyKind::ThermalExcursion,
+    ];
+
+    pub fn description(self) -> &'static str {
+        match self {
+            AnomalyKind::StuckValve => "stuck propellant valve",
+            AnomalyKind::GuidanceDrift => "guidance drift",
+            AnomalyKind::SensorDropout => "star tracker dropout",
+            AnomalyKind::ThermalExcursion => "thermal excursion",
+        }
+    }
+}
+
+/// How an in-transit anomaly played out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnomalyResponse {
+    /// Flagged in telemetry; nothing to do.
+    Benign,
+    /// A mid-course correction burned reserve delta-v (m/s).
+    Correction { delta_v: f64 },
+    /// The engineering team worked the problem from the ground.
+    TeamResponse { cost: f64 },
+    /// A correction was needed but the reserve couldn't cover it.
+    Stranded,
+    /// The vehicle was lost.
+    MissionLost,
+}
+
+impl std::fmt::Display for AnomalyResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnomalyResponse::Benign => write!(f, "no action needed"),
+            AnomalyResponse::Correction { delta_v } => {
+                write!(f, "mid-course correction ({delta_v:.0} m/s)")
+            }
+            AnomalyResponse::TeamResponse { cost } => {
+                write!(f, "resolved by the ground team ({})", crate::resources::format_money(*cost))
+            }
+            AnomalyResponse::Stranded => write!(f, "correction beyond reserves"),
+            AnomalyResponse::MissionLost => write!(f, "mission lost"),
+        }
+    }
+}
+
+/// Roll one in-transit day. Most days nothing happens; otherwise the
+/// anomaly and what it would take to resolve it, before the flight's
+/// reserves or team have been checked.
+pub fn roll_transit_anomaly(
+    rng: &mut impl rand::Rng, cfg: &crate::balance_config::TransitAnomalyConfig,
+) -> Option<(AnomalyKind, AnomalyResponse)> {
+    if rng.gen::<f64>() >= cfg.daily_chance {
+        return None;
+    }
+    let kind = AnomalyKind::ALL[rng.gen_range(0..AnomalyKind::ALL.len())];
+    let weights = [cfg.benign_weight, cfg.correction_weight, cfg.team_weight, cfg.loss_weight]
+        .map(|w| w.max(0.0));
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut pick = rng.gen::<f64>() * total;
+    let mut outcome = weights.len() - 1;
+    for (i, w) in weights.iter().enumerate() {
+        if pick < *w {
+            outcome = i;
+            break;
+        }
+        pick -= w;
+    }
+    let response = match outcome {
+        0 => AnomalyResponse::Benign,
+        1 => {
+            let (lo, hi) = (cfg.correction_dv_min, cfg.correction_dv_max.max(cfg.correction_dv_min));
+            AnomalyResponse::Correction { delta_v: rng.gen_range(lo..=hi) }
+        }
+        2 => AnomalyResponse::TeamResponse { cost: cfg.team_response_cost },
+        _ => AnomalyResponse::MissionLost,
+    };
+    Some((kind, response))
+}
+
+/// One entry in a flight's telemetry log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitAnomaly {
+    pub date: GameDate,
+    /// Route leg the flight was on.
+    pub leg: usize,
+    pub kind: AnomalyKind,
+    pub response: AnomalyResponse,
+}
+
 /// A rocket in flight through the location graph.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Flight {
@@ -147,6 +275,26 @@ pub struct Flight {
     /// than when a stage's engine happens to fire.
     #[serde(default)]
     pub reactor_flaws_rolled: bool,
+    /// Design revision the rocket was built to (certification streaks).
+    #[serde(default)]
+    pub revision: u32,
+    /// Astronauts aboard; empty for an uncrewed flight.
+    #[serde(default)]
+    pub crew: Vec<crate::astronaut::AstronautId>,
+    /// In-transit anomalies, oldest first.
+    #[serde(default)]
+    pub telemetry: Vec<TransitAnomaly>,
+    /// Where the flight's spent stages have ended up so far.
+    #[serde(default)]
+    pub stage_disposals: Vec<crate::disposal::StageDisposal>,
+    /// Chance an approach to dock on arrival goes wrong, fixed at launch
+    /// by the software and avionics the flight carries.
+    #[serde(default)]
+    pub docking_risk: f64,
+    /// How far off target (m/s) the launch inserted its payload, drawn
+    /// at launch (see [`crate::insertion`]).
+    #[serde(default)]
+    pub insertion_error: f64,
 }
 
 /// Sub-phase of the current leg, used for status display.
@@ -170,7 +318,95 @@ impl FlightPhase {
     }
 }
 
+/// Snapshot of one flight for tracking displays: where it is, where it's
+/// headed, and how far along the route it has come. Location fields are
+/// location ids; callers map them to display names.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlightStatusSummary {
+    pub flight_id: FlightId,
+    pub rocket_name: String,
+    pub destination: String,
+    pub leg_from: String,
+    pub leg_to: String,
+    /// Zero-based index of the leg in progress.
+    pub leg: usize,
+    pub legs: usize,
+    pub phase: Option<FlightPhase>,
+    pub days_remaining: u32,
+    pub payload_count: usize,
+    pub payload_kg: f64,
+    /// Fraction of the whole route's days already flown, in [0, 1].
+    pub progress: f64,
+}
+
+/// A flight's route as plain data for a map display: the stops in
+/// order, the legs between them, and where along them the flight is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightPath {
+    pub flight_id: FlightId,
+    pub rocket_name: String,
+    /// Every location the route touches, departure first.
+    pub stops: Vec<PathStop>,
+    /// `legs[i]` runs from `stops[i]` to `stops[i + 1]`.
+    pub legs: Vec<PathLeg>,
+    /// Zero-based index of the leg in progress (== `legs.len()` once
+    /// the route is flown).
+    pub current_leg: usize,
+    /// Fraction of the current leg's days flown, in [0, 1].
+    pub leg_progress: f64,
+    /// Fraction of the whole route's days flown, in [0, 1].
+    pub progress: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PathStop {
+    pub id: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PathLeg {
+    pub delta_v: f64,
+    pub days: u32,
+}
+
 impl Flight {
+    /// The route with progress, for drawing on the orbit map.
+    pub fn path(&self) -> FlightPath {
+        let stop = |id: &str| PathStop {
+            id: id.to_string(),
+            display_name: DELTA_V_MAP.location(id)
+                .map(|l| l.display_name.to_string())
+                .unwrap_or_else(|| id.to_string()),
+        };
+        let mut stops: Vec<PathStop> = self.route.first()
+            .map(|leg| stop(&leg.from))
+            .into_iter()
+            .collect();
+        stops.extend(self.route.iter().map(|leg| stop(&leg.to)));
+        if stops.is_empty() {
+            stops.push(stop(&self.current_location));
+        }
+        let leg_progress = match self.route.get(self.current_leg) {
+            Some(leg) if leg.total_days() > 0 => {
+                (1.0 - self.leg_days_remaining as f64 / leg.total_days() as f64).clamp(0.0, 1.0)
+            }
+            Some(_) => 0.0,
+            None => 1.0,
+        };
+        FlightPath {
+            flight_id: self.id,
+            rocket_name: self.rocket_name.clone(),
+            stops,
+            legs: self.route.iter()
+                .map(|leg| PathLeg { delta_v: leg.delta_v_cost, days: leg.total_days() })
+                .collect(),
+            current_leg: self.current_leg.min(self.route.len()),
+            leg_progress,
+            progress: self.progress(),
+        }
+    }
+
     /// Total payload mass across all payloads.
     pub fn total_payload_kg(&self) -> f64 {
         self.payloads.iter().map(|p| p.mass_kg()).sum()
@@ -192,6 +428,42 @@ impl Flight {
         total
     }
 
+    /// Days the whole route takes, first leg to last.
+    pub fn route_days(&self) -> u32 {
+        self.route.iter().map(|leg| leg.total_days()).sum()
+    }
+
+    /// Fraction of the route flown so far, in [0, 1]. A route with no
+    /// duration counts as done.
+    pub fn progress(&self) -> f64 {
+        let total = self.route_days();
+        if total == 0 {
+            return 1.0;
+        }
+        (1.0 - self.eta_days() as f64 / total as f64).clamp(0.0, 1.0)
+    }
+
+    /// Everything a tracking display needs about this flight.
+    pub fn status_summary(&self) -> FlightStatusSummary {
+        let (leg_from, leg_to) = self.route.get(self.current_leg)
+            .map(|leg| (leg.from.clone(), leg.to.clone()))
+            .unwrap_or_else(|| (self.current_location.clone(), self.current_location.clone()));
+        FlightStatusSummary {
+            flight_id: self.id,
+            rocket_name: self.rocket_name.clone(),
+            destination: self.destination().to_string(),
+            leg_from,
+            leg_to,
+            leg: self.current_leg.min(self.route.len().saturating_sub(1)),
+            legs: self.route.len(),
+            phase: self.current_phase(),
+            days_remaining: self.eta_days(),
+            payload_count: self.payloads.len(),
+            payload_kg: self.total_payload_kg(),
+            progress: self.progress(),
+        }
+    }
+
     /// What sub-phase the flight is currently in.
     /// Returns None if the flight has completed all legs.
     pub fn current_phase(&self) -> Option<FlightPhase> {
@@ -422,10 +694,16 @@ mod tests {
             propellant_mass_kg: prop, structural_mass_kg: dry,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design = RocketDesign {
             id: RocketDesignId(id), name: format!("Tiny{}", id),
             stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
         };
         // Payload mass on the inner rocket = 0 here; tests using nested
         // payloads sum manually.
@@ -445,6 +723,7 @@ mod tests {
             nested_payloads: vec![],
             rocket_project_id: RocketProjectId(1),
             name: "LEM".into(),
+            health: crate::servicing::FULL_HEALTH,
         };
         assert!((lem_payload.mass_kg() - 700.0).abs() < 0.01);
 
@@ -458,6 +737,7 @@ mod tests {
             nested_payloads: vec![lem_payload],
             rocket_project_id: RocketProjectId(2),
             name: "CSM".into(),
+            health: crate::servicing::FULL_HEALTH,
         };
         // CSM (1300) + LEM (700) = 2000.
         assert!((csm_payload.mass_kg() - 2000.0).abs() < 0.01);
@@ -495,6 +775,7 @@ mod tests {
             id: crate::rocket::RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![],
+            performance_reserve: Some(0.0),
         };
         let rocket = design.instantiate(
             crate::rocket::RocketId(1), "earth_surface", 100.0,
@@ -529,6 +810,12 @@ mod tests {
             launch_partial: false,
             flaw_rolled_groups: std::collections::HashSet::new(),
             reactor_flaws_rolled: false,
+            revision: 0,
+            crew: Vec::new(),
+            telemetry: Vec::new(),
+            stage_disposals: Vec::new(),
+            docking_risk: 0.0,
+            insertion_error: 0.0,
         };
         // On leg 0 with 1 day remaining + leg 1 has 0+1=1 day
         assert_eq!(flight.eta_days(), 2);
@@ -536,6 +823,44 @@ mod tests {
         assert_eq!(flight.total_payload_kg(), 100.0);
     }
 
+    #[test]
+    fn test_status_summary_tracks_route_progress() {
+        let mut flight = make_two_leg_flight();
+        let total = flight.route_days();
+        let summary = flight.status_summary();
+        assert_eq!(summary.legs, 2);
+        assert_eq!(summary.leg, 0);
+        assert_eq!((summary.leg_from.as_str(), summary.leg_to.as_str()), ("earth_surface", "leo"));
+        assert_eq!(summary.days_remaining, total);
+        assert_eq!(summary.progress, 0.0);
+
+        // Into the coast of the second leg.
+        flight.current_leg = 1;
+        flight.leg_days_remaining = 2;
+        let summary = flight.status_summary();
+        assert_eq!(summary.leg_from, "leo");
+        assert_eq!(summary.phase, Some(FlightPhase::Coasting));
+        assert!((summary.progress - (total - 2) as f64 / total as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_path_lists_stops_legs_and_leg_progress() {
+        let mut flight = make_two_leg_flight();
+        let path = flight.path();
+        let ids: Vec<_> = path.stops.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["earth_surface", "leo", "gto"]);
+        assert_eq!(path.stops[1].display_name, "Low Earth Orbit");
+        assert_eq!(path.legs.len(), 2);
+        assert_eq!(path.legs[1].days, flight.route[1].total_days());
+        assert_eq!((path.current_leg, path.leg_progress), (0, 0.0));
+
+        flight.current_leg = 1;
+        flight.leg_days_remaining = flight.route[1].total_days() / 2;
+        let path = flight.path();
+        assert_eq!(path.current_leg, 1);
+        assert!(path.leg_progress > 0.0 && path.leg_progress < 1.0);
+    }
+
     /// A flight serialized before `company` existed (the field absent
     /// from its JSON) must deserialize as player-owned; an explicit
     /// competitor ref must round-trip intact.
@@ -591,6 +916,11 @@ mod tests {
             propellant_mass_kg: 350_000.0, structural_mass_kg: 25_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -598,11 +928,17 @@ mod tests {
             propellant_mass_kg: 90_000.0, structural_mass_kg: 5_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "TwoStage".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
         let rocket = design.instantiate(RocketId(1), "earth_surface", 5_000.0);
 
@@ -636,6 +972,12 @@ mod tests {
             launch_partial: false,
             flaw_rolled_groups: std::collections::HashSet::new(),
             reactor_flaws_rolled: false,
+            revision: 0,
+            crew: Vec::new(),
+            telemetry: Vec::new(),
+            stage_disposals: Vec::new(),
+            docking_risk: 0.0,
+            insertion_error: 0.0,
         }
     }
 
@@ -731,10 +1073,16 @@ mod tests {
             propellant_mass_kg: 1_000.0, structural_mass_kg: 200.0,
             fairing: None,
             power_sources: vec![PowerSource::new_solar_panel(panel_w)],
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         RocketDesign {
             id: RocketDesignId(1), name: "Ion".into(),
             stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -0,0 +1,123 @@
+//! A cash-flow forecast: the money the company can expect to have each
+//! day for the next while. It is built from what's already committed —
+//! salaries and the other monthly bills, pad rent coming in, payments
+//! for contracts in flight or accepted — each weighted by how likely it
+//! is to happen. Manufacturing orders are paid for when placed and
+//! their labor is the manufacturing teams' salaries, so they add
+//! nothing of their own. Built on demand by `GameState::cash_forecast`;
+//! nothing here is saved.
+
+use std::fmt;
+
+use crate::calendar::GameDate;
+
+/// Where a projected payment comes from or goes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashFlowKind {
+    /// Engineering, manufacturing and crew payroll.
+    Salaries,
+    /// Storage, pad insurance and recovery fleet upkeep.
+    Overheads,
+    PadRent,
+    /// Payment for a contract on a flight already under way.
+    Delivery,
+    /// Payment for an accepted contract not yet flown.
+    Contract,
+}
+
+impl fmt::Display for CashFlowKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CashFlowKind::Salaries => write!(f, "Salaries"),
+            CashFlowKind::Overheads => write!(f, "Overheads"),
+            CashFlowKind::PadRent => write!(f, "Pad rent"),
+            CashFlowKind::Delivery => write!(f, "Delivery"),
+            CashFlowKind::Contract => write!(f, "Contract"),
+        }
+    }
+}
+
+/// One projected payment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashFlowItem {
+    pub date: GameDate,
+    pub kind: CashFlowKind,
+    pub description: String,
+    /// Money in if positive, out if negative.
+    pub amount: f64,
+    /// Chance it happens; the forecast counts `amount × probability`.
+    pub probability: f64,
+}
+
+impl CashFlowItem {
+    pub fn expected(&self) -> f64 {
+        self.amount * self.probability
+    }
+}
+
+/// Money day by day from `start`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashForecast {
+    pub start: GameDate,
+    /// Money on hand at the start.
+    pub opening: f64,
+    /// Everything the forecast counted, by date.
+    pub items: Vec<CashFlowItem>,
+    /// Expected money at the end of each day, starting with `start`.
+    pub daily: Vec<(GameDate, f64)>,
+    /// The first day expected money goes below zero.
+    pub insolvent_on: Option<GameDate>,
+}
+
+impl CashForecast {
+    /// Run `items` over `days` days from `start`. Items outside the
+    /// window are dropped.
+    pub fn project(start: GameDate, opening: f64, days: u32, mut items: Vec<CashFlowItem>) -> Self {
+        let end = start.add_days(days);
+        items.retain(|i| i.date >= start && i.date < end);
+        items.sort_by_key(|i| i.date);
+        let mut daily = Vec::with_capacity(days as usize);
+        let mut money = opening;
+        let mut next = items.iter().peekable();
+        let mut date = start;
+        for _ in 0..days {
+            while let Some(item) = next.next_if(|i| i.date == date) {
+                money += item.expected();
+            }
+            daily.push((date, money));
+            date = date.next_day();
+        }
+        let insolvent_on = daily.iter().find(|(_, m)| *m < 0.0).map(|&(d, _)| d);
+        CashForecast { start, opening, items, daily, insolvent_on }
+    }
+
+    /// The lowest expected balance and the day it falls on.
+    pub fn low_point(&self) -> Option<(GameDate, f64)> {
+        self.daily.iter().copied().min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(date: GameDate, amount: f64, probability: f64) -> CashFlowItem {
+        CashFlowItem { date, kind: CashFlowKind::Contract, description: String::new(), amount, probability }
+    }
+
+    #[test]
+    fn test_projection_weights_payments_and_finds_insolvency() {
+        let start = GameDate::new(2030, 1, 30);
+        let items = vec![
+            item(GameDate::new(2030, 2, 1), -100.0, 1.0),
+            item(GameDate::new(2030, 1, 31), 80.0, 0.5),
+            item(GameDate::new(2030, 3, 1), -1000.0, 1.0),
+        ];
+        let forecast = CashForecast::project(start, 50.0, 5, items);
+        assert_eq!(forecast.items.len(), 2, "March is past the window");
+        let money: Vec<f64> = forecast.daily.iter().map(|&(_, m)| m).collect();
+        assert_eq!(money, vec![50.0, 90.0, -10.0, -10.0, -10.0]);
+        assert_eq!(forecast.insolvent_on, Some(GameDate::new(2030, 2, 1)));
+        assert_eq!(forecast.low_point(), Some((GameDate::new(2030, 2, 1), -10.0)));
+    }
+}
@@ -4,9 +4,13 @@
 //! (determinism depends on it).
 
 
+use rand::seq::SliceRandom;
+
 use crate::contract::{self};
 use crate::engine_project::EngineSource;
+use crate::budget::Department;
 use crate::event::GameEvent;
+use crate::team::MoraleCause;
 use crate::rocket_project::RocketProjectId;
 
 use super::*;
@@ -15,16 +19,33 @@ impl GameState {
     /// Advance the game by one day. Returns events generated this tick.
     pub fn advance_day(&mut self) -> Vec<GameEvent> {
         let mut events = Vec::new();
+        let money_before = self.player_company.money;
+        self.close_launch_window(&mut events);
 
         self.date = self.date.next_day();
+        // Shared prices move on yesterday's activity before anyone
+        // acts today.
+        self.tick_world_market();
 
         // Daily R&D across the player's project lists. The tick is a
         // Company method so competitors can eventually run the same
         // loop; tech-deficiency resolution stays here (it needs the
         // world's technology table).
-        let research = self.player_company.tick_daily_research(
-            &mut self.seed.contingent_rng, &self.balance,
-        );
+        // After a crew loss the company stands down: teams only get a
+        // day's work done often enough to average the stand-down
+        // efficiency. Morale and crunch scale each project's work by
+        // the teams on it.
+        let teams_work = self.player_company.astronauts
+            .teams_work_today(self.date, &self.balance.astronauts);
+        let mut research = if teams_work {
+            self.player_company.tick_daily_research(&mut self.seed.contingent_rng, &self.balance)
+        } else {
+            crate::company::ResearchTick::default()
+        };
+        let morale = self.player_company
+            .tick_morale(&mut self.seed.contingent_rng, &self.balance.morale);
+        self.player_company.marketing.tick_day(&self.balance.marketing);
+        research.events.extend(morale.into_iter().filter_map(crate::company::morale_game_event));
         for evt in &research.events {
             self.event_log.push(self.date, evt.clone());
         }
@@ -91,48 +112,7 @@ impl GameState {
 
         // Apply tech deficiencies to newly completed engine designs
         for pi in newly_designed_engines {
-            let project = &mut self.player_company.engine_projects[pi];
-            if let Some(tech_id) = project.technology_id {
-                if let Some(tech) = self.technologies.iter().find(|t| t.id == tech_id) {
-                    let deficiency_ids: Vec<crate::technology::TechDeficiencyId> =
-                        tech.deficiencies.iter().map(|d| d.id).collect();
-                    // Apply stat penalties from unsolved deficiencies
-                    for def in &tech.deficiencies {
-                        match &def.kind {
-                            crate::technology::TechDeficiencyKind::IspPenalty(frac) => {
-                                project.design.isp_s *= 1.0 - frac;
-                            }
-                            crate::technology::TechDeficiencyKind::MassPenalty(frac) => {
-                                project.design.mass_kg *= 1.0 + frac;
-                            }
-                            crate::technology::TechDeficiencyKind::ThrustPenalty(frac) => {
-                                project.design.thrust_n *= 1.0 - frac;
-                            }
-                            crate::technology::TechDeficiencyKind::ComplexityPenalty(n) => {
-                                project.complexity += n;
-                            }
-                            // Engine techs never generate PowerPenalty
-                            // (that's reactor-domain, handled separately).
-                            crate::technology::TechDeficiencyKind::PowerPenalty(_) => {}
-                        }
-                    }
-                    project.tech_deficiency_ids = deficiency_ids;
-                    let engine_name = project.design.name.clone();
-                    let tech_name = tech.name.clone();
-                    let desc: Vec<String> = tech.deficiencies.iter()
-                        .map(|d| format!("{}: {}", d.description, d.kind))
-                        .collect();
-                    if !desc.is_empty() {
-                        let evt = GameEvent::TechDeficienciesFound {
-                            engine_name: engine_name.clone(),
-                            tech_name: tech_name.clone(),
-                            deficiencies: desc.join(", "),
-                        };
-                        self.event_log.push(self.date, evt.clone());
-                        events.push(evt);
-                    }
-                }
-            }
+            self.apply_tech_deficiencies(pi, &mut events);
         }
 
         // Process reactor tech-deficiency revision attempts (mirrors the
@@ -241,12 +221,22 @@ impl GameState {
             self.event_log.push(self.date, evt.clone());
             events.push(evt);
 
+            self.close_budget_month(&mut events);
+            let depreciation = self.player_company.assets.depreciate_month();
+            self.record_depreciation(depreciation);
+
             // Deduct salaries
-            let salary = self.player_company.monthly_salary_cost();
+            let (eng_salary, mfg_salary) = self.player_company.monthly_salary_split(&self.balance.costs);
+            let crew_salary = self.player_company.astronauts.monthly_salary(&self.balance.astronauts);
+            let salary = eng_salary + mfg_salary + crew_salary;
             if salary > 0.0 {
                 self.player_company.money -= salary;
                 // Track expense
                 self.record_expense(salary);
+                let budgets = &mut self.player_company.budgets;
+                budgets.record(Department::Engineering, eng_salary);
+                budgets.record(Department::Manufacturing, mfg_salary);
+                budgets.record(Department::Testing, crew_salary);
                 let evt = GameEvent::SalariesPaid { amount: salary };
                 self.event_log.push(self.date, evt.clone());
                 events.push(evt);
@@ -257,12 +247,30 @@ impl GameState {
                     };
                     self.event_log.push(self.date, evt.clone());
                     events.push(evt);
+                    let evt = self.shift_morale(MoraleCause::MissedPayroll);
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
                 }
             }
 
+            // Storage fees for hardware on the shelf
+            let storage = self.player_company.monthly_storage_cost(&self.balance.storage);
+            if storage > 0.0 {
+                self.player_company.money -= storage;
+                self.record_expense(storage);
+                self.player_company.budgets.record(Department::Facilities, storage);
+                let evt = GameEvent::StorageFeesPaid { amount: storage };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            self.charge_pad_insurance(&mut events);
+            self.charge_recovery_fleet_upkeep(&mut events);
+
+            self.tick_espionage(&mut events);
+
             // Competitors pay the same salaries, silently.
             for comp in &mut self.competitors {
-                let salary = comp.company.monthly_salary_cost();
+                let salary = comp.company.monthly_salary_cost(&self.balance.costs);
                 comp.company.money -= salary;
             }
 
@@ -299,6 +307,7 @@ impl GameState {
             // Check yearly tech unlock rolls (on January)
             if self.date.month == 1 {
                 self.check_tech_unlocks(&mut events);
+                self.inflate_salaries(&mut events);
             }
 
             // Generate monthly solicitations from all active markets.
@@ -309,20 +318,72 @@ impl GameState {
             // market's volume can never shift another's draws — the
             // year-1 floor can't be starved by stream reshuffling,
             // and the additive-only property holds exactly.
-            let econ_mod = self.economy.modifier;
-            let mut generated = 0u32;
-            for market in self.markets.iter_mut() {
+            self.player_company.marketing.record_month(self.date);
+            self.apply_media_sentiment();
+            // The world's customers shop more when launch is busy.
+            let econ_mod = self.economy.modifier * self.world_market.prices.demand;
+            let launch_price = self.world_market.prices.launch;
+            let trend = self.market_trend(0.0);
+            // Saturated and neglected segments shape what's issued.
+            // Dropping saturated contracts draws from its own stream so
+            // the generation streams stay untouched.
+            self.demand.decay_month(&self.balance.demand);
+            // What the player has flown weights where and how heavy.
+            let capability = self.player_company.capability_profile();
+            // Customers pay more to a company they rate.
+            let markets_cfg = &self.balance.markets;
+            let relationship: Vec<f64> = self.markets.iter().map(|market| {
+                let rep = self.player_company.reputation.segment(market.reputation_segment, &self.balance.reputation);
+                1.0 + markets_cfg.relationship_premium * contract::rep_factor(rep, market.rep_target, markets_cfg.rep_scale)
+            }).collect();
+            let mut batch = Vec::new();
+            for (i, market) in self.markets.iter_mut().enumerate() {
                 let query = format!(
                     "contracts_{}_{}_{}", self.date.year, self.date.month, market.id.0,
                 );
                 let mut rng = self.seed.world_query(&query);
-                let cs = contract::generate_market_contracts(
+                let mut cs = contract::generate_market_contracts(
                     market, &mut rng, &mut self.next_contract_id,
-                    self.date, econ_mod, &self.balance.markets,
+                    self.date, econ_mod, trend, &self.balance.markets, &capability,
+                );
+                let mut demand_rng = self.seed.world_query(&format!(
+                    "demand_{}_{}_{}", self.date.year, self.date.month, market.id.0,
+                ));
+                self.demand.shape_contracts(
+                    &mut cs, market.budget_tolerance,
+                    |location_id| market.destination_volume(location_id, econ_mod, self.date),
+                    &mut demand_rng, &self.balance.demand,
                 );
-                generated += cs.len() as u32;
-                self.available_contracts.extend(cs);
+                for c in &mut cs {
+                    c.apply_relationship(relationship[i], market.budget_tolerance);
+                }
+                batch.extend(cs);
+            }
+            // Always something to reach for: top up contracts beyond
+            // what's been flown, each from the first active market, in
+            // random order, that has one to offer. Its own stream, so
+            // the markets' draws are untouched.
+            if !capability.is_empty() {
+                let mut stretch_rng = self.seed.world_query(&format!("stretch_{}_{}", self.date.year, self.date.month));
+                let stretches = batch.iter().filter(|c| capability.is_stretch(c)).count() as u32;
+                let mut active: Vec<usize> = (0..self.markets.len()).filter(|&i| self.markets[i].active).collect();
+                for _ in stretches..self.balance.markets.stretch_contracts_per_month {
+                    active.shuffle(&mut stretch_rng);
+                    let stretch = active.iter().find_map(|&i| contract::generate_stretch_contract(
+                        &self.markets[i], &mut stretch_rng, &mut self.next_contract_id,
+                        self.date, econ_mod, trend, &self.balance.markets, &capability,
+                    ).map(|c| (i, c)));
+                    let Some((i, mut c)) = stretch else { break };
+                    c.apply_relationship(relationship[i], self.markets[i].budget_tolerance);
+                    batch.push(c);
+                }
             }
+            for c in &mut batch {
+                c.apply_demand(launch_price);
+                c.budget_ceiling *= launch_price;
+            }
+            let generated = batch.len() as u32;
+            self.available_contracts.extend(batch);
             if generated > 0 {
                 // Sort by market ID so display order matches selection order
                 self.available_contracts.sort_by_key(|c| c.market_id.0);
@@ -366,6 +427,7 @@ impl GameState {
                 // clock — a block commitment is a decision, not a
                 // ticker item.
                 let dest = campaign.destination.clone();
+                let plane_change_dv = self.plane_change_dv(&dest);
                 let mut liftable = false;
                 for rp in &self.player_company.rocket_projects {
                     if !matches!(rp.status,
@@ -375,8 +437,8 @@ impl GameState {
                     }
                     let cap = *self.payload_capability_cache
                         .entry((rp.project_id, rp.revision, dest.clone()))
-                        .or_insert_with(|| crate::rocket_project::max_payload_to(
-                            &rp.design, "earth_surface", &dest,
+                        .or_insert_with(|| crate::rocket_project::max_payload_with_extra_dv(
+                            &rp.design, "earth_surface", &dest, plane_change_dv,
                         ));
                     if campaign.payload_kg <= cap * crate::game_state::BID_PAYLOAD_MARGIN {
                         liftable = true;
@@ -400,10 +462,33 @@ impl GameState {
                 self.active_campaigns.push(campaign);
             }
 
+            self.tick_gov_programs(&mut events);
+
+            // Close the books on last quarter before opening the new month.
+            self.close_quarter(&mut events);
+            self.report_quarterly_earnings(&mut events);
+            self.record_valuation();
+
             // Start new month in financials
             self.ensure_current_month_financials();
+
+            self.refresh_pad_lease_offers();
+            self.tick_launch_providers();
         }
 
+        // Pad rent for today's lease, if any.
+        self.collect_pad_leases(&mut events);
+        self.process_license_applications(&mut events);
+        self.tick_pad_failure(&mut events);
+        self.tick_customer_payloads(&mut events);
+        self.tick_pad_conversion(&mut events);
+        self.tick_astronauts(&mut events);
+        self.tick_payroll(&mut events);
+        self.tick_infrastructure(&mut events);
+        self.tick_asset_wear(&mut events);
+        self.tick_constellations(&mut events);
+        self.tick_science(&mut events);
+
         // Resolve campaign block bids whose window closed, then issue
         // due mission contracts (daily; intervals are day-grained, not
         // month-grained). Resolution runs first so a just-won program
@@ -424,11 +509,13 @@ impl GameState {
         // overdue campaign missions — both feed the program clause).
         self.expire_contracts(&mut events);
         self.expire_competitor_campaign_missions(&mut events);
+        self.check_gov_program_deadlines(&mut events);
 
         // Fly competitors' awarded contracts that reached their
         // scheduled launch day (abstract launches — real inventory,
         // real reputation, no flight sim).
         self.process_competitor_launches(&mut events);
+        self.settle_subcontracts(&mut events);
 
         // Track launch drought (yearly check)
         if self.date.is_first_of_month() && self.date.month == 1 && self.date.day == 1 {
@@ -447,7 +534,11 @@ impl GameState {
         }
 
         // Process manufacturing
-        let mfg_events = self.player_company.manufacturing.advance_day(&self.balance.costs);
+        let mfg_events = if teams_work {
+            self.player_company.manufacturing.advance_day(&self.balance.costs, self.date)
+        } else {
+            Vec::new()
+        };
         for me in mfg_events {
             let evt = match me {
                 crate::manufacturing::ManufacturingEvent::EngineBuilt {
@@ -475,6 +566,19 @@ impl GameState {
                 }
                 crate::manufacturing::ManufacturingEvent::FloorSpaceComplete { units } =>
                     GameEvent::FloorSpaceComplete { units },
+                crate::manufacturing::ManufacturingEvent::Recertified { item_name, .. } =>
+                    GameEvent::HardwareRecertified { item_name },
+                crate::manufacturing::ManufacturingEvent::BoosterInspected { item_id, item_name, .. } => {
+                    let failure_chance = self.player_company.manufacturing.inventory.stages.iter()
+                        .find(|s| s.item_id == item_id)
+                        .and_then(|s| s.booster.as_ref())
+                        .map_or(0.0, |b| b.failure_chance(&self.balance.booster_reuse));
+                    GameEvent::BoosterInspected { item_name, failure_chance }
+                }
+                crate::manufacturing::ManufacturingEvent::Retrofitted { rocket_name, revision, .. } =>
+                    GameEvent::RocketRetrofitted { rocket_name, revision },
+                crate::manufacturing::ManufacturingEvent::PadAbortTestReady { rocket_project_id, rocket_name, .. } =>
+                    self.fly_pad_abort_test(rocket_project_id, rocket_name),
             };
             self.event_log.push(self.date, evt.clone());
             events.push(evt);
@@ -483,6 +587,12 @@ impl GameState {
         // Try to unblock manufacturing orders that now have prerequisites
         self.player_company.try_unblock_manufacturing_orders();
 
+        // Age stored hardware against its shelf life
+        for evt in self.player_company.tick_inventory_aging(self.date, &self.balance.storage) {
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+
         // Auto-reorder rockets to maintain inventory targets
         let auto_events = self.player_company.auto_reorder_rockets(&self.balance);
         for evt in auto_events {
@@ -491,7 +601,7 @@ impl GameState {
         }
 
         // Auto-assign idle manufacturing teams to least-staffed orders
-        self.player_company.auto_assign_idle_manufacturing_teams();
+        self.player_company.auto_assign_idle_manufacturing_teams(&self.balance.costs);
 
         // Competitors run the same manufacturing machinery daily.
         self.tick_competitors(&mut events);
@@ -624,6 +734,101 @@ impl GameState {
             self.player_company.notified_manufacturing_idle = false;
         }
 
+        self.tick_challenge(&mut events);
+        self.tick_tutorial(&mut events);
+
+        if self.pause_rules.should_pause(&events, money_before, self.player_company.money) {
+            self.speed = GameSpeed::Paused;
+        }
+
         events
     }
+
+    /// Skip ahead up to `max_days` without waiting on the UI between
+    /// days. Quiet stretches (see `quiet_days`) go up to a week at a
+    /// time with their work, pay accrual and boiloff in closed form;
+    /// every other day runs the full `advance_day`, so a skip plays out
+    /// as the same days would at normal speed. Stops early, paused, on
+    /// the first day that needs the player: a critical event, or
+    /// anything that pauses the game itself (market shifts, program
+    /// announcements, idle factories). Otherwise the speed is left as
+    /// it was.
+    pub fn fast_forward(&mut self, max_days: u32) -> FastForward {
+        let speed = self.speed;
+        // Run "unpaused" so a self-pause shows up as a speed change.
+        self.speed = self.previous_speed;
+        let mut result = FastForward { days: 0, events: Vec::new(), interrupted: false };
+        while result.days < max_days {
+            // The first day runs in full, to settle whatever the player
+            // just changed.
+            let quiet = match result.days {
+                0 => 0,
+                done => self.quiet_days((max_days - done).min(super::quiet_ops::MAX_QUIET_DAYS)),
+            };
+            if quiet > 1 {
+                self.advance_quiet_days(quiet);
+                result.days += quiet;
+                continue;
+            }
+            let day_events = self.advance_day();
+            result.days += 1;
+            let critical = day_events.iter()
+                .any(|e| e.importance() == crate::event::EventImportance::Critical);
+            result.events.extend(day_events);
+            if critical || self.speed == GameSpeed::Paused {
+                self.speed = GameSpeed::Paused;
+                result.interrupted = true;
+                return result;
+            }
+        }
+        self.speed = speed;
+        result
+    }
+
+    /// An engine design using an experimental technology completes
+    /// carrying that technology's deficiencies.
+    pub(super) fn apply_tech_deficiencies(&mut self, pi: usize, events: &mut Vec<GameEvent>) {
+        let project = &mut self.player_company.engine_projects[pi];
+        if let Some(tech_id) = project.technology_id {
+            if let Some(tech) = self.technologies.iter().find(|t| t.id == tech_id) {
+                let deficiency_ids: Vec<crate::technology::TechDeficiencyId> =
+                    tech.deficiencies.iter().map(|d| d.id).collect();
+                // Apply stat penalties from unsolved deficiencies
+                for def in &tech.deficiencies {
+                    match &def.kind {
+                        crate::technology::TechDeficiencyKind::IspPenalty(frac) => {
+                            project.design.isp_s *= 1.0 - frac;
+                        }
+                        crate::technology::TechDeficiencyKind::MassPenalty(frac) => {
+                            project.design.mass_kg *= 1.0 + frac;
+                        }
+                        crate::technology::TechDeficiencyKind::ThrustPenalty(frac) => {
+                            project.design.thrust_n *= 1.0 - frac;
+                        }
+                        crate::technology::TechDeficiencyKind::ComplexityPenalty(n) => {
+                            project.complexity += n;
+                        }
+                        // Engine techs never generate PowerPenalty
+                        // (that's reactor-domain, handled separately).
+                        crate::technology::TechDeficiencyKind::PowerPenalty(_) => {}
+                    }
+                }
+                project.tech_deficiency_ids = deficiency_ids;
+                let engine_name = project.design.name.clone();
+                let tech_name = tech.name.clone();
+                let desc: Vec<String> = tech.deficiencies.iter()
+                    .map(|d| format!("{}: {}", d.description, d.kind))
+                    .collect();
+                if !desc.is_empty() {
+                    let evt = GameEvent::TechDeficienciesFound {
+                        engine_name: engine_name.clone(),
+                        tech_name: tech_name.clone(),
+                        deficiencies: desc.join(", "),
+                    };
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
+                }
+            }
+        }
+    }
 }
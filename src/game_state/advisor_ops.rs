@@ -0,0 +1,94 @@
+//! Running the advisor's rules (see [`crate::advisor`]) over the
+//! player's company.
+
+use crate::advisor::{monthly_burn, Hint, HintTopic, Severity};
+use crate::contract::ContractStatus;
+use crate::engine::G0;
+use crate::location::DELTA_V_MAP;
+use crate::resources::format_money;
+
+use super::*;
+
+impl GameState {
+    /// Today's advice, most severe first.
+    pub fn advisor_hints(&self) -> Vec<Hint> {
+        let company = &self.player_company;
+        let cfg = &self.balance.advisor;
+        let mut hints = Vec::new();
+
+        let idle = company.unassigned_team_count();
+        if idle > 0 {
+            let active = company.active_team_count();
+            let message = if active == 1 {
+                "Your only engineering team is idle".to_string()
+            } else {
+                format!("{} of your {} engineering teams are idle", idle, active)
+            };
+            hints.push(Hint { severity: Severity::Warning, topic: HintTopic::IdleTeams, message });
+        }
+
+        let g = DELTA_V_MAP.surface_properties(&company.launch_site.location)
+            .map_or(G0, |p| p.gravity_m_s2);
+        for rp in &company.rocket_projects {
+            let Some(first) = rp.design.stage_groups.first() else { continue };
+            let thrust: f64 = first.iter().map(|s| s.total_thrust_n()).sum();
+            let twr = thrust / (rp.design.total_mass_kg() * g);
+            if twr >= cfg.min_liftoff_twr {
+                continue;
+            }
+            let (severity, message) = if twr < 1.0 {
+                (Severity::Critical, format!("{} can't lift off: TWR {:.2}", rp.design.name, twr))
+            } else {
+                (Severity::Warning, format!("{}'s liftoff TWR is {:.2}, under {:.1}", rp.design.name, twr, cfg.min_liftoff_twr))
+            };
+            hints.push(Hint { severity, topic: HintTopic::LowTwr(rp.project_id), message });
+        }
+
+        let burn = monthly_burn(company.monthly_financials.iter(), (self.date.year, self.date.month), cfg.burn_months)
+            .unwrap_or_else(|| company.monthly_salary_cost(&self.balance.costs));
+        if burn > 0.0 {
+            let months = company.money.max(0.0) / burn;
+            let severity = if months < cfg.runway_critical_months {
+                Some(Severity::Critical)
+            } else if months < cfg.runway_warning_months {
+                Some(Severity::Warning)
+            } else {
+                None
+            };
+            if let Some(severity) = severity {
+                let left = months.ceil() as u32;
+                hints.push(Hint {
+                    severity,
+                    topic: HintTopic::Runway,
+                    message: format!("You can't afford salaries in {} month{} at the current burn of {}/mo",
+                        left, if left == 1 { "" } else { "s" }, format_money(burn)),
+                });
+            }
+        }
+
+        let rockets_ready = company.manufacturing.inventory.rockets.iter().any(|r| !r.expired);
+        if !rockets_ready {
+            for c in company.active_contracts.iter().filter(|c| matches!(c.status, ContractStatus::Accepted)) {
+                let days = self.date.days_until(&c.deadline);
+                if days <= cfg.deadline_warning_days {
+                    hints.push(Hint {
+                        severity: Severity::Warning,
+                        topic: HintTopic::ContractDeadline(c.id),
+                        message: format!("{} is due in {} days and no rocket is ready", c.name, days),
+                    });
+                }
+            }
+        }
+
+        for r in company.manufacturing.inventory.rockets.iter().filter(|r| r.expired) {
+            hints.push(Hint {
+                severity: Severity::Info,
+                topic: HintTopic::ExpiredRocket,
+                message: format!("{} is past its shelf life", r.rocket_name),
+            });
+        }
+
+        hints.sort_by_key(|h| std::cmp::Reverse(h.severity));
+        hints
+    }
+}
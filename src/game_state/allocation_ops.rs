@@ -0,0 +1,114 @@
+//! Suggesting and applying a spread of engineering teams over the
+//! design projects (see [`crate::allocation`]).
+
+use crate::allocation::{spread, Allocation, AllocationSlot, ProjectRef, WorkPhase};
+use crate::depot_project::DepotDesignStatus;
+use crate::engine_project::EngineDesignStatus;
+use crate::reactor_project::ReactorDesignStatus;
+use crate::rocket_project::RocketDesignStatus;
+use crate::software_project::SoftwareDesignStatus;
+
+use super::*;
+
+impl GameState {
+    /// The best spread of the company's engineering teams over its
+    /// design projects, next to how they're spread now. Teams preparing
+    /// flights are left where they are.
+    pub fn suggest_team_allocation(&self) -> Allocation {
+        let company = &self.player_company;
+        let cfg = &self.balance.allocation;
+        let weight = |phase| match phase {
+            WorkPhase::Design => cfg.design_weight,
+            WorkPhase::Revision => cfg.revision_weight,
+            WorkPhase::Testing => cfg.testing_weight,
+        };
+        let mut slots = Vec::new();
+        let mut slot = |project, name: &str, phase: Option<WorkPhase>, current| {
+            let Some(phase) = phase else { return };
+            slots.push(AllocationSlot { project, name: name.to_string(), phase, weight: weight(phase), current, suggested: 0 });
+        };
+        for ep in &company.engine_projects {
+            let phase = match ep.status {
+                EngineDesignStatus::Proposed { .. } => None,
+                EngineDesignStatus::InDesign { .. } => Some(WorkPhase::Design),
+                EngineDesignStatus::Testing { .. } => Some(WorkPhase::Testing),
+                EngineDesignStatus::Revising { .. } => Some(WorkPhase::Revision),
+            };
+            slot(ProjectRef::Engine(ep.project_id), &ep.design.name, phase, ep.teams_assigned);
+        }
+        for rp in &company.rocket_projects {
+            let phase = match rp.status {
+                RocketDesignStatus::InDesign { .. } => WorkPhase::Design,
+                RocketDesignStatus::Testing { .. } => WorkPhase::Testing,
+                RocketDesignStatus::Revising { .. } => WorkPhase::Revision,
+            };
+            slot(ProjectRef::Rocket(rp.project_id), &rp.design.name, Some(phase), rp.teams_assigned);
+        }
+        for rp in &company.reactor_projects {
+            let phase = match rp.status {
+                ReactorDesignStatus::Proposed { .. } => None,
+                ReactorDesignStatus::InDesign { .. } => Some(WorkPhase::Design),
+                ReactorDesignStatus::Testing { .. } => Some(WorkPhase::Testing),
+                ReactorDesignStatus::Revising { .. } => Some(WorkPhase::Revision),
+            };
+            slot(ProjectRef::Reactor(rp.project_id), &rp.design.name, phase, rp.teams_assigned);
+        }
+        for sp in &company.software_projects {
+            let phase = match sp.status {
+                SoftwareDesignStatus::InDesign { .. } => WorkPhase::Design,
+                SoftwareDesignStatus::Testing { .. } => WorkPhase::Testing,
+                SoftwareDesignStatus::Revising { .. } => WorkPhase::Revision,
+            };
+            slot(ProjectRef::Software(sp.project_id), &sp.name, Some(phase), sp.teams_assigned);
+        }
+        for dp in &company.depot_projects {
+            let phase = match dp.status {
+                DepotDesignStatus::Proposed => None,
+                DepotDesignStatus::InDesign { .. } => Some(WorkPhase::Design),
+                DepotDesignStatus::Testing { .. } => Some(WorkPhase::Testing),
+                DepotDesignStatus::Revising { .. } => Some(WorkPhase::Revision),
+            };
+            slot(ProjectRef::Depot(dp.project_id), &dp.design.name, phase, dp.teams_assigned);
+        }
+
+        let preparing: u32 = company.flight_preparations.iter().map(|p| p.teams_assigned).sum();
+        let teams = company.active_team_count().saturating_sub(preparing);
+        let weights: Vec<f64> = slots.iter().map(|s| s.weight).collect();
+        let current: Vec<u32> = slots.iter().map(|s| s.current).collect();
+        for (s, n) in slots.iter_mut().zip(spread(&weights, &current, teams)) {
+            s.suggested = n;
+        }
+        let active: Vec<_> = company.teams.iter().filter(|t| !t.furloughed).collect();
+        let efficiency = if active.is_empty() {
+            1.0
+        } else {
+            active.iter().map(|t| t.efficiency(&self.balance.morale)).sum::<f64>() / active.len() as f64
+        };
+        Allocation { slots, teams, efficiency }
+    }
+
+    /// Reassign the teams as `suggest_team_allocation` suggests and
+    /// return the suggestion applied.
+    pub fn apply_team_allocation(&mut self) -> Allocation {
+        let allocation = self.suggest_team_allocation();
+        let company = &mut self.player_company;
+        for s in &allocation.slots {
+            let teams = match s.project {
+                ProjectRef::Engine(id) => company.engine_projects.iter_mut()
+                    .find(|p| p.project_id == id).map(|p| &mut p.teams_assigned),
+                ProjectRef::Rocket(id) => company.rocket_projects.iter_mut()
+                    .find(|p| p.project_id == id).map(|p| &mut p.teams_assigned),
+                ProjectRef::Reactor(id) => company.reactor_projects.iter_mut()
+                    .find(|p| p.project_id == id).map(|p| &mut p.teams_assigned),
+                ProjectRef::Software(id) => company.software_projects.iter_mut()
+                    .find(|p| p.project_id == id).map(|p| &mut p.teams_assigned),
+                ProjectRef::Depot(id) => company.depot_projects.iter_mut()
+                    .find(|p| p.project_id == id).map(|p| &mut p.teams_assigned),
+            };
+            if let Some(teams) = teams {
+                *teams = s.suggested;
+            }
+        }
+        allocation
+    }
+}
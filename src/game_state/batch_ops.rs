@@ -0,0 +1,93 @@
+//! Running batches of player actions (see [`crate::batch`]).
+
+use crate::batch::{Action, ActionError, BatchError};
+use crate::event::GameEvent;
+
+use super::*;
+
+impl GameState {
+    /// Run `actions` in order, all or nothing. On success every event
+    /// they raised is logged and returned; if one is refused the
+    /// company is put back as it was before the first and nothing is
+    /// logged.
+    pub fn run_batch(&mut self, actions: &[Action]) -> Result<Vec<GameEvent>, BatchError> {
+        // Every action only touches the company, so it is all a
+        // rollback has to restore.
+        let before = self.player_company.clone();
+        let mut events = Vec::new();
+        for (step, action) in actions.iter().enumerate() {
+            if let Err(error) = self.run_action(action, &mut events) {
+                self.player_company = before;
+                return Err(BatchError { step, action: action.clone(), error });
+            }
+        }
+        for evt in &events {
+            self.event_log.push(self.date, evt.clone());
+        }
+        Ok(events)
+    }
+
+    fn run_action(&mut self, action: &Action, events: &mut Vec<GameEvent>) -> Result<(), ActionError> {
+        let balance = &self.balance;
+        let company = &mut self.player_company;
+        let engine_index = |company: &Company, id| company.engine_projects.iter()
+            .position(|ep| ep.project_id == id)
+            .ok_or(ActionError::NoSuchProject);
+        let rocket_index = |company: &Company, id| company.rocket_projects.iter()
+            .position(|rp| rp.project_id == id)
+            .ok_or(ActionError::NoSuchProject);
+        match *action {
+            Action::ReviseEngine { engine } => {
+                let i = engine_index(company, engine)?;
+                company.start_engine_revision(i).ok_or(ActionError::NothingToRevise)?;
+            }
+            Action::ReviseRocket { rocket } => {
+                let i = rocket_index(company, rocket)?;
+                company.start_rocket_revision(i).ok_or(ActionError::NothingToRevise)?;
+            }
+            Action::OrderEngines { engine, count } => {
+                let i = engine_index(company, engine)?;
+                for _ in 0..count {
+                    let (_, evt) = company.order_engine_build(i, balance).ok_or(ActionError::OrderRefused)?;
+                    events.push(evt);
+                }
+            }
+            Action::OrderRocket { rocket } => {
+                let i = rocket_index(company, rocket)?;
+                let (_, evt) = company.order_rocket_build(i, balance).ok_or(ActionError::OrderRefused)?;
+                events.push(evt);
+            }
+            Action::SetAutoBuild { rocket, target } => {
+                rocket_index(company, rocket)?;
+                if !company.set_auto_build_target(rocket, target) {
+                    return Err(ActionError::OrderRefused);
+                }
+            }
+            Action::HireTeam => {
+                let name = format!("Team {}", company.team_count() + 1);
+                events.push(company.hire_team(name, balance).ok_or(ActionError::OverBudget)?);
+            }
+            Action::HireManufacturingTeam => {
+                let name = format!("Mfg Team {}", company.manufacturing_teams.len() + 1);
+                events.push(company.hire_manufacturing_team(name, balance).ok_or(ActionError::OverBudget)?);
+            }
+            Action::AssignEngineTeams { engine, teams } => {
+                let i = engine_index(company, engine)?;
+                for _ in 0..teams {
+                    if !company.add_team_to_project(i) {
+                        return Err(ActionError::NoIdleTeam);
+                    }
+                }
+            }
+            Action::AssignRocketTeams { rocket, teams } => {
+                let i = rocket_index(company, rocket)?;
+                for _ in 0..teams {
+                    if !company.add_team_to_rocket_project(i) {
+                        return Err(ActionError::NoIdleTeam);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
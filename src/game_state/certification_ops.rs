@@ -0,0 +1,55 @@
+//! Design certification for crewed and national-security customers:
+//! opening reviews, tracking each revision's flight record, and gating
+//! contracts in markets that demand a certified design.
+
+use crate::budget::Department;
+use crate::event::GameEvent;
+use crate::rocket_project::{CertificationError, RocketProjectId};
+
+use super::*;
+
+impl GameState {
+    /// Freeze a rocket project's current revision and open its
+    /// certification review, charging the documentation cost. The
+    /// project's teams work the review in place of testing.
+    pub fn start_certification(&mut self, project_index: usize) -> Result<GameEvent, CertificationError> {
+        let cfg = self.balance.certification.clone();
+        let project = self.player_company.rocket_projects.get_mut(project_index)
+            .ok_or(CertificationError::ProjectMissing)?;
+        project.start_certification(&cfg)?;
+        let rocket_name = project.design.name.clone();
+        self.player_company.money -= cfg.documentation_cost;
+        self.record_expense(cfg.documentation_cost);
+        self.player_company.budgets.record(Department::Testing, cfg.documentation_cost);
+        let evt = GameEvent::CertificationStarted { rocket_name, cost: cfg.documentation_cost };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Count a flight toward (or against) its design revision's
+    /// certification record.
+    pub(super) fn record_certification_flight(
+        &mut self, project_id: RocketProjectId, revision: u32, success: bool,
+    ) -> Option<GameEvent> {
+        let project = self.player_company.rocket_projects.iter_mut()
+            .find(|rp| rp.project_id == project_id)?;
+        if !project.record_flight(revision, success) {
+            return None;
+        }
+        let evt = GameEvent::CertificationRevoked { rocket_name: project.design.name.clone() };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// True if the player holds at least one certified design.
+    pub fn has_certified_design(&self) -> bool {
+        self.player_company.rocket_projects.iter().any(|rp| rp.is_certified())
+    }
+
+    /// True if the market only awards to certified designs and the
+    /// player has none.
+    pub fn certification_blocks_market(&self, market_id: contract::MarketId) -> bool {
+        self.markets.iter().any(|m| m.id == market_id && m.requires_certification)
+            && !self.has_certified_design()
+    }
+}
@@ -0,0 +1,58 @@
+//! Playing a challenge (see [`crate::challenge`]): starting a run and
+//! checking its objectives at the end of each day.
+
+use crate::challenge::{Challenge, ChallengeOutcome, ChallengeRun, ChallengeUpdate, Progress};
+use crate::event::GameEvent;
+use crate::launch::LaunchOutcome;
+
+use super::*;
+
+impl GameState {
+    /// Play `challenge` from today. Replaces any run already going.
+    pub fn start_challenge(&mut self, challenge: Challenge) {
+        self.challenge = Some(ChallengeRun::new(challenge, self.date));
+    }
+
+    /// Where the player stands on every kind of challenge goal.
+    pub fn challenge_progress(&self) -> Progress {
+        let company = &self.player_company;
+        let mut progress = Progress {
+            boosters_landed: company.manufacturing.last_booster_serial as u32,
+            money: company.money,
+            constellations: self.constellations.len() as u32,
+            ..Default::default()
+        };
+        for r in company.launch_history.iter().filter(|r| matches!(r.outcome, LaunchOutcome::Success)) {
+            progress.successful_launches += 1;
+            if r.contract_id.is_some() {
+                progress.contracts_delivered += 1;
+            }
+            if !progress.reached.contains(&r.destination) {
+                progress.reached.push(r.destination.clone());
+            }
+        }
+        progress
+    }
+
+    /// End of day: count the day against the challenge and check its
+    /// objectives.
+    pub(super) fn tick_challenge(&mut self, events: &mut Vec<GameEvent>) {
+        if self.challenge.as_ref().is_none_or(|r| r.outcome.is_some()) {
+            return;
+        }
+        let progress = self.challenge_progress();
+        let Some(run) = self.challenge.as_mut() else { return };
+        let name = run.challenge.name.clone();
+        for update in run.advance(&progress, &self.balance.challenge) {
+            let evt = match update {
+                ChallengeUpdate::ObjectiveMet { goal, day, .. } => GameEvent::ChallengeObjectiveMet { goal, day },
+                ChallengeUpdate::Ended(ChallengeOutcome::Won { day, score }) =>
+                    GameEvent::ChallengeWon { name: name.clone(), day, score },
+                ChallengeUpdate::Ended(ChallengeOutcome::Failed { reason, .. }) =>
+                    GameEvent::ChallengeFailed { name: name.clone(), reason },
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Constellations: forming them, their daily revenue, replacement
+//! planning, and auto-replace launches.
+
+use crate::constellation::{self, Constellation, ConstellationError, ConstellationId, ReplacementTask};
+use crate::contract::destination_display_name;
+use crate::event::GameEvent;
+use crate::flight::{CompanyRef, Payload};
+
+use super::*;
+
+impl GameState {
+    /// Group the spacecraft of design `rocket_project_id` at `location`
+    /// into a constellation of `required` slots.
+    pub fn form_constellation(
+        &mut self,
+        name: String,
+        location: &str,
+        rocket_project_id: RocketProjectId,
+        required: u32,
+    ) -> Result<GameEvent, ConstellationError> {
+        if !self.player_company.rocket_projects.iter().any(|rp| rp.project_id == rocket_project_id) {
+            return Err(ConstellationError::NoSuchDesign);
+        }
+        if crate::location::DELTA_V_MAP.location(location).is_none() {
+            return Err(ConstellationError::UnknownLocation(location.to_string()));
+        }
+        if required == 0 {
+            return Err(ConstellationError::NoSlots);
+        }
+        if let Some(c) = self.constellations.iter()
+            .find(|c| c.location == location && c.rocket_project_id == rocket_project_id)
+        {
+            return Err(ConstellationError::AlreadyFormed(c.name.clone()));
+        }
+        let id = ConstellationId(self.next_constellation_id);
+        self.next_constellation_id += 1;
+        let evt = GameEvent::ConstellationFormed {
+            name: name.clone(),
+            location: destination_display_name(location).to_string(),
+            required,
+        };
+        self.constellations.push(Constellation {
+            id,
+            name,
+            location: location.to_string(),
+            rocket_project_id,
+            required,
+            auto_replace: false,
+            revenue_earned: 0.0,
+            open_tasks: 0,
+        });
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Turn auto-replace on or off. False if there's no such
+    /// constellation.
+    pub fn set_constellation_auto_replace(&mut self, id: ConstellationId, on: bool) -> bool {
+        match self.constellations.iter_mut().find(|c| c.id == id) {
+            Some(c) => {
+                c.auto_replace = on;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The constellation's members: its design's spacecraft parked at
+    /// its location.
+    pub fn constellation_members<'a>(&'a self, c: &'a Constellation) -> impl Iterator<Item = &'a Spacecraft> + 'a {
+        self.spacecraft.iter()
+            .filter(move |sc| sc.location == c.location && sc.rocket_project_id == c.rocket_project_id)
+    }
+
+    /// Working members: worn-out satellites hold a slot but don't earn.
+    pub fn constellation_operational(&self, c: &Constellation) -> usize {
+        self.constellation_members(c).filter(|sc| sc.health > 0.0).count()
+    }
+
+    /// Satellites of the constellation's design on their way to it,
+    /// flying themselves or carried as payload.
+    fn constellation_inbound(&self, c: &Constellation) -> usize {
+        self.active_flights.iter()
+            .filter(|f| f.company == CompanyRef::Player && f.destination() == c.location)
+            .map(|f| {
+                let itself = usize::from(f.persist && f.rocket_project_id == c.rocket_project_id);
+                let carried = f.payloads.iter()
+                    .filter(|p| matches!(p, Payload::Spacecraft { deploy_at: Some(d), rocket_project_id, .. }
+                        if *d == c.location && *rocket_project_id == c.rocket_project_id))
+                    .count();
+                itself + carried
+            })
+            .sum()
+    }
+
+    /// Open replacement tasks for `c`, earliest first.
+    fn constellation_tasks(&self, c: &Constellation) -> Vec<ReplacementTask> {
+        let healths: Vec<f64> = self.constellation_members(c).map(|sc| sc.health).collect();
+        constellation::replacement_dates(
+            self.date,
+            &healths,
+            self.constellation_inbound(c),
+            c.required,
+            self.balance.servicing.spacecraft_wear_per_day,
+            self.balance.constellation.planning_horizon_days,
+        )
+        .into_iter()
+        .map(|needed_by| ReplacementTask {
+            constellation_id: c.id,
+            constellation_name: c.name.clone(),
+            needed_by,
+        })
+        .collect()
+    }
+
+    /// Every constellation's open replacement tasks, earliest first.
+    pub fn replacement_tasks(&self) -> Vec<ReplacementTask> {
+        let mut tasks: Vec<ReplacementTask> = self.constellations.iter()
+            .flat_map(|c| self.constellation_tasks(c))
+            .collect();
+        tasks.sort_by_key(|t| t.needed_by);
+        tasks
+    }
+
+    /// A day of constellation revenue and replacement planning.
+    /// Reports each new replacement task, and with auto-replace on
+    /// launches a satellite from inventory for the open ones.
+    pub(super) fn tick_constellations(&mut self, events: &mut Vec<GameEvent>) {
+        for i in 0..self.constellations.len() {
+            let c = &self.constellations[i];
+            let revenue = c.daily_revenue(self.constellation_operational(c), &self.balance.constellation);
+            let mut tasks = self.constellation_tasks(c);
+            if revenue > 0.0 {
+                self.player_company.money += revenue;
+                self.record_income(revenue);
+                self.constellations[i].revenue_earned += revenue;
+            }
+
+            if self.constellations[i].auto_replace && !tasks.is_empty() {
+                if let Some(launch_events) = self.launch_replacement(i) {
+                    events.extend(launch_events);
+                    tasks = self.constellation_tasks(&self.constellations[i]);
+                }
+            }
+
+            let c = &mut self.constellations[i];
+            if tasks.len() > c.open_tasks {
+                let evt = GameEvent::ReplacementNeeded {
+                    constellation: c.name.clone(),
+                    needed_by: tasks[0].needed_by,
+                };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            c.open_tasks = tasks.len();
+        }
+    }
+
+    /// Launch one satellite of constellation `index`'s design from
+    /// inventory to its location, if one is built and the pad is free.
+    fn launch_replacement(&mut self, index: usize) -> Option<Vec<GameEvent>> {
+        let c = &self.constellations[index];
+        let (project_id, location, name) = (c.rocket_project_id, c.location.clone(), c.name.clone());
+        let item = self.player_company.manufacturing.inventory.rockets.iter()
+            .find(|r| r.rocket_project_id == project_id)?;
+        let (item_id, rocket_name) = (item.item_id, item.rocket_name.clone());
+        let pad = &self.player_company.launch_site;
+        if pad.lease_on(self.date).is_some()
+            || self.player_company.compliance.check(&location, self.date, pad, &self.balance.regulation).is_err()
+        {
+            return None;
+        }
+        let (mut launch_events, _) = self.launch_rocket(item_id, &location, Vec::new(), true)?;
+        let evt = GameEvent::ReplacementLaunched { constellation: name, rocket_name };
+        self.event_log.push(self.date, evt.clone());
+        launch_events.push(evt);
+        Some(launch_events)
+    }
+}
@@ -0,0 +1,215 @@
+//! Launch-day operations: the commit checklist, running a countdown on
+//! a planned manifest, and flying it at T-0.
+
+use rand::Rng;
+
+use crate::commit_criteria::{LaunchChecklist, LaunchReadings};
+use crate::countdown::{Countdown, CountdownOutcome, CountdownSignal};
+use crate::event::GameEvent;
+use crate::manufacturing::InventoryItemId;
+
+use super::*;
+
+impl GameState {
+    /// Today's chance a weather check comes up no-go: anywhere from
+    /// clear to twice the usual.
+    pub fn weather_forecast(&self) -> f64 {
+        let mut rng = self.seed.world_query(&format!("weather:{}", self.date));
+        (self.balance.countdown.weather_no_go_chance * rng.gen_range(0.0..2.0)).min(1.0)
+    }
+
+    /// Mass of a manifest's contract deliveries and spacecraft.
+    pub fn manifest_mass_kg(&self, contract_indices: &[usize], spacecraft_item_ids: &[InventoryItemId]) -> f64 {
+        let company = &self.player_company;
+        let contracts: f64 = contract_indices.iter()
+            .filter_map(|&i| company.active_contracts.get(i))
+            .map(|c| c.payload_kg)
+            .sum();
+        let spacecraft: f64 = spacecraft_item_ids.iter()
+            .filter_map(|id| company.manufacturing.inventory.rockets.iter().find(|r| r.item_id == *id))
+            .filter_map(|r| company.rocket_projects.iter().find(|rp| rp.project_id == r.rocket_project_id))
+            .map(|rp| rp.design.total_mass_kg())
+            .sum();
+        contracts + spacecraft
+    }
+
+    /// Run the inventory rocket's design checklist for a flight of
+    /// `payload_kg` to `destination`. None if the rocket is gone.
+    pub fn launch_checklist(
+        &self,
+        rocket_item_id: InventoryItemId,
+        destination: &str,
+        payload_kg: f64,
+    ) -> Option<LaunchChecklist> {
+        let company = &self.player_company;
+        let inv = company.manufacturing.inventory.rockets.iter().find(|r| r.item_id == rocket_item_id)?;
+        let rp = company.rocket_projects.iter().find(|rp| rp.project_id == inv.rocket_project_id)?;
+        let criteria = &rp.commit_criteria;
+        let design = inv.design.as_ref().unwrap_or(&rp.design);
+        let mut readings = LaunchReadings::default();
+        if criteria.min_engine_testing_cycles > 0 {
+            readings.engine_testing_cycles = design.stage_groups.iter().flatten()
+                .filter_map(|stage| company.engine_projects.iter().find(|ep| ep.design.id == stage.engine.id))
+                .map(|ep| ep.testing_cycles(&self.balance))
+                .min();
+        }
+        if criteria.max_hidden_flaws.is_some() {
+            readings.hidden_flaws = company.estimated_hidden_flaws(design, Some(rp), &self.balance);
+        }
+        if criteria.max_weather_no_go.is_some() {
+            readings.weather_no_go = self.weather_forecast();
+        }
+        if criteria.min_flight_reserve.is_some() {
+            // The reserve that counts is what's actually loaded.
+            let share = self.propellant_load_share(design, inv.propellant_load, destination, payload_kg);
+            let design = &design.with_propellant_load(share);
+            let total = design.total_delta_v(payload_kg);
+            readings.flight_reserve = crate::location::DELTA_V_MAP
+                .shortest_path_for_rocket("earth_surface", destination, design, payload_kg)
+                .filter(|_| total > 0.0)
+                .map(|(_, required)| 1.0 - (required + self.plane_change_dv(destination)) / total);
+        }
+        Some(LaunchChecklist::evaluate(criteria, &readings))
+    }
+
+    /// Validate a manifest and run its carrier's checklist. An enforced
+    /// checklist with a no-go refuses the launch; an advisory one is
+    /// handed back for the caller to warn with.
+    pub fn check_commit_criteria(
+        &self,
+        rocket_item_id: InventoryItemId,
+        contract_indices: &[usize],
+        spacecraft_item_ids: &[InventoryItemId],
+    ) -> Result<LaunchChecklist, ManifestError> {
+        let destination = self.plan_manifest(contract_indices, spacecraft_item_ids)?;
+        self.check_pad_fits(rocket_item_id)?;
+        let payload_kg = self.manifest_mass_kg(contract_indices, spacecraft_item_ids);
+        let checklist = self.launch_checklist(rocket_item_id, &destination, payload_kg)
+            .ok_or(ManifestError::RocketMissing)?;
+        if checklist.blocks_launch() {
+            return Err(ManifestError::CommitCriteriaNotMet(checklist.failures()));
+        }
+        Ok(checklist)
+    }
+
+    /// Start a countdown for the inventory rocket `rocket_item_id` with
+    /// this manifest. The manifest is checked now but nothing is taken
+    /// until liftoff, so a scrub costs nothing.
+    pub fn begin_countdown(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        contract_indices: &[usize],
+        spacecraft_item_ids: &[InventoryItemId],
+        persist: bool,
+    ) -> Result<(), ManifestError> {
+        if self.countdown.is_some() {
+            return Err(ManifestError::CountdownUnderway);
+        }
+        let rocket_name = self.player_company.manufacturing.inventory.rockets.iter()
+            .find(|r| r.item_id == rocket_item_id)
+            .ok_or(ManifestError::RocketMissing)?
+            .rocket_name.clone();
+        self.check_commit_criteria(rocket_item_id, contract_indices, spacecraft_item_ids)?;
+        let destination = self.plan_manifest(contract_indices, spacecraft_item_ids)?;
+        let contract_ids = contract_indices.iter()
+            .map(|&i| self.player_company.active_contracts[i].id)
+            .collect();
+        let mut countdown = Countdown::new(
+            rocket_item_id,
+            rocket_name,
+            destination,
+            contract_ids,
+            spacecraft_item_ids.to_vec(),
+            persist,
+            &self.balance.countdown,
+        );
+        countdown.weather_no_go_chance = self.weather_forecast();
+        self.countdown = Some(countdown);
+        Ok(())
+    }
+
+    /// Run the countdown for up to `minutes`, stopping early at liftoff
+    /// or a scrub. Returns the signals raised and how it stands.
+    pub fn step_countdown(&mut self, minutes: u32) -> (Vec<CountdownSignal>, CountdownOutcome) {
+        let mut signals = Vec::new();
+        let Some(cd) = self.countdown.as_mut() else {
+            return (signals, CountdownOutcome::Counting);
+        };
+        for _ in 0..minutes {
+            let mut rng = self.seed.world_query(&format!(
+                "countdown:{}:{}:{}", cd.rocket_item_id.0, self.date, cd.elapsed_min,
+            ));
+            signals.extend(cd.step(&mut rng, &self.balance.countdown));
+            if cd.is_finished() {
+                break;
+            }
+        }
+        let outcome = match signals.last() {
+            Some(CountdownSignal::Liftoff) => self.lift_off(),
+            Some(CountdownSignal::Scrubbed { reason }) => CountdownOutcome::Scrubbed(self.end_countdown(reason.clone())),
+            _ => CountdownOutcome::Counting,
+        };
+        (signals, outcome)
+    }
+
+    /// Fly the counted-down manifest. A manifest that no longer holds
+    /// together (a contract gone, a payload taken) scrubs instead.
+    fn lift_off(&mut self) -> CountdownOutcome {
+        let cd = self.countdown.clone().expect("counting down");
+        let contract_indices: Option<Vec<usize>> = cd.contract_ids.iter()
+            .map(|id| self.player_company.active_contracts.iter().position(|c| c.id == *id))
+            .collect();
+        let Some(contract_indices) = contract_indices else {
+            return CountdownOutcome::Scrubbed(self.end_countdown("a contract on the manifest is gone".into()));
+        };
+        if !self.player_company.manufacturing.inventory.rockets.iter().any(|r| r.item_id == cd.rocket_item_id) {
+            return CountdownOutcome::Scrubbed(self.end_countdown("launch vehicle missing".into()));
+        }
+        let payloads = match self.build_launch_payloads(&contract_indices, &cd.spacecraft_item_ids) {
+            Ok((_, payloads)) => payloads,
+            Err(_) => return CountdownOutcome::Scrubbed(self.end_countdown("manifest no longer valid".into())),
+        };
+        self.countdown = None;
+        match self.launch_rocket(cd.rocket_item_id, &cd.destination, payloads, cd.persist) {
+            Some((events, record)) => CountdownOutcome::Launched(events, record),
+            None => CountdownOutcome::Scrubbed(self.end_countdown("launch vehicle missing".into())),
+        }
+    }
+
+    /// Clear the countdown and log the scrub.
+    fn end_countdown(&mut self, reason: String) -> GameEvent {
+        let rocket_name = self.countdown.take().map(|cd| cd.rocket_name).unwrap_or_default();
+        let evt = GameEvent::LaunchScrubbed { rocket_name, reason };
+        self.event_log.push(self.date, evt.clone());
+        evt
+    }
+
+    /// Director's hold. None if there's no running count to stop.
+    pub fn hold_countdown(&mut self) -> Option<CountdownSignal> {
+        self.countdown.as_mut()?.hold()
+    }
+
+    /// Resume from a hold outside the recycle point.
+    pub fn resume_countdown(&mut self) -> Option<CountdownSignal> {
+        self.countdown.as_mut()?.resume(&self.balance.countdown)
+    }
+
+    /// Recycle a hold inside the recycle point back to it.
+    pub fn recycle_countdown(&mut self) -> Option<CountdownSignal> {
+        self.countdown.as_mut()?.recycle(&self.balance.countdown)
+    }
+
+    /// Call off today's attempt. None if no countdown is running.
+    pub fn scrub_countdown(&mut self, reason: &str) -> Option<GameEvent> {
+        self.countdown.as_ref()?;
+        Some(self.end_countdown(reason.to_string()))
+    }
+
+    /// A countdown still running when the day ends has used up its
+    /// window.
+    pub(super) fn close_launch_window(&mut self, events: &mut Vec<GameEvent>) {
+        if self.countdown.is_some() {
+            events.push(self.end_countdown("launch window closed".into()));
+        }
+    }
+}
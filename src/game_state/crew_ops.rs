@@ -0,0 +1,144 @@
+//! Crewed flight: assigning astronauts to rockets, bringing them home,
+//! launch aborts and pad-abort tests, and what losing a crew costs the
+//! company.
+
+use rand::Rng;
+
+use crate::astronaut::{AstronautId, CareerFlight, CrewError};
+use crate::event::GameEvent;
+use crate::manufacturing::InventoryItemId;
+use crate::rocket_project::RocketProjectId;
+
+use super::*;
+
+impl GameState {
+    /// Assign a crew to a rocket in inventory. They board when it
+    /// launches.
+    pub fn assign_crew(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        crew: Vec<AstronautId>,
+    ) -> Result<GameEvent, CrewError> {
+        let rocket_name = self.player_company.manufacturing.inventory.rockets.iter()
+            .find(|r| r.item_id == rocket_item_id)
+            .map(|r| r.rocket_name.clone())
+            .unwrap_or_default();
+        let corps = &mut self.player_company.astronauts;
+        corps.assign(rocket_item_id, crew.clone(), &self.balance.astronauts)?;
+        let evt = GameEvent::CrewAssigned { rocket_name, crew: self.crew_names(&crew) };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Daily: training and rest. Logs qualifications.
+    pub(super) fn tick_astronauts(&mut self, events: &mut Vec<GameEvent>) {
+        for name in self.player_company.astronauts.tick_day(&self.balance.astronauts) {
+            let evt = GameEvent::AstronautQualified { name };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// A crewed flight came home. None for an uncrewed one.
+    pub(super) fn land_crew(
+        &mut self,
+        crew: &[AstronautId],
+        rocket_name: &str,
+        destination: &str,
+        launch_date: GameDate,
+    ) -> Option<GameEvent> {
+        if crew.is_empty() {
+            return None;
+        }
+        let flight = CareerFlight {
+            launch_date,
+            rocket_name: rocket_name.to_string(),
+            destination: destination.to_string(),
+            survived: true,
+        };
+        self.player_company.astronauts.land(crew, flight, &self.balance.astronauts);
+        Some(GameEvent::CrewReturned { rocket_name: rocket_name.to_string(), crew: self.crew_names(crew) })
+    }
+
+    /// A crewed flight was lost with everyone aboard: a launch failure's
+    /// reputation hit several times over, and a company-wide stand-down.
+    /// None for an uncrewed flight.
+    pub(super) fn lose_crew(
+        &mut self,
+        crew: &[AstronautId],
+        rocket_name: &str,
+        destination: &str,
+        launch_date: GameDate,
+    ) -> Option<GameEvent> {
+        if crew.is_empty() {
+            return None;
+        }
+        let cfg = &self.balance.astronauts;
+        let flight = CareerFlight {
+            launch_date,
+            rocket_name: rocket_name.to_string(),
+            destination: destination.to_string(),
+            survived: false,
+        };
+        let names = self.player_company.astronauts.lose(crew, flight, self.date, cfg);
+        self.player_company.reputation.on_launch_failure(&self.balance.reputation, cfg.crew_loss_severity);
+        self.player_company.reputation.on_crew_loss(&self.balance.reputation);
+        Some(GameEvent::CrewLost {
+            rocket_name: rocket_name.to_string(),
+            crew: names,
+            stand_down_until: self.player_company.astronauts.stand_down_until.unwrap_or(self.date),
+        })
+    }
+
+    /// The booster failed under a crew. With an abort system, roll
+    /// `abort_chance` to pull them clear; otherwise, or if the abort
+    /// fails, they're lost. None for an uncrewed flight.
+    pub(super) fn crew_on_launch_failure(
+        &mut self,
+        crew: &[AstronautId],
+        rocket_name: &str,
+        destination: &str,
+        abort_chance: Option<f64>,
+    ) -> Option<GameEvent> {
+        if crew.is_empty() {
+            return None;
+        }
+        let query = format!("launch_abort_{}_{}", rocket_name, self.date);
+        let aborted = abort_chance.is_some_and(|p| self.seed.world_query(&query).gen::<f64>() < p);
+        if !aborted {
+            return self.lose_crew(crew, rocket_name, destination, self.date);
+        }
+        let flight = CareerFlight {
+            launch_date: self.date,
+            rocket_name: rocket_name.to_string(),
+            destination: destination.to_string(),
+            survived: true,
+        };
+        self.player_company.astronauts.land(crew, flight, &self.balance.astronauts);
+        Some(GameEvent::CrewAborted { rocket_name: rocket_name.to_string(), crew: self.crew_names(crew) })
+    }
+
+    /// A pad-abort test is stacked: fire it. The test passes with the
+    /// design's current abort chance. Returns the (unlogged) result.
+    pub(super) fn fly_pad_abort_test(&mut self, project_id: RocketProjectId, rocket_name: String) -> GameEvent {
+        let query = format!("pad_abort_{}_{}", project_id.0, self.date);
+        let roll = self.seed.world_query(&query).gen::<f64>();
+        let cfg = &self.balance.launch_abort;
+        let passed = match self.player_company.rocket_projects.iter_mut().find(|rp| rp.project_id == project_id) {
+            Some(rp) => {
+                let passed = roll < rp.abort_success_chance(cfg);
+                rp.record_pad_abort(passed);
+                passed
+            }
+            None => false,
+        };
+        GameEvent::PadAbortTested { rocket_name, passed }
+    }
+
+    fn crew_names(&self, crew: &[AstronautId]) -> Vec<String> {
+        crew.iter()
+            .filter_map(|id| self.player_company.astronauts.get(*id))
+            .map(|a| a.name.clone())
+            .collect()
+    }
+}
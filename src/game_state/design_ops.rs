@@ -0,0 +1,125 @@
+//! Design files: exporting the player's rocket designs and importing
+//! them — from this game or another — as new projects.
+
+use crate::design_file::{DesignFile, DesignImportError, EngineOrigin};
+use crate::engine::{EngineDesign, EngineId};
+use crate::engine_project::{EngineProject, EngineProjectId};
+use crate::event::GameEvent;
+use crate::power::PowerSourceKind;
+use crate::rocket::RocketDesignId;
+use crate::rocket_project::RocketProject;
+use crate::technology::{self, TechnologyId};
+
+use super::*;
+
+impl GameState {
+    /// The rocket design at `index` as a shareable JSON design file.
+    pub fn export_design(&self, index: usize) -> Option<String> {
+        self.player_company.export_design(index).map(|file| file.to_json())
+    }
+
+    /// Import a design file as a new rocket project. In-house engines
+    /// are designed again from this game's baselines as new engine
+    /// projects; contracted ones are matched by name to engines already
+    /// under contract or on offer in the catalog. Nothing changes unless
+    /// every engine is available and the rebuilt design validates.
+    /// Returns the new project's index.
+    pub fn import_design(&mut self, json: &str) -> Result<usize, DesignImportError> {
+        let file = DesignFile::from_json(json)?;
+        let company = &self.player_company;
+
+        let uses_reactor = file.design.stage_groups.iter().flatten()
+            .flat_map(|s| &s.power_sources)
+            .any(|p| matches!(p.kind, PowerSourceKind::Reactor { .. }));
+        if uses_reactor {
+            self.require_technology(technology::TECH_FISSION_REACTOR, &file.design.name)?;
+        }
+
+        // Resolve every engine before touching the company.
+        let mut new_projects: Vec<EngineProject> = Vec::new();
+        let mut catalog_picks: Vec<usize> = Vec::new();
+        let mut engines: Vec<(EngineId, EngineDesign)> = Vec::new();
+        for snap in &file.engines {
+            let name = &snap.design.name;
+            let design = match &snap.origin {
+                EngineOrigin::Designed { preset, scale, technology_id } => {
+                    let tech = technology_id.or_else(|| technology::technology_for_preset(*preset));
+                    if let Some(tech) = tech {
+                        self.require_technology(tech, name)?;
+                    }
+                    let n = new_projects.len() as u64;
+                    let mut project = EngineProject::new(
+                        EngineProjectId(company.next_project_id + n),
+                        EngineId(company.next_engine_id + n),
+                        name.clone(), snap.design.cycle, *preset, *scale,
+                        !snap.design.needs_atmosphere, &self.balance,
+                    ).ok_or_else(|| DesignImportError::Invalid(vec![format!("{} can't be designed here", name)]))?;
+                    project.technology_id = *technology_id;
+                    let design = project.design.clone();
+                    new_projects.push(project);
+                    design
+                }
+                EngineOrigin::Contracted { .. } => {
+                    if let Some(ce) = company.contracted_engines.iter().find(|ce| &ce.design.name == name) {
+                        ce.design.clone()
+                    } else {
+                        let idx = company.third_party_catalog.iter()
+                            .position(|e| &e.design.name == name && e.available_from <= self.date)
+                            .ok_or_else(|| DesignImportError::EngineUnavailable(name.clone()))?;
+                        catalog_picks.push(idx);
+                        company.third_party_catalog[idx].design.clone()
+                    }
+                }
+            };
+            engines.push((snap.design.id, design));
+        }
+
+        let mut design = file.design;
+        design.id = RocketDesignId(company.next_rocket_project_id);
+        // Files from before reserves carry none; an import is a new
+        // design, so it takes the default rather than flying as legacy.
+        design.performance_reserve.get_or_insert(crate::rocket::DEFAULT_PERFORMANCE_RESERVE);
+        for stage in design.stage_groups.iter_mut().flatten() {
+            if let Some((_, engine)) = engines.iter().find(|(id, _)| *id == stage.engine.id) {
+                stage.engine = engine.clone();
+            }
+        }
+        let problems = design.validate();
+        if !problems.is_empty() {
+            return Err(DesignImportError::Invalid(problems));
+        }
+
+        for idx in catalog_picks {
+            if let Some(evt) = self.player_company.contract_third_party(idx, self.date, &self.seed, &self.balance) {
+                self.event_log.push(self.date, evt);
+            }
+        }
+        let company = &mut self.player_company;
+        let new_engines = new_projects.len() as u32;
+        company.next_project_id += new_engines as u64;
+        company.next_engine_id += new_engines as u64;
+        company.engine_projects.extend(new_projects);
+
+        let project_id = RocketProjectId(company.next_rocket_project_id);
+        company.next_rocket_project_id += 1;
+        let unit_cost = company.estimated_unit_cost(&design, &self.balance);
+        let rocket_name = design.name.clone();
+        company.rocket_projects.push(RocketProject::new(project_id, design, &self.balance));
+
+        let evt = GameEvent::RocketDesignImported { rocket_name, new_engines, unit_cost };
+        self.event_log.push(self.date, evt);
+        Ok(self.player_company.rocket_projects.len() - 1)
+    }
+
+    /// Ok if `tech` is unlocked in this game; otherwise the error
+    /// naming what `part` is waiting on.
+    fn require_technology(&self, tech: TechnologyId, part: &str) -> Result<(), DesignImportError> {
+        match self.technologies.iter().find(|t| t.id == tech) {
+            Some(t) if t.unlocked => Ok(()),
+            found => Err(DesignImportError::TechnologyLocked {
+                engine: part.to_string(),
+                technology: found.map_or_else(|| format!("technology {}", tech.0), |t| t.name.clone()),
+            }),
+        }
+    }
+}
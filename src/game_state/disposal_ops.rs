@@ -0,0 +1,112 @@
+//! Spent stages: clearing the downrange zone at launch, and disposing
+//! of the last stage when a flight ends.
+
+use rand::Rng;
+
+use crate::budget::Department;
+use crate::contract::destination_display_name;
+use crate::disposal::{self, StageDisposal, StageFate, UpperStageDisposal};
+use crate::event::GameEvent;
+use crate::flight::FlightId;
+use crate::rocket::{Rocket, RocketDesign};
+
+use super::*;
+
+impl GameState {
+    /// Clear the downrange zone for a launch of `design` and drop its
+    /// first stage there. A stage that strays outside the zone draws a
+    /// fine and an environmental review. Single-stage vehicles drop
+    /// nothing.
+    pub(super) fn drop_first_stage(
+        &mut self,
+        flight_id: FlightId,
+        design: &RocketDesign,
+    ) -> (Option<StageDisposal>, Vec<GameEvent>) {
+        let mut events = Vec::new();
+        if design.stage_groups.len() < 2 {
+            return (None, events);
+        }
+        let cfg = self.balance.disposal.clone();
+        self.player_company.money -= cfg.downrange_clearance_fee;
+        self.record_expense(cfg.downrange_clearance_fee);
+        self.player_company.budgets.record(Department::Testing, cfg.downrange_clearance_fee);
+
+        let mut rng = self.seed.world_query(&format!("downrange:{}:{}", flight_id.0, self.date));
+        let cleared = rng.gen::<f64>() >= cfg.stray_stage_chance;
+        if !cleared {
+            self.player_company.money -= cfg.stray_stage_fine;
+            self.record_expense(cfg.stray_stage_fine);
+            events.push(GameEvent::RegulatoryFine {
+                reason: format!("stage outside the {}", cfg.downrange_zone),
+                amount: cfg.stray_stage_fine,
+            });
+            events.push(self.start_environmental_review("stray first stage"));
+        }
+        for evt in &events {
+            self.event_log.push(self.date, evt.clone());
+        }
+        let fate = StageFate::Downrange { zone: cfg.downrange_zone, cleared };
+        (Some(StageDisposal { stage_group: 0, fate }), events)
+    }
+
+    /// Dispose of a finished flight's stages: those jettisoned on the
+    /// way burned up, and the stack still attached goes the way of the
+    /// design's disposal option — a deorbit burn from what's left in
+    /// the tanks, or abandonment. Stages left in Earth orbit past the
+    /// debris allowance are fined.
+    pub(super) fn dispose_final_stage(
+        &mut self,
+        rocket_project_id: RocketProjectId,
+        design: &RocketDesign,
+        rocket: &mut Rocket,
+        destination: &str,
+        disposals: &mut Vec<StageDisposal>,
+    ) -> Vec<GameEvent> {
+        let Some(final_group) = rocket.stage_states.iter()
+            .position(|group| group.iter().any(|s| s.attached))
+        else {
+            return Vec::new();
+        };
+        for gi in 1..final_group {
+            if !disposals.iter().any(|d| d.stage_group == gi) {
+                disposals.push(StageDisposal { stage_group: gi, fate: StageFate::Reentered });
+            }
+        }
+        // A first stage that made it all the way never fell downrange.
+        disposals.retain(|d| d.stage_group != final_group);
+
+        let option = self.player_company.rocket_projects.iter()
+            .find(|rp| rp.project_id == rocket_project_id)
+            .map_or(UpperStageDisposal::default(), |rp| rp.disposal);
+        let cfg = self.balance.disposal.clone();
+        let fate = disposal::final_stage_fate(
+            option,
+            destination,
+            destination_display_name(destination),
+            rocket.remaining_delta_v(design),
+            cfg.deorbit_delta_v,
+        );
+        if fate == StageFate::Deorbited {
+            rocket.burn_sequential(design, cfg.deorbit_delta_v, 0.0);
+        }
+
+        let mut events = Vec::new();
+        if matches!(fate, StageFate::Debris { .. }) {
+            let compliance = &mut self.player_company.compliance;
+            compliance.orbital_debris += 1;
+            if compliance.orbital_debris > cfg.debris_allowance {
+                self.player_company.money -= cfg.orbital_debris_fine;
+                self.record_expense(cfg.orbital_debris_fine);
+                let evt = GameEvent::RegulatoryFine {
+                    reason: "spent stage left in orbit".into(),
+                    amount: cfg.orbital_debris_fine,
+                };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+        disposals.push(StageDisposal { stage_group: final_group, fate });
+        disposals.sort_by_key(|d| d.stage_group);
+        events
+    }
+}
@@ -0,0 +1,130 @@
+//! Espionage: the monthly security bill, competitors copying the
+//! player's engines, and leaked competitor data for sale.
+
+use rand::Rng;
+
+use crate::engine_project::EngineDesignStatus;
+use crate::espionage::{self, LeakKind, LeakOffer};
+use crate::budget::Department;
+use crate::event::GameEvent;
+
+use super::*;
+
+impl GameState {
+    /// Set the counter-espionage spending tier (clamped to the max).
+    pub fn set_security_level(&mut self, level: u32) {
+        self.player_company.security_level = level.min(self.balance.espionage.max_security_level);
+    }
+
+    /// Month-start espionage tick. Competitors only copy designs that
+    /// have left the drawing board (testing or revising) and that they
+    /// haven't already taken.
+    pub(super) fn tick_espionage(&mut self, events: &mut Vec<GameEvent>) {
+        let cfg = self.balance.espionage.clone();
+
+        let security_cost = self.player_company.security_level as f64 * cfg.security_cost_per_level;
+        if security_cost > 0.0 {
+            self.player_company.money -= security_cost;
+            self.record_expense(security_cost);
+            self.player_company.budgets.record(Department::Facilities, security_cost);
+        }
+
+        if self.leak_offer.as_ref().is_some_and(|o| o.expires < self.date) {
+            self.leak_offer = None;
+        }
+        if self.competitors.is_empty() {
+            return;
+        }
+
+        // A competitor copies one of our engines.
+        let chance = espionage::copy_chance(&cfg, self.player_company.security_level);
+        if self.seed.contingent_rng.gen::<f64>() < chance {
+            let ci = self.seed.contingent_rng.gen_range(0..self.competitors.len());
+            let copied = &self.competitors[ci].copied_engines;
+            let targets: Vec<String> = self.player_company.engine_projects.iter()
+                .filter(|ep| matches!(ep.status,
+                    EngineDesignStatus::Testing { .. } | EngineDesignStatus::Revising { .. }))
+                .map(|ep| ep.design.name.clone())
+                .filter(|name| !copied.contains(name))
+                .collect();
+            if !targets.is_empty() {
+                let engine_name = targets[self.seed.contingent_rng.gen_range(0..targets.len())].clone();
+                let comp = &mut self.competitors[ci];
+                comp.absorb_copied_engine(engine_name.clone(), cfg.copy_failure_rate_factor);
+                self.player_company.reputation.on_design_copied(cfg.copy_reputation_penalty);
+                let evt = GameEvent::DesignCopied {
+                    engine_name,
+                    company: comp.company.name.clone(),
+                };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+
+        // Leaked competitor data comes up for sale.
+        if self.leak_offer.is_none() && self.seed.contingent_rng.gen::<f64>() < cfg.leak_offer_chance {
+            let rng = &mut self.seed.contingent_rng;
+            let source = self.competitors[rng.gen_range(0..self.competitors.len())]
+                .company.name.clone();
+            // Nobody can leak what hasn't been invented yet.
+            let era = &self.balance.era;
+            let year = self.date.year;
+            let locked: Vec<_> = self.technologies.iter()
+                .filter(|t| !t.unlocked && era.tech_arrival(t.id).is_none_or(|y| y <= year))
+                .map(|t| (t.id, t.name.clone()))
+                .collect();
+            let kind = if !locked.is_empty() && rng.gen_bool(0.5) {
+                let (technology_id, name) = locked[rng.gen_range(0..locked.len())].clone();
+                LeakKind::Technology { technology_id, name }
+            } else {
+                LeakKind::TestingWork { cycles: cfg.leak_testing_cycles }
+            };
+            let price = rng.gen_range(cfg.leak_price_min..=cfg.leak_price_max.max(cfg.leak_price_min));
+            let offer = LeakOffer {
+                source,
+                kind,
+                price: (price / 10_000.0).round() * 10_000.0,
+                expires: self.date.add_days(cfg.leak_offer_days),
+            };
+            let evt = GameEvent::LeakOffered {
+                description: offer.description(),
+                price: offer.price,
+            };
+            self.leak_offer = Some(offer);
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Buy the leaked data on offer. Test data goes to the engine in
+    /// testing with the least cumulative testing work; returns None
+    /// (keeping the offer) if there's no offer or nothing to apply it to.
+    pub fn buy_leak(&mut self) -> Option<GameEvent> {
+        let offer = self.leak_offer.as_ref()?;
+        match &offer.kind {
+            LeakKind::TestingWork { cycles } => {
+                let work = *cycles as f64 * self.balance.work.testing_cycle_work;
+                let ep = self.player_company.engine_projects.iter_mut()
+                    .filter(|ep| matches!(ep.status, EngineDesignStatus::Testing { .. }))
+                    .min_by(|a, b| a.cumulative_testing_work.total_cmp(&b.cumulative_testing_work))?;
+                if let EngineDesignStatus::Testing { work_completed } = &mut ep.status {
+                    *work_completed += work;
+                }
+                ep.cumulative_testing_work += work;
+            }
+            LeakKind::Technology { technology_id, .. } => {
+                let tech = self.technologies.iter_mut().find(|t| t.id == *technology_id)?;
+                tech.unlocked = true;
+            }
+        }
+        let offer = self.leak_offer.take()?;
+        self.player_company.money -= offer.price;
+        self.record_expense(offer.price);
+        let evt = GameEvent::LeakPurchased {
+            description: offer.description(),
+            price: offer.price,
+        };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+}
@@ -0,0 +1,135 @@
+//! Company valuation, the IPO, quarterly earnings reports, and the
+//! books: budgets and fixed assets.
+
+use crate::assets::{AssetId, AssetKind};
+use crate::event::GameEvent;
+use crate::valuation::{PublicListing, Valuation, ValuationRecord};
+
+use super::*;
+
+impl GameState {
+    /// The player company's current appraisal.
+    pub fn valuation(&self) -> Valuation {
+        Valuation::appraise(&self.player_company, &self.balance)
+    }
+
+    /// Append this month's valuation to the history, dropping the
+    /// oldest entries past the configured window.
+    pub(super) fn record_valuation(&mut self) {
+        let value = self.valuation().total();
+        let history = &mut self.player_company.valuation_history;
+        history.push(ValuationRecord { date: self.date, value });
+        let cap = self.balance.valuation.history_months;
+        if history.len() > cap {
+            history.drain(..history.len() - cap);
+        }
+    }
+
+    /// Take the company public: sell the float for cash and take on a
+    /// quarterly earnings target. Returns None if already listed or
+    /// the valuation is below the IPO minimum.
+    pub fn ipo(&mut self) -> Option<GameEvent> {
+        let cfg = &self.balance.valuation;
+        if self.player_company.listing.is_some() {
+            return None;
+        }
+        let valuation = self.valuation().total();
+        if valuation < cfg.min_ipo_valuation {
+            return None;
+        }
+        let raised = valuation * cfg.ipo_float_fraction;
+        self.player_company.listing = Some(PublicListing {
+            ipo_date: self.date,
+            raised,
+            quarterly_target: valuation * cfg.quarterly_target_fraction,
+            sentiment: 1.0,
+            missed_quarters: 0,
+        });
+        // Equity, not earnings: kept out of the monthly income books.
+        self.player_company.money += raised;
+        let evt = GameEvent::IpoCompleted { raised, valuation };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// On the first day of a quarter, close the books on the one just
+    /// ended: report its income and expenses.
+    pub(super) fn close_quarter(&mut self, events: &mut Vec<GameEvent>) {
+        if !self.date.is_first_of_quarter() {
+            return;
+        }
+        let (year, quarter) = self.date.previous_quarter();
+        let Some((income, expenses)) = self.player_company.quarter_financials(year, quarter) else {
+            return;
+        };
+        let evt = GameEvent::QuarterClosed { year, quarter, income, expenses };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+
+    /// Sell a block of floor space for its book value. None if there's
+    /// no such asset, it isn't floor space, or its units are in use.
+    pub fn sell_asset(&mut self, id: AssetId) -> Option<GameEvent> {
+        let company = &mut self.player_company;
+        let AssetKind::FloorSpace { units } = company.assets.get(id)?.kind else {
+            return None;
+        };
+        if company.manufacturing.floor_space_available() < units {
+            return None;
+        }
+        let asset = company.assets.dispose(id)?;
+        company.manufacturing.floor_space.total_units -= units;
+        company.money += asset.book_value;
+        self.record_income(asset.book_value);
+        let evt = GameEvent::AssetSold { name: asset.kind.name(), proceeds: asset.book_value };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// On the first day of a month, close the department budgets on
+    /// the one just ended and flag any department that overspent.
+    pub(super) fn close_budget_month(&mut self, events: &mut Vec<GameEvent>) {
+        let (year, month) = self.date.previous_month();
+        let closed = self.player_company.budgets.close_month(year, month);
+        for (department, spent, budget) in closed.overruns() {
+            let evt = GameEvent::BudgetOverrun { department, spent, budget };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// On the first day of a quarter, judge the previous quarter's net
+    /// earnings against the listing's target. Quarters that began
+    /// before the IPO aren't judged.
+    pub(super) fn report_quarterly_earnings(&mut self, events: &mut Vec<GameEvent>) {
+        if !self.date.is_first_of_quarter() {
+            return;
+        }
+        let Some(listing) = &self.player_company.listing else {
+            return;
+        };
+        let (year, quarter) = self.date.previous_quarter();
+        let (qy, qm) = crate::calendar::quarter_months(year, quarter)[0];
+        if listing.ipo_date > GameDate::new(qy, qm, 1) {
+            return;
+        }
+        let net = self.player_company.quarter_financials(year, quarter)
+            .map_or(0.0, |(income, expenses)| income - expenses);
+
+        let cfg = &self.balance.valuation;
+        let target = listing.quarterly_target;
+        let met = net >= target;
+        let listing = self.player_company.listing.as_mut().unwrap();
+        if met {
+            listing.sentiment = (listing.sentiment + cfg.beat_sentiment_gain).min(1.0);
+            listing.missed_quarters = 0;
+        } else {
+            listing.sentiment *= cfg.miss_sentiment_factor;
+            listing.missed_quarters += 1;
+        }
+        self.player_company.reputation.on_earnings_report(&self.balance.reputation, met);
+        let evt = GameEvent::EarningsReported { net, target, met };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+}
@@ -0,0 +1,148 @@
+//! Gathering everything the company owns into one
+//! [`FleetRegistry`] (see [`crate::fleet`]).
+
+use crate::assets::AssetKind;
+use crate::booster_reuse::RetirementAdvice;
+use crate::flight::CompanyRef;
+use crate::fleet::{months_between, FleetCategory, FleetEntry, FleetRegistry, FleetStatus};
+
+use super::*;
+
+impl GameState {
+    /// Everything the company owns today, with where it is, how it
+    /// stands, its book value and age.
+    pub fn fleet_registry(&self) -> FleetRegistry {
+        let company = &self.player_company;
+        let today = self.date;
+        let site = &company.launch_site;
+        let mut entries = Vec::new();
+
+        for r in &company.manufacturing.inventory.rockets {
+            let (status, detail) = if r.expired {
+                (FleetStatus::NeedsAttention, "past its shelf life".to_string())
+            } else if self.check_pad_fits(r.item_id).is_err() {
+                (FleetStatus::NeedsAttention, "too heavy for the pad".to_string())
+            } else {
+                match company.flight_preparation(r.item_id).filter(|p| !p.is_complete()) {
+                    Some(p) => (FleetStatus::Busy,
+                        format!("integration {:.0}%", 100.0 * p.work_completed / p.work_required)),
+                    None => (FleetStatus::Ready, format!("Rev {}", r.revision)),
+                }
+            };
+            entries.push(FleetEntry {
+                category: FleetCategory::Rocket,
+                name: r.rocket_name.clone(),
+                location: site.location.clone(),
+                status,
+                detail,
+                book_value: r.build_cost,
+                age_months: r.built_on.map(|d| months_between(d, today)),
+            });
+        }
+
+        let reuse = &self.balance.booster_reuse;
+        for s in &company.manufacturing.inventory.stages {
+            let Some(life) = &s.booster else { continue };
+            let advice = life.retirement_advice(reuse);
+            entries.push(FleetEntry {
+                category: FleetCategory::Booster,
+                name: format!("B{}", life.serial),
+                location: site.location.clone(),
+                status: if advice == RetirementAdvice::Fly { FleetStatus::Ready } else { FleetStatus::NeedsAttention },
+                detail: format!("{} flights, {}", life.flights, advice),
+                book_value: s.build_cost,
+                age_months: life.refurbished_on.first().map(|&d| months_between(d, today)),
+            });
+        }
+
+        for sc in &self.spacecraft {
+            let constellation = self.constellations.iter()
+                .find(|c| c.location == sc.location && c.rocket_project_id == sc.rocket_project_id);
+            let (category, status, detail) = match (constellation, sc.health > 0.0) {
+                (Some(c), true) => (FleetCategory::Satellite, FleetStatus::InService, c.name.clone()),
+                (Some(c), false) => (FleetCategory::Satellite, FleetStatus::NeedsAttention, format!("{}, worn out", c.name)),
+                (None, true) => (FleetCategory::Spacecraft, FleetStatus::Ready, format!("health {:.0}%", 100.0 * sc.health)),
+                (None, false) => (FleetCategory::Spacecraft, FleetStatus::NeedsAttention, "needs servicing".to_string()),
+            };
+            entries.push(FleetEntry {
+                category,
+                name: sc.name.clone(),
+                location: sc.location.clone(),
+                status,
+                detail,
+                book_value: 0.0,
+                age_months: None,
+            });
+        }
+        for f in self.active_flights.iter().filter(|f| f.company == CompanyRef::Player && f.persist) {
+            entries.push(FleetEntry {
+                category: FleetCategory::Spacecraft,
+                name: f.rocket_name.clone(),
+                location: f.current_location.clone(),
+                status: FleetStatus::Busy,
+                detail: format!("en route to {}", f.destination()),
+                book_value: 0.0,
+                age_months: Some(months_between(f.launch_date, today)),
+            });
+        }
+
+        for infra in &self.infrastructure {
+            let depot = &infra.depot;
+            entries.push(FleetEntry {
+                category: FleetCategory::Depot,
+                name: depot.design.name.clone(),
+                location: infra.location.clone(),
+                status: FleetStatus::InService,
+                detail: format!("{:.0} / {:.0} kg", depot.total_kg(), depot.capacity_kg()),
+                book_value: 0.0,
+                age_months: None,
+            });
+            for plant in &infra.isru_plants {
+                entries.push(FleetEntry {
+                    category: FleetCategory::IsruPlant,
+                    name: plant.design.name.clone(),
+                    location: infra.location.clone(),
+                    status: if plant.health > 0.0 { FleetStatus::InService } else { FleetStatus::NeedsAttention },
+                    detail: format!("health {:.0}%", 100.0 * plant.health),
+                    book_value: 0.0,
+                    age_months: Some(months_between(plant.deployed_on, today)),
+                });
+            }
+        }
+
+        for asset in &company.assets.assets {
+            let (category, status, detail) = match &asset.kind {
+                AssetKind::FloorSpace { units } =>
+                    (FleetCategory::Facility, FleetStatus::InService, format!("{} units", units)),
+                AssetKind::LaunchPad => {
+                    let (status, detail) = if let Some(until) = site.rebuild_until.filter(|&u| today < u) {
+                        (FleetStatus::NeedsAttention, format!("rebuilding until {}", until))
+                    } else if let Some(until) = site.pad_busy_until(today) {
+                        (FleetStatus::Busy, format!("busy until {}", until))
+                    } else if let Some(lease) = site.lease_on(today) {
+                        (FleetStatus::Busy, format!("leased to {}", lease.lessee))
+                    } else {
+                        (FleetStatus::Ready, format!("{} integration", site.integration))
+                    };
+                    (FleetCategory::LaunchPad, status, detail)
+                }
+                AssetKind::RecoveryAsset { name } => {
+                    let ship = company.recovery_fleet.assets.iter().find(|a| &a.name == name);
+                    let status = if ship.is_some_and(|a| a.available(today)) { FleetStatus::Ready } else { FleetStatus::Busy };
+                    (FleetCategory::RecoveryAsset, status, ship.map(|a| a.status(today)).unwrap_or_default())
+                }
+            };
+            entries.push(FleetEntry {
+                category,
+                name: asset.kind.name(),
+                location: site.location.clone(),
+                status,
+                detail,
+                book_value: asset.book_value,
+                age_months: Some(asset.months_on_books),
+            });
+        }
+
+        FleetRegistry { as_of: today, entries }
+    }
+}
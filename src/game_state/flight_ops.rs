@@ -3,30 +3,38 @@
 //! (fly / dock / undock).
 
 
-use crate::engine_project::EngineSource;
+use crate::engine::EngineId;
+use crate::engine_project::{EngineProject, EngineSource, WorkEvent};
 use crate::flight::{Flight, FlightId, FlightStatus, Payload};
+use crate::budget::Department;
 use crate::event::GameEvent;
 use crate::launch::{self, LaunchRecord, LaunchOutcome};
 use crate::rocket::RocketId;
+use crate::rocket_project::RocketWorkEvent;
+use crate::servicing::ServiceTarget;
+use crate::team::MoraleCause;
 
 use super::*;
 
 impl GameState {
-    /// Assemble a launch manifest from contract picks and spacecraft
-    /// inventory items: resolves the shared destination (all picked
-    /// contracts must agree; defaults to LEO with no contract picks),
-    /// builds `ContractDelivery` payloads, and takes each picked
-    /// inventory rocket, instantiating it as a `Spacecraft` payload
-    /// deployed at the destination. Validates everything before
-    /// consuming inventory, so on error nothing is taken. An empty
-    /// manifest becomes a zero-mass test launch.
-    ///
-    /// `contract_indices` index into `player_company.active_contracts`.
-    pub fn build_launch_payloads(
-        &mut self,
+    /// Validate a launch manifest without taking anything: the pad is
+    /// free, picked contracts share a destination (LEO with none
+    /// picked), the regulator clears it, and picked spacecraft are still
+    /// in inventory. Returns the destination.
+    pub fn plan_manifest(
+        &self,
         contract_indices: &[usize],
         spacecraft_item_ids: &[crate::manufacturing::InventoryItemId],
-    ) -> Result<(String, Vec<Payload>), ManifestError> {
+    ) -> Result<String, ManifestError> {
+        // Leased days belong to the lessee.
+        let site = &self.player_company.launch_site;
+        if let Some(lease) = site.lease_on(self.date) {
+            return Err(ManifestError::PadLeased {
+                lessee: lease.lessee.clone(),
+                free_on: site.next_free_day(self.date),
+            });
+        }
+
         // Destination must agree across picked contracts.
         let mut destination: Option<String> = None;
         for &i in contract_indices {
@@ -42,7 +50,20 @@ impl GameState {
                 }
             }
         }
+        // The customers must have shipped their payloads.
+        for &i in contract_indices {
+            let c = &self.player_company.active_contracts[i];
+            if let Some(p) = self.player_company.customer_payloads.get(c.id).filter(|p| p.ready_on > self.date) {
+                return Err(ManifestError::PayloadNotReady {
+                    contract_name: c.name.clone(),
+                    ready_on: p.ready_on,
+                });
+            }
+        }
         let destination = destination.unwrap_or_else(|| "leo".to_string());
+        self.player_company.compliance
+            .check(&destination, self.date, site, &self.balance.regulation)
+            .map_err(ManifestError::NotCompliant)?;
 
         // Validate spacecraft picks before consuming any inventory.
         for &item_id in spacecraft_item_ids {
@@ -55,6 +76,25 @@ impl GameState {
                 return Err(ManifestError::PayloadProjectMissing);
             }
         }
+        Ok(destination)
+    }
+
+    /// Assemble a launch manifest from contract picks and spacecraft
+    /// inventory items: resolves the shared destination (all picked
+    /// contracts must agree; defaults to LEO with no contract picks),
+    /// builds `ContractDelivery` payloads, and takes each picked
+    /// inventory rocket, instantiating it as a `Spacecraft` payload
+    /// deployed at the destination. Validates everything before
+    /// consuming inventory, so on error nothing is taken. An empty
+    /// manifest becomes a zero-mass test launch.
+    ///
+    /// `contract_indices` index into `player_company.active_contracts`.
+    pub fn build_launch_payloads(
+        &mut self,
+        contract_indices: &[usize],
+        spacecraft_item_ids: &[crate::manufacturing::InventoryItemId],
+    ) -> Result<(String, Vec<Payload>), ManifestError> {
+        let destination = self.plan_manifest(contract_indices, spacecraft_item_ids)?;
 
         let mut payloads: Vec<Payload> = Vec::new();
         for &i in contract_indices {
@@ -86,6 +126,7 @@ impl GameState {
                 nested_payloads: vec![],
                 rocket_project_id: inv_rocket.rocket_project_id,
                 name: inv_rocket.rocket_name.clone(),
+                health: crate::servicing::FULL_HEALTH,
             });
         }
 
@@ -96,6 +137,61 @@ impl GameState {
         Ok((destination, payloads))
     }
 
+    /// Start an integration campaign validating the inventory rocket
+    /// `item_id` for a flight of `payload_kg` to `destination`. None if
+    /// the rocket or a free team is missing, or the destination is
+    /// unreachable.
+    pub fn start_integration_campaign(
+        &mut self, item_id: crate::manufacturing::InventoryItemId, destination: &str, payload_kg: f64,
+    ) -> Option<GameEvent> {
+        let plan = crate::integration::MissionPlan::new(destination, payload_kg, self.plane_change_dv(destination))?;
+        let evt = self.player_company.start_integration_campaign(item_id, plan, &self.balance)?;
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Choose how full the inventory rocket `item_id`'s liquid tanks are
+    /// loaded for its launch. False if there's no such rocket.
+    pub fn set_propellant_load(
+        &mut self, item_id: crate::manufacturing::InventoryItemId, load: crate::rocket::PropellantLoad,
+    ) -> bool {
+        let min = self.balance.propellant_loading.min_load_fraction;
+        let load = match load {
+            crate::rocket::PropellantLoad::Partial(share) if share >= 1.0 => crate::rocket::PropellantLoad::Full,
+            crate::rocket::PropellantLoad::Partial(share) => crate::rocket::PropellantLoad::Partial(share.max(min)),
+            other => other,
+        };
+        match self.player_company.manufacturing.inventory.rockets.iter_mut().find(|r| r.item_id == item_id) {
+            Some(rocket) => {
+                rocket.propellant_load = load;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Share of its liquid tanks `design` is filled to under `load` for
+    /// `payload_kg` to `destination` from the company's pad. Auto-fill
+    /// loads just enough to keep the design's performance reserve, and
+    /// fills up when even full tanks fall short.
+    pub fn propellant_load_share(
+        &self,
+        design: &crate::rocket::RocketDesign,
+        load: crate::rocket::PropellantLoad,
+        destination: &str,
+        payload_kg: f64,
+    ) -> f64 {
+        let min = self.balance.propellant_loading.min_load_fraction;
+        match load {
+            crate::rocket::PropellantLoad::Full => 1.0,
+            crate::rocket::PropellantLoad::Partial(share) => share.clamp(min, 1.0),
+            crate::rocket::PropellantLoad::AutoFill => crate::location::DELTA_V_MAP
+                .shortest_path_for_rocket("earth_surface", destination, design, payload_kg)
+                .and_then(|(_, dv)| design.fill_share_for(payload_kg, dv + self.plane_change_dv(destination), min))
+                .unwrap_or(1.0),
+        }
+    }
+
     /// Launch a rocket carrying a manifest of payloads.
     /// `rocket_item_id` identifies the InventoryRocket to use as the carrier.
     /// `payloads` is the full manifest — any combination of contract
@@ -113,29 +209,136 @@ impl GameState {
         persist: bool,
     ) -> Option<(Vec<GameEvent>, Option<LaunchRecord>)> {
         let total_payload_kg: f64 = payloads.iter().map(|p| p.mass_kg()).sum();
+        // The pad's facility has to be able to stack it.
+        self.check_pad_fits(rocket_item_id).ok()?;
 
         // Take the rocket from inventory
         let inv_rocket = self.player_company.manufacturing.inventory.take_rocket(rocket_item_id)?;
+        // A finished integration campaign covering this flight scales
+        // down every flaw it could hit. Spent either way.
+        let integration_factor = self.player_company.take_flight_preparation(rocket_item_id)
+            .map_or(1.0, |prep| prep.activation_factor(destination, total_payload_kg, &self.balance.integration));
+        // Press booked for this rocket covers the launch, for better or worse.
+        let press = self.player_company.marketing.take_press_event(rocket_item_id);
+        // The assigned crew, if any, boards; a skilled crew heads off
+        // some of the trouble.
+        let crew = self.player_company.astronauts.board(rocket_item_id);
+        let crew_factor = 1.0 - self.balance.astronauts.skill_flaw_reduction
+            * self.player_company.astronauts.crew_skill(&crew);
+        let flaw_factor = integration_factor * crew_factor;
 
         // Find the rocket project for this rocket
         let rp = self.player_company.rocket_projects.iter()
             .find(|rp| rp.project_id == inv_rocket.rocket_project_id)?;
+        // Stock flies as built; a later modification needs a retrofit.
+        // It flies with whatever propellant it was loaded with.
+        let built = inv_rocket.design.as_ref().unwrap_or(&rp.design);
+        let load_share = self.propellant_load_share(built, inv_rocket.propellant_load, destination, total_payload_kg);
+        let propellant_price = self.world_market.prices.propellant;
+        let propellant_saved = built.liquid_propellant_cost() * propellant_price * (1.0 - load_share);
+        let design = &built.with_propellant_load(load_share);
+        let uses_solids = design.uses_solid_motors();
+        let abort_chance = design.has_launch_abort_system()
+            .then(|| rp.abort_success_chance(&self.balance.launch_abort));
+
+        // Use snapshotted rocket flaws from the inventory item. Flight
+        // software loads at the pad, so its lineage's live flaws fly
+        // too, followed by the guidance risk of immature code.
+        let software_id = rp.flight_software();
+        let maturity = self.player_company.flight_software_maturity(rp, &self.balance);
+        let software_flaws = software_id
+            .and_then(|id| self.player_company.software_projects.iter().find(|sw| sw.project_id == id))
+            .map(|sw| sw.flaws.clone())
+            .unwrap_or_default();
+        let hardware_len = inv_rocket.rocket_flaws.len();
+        let software_len = software_flaws.len();
+        let mut flight_flaws = inv_rocket.rocket_flaws.clone();
+        flight_flaws.extend(software_flaws);
+        flight_flaws.extend(crate::software_project::immaturity_flaw(maturity, &self.balance.software));
+        if inv_rocket.expired {
+            flight_flaws.push(crate::manufacturing::aging_flaw(&self.balance.storage));
+        }
+        for (_, life) in &inv_rocket.boosters {
+            flight_flaws.push(crate::booster_reuse::wear_flaw(life, &self.balance.booster_reuse));
+        }
+        // Any approach to dock on arrival rides on the same software and
+        // avionics.
+        let approach_flaws = crate::docking::hidden_approach_flaws(&flight_flaws[..hardware_len + software_len]);
+        let docking_risk = crate::docking::failure_chance(maturity, approach_flaws, &self.balance.docking);
+        // So does where the launch drops its payload.
+        let insertion_dispersion = crate::insertion::dispersion_m_s(maturity, approach_flaws, &self.balance.insertion);
+        #[allow(unused_mut)]
+        let mut validated_engines: Option<Vec<EngineProject>> = None;
+        if flaw_factor < 1.0 {
+            for f in &mut flight_flaws {
+                f.activation_chance *= flaw_factor;
+            }
+            validated_engines = Some(self.player_company.engine_projects.iter()
+                .cloned()
+                .map(|mut ep| {
+                    for f in &mut ep.flaws {
+                        f.activation_chance *= flaw_factor;
+                    }
+                    ep
+                })
+                .collect());
+        }
+        #[cfg(any(feature = "qa", debug_assertions))]
+        let forced = self.qa.next_launch.take();
+        #[cfg(any(feature = "qa", debug_assertions))]
+        if let Some(outcome) = &forced {
+            crate::qa::rig_launch(outcome, &mut flight_flaws, &mut validated_engines, &self.player_company.engine_projects);
+        }
+        let engine_projects = validated_engines.as_deref().unwrap_or(&self.player_company.engine_projects);
+        let rocket_flaws = &flight_flaws;
+        // Split a combined flaw index into hardware / software parts;
+        // the immaturity, aging and booster wear pseudo-flaws map to
+        // neither.
+        let split = |idx: usize| -> (Option<usize>, Option<usize>) {
+            if idx < hardware_len {
+                (Some(idx), None)
+            } else if idx < hardware_len + software_len {
+                (None, Some(idx - hardware_len))
+            } else {
+                (None, None)
+            }
+        };
 
-        // Use snapshotted rocket flaws from the inventory item
-        let rocket_flaws = &inv_rocket.rocket_flaws;
-
+        let plane_change_dv = self.plane_change_dv(destination);
         // Simulate flaw activation at launch
-        let sim = launch::simulate_launch(
-            &rp.design,
+        #[allow(unused_mut)]
+        let mut sim = launch::simulate_launch(
+            design,
             destination,
             total_payload_kg,
-            &self.player_company.engine_projects,
+            plane_change_dv,
+            engine_projects,
             rocket_flaws,
             &self.player_company.contracted_engines,
             &mut self.seed.contingent_rng,
         );
+        #[cfg(any(feature = "qa", debug_assertions))]
+        if let Some(outcome) = &forced {
+            crate::qa::force_outcome(outcome, &mut sim);
+        }
 
         let mut events = Vec::new();
+        let propellant_cost = design.liquid_propellant_cost() * propellant_price;
+        self.world_market.record_launch(design.total_propellant_mass_kg());
+        self.player_company.money -= propellant_cost;
+        self.record_expense(propellant_cost);
+        self.player_company.budgets.record(Department::Testing, propellant_cost);
+        if load_share < 1.0 {
+            let evt = GameEvent::PropellantOffloaded {
+                rocket_name: inv_rocket.rocket_name.clone(),
+                share: load_share,
+                saved: propellant_saved,
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        // (engine id, engine name, flaw) for the common-cause notices.
+        let mut shared_discoveries: Vec<(EngineId, String, String)> = Vec::new();
 
         // Mark activated flaws as discovered on engine projects
         for (engine_id, indices) in &sim.engine_flaw_discoveries {
@@ -151,6 +354,7 @@ impl GameState {
                         };
                         self.event_log.push(self.date, evt.clone());
                         events.push(evt);
+                        shared_discoveries.push((*engine_id, ep.design.name.clone(), ep.flaws[idx].description.clone()));
                     }
                 }
             }
@@ -165,17 +369,26 @@ impl GameState {
                     for &idx in indices {
                         if idx < ce.flaws.len() {
                             ce.flaws[idx].discovered = true;
+                            shared_discoveries.push((ce.design.id, ce.design.name.clone(), ce.flaws[idx].description.clone()));
                         }
                     }
                 }
             }
         }
+        for (engine_id, engine_name, flaw_description) in shared_discoveries {
+            if let Some(evt) = self.common_cause_flaw(engine_id, engine_name, flaw_description) {
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
 
         // Mark activated flaws as discovered on rocket project
         if let Some(rp_mut) = self.player_company.rocket_projects.iter_mut()
             .find(|rp| rp.project_id == inv_rocket.rocket_project_id)
         {
-            for &idx in &sim.rocket_flaw_discoveries {
+            rp_mut.flights += 1;
+            rp_mut.flown_build_cost += inv_rocket.build_cost;
+            for idx in sim.rocket_flaw_discoveries.iter().filter_map(|&i| split(i).0) {
                 if idx < rp_mut.flaws.len() {
                     rp_mut.flaws[idx].discovered = true;
                     let evt = GameEvent::RocketFlawDiscovered {
@@ -187,10 +400,93 @@ impl GameState {
                 }
             }
         }
+        // Cost-plus customers are charged for the hardware their payload
+        // flies on, pro rata by mass, plus the indirect-cost allocation.
+        let charged_cost = inv_rocket.build_cost * (1.0 + self.balance.markets.cost_plus_indirect_rate);
+        for p in &payloads {
+            if let Payload::ContractDelivery { contract_id, .. } = p {
+                let share = if total_payload_kg > 0.0 { p.mass_kg() / total_payload_kg } else { 1.0 };
+                if let Some(c) = self.player_company.active_contracts.iter_mut().find(|c| c.id == *contract_id) {
+                    c.record_cost(charged_cost * share);
+                }
+            }
+        }
+        // ...and on the software lineage
+        if let Some(sw) = software_id
+            .and_then(|id| self.player_company.software_projects.iter_mut().find(|sw| sw.project_id == id))
+        {
+            for idx in sim.rocket_flaw_discoveries.iter().filter_map(|&i| split(i).1) {
+                if idx < sw.flaws.len() && !sw.flaws[idx].discovered {
+                    sw.flaws[idx].discovered = true;
+                    let evt = GameEvent::SoftwareFlawDiscovered {
+                        software_name: sw.name.clone(),
+                        flaw_description: sw.flaws[idx].description.clone(),
+                    };
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
+                }
+            }
+        }
 
+        // A clean flight may still show telemetry anomalies that hint at
+        // hidden flaws for the next testing cycles.
+        let near_misses = launch::roll_near_misses(
+            &sim.degraded_design,
+            &self.player_company.engine_projects,
+            rocket_flaws,
+            &sim,
+            self.balance.flaws.near_miss_ratio,
+            &mut self.seed.contingent_rng,
+        );
+        for (engine_id, hints) in near_misses.engine {
+            if let Some(ep) = self.player_company.engine_projects.iter_mut()
+                .find(|ep| ep.design.id == engine_id)
+            {
+                if let Some(WorkEvent::AnomalyDetected { hints }) = ep.record_anomalies(hints) {
+                    let evt = GameEvent::AnomalyDetected { vehicle_name: ep.design.name.clone(), hints };
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
+                }
+            }
+        }
+        if let Some(rp_mut) = self.player_company.rocket_projects.iter_mut()
+            .find(|rp| rp.project_id == inv_rocket.rocket_project_id)
+        {
+            let hardware_hints = near_misses.rocket.iter()
+                .filter_map(|(i, hint)| split(*i).0.map(|idx| (idx, hint.clone())))
+                .collect();
+            if let Some(RocketWorkEvent::AnomalyDetected { hints }) = rp_mut.record_anomalies(hardware_hints) {
+                let evt = GameEvent::AnomalyDetected { vehicle_name: rp_mut.design.name.clone(), hints };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+        if let Some(sw) = software_id
+            .and_then(|id| self.player_company.software_projects.iter_mut().find(|sw| sw.project_id == id))
+        {
+            let software_hints = near_misses.rocket.into_iter()
+                .filter_map(|(i, hint)| split(i).1.map(|idx| (idx, hint)))
+                .collect();
+            let hints = crate::flaw::record_anomalies(&mut sw.flaws, software_hints);
+            if !hints.is_empty() {
+                let evt = GameEvent::AnomalyDetected { vehicle_name: sw.name.clone(), hints };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
 
         // Update launch tracking
         self.player_company.last_launch_date = Some(self.date);
+        let failed = matches!(sim.outcome, LaunchOutcome::Failure { .. });
+        events.extend(self.record_regulated_launch(uses_solids, failed));
+        events.push(self.start_pad_flow(design));
+        let manifest_contract_ids: Vec<crate::contract::ContractId> = payloads.iter()
+            .filter_map(|p| match p {
+                Payload::ContractDelivery { contract_id, .. } => Some(*contract_id),
+                _ => None,
+            })
+            .collect();
+        events.extend(self.record_export_launch(&manifest_contract_ids));
 
         // Catastrophic failure at launch — resolve immediately. The carrier
         // and all nested Spacecraft payloads are destroyed (the `payloads`
@@ -198,18 +494,17 @@ impl GameState {
         // All on-manifest contracts are forfeited.
         if matches!(sim.outcome, LaunchOutcome::Failure { .. }) {
             let mut contract_id_for_record: Option<crate::contract::ContractId> = None;
-            let manifest_contract_ids: Vec<crate::contract::ContractId> = payloads.iter()
-                .filter_map(|p| match p {
-                    Payload::ContractDelivery { contract_id, .. } => Some(*contract_id),
-                    _ => None,
-                })
-                .collect();
             if let Some(first) = manifest_contract_ids.first() {
                 contract_id_for_record = Some(*first);
             }
 
-            let severity = self.manifest_failure_severity(&manifest_contract_ids);
+            let severity = self.manifest_failure_severity(&manifest_contract_ids)
+                * crate::marketing::Marketing::failure_multiplier(press.as_ref().map(|p| p.kind), &self.balance.marketing);
             self.player_company.reputation.on_launch_failure(&self.balance.reputation, severity);
+            if let Some(evt) = self.crew_on_launch_failure(&crew, &inv_rocket.rocket_name, destination, abort_chance) {
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
 
             for cid in &manifest_contract_ids {
                 if let Some(ci) = self.player_company.active_contracts.iter()
@@ -229,6 +524,21 @@ impl GameState {
             };
             self.event_log.push(self.date, evt.clone());
             events.push(evt);
+            let evt = self.shift_morale(MoraleCause::LaunchFailure);
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+            if let Some(evt) = self.publicize_launch(press, false) {
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            events.extend(self.record_certification_flight(
+                inv_rocket.rocket_project_id, inv_rocket.revision, false,
+            ));
+            if crate::pad_failure::destroys_pad(
+                &sim.flaws_activated, design.total_propellant_mass_kg(), &self.balance.pad_failure,
+            ) {
+                events.extend(self.destroy_pad(&inv_rocket.rocket_name));
+            }
 
             let record = LaunchRecord {
                 launch_date: self.date,
@@ -238,12 +548,17 @@ impl GameState {
                 payload_kg: total_payload_kg,
                 outcome: sim.outcome,
                 flaws_activated: sim.flaws_activated,
+                transit_anomalies: Vec::new(),
+                stage_disposals: Vec::new(),
             };
             self.player_company.launch_history.push(record.clone());
             self.speed = GameSpeed::Paused;
             return Some((events, Some(record)));
         }
 
+        events.extend(self.recover_fairings(inv_rocket.rocket_project_id, &inv_rocket.rocket_name));
+        events.extend(self.recover_boosters(&inv_rocket, &sim.degraded_design));
+
         // Success or partial failure — create a flight in transit.
         // Refuse to launch if the active group's engines have no
         // electrical power available at takeoff (e.g. ion stage with no
@@ -278,6 +593,8 @@ impl GameState {
 
         let flight_id = FlightId(self.next_flight_id);
         self.next_flight_id += 1;
+        let (first_stage, drop_events) = self.drop_first_stage(flight_id, &sim.degraded_design);
+        events.extend(drop_events);
 
         // Instantiate a Rocket with per-stage propellant tracking
         let rocket_instance_id = RocketId(self.next_rocket_id);
@@ -290,6 +607,8 @@ impl GameState {
 
         let dest_display = crate::contract::destination_display_name(destination);
 
+        let launch_partial = matches!(sim.outcome, LaunchOutcome::PartialFailure { .. });
+        let insertion_error = crate::insertion::draw_error(insertion_dispersion, &mut self.seed.contingent_rng);
         let flight = Flight {
             id: flight_id,
             // launch_rocket is the player's pad; competitor flights
@@ -308,9 +627,15 @@ impl GameState {
             flaws_activated: sim.flaws_activated,
             launch_date: self.date,
             persist,
-            launch_partial: matches!(sim.outcome, LaunchOutcome::PartialFailure { .. }),
+            launch_partial,
             flaw_rolled_groups: sim.flaw_rolled_groups,
             reactor_flaws_rolled: false,
+            revision: inv_rocket.revision,
+            crew,
+            telemetry: Vec::new(),
+            stage_disposals: first_stage.into_iter().collect(),
+            docking_risk,
+            insertion_error,
         };
 
         self.active_flights.push(flight);
@@ -321,12 +646,167 @@ impl GameState {
         };
         self.event_log.push(self.date, evt.clone());
         events.push(evt);
+        if let Some(evt) = self.publicize_launch(press, !launch_partial) {
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
 
         self.speed = GameSpeed::Paused;
 
         Some((events, None))
     }
 
+    /// Fly recovery operations for every recoverable fairing on the
+    /// project's design. Each operation is paid up front; the catch is
+    /// rolled against the sea state at the recovery zone, and caught
+    /// fairings go into the manufacturing pool for later builds.
+    pub(super) fn recover_fairings(
+        &mut self,
+        project_id: crate::rocket_project::RocketProjectId,
+        rocket_name: &str,
+    ) -> Vec<GameEvent> {
+        use rand::Rng;
+
+        let fairings = self.player_company.rocket_projects.iter()
+            .find(|rp| rp.project_id == project_id)
+            .map_or(0, |rp| rp.design.stage_groups.iter().flatten()
+                .filter(|s| s.fairing.as_ref().is_some_and(|f| f.recover))
+                .count());
+        let cfg = &self.balance.recovery;
+        let mut events = Vec::new();
+        for _ in 0..fairings {
+            self.player_company.money -= cfg.fairing_recovery_cost;
+            self.player_company.budgets.record(Department::Testing, cfg.fairing_recovery_cost);
+            let rng = &mut self.seed.contingent_rng;
+            let sea_state = rng.gen_range(0..=cfg.max_sea_state);
+            let recovered = rng.gen::<f64>() < cfg.fairing_success_chance(sea_state);
+            if recovered {
+                self.player_company.manufacturing.add_recovered_fairing(project_id);
+            }
+            let evt = GameEvent::FairingRecovery {
+                rocket_name: rocket_name.to_string(),
+                sea_state,
+                recovered,
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        events
+    }
+
+    /// Fly home every first-group stage of `design` fitted with a landing
+    /// kit, onto whatever recovery asset is free and open; with none,
+    /// the booster is expended. Each attempt pays for the landing; a
+    /// booster that sticks it is refurbished and goes back into stock
+    /// under its serial, one flight older. Single-stage vehicles have no
+    /// booster to land.
+    pub(super) fn recover_boosters(
+        &mut self,
+        rocket: &crate::manufacturing::InventoryRocket,
+        design: &crate::rocket::RocketDesign,
+    ) -> Vec<GameEvent> {
+        use rand::Rng;
+
+        let mut events = Vec::new();
+        if design.stage_groups.len() < 2 {
+            return events;
+        }
+        let cfg = self.balance.booster_reuse.clone();
+        let rocket_dry_kg: f64 = design.stage_groups.iter().flatten().map(|s| s.dry_mass_kg()).sum();
+        let boosters = &design.stage_groups[0];
+        for (si, stage) in boosters.iter().enumerate().filter(|(_, s)| s.landing_kit) {
+            let slot = self.player_company.recovery_fleet.claim(
+                self.date, self.balance.recovery.max_sea_state,
+                &mut self.seed.contingent_rng, &self.balance.recovery_fleet,
+            );
+            let slot = match slot {
+                Ok(slot) => slot,
+                Err(reason) => {
+                    let evt = GameEvent::BoosterExpended {
+                        rocket_name: rocket.rocket_name.clone(),
+                        reason: reason.to_string(),
+                    };
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
+                    continue;
+                }
+            };
+            self.player_company.money -= cfg.landing_cost;
+            self.record_expense(cfg.landing_cost);
+            self.player_company.budgets.record(Department::Testing, cfg.landing_cost);
+            let flown = rocket.boosters.iter().find(|(i, _)| *i == si).map(|(_, life)| life.clone());
+            let landing_chance = cfg.landing_success_chance
+                - self.balance.recovery_fleet.drone_ship_sea_state_penalty * slot.sea_state as f64;
+            let landed = self.seed.contingent_rng.gen::<f64>() < landing_chance;
+            let evt = if landed {
+                let mut life = match flown {
+                    Some(mut life) => {
+                        life.flights += 1;
+                        life
+                    }
+                    None => {
+                        let serial = self.player_company.manufacturing.next_booster_serial();
+                        crate::booster_reuse::BoosterLife::first_landing(serial, &mut self.seed.contingent_rng, &cfg)
+                    }
+                };
+                life.refurbished_on.push(self.date);
+                let refurbish_cost = cfg.refurbish_cost_fraction * rocket.build_cost
+                    * stage.dry_mass_kg() / rocket_dry_kg.max(1.0);
+                self.player_company.money -= refurbish_cost;
+                self.record_expense(refurbish_cost);
+                self.player_company.budgets.record(Department::Manufacturing, refurbish_cost);
+                let label = if boosters.len() == 1 {
+                    "1".to_string()
+                } else {
+                    format!("1{}", (b'a' + si as u8) as char)
+                };
+                let evt = GameEvent::BoosterLanding {
+                    rocket_name: rocket.rocket_name.clone(),
+                    serial: life.serial,
+                    flights: life.flights,
+                    landed: true,
+                };
+                let mfg = &mut self.player_company.manufacturing;
+                let item_id = mfg.next_inventory_id();
+                mfg.inventory.stages.push(crate::manufacturing::InventoryStage {
+                    item_id,
+                    rocket_project_id: rocket.rocket_project_id,
+                    group_index: 0,
+                    stage_index: si,
+                    stage_name: format!("{} S{} B{}", rocket.rocket_name, label, life.serial),
+                    build_cost: refurbish_cost,
+                    built_on: Some(self.date),
+                    expired: false,
+                    booster: Some(life),
+                });
+                evt
+            } else {
+                GameEvent::BoosterLanding {
+                    rocket_name: rocket.rocket_name.clone(),
+                    serial: flown.as_ref().map_or(0, |life| life.serial),
+                    flights: flown.map_or(1, |life| life.flights + 1),
+                    landed: false,
+                }
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        events
+    }
+
+    /// Status of every player flight in transit, for tracking displays.
+    pub fn tracked_flights(&self) -> Vec<crate::flight::FlightStatusSummary> {
+        self.active_flights.iter()
+            .filter(|f| f.company == crate::flight::CompanyRef::Player)
+            .map(|f| f.status_summary())
+            .collect()
+    }
+
+    /// Route and progress of one active flight, for the orbit map.
+    pub fn flight_path(&self, id: crate::flight::FlightId) -> Option<crate::flight::FlightPath> {
+        self.active_flights.iter().find(|f| f.id == id).map(|f| f.path())
+    }
+
     /// Process daily flight advancement. Returns events generated.
     pub(super) fn advance_flights(&mut self) -> Vec<GameEvent> {
         use rand::Rng;
@@ -334,6 +814,7 @@ impl GameState {
         use crate::flaw::{FlawConsequence, FlawTrigger};
         use crate::engine_project::EngineSource;
         use crate::rocket_project::RocketProjectId;
+        use crate::flight::AnomalyResponse;
 
         let mut events = Vec::new();
         let mut arrived_indices = Vec::new();
@@ -349,6 +830,7 @@ impl GameState {
             activation_chance: f64,
             consequence: FlawConsequence,
             description: String,
+            subsystem: crate::flaw::Subsystem,
             source: EngineSource,
             flaw_index: usize,
         }
@@ -362,6 +844,7 @@ impl GameState {
                     activation_chance: flaw.activation_chance,
                     consequence: flaw.consequence.clone(),
                     description: flaw.description.clone(),
+                    subsystem: flaw.subsystem,
                     source,
                     flaw_index: fi,
                 });
@@ -376,6 +859,7 @@ impl GameState {
                     activation_chance: flaw.activation_chance,
                     consequence: flaw.consequence.clone(),
                     description: flaw.description.clone(),
+                    subsystem: flaw.subsystem,
                     source,
                     flaw_index: fi,
                 });
@@ -576,6 +1060,63 @@ impl GameState {
                 flight.reactor_flaws_rolled = true;
             }
 
+            // In-transit anomalies, one roll per day on a leg that takes
+            // days. Corrections burn the vehicle's reserve; a team
+            // response needs engineers (without any, the vehicle has to
+            // burn its way out at the worst-case cost). Keyed per flight
+            // and day so the daily rolls leave the contingent stream alone.
+            let on_long_leg = flight.route.get(flight.current_leg)
+                .is_some_and(|leg| leg.total_days() > 0);
+            if flight_lost.is_none() && on_long_leg {
+                let mut rng = self.seed.world_query(
+                    &format!("transit_anomaly_{}_{}", flight.id.0, self.date),
+                );
+                let rolled = crate::flight::roll_transit_anomaly(&mut rng, &self.balance.transit_anomaly);
+                if let Some((kind, mut response)) = rolled {
+                    let company = match flight.company {
+                        crate::flight::CompanyRef::Player => &mut self.player_company,
+                        crate::flight::CompanyRef::Competitor(ci) => &mut self.competitors[ci].company,
+                    };
+                    if let AnomalyResponse::TeamResponse { cost } = response {
+                        if company.teams.is_empty() {
+                            response = AnomalyResponse::Correction {
+                                delta_v: self.balance.transit_anomaly.correction_dv_max,
+                            };
+                        } else {
+                            company.money -= cost;
+                        }
+                    }
+                    if let AnomalyResponse::Correction { delta_v } = response {
+                        let burn = flight.rocket.burn_sequential(&flight.design, delta_v, 0.0);
+                        if burn.dv_achieved < delta_v * 0.95 {
+                            response = AnomalyResponse::Stranded;
+                        }
+                    }
+                    events.push(GameEvent::TransitAnomaly {
+                        rocket_name: flight.rocket_name.clone(),
+                        anomaly: kind.description().to_string(),
+                        response: response.to_string(),
+                    });
+                    flight.telemetry.push(crate::flight::TransitAnomaly {
+                        date: self.date,
+                        leg: flight.current_leg,
+                        kind,
+                        response: response.clone(),
+                    });
+                    match response {
+                        AnomalyResponse::Stranded => {
+                            flight.status = FlightStatus::Stranded;
+                            stranded_indices.push(i);
+                            continue;
+                        }
+                        AnomalyResponse::MissionLost => {
+                            flight_lost = Some(format!("in-transit {}", kind.description()));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
             // A catastrophic stage loss during the daily rolls destroys
             // the vehicle — fail it now rather than letting the downstream
             // dv check report it as merely stranded.
@@ -676,6 +1217,7 @@ impl GameState {
                                         flaw_description: flaw_ref.description.clone(),
                                         consequence: flaw_ref.consequence.clone(),
                                         engine_name: flaw_ref.engine_name.clone(),
+                                        subsystem: flaw_ref.subsystem,
                                     });
 
                                     // Apply consequence to the stage that has the flaw
@@ -737,6 +1279,15 @@ impl GameState {
                 flight.current_leg += 1;
                 if flight.current_leg < flight.route.len() {
                     flight.leg_days_remaining = flight.route[flight.current_leg].total_days();
+                    if flight.company == crate::flight::CompanyRef::Player {
+                        events.push(GameEvent::FlightLegComplete {
+                            rocket_name: flight.rocket_name.clone(),
+                            location: crate::contract::destination_display_name(&flight.route[flight.current_leg].from)
+                                .to_string(),
+                            leg: flight.current_leg as u32,
+                            legs: flight.route.len() as u32,
+                        });
+                    }
                 } else {
                     // All legs complete
                     flight.status = FlightStatus::Arrived;
@@ -746,6 +1297,7 @@ impl GameState {
         }
 
         // Apply flaw discoveries to engine/rocket projects
+        let mut shared_discoveries: Vec<(EngineId, String, String)> = Vec::new();
         for (source, flaw_index, _engine_name) in &flaw_discoveries {
             match source {
                 EngineSource::PlayerDesign(project_id) => {
@@ -759,6 +1311,7 @@ impl GameState {
                                 flaw_description: ep.flaws[*flaw_index].description.clone(),
                             };
                             events.push(evt);
+                            shared_discoveries.push((ep.design.id, ep.design.name.clone(), ep.flaws[*flaw_index].description.clone()));
                         }
                     }
                 }
@@ -766,13 +1319,17 @@ impl GameState {
                     if let Some(ce) = self.player_company.contracted_engines.iter_mut()
                         .find(|ce| ce.id == *ce_id)
                     {
-                        if *flaw_index < ce.flaws.len() {
+                        if *flaw_index < ce.flaws.len() && !ce.flaws[*flaw_index].discovered {
                             ce.flaws[*flaw_index].discovered = true;
+                            shared_discoveries.push((ce.design.id, ce.design.name.clone(), ce.flaws[*flaw_index].description.clone()));
                         }
                     }
                 }
             }
         }
+        for (engine_id, engine_name, flaw_description) in shared_discoveries {
+            events.extend(self.common_cause_flaw(engine_id, engine_name, flaw_description));
+        }
 
         // Apply rocket project endurance flaw discoveries
         for (project_id, flaw_index) in &rocket_flaw_discoveries {
@@ -831,6 +1388,12 @@ impl GameState {
                     events.extend(arrival_events);
                 }
                 FlightEnd::Stranded => {
+                    events.extend(self.record_certification_flight(
+                        flight.rocket_project_id, flight.revision, false,
+                    ));
+                    events.extend(self.lose_crew(
+                        &flight.crew, &flight.rocket_name, &flight.current_location, flight.launch_date,
+                    ));
                     let evt = GameEvent::SpacecraftStranded {
                         rocket_name: flight.rocket_name.clone(),
                         location,
@@ -853,12 +1416,19 @@ impl GameState {
                         .collect();
                     let severity = self.manifest_failure_severity(&manifest);
                     self.player_company.reputation.on_launch_failure(&self.balance.reputation, severity);
+                    events.extend(self.lose_crew(
+                        &flight.crew, &flight.rocket_name, &flight.current_location, flight.launch_date,
+                    ));
+                    events.extend(self.record_certification_flight(
+                        flight.rocket_project_id, flight.revision, false,
+                    ));
                     let evt = GameEvent::SpacecraftLost {
                         rocket_name: flight.rocket_name.clone(),
                         location,
                         reason,
                     };
                     events.push(evt);
+                    events.push(self.shift_morale(MoraleCause::LaunchFailure));
                 }
             }
         }
@@ -867,7 +1437,9 @@ impl GameState {
     }
 
     /// Resolve a flight that has arrived at its destination.
-    pub(super) fn resolve_arrived_flight(&mut self, flight: Flight) -> Vec<GameEvent> {
+    pub(super) fn resolve_arrived_flight(&mut self, mut flight: Flight) -> Vec<GameEvent> {
+        use rand::Rng;
+
         let mut events = Vec::new();
         let destination = flight.destination().to_string();
         let dest_display = crate::contract::destination_display_name(&destination);
@@ -893,8 +1465,31 @@ impl GameState {
             self.player_company.reputation.on_launch_partial_failure(
                 &self.balance.reputation, severity,
             );
+            events.push(self.shift_morale(MoraleCause::LaunchFailure));
         } else {
             self.player_company.reputation.on_launch_success(&self.balance.reputation);
+            events.push(self.shift_morale(MoraleCause::LaunchSuccess));
+        }
+
+        // Trim the insertion into the tightest tolerance on the manifest
+        // with whatever the upper stages have left; what they can't
+        // correct, the customers discount.
+        let tightest = flight.payloads.iter()
+            .filter_map(|p| match p {
+                Payload::ContractDelivery { contract_id, .. } => self.player_company.active_contracts.iter()
+                    .find(|c| c.id == *contract_id)
+                    .and_then(|c| c.insertion_tolerance),
+                _ => None,
+            })
+            .fold(f64::INFINITY, f64::min);
+        let mut insertion_error = flight.insertion_error;
+        if insertion_error > tightest {
+            let needed = insertion_error - tightest;
+            let dv = flight.rocket.burn_sequential(&flight.design, needed, 0.0).dv_achieved.clamp(0.0, needed);
+            if dv > 0.0 {
+                insertion_error -= dv;
+                events.push(GameEvent::InsertionCorrected { rocket_name: flight.rocket_name.clone(), dv });
+            }
         }
 
         // Process each payload. Spacecraft payloads marked for this
@@ -912,14 +1507,38 @@ impl GameState {
                         .position(|c| c.id == contract_id)
                     {
                         let contract = &self.player_company.active_contracts[ci];
-                        let payment = if is_partial {
-                            contract.payment * 0.5
-                        } else {
-                            contract.payment
-                        };
+                        let audited = contract.is_cost_plus()
+                            && self.seed.contingent_rng.gen::<f64>() < self.balance.markets.cost_plus_audit_chance;
+                        let settlement = contract.settle(is_partial, flight.launch_date, audited, &self.balance.markets);
+                        let withheld = settlement.payment
+                            * contract.insertion_penalty(insertion_error, &self.balance.markets);
+                        let payment = settlement.payment - withheld;
                         let contract_name = contract.name.clone();
+                        if withheld > 0.0 {
+                            events.push(GameEvent::InsertionOutOfTolerance {
+                                contract_name: contract_name.clone(),
+                                error: insertion_error,
+                                tolerance: contract.insertion_tolerance.unwrap_or_default(),
+                                withheld,
+                            });
+                        }
+                        if audited {
+                            events.push(GameEvent::ContractAudited {
+                                contract_name: contract_name.clone(),
+                                disallowed: settlement.disallowed,
+                            });
+                        }
+                        if settlement.fee_forfeited {
+                            events.push(GameEvent::ContractFeeWithheld { contract_name: contract_name.clone() });
+                        }
+                        self.demand.record_delivery(contract.market_id, &contract.destination);
                         self.player_company.money += payment;
                         self.record_income(payment);
+                        if let Some(rp) = self.player_company.rocket_projects.iter_mut()
+                            .find(|rp| rp.project_id == flight.rocket_project_id)
+                        {
+                            rp.flight_revenue += payment;
+                        }
                         self.player_company.reputation.on_contract_launch(&self.balance.reputation);
 
                         let pay_evt = GameEvent::PaymentReceived {
@@ -928,7 +1547,10 @@ impl GameState {
                         };
                         events.push(pay_evt);
 
-                        self.player_company.active_contracts.remove(ci);
+                        let contract = self.player_company.active_contracts.remove(ci);
+                        events.extend(self.start_science_mission(
+                            &contract, is_partial, Some(flight.rocket_project_id),
+                        ));
                     }
                 }
                 Payload::TestMass { .. } => {
@@ -937,6 +1559,29 @@ impl GameState {
                 Payload::Spacecraft { deploy_at: Some(ref d), .. } if *d == destination => {
                     deployed_spacecraft.push(payload);
                 }
+                Payload::IsruPlant { design, deploy_at } if deploy_at == destination => {
+                    // A plant joining an existing depot has to berth at it.
+                    let failed = if self.infrastructure_at(&destination).is_some() {
+                        let plants = ServiceTarget::IsruPlants { location: destination.clone() };
+                        self.fly_approach(design.name.clone(), plants, &destination, flight.docking_risk)
+                    } else {
+                        None
+                    };
+                    match failed {
+                        Some(failed) => events.push(failed),
+                        None => events.push(self.deploy_isru_plant(design, &destination)),
+                    }
+                }
+                Payload::Depot { design, flaws, deploy_at, .. } if deploy_at == destination => {
+                    events.push(self.deploy_depot(design, flaws, &destination));
+                }
+                Payload::ServicingKit(kit) if kit.target.location() == destination => {
+                    let payload_name = format!("Servicing kit for {}", kit.asset_name);
+                    match self.fly_approach(payload_name, kit.target.clone(), &destination, flight.docking_risk) {
+                        Some(failed) => events.push(failed),
+                        None => events.push(self.service_asset(kit)),
+                    }
+                }
                 other => {
                     // Spacecraft payload bound for some other waypoint —
                     // not implemented yet (Phase 2). For now keep it on the
@@ -966,12 +1611,29 @@ impl GameState {
             LaunchOutcome::Success
         };
 
+        events.extend(self.record_certification_flight(
+            flight.rocket_project_id, flight.revision, !is_partial,
+        ));
+        if !is_partial {
+            events.extend(self.record_program_deliveries(&destination, total_payload_kg));
+        }
+        events.extend(self.land_crew(&flight.crew, &flight.rocket_name, &destination, flight.launch_date));
+
         // Persist as spacecraft if requested
         let persist = flight.persist;
-        let rocket_instance = flight.rocket;
+        let mut rocket_instance = flight.rocket;
         let design_clone = flight.design;
         let rocket_name = flight.rocket_name;
         let dest_for_spacecraft = destination.clone();
+        let mut stage_disposals = flight.stage_disposals;
+        if !persist {
+            events.extend(self.dispose_final_stage(
+                flight.rocket_project_id, &design_clone, &mut rocket_instance, &destination, &mut stage_disposals,
+            ));
+            events.extend(self.salvage_residuals(
+                &design_clone, &mut rocket_instance, &rocket_name, &destination,
+            ));
+        }
 
         let record = LaunchRecord {
             launch_date: flight.launch_date,
@@ -981,6 +1643,8 @@ impl GameState {
             payload_kg: total_payload_kg,
             outcome,
             flaws_activated: flight.flaws_activated,
+            transit_anomalies: flight.telemetry,
+            stage_disposals,
         };
         self.player_company.launch_history.push(record);
 
@@ -995,13 +1659,14 @@ impl GameState {
                 location: dest_for_spacecraft,
                 rocket_project_id: flight.rocket_project_id,
                 payloads: remaining_payloads,
+                health: crate::servicing::FULL_HEALTH,
             });
         }
 
         // Detach Spacecraft payloads at this destination into the fleet.
         for payload in deployed_spacecraft {
             if let Payload::Spacecraft {
-                design, rocket, nested_payloads, rocket_project_id, name, ..
+                design, rocket, nested_payloads, rocket_project_id, name, health, ..
             } = payload {
                 let sc_id = SpacecraftId(self.next_rocket_id);
                 self.next_rocket_id += 1;
@@ -1018,6 +1683,7 @@ impl GameState {
                     location: destination.clone(),
                     rocket_project_id,
                     payloads: nested_payloads,
+                    health,
                 });
             }
         }
@@ -1025,6 +1691,15 @@ impl GameState {
         events
     }
 
+    /// Notice that a newly found engine flaw is shared by every design
+    /// flying that engine. None when only one design uses it.
+    fn common_cause_flaw(
+        &self, engine_id: EngineId, engine_name: String, flaw_description: String,
+    ) -> Option<GameEvent> {
+        let designs = self.player_company.designs_using_engine(engine_id);
+        (designs.len() > 1).then_some(GameEvent::CommonCauseFlaw { engine_name, flaw_description, designs })
+    }
+
     /// Send a spacecraft on a new flight to a destination. Any payloads
     /// the spacecraft is still carrying ride along; those whose `deploy_at`
     /// matches the destination will be detached on arrival (via the regular
@@ -1033,6 +1708,9 @@ impl GameState {
         if spacecraft_index >= self.spacecraft.len() {
             return;
         }
+        if self.spacecraft[spacecraft_index].health <= 0.0 {
+            return;
+        }
         let mut sc = self.spacecraft.remove(spacecraft_index);
         // Recompute payload mass from current carried payloads (live value
         // may differ from rocket.payload_mass_kg if payloads were detached
@@ -1099,6 +1777,12 @@ impl GameState {
             launch_partial: false,
             flaw_rolled_groups: std::collections::HashSet::new(),
             reactor_flaws_rolled: false,
+            revision: 0,
+            crew: Vec::new(),
+            telemetry: Vec::new(),
+            stage_disposals: Vec::new(),
+            docking_risk: 0.0,
+            insertion_error: 0.0,
         };
 
         self.active_flights.push(flight);
@@ -1137,6 +1821,7 @@ impl GameState {
             nested_payloads: small.payloads,
             rocket_project_id: small.rocket_project_id,
             name: small.name,
+            health: small.health,
         };
         self.spacecraft[adjusted_large].payloads.push(payload);
 
@@ -1165,7 +1850,7 @@ impl GameState {
         let carrier_name = self.spacecraft[carrier_idx].name.clone();
         let payload = self.spacecraft[carrier_idx].payloads.remove(payload_idx);
         let crate::flight::Payload::Spacecraft {
-            design, rocket, nested_payloads, rocket_project_id, name, ..
+            design, rocket, nested_payloads, rocket_project_id, name, health, ..
         } = payload else {
             return false; // unreachable given the matches! above
         };
@@ -1178,6 +1863,7 @@ impl GameState {
             location: location.clone(),
             rocket_project_id,
             payloads: nested_payloads,
+            health,
         });
 
         let evt = GameEvent::SpacecraftUndocked {
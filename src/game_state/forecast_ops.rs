@@ -0,0 +1,85 @@
+//! Projecting the player's cash (see [`crate::forecast`]).
+
+use crate::contract::{ContractId, ContractStatus};
+use crate::flight::{CompanyRef, Payload};
+use crate::forecast::{CashFlowItem, CashFlowKind, CashForecast};
+
+use super::*;
+
+impl GameState {
+    /// Expected money each day for the next `days` days, starting
+    /// tomorrow.
+    pub fn cash_forecast(&self, days: u32) -> CashForecast {
+        let company = &self.player_company;
+        let cfg = &self.balance.forecast;
+        let start = self.date.next_day();
+        let end = start.add_days(days);
+        let mut items = Vec::new();
+        let certain = |date, kind, description: &str, amount| CashFlowItem {
+            date, kind, description: description.to_string(), amount, probability: 1.0,
+        };
+
+        // Monthly bills at today's rates, on each first of the month.
+        let salaries = company.monthly_salary_cost(&self.balance.costs)
+            + company.astronauts.monthly_salary(&self.balance.astronauts);
+        let insurance = if company.launch_site.insured { self.balance.pad_failure.insurance_premium_per_month } else { 0.0 };
+        let overheads = company.monthly_storage_cost(&self.balance.storage)
+            + insurance
+            + company.recovery_fleet.monthly_upkeep(&self.balance.recovery_fleet);
+        let mut date = start;
+        while date < end {
+            if date.is_first_of_month() {
+                if salaries > 0.0 {
+                    items.push(certain(date, CashFlowKind::Salaries, "Payroll", -salaries));
+                }
+                if overheads > 0.0 {
+                    items.push(certain(date, CashFlowKind::Overheads, "Storage, insurance and upkeep", -overheads));
+                }
+            }
+            if let Some(lease) = company.launch_site.lease_on(date) {
+                items.push(certain(date, CashFlowKind::PadRent, &lease.lessee, lease.daily_rate));
+            }
+            date = date.next_day();
+        }
+
+        // Contracts already flying are paid on arrival.
+        let mut flying: Vec<ContractId> = Vec::new();
+        for flight in self.active_flights.iter().filter(|f| f.company == CompanyRef::Player) {
+            let arrival = start.add_days(flight.eta_days().saturating_sub(1));
+            for payload in &flight.payloads {
+                let Payload::ContractDelivery { contract_id, .. } = payload else { continue };
+                flying.push(*contract_id);
+                if let Some(c) = company.active_contracts.iter().find(|c| c.id == *contract_id) {
+                    items.push(CashFlowItem {
+                        date: arrival,
+                        kind: CashFlowKind::Delivery,
+                        description: c.name.clone(),
+                        amount: c.payment,
+                        probability: cfg.in_transit_success,
+                    });
+                }
+            }
+        }
+
+        // The rest are counted at their deadline, the latest they can
+        // pay, on the odds of the company's most reliable rocket.
+        let reliability = company.rocket_projects.iter()
+            .map(|rp| company.estimated_flaw_risk(&rp.design, Some(rp), &self.balance).iter()
+                .map(|r| 1.0 - r.risk)
+                .product::<f64>())
+            .fold(0.0, f64::max);
+        for c in company.active_contracts.iter()
+            .filter(|c| matches!(c.status, ContractStatus::Accepted) && !flying.contains(&c.id))
+        {
+            items.push(CashFlowItem {
+                date: c.deadline,
+                kind: CashFlowKind::Contract,
+                description: c.name.clone(),
+                amount: c.payment,
+                probability: reliability,
+            });
+        }
+
+        CashForecast::project(start, company.money, days, items)
+    }
+}
@@ -0,0 +1,113 @@
+//! Government relations: program announcements, commitments, the
+//! subsidy stream, and milestone verification.
+
+use crate::event::GameEvent;
+use crate::gov_program::{self, GovProgramId, ProgramStatus};
+
+use super::*;
+
+impl GameState {
+    /// Sign on to an announced program before its response window
+    /// closes. Returns None if there's no such open program.
+    pub fn commit_gov_program(&mut self, id: GovProgramId) -> Option<GameEvent> {
+        let today = self.date;
+        let program = self.gov_programs.iter_mut().find(|p| p.id == id)?;
+        match program.status {
+            ProgramStatus::Announced { respond_by } if today <= respond_by => {}
+            _ => return None,
+        }
+        program.status = ProgramStatus::Committed;
+        let evt = GameEvent::GovProgramCommitted { program: program.name.clone() };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Monthly: roll a new announcement, drop announcements nobody
+    /// answered, and pay the subsidy on running programs.
+    pub(super) fn tick_gov_programs(&mut self, events: &mut Vec<GameEvent>) {
+        let today = self.date;
+        self.gov_programs.retain(|p| match p.status {
+            ProgramStatus::Announced { respond_by } => today <= respond_by,
+            _ => true,
+        });
+
+        let query = format!("gov_programs_{}_{}", today.year, today.month);
+        let mut rng = self.seed.world_query(&query);
+        let live: Vec<&str> = self.gov_programs.iter()
+            .filter(|p| p.is_live())
+            .map(|p| p.name.as_str())
+            .collect();
+        if let Some(program) = gov_program::announce(
+            &mut rng, &live, &mut self.next_gov_program_id, today, &self.balance.gov_programs,
+        ) {
+            let evt = GameEvent::GovProgramAnnounced {
+                program: program.name.clone(),
+                monthly_subsidy: program.monthly_subsidy,
+                term_months: program.term_months,
+                milestones: program.milestones.len() as u32,
+                respond_by: match program.status {
+                    ProgramStatus::Announced { respond_by } => respond_by,
+                    _ => today,
+                },
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+            self.gov_programs.push(program);
+        }
+
+        let mut paid: Vec<(String, f64)> = Vec::new();
+        for program in &mut self.gov_programs {
+            if let Some(amount) = program.subsidy_due() {
+                program.pay_subsidy(amount);
+                paid.push((program.name.clone(), amount));
+            }
+        }
+        for (program, amount) in paid {
+            self.player_company.money += amount;
+            self.record_income(amount);
+            let evt = GameEvent::GovSubsidyReceived { program, amount };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Daily: fail committed programs with a milestone past its
+    /// deadline and claw back the subsidy.
+    pub(super) fn check_gov_program_deadlines(&mut self, events: &mut Vec<GameEvent>) {
+        let cfg = self.balance.gov_programs.clone();
+        let mut failed: Vec<(String, f64)> = Vec::new();
+        for program in &mut self.gov_programs {
+            if program.missed_milestone(self.date).is_some() {
+                failed.push((program.name.clone(), program.clawback(&cfg)));
+                program.status = ProgramStatus::Failed;
+            }
+        }
+        for (program, clawback) in failed {
+            self.player_company.money -= clawback;
+            self.record_expense(clawback);
+            let evt = GameEvent::GovProgramFailed { program, clawback };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Milestone verification hook: a successful flight delivered
+    /// `payload_kg` to `destination`. Returns the milestone events.
+    pub(super) fn record_program_deliveries(&mut self, destination: &str, payload_kg: f64) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        for program in &mut self.gov_programs {
+            let was_committed = program.status == ProgramStatus::Committed;
+            for i in program.record_delivery(destination, payload_kg, self.date) {
+                let destination = crate::contract::destination_display_name(&program.milestones[i].destination);
+                events.push(GameEvent::GovMilestoneMet {
+                    program: program.name.clone(),
+                    destination: destination.to_string(),
+                });
+            }
+            if was_committed && program.status == ProgramStatus::Completed {
+                events.push(GameEvent::GovProgramCompleted { program: program.name.clone() });
+            }
+        }
+        events
+    }
+}
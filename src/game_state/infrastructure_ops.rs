@@ -0,0 +1,318 @@
+//! Off-Earth infrastructure: launching ISRU plants and player-designed
+//! depots, setting them down, the daily production tick, and refueling
+//! spacecraft from depots.
+
+use rand::Rng;
+
+use crate::budget::Department;
+use crate::event::GameEvent;
+use crate::flaw::{Flaw, FlawConsequence};
+use crate::flight::Payload;
+use crate::infrastructure::{
+    Depot, DepotDesign, DepotReport, InboundFlight, InfrastructureError, IsruPlant, IsruPlantDesign,
+    LocationInfrastructure, TransferDirection, TransferOrder,
+};
+use crate::launch::LaunchRecord;
+use crate::manufacturing::InventoryItemId;
+
+use super::*;
+
+impl GameState {
+    /// The infrastructure built up at `location`, if any.
+    pub fn infrastructure_at(&self, location: &str) -> Option<&LocationInfrastructure> {
+        self.infrastructure.iter().find(|i| i.location == location)
+    }
+
+    /// Buy a stock ISRU plant and launch it on the inventory rocket
+    /// `rocket_item_id` to `site`, where it's set down on arrival.
+    pub fn launch_isru_plant(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        site: &str,
+    ) -> Result<(Vec<GameEvent>, Option<LaunchRecord>), InfrastructureError> {
+        let cfg = &self.balance.isru;
+        if cfg.products_at(site).is_none() {
+            return Err(InfrastructureError::NotAnIsruSite(site.to_string()));
+        }
+        self.check_infrastructure_launch(rocket_item_id, site)?;
+        let plant_number = self.infrastructure.iter().map(|i| i.isru_plants.len()).sum::<usize>() + 1;
+        let design = IsruPlantDesign::sized(
+            format!("ISRU Plant {}", plant_number), cfg.plant_output_kg_per_day, cfg,
+        );
+        if self.player_company.money < design.unit_cost {
+            return Err(InfrastructureError::CantAfford { cost: design.unit_cost });
+        }
+
+        self.player_company.money -= design.unit_cost;
+        self.record_expense(design.unit_cost);
+        self.player_company.budgets.record(Department::Manufacturing, design.unit_cost);
+        let payload = Payload::IsruPlant { design, deploy_at: site.to_string() };
+        self.launch_rocket(rocket_item_id, site, vec![payload], false)
+            .ok_or(InfrastructureError::RocketMissing)
+    }
+
+    /// Build a depot to the design at `project_index` and launch it on
+    /// the inventory rocket `rocket_item_id` to `location`, where it
+    /// takes over from the depot there on arrival.
+    pub fn launch_depot(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        project_index: usize,
+        location: &str,
+    ) -> Result<(Vec<GameEvent>, Option<LaunchRecord>), InfrastructureError> {
+        let project = self.player_company.depot_projects.get(project_index)
+            .filter(|p| p.is_flight_ready())
+            .ok_or(InfrastructureError::DepotNotReady)?;
+        self.check_infrastructure_launch(rocket_item_id, location)?;
+        let cfg = &self.balance.depot_design;
+        let cost = project.design.unit_cost(cfg);
+        if self.player_company.money < cost {
+            return Err(InfrastructureError::CantAffordDepot { cost });
+        }
+        let payload = Payload::Depot {
+            design: project.design.clone(),
+            flaws: project.flaws.clone(),
+            mass_kg: project.design.mass_kg(cfg),
+            deploy_at: location.to_string(),
+        };
+
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.budgets.record(Department::Manufacturing, cost);
+        self.player_company.depot_projects[project_index].launched += 1;
+        self.launch_rocket(rocket_item_id, location, vec![payload], false)
+            .ok_or(InfrastructureError::RocketMissing)
+    }
+
+    /// The rocket is in inventory and the pad and licenses allow a
+    /// flight to `site`.
+    fn check_infrastructure_launch(&self, rocket_item_id: InventoryItemId, site: &str) -> Result<(), InfrastructureError> {
+        if !self.player_company.manufacturing.inventory.rockets.iter().any(|r| r.item_id == rocket_item_id) {
+            return Err(InfrastructureError::RocketMissing);
+        }
+        let pad = &self.player_company.launch_site;
+        if let Some(lease) = pad.lease_on(self.date) {
+            return Err(InfrastructureError::PadLeased {
+                lessee: lease.lessee.clone(),
+                free_on: pad.next_free_day(self.date),
+            });
+        }
+        self.player_company.compliance
+            .check(site, self.date, pad, &self.balance.regulation)
+            .map_err(InfrastructureError::NotCompliant)
+    }
+
+    /// Put a delivered depot in service at `location`. The old depot's
+    /// stores are pumped across; whatever doesn't fit is lost.
+    pub(super) fn deploy_depot(&mut self, design: DepotDesign, flaws: Vec<Flaw>, location: &str) -> GameEvent {
+        let idx = self.infrastructure_index(location);
+        let evt = GameEvent::DepotDeployed { depot_name: design.name.clone(), location: location.to_string() };
+        let depot = &mut self.infrastructure[idx].depot;
+        let stores = std::mem::take(&mut depot.stores);
+        *depot = Depot { design, stores: Vec::new(), flaws };
+        for (propellant, kg) in stores {
+            depot.add(propellant, kg);
+        }
+        evt
+    }
+
+    /// Index of the infrastructure at `location`, starting it with a
+    /// stock depot if nothing's there yet.
+    fn infrastructure_index(&mut self, location: &str) -> usize {
+        match self.infrastructure.iter().position(|i| i.location == location) {
+            Some(idx) => idx,
+            None => {
+                self.infrastructure.push(LocationInfrastructure::new(location, &self.balance.isru));
+                self.infrastructure.len() - 1
+            }
+        }
+    }
+
+    /// Set a delivered plant down at `location`.
+    pub(super) fn deploy_isru_plant(&mut self, design: IsruPlantDesign, location: &str) -> GameEvent {
+        let idx = self.infrastructure_index(location);
+        let evt = GameEvent::IsruPlantDeployed { plant_name: design.name.clone(), location: location.to_string() };
+        self.infrastructure[idx].isru_plants.push(IsruPlant {
+            design, deployed_on: self.date, produced_kg: 0.0, health: crate::servicing::FULL_HEALTH,
+        });
+        evt
+    }
+
+    /// Pump what's left in an expended carrier's tanks into the depot at
+    /// `location` before it's discarded. None when there's no depot or
+    /// nothing it could take.
+    pub(super) fn salvage_residuals(
+        &mut self,
+        design: &crate::rocket::RocketDesign,
+        rocket: &mut crate::rocket::Rocket,
+        rocket_name: &str,
+        location: &str,
+    ) -> Option<GameEvent> {
+        let infra = self.infrastructure.iter_mut().find(|i| i.location == location)?;
+        let moved = infra.depot.offload(design, rocket);
+        (moved > 0.0).then(|| GameEvent::PropellantSalvaged {
+            rocket_name: rocket_name.to_string(),
+            location: location.to_string(),
+            propellant_kg: moved,
+        })
+    }
+
+    /// A day of ISRU production and boiloff everywhere. Reports each
+    /// depot the day it fills (but not when it's only topping back up
+    /// after a day's boiloff).
+    pub(super) fn tick_infrastructure(&mut self, events: &mut Vec<GameEvent>) {
+        for infra in &mut self.infrastructure {
+            let had_room = infra.depot.free_kg() >= 0.01 * infra.depot.capacity_kg();
+            let stored = infra.produce_day(&self.balance.isru);
+            if had_room && stored > 0.0 && infra.depot.free_kg() <= 0.0 {
+                let evt = GameEvent::DepotFull { location: infra.location.clone() };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            infra.boil_off_day(&self.balance.isru);
+        }
+        self.roll_depot_flaws(events);
+        self.run_transfer_orders(events);
+    }
+
+    /// Roll each depot's flaws for the day. One that bites spills
+    /// propellant — a share of it, the cryogenics, or the lot, by its
+    /// consequence — and shows up as found on the design it came from.
+    fn roll_depot_flaws(&mut self, events: &mut Vec<GameEvent>) {
+        let rng = &mut self.seed.contingent_rng;
+        let mut revealed = Vec::new();
+        for infra in &mut self.infrastructure {
+            let mut bitten = Vec::new();
+            for flaw in &mut infra.depot.flaws {
+                if rng.gen::<f64>() < flaw.daily_rate() {
+                    flaw.discovered = true;
+                    bitten.push(flaw.clone());
+                }
+            }
+            for flaw in bitten {
+                revealed.push(flaw.id);
+                let lost_kg = match flaw.consequence {
+                    FlawConsequence::PerformanceDegradation(fraction) => infra.depot.spill(fraction, false),
+                    FlawConsequence::EngineLoss => infra.depot.spill(1.0, true),
+                    FlawConsequence::StageLoss => infra.depot.spill(1.0, false),
+                };
+                let evt = GameEvent::DepotFlawActivated {
+                    location: infra.location.clone(),
+                    flaw_description: flaw.description,
+                    lost_kg,
+                };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+        for id in revealed {
+            for project in &mut self.player_company.depot_projects {
+                project.reveal_flaw(id);
+            }
+        }
+    }
+
+    /// Every depot's stock, rates, and inbound traffic.
+    pub fn depot_reports(&self) -> Vec<DepotReport> {
+        self.infrastructure.iter().map(|infra| {
+            let mut inbound: Vec<InboundFlight> = self.active_flights.iter()
+                .filter(|f| f.company == crate::flight::CompanyRef::Player && f.destination() == infra.location)
+                .map(|f| InboundFlight {
+                    flight_id: f.id,
+                    rocket_name: f.rocket_name.clone(),
+                    eta_days: f.eta_days(),
+                    propellant_kg: crate::infrastructure::transferable_kg(&f.design, &f.rocket),
+                })
+                .collect();
+            inbound.sort_by_key(|f| f.eta_days);
+            let transfer_orders = self.transfer_orders.iter()
+                .filter(|o| self.spacecraft.iter()
+                    .any(|sc| sc.id == o.spacecraft_id && sc.location == infra.location))
+                .cloned()
+                .collect();
+            DepotReport {
+                location: infra.location.clone(),
+                capacity_kg: infra.depot.capacity_kg(),
+                total_kg: infra.depot.total_kg(),
+                inventories: infra.depot.stores.clone(),
+                fill_rate_kg_per_day: infra.last_produced_kg,
+                boiloff_kg_last_day: infra.last_boiloff_kg,
+                power: infra.power_budget(&self.balance.isru),
+                inbound,
+                transfer_orders,
+            }
+        }).collect()
+    }
+
+    /// Set (or replace) the standing transfer order for a spacecraft.
+    pub fn schedule_transfer(
+        &mut self,
+        spacecraft_id: SpacecraftId,
+        direction: TransferDirection,
+    ) -> Result<(), InfrastructureError> {
+        if !self.spacecraft.iter().any(|sc| sc.id == spacecraft_id) {
+            return Err(InfrastructureError::RocketMissing);
+        }
+        self.transfer_orders.retain(|o| o.spacecraft_id != spacecraft_id);
+        self.transfer_orders.push(TransferOrder { spacecraft_id, direction });
+        Ok(())
+    }
+
+    pub fn cancel_transfer(&mut self, spacecraft_id: SpacecraftId) -> Option<TransferOrder> {
+        let idx = self.transfer_orders.iter().position(|o| o.spacecraft_id == spacecraft_id)?;
+        Some(self.transfer_orders.remove(idx))
+    }
+
+    /// Run each standing order whose spacecraft is parked at a depot.
+    /// Orders for spacecraft away on a flight wait for them to return,
+    /// and a depot serves only as many a day as it has docking ports.
+    fn run_transfer_orders(&mut self, events: &mut Vec<GameEvent>) {
+        let mut berthed = vec![0u32; self.infrastructure.len()];
+        for order in &self.transfer_orders {
+            let Some(sc) = self.spacecraft.iter_mut().find(|sc| sc.id == order.spacecraft_id) else {
+                continue;
+            };
+            let Some(idx) = self.infrastructure.iter().position(|i| i.location == sc.location) else {
+                continue;
+            };
+            let infra = &mut self.infrastructure[idx];
+            if berthed[idx] >= infra.depot.design.docking_ports {
+                continue;
+            }
+            berthed[idx] += 1;
+            let moved = match order.direction {
+                TransferDirection::Fill => infra.depot.refuel(&sc.design, &mut sc.rocket),
+                TransferDirection::Offload => infra.depot.offload(&sc.design, &mut sc.rocket),
+            };
+            if moved > 0.0 {
+                let evt = GameEvent::PropellantTransferred {
+                    spacecraft_name: sc.name.clone(),
+                    location: sc.location.clone(),
+                    propellant_kg: moved,
+                    to_depot: order.direction == TransferDirection::Offload,
+                };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+    }
+
+    /// Top up a spacecraft's tanks from the depot where it's parked.
+    pub fn refuel_spacecraft(&mut self, spacecraft_index: usize) -> Result<GameEvent, InfrastructureError> {
+        let sc = self.spacecraft.get_mut(spacecraft_index).ok_or(InfrastructureError::RocketMissing)?;
+        let infra = self.infrastructure.iter_mut()
+            .find(|i| i.location == sc.location)
+            .ok_or_else(|| InfrastructureError::NoDepot(sc.location.clone()))?;
+        let loaded = infra.depot.refuel(&sc.design, &mut sc.rocket);
+        if loaded <= 0.0 {
+            return Err(InfrastructureError::NothingToLoad);
+        }
+        let evt = GameEvent::SpacecraftRefueled {
+            spacecraft_name: sc.name.clone(),
+            location: sc.location.clone(),
+            propellant_kg: loaded,
+        };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+}
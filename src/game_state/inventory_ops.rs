@@ -0,0 +1,38 @@
+//! Disposing of stored hardware: scrapping items outright and
+//! stripping engines back out of assembled rockets.
+
+use crate::event::GameEvent;
+use crate::manufacturing::InventoryItemId;
+
+use super::*;
+
+impl GameState {
+    /// Scrap a stored engine, stage or rocket, crediting the salvage.
+    /// None if no such item is stored.
+    pub fn scrap_inventory_item(&mut self, item_id: InventoryItemId) -> Option<GameEvent> {
+        let (item_name, recovered) = self.player_company
+            .scrap_inventory_item(item_id, &self.balance.storage)?;
+        self.credit_salvage(recovered);
+        let evt = GameEvent::InventoryScrapped { item_name, recovered };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Take a stored rocket apart, returning its engines to inventory
+    /// and scrapping the rest. None if no such rocket is stored.
+    pub fn strip_rocket_engines(&mut self, item_id: InventoryItemId) -> Option<GameEvent> {
+        let (rocket_name, engines, recovered) = self.player_company
+            .strip_rocket_engines(item_id, &self.balance.storage)?;
+        self.credit_salvage(recovered);
+        let evt = GameEvent::EnginesStripped { rocket_name, engines, recovered };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    fn credit_salvage(&mut self, amount: f64) {
+        if amount > 0.0 {
+            self.player_company.money += amount;
+            self.record_income(amount);
+        }
+    }
+}
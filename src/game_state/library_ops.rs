@@ -0,0 +1,75 @@
+//! Freezing the player's designs into the company library (see
+//! [`crate::library`]) and, in sandbox games, taking them back out.
+
+use crate::engine_project::EngineDesignStatus;
+use crate::event::GameEvent;
+use crate::library::{ArchivedDesign, LibraryEntry};
+use crate::rocket_project::RocketDesignStatus;
+use crate::sandbox::SandboxError;
+
+use super::*;
+
+impl GameState {
+    /// The current revision of engine project `index` as a library
+    /// entry. None until its design work is done.
+    pub fn archive_engine(&self, index: usize) -> Option<LibraryEntry> {
+        let ep = self.player_company.engine_projects.get(index)?;
+        if matches!(ep.status, EngineDesignStatus::Proposed { .. } | EngineDesignStatus::InDesign { .. }) {
+            return None;
+        }
+        Some(LibraryEntry {
+            name: ep.design.name.clone(),
+            revision: ep.revision,
+            company: self.player_company.name.clone(),
+            archived_on: self.date,
+            known_flaws: ep.flaws.iter().filter(|f| f.discovered).count() as u32,
+            testing_work: ep.cumulative_testing_work,
+            design: ArchivedDesign::Engine {
+                cycle: ep.design.cycle,
+                preset: ep.preset,
+                thrust_n: ep.design.thrust_n,
+                isp_s: ep.design.isp_s,
+                mass_kg: ep.design.mass_kg,
+            },
+        })
+    }
+
+    /// The current revision of rocket project `index` as a library
+    /// entry, with its design file. None until its design work is done
+    /// or if it flies an engine that can't be exported.
+    pub fn archive_rocket(&self, index: usize) -> Option<LibraryEntry> {
+        let rp = self.player_company.rocket_projects.get(index)?;
+        if matches!(rp.status, RocketDesignStatus::InDesign { .. }) {
+            return None;
+        }
+        let file = self.player_company.export_design(index)?;
+        Some(LibraryEntry {
+            name: rp.design.name.clone(),
+            revision: rp.revision,
+            company: self.player_company.name.clone(),
+            archived_on: self.date,
+            known_flaws: rp.flaws.iter().filter(|f| f.discovered).count() as u32,
+            testing_work: rp.cumulative_testing_work,
+            design: ArchivedDesign::Rocket {
+                stages: rp.design.stage_groups.iter().map(|g| g.len()).sum(),
+                liftoff_kg: rp.design.total_mass_kg(),
+                flights: rp.flights,
+                flight_revenue: rp.flight_revenue,
+                certified: rp.is_certified(),
+                file,
+            },
+        })
+    }
+
+    /// Bring an archived rocket into this game as a new project, the
+    /// same way a design file is imported. Engines are kept for their
+    /// stats only.
+    pub fn sandbox_import_archived(&mut self, entry: &LibraryEntry) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        let ArchivedDesign::Rocket { file, .. } = &entry.design else {
+            return Err(SandboxError::StatsOnly);
+        };
+        self.import_design(&file.to_json()).map_err(SandboxError::Import)?;
+        Ok(self.record_sandbox_edit(format!("imported {} Rev {} from the library", entry.name, entry.revision)))
+    }
+}
@@ -1,14 +1,27 @@
 //! Markets and the award pipeline: contract issue, the standing
 //! bid-rule engine, sealed-bid resolution, competitor ticks and
-//! abstract launches, expiry, and seed-driven market/tech events.
+//! abstract launches, expiry, seed-driven market/tech events, and
+//! pad leasing to outside operators.
 
 
+use crate::bidding::{self, Bidder, SealedBid};
+use crate::budget::Department;
 use crate::contract::{self};
 use crate::event::GameEvent;
+use crate::launch_site::{PadLease, PadLeaseError, RelocationError};
+use crate::market_trend::{DestinationOutlook, MarketReport, MarketTrend};
 use crate::rocket_project::RocketProjectId;
 
 use super::*;
 
+/// Outside operators that rent pad time (alongside any competitors).
+const PAD_LESSEES: &[&str] = &[
+    "Suborbital Research Corp",
+    "Orbital Sounding Ltd",
+    "University Rocketry Consortium",
+    "Meridian Launch Services",
+];
+
 impl GameState {
     /// Issue mission contracts for won campaigns whose next issue date
     /// has arrived, and retire campaigns that have issued their last
@@ -201,14 +214,20 @@ impl GameState {
 
     /// Place (or revise) the player's sealed block bid — one price per
     /// mission, applied to the whole block — on a soliciting campaign.
-    /// Returns None if the campaign is unknown, already resolved, or
-    /// the bid is not positive.
+    /// Returns None if the campaign is unknown, already resolved, the
+    /// bid is not positive, or its market needs a certified design.
     pub fn place_campaign_bid(
         &mut self, campaign_id: contract::CampaignId, bid: f64,
     ) -> Option<GameEvent> {
         if bid <= 0.0 {
             return None;
         }
+        let market_id = self.active_campaigns.iter()
+            .find(|c| c.id == campaign_id)?
+            .market_id;
+        if self.certification_blocks_market(market_id) {
+            return None;
+        }
         let campaign = self.active_campaigns.iter_mut()
             .find(|c| c.id == campaign_id)?;
         let contract::CampaignStatus::Soliciting { player_bid, .. } = &mut campaign.status
@@ -249,39 +268,15 @@ impl GameState {
             let market = self.markets.iter()
                 .find(|m| m.id == self.active_campaigns[i].market_id)
                 .cloned();
-            let rep_scale = self.balance.markets.rep_scale;
-            let score = |bid: f64, rep: f64| {
-                market.as_ref().map_or(0.0, |m| {
-                    contract::bid_score(bid, ceiling, rep, m, rep_scale)
+            let bids = {
+                let campaign = &self.active_campaigns[i];
+                self.sealed_bids(player_bid, market.as_ref(), |comp| {
+                    comp.compute_block_bid(campaign, &self.balance, &self.seed)
                 })
             };
-
-            // (bidder, bid): bidder None = player, Some(ci) = competitor.
-            // Player first + strict `>` replacement = ties break toward
-            // the player, exactly like resolve_bids.
-            let mut winner: Option<(Option<usize>, f64)> = None;
-            let mut best_score = f64::NEG_INFINITY;
-            let mut consider = |who: Option<usize>, bid: f64, s: f64| {
-                if s > best_score {
-                    best_score = s;
-                    winner = Some((who, bid));
-                }
-            };
-            if let Some(bid) = player_bid {
-                if bid <= ceiling {
-                    consider(None, bid, score(bid, self.player_company.reputation.total()));
-                }
-            }
-            {
-                let campaign = &self.active_campaigns[i];
-                for (ci, comp) in self.competitors.iter().enumerate() {
-                    if let Some(bid) = comp.compute_block_bid(campaign, &self.balance, &self.seed) {
-                        if bid <= ceiling {
-                            consider(Some(ci), bid, score(bid, comp.company.reputation.total()));
-                        }
-                    }
-                }
-            }
+            let winner = bidding::resolve(
+                &bids, ceiling, market.as_ref(), self.balance.markets.rep_scale, &self.balance.bidding,
+            );
 
             // Block awards land in the same price-discovery history
             // as single solicitations, tagged with the mission count
@@ -299,8 +294,8 @@ impl GameState {
                         outcome,
                     }
                 };
-            match winner {
-                Some((None, bid)) => {
+            match winner.map(|a| (a.bidder, a.amount)) {
+                Some((Bidder::Player, bid)) => {
                     let name = self.player_company.name.clone();
                     let campaign = &mut self.active_campaigns[i];
                     campaign.payment_per_mission = bid;
@@ -328,7 +323,7 @@ impl GameState {
                     self.speed = GameSpeed::Paused;
                     i += 1;
                 }
-                Some((Some(ci), bid)) => {
+                Some((Bidder::Competitor(ci), bid)) => {
                     let company_name = self.competitors[ci].company.name.clone();
                     let campaign = &mut self.active_campaigns[i];
                     campaign.payment_per_mission = bid;
@@ -355,6 +350,10 @@ impl GameState {
                     self.push_award_record(record);
                     self.event_log.push(self.date, evt.clone());
                     events.push(evt);
+                    if player_bid.is_some() {
+                        let program = self.active_campaigns[i].name.clone();
+                        self.write_off_proposal(program, events);
+                    }
                     i += 1;
                 }
                 None => {
@@ -372,6 +371,7 @@ impl GameState {
                         };
                         self.event_log.push(self.date, evt.clone());
                         events.push(evt);
+                        self.write_off_proposal(campaign.name, events);
                     }
                     // No bid at all: lapses without ceremony.
                 }
@@ -381,7 +381,7 @@ impl GameState {
 
     /// Resolve solicitations whose bid window has closed. With the
     /// player as sole bidder (M3 Task 1), a bid wins iff it fits the
-    /// customer's hidden budget; `contract::bid_score` ranks bidders
+    /// customer's hidden budget; `bidding::resolve` ranks bidders
     /// once DinoSoar enters (Task 2). Unbid solicitations lapse
     /// quietly.
     /// The standing-rule auto-bidder (M3 Task 3). For each unbid
@@ -423,14 +423,15 @@ impl GameState {
     ) -> (Vec<RocketProjectId>, Option<f64>) {
         let mut capable_projects: Vec<RocketProjectId> = Vec::new();
         let mut best_cost: Option<f64> = None;
+        let plane_change_dv = self.plane_change_dv(destination);
         for rp in &self.player_company.rocket_projects {
             if !matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }) {
                 continue;
             }
             let cap = *self.payload_capability_cache
                 .entry((rp.project_id, rp.revision, destination.to_string()))
-                .or_insert_with(|| crate::rocket_project::max_payload_to(
-                    &rp.design, "earth_surface", destination,
+                .or_insert_with(|| crate::rocket_project::max_payload_with_extra_dv(
+                    &rp.design, "earth_surface", destination, plane_change_dv,
                 ));
             if payload_kg > cap * BID_PAYLOAD_MARGIN {
                 continue;
@@ -449,6 +450,50 @@ impl GameState {
         (capable_projects, best_cost)
     }
 
+    /// Where customer payloads and prices will have drifted
+    /// `years_ahead` from today.
+    pub fn market_trend(&self, years_ahead: f64) -> MarketTrend {
+        let years = self.elapsed_days() as f64 / 365.25 + years_ahead;
+        MarketTrend::at(years, &self.balance.market_trend)
+    }
+
+    /// Each active market destination today and `report_years` out,
+    /// against the most the company's flight-ready designs can lift
+    /// there.
+    pub fn market_report(&self) -> MarketReport {
+        let years_ahead = self.balance.market_trend.report_years;
+        let (now, then) = (self.market_trend(0.0), self.market_trend(years_ahead as f64));
+        let mut capability: HashMap<&str, f64> = HashMap::new();
+        let mut outlooks = Vec::new();
+        for market in self.markets.iter().filter(|m| m.active) {
+            for dest in &market.destinations {
+                let capability_kg = *capability.entry(&dest.location_id).or_insert_with(|| {
+                    self.player_company.rocket_projects.iter()
+                        .filter(|rp| matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }))
+                        .map(|rp| self.max_payload_from_pad(&rp.design, &dest.location_id))
+                        .fold(0.0, f64::max)
+                });
+                outlooks.push(DestinationOutlook {
+                    market_id: market.id,
+                    market_name: market.name.clone(),
+                    location_id: dest.location_id.clone(),
+                    display_name: dest.display_name.clone(),
+                    payload_now_kg: now.payload_range(dest),
+                    payload_then_kg: then.payload_range(dest),
+                    rate_per_kg_now: now.rate_per_kg(dest),
+                    rate_per_kg_then: then.rate_per_kg(dest),
+                    capability_kg,
+                    demand: self.demand.outlook(
+                        market.id, &dest.location_id,
+                        market.destination_volume(&dest.location_id, self.economy.modifier, self.date),
+                        &self.balance.demand,
+                    ),
+                });
+            }
+        }
+        MarketReport { years_ahead, outlooks, world: self.world_market.prices }
+    }
+
     pub(super) fn run_bid_rules(&mut self, events: &mut Vec<GameEvent>) {
         if self.player_company.bid_rules.is_empty() {
             return;
@@ -516,41 +561,13 @@ impl GameState {
             let mut c = self.available_contracts.remove(i);
 
             let market = self.markets.iter().find(|m| m.id == c.market_id).cloned();
-            let rep_scale = self.balance.markets.rep_scale;
-            let score = |bid: f64, rep: f64| {
-                market.as_ref().map_or(0.0, |m| {
-                    contract::bid_score(bid, c.budget_ceiling, rep, m, rep_scale)
-                })
-            };
-
-            // Gather sealed bids: the player first, then each
-            // competitor's scripted price. Over-ceiling bids never
-            // score. Ties break toward the earlier entry, so an
-            // exactly-matched player never loses to a coin flip.
-            // (bidder, bid): bidder None = player, Some(ci) = competitor.
-            let mut winner: Option<(Option<usize>, f64)> = None;
-            let mut best_score = f64::NEG_INFINITY;
-            let mut consider = |who: Option<usize>, bid: f64, s: f64| {
-                if s > best_score {
-                    best_score = s;
-                    winner = Some((who, bid));
-                }
-            };
-            let mut player_over_ceiling = false;
-            if let Some(bid) = c.player_bid {
-                if bid <= c.budget_ceiling {
-                    consider(None, bid, score(bid, self.player_company.reputation.total()));
-                } else {
-                    player_over_ceiling = true;
-                }
-            }
-            for (ci, comp) in self.competitors.iter().enumerate() {
-                if let Some(bid) = comp.compute_bid(&c, &self.balance, &self.seed) {
-                    if bid <= c.budget_ceiling {
-                        consider(Some(ci), bid, score(bid, comp.company.reputation.total()));
-                    }
-                }
-            }
+            let bids = self.sealed_bids(c.player_bid, market.as_ref(), |comp| {
+                comp.compute_bid(&c, &self.balance, &self.seed)
+            });
+            let winner = bidding::resolve(
+                &bids, c.budget_ceiling, market.as_ref(), self.balance.markets.rep_scale, &self.balance.bidding,
+            );
+            let player_over_ceiling = c.player_bid.is_some_and(|bid| bid > c.budget_ceiling);
 
             let record_date = self.date;
             let record_outcome = move |outcome: contract::AwardOutcome, c: &contract::Contract| {
@@ -564,8 +581,8 @@ impl GameState {
                     outcome,
                 }
             };
-            match winner {
-                Some((None, bid)) => {
+            match winner.map(|a| (a.bidder, a.amount)) {
+                Some((Bidder::Player, bid)) => {
                     let record = record_outcome(
                         contract::AwardOutcome::PlayerWon { amount: bid }, &c,
                     );
@@ -576,14 +593,17 @@ impl GameState {
                         contract_name: c.name.clone(),
                         amount: bid,
                     };
+                    let filed = self.file_export_paperwork(&c);
+                    self.track_customer_payload(&c);
                     self.player_company.active_contracts.push(c);
                     self.event_log.push(self.date, evt.clone());
                     events.push(evt);
+                    events.extend(filed);
                     // Winning a contract is a decision point (schedule
                     // the launch, adjust rules) — stop the clock.
                     self.speed = GameSpeed::Paused;
                 }
-                Some((Some(ci), bid)) => {
+                Some((Bidder::Competitor(ci), bid)) => {
                     let losing_player_bid = c.player_bid;
                     let record = record_outcome(
                         contract::AwardOutcome::CompetitorWon {
@@ -611,9 +631,13 @@ impl GameState {
                         amount: bid,
                         player_bid: losing_player_bid,
                     };
+                    let contract_name = c.name.clone();
                     comp.company.active_contracts.push(c);
                     self.event_log.push(self.date, evt.clone());
                     events.push(evt);
+                    if losing_player_bid.is_some() {
+                        self.write_off_proposal(contract_name, events);
+                    }
                 }
                 None if player_over_ceiling => {
                     // Over budget: no award, and the customer doesn't
@@ -630,12 +654,60 @@ impl GameState {
                     };
                     self.event_log.push(self.date, evt.clone());
                     events.push(evt);
+                    self.write_off_proposal(c.name, events);
                 }
                 None => {} // No valid bids: lapses without ceremony.
             }
         }
     }
 
+    /// The sealed bids on one solicitation: the player's first (so ties
+    /// go their way), then each competitor's scripted price. Each bid
+    /// carries the bidder's reputation with the market's segment.
+    fn sealed_bids(
+        &self,
+        player_bid: Option<f64>,
+        market: Option<&contract::Market>,
+        competitor_bid: impl Fn(&crate::competitor::Competitor) -> Option<f64>,
+    ) -> Vec<SealedBid> {
+        let segment = market.map(|m| m.reputation_segment).unwrap_or_default();
+        let rep_cfg = &self.balance.reputation;
+        let player = &self.player_company;
+        let mut bids: Vec<SealedBid> = player_bid.into_iter()
+            .map(|amount| SealedBid {
+                bidder: Bidder::Player,
+                amount,
+                reputation: player.reputation.segment(segment, rep_cfg),
+                track_record: bidding::track_record(&player.launch_history),
+            })
+            .collect();
+        for (ci, comp) in self.competitors.iter().enumerate() {
+            if let Some(amount) = competitor_bid(comp) {
+                bids.push(SealedBid {
+                    bidder: Bidder::Competitor(ci),
+                    amount,
+                    reputation: comp.company.reputation.segment(segment, rep_cfg),
+                    track_record: bidding::track_record(&comp.company.launch_history),
+                });
+            }
+        }
+        bids
+    }
+
+    /// Charge the engineering time that went into a losing proposal.
+    fn write_off_proposal(&mut self, contract_name: String, events: &mut Vec<GameEvent>) {
+        let cost = bidding::proposal_cost(&self.balance.bidding, self.balance.costs.engineering_monthly_salary);
+        if cost <= 0.0 {
+            return;
+        }
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.budgets.record(Department::Engineering, cost);
+        let evt = GameEvent::ProposalWrittenOff { contract_name, cost };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+
     /// Append to the award-history record, dropping the oldest entries
     /// past the cap (bounds save size; ~15 awards/year game-time).
     pub(super) fn push_award_record(&mut self, record: contract::AwardRecord) {
@@ -653,7 +725,7 @@ impl GameState {
     pub(super) fn tick_competitors(&mut self, events: &mut Vec<GameEvent>) {
         for ci in 0..self.competitors.len() {
             let comp = &mut self.competitors[ci];
-            let mfg_events = comp.company.manufacturing.advance_day(&self.balance.costs);
+            let mfg_events = comp.company.manufacturing.advance_day(&self.balance.costs, self.date);
             for me in mfg_events {
                 if let crate::manufacturing::ManufacturingEvent::RocketIntegrated {
                     design_id, rocket_name, build_cost, ..
@@ -675,7 +747,40 @@ impl GameState {
             // Auto-build events are the competitor's internal
             // bookkeeping, not news.
             let _ = comp.company.auto_reorder_rockets(&self.balance);
-            comp.company.auto_assign_idle_manufacturing_teams();
+            comp.company.auto_assign_idle_manufacturing_teams(&self.balance.costs);
+        }
+    }
+
+    /// The world market's daily tick: fold in every company's activity
+    /// and re-price the vendor engines on offer to match.
+    pub(super) fn tick_world_market(&mut self) {
+        let ratio = self.world_market.tick_day(
+            self.date.is_first_of_month(), self.vendor_engines_bought(), &self.balance.world_market,
+        );
+        self.reprice_vendor_engines(ratio);
+    }
+
+    /// Vendor engines bought by every company, all time.
+    pub(super) fn vendor_engines_bought(&self) -> u32 {
+        std::iter::once(&self.player_company)
+            .chain(self.competitors.iter().map(|c| &c.company))
+            .map(|c| c.contracted_engine_build_counts.values().sum::<u32>())
+            .sum()
+    }
+
+    /// Scale every vendor engine on offer by the engine index's move.
+    pub(super) fn reprice_vendor_engines(&mut self, ratio: f64) {
+        if ratio != 1.0 {
+            for company in std::iter::once(&mut self.player_company)
+                .chain(self.competitors.iter_mut().map(|c| &mut c.company))
+            {
+                for ce in &mut company.contracted_engines {
+                    ce.purchase_cost_per_unit *= ratio;
+                }
+                for entry in &mut company.third_party_catalog {
+                    entry.purchase_cost_per_unit *= ratio;
+                }
+            }
         }
     }
 
@@ -736,15 +841,35 @@ impl GameState {
                 let failed = rocket.rocket_flaws.iter()
                     .any(|fl| rng.gen::<f64>() < fl.activation_chance);
 
+                let propellant_kg = self.competitors[ci].company.rocket_projects.iter()
+                    .find(|rp| rp.project_id == rocket.rocket_project_id)
+                    .map_or(0.0, |rp| rp.design.total_propellant_mass_kg());
+                self.world_market.record_launch(propellant_kg);
                 let comp = &mut self.competitors[ci];
                 if failed {
                     comp.company.reputation.on_launch_failure(&self.balance.reputation, severity);
                 } else {
+                    self.demand.record_delivery(contract.market_id, &contract.destination);
                     comp.company.money += contract.payment;
                     comp.company.reputation.on_launch_success(&self.balance.reputation);
                     comp.company.reputation.on_contract_launch(&self.balance.reputation);
                 }
                 comp.company.last_launch_date = Some(self.date);
+                comp.company.launch_history.push(crate::launch::LaunchRecord {
+                    launch_date: self.date,
+                    rocket_name: rocket.rocket_name.clone(),
+                    contract_id: Some(contract.id),
+                    destination: contract.destination.clone(),
+                    payload_kg: contract.payload_kg,
+                    outcome: if failed {
+                        crate::launch::LaunchOutcome::Failure { reason: "Flaw activated".into() }
+                    } else {
+                        crate::launch::LaunchOutcome::Success
+                    },
+                    flaws_activated: Vec::new(),
+                    transit_anomalies: Vec::new(),
+                    stage_disposals: Vec::new(),
+                });
                 let evt = GameEvent::CompetitorLaunch {
                     company: comp.company.name.clone(),
                     contract_name: contract.name.clone(),
@@ -758,9 +883,16 @@ impl GameState {
 
     /// Place (or revise) a sealed bid on an available solicitation.
     /// Returns None if the index is invalid, the contract is
-    /// pre-priced (campaign missions, legacy saves), or the bid is
-    /// not positive.
+    /// pre-priced (campaign missions, legacy saves), the bid is not
+    /// positive, the market needs a certified design, or export
+    /// controls bar the player.
     pub fn place_bid(&mut self, index: usize, bid: f64) -> Option<GameEvent> {
+        let market_id = self.available_contracts.get(index)?.market_id;
+        if self.certification_blocks_market(market_id)
+            || self.export_blocks_contract(&self.available_contracts[index])
+        {
+            return None;
+        }
         let c = self.available_contracts.get_mut(index)?;
         if !c.is_solicitation() || bid <= 0.0 {
             return None;
@@ -829,32 +961,146 @@ impl GameState {
 
     /// Accept a pre-priced contract from the available market
     /// (campaign missions and pre-M3 saves). Solicitations must be
-    /// bid on instead — see [`GameState::place_bid`].
+    /// bid on instead — see [`GameState::place_bid`]. Markets requiring
+    /// certification refuse players without a certified design, and
+    /// export-controlled work players without a clearance.
     pub fn accept_contract(&mut self, index: usize) -> Option<GameEvent> {
         if index >= self.available_contracts.len()
             || self.available_contracts[index].is_solicitation()
+            || self.certification_blocks_market(self.available_contracts[index].market_id)
+            || self.export_blocks_contract(&self.available_contracts[index])
         {
             return None;
         }
         let mut c = self.available_contracts.remove(index);
         let name = c.name.clone();
         c.status = contract::ContractStatus::Accepted;
+        self.file_export_paperwork(&c);
+        self.track_customer_payload(&c);
         self.player_company.active_contracts.push(c);
         let evt = GameEvent::ContractAccepted { contract_name: name };
         self.event_log.push(self.date, evt.clone());
         Some(evt)
     }
 
-    /// Check yearly tech unlock rolls.
+    /// Replace the pad lease offers with a fresh monthly batch. Lessees
+    /// are competitors (when running) or abstract outside operators.
+    pub(super) fn refresh_pad_lease_offers(&mut self) {
+        use rand::Rng;
+        let cfg = &self.balance.pad_leasing;
+        let mut lessees: Vec<String> = self.competitors.iter()
+            .map(|c| c.company.name.clone())
+            .collect();
+        lessees.extend(PAD_LESSEES.iter().map(|s| s.to_string()));
+
+        let rng = &mut self.seed.contingent_rng;
+        self.pad_lease_offers = (0..cfg.offers_per_month)
+            .map(|_| PadLease {
+                lessee: lessees[rng.gen_range(0..lessees.len())].clone(),
+                start: self.date.add_days(rng.gen_range(7..=cfg.max_lead_days.max(7))),
+                days: rng.gen_range(cfg.min_days..=cfg.max_days.max(cfg.min_days)).max(1),
+                daily_rate: rng.gen_range(cfg.daily_rate_min..=cfg.daily_rate_max.max(cfg.daily_rate_min)),
+            })
+            .collect();
+    }
+
+    /// Sign a pad lease offer. Fails if its days collide with an
+    /// existing lease; the offer stays posted in that case.
+    pub fn accept_pad_lease(&mut self, index: usize) -> Result<GameEvent, PadLeaseError> {
+        let offer = self.pad_lease_offers.get(index)
+            .ok_or(PadLeaseError::OfferMissing)?
+            .clone();
+        self.player_company.launch_site.add_lease(offer.clone(), self.date)?;
+        self.pad_lease_offers.remove(index);
+        let evt = GameEvent::PadLeaseSigned {
+            lessee: offer.lessee,
+            start: offer.start,
+            days: offer.days,
+            daily_rate: offer.daily_rate,
+        };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Back out of a signed lease before it starts. No penalty — the
+    /// lessee simply goes elsewhere.
+    pub fn cancel_pad_lease(&mut self, index: usize) -> Option<PadLease> {
+        self.player_company.launch_site.cancel_lease(index, self.date)
+    }
+
+    /// Extra delta-v a launch to `destination` spends on plane changes
+    /// from the company's pad (see `LaunchSite::plane_change_dv`).
+    pub fn plane_change_dv(&self, destination: &str) -> f64 {
+        self.player_company.launch_site.plane_change_dv(destination, &self.balance.launch_sites)
+    }
+
+    /// Most `design` can lift from the company's pad to `destination`.
+    pub fn max_payload_from_pad(&self, design: &crate::rocket::RocketDesign, destination: &str) -> f64 {
+        crate::rocket_project::max_payload_with_extra_dv(
+            design, "earth_surface", destination, self.plane_change_dv(destination),
+        )
+    }
+
+    /// Move the company's pad to another site. The new pad starts with
+    /// no launch history; signed leases have to run out first.
+    pub fn relocate_launch_site(&mut self, site_id: &str) -> Result<GameEvent, RelocationError> {
+        let site = crate::launch_site::site_location(site_id).ok_or(RelocationError::UnknownSite)?;
+        let pad = &self.player_company.launch_site;
+        if pad.location == site.id {
+            return Err(RelocationError::AlreadyThere);
+        }
+        if !pad.leases.is_empty() {
+            return Err(RelocationError::LeasesBooked);
+        }
+        let cost = self.balance.launch_sites.relocation_cost;
+        let company = &mut self.player_company;
+        if company.money < cost || !company.budgets.allows(Department::Facilities, cost) {
+            return Err(RelocationError::CantAfford { cost });
+        }
+        company.money -= cost;
+        company.budgets.record(Department::Facilities, cost);
+        company.launch_site.location = site.id.to_string();
+        company.launch_site.recent_launches.clear();
+        self.record_expense(cost);
+        // Payload capability depends on where the pad is.
+        self.payload_capability_cache.clear();
+        let evt = GameEvent::LaunchSiteRelocated { site: site.name.to_string(), cost };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Collect today's pad rent and retire finished leases.
+    pub(super) fn collect_pad_leases(&mut self, events: &mut Vec<GameEvent>) {
+        if let Some(rate) = self.player_company.launch_site.lease_on(self.date).map(|l| l.daily_rate) {
+            self.player_company.money += rate;
+            self.record_income(rate);
+        }
+        for lease in self.player_company.launch_site.expire_leases(self.date) {
+            let evt = GameEvent::PadLeaseCompleted {
+                income: lease.total_value(),
+                lessee: lease.lessee,
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Check yearly tech unlock rolls. A technology the era holds back
+    /// can't roll before its arrival year and arrives outright in it.
     pub(super) fn check_tech_unlocks(&mut self, events: &mut Vec<GameEvent>) {
         use rand::Rng;
         for tech in &mut self.technologies {
             if tech.unlocked {
                 continue;
             }
+            let arrives = self.balance.era.tech_arrival(tech.id);
+            if arrives.is_some_and(|year| self.date.year < year) {
+                continue;
+            }
             let query = format!("tech_unlock_{}_{}", tech.id.0, self.date.year);
             let mut rng = self.seed.world_query(&query);
             let chance = match tech.difficulty {
+                _ if arrives.is_some() => 1.0,
                 0 => 0.0,
                 1 => 0.10,
                 _ => 0.08,
@@ -0,0 +1,107 @@
+//! Buying publicity, and what it does when a publicized rocket flies.
+//! Sentiment drift runs in the daily tick; its contract modifier is
+//! applied with the monthly solicitations.
+
+use crate::event::GameEvent;
+use crate::manufacturing::InventoryItemId;
+use crate::marketing::{MarketingError, PressEvent, PressKind};
+
+use super::*;
+
+/// Key of the market modifier media sentiment puts on contract rates.
+const MEDIA_MODIFIER_ID: &str = "media_sentiment";
+
+impl GameState {
+    /// Buy a PR campaign, or extend the running one.
+    pub fn start_pr_campaign(&mut self) -> Result<GameEvent, MarketingError> {
+        let cfg = &self.balance.marketing;
+        let (cost, days) = (cfg.pr_campaign_cost, cfg.pr_campaign_days);
+        if self.player_company.money < cost {
+            return Err(MarketingError::CantAfford { cost });
+        }
+        // Publicity sits outside the department budgets.
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.marketing.pr_days_remaining += days;
+        let evt = GameEvent::PrCampaignStarted { cost, days };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Book press for the launch of rocket `item_id`, paid up front.
+    pub fn schedule_press_event(
+        &mut self,
+        item_id: InventoryItemId,
+        kind: PressKind,
+    ) -> Result<GameEvent, MarketingError> {
+        let rocket_name = self.player_company.manufacturing.inventory.rockets.iter()
+            .find(|r| r.item_id == item_id)
+            .map(|r| r.rocket_name.clone())
+            .ok_or(MarketingError::NoSuchRocket)?;
+        if self.player_company.marketing.press_event(item_id).is_some() {
+            return Err(MarketingError::AlreadyScheduled);
+        }
+        let cfg = &self.balance.marketing;
+        let cost = match kind {
+            PressKind::Briefing => cfg.briefing_cost,
+            PressKind::Livestream => cfg.livestream_cost,
+        };
+        if self.player_company.money < cost {
+            return Err(MarketingError::CantAfford { cost });
+        }
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.marketing.press_events.push(PressEvent {
+            rocket_item_id: item_id,
+            rocket_name: rocket_name.clone(),
+            kind,
+        });
+        let evt = GameEvent::PressEventScheduled { rocket_name, kind, cost };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Fame and sentiment from a launch clearing (or not clearing) the
+    /// pad. A failure's extra reputation damage rides on the severity
+    /// the caller passes to `on_launch_failure` (see
+    /// `Marketing::failure_multiplier`). Returns the coverage event for
+    /// publicized launches; the caller logs it.
+    pub(super) fn publicize_launch(&mut self, press: Option<PressEvent>, success: bool) -> Option<GameEvent> {
+        let cfg = &self.balance.marketing;
+        let kind = press.as_ref().map(|p| p.kind);
+        let marketing = &mut self.player_company.marketing;
+        if success {
+            let fame = marketing.fame_multiplier(kind, cfg);
+            self.player_company.reputation.on_press_coverage(&self.balance.reputation, fame);
+        }
+        marketing.on_launch(kind, success, cfg);
+        let press = press?;
+        Some(GameEvent::PressCoverage {
+            rocket_name: press.rocket_name,
+            kind: press.kind,
+            success,
+            sentiment: marketing.sentiment,
+        })
+    }
+
+    /// Refresh the media-sentiment rate modifier on every market ahead
+    /// of the month's solicitations; it lapses on its own if not
+    /// renewed.
+    pub(super) fn apply_media_sentiment(&mut self) {
+        let mult = self.player_company.marketing.contract_rate_mult(&self.balance.marketing);
+        let end_date = self.date.add_days(31);
+        for market in &mut self.markets {
+            market.modifiers.retain(|m| m.id != MEDIA_MODIFIER_ID);
+            if (mult - 1.0).abs() < 0.005 {
+                continue;
+            }
+            market.add_modifier(contract::MarketModifier {
+                id: MEDIA_MODIFIER_ID.into(),
+                description: format!("Media sentiment toward you ({:+.0}% rates)", 100.0 * (mult - 1.0)),
+                volume_mult: 1.0,
+                rate_mult: mult,
+                end_date: Some(end_date),
+            });
+        }
+    }
+}
@@ -14,8 +14,43 @@ use crate::balance_config::BalanceConfig;
 pub use crate::company::{Company, BidRule, MonthlyFinancials};
 
 mod advance;
+mod certification_ops;
+mod crew_ops;
+mod design_ops;
+mod espionage_ops;
+mod finance_ops;
 mod flight_ops;
+mod gov_program_ops;
+mod infrastructure_ops;
+mod review_ops;
+mod morale_ops;
+mod payroll_ops;
+mod marketing_ops;
+mod servicing_ops;
+mod constellation_ops;
+mod countdown_ops;
+mod disposal_ops;
+mod subcontract_ops;
+mod science_ops;
+#[cfg(any(feature = "qa", debug_assertions))]
+mod qa_ops;
+mod inventory_ops;
 mod market_ops;
+mod regulation_ops;
+mod pad_failure_ops;
+mod recovery_fleet_ops;
+mod payload_ops;
+mod pad_integration_ops;
+mod fleet_ops;
+mod challenge_ops;
+mod sandbox_ops;
+mod library_ops;
+mod tutorial_ops;
+mod advisor_ops;
+mod forecast_ops;
+mod batch_ops;
+mod allocation_ops;
+mod quiet_ops;
 
 /// Game simulation speed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -56,6 +91,16 @@ impl GameSpeed {
     }
 }
 
+/// Outcome of `GameState::fast_forward`.
+#[derive(Debug, Clone)]
+pub struct FastForward {
+    /// Days actually advanced.
+    pub days: u32,
+    /// Every event raised along the way, in order.
+    pub events: Vec<GameEvent>,
+    /// True if something needing the player stopped the skip early.
+    pub interrupted: bool,
+}
 
 /// Unique identifier for a spacecraft.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -76,6 +121,10 @@ pub struct Spacecraft {
     /// `deploy_at` matches a stop on the new mission.
     #[serde(default)]
     pub payloads: Vec<crate::flight::Payload>,
+    /// Wears down day by day; at zero the spacecraft can't fly until
+    /// it's serviced.
+    #[serde(default = "crate::servicing::full_health")]
+    pub health: f64,
 }
 
 impl Spacecraft {
@@ -95,7 +144,7 @@ const EVENT_LOG_SIZE: usize = 1000;
 pub const BID_PAYLOAD_MARGIN: f64 = 0.9;
 
 /// Why a launch manifest couldn't be assembled.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ManifestError {
     /// Two picked contracts want different destinations.
     ConflictingDestinations { first: String, second: String },
@@ -103,6 +152,20 @@ pub enum ManifestError {
     SpacecraftMissing,
     /// A picked spacecraft's rocket project no longer exists.
     PayloadProjectMissing,
+    /// The pad is leased to another operator today.
+    PadLeased { lessee: String, free_on: GameDate },
+    /// The regulator won't clear the launch.
+    NotCompliant(crate::regulation::ComplianceIssue),
+    /// The launch vehicle is no longer in inventory.
+    RocketMissing,
+    /// Another countdown already has the pad.
+    CountdownUnderway,
+    /// The carrier's enforced commit checklist came up no-go on these.
+    CommitCriteriaNotMet(Vec<crate::commit_criteria::Criterion>),
+    /// The customer hasn't shipped a picked contract's payload yet.
+    PayloadNotReady { contract_name: String, ready_on: GameDate },
+    /// The launch vehicle is heavier than the pad's facility can handle.
+    TooLargeForPad { liftoff_kg: f64, max_kg: f64 },
 }
 
 /// Top-level game state.
@@ -134,6 +197,20 @@ pub struct GameState {
     /// Spacecraft persisted after arrival.
     #[serde(default)]
     pub spacecraft: Vec<Spacecraft>,
+    /// Depots and ISRU plants, one entry per location built up.
+    #[serde(default)]
+    pub infrastructure: Vec<crate::infrastructure::LocationInfrastructure>,
+    /// Standing spacecraft ↔ depot transfer orders, run daily.
+    #[serde(default)]
+    pub transfer_orders: Vec<crate::infrastructure::TransferOrder>,
+    /// Satellite constellations the player has formed.
+    #[serde(default)]
+    pub constellations: Vec<crate::constellation::Constellation>,
+    #[serde(default = "default_next_constellation_id")]
+    pub next_constellation_id: u64,
+    /// Today's launch attempt, if a countdown is running.
+    #[serde(default)]
+    pub countdown: Option<crate::countdown::Countdown>,
     /// Current economic conditions affecting the launch market.
     #[serde(default)]
     pub economy: crate::economy::EconomicState,
@@ -160,10 +237,51 @@ pub struct GameState {
     pub active_campaigns: Vec<contract::Campaign>,
     #[serde(default = "default_next_campaign_id")]
     pub next_campaign_id: u64,
+    /// Government programs announced or under way.
+    #[serde(default)]
+    pub gov_programs: Vec<crate::gov_program::GovProgram>,
+    #[serde(default = "default_next_gov_program_id")]
+    pub next_gov_program_id: u64,
+    /// Outside operators' standing offers to rent the player's pad,
+    /// reposted monthly.
+    #[serde(default)]
+    pub pad_lease_offers: Vec<crate::launch_site::PadLease>,
+    /// Outside launch companies taking subcontracted payloads.
+    #[serde(default = "crate::subcontract::default_providers")]
+    pub launch_providers: Vec<crate::subcontract::LaunchProvider>,
+    /// Every company's recent deliveries by market segment.
+    #[serde(default)]
+    pub demand: crate::demand::DemandModel,
+    /// Prices and demand shared by every company.
+    #[serde(default)]
+    pub world_market: crate::market::WorldMarket,
+    /// Science assets returning data for delivered contracts.
+    #[serde(default)]
+    pub science_missions: Vec<crate::science::ScienceMission>,
+    /// Leaked competitor data currently for sale, if any.
+    #[serde(default)]
+    pub leak_offer: Option<crate::espionage::LeakOffer>,
+    /// Player-chosen auto-pause triggers.
+    #[serde(default)]
+    pub pause_rules: crate::event::PauseRules,
     /// Tunable balance parameters this game was created with. Saves
     /// remember their balance; old saves load with defaults.
     #[serde(default)]
     pub balance: crate::balance_config::BalanceConfig,
+    /// This world's procedural map variant, if it was created with
+    /// one. Installed from here when the save loads.
+    #[serde(default)]
+    pub map_variant: Option<crate::map_variant::MapVariant>,
+    /// The challenge being played, if this is a challenge game.
+    #[serde(default)]
+    pub challenge: Option<crate::challenge::ChallengeRun>,
+    /// The first-game tutorial, if this game is playing one.
+    #[serde(default)]
+    pub tutorial: Option<crate::tutorial::Tutorial>,
+    /// Set for good once a sandbox edit has been made: the game's
+    /// results, challenge score included, weren't earned.
+    #[serde(default)]
+    pub sandbox: bool,
     /// Max-payload lookups for the bid rule engine, keyed by
     /// (project, revision, destination). Path planning is far too
     /// slow to run per contract per day. Not serialized — rebuilt on
@@ -171,10 +289,16 @@ pub struct GameState {
     /// change stage_groups without bumping revision).
     #[serde(skip)]
     pub payload_capability_cache: HashMap<(RocketProjectId, u32, String), f64>,
+    /// QA fault-injection controls.
+    #[cfg(any(feature = "qa", debug_assertions))]
+    #[serde(skip)]
+    pub qa: crate::qa::QaControls,
 }
 
 fn default_next_contract_id() -> u64 { 1 }
 fn default_next_campaign_id() -> u64 { 1 }
+fn default_next_gov_program_id() -> u64 { 1 }
+fn default_next_constellation_id() -> u64 { 1 }
 fn default_next_flight_id() -> u64 { 1 }
 fn default_next_rocket_id() -> u64 { 1 }
 fn default_markets() -> Vec<contract::Market> {
@@ -203,13 +327,19 @@ impl GameState {
         seed_value: u64,
         balance: BalanceConfig,
     ) -> Self {
-        let start = GameDate::default_start();
+        let start = GameDate::new(balance.era.start_year, 1, 1);
         let mut event_log = EventLog::new(EVENT_LOG_SIZE);
         event_log.push(start, GameEvent::GameStarted);
         let seed = GameSeed::new(seed_value);
 
         let economy = crate::economy::initial_state(&seed, start);
-        let technologies = crate::technology::generate_technologies(&seed);
+        let mut technologies = crate::technology::generate_technologies(&seed);
+        // Technologies the era hasn't reached yet start locked.
+        for tech in &mut technologies {
+            if balance.era.tech_arrival(tech.id).is_some() {
+                tech.unlocked = false;
+            }
+        }
 
         // Realize the archetype table for this world: presence rolls,
         // volume/rate multipliers, growth rates, and weight tilts
@@ -227,6 +357,14 @@ impl GameState {
                 })
                 .collect();
 
+        // Rolled again here (deterministically) so the save carries the
+        // variant `map_variant::prepare_new_world` installed.
+        let map_variant = balance.map_variant.enabled.then(|| {
+            crate::map_variant::MapVariant::generate(
+                &seed, &crate::location::base_map(), &balance.map_variant,
+            )
+        });
+
         let competitors = if balance.competitor.enabled {
             vec![crate::competitor::realize_dinosoar(&seed, &balance)]
         } else {
@@ -247,6 +385,11 @@ impl GameState {
             next_flight_id: 1,
             next_rocket_id: 1,
             spacecraft: Vec::new(),
+            infrastructure: Vec::new(),
+            transfer_orders: Vec::new(),
+            constellations: Vec::new(),
+            next_constellation_id: 1,
+            countdown: None,
             economy,
             markets,
             fired_market_events: Vec::new(),
@@ -254,9 +397,24 @@ impl GameState {
             award_history: Vec::new(),
             active_campaigns: Vec::new(),
             next_campaign_id: 1,
+            gov_programs: Vec::new(),
+            next_gov_program_id: 1,
+            pad_lease_offers: Vec::new(),
+            launch_providers: crate::subcontract::default_providers(),
+            demand: Default::default(),
+            world_market: Default::default(),
+            science_missions: Vec::new(),
+            leak_offer: None,
             technologies,
+            pause_rules: crate::event::PauseRules::default(),
             balance,
+            map_variant,
+            challenge: None,
+            tutorial: None,
+            sandbox: false,
             payload_capability_cache: HashMap::new(),
+            #[cfg(any(feature = "qa", debug_assertions))]
+            qa: Default::default(),
         }
     }
 
@@ -335,6 +493,26 @@ impl GameState {
         })
     }
 
+    /// Set a rocket project's flight performance reserve (clamped to
+    /// `[0, MAX_PERFORMANCE_RESERVE]`). An operations choice, not a
+    /// hardware change — no modification work or flaw roll. Returns
+    /// false if the project doesn't exist.
+    pub fn set_performance_reserve(
+        &mut self,
+        project_id: crate::rocket_project::RocketProjectId,
+        reserve: f64,
+    ) -> bool {
+        let Some(project) = self.player_company.rocket_projects.iter_mut()
+            .find(|p| p.project_id == project_id) else {
+            return false;
+        };
+        project.design.performance_reserve =
+            Some(reserve.clamp(0.0, crate::rocket::MAX_PERFORMANCE_RESERVE));
+        // Planned payload capability depends on the reserve.
+        self.payload_capability_cache.clear();
+        true
+    }
+
     /// Days elapsed since the game started.
     pub fn elapsed_days(&self) -> u32 {
         self.start_date.days_until(&self.date)
@@ -370,6 +548,7 @@ impl GameState {
                 month,
                 income: 0.0,
                 expenses: 0.0,
+                depreciation: 0.0,
             });
             // Keep rolling 12-month window
             while self.player_company.monthly_financials.len() > 12 {
@@ -390,6 +569,18 @@ impl GameState {
         }
     }
 
+    /// Record a depreciation charge in the current month's financials.
+    pub(super) fn record_depreciation(&mut self, amount: f64) {
+        self.ensure_current_month_financials();
+        let year = self.date.year;
+        let month = self.date.month;
+        if let Some(f) = self.player_company.monthly_financials.iter_mut()
+            .find(|f| f.year == year && f.month == month)
+        {
+            f.depreciation += amount;
+        }
+    }
+
     /// Record income in the current month's financials.
     pub(super) fn record_income(&mut self, amount: f64) {
         self.ensure_current_month_financials();
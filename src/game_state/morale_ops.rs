@@ -0,0 +1,50 @@
+//! Engineering morale actions. The daily drift, crunch drain and
+//! resignations run in `Company::tick_morale`.
+
+use crate::event::GameEvent;
+use crate::team::MoraleCause;
+
+use super::*;
+
+impl GameState {
+    /// Move engineering morale for `cause`. The caller logs the event.
+    pub(crate) fn shift_morale(&mut self, cause: MoraleCause) -> GameEvent {
+        let we = self.player_company.shift_morale(cause, &self.balance.morale);
+        crate::company::morale_game_event(we)
+            .expect("a morale shift always maps to a game event")
+    }
+
+    /// Let an engineering team go. None if there are no teams.
+    pub fn fire_team(&mut self) -> Option<GameEvent> {
+        let (team_name, shift) = self.player_company.fire_team(&self.balance.morale)?;
+        let fired = GameEvent::TeamFired { team_name };
+        self.event_log.push(self.date, fired.clone());
+        if let Some(evt) = crate::company::morale_game_event(shift) {
+            self.event_log.push(self.date, evt);
+        }
+        Some(fired)
+    }
+
+    /// Furlough an idle engineering team. None if no team is idle.
+    pub fn furlough_team(&mut self) -> Option<GameEvent> {
+        let team_name = self.player_company.furlough_team()?;
+        let evt = GameEvent::TeamFurloughed { team_name };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Recall a furloughed engineering team. None if nobody is on
+    /// furlough.
+    pub fn recall_team(&mut self) -> Option<GameEvent> {
+        let team_name = self.player_company.recall_team(&self.balance.morale)?;
+        let evt = GameEvent::TeamRecalled { team_name };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Start or end crunch; returns whether engineering is now crunching.
+    pub fn toggle_crunch(&mut self) -> bool {
+        self.player_company.crunch = !self.player_company.crunch;
+        self.player_company.crunch
+    }
+}
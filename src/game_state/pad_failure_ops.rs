@@ -0,0 +1,138 @@
+//! The arc a catastrophic pad failure sets off (see
+//! [`crate::pad_failure`]): rebuild, stand-down, insurance claim and
+//! press crisis when it happens; the rebuild finishing and the press
+//! deadline lapsing in the daily tick; the insurance premium monthly.
+
+use crate::budget::Department;
+use crate::event::GameEvent;
+use crate::marketing::MarketingError;
+use crate::pad_failure::{CrisisResponse, PressCrisis};
+
+use super::*;
+
+impl GameState {
+    /// `rocket_name` blew up on the pad and took it with it. Starts the
+    /// rebuild (paid now, less any insurance), grounds launches for the
+    /// investigation, and puts the company in front of the press.
+    pub(super) fn destroy_pad(&mut self, rocket_name: &str) -> Vec<GameEvent> {
+        let cfg = self.balance.pad_failure.clone();
+        let mut events = Vec::new();
+
+        let ready_on = self.date.add_days(cfg.rebuild_days);
+        let site = &mut self.player_company.launch_site;
+        site.rebuild_until = Some(ready_on);
+        // Lessees take their business elsewhere.
+        site.leases.retain(|l| l.end() <= self.date);
+        self.player_company.money -= cfg.rebuild_cost;
+        self.record_expense(cfg.rebuild_cost);
+        self.player_company.budgets.record(Department::Facilities, cfg.rebuild_cost);
+        events.push(GameEvent::PadDestroyed {
+            rocket_name: rocket_name.to_string(),
+            rebuild_cost: cfg.rebuild_cost,
+            ready_on,
+        });
+        if self.player_company.launch_site.insured {
+            let amount = cfg.rebuild_cost * cfg.insurance_coverage;
+            self.player_company.money += amount;
+            self.record_income(amount);
+            events.push(GameEvent::InsuranceClaimPaid { amount });
+        }
+
+        let until = self.date.add_days(cfg.stand_down_days);
+        let compliance = &mut self.player_company.compliance;
+        compliance.stand_down_until = Some(compliance.stand_down_until.map_or(until, |u| u.max(until)));
+        events.push(GameEvent::RegulatoryStandDown { until });
+
+        let respond_by = self.date.add_days(cfg.response_days);
+        let marketing = &mut self.player_company.marketing;
+        marketing.shift_sentiment(cfg.crisis_sentiment_hit);
+        marketing.crisis = Some(PressCrisis { rocket_name: rocket_name.to_string(), respond_by });
+        self.player_company.reputation.on_press_crisis(cfg.crisis_reputation_penalty);
+        events.push(GameEvent::PressCrisis { rocket_name: rocket_name.to_string(), respond_by });
+
+        for evt in &events {
+            self.event_log.push(self.date, evt.clone());
+        }
+        events
+    }
+
+    /// Answer the open press crisis. A transparent briefing costs more
+    /// but wins back sentiment and reputation; spin is cheap but may
+    /// backfire.
+    pub fn respond_to_press_crisis(&mut self, response: CrisisResponse) -> Result<GameEvent, MarketingError> {
+        if self.player_company.marketing.crisis.is_none() {
+            return Err(MarketingError::NoCrisis);
+        }
+        let cfg = &self.balance.pad_failure;
+        let cost = match response {
+            CrisisResponse::Transparent => cfg.transparent_cost,
+            CrisisResponse::Spin => cfg.spin_cost,
+        };
+        if self.player_company.money < cost {
+            return Err(MarketingError::CantAfford { cost });
+        }
+        let (sentiment, backfired) = match response {
+            CrisisResponse::Transparent => {
+                self.player_company.reputation.on_press_crisis(-cfg.transparent_reputation);
+                (cfg.transparent_sentiment, false)
+            }
+            CrisisResponse::Spin => {
+                use rand::Rng;
+                if self.seed.contingent_rng.gen::<f64>() < cfg.spin_backfire_chance {
+                    (cfg.spin_backfire_sentiment, true)
+                } else {
+                    (cfg.spin_sentiment, false)
+                }
+            }
+        };
+        // Publicity sits outside the department budgets.
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        let marketing = &mut self.player_company.marketing;
+        marketing.crisis = None;
+        marketing.shift_sentiment(sentiment);
+        let evt = GameEvent::PressCrisisAnswered { response, cost, sentiment, backfired };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Insure the pad against destruction, or drop the cover.
+    pub fn set_pad_insurance(&mut self, insured: bool) {
+        self.player_company.launch_site.insured = insured;
+    }
+
+    /// Daily: finish a pad rebuild, and let an unanswered press crisis
+    /// lapse into silence.
+    pub(super) fn tick_pad_failure(&mut self, events: &mut Vec<GameEvent>) {
+        let site = &mut self.player_company.launch_site;
+        if site.rebuild_until.is_some_and(|until| self.date >= until) {
+            site.rebuild_until = None;
+            let evt = GameEvent::PadRebuilt;
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        let marketing = &mut self.player_company.marketing;
+        if marketing.crisis.as_ref().is_some_and(|c| self.date > c.respond_by) {
+            marketing.crisis = None;
+            let sentiment = self.balance.pad_failure.silence_sentiment;
+            marketing.shift_sentiment(sentiment);
+            let evt = GameEvent::PressCrisisIgnored { sentiment };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Monthly: the pad insurance premium, while covered.
+    pub(super) fn charge_pad_insurance(&mut self, events: &mut Vec<GameEvent>) {
+        if !self.player_company.launch_site.insured {
+            return;
+        }
+        let amount = self.balance.pad_failure.insurance_premium_per_month;
+        self.player_company.money -= amount;
+        self.record_expense(amount);
+        self.player_company.budgets.record(Department::Facilities, amount);
+        let evt = GameEvent::PadInsurancePaid { amount };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+}
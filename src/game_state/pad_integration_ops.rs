@@ -0,0 +1,93 @@
+//! The pad's integration facility (see
+//! [`crate::launch_site::IntegrationMode`]): the size of vehicle it
+//! takes, the pad flow after each launch, and converting it.
+
+use crate::budget::Department;
+use crate::event::GameEvent;
+use crate::launch_site::{PadConversion, PadConversionError};
+use crate::manufacturing::InventoryItemId;
+use crate::rocket::RocketDesign;
+
+use super::*;
+
+impl GameState {
+    /// Check that the pad's facility can handle inventory rocket
+    /// `rocket_item_id`.
+    pub fn check_pad_fits(&self, rocket_item_id: InventoryItemId) -> Result<(), ManifestError> {
+        let company = &self.player_company;
+        let inv = company.manufacturing.inventory.rockets.iter()
+            .find(|r| r.item_id == rocket_item_id)
+            .ok_or(ManifestError::RocketMissing)?;
+        let Some(rp) = company.rocket_projects.iter().find(|rp| rp.project_id == inv.rocket_project_id) else {
+            return Err(ManifestError::RocketMissing);
+        };
+        let liftoff_kg = inv.design.as_ref().unwrap_or(&rp.design).total_mass_kg();
+        let cfg = &self.balance.launch_sites;
+        if company.launch_site.fits(liftoff_kg, cfg) {
+            Ok(())
+        } else {
+            Err(ManifestError::TooLargeForPad {
+                liftoff_kg,
+                max_kg: company.launch_site.integration.max_liftoff_kg(cfg),
+            })
+        }
+    }
+
+    /// Start converting the pad to the other integration mode. The pad
+    /// is closed until it's done.
+    pub fn convert_pad(&mut self) -> Result<GameEvent, PadConversionError> {
+        let cfg = &self.balance.launch_sites;
+        let pad = &self.player_company.launch_site;
+        if let Some(c) = pad.conversion {
+            return Err(PadConversionError::Converting { ready_on: c.ready_on });
+        }
+        if !pad.leases.is_empty() {
+            return Err(PadConversionError::LeasesBooked);
+        }
+        let to = pad.integration.other();
+        let cost = to.conversion_cost(cfg);
+        let ready_on = self.date.add_days(cfg.conversion_days);
+        let company = &mut self.player_company;
+        if company.money < cost || !company.budgets.allows(Department::Facilities, cost) {
+            return Err(PadConversionError::CantAfford { cost });
+        }
+        company.money -= cost;
+        company.budgets.record(Department::Facilities, cost);
+        company.launch_site.conversion = Some(PadConversion { to, ready_on });
+        self.record_expense(cost);
+        let evt = GameEvent::PadConversionStarted { mode: to.to_string(), ready_on, cost };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Daily: finish a pad conversion that's due.
+    pub(super) fn tick_pad_conversion(&mut self, events: &mut Vec<GameEvent>) {
+        let pad = &mut self.player_company.launch_site;
+        let Some(c) = pad.conversion.filter(|c| self.date >= c.ready_on) else {
+            return;
+        };
+        pad.integration = c.to;
+        pad.conversion = None;
+        let evt = GameEvent::PadConverted { mode: c.to.to_string() };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+
+    /// After a launch the pad is tied up readying the next vehicle for
+    /// a time set by the facility and the size of the stack it was
+    /// rigged for, and the pad crew is paid for it.
+    pub(super) fn start_pad_flow(&mut self, design: &RocketDesign) -> GameEvent {
+        let cfg = &self.balance.launch_sites;
+        let stages: usize = design.stage_groups.iter().map(|g| g.len()).sum();
+        let days = self.player_company.launch_site.integration.pad_flow_days(stages, cfg);
+        let cost = days as f64 * cfg.pad_crew_cost_per_day;
+        let until = self.date.add_days(days);
+        self.player_company.launch_site.pad_flow_until = Some(until);
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.budgets.record(Department::Facilities, cost);
+        let evt = GameEvent::PadFlowStarted { until, cost };
+        self.event_log.push(self.date, evt.clone());
+        evt
+    }
+}
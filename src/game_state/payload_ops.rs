@@ -0,0 +1,67 @@
+//! Customer payload readiness (see [`crate::payload_readiness`]): the
+//! ready day set when a contract is signed, daily slips with their
+//! compensation, and renegotiating deadlines a slip runs into.
+
+use crate::event::GameEvent;
+
+use super::*;
+
+impl GameState {
+    /// Set the ready day of a contract's payload as the player takes
+    /// it on.
+    pub(super) fn track_customer_payload(&mut self, contract: &Contract) {
+        let mut rng = self.seed.world_query(&format!("payload_ready:{}", contract.id.0));
+        self.player_company.customer_payloads.track(
+            contract.id,
+            self.date,
+            contract.deadline,
+            &mut rng,
+            &self.balance.payload_readiness,
+        );
+    }
+
+    /// Daily: payloads still with their customers may slip. The
+    /// customer pays for the delay, and a slip that leaves too little
+    /// time before the deadline pushes the deadline back.
+    pub(super) fn tick_customer_payloads(&mut self, events: &mut Vec<GameEvent>) {
+        let company = &mut self.player_company;
+        let active: Vec<contract::ContractId> = company.active_contracts.iter().map(|c| c.id).collect();
+        company.customer_payloads.prune(|id| active.contains(&id));
+
+        let cfg = self.balance.payload_readiness.clone();
+        for i in 0..self.player_company.active_contracts.len() {
+            let c = &self.player_company.active_contracts[i];
+            let (id, name, payment) = (c.id, c.name.clone(), c.payment);
+            if self.player_company.customer_payloads.get(id).is_some_and(|p| p.ready_on == self.date) {
+                let evt = GameEvent::PayloadReady { contract_name: name.clone() };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            let mut rng = self.seed.world_query(&format!("payload_slip:{}:{}", id.0, self.date));
+            let Some(slip) = self.player_company.customer_payloads.roll_slip(id, self.date, payment, &mut rng, &cfg) else {
+                continue;
+            };
+            let evt = GameEvent::PayloadSlipped { contract_name: name.clone(), days: slip.days, ready_on: slip.ready_on };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+            if slip.compensation > 0.0 {
+                self.player_company.money += slip.compensation;
+                self.record_income(slip.compensation);
+                let evt = GameEvent::SlipCompensationPaid { contract_name: name.clone(), amount: slip.compensation };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+            let needed = slip.ready_on.add_days(cfg.renegotiation_margin_days);
+            let c = &mut self.player_company.active_contracts[i];
+            if needed > c.deadline {
+                c.deadline = needed;
+                if let contract::ContractPricing::CostPlus { launch_by, .. } = &mut c.pricing {
+                    *launch_by = (*launch_by).max(needed);
+                }
+                let evt = GameEvent::ContractRenegotiated { contract_name: name, deadline: needed };
+                self.event_log.push(self.date, evt.clone());
+                events.push(evt);
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Payroll: January wage inflation, and the pay talks engineering
+//! teams open as they gain experience.
+
+use crate::event::GameEvent;
+use crate::team::RaiseDemand;
+
+use super::*;
+
+impl GameState {
+    /// A year of wage inflation for every company. Runs each January.
+    pub(super) fn inflate_salaries(&mut self, events: &mut Vec<GameEvent>) {
+        let rate = self.balance.payroll.salary_inflation;
+        if rate == 0.0 {
+            return;
+        }
+        self.player_company.inflate_salaries(rate);
+        for comp in &mut self.competitors {
+            comp.company.inflate_salaries(rate);
+        }
+        let evt = GameEvent::SalariesInflated { rate };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+
+    /// A day on the job for every working engineering team. A team that
+    /// reaches a pay-talk anniversary asks for a raise; a request left
+    /// unanswered past its deadline counts as refused. Competitors
+    /// grant raises outright.
+    pub(super) fn tick_payroll(&mut self, events: &mut Vec<GameEvent>) {
+        let cfg = &self.balance.payroll;
+        let period = cfg.renegotiation_years.max(1) * 365;
+        for team in self.player_company.teams.iter_mut().filter(|t| !t.furloughed) {
+            team.experience_days += 1;
+            if team.experience_days % period != 0 || team.raise_demand.is_some() {
+                continue;
+            }
+            let monthly_raise = team.raise_ask(cfg);
+            if monthly_raise <= 0.0 {
+                continue;
+            }
+            let deadline = self.date.add_days(cfg.response_days);
+            team.raise_demand = Some(RaiseDemand { monthly_raise, deadline });
+            let evt = GameEvent::RaiseDemanded { team_name: team.name.clone(), monthly_raise, deadline };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        for comp in &mut self.competitors {
+            for team in &mut comp.company.teams {
+                team.experience_days += 1;
+                if team.experience_days % period == 0 {
+                    team.monthly_salary += team.raise_ask(cfg);
+                }
+            }
+        }
+        // Back to front, so a team walking out doesn't shift the rest.
+        for index in (0..self.player_company.teams.len()).rev() {
+            let lapsed = self.player_company.teams[index].raise_demand
+                .is_some_and(|d| d.deadline <= self.date);
+            if lapsed {
+                events.extend(self.refuse_raise(index));
+            }
+        }
+    }
+
+    /// Give engineering team `index` the raise it asked for. None if it
+    /// hasn't asked.
+    pub fn accept_raise(&mut self, index: usize) -> Option<GameEvent> {
+        let (team_name, monthly_raise) = self.player_company.accept_raise(index)?;
+        let evt = GameEvent::RaiseGranted { team_name, monthly_raise };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Turn down the raise engineering team `index` asked for, at the
+    /// risk of it walking out. None if it hasn't asked.
+    pub fn refuse_raise(&mut self, index: usize) -> Option<GameEvent> {
+        let (team_name, quit) = self.player_company
+            .refuse_raise(index, &mut self.seed.contingent_rng, &self.balance.payroll)?;
+        let evt = GameEvent::RaiseRefused { team_name, quit };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// The first engineering team waiting on an answer about a raise.
+    pub fn pending_raise_index(&self) -> Option<usize> {
+        self.player_company.teams.iter().position(|t| t.raise_demand.is_some())
+    }
+}
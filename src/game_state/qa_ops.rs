@@ -0,0 +1,47 @@
+//! QA fault injection: forcing the next launch's outcome and injecting
+//! engine work events. Debug builds and the `qa` feature only.
+
+use crate::engine_project::{EngineProjectId, WorkEvent};
+use crate::flaw::{FlawId, Subsystem};
+use crate::qa::ForcedOutcome;
+
+use super::*;
+
+impl GameState {
+    /// Make the next launch lose its first stage at `subsystem`'s
+    /// launch event.
+    pub fn qa_force_launch_failure(&mut self, subsystem: Subsystem) {
+        self.qa.next_launch = Some(ForcedOutcome::FailAt(subsystem));
+    }
+
+    /// Make flaw `id` fire on the next launch. False if no engine,
+    /// rocket or software flaw of the player's has that id.
+    pub fn qa_force_flaw(&mut self, id: FlawId) -> bool {
+        let company = &self.player_company;
+        let known = company.engine_projects.iter().flat_map(|ep| &ep.flaws)
+            .chain(company.rocket_projects.iter().flat_map(|rp| &rp.flaws))
+            .chain(company.software_projects.iter().flat_map(|sw| &sw.flaws))
+            .chain(company.manufacturing.inventory.rockets.iter().flat_map(|r| &r.rocket_flaws))
+            .any(|f| f.id == id);
+        if known {
+            self.qa.next_launch = Some(ForcedOutcome::TriggerFlaw(id));
+        }
+        known
+    }
+
+    /// Hand `event` to engine project `id` on the next R&D tick, as if
+    /// its own work had produced it. False if there's no such project.
+    pub fn qa_inject_work_event(&mut self, id: EngineProjectId, event: WorkEvent) -> bool {
+        if !self.player_company.engine_projects.iter().any(|ep| ep.project_id == id) {
+            return false;
+        }
+        self.player_company.qa_work_events.push((id, event));
+        true
+    }
+
+    /// Drop anything rigged and not yet used.
+    pub fn qa_clear(&mut self) {
+        self.qa = Default::default();
+        self.player_company.qa_work_events.clear();
+    }
+}
@@ -0,0 +1,146 @@
+//! Quiet stretches: runs of days in which nothing happens but steady
+//! work, pay accrual, morale drift, price easing and boiloff. Those
+//! all have closed forms, so `fast_forward` takes such a stretch in
+//! one step instead of day by day.
+
+use crate::contract::CampaignStatus;
+use crate::gov_program::ProgramStatus;
+
+use super::*;
+
+/// Longest stretch `fast_forward` takes in one step.
+pub const MAX_QUIET_DAYS: u32 = 7;
+
+impl GameState {
+    /// How many of the next `max` days pass quietly: no month start, no
+    /// deadline, delivery or launch day, no milestone in R&D or on the
+    /// factory floor, and nothing running that rolls dice daily
+    /// (flights, spacecraft, depot flaws, payload slips, attrition).
+    /// Zero if anything like that is under way at all. Assumes a day
+    /// has run since the player last acted, so the passes that react
+    /// to the state (bid rules, auto-builds, team assignment) have
+    /// nothing left to do.
+    pub fn quiet_days(&self, max: u32) -> u32 {
+        let company = &self.player_company;
+        // The first of the month runs the books.
+        let mut days = max.min(self.date.days_in_month() - self.date.day);
+        let busy = self.countdown.is_some()
+            || !self.active_flights.is_empty()
+            || !self.spacecraft.is_empty()
+            || !self.transfer_orders.is_empty()
+            || !self.constellations.is_empty()
+            || !self.science_missions.is_empty()
+            || self.challenge.as_ref().is_some_and(|r| r.outcome.is_none())
+            || self.tutorial.as_ref().is_some_and(|t| !t.is_finished())
+            || self.infrastructure.iter().any(|infra| {
+                !infra.isru_plants.is_empty()
+                    || infra.depot.flaws.iter().any(|f| f.daily_rate() > 0.0)
+                    || !infra.boiloff_steady(&self.balance.isru)
+            })
+            || company.astronauts.stand_down_until.is_some()
+            || company.marketing.pr_days_remaining > 0
+            || !company.launch_site.leases.is_empty()
+            || company.customer_payloads.payloads.iter().any(|p| p.ready_on > self.date)
+            || !company.morale_steady(&self.balance.morale);
+        if busy || days == 0 {
+            return 0;
+        }
+
+        // The first day each dated thing comes due.
+        let mut due: Vec<GameDate> = Vec::new();
+        for c in self.available_contracts.iter().chain(&company.active_contracts) {
+            due.push(c.deadline.next_day());
+            due.extend(c.bid_deadline.map(GameDate::next_day));
+        }
+        for campaign in &self.active_campaigns {
+            match campaign.status {
+                CampaignStatus::Soliciting { bid_deadline, .. } => due.push(bid_deadline.next_day()),
+                CampaignStatus::Won { .. } if campaign.missions_issued < campaign.missions_total =>
+                    due.push(campaign.next_issue_date),
+                CampaignStatus::Won { .. } => {}
+            }
+        }
+        for comp in &self.competitors {
+            due.extend(comp.company.active_contracts.iter().map(|c| c.deadline.next_day()));
+            due.extend(comp.scheduled_launches.iter().map(|l| l.launch_date));
+        }
+        due.extend(company.subcontracts.iter().map(|s| s.launch_date));
+        for program in self.gov_programs.iter().filter(|p| p.status == ProgramStatus::Committed) {
+            due.extend(program.milestones.iter().filter(|m| !m.is_met()).map(|m| m.deadline.next_day()));
+        }
+        let compliance = &company.compliance;
+        due.extend(compliance.applications.iter().map(|a| a.ready_on));
+        due.extend(compliance.clearance_ready_on);
+        due.extend(compliance.export_ban_until);
+        let site = &company.launch_site;
+        due.extend(site.rebuild_until);
+        due.extend(site.pad_flow_until);
+        due.extend(site.conversion.map(|c| c.ready_on));
+        due.extend(company.marketing.crisis.as_ref().map(|c| c.respond_by.next_day()));
+        due.extend(company.teams.iter().filter_map(|t| t.raise_demand.map(|d| d.deadline)));
+        due.extend(company.next_hardware_expiry(&self.balance.storage));
+        let horizon = self.date.add_days(days);
+        for date in due.into_iter().filter(|d| *d <= horizon) {
+            days = days.min(self.date.days_until(&date).saturating_sub(1));
+        }
+
+        // Counters that finish something when they run out.
+        let period = self.balance.payroll.renegotiation_years.max(1) * 365;
+        let talks = company.teams.iter().filter(|t| !t.furloughed)
+            .chain(self.competitors.iter().flat_map(|c| &c.company.teams))
+            .map(|t| period - t.experience_days % period - 1);
+        let training = company.astronauts.astronauts.iter().filter_map(|a| match a.status {
+            crate::astronaut::AstronautStatus::Training { days_remaining } => Some(days_remaining.saturating_sub(1)),
+            _ => None,
+        });
+        let reviews = company.external_reviews.iter().map(|r| r.days_remaining.saturating_sub(1));
+        let factories = std::iter::once(company)
+            .chain(self.competitors.iter().map(|c| &c.company))
+            .map(|c| c.manufacturing.quiet_days(&self.balance.costs));
+        days = talks.chain(training).chain(reviews).chain(factories).fold(days, u32::min);
+
+        // R&D milestones depend on each project's stage, so probe for
+        // them, halving the stretch until none falls in it.
+        while days > 1 && !company.research_quiet_for(days, &self.balance) {
+            days /= 2;
+        }
+        if days == 1 && !company.research_quiet_for(1, &self.balance) {
+            return 0;
+        }
+        days
+    }
+
+    /// Run `days` days that [`Self::quiet_days`] vouched for in one
+    /// step. Raises no events and moves no money: salaries wait for
+    /// the month start, and what accrues daily is the cost attributed
+    /// to projects and orders.
+    pub(super) fn advance_quiet_days(&mut self, days: u32) {
+        self.date = self.date.add_days(days);
+        let ratio = self.world_market.ease_days(days, self.vendor_engines_bought(), &self.balance.world_market);
+        self.reprice_vendor_engines(ratio);
+
+        let company = &mut self.player_company;
+        let research = company.tick_research(days, &mut self.seed.contingent_rng, &self.balance);
+        debug_assert!(research.milestones == 0 && research.events.is_empty(), "R&D milestone in a quiet stretch");
+        for team in &mut company.teams {
+            team.recover_morale(days, &self.balance.morale);
+        }
+        company.marketing.fade_days(days, &self.balance.marketing);
+        let qualified = company.astronauts.tick_days(days, &self.balance.astronauts);
+        debug_assert!(qualified.is_empty(), "astronaut qualified in a quiet stretch");
+        for team in company.teams.iter_mut().filter(|t| !t.furloughed) {
+            team.experience_days += days;
+        }
+        company.manufacturing.advance_quiet_days(days, &self.balance.costs);
+
+        for infra in &mut self.infrastructure {
+            infra.boil_off_days(days, &self.balance.isru);
+        }
+        for comp in &mut self.competitors {
+            for team in &mut comp.company.teams {
+                team.experience_days += days;
+            }
+            comp.company.manufacturing.advance_quiet_days(days, &self.balance.costs);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Buying and sending out the recovery fleet (see
+//! [`crate::recovery_fleet`]), and its monthly upkeep.
+
+use crate::assets::AssetKind;
+use crate::budget::Department;
+use crate::event::GameEvent;
+use crate::recovery_fleet::{RecoveryAssetId, RecoveryAssetKind, RecoveryFleetError};
+
+use super::*;
+
+impl GameState {
+    /// Buy a landing zone or drone ship. It goes on the books as a
+    /// fixed asset; a drone ship starts in port.
+    pub fn buy_recovery_asset(&mut self, kind: RecoveryAssetKind) -> Result<GameEvent, RecoveryFleetError> {
+        let cfg = &self.balance.recovery_fleet;
+        let cost = match kind {
+            RecoveryAssetKind::LandingZone => cfg.landing_zone_cost,
+            RecoveryAssetKind::DroneShip => cfg.drone_ship_cost,
+        };
+        let company = &mut self.player_company;
+        if company.money < cost || !company.budgets.allows(Department::Facilities, cost) {
+            return Err(RecoveryFleetError::CantAfford { cost });
+        }
+        let name = company.recovery_fleet.add(kind).name.clone();
+        company.money -= cost;
+        company.budgets.record(Department::Facilities, cost);
+        company.assets.acquire(AssetKind::RecoveryAsset { name: name.clone() }, cost, &self.balance.depreciation);
+        let evt = GameEvent::RecoveryAssetPurchased { name, cost };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Send drone ship `id` out to the landing area ahead of a launch.
+    pub fn send_out_drone_ship(&mut self, id: RecoveryAssetId) -> Result<GameEvent, RecoveryFleetError> {
+        let fleet = &mut self.player_company.recovery_fleet;
+        let on_station = fleet.send_out(id, self.date, &self.balance.recovery_fleet)?;
+        let name = fleet.assets.iter().find(|a| a.id == id).map(|a| a.name.clone()).unwrap_or_default();
+        let evt = GameEvent::DroneShipSailed { name, on_station };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+
+    /// Monthly: crews, moorings and upkeep for the recovery fleet.
+    pub(super) fn charge_recovery_fleet_upkeep(&mut self, events: &mut Vec<GameEvent>) {
+        let amount = self.player_company.recovery_fleet.monthly_upkeep(&self.balance.recovery_fleet);
+        if amount <= 0.0 {
+            return;
+        }
+        self.player_company.money -= amount;
+        self.record_expense(amount);
+        self.player_company.budgets.record(Department::Facilities, amount);
+        let evt = GameEvent::RecoveryFleetUpkeepPaid { amount };
+        self.event_log.push(self.date, evt.clone());
+        events.push(evt);
+    }
+}
@@ -0,0 +1,182 @@
+//! The regulator: license applications, environmental reviews, export
+//! controls, and fines. Launch clearance itself is checked when the
+//! manifest is built (see `build_launch_payloads`).
+
+use crate::budget::Department;
+use crate::contract::{Contract, ContractId};
+use crate::event::GameEvent;
+use crate::regulation::{ExportFiling, LicenseApplication, LicenseClass};
+
+use super::*;
+
+impl GameState {
+    /// Apply for a launch license. The fee is charged now; the license
+    /// is granted after the class's lead time. Returns None if the
+    /// license is already held or applied for.
+    pub fn apply_for_license(&mut self, class: LicenseClass) -> Option<GameEvent> {
+        let compliance = &self.player_company.compliance;
+        if compliance.has_license(class) || compliance.application(class).is_some() {
+            return None;
+        }
+        let (fee, lead_days) = self.balance.regulation.license_terms(class);
+        let ready_on = self.date.add_days(lead_days);
+        self.player_company.money -= fee;
+        self.record_expense(fee);
+        self.player_company.budgets.record(Department::Testing, fee);
+        self.player_company.compliance.applications.push(LicenseApplication { class, ready_on });
+        let evt = GameEvent::LicenseApplied { class, fee, ready_on };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Grant licenses whose application lead time has elapsed.
+    pub(super) fn process_license_applications(&mut self, events: &mut Vec<GameEvent>) {
+        for class in self.player_company.compliance.grant_due(self.date) {
+            let evt = GameEvent::LicenseGranted { class };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+        if self.player_company.compliance.grant_clearance_due(self.date) {
+            let evt = GameEvent::SecurityClearanceGranted;
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+
+    /// Start the facility security-clearance upgrade export-controlled
+    /// customers require. The cost is charged now; the clearance is
+    /// granted after its lead time. Returns None if the clearance is
+    /// already held or under way.
+    pub fn upgrade_security_clearance(&mut self) -> Option<GameEvent> {
+        let compliance = &self.player_company.compliance;
+        if compliance.security_clearance || compliance.clearance_ready_on.is_some() {
+            return None;
+        }
+        let cost = self.balance.regulation.clearance_upgrade_cost;
+        let ready_on = self.date.add_days(self.balance.regulation.clearance_upgrade_days);
+        self.player_company.money -= cost;
+        self.record_expense(cost);
+        self.player_company.budgets.record(Department::Testing, cost);
+        self.player_company.compliance.clearance_ready_on = Some(ready_on);
+        let evt = GameEvent::SecurityClearanceStarted { cost, ready_on };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Whether export controls keep the player from competing for a
+    /// contract today: it's controlled, and the company isn't cleared
+    /// or is serving a ban.
+    pub fn export_blocks_contract(&self, contract: &Contract) -> bool {
+        contract.export_controlled
+            && !self.player_company.compliance.export_eligible(self.date)
+    }
+
+    /// File the export paperwork for a controlled contract the player
+    /// just took on, charging its fee. No-op for uncontrolled work.
+    pub(super) fn file_export_paperwork(&mut self, contract: &Contract) -> Option<GameEvent> {
+        if !contract.export_controlled {
+            return None;
+        }
+        let fee = self.balance.regulation.export_paperwork_fee;
+        let ready_on = self.date.add_days(self.balance.regulation.export_paperwork_days);
+        self.player_company.money -= fee;
+        self.record_expense(fee);
+        self.player_company.budgets.record(Department::Testing, fee);
+        self.player_company.compliance.export_filings.push(ExportFiling {
+            contract_id: contract.id,
+            ready_on,
+        });
+        let evt = GameEvent::ExportPaperworkFiled {
+            contract_name: contract.name.clone(),
+            fee,
+            ready_on,
+        };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Clear a launch's controlled payloads with the regulator. Each
+    /// one flying before its paperwork has cleared is a violation: a
+    /// fine, and a ban from controlled work that also withdraws the
+    /// player's pending bids on it. The flown contracts' filings are
+    /// closed either way.
+    pub(super) fn record_export_launch(&mut self, contract_ids: &[ContractId]) -> Vec<GameEvent> {
+        let cfg = self.balance.regulation.clone();
+        let mut events = Vec::new();
+        for cid in contract_ids {
+            let Some(contract) = self.player_company.active_contracts.iter()
+                .find(|c| c.id == *cid && c.export_controlled)
+            else {
+                continue;
+            };
+            let cleared = self.player_company.compliance.export_filing(*cid)
+                .is_some_and(|f| f.ready_on <= self.date);
+            if cleared {
+                continue;
+            }
+            let contract_name = contract.name.clone();
+            let banned_until = self.date.add_days(cfg.export_ban_days);
+            self.player_company.money -= cfg.export_violation_fine;
+            self.record_expense(cfg.export_violation_fine);
+            let compliance = &mut self.player_company.compliance;
+            compliance.export_ban_until =
+                Some(compliance.export_ban_until.map_or(banned_until, |u| u.max(banned_until)));
+            for c in self.available_contracts.iter_mut().filter(|c| c.export_controlled) {
+                c.player_bid = None;
+            }
+            events.push(GameEvent::ExportViolation {
+                contract_name,
+                fine: cfg.export_violation_fine,
+                banned_until,
+            });
+        }
+        self.player_company.compliance.export_filings.retain(|f| !contract_ids.contains(&f.contract_id));
+        for evt in &events {
+            self.event_log.push(self.date, evt.clone());
+        }
+        events
+    }
+
+    /// Book a launch with the regulator: counts it against the site's
+    /// cadence, tallies solid-motor use toward the next environmental
+    /// review, and fines failure debris (which also starts a review).
+    pub(super) fn record_regulated_launch(&mut self, uses_solids: bool, failed: bool) -> Vec<GameEvent> {
+        let cfg = self.balance.regulation.clone();
+        self.player_company.launch_site.record_launch(self.date, cfg.cadence_window_days);
+
+        let mut events = Vec::new();
+        if failed {
+            self.player_company.money -= cfg.debris_fine;
+            self.record_expense(cfg.debris_fine);
+            events.push(GameEvent::RegulatoryFine {
+                reason: "launch failure debris".into(),
+                amount: cfg.debris_fine,
+            });
+            events.push(self.start_environmental_review("failure debris"));
+        } else if uses_solids {
+            let compliance = &mut self.player_company.compliance;
+            compliance.solid_launches_since_review += 1;
+            if compliance.solid_launches_since_review >= cfg.solid_launches_per_review {
+                events.push(self.start_environmental_review("solid motor exhaust"));
+            }
+        }
+        for evt in &events {
+            self.event_log.push(self.date, evt.clone());
+        }
+        events
+    }
+
+    /// Close the site for an environmental review and charge its fee.
+    pub(super) fn start_environmental_review(&mut self, reason: &str) -> GameEvent {
+        let cfg = &self.balance.regulation;
+        let until = self.date.add_days(cfg.review_days);
+        let fee = cfg.review_fee;
+        let compliance = &mut self.player_company.compliance;
+        compliance.review_until = Some(compliance.review_until.map_or(until, |u| u.max(until)));
+        compliance.solid_launches_since_review = 0;
+        self.player_company.money -= fee;
+        self.record_expense(fee);
+        self.player_company.budgets.record(Department::Testing, fee);
+        GameEvent::EnvironmentalReview { reason: reason.to_string(), until }
+    }
+}
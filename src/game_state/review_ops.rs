@@ -0,0 +1,56 @@
+//! Ordering external design reviews. The countdown and findings run
+//! with the rest of R&D in `Company::tick_daily_research`.
+
+use crate::budget::Department;
+use crate::engine_project::EngineDesignStatus;
+use crate::event::GameEvent;
+use crate::external_review::{self, ExternalReview, ReviewError, ReviewTarget};
+use crate::rocket_project::RocketDesignStatus;
+
+use super::*;
+
+impl GameState {
+    /// Pay an outside firm to review a design in testing.
+    pub fn order_external_review(&mut self, target: ReviewTarget) -> Result<GameEvent, ReviewError> {
+        let cfg = &self.balance.external_review;
+        let company = &self.player_company;
+        let (target_name, fee) = match target {
+            ReviewTarget::Engine(id) => {
+                let p = company.find_engine_project(id).ok_or(ReviewError::NoSuchProject)?;
+                if !matches!(p.status, EngineDesignStatus::Testing { .. }) {
+                    return Err(ReviewError::NotInTesting);
+                }
+                (p.design.name.clone(), external_review::engine_fee(p.complexity, cfg))
+            }
+            ReviewTarget::Rocket(id) => {
+                let p = company.rocket_projects.iter()
+                    .find(|p| p.project_id == id)
+                    .ok_or(ReviewError::NoSuchProject)?;
+                if !matches!(p.status, RocketDesignStatus::Testing { .. }) {
+                    return Err(ReviewError::NotInTesting);
+                }
+                (p.design.name.clone(), cfg.rocket_fee)
+            }
+        };
+        if company.external_reviews.iter().any(|r| r.target == target) {
+            return Err(ReviewError::AlreadyUnderReview);
+        }
+        if company.money < fee {
+            return Err(ReviewError::CantAfford { cost: fee });
+        }
+
+        let days = cfg.duration_days.max(1);
+        self.player_company.money -= fee;
+        self.record_expense(fee);
+        self.player_company.budgets.record(Department::Engineering, fee);
+        self.player_company.external_reviews.push(ExternalReview {
+            target,
+            target_name: target_name.clone(),
+            fee,
+            days_remaining: days,
+        });
+        let evt = GameEvent::ExternalReviewOrdered { target_name, fee, days };
+        self.event_log.push(self.date, evt.clone());
+        Ok(evt)
+    }
+}
@@ -0,0 +1,182 @@
+//! Sandbox edits (see [`crate::sandbox`]). Each checks the balance
+//! allows them and, once made, marks the game as sandbox and logs what
+//! was done.
+
+use crate::engine_project::{EngineDesignStatus, EngineSource, WorkEvent};
+use crate::event::GameEvent;
+use crate::flight::{CompanyRef, FlightId, FlightStatus};
+use crate::manufacturing::{InventoryEngine, InventoryRocket};
+use crate::rocket_project::{RocketDesignStatus, RocketWorkEvent};
+use crate::sandbox::{SandboxError, SandboxTarget};
+
+use super::*;
+
+impl GameState {
+    pub(super) fn check_sandbox(&self) -> Result<(), SandboxError> {
+        if self.balance.sandbox.enabled { Ok(()) } else { Err(SandboxError::Disabled) }
+    }
+
+    pub(super) fn record_sandbox_edit(&mut self, description: String) -> GameEvent {
+        self.sandbox = true;
+        let evt = GameEvent::SandboxEdit { description };
+        self.event_log.push(self.date, evt.clone());
+        evt
+    }
+
+    /// Put `amount` in the bank. It isn't booked as income.
+    pub fn sandbox_spawn_money(&mut self, amount: f64) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        self.player_company.money += amount;
+        Ok(self.record_sandbox_edit(format!("spawned {}", crate::resources::format_money(amount))))
+    }
+
+    /// Do `work` units of testing on a design being tested at once, with
+    /// the flaw discovery rolls that work would bring.
+    pub fn sandbox_grant_testing_work(&mut self, target: SandboxTarget, work: f64) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        let company = &mut self.player_company;
+        let rng = &mut self.seed.contingent_rng;
+        let next_flaw_id = &mut company.next_flaw_id;
+        let (name, found) = match target {
+            SandboxTarget::Engine(id) => {
+                let ep = company.engine_projects.iter_mut().find(|ep| ep.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                if !matches!(ep.status, EngineDesignStatus::Testing { .. }) {
+                    return Err(SandboxError::NotTesting);
+                }
+                let found = ep.apply_work(work, rng, next_flaw_id, &self.balance).iter()
+                    .filter(|e| matches!(e, WorkEvent::FlawDiscovered { .. }))
+                    .count();
+                (ep.design.name.clone(), found)
+            }
+            SandboxTarget::Rocket(id) => {
+                let rp = company.rocket_projects.iter_mut().find(|rp| rp.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                if !matches!(rp.status, RocketDesignStatus::Testing { .. }) {
+                    return Err(SandboxError::NotTesting);
+                }
+                let found = rp.apply_work(work, rng, next_flaw_id, &self.balance).iter()
+                    .filter(|e| matches!(e, RocketWorkEvent::FlawDiscovered { .. }))
+                    .count();
+                (rp.design.name.clone(), found)
+            }
+        };
+        Ok(self.record_sandbox_edit(format!("granted {:.0} testing work to {} ({} flaws found)", work, name, found)))
+    }
+
+    /// Finish a design's remaining design work now. It moves into
+    /// testing with its flaws rolled as usual.
+    pub fn sandbox_complete_design(&mut self, target: SandboxTarget) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        let company = &mut self.player_company;
+        let rng = &mut self.seed.contingent_rng;
+        let next_flaw_id = &mut company.next_flaw_id;
+        let name = match target {
+            SandboxTarget::Engine(id) => {
+                let pi = company.engine_projects.iter().position(|ep| ep.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                let ep = &mut company.engine_projects[pi];
+                let EngineDesignStatus::InDesign { work_completed, work_required } = ep.status else {
+                    return Err(SandboxError::NotInDesign);
+                };
+                ep.apply_work((work_required - work_completed).max(0.0), rng, next_flaw_id, &self.balance);
+                let name = ep.design.name.clone();
+                // Already in the log; the caller gets the sandbox event.
+                self.apply_tech_deficiencies(pi, &mut Vec::new());
+                name
+            }
+            SandboxTarget::Rocket(id) => {
+                let rp = company.rocket_projects.iter_mut().find(|rp| rp.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                let RocketDesignStatus::InDesign { work_completed, work_required } = rp.status else {
+                    return Err(SandboxError::NotInDesign);
+                };
+                rp.apply_work((work_required - work_completed).max(0.0), rng, next_flaw_id, &self.balance);
+                rp.design.name.clone()
+            }
+        };
+        Ok(self.record_sandbox_edit(format!("completed the design of {}", name)))
+    }
+
+    /// Put `count` new units of a finished design in stock: engines, or
+    /// integrated rockets built to the project's current revision. They
+    /// cost nothing and carry the design's flaws.
+    pub fn sandbox_add_inventory(&mut self, target: SandboxTarget, count: u32) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        let today = self.date;
+        let company = &mut self.player_company;
+        let name = match target {
+            SandboxTarget::Engine(id) => {
+                let ep = company.engine_projects.iter().find(|ep| ep.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                if matches!(ep.status, EngineDesignStatus::Proposed { .. } | EngineDesignStatus::InDesign { .. }) {
+                    return Err(SandboxError::DesignIncomplete);
+                }
+                let ep = ep.clone();
+                for _ in 0..count {
+                    let item_id = company.manufacturing.next_inventory_id();
+                    company.manufacturing.inventory.engines.push(InventoryEngine {
+                        item_id,
+                        source: EngineSource::PlayerDesign(ep.project_id),
+                        engine_id: ep.design.id,
+                        engine_name: ep.design.name.clone(),
+                        build_cost: 0.0,
+                        revision: ep.revision,
+                        flaw_ids: ep.flaws.iter().map(|f| f.id).collect(),
+                        improvements: ep.improvements.iter().filter(|i| i.actualized).cloned().collect(),
+                        built_on: Some(today),
+                        expired: false,
+                    });
+                }
+                ep.design.name
+            }
+            SandboxTarget::Rocket(id) => {
+                let rp = company.rocket_projects.iter().find(|rp| rp.project_id == id)
+                    .ok_or(SandboxError::NoSuchProject)?;
+                if matches!(rp.status, RocketDesignStatus::InDesign { .. }) {
+                    return Err(SandboxError::DesignIncomplete);
+                }
+                let rp = rp.clone();
+                for _ in 0..count {
+                    let item_id = company.manufacturing.next_inventory_id();
+                    company.manufacturing.inventory.rockets.push(InventoryRocket {
+                        item_id,
+                        rocket_project_id: rp.project_id,
+                        design_id: rp.design.id,
+                        rocket_name: rp.design.name.clone(),
+                        build_cost: 0.0,
+                        revision: rp.revision,
+                        rocket_flaws: rp.flaws.clone(),
+                        design: Some(rp.design.clone()),
+                        built_on: Some(today),
+                        expired: false,
+                        propellant_load: Default::default(),
+                        boosters: Vec::new(),
+                    });
+                }
+                rp.design.name
+            }
+        };
+        Ok(self.record_sandbox_edit(format!("added {} × {} to inventory", count, name)))
+    }
+
+    /// Skip a flight ahead to the start of its last leg with a day to
+    /// go; it arrives on the next tick. The burns for the legs skipped
+    /// are never made.
+    pub fn sandbox_teleport_flight(&mut self, id: FlightId) -> Result<GameEvent, SandboxError> {
+        self.check_sandbox()?;
+        let flight = self.active_flights.iter_mut()
+            .find(|f| f.id == id && f.company == CompanyRef::Player)
+            .ok_or(SandboxError::NoSuchFlight)?;
+        if !matches!(flight.status, FlightStatus::InTransit) || flight.route.is_empty() {
+            return Err(SandboxError::NotInTransit);
+        }
+        flight.current_leg = flight.route.len() - 1;
+        let from = flight.route[flight.current_leg].from.clone();
+        flight.current_location = from.clone();
+        flight.rocket.location = from;
+        flight.leg_days_remaining = 1;
+        let description = format!("moved {} to its final approach to {}", flight.rocket_name, flight.destination());
+        Ok(self.record_sandbox_edit(description))
+    }
+}
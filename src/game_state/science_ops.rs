@@ -0,0 +1,76 @@
+//! Science missions: data-return contracts paid day by day as their
+//! assets send data home.
+
+use crate::contract::Contract;
+use crate::event::GameEvent;
+use crate::science::{self, ScienceDay, ScienceMission};
+
+use super::*;
+
+impl GameState {
+    /// `contract` was delivered: if it pays for returned data, start its
+    /// data campaign. `rocket_project_id` is the lineage that flew it,
+    /// None for a launch provider.
+    pub(super) fn start_science_mission(
+        &mut self,
+        contract: &Contract,
+        partial: bool,
+        rocket_project_id: Option<RocketProjectId>,
+    ) -> Option<GameEvent> {
+        let (value, months) = contract.data_return(partial, &self.balance.markets)?;
+        self.science_missions.push(ScienceMission::new(
+            contract, rocket_project_id, value, months,
+            &mut self.seed.contingent_rng, &self.balance.science,
+        ));
+        let evt = GameEvent::ScienceCampaignStarted { contract_name: contract.name.clone(), months };
+        self.event_log.push(self.date, evt.clone());
+        Some(evt)
+    }
+
+    /// Share of a science asset's data heard at `location_id`. A working
+    /// constellation there relays everything.
+    pub fn science_coverage(&self, location_id: &str) -> f64 {
+        let relayed = self.constellations.iter()
+            .any(|c| c.location == location_id && self.constellation_operational(c) > 0);
+        science::coverage(location_id, relayed, &self.balance.science)
+    }
+
+    /// A day of data from every science asset, paid as it comes down.
+    /// Assets that finish their campaign or fail drop off.
+    pub(super) fn tick_science(&mut self, events: &mut Vec<GameEvent>) {
+        let mut index = 0;
+        while index < self.science_missions.len() {
+            let coverage = self.science_coverage(&self.science_missions[index].location_id);
+            let mission = &mut self.science_missions[index];
+            let (paid, done) = match mission.day(coverage) {
+                ScienceDay::Returned(paid) => (paid, None),
+                ScienceDay::Complete(paid) => (paid, Some(GameEvent::ScienceCampaignComplete {
+                    contract_name: mission.contract_name.clone(),
+                    returned: mission.returned,
+                })),
+                ScienceDay::Lost => (0.0, Some(GameEvent::ScienceAssetLost {
+                    contract_name: mission.contract_name.clone(),
+                    returned: mission.returned,
+                })),
+            };
+            let project = mission.rocket_project_id;
+            if paid > 0.0 {
+                self.player_company.money += paid;
+                self.record_income(paid);
+                if let Some(rp) = self.player_company.rocket_projects.iter_mut()
+                    .find(|rp| Some(rp.project_id) == project)
+                {
+                    rp.flight_revenue += paid;
+                }
+            }
+            match done {
+                Some(evt) => {
+                    self.science_missions.remove(index);
+                    self.event_log.push(self.date, evt.clone());
+                    events.push(evt);
+                }
+                None => index += 1,
+            }
+        }
+    }
+}
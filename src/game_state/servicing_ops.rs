@@ -0,0 +1,189 @@
+//! Servicing missions: daily wear on plants and parked spacecraft, and
+//! the kits launched to restore them.
+
+use crate::budget::Department;
+use crate::contract::destination_display_name;
+use crate::docking::{self, DockingOutcome};
+use crate::event::GameEvent;
+use crate::flight::Payload;
+use crate::launch::LaunchRecord;
+use crate::manufacturing::InventoryItemId;
+use crate::servicing::{self, ServiceTarget, ServicingError, ServicingKit, FULL_HEALTH};
+
+use super::*;
+
+impl GameState {
+    /// Name and health of what `target` points at, if it's still there.
+    fn service_target_status(&self, target: &ServiceTarget) -> Option<(String, f64)> {
+        match target {
+            ServiceTarget::IsruPlants { location } => {
+                let health = self.infrastructure_at(location)?.lowest_plant_health()?;
+                Some((format!("ISRU plants at {}", destination_display_name(location)), health))
+            }
+            ServiceTarget::Spacecraft { id, location } => self.spacecraft.iter()
+                .find(|sc| sc.id == *id && sc.location == *location)
+                .map(|sc| (sc.name.clone(), sc.health)),
+        }
+    }
+
+    /// The most worn asset that a kit could help, with the propellant a
+    /// kit for it should carry (enough to fill a spacecraft's tanks).
+    pub fn most_worn_asset(&self) -> Option<(ServiceTarget, f64)> {
+        let plants = self.infrastructure.iter().filter_map(|infra| {
+            let health = infra.lowest_plant_health()?;
+            (health < FULL_HEALTH)
+                .then(|| (ServiceTarget::IsruPlants { location: infra.location.clone() }, 0.0, health))
+        });
+        let spacecraft = self.spacecraft.iter().filter_map(|sc| {
+            let room = servicing::tank_room_kg(&sc.design, &sc.rocket);
+            (sc.health < FULL_HEALTH || room > 0.0).then(|| (
+                ServiceTarget::Spacecraft { id: sc.id, location: sc.location.clone() },
+                room,
+                sc.health,
+            ))
+        });
+        plants.chain(spacecraft)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(target, propellant_kg, _)| (target, propellant_kg))
+    }
+
+    /// Buy a servicing kit for `target`, carrying `propellant_kg` for a
+    /// spacecraft's tanks, and launch it on the inventory rocket
+    /// `rocket_item_id` to the target's location.
+    pub fn launch_servicing_mission(
+        &mut self,
+        rocket_item_id: InventoryItemId,
+        target: ServiceTarget,
+        propellant_kg: f64,
+    ) -> Result<(Vec<GameEvent>, Option<LaunchRecord>), ServicingError> {
+        let (asset_name, health) = self.service_target_status(&target).ok_or(ServicingError::NoSuchAsset)?;
+        let propellant_kg = match target {
+            ServiceTarget::IsruPlants { .. } => 0.0,
+            ServiceTarget::Spacecraft { .. } => propellant_kg.max(0.0),
+        };
+        if health >= FULL_HEALTH && propellant_kg <= 0.0 {
+            return Err(ServicingError::NothingToService);
+        }
+        if !self.player_company.manufacturing.inventory.rockets.iter().any(|r| r.item_id == rocket_item_id) {
+            return Err(ServicingError::RocketMissing);
+        }
+        let location = target.location().to_string();
+        let pad = &self.player_company.launch_site;
+        if let Some(lease) = pad.lease_on(self.date) {
+            return Err(ServicingError::PadLeased {
+                lessee: lease.lessee.clone(),
+                free_on: pad.next_free_day(self.date),
+            });
+        }
+        self.player_company.compliance
+            .check(&location, self.date, pad, &self.balance.regulation)
+            .map_err(ServicingError::NotCompliant)?;
+        let kit = ServicingKit::new(target, asset_name, propellant_kg, &self.balance.servicing);
+        if self.player_company.money < kit.cost {
+            return Err(ServicingError::CantAfford { cost: kit.cost });
+        }
+
+        self.player_company.money -= kit.cost;
+        self.record_expense(kit.cost);
+        self.player_company.budgets.record(Department::Manufacturing, kit.cost);
+        self.launch_rocket(rocket_item_id, &location, vec![Payload::ServicingKit(kit)], false)
+            .ok_or(ServicingError::RocketMissing)
+    }
+
+    /// Hand an arrived kit over to its asset.
+    pub(super) fn service_asset(&mut self, kit: ServicingKit) -> GameEvent {
+        let location = destination_display_name(kit.target.location()).to_string();
+        match &kit.target {
+            ServiceTarget::IsruPlants { location: site } => {
+                if let Some(infra) = self.infrastructure.iter_mut()
+                    .find(|i| i.location == *site && !i.isru_plants.is_empty())
+                {
+                    for plant in &mut infra.isru_plants {
+                        plant.health = FULL_HEALTH;
+                    }
+                    return GameEvent::AssetServiced { asset: kit.asset_name, location, propellant_kg: 0.0 };
+                }
+            }
+            ServiceTarget::Spacecraft { id, location: site } => {
+                if let Some(sc) = self.spacecraft.iter_mut().find(|sc| sc.id == *id && sc.location == *site) {
+                    sc.health = FULL_HEALTH;
+                    let loaded = servicing::top_up(&sc.design, &mut sc.rocket, kit.propellant_kg);
+                    return GameEvent::AssetServiced { asset: sc.name.clone(), location, propellant_kg: loaded };
+                }
+            }
+        }
+        GameEvent::ServicingMissed { asset: kit.asset_name, location }
+    }
+
+    /// Fly an arriving payload's approach to `target`, with `risk` of it
+    /// going wrong. None if it docked; otherwise the payload is lost, and
+    /// a collision also knocks health off the target (or, with no plants
+    /// there yet, just hits the depot).
+    pub(super) fn fly_approach(
+        &mut self,
+        payload: String,
+        target: ServiceTarget,
+        location: &str,
+        risk: f64,
+    ) -> Option<GameEvent> {
+        if risk <= 0.0 {
+            return None;
+        }
+        let cfg = &self.balance.docking;
+        let outcome = docking::roll(risk, &mut self.seed.contingent_rng, cfg);
+        let display = destination_display_name(location).to_string();
+        match outcome {
+            DockingOutcome::Docked => None,
+            DockingOutcome::Aborted => Some(GameEvent::DockingAborted { payload, location: display }),
+            DockingOutcome::Collision => {
+                let damage = cfg.collision_damage;
+                let target_name = match target {
+                    ServiceTarget::Spacecraft { id, location: site } => self.spacecraft.iter_mut()
+                        .find(|sc| sc.id == id && sc.location == site)
+                        .map(|sc| {
+                            sc.health = servicing::wear(sc.health, damage);
+                            sc.name.clone()
+                        }),
+                    ServiceTarget::IsruPlants { location: site } => self.infrastructure.iter_mut()
+                        .find(|i| i.location == site && !i.isru_plants.is_empty())
+                        .map(|infra| {
+                            for plant in &mut infra.isru_plants {
+                                plant.health = servicing::wear(plant.health, damage);
+                            }
+                            format!("ISRU plants at {}", display)
+                        }),
+                };
+                let target = target_name.unwrap_or_else(|| format!("the depot at {}", display));
+                Some(GameEvent::DockingCollision { payload, target, location: display })
+            }
+        }
+    }
+
+    /// A day's wear on every plant and parked spacecraft. Reports each
+    /// asset the day it wears out.
+    pub(super) fn tick_asset_wear(&mut self, events: &mut Vec<GameEvent>) {
+        let cfg = &self.balance.servicing;
+        let mut worn_out = Vec::new();
+        for infra in &mut self.infrastructure {
+            for plant in &mut infra.isru_plants {
+                let before = plant.health;
+                plant.health = servicing::wear(before, cfg.plant_wear_per_day);
+                if before > 0.0 && plant.health <= 0.0 {
+                    worn_out.push((plant.design.name.clone(), infra.location.clone()));
+                }
+            }
+        }
+        for sc in &mut self.spacecraft {
+            let before = sc.health;
+            sc.health = servicing::wear(before, cfg.spacecraft_wear_per_day);
+            if before > 0.0 && sc.health <= 0.0 {
+                worn_out.push((sc.name.clone(), sc.location.clone()));
+            }
+        }
+        for (asset, location) in worn_out {
+            let evt = GameEvent::AssetWornOut { asset, location: destination_display_name(&location).to_string() };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+}
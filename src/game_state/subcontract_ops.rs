@@ -0,0 +1,102 @@
+//! Subcontracting: booking outside providers to fly accepted
+//! contracts, settling when they launch, and the providers' monthly
+//! and yearly booking cycle.
+
+use crate::event::GameEvent;
+use crate::flight::Payload;
+use crate::subcontract::{Subcontract, SubcontractError};
+
+use super::*;
+
+impl GameState {
+    /// Hand accepted contract `contract_index` to launch provider
+    /// `provider_index`. The fee is paid now; the contract leaves the
+    /// company's books and is settled when the provider flies it.
+    pub fn subcontract_launch(
+        &mut self,
+        contract_index: usize,
+        provider_index: usize,
+    ) -> Result<GameEvent, SubcontractError> {
+        let contract = self.player_company.active_contracts.get(contract_index)
+            .ok_or(SubcontractError::NoSuchContract)?;
+        let provider = self.launch_providers.get(provider_index)
+            .ok_or(SubcontractError::NoSuchProvider)?;
+        let flying = self.active_flights.iter()
+            .flat_map(|f| f.payloads.iter())
+            .any(|p| matches!(p, Payload::ContractDelivery { contract_id, .. } if *contract_id == contract.id));
+        if flying {
+            return Err(SubcontractError::AlreadyFlying);
+        }
+        if !provider.serves(&contract.destination) {
+            return Err(SubcontractError::DestinationNotServed);
+        }
+        if provider.slots_left() == 0 {
+            return Err(SubcontractError::FullyBooked);
+        }
+        let launch_date = self.date.add_days(provider.lead_days);
+        if launch_date > contract.deadline {
+            return Err(SubcontractError::TooLate { launch_date });
+        }
+        let fee = provider.quote(contract);
+        if fee > self.player_company.money {
+            return Err(SubcontractError::CannotAfford { fee });
+        }
+
+        let cfg = self.balance.subcontract.clone();
+        let provider = &mut self.launch_providers[provider_index];
+        provider.book(&cfg);
+        let provider = provider.name.clone();
+        let mut contract = self.player_company.active_contracts.remove(contract_index);
+        // A cost-plus customer reimburses the fee like any other cost.
+        contract.record_cost(fee);
+        self.player_company.money -= fee;
+        self.record_expense(fee);
+
+        let evt = GameEvent::ContractSubcontracted {
+            contract_name: contract.name.clone(),
+            provider: provider.clone(),
+            fee,
+        };
+        self.event_log.push(self.date, evt.clone());
+        self.player_company.subcontracts.push(Subcontract { contract, provider, fee, launch_date });
+        Ok(evt)
+    }
+
+    /// Settle subcontracts whose provider launched today. Providers
+    /// don't fail the company's customers.
+    pub(super) fn settle_subcontracts(&mut self, events: &mut Vec<GameEvent>) {
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.player_company.subcontracts)
+            .into_iter()
+            .partition(|s| s.launch_date <= self.date);
+        self.player_company.subcontracts = pending;
+        for sub in due {
+            let settlement = sub.contract.settle(false, sub.launch_date, false, &self.balance.markets);
+            self.demand.record_delivery(sub.contract.market_id, &sub.contract.destination);
+            self.player_company.money += settlement.payment;
+            self.record_income(settlement.payment);
+            self.player_company.reputation.on_subcontracted_delivery(
+                &self.balance.reputation, self.balance.subcontract.fame_share,
+            );
+            let evt = GameEvent::SubcontractDelivered {
+                contract_name: sub.contract.name.clone(),
+                provider: sub.provider,
+                payment: settlement.payment,
+            };
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+            events.extend(self.start_science_mission(&sub.contract, false, None));
+        }
+    }
+
+    /// Month start: providers' prices ease back toward base, and a new
+    /// year opens their manifests.
+    pub(super) fn tick_launch_providers(&mut self) {
+        let cfg = &self.balance.subcontract;
+        for provider in &mut self.launch_providers {
+            provider.ease(cfg);
+            if self.date.month == 1 {
+                provider.new_year();
+            }
+        }
+    }
+}
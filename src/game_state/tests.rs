@@ -163,6 +163,11 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         structural_mass_kg: 5000.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let stage2 = Stage {
         id: StageId(2),
@@ -173,6 +178,11 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         structural_mass_kg: 1000.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     // Stage 3 sized so that LEO→GTO (2440 m/s) + GTO→GEO (1500 m/s) = 3940 m/s
     // exceeds its dv, ensuring it gets exhausted and jettisoned mid-flight.
@@ -186,6 +196,11 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         structural_mass_kg: 300.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
 
     let design = RocketDesign {
@@ -196,6 +211,7 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
             vec![stage2],
             vec![stage3],
         ],
+        performance_reserve: Some(0.0),
     };
 
     // Engine projects with guaranteed flaws
@@ -206,6 +222,8 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         activation_chance: 1.0,
         discovery_probability: 1.0,
         discovered: false, trigger: FlawTrigger::PerFlight,
+        subsystem: crate::flaw::Subsystem::Combustion,
+        anomaly: None,
     };
     let flaw2 = Flaw {
         id: FlawId(2),
@@ -214,6 +232,8 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         activation_chance: 1.0,
         discovery_probability: 1.0,
         discovered: false, trigger: FlawTrigger::PerFlight,
+        subsystem: crate::flaw::Subsystem::Combustion,
+        anomaly: None,
     };
 
     let ep1 = EngineProject {
@@ -229,7 +249,7 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         teams_assigned: 0,
         complexity: 6,
         nre_cost: 0.0, improvements: Vec::new(), cumulative_testing_work: 0.0,
-        tech_deficiency_ids: Vec::new(), technology_id: None,
+        tech_deficiency_ids: Vec::new(), technology_id: None, heritage: None, fundamental_issue: None,
     };
     let ep2 = EngineProject {
         project_id: EngineProjectId(2),
@@ -244,7 +264,7 @@ fn make_three_stage_design() -> (RocketDesign, Vec<crate::engine_project::Engine
         teams_assigned: 0,
         complexity: 6,
         nre_cost: 0.0, improvements: Vec::new(), cumulative_testing_work: 0.0,
-        tech_deficiency_ids: Vec::new(), technology_id: None,
+        tech_deficiency_ids: Vec::new(), technology_id: None, heritage: None, fundamental_issue: None,
     };
 
     (design, vec![ep1, ep2])
@@ -267,6 +287,7 @@ fn test_flaw_scoping_by_stage_usage() {
                 design.stage_groups[0].clone(),
                 design.stage_groups[1].clone(),
             ],
+            performance_reserve: Some(0.0),
         };
         two_stage.total_delta_v(0.0)
     };
@@ -281,7 +302,7 @@ fn test_flaw_scoping_by_stage_usage() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(42);
 
     let sim = crate::launch::simulate_launch(
-        &design, "leo", 0.0,
+        &design, "leo", 0.0, 0.0,
         &engine_projects, &rp.flaws, &[], &mut rng,
     );
 
@@ -335,6 +356,7 @@ fn test_flaw_scoping_by_stage_usage() {
         location: "leo".into(),
         rocket_project_id: RocketProjectId(1),
         payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
     };
     gs.spacecraft.push(sc);
 
@@ -385,7 +407,7 @@ fn test_spacecraft_has_remaining_dv_after_leo_launch() {
     use rand::SeedableRng;
     let mut rng = rand::rngs::StdRng::seed_from_u64(99);
     let sim = crate::launch::simulate_launch(
-        &design, "leo", 0.0,
+        &design, "leo", 0.0, 0.0,
         &gs.player_company.engine_projects, &rp.flaws, &[], &mut rng,
     );
 
@@ -422,6 +444,12 @@ fn test_spacecraft_has_remaining_dv_after_leo_launch() {
         launch_partial: false,
         flaw_rolled_groups: sim.flaw_rolled_groups,
         reactor_flaws_rolled: false,
+        revision: 0,
+        crew: Vec::new(),
+        telemetry: Vec::new(),
+        stage_disposals: Vec::new(),
+        docking_risk: 0.0,
+        insertion_error: 0.0,
     };
 
     gs.active_flights.push(flight);
@@ -595,6 +623,11 @@ fn test_hybrid_ion_chemical_to_asteroid_surface() {
         propellant_mass_kg: 200_000.0, structural_mass_kg: 5000.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let stage2 = Stage {
         id: StageId(2), name: "S2".into(),
@@ -602,6 +635,11 @@ fn test_hybrid_ion_chemical_to_asteroid_surface() {
         propellant_mass_kg: 30_000.0, structural_mass_kg: 1000.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
 
     // Stage 3: ion engine for transit (very high Isp, very low thrust)
@@ -625,6 +663,11 @@ fn test_hybrid_ion_chemical_to_asteroid_surface() {
         propellant_mass_kg: 500.0, structural_mass_kg: 50.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
 
     // Stage 4: small hypergolic thruster for asteroid landing
@@ -649,6 +692,11 @@ fn test_hybrid_ion_chemical_to_asteroid_surface() {
         propellant_mass_kg: 100.0, structural_mass_kg: 20.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
 
     let design = RocketDesign {
@@ -660,6 +708,7 @@ fn test_hybrid_ion_chemical_to_asteroid_surface() {
             vec![ion_stage],    // group 2: ion transit
             vec![lander_stage], // group 3: hypergolic lander
         ],
+        performance_reserve: Some(0.0),
     };
 
     // Instantiate at LEO (as if we've already launched)
@@ -810,6 +859,84 @@ fn test_rocket_cost_history_includes_full_cost_at_completion() {
         "recorded rocket cost should reflect labor too; got {}", recorded);
 }
 
+#[test]
+fn test_recovered_fairing_discounts_next_build() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let rp_id = setup_buildable_rocket(&mut gs);
+    let mut fairing = crate::stage::Fairing::sized_for(5_000.0);
+    fairing.recover = true;
+    gs.player_company.rocket_projects[0].design.stage_groups[2][0].fairing = Some(fairing.clone());
+
+    // Calm seas guarantee the catch.
+    gs.balance.recovery.max_sea_state = 0;
+    gs.balance.recovery.fairing_calm_sea_success = 1.0;
+    let money_before = gs.player_company.money;
+    let events = gs.recover_fairings(rp_id, "Test");
+    assert!(matches!(events.as_slice(), [GameEvent::FairingRecovery { recovered: true, .. }]));
+    assert_eq!(gs.player_company.money, money_before - gs.balance.recovery.fairing_recovery_cost);
+
+    // Same build with an empty pool pays for a new fairing.
+    let mut baseline = gs.player_company.clone();
+    baseline.manufacturing.recovered_fairings.clear();
+    let (new_cost, _) = baseline.order_rocket_build(0, &gs.balance).unwrap();
+    let (reused_cost, _) = gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    let fairing_cost = crate::resources::fairing_material_cost(
+        fairing.mass_kg, &gs.balance.costs.resource_prices);
+    let saving = fairing_cost * (1.0 - gs.balance.recovery.fairing_refurbish_fraction);
+    assert!((new_cost - reused_cost - saving).abs() < 1.0,
+        "reused={} new={} saving={}", reused_cost, new_cost, saving);
+    assert_eq!(gs.player_company.manufacturing.recovered_fairings.get(&rp_id), Some(&0));
+}
+
+#[test]
+fn test_stages_common_across_designs_build_cheaper_and_learn_together() {
+    use crate::engine::EngineId;
+    use crate::engine_project::{EngineHeritage, EngineProjectId};
+    use crate::manufacturing::{ManufacturingOrder, ManufacturingOrderId, ManufacturingOrderType};
+    use crate::rocket::RocketDesignId;
+    use crate::rocket_project::RocketProjectId;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let rp_id = setup_buildable_rocket(&mut gs);
+    let first_stage_order = |company: &crate::company::Company| company.manufacturing.orders.iter()
+        .find(|o| matches!(o.order_type, ManufacturingOrderType::Stage { rocket_project_id, group_index: 0, stage_index: 0, .. }
+            if rocket_project_id == rp_id))
+        .map(|o| (o.work_required, o.material_cost))
+        .unwrap();
+    let mut alone = gs.player_company.clone();
+    alone.order_rocket_build(0, &gs.balance).unwrap();
+    assert!(gs.player_company.commonality_analysis(&gs.balance).families.is_empty());
+
+    // A second design whose first stage flies a scaled variant of the
+    // same engine on the same tank, with nine flown already.
+    let mut variant = gs.player_company.engine_projects[0].clone();
+    variant.project_id = EngineProjectId(9);
+    variant.design.id = EngineId(9);
+    variant.heritage = Some(EngineHeritage { parent: EngineProjectId(1), inherited_flaws: Vec::new() });
+    gs.player_company.engine_projects.push(variant.clone());
+    let mut sibling = gs.player_company.rocket_projects[0].clone();
+    sibling.project_id = RocketProjectId(2);
+    sibling.design.id = RocketDesignId(2);
+    sibling.design.stage_groups[0][0].engine = variant.design;
+    gs.player_company.rocket_projects.push(sibling);
+    gs.player_company.rocket_build_counts.insert(RocketDesignId(2), 9);
+
+    let analysis = gs.player_company.commonality_analysis(&gs.balance);
+    let family = analysis.family_of(rp_id, 0, 0).expect("shared with the sibling");
+    assert_eq!((family.design_count(), family.builds), (2, 9));
+    assert_eq!(family.key.engine_lineage, crate::engine_project::EngineSource::PlayerDesign(EngineProjectId(1)));
+
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    let stage = &gs.player_company.rocket_projects[0].design.stage_groups[0][0];
+    let mut expected = ManufacturingOrder::new_stage(
+        ManufacturingOrderId(0), rp_id, 0, 0, String::new(), stage.structural_mass_kg, 9, &gs.balance,
+    );
+    expected.apply_commonality(&gs.balance.commonality);
+    let (work, material) = first_stage_order(&gs.player_company);
+    assert!((work - expected.work_required).abs() < 1e-9 && (material - expected.material_cost).abs() < 1e-6);
+    assert!(work < first_stage_order(&alone).0 && material < first_stage_order(&alone).1);
+}
+
 #[test]
 fn test_engine_cost_history_populated_on_completion() {
     use crate::engine_project::EngineProjectId;
@@ -901,10 +1028,16 @@ fn tiny_payload_spacecraft(
         propellant_mass_kg: 500.0, structural_mass_kg: 100.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let design = RocketDesign {
         id: RocketDesignId(id), name: name.into(),
         stage_groups: vec![vec![stage]],
+        performance_reserve: Some(0.0),
     };
     let nested_mass: f64 = nested.iter().map(|p| p.mass_kg()).sum();
     let rocket = design.instantiate(RocketId(id), "earth_surface", nested_mass);
@@ -915,6 +1048,7 @@ fn tiny_payload_spacecraft(
         nested_payloads: nested,
         rocket_project_id: RocketProjectId(id),
         name: name.into(),
+        health: crate::servicing::FULL_HEALTH,
     }
 }
 
@@ -924,6 +1058,14 @@ fn tiny_payload_spacecraft(
 fn arrive_test_flight(
     gs: &mut GameState, destination: &str, payloads: Vec<Payload>,
 ) -> Vec<crate::event::GameEvent> {
+    let flight = arrived_test_flight(gs, destination, payloads);
+    gs.resolve_arrived_flight(flight)
+}
+
+/// A player flight that has just arrived at `destination`.
+fn arrived_test_flight(
+    gs: &GameState, destination: &str, payloads: Vec<Payload>,
+) -> crate::flight::Flight {
     use crate::flight::{Flight, FlightId, FlightLeg, FlightStatus};
     use crate::rocket::{RocketDesign, RocketId};
 
@@ -931,9 +1073,10 @@ fn arrive_test_flight(
     let design = RocketDesign {
         id: RocketDesignId(999), name: "CarrierStub".into(),
         stage_groups: vec![],
+        performance_reserve: Some(0.0),
     };
     let rocket = design.instantiate(RocketId(999), "earth_surface", 0.0);
-    let flight = Flight {
+    Flight {
         id: FlightId(1),
         company: crate::flight::CompanyRef::Player,
         rocket_name: "Carrier".into(),
@@ -957,8 +1100,101 @@ fn arrive_test_flight(
         launch_partial: false,
         flaw_rolled_groups: std::collections::HashSet::new(),
         reactor_flaws_rolled: false,
+        revision: 0,
+        crew: Vec::new(),
+        telemetry: Vec::new(),
+        stage_disposals: Vec::new(),
+        docking_risk: 0.0,
+        insertion_error: 0.0,
+    }
+}
+
+#[test]
+fn test_transit_anomalies_burn_reserves_call_the_team_or_lose_the_mission() {
+    use crate::event::GameEvent;
+    use crate::flight::{AnomalyResponse, FlightLeg, FlightStatus};
+
+    // A fueled tiny vehicle 30 days into a long coast.
+    let in_transit = |gs: &GameState| {
+        let Payload::Spacecraft { design, rocket, .. } = tiny_payload_spacecraft(7, "Probe", "l1", vec![])
+        else { unreachable!() };
+        let mut flight = arrived_test_flight(gs, "l1", vec![]);
+        flight.design = design;
+        flight.rocket = rocket;
+        flight.current_location = "leo".into();
+        flight.route = vec![FlightLeg {
+            from: "leo".into(), to: "l1".into(),
+            delta_v_cost: 100.0, burn_days: 1, coast_days: 60,
+            ambient_pressure_pa: 0.0,
+        }];
+        flight.leg_days_remaining = 30;
+        flight.status = FlightStatus::InTransit;
+        flight
     };
-    gs.resolve_arrived_flight(flight)
+    let only = |correction: f64, team: f64, loss: f64| crate::balance_config::TransitAnomalyConfig {
+        daily_chance: 1.0,
+        benign_weight: 0.0,
+        correction_weight: correction,
+        team_weight: team,
+        loss_weight: loss,
+        correction_dv_min: 50.0,
+        correction_dv_max: 50.0,
+        ..Default::default()
+    };
+
+    // A mid-course correction comes out of the reserve.
+    let reserve = |gs: &GameState| {
+        let f = &gs.active_flights[0];
+        f.rocket.remaining_delta_v(&f.design)
+    };
+    let mut gs = GameState::new("Test".into(), 1_000_000.0, 42);
+    gs.balance.transit_anomaly = only(1.0, 0.0, 0.0);
+    gs.active_flights.push(in_transit(&gs));
+    let dv_before = reserve(&gs);
+    let events = gs.advance_flights();
+    let flight = &gs.active_flights[0];
+    assert_eq!(flight.telemetry.len(), 1);
+    assert_eq!(flight.telemetry[0].response, AnomalyResponse::Correction { delta_v: 50.0 });
+    assert!((dv_before - reserve(&gs) - 50.0).abs() < 1.0);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::TransitAnomaly { .. })));
+
+    // The team works the problem for money, not propellant...
+    gs.balance.transit_anomaly = only(0.0, 1.0, 0.0);
+    let (money, dv) = (gs.player_company.money, reserve(&gs));
+    gs.advance_flights();
+    assert_eq!(gs.player_company.money, money - gs.balance.transit_anomaly.team_response_cost);
+    assert_eq!(reserve(&gs), dv);
+    // ...unless there's no team, and the vehicle burns its way out.
+    gs.player_company.teams.clear();
+    gs.advance_flights();
+    assert!(matches!(
+        gs.active_flights[0].telemetry.last().unwrap().response,
+        AnomalyResponse::Correction { .. },
+    ));
+
+    // A correction beyond the reserve strands the vehicle.
+    gs.balance.transit_anomaly = crate::balance_config::TransitAnomalyConfig {
+        correction_dv_min: 1.0e6, correction_dv_max: 1.0e6, ..only(1.0, 0.0, 0.0)
+    };
+    let events = gs.advance_flights();
+    assert!(gs.active_flights.is_empty());
+    assert!(events.iter().any(|e| matches!(e, GameEvent::SpacecraftStranded { .. })));
+
+    // Some anomalies end the mission.
+    gs.balance.transit_anomaly = only(0.0, 0.0, 1.0);
+    gs.active_flights.push(in_transit(&gs));
+    let events = gs.advance_flights();
+    assert!(gs.active_flights.is_empty());
+    assert!(events.iter().any(|e| matches!(e, GameEvent::SpacecraftLost { .. })));
+
+    // Legs that take no days never roll.
+    let mut flight = in_transit(&gs);
+    flight.route[0].burn_days = 0;
+    flight.route[0].coast_days = 0;
+    flight.leg_days_remaining = 0;
+    gs.active_flights.push(flight);
+    gs.advance_flights();
+    assert!(gs.player_company.launch_history.last().unwrap().transit_anomalies.is_empty());
 }
 
 #[test]
@@ -1020,6 +1256,11 @@ fn test_multiple_payloads_at_same_destination() {
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: crate::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     };
     let contract_b = Contract {
         id: ContractId(2), name: "B".into(),
@@ -1031,6 +1272,11 @@ fn test_multiple_payloads_at_same_destination() {
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: crate::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     };
     gs.player_company.active_contracts.push(contract_a);
     gs.player_company.active_contracts.push(contract_b);
@@ -1072,10 +1318,16 @@ fn push_test_spacecraft(gs: &mut GameState, id: u64, name: &str, location: &str)
         propellant_mass_kg: 100.0, structural_mass_kg: 10.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let design = RocketDesign {
         id: RocketDesignId(id), name: name.into(),
         stage_groups: vec![vec![stage]],
+        performance_reserve: Some(0.0),
     };
     let rocket = design.instantiate(RocketId(id), location, 0.0);
     gs.spacecraft.push(Spacecraft {
@@ -1085,6 +1337,7 @@ fn push_test_spacecraft(gs: &mut GameState, id: u64, name: &str, location: &str)
         location: location.into(),
         rocket_project_id: RocketProjectId(id),
         payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
     });
     gs.spacecraft.len() - 1
 }
@@ -1503,6 +1756,8 @@ fn test_reactor_flaw_activates_mid_flight() {
         discovery_probability: 1.0,
         discovered: false,
         trigger: FlawTrigger::PerDay,
+        subsystem: crate::flaw::Subsystem::Combustion,
+        anomaly: None,
     }];
     gs.player_company.reactor_projects.push(rproj);
 
@@ -1526,10 +1781,16 @@ fn test_reactor_flaw_activates_mid_flight() {
         propellant_mass_kg: 40_000.0, structural_mass_kg: 1_000.0,
         fairing: None,
         power_sources: vec![PowerSource::from_reactor_design(reactor_design)],
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let design = RocketDesign {
         id: RocketDesignId(1), name: "ReactorCraft".into(),
         stage_groups: vec![vec![stage]],
+        performance_reserve: Some(0.0),
     };
     let rocket = design.instantiate(RocketId(1), "leo", 0.0);
     gs.spacecraft.push(Spacecraft {
@@ -1540,6 +1801,7 @@ fn test_reactor_flaw_activates_mid_flight() {
         location: "leo".into(),
         rocket_project_id: RocketProjectId(0),
         payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
     });
 
     gs.fly_spacecraft(0, "geo");
@@ -1596,6 +1858,8 @@ fn test_reactor_perflight_flaw_fires_at_flight_start() {
         discovery_probability: 1.0,
         discovered: false,
         trigger: FlawTrigger::PerFlight,
+        subsystem: crate::flaw::Subsystem::Combustion,
+        anomaly: None,
     }];
     gs.player_company.reactor_projects.push(rproj);
 
@@ -1617,10 +1881,16 @@ fn test_reactor_perflight_flaw_fires_at_flight_start() {
         propellant_mass_kg: 40_000.0, structural_mass_kg: 1_000.0,
         fairing: None,
         power_sources: vec![PowerSource::from_reactor_design(reactor_design)],
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let design = RocketDesign {
         id: RocketDesignId(1), name: "ReactorCraft".into(),
         stage_groups: vec![vec![stage]],
+        performance_reserve: Some(0.0),
     };
     let rocket = design.instantiate(RocketId(1), "leo", 0.0);
     gs.spacecraft.push(Spacecraft {
@@ -1628,6 +1898,7 @@ fn test_reactor_perflight_flaw_fires_at_flight_start() {
         rocket, design, location: "leo".into(),
         rocket_project_id: RocketProjectId(0),
         payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
     });
 
     gs.fly_spacecraft(0, "geo");
@@ -1670,6 +1941,8 @@ fn test_mid_flight_stage_loss_destroys_vehicle() {
         discovery_probability: 1.0,
         discovered: false,
         trigger: FlawTrigger::PerDay,
+        subsystem: crate::flaw::Subsystem::Combustion,
+        anomaly: None,
     }];
     gs.player_company.reactor_projects.push(rproj);
 
@@ -1691,10 +1964,16 @@ fn test_mid_flight_stage_loss_destroys_vehicle() {
         propellant_mass_kg: 40_000.0, structural_mass_kg: 1_000.0,
         fairing: None,
         power_sources: vec![PowerSource::from_reactor_design(reactor_design)],
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     let design = RocketDesign {
         id: RocketDesignId(1), name: "Doomed".into(),
         stage_groups: vec![vec![stage]],
+        performance_reserve: Some(0.0),
     };
     let rocket = design.instantiate(RocketId(1), "leo", 0.0);
     gs.spacecraft.push(Spacecraft {
@@ -1702,6 +1981,7 @@ fn test_mid_flight_stage_loss_destroys_vehicle() {
         rocket, design, location: "leo".into(),
         rocket_project_id: RocketProjectId(0),
         payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
     });
 
     let rep_before = gs.player_company.reputation.total();
@@ -1813,6 +2093,11 @@ fn push_contract(gs: &mut GameState, id: u64, destination: &str) -> usize {
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: crate::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     });
     gs.player_company.active_contracts.len() - 1
 }
@@ -1868,6 +2153,11 @@ fn test_build_launch_payloads_validates_before_consuming() {
             build_cost: 0.0,
             revision: 0,
             rocket_flaws: Vec::new(),
+            design: None,
+            built_on: None,
+            expired: false,
+            propellant_load: Default::default(),
+            boosters: Vec::new(),
         });
 
     let real = crate::manufacturing::InventoryItemId(10);
@@ -1889,7 +2179,7 @@ fn test_build_launch_payloads_validates_before_consuming() {
 fn test_buy_floor_space_debits_money() {
     let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
     let before = gs.player_company.money;
-    let cost = gs.player_company.buy_floor_space(2, &gs.balance.clone());
+    let cost = gs.player_company.buy_floor_space(2, &gs.balance.clone()).unwrap();
     assert_eq!(cost, 2.0 * gs.balance.costs.floor_space_cost);
     assert_eq!(gs.player_company.money, before - cost);
     assert_eq!(gs.player_company.manufacturing.floor_space.under_construction.len(), 1);
@@ -1917,3 +2207,2694 @@ fn test_cycle_auto_build_target_requires_testing_and_wraps() {
     assert_eq!(gs.player_company.cycle_auto_build_target(0), Some(0));
     assert!(gs.player_company.auto_build_targets.get(&pid).is_none());
 }
+
+#[test]
+fn test_pad_lease_pays_rent_and_blocks_launches() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let start = gs.date.add_days(2);
+    gs.pad_lease_offers = vec![
+        crate::launch_site::PadLease {
+            lessee: "Tenant".into(), start, days: 3, daily_rate: 50_000.0,
+        },
+        crate::launch_site::PadLease {
+            lessee: "Overlap".into(), start: start.add_days(2), days: 3, daily_rate: 50_000.0,
+        },
+    ];
+    assert!(gs.accept_pad_lease(0).is_ok());
+    // The second offer collides with the first lease and stays posted.
+    assert!(matches!(
+        gs.accept_pad_lease(0),
+        Err(crate::launch_site::PadLeaseError::Conflict { .. })
+    ));
+    assert_eq!(gs.pad_lease_offers.len(), 1);
+
+    let base = gs.player_company.clone();
+    gs.advance_day();
+    gs.advance_day();
+    assert_eq!(gs.date, start);
+    assert!(matches!(
+        gs.build_launch_payloads(&[], &[]),
+        Err(ManifestError::PadLeased { free_on, .. }) if free_on == start.add_days(3)
+    ));
+
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        events.extend(gs.advance_day());
+    }
+    assert!(gs.build_launch_payloads(&[], &[]).is_ok());
+    assert!(gs.player_company.launch_site.leases.is_empty());
+    assert!(events.iter().any(|e| matches!(
+        e, GameEvent::PadLeaseCompleted { income, .. } if *income == 150_000.0
+    )));
+    // No month start in between, so rent is the only money movement.
+    assert_eq!(gs.player_company.money, base.money + 150_000.0);
+}
+
+#[test]
+fn test_regulator_blocks_launches_and_fines_debris() {
+    use crate::regulation::{ComplianceIssue, LicenseClass};
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let cfg = gs.balance.regulation.clone();
+
+    // Cadence: the window's quota of launches closes the pad.
+    for _ in 0..cfg.max_launches_per_window {
+        assert!(gs.build_launch_payloads(&[], &[]).is_ok());
+        gs.record_regulated_launch(false, false);
+    }
+    assert!(matches!(
+        gs.build_launch_payloads(&[], &[]),
+        Err(ManifestError::NotCompliant(ComplianceIssue::CadenceLimit { .. }))
+    ));
+    gs.player_company.launch_site.recent_launches.clear();
+
+    // Failure debris: fined, and the site closes for a review.
+    let before = gs.player_company.money;
+    let events = gs.record_regulated_launch(false, true);
+    assert!(matches!(events.as_slice(), [
+        GameEvent::RegulatoryFine { .. },
+        GameEvent::EnvironmentalReview { .. },
+    ]));
+    assert_eq!(gs.player_company.money, before - cfg.debris_fine - cfg.review_fee);
+    assert!(matches!(
+        gs.build_launch_payloads(&[], &[]),
+        Err(ManifestError::NotCompliant(ComplianceIssue::UnderReview { .. }))
+    ));
+
+    // Licenses arrive after their lead time.
+    assert!(gs.apply_for_license(LicenseClass::Cislunar).is_some());
+    assert!(gs.apply_for_license(LicenseClass::Cislunar).is_none());
+    for _ in 0..cfg.cislunar_license_lead_days {
+        gs.advance_day();
+    }
+    assert!(gs.player_company.compliance.has_license(LicenseClass::Cislunar));
+}
+
+#[test]
+fn test_export_controls_gate_work_and_punish_early_launches() {
+    use crate::contract::ContractId;
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let cfg = gs.balance.regulation.clone();
+    let controlled = |id, bid_deadline| Contract {
+        id: ContractId(id),
+        name: format!("C{}", id),
+        bid_deadline,
+        export_controlled: true,
+        reward: None,
+        ..crate::contract::test_support::solicitation_fixture()
+    };
+    let post = |gs: &mut GameState| {
+        gs.available_contracts = vec![
+            controlled(900, None),
+            controlled(901, None),
+            controlled(902, Some(GameDate::new(2001, 12, 1))),
+        ];
+    };
+
+    // Uncleared companies can neither accept nor bid.
+    post(&mut gs);
+    assert!(gs.accept_contract(0).is_none());
+    assert!(gs.place_bid(2, 1_000_000.0).is_none());
+
+    assert!(gs.upgrade_security_clearance().is_some());
+    assert!(gs.upgrade_security_clearance().is_none());
+    for _ in 0..cfg.clearance_upgrade_days {
+        gs.advance_day();
+    }
+    assert!(gs.player_company.compliance.security_clearance);
+
+    // Cleared: taking on controlled work files its paperwork.
+    post(&mut gs);
+    let money = gs.player_company.money;
+    assert!(gs.accept_contract(0).is_some());
+    assert!(gs.accept_contract(0).is_some());
+    assert_eq!(gs.player_company.money, money - 2.0 * cfg.export_paperwork_fee);
+    assert!(gs.place_bid(0, 1_000_000.0).is_some());
+    let ready_on = gs.player_company.compliance.export_filing(ContractId(900)).unwrap().ready_on;
+
+    // Flying before the paperwork clears: fined, barred, bids withdrawn.
+    let money = gs.player_company.money;
+    let events = gs.record_export_launch(&[ContractId(900)]);
+    assert!(matches!(events.as_slice(), [GameEvent::ExportViolation { .. }]));
+    assert_eq!(gs.player_company.money, money - cfg.export_violation_fine);
+    assert!(gs.available_contracts[0].player_bid.is_none());
+    assert!(gs.place_bid(0, 1_000_000.0).is_none());
+    assert!(gs.player_company.compliance.export_filing(ContractId(900)).is_none());
+
+    // Once cleared, the paperwork covers the launch.
+    gs.date = ready_on;
+    assert!(gs.record_export_launch(&[ContractId(901)]).is_empty());
+}
+
+#[test]
+fn test_pad_disaster_closes_pad_grounds_launches_and_opens_press_crisis() {
+    use crate::pad_failure::CrisisResponse;
+    use crate::regulation::ComplianceIssue;
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let cfg = gs.balance.pad_failure.clone();
+    gs.set_pad_insurance(true);
+    let money = gs.player_company.money;
+    let events = gs.destroy_pad("Heavy 1");
+    assert!(matches!(events.as_slice(), [
+        GameEvent::PadDestroyed { .. },
+        GameEvent::InsuranceClaimPaid { .. },
+        GameEvent::RegulatoryStandDown { .. },
+        GameEvent::PressCrisis { .. },
+    ]));
+    let claim = cfg.rebuild_cost * cfg.insurance_coverage;
+    assert_eq!(gs.player_company.money, money - cfg.rebuild_cost + claim);
+    assert!(matches!(
+        gs.build_launch_payloads(&[], &[]),
+        Err(ManifestError::NotCompliant(ComplianceIssue::PadRebuilding { .. }))
+    ));
+    assert!(gs.player_company.marketing.sentiment < 0.0);
+
+    // A transparent answer wins some of it back; there's only one crisis.
+    let sentiment = gs.player_company.marketing.sentiment;
+    assert!(gs.respond_to_press_crisis(CrisisResponse::Transparent).is_ok());
+    assert!(gs.player_company.marketing.sentiment > sentiment);
+    assert!(gs.respond_to_press_crisis(CrisisResponse::Spin).is_err());
+
+    // The stand-down lifts before the rebuild finishes.
+    let mut events = Vec::new();
+    for _ in 0..cfg.stand_down_days {
+        events.extend(gs.advance_day());
+    }
+    assert!(matches!(
+        gs.build_launch_payloads(&[], &[]),
+        Err(ManifestError::NotCompliant(ComplianceIssue::PadRebuilding { .. }))
+    ));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::PadInsurancePaid { .. })));
+    for _ in cfg.stand_down_days..cfg.rebuild_days {
+        events.extend(gs.advance_day());
+    }
+    assert!(events.iter().any(|e| matches!(e, GameEvent::PadRebuilt)));
+    assert!(!gs.player_company.launch_site.is_rebuilding(gs.date));
+
+    // Left unanswered, a crisis lapses into silence.
+    gs.destroy_pad("Heavy 2");
+    let mut events = Vec::new();
+    for _ in 0..=cfg.response_days {
+        events.extend(gs.advance_day());
+    }
+    assert!(events.iter().any(|e| matches!(e, GameEvent::PressCrisisIgnored { .. })));
+    assert!(gs.player_company.marketing.crisis.is_none());
+}
+
+#[test]
+fn test_landed_booster_reflies_under_its_serial_and_inspection_reveals_wear() {
+    use crate::manufacturing::ManufacturingOrderType;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.rocket_projects[0].design.stage_groups[0][0].landing_kit = true;
+    gs.balance.booster_reuse.landing_success_chance = 1.0;
+    gs.balance.recovery_fleet.landing_zone_weather_scrub = 0.0;
+    gs.balance.recovery_fleet.landing_zone_turnaround_days = 0;
+    let design = gs.player_company.rocket_projects[0].design.clone();
+
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let first = gs.player_company.manufacturing.inventory.rockets.remove(0);
+    assert!(first.boosters.is_empty(), "a new build flies a new booster");
+    // With nowhere to land, the booster is expended.
+    let events = gs.recover_boosters(&first, &design);
+    assert!(matches!(events.as_slice(), [GameEvent::BoosterExpended { .. }]));
+    gs.buy_recovery_asset(crate::recovery_fleet::RecoveryAssetKind::LandingZone).unwrap();
+    let events = gs.recover_boosters(&first, &design);
+    assert!(matches!(events.as_slice(),
+        [GameEvent::BoosterLanding { landed: true, serial: 1, flights: 1, .. }]));
+
+    // The next build reuses it: no new first stage is ordered, and the
+    // rocket carries the booster's history to the pad.
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    assert!(!gs.player_company.manufacturing.orders.iter().any(|o| matches!(&o.order_type,
+        ManufacturingOrderType::Stage { group_index: 0, .. })));
+    run_manufacturing_to_rocket(&mut gs);
+    let second = gs.player_company.manufacturing.inventory.rockets.remove(0);
+    let [(0, life)] = second.boosters.as_slice() else { panic!("booster not stacked: {:?}", second.boosters) };
+    assert_eq!(life.serial, 1);
+    let wear = crate::booster_reuse::wear_flaw(life, &gs.balance.booster_reuse);
+    assert_eq!(wear.activation_chance, life.failure_chance(&gs.balance.booster_reuse));
+    let events = gs.recover_boosters(&second, &design);
+    assert!(matches!(events.as_slice(),
+        [GameEvent::BoosterLanding { landed: true, serial: 1, flights: 2, .. }]));
+
+    // A teardown reveals the wear and counts as a refurbishment.
+    let stored = gs.player_company.manufacturing.inventory.stages.iter()
+        .find(|s| s.booster.is_some()).unwrap().item_id;
+    gs.player_company.order_booster_inspection(stored, &gs.balance).expect("inspection ordered");
+    assert!(gs.player_company.order_booster_inspection(stored, &gs.balance).is_none(), "already queued");
+    for _ in 0..10 {
+        for order in &mut gs.player_company.manufacturing.orders {
+            if order.teams_assigned > 0 {
+                order.work_completed = order.work_required;
+            }
+        }
+        gs.advance_day();
+    }
+    let life = gs.player_company.manufacturing.inventory.stages.iter()
+        .find(|s| s.item_id == stored).and_then(|s| s.booster.as_ref()).unwrap();
+    assert!(life.wear_revealed);
+    assert_eq!(life.refurbished_on.len(), 3);
+    assert!(gs.event_log.iter().any(|(_, e)| matches!(e, GameEvent::BoosterInspected { .. })));
+}
+
+#[test]
+fn test_drone_ship_catches_only_when_sent_out_and_costs_upkeep() {
+    use crate::recovery_fleet::RecoveryAssetKind;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.rocket_projects[0].design.stage_groups[0][0].landing_kit = true;
+    gs.balance.recovery.max_sea_state = 0;
+    let design = gs.player_company.rocket_projects[0].design.clone();
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let rocket = gs.player_company.manufacturing.inventory.rockets.remove(0);
+
+    let book_before = gs.player_company.assets.book_value();
+    gs.buy_recovery_asset(RecoveryAssetKind::DroneShip).unwrap();
+    assert!(gs.player_company.assets.book_value() > book_before, "the ship goes on the books");
+    let ship = gs.player_company.recovery_fleet.assets[0].id;
+    let events = gs.recover_boosters(&rocket, &design);
+    assert!(matches!(events.as_slice(), [GameEvent::BoosterExpended { .. }]), "still in port");
+
+    gs.send_out_drone_ship(ship).unwrap();
+    gs.date = gs.date.add_days(gs.balance.recovery_fleet.drone_ship_transit_days);
+    let events = gs.recover_boosters(&rocket, &design);
+    assert!(matches!(events.as_slice(), [GameEvent::BoosterLanding { .. }]));
+    assert!(!gs.player_company.recovery_fleet.assets[0].available(gs.date), "turning around");
+
+    let mut events = Vec::new();
+    let money = gs.player_company.money;
+    gs.charge_recovery_fleet_upkeep(&mut events);
+    assert_eq!(gs.player_company.money, money - gs.balance.recovery_fleet.drone_ship_upkeep_per_month);
+}
+
+#[test]
+fn test_customer_payload_slips_block_launch_pay_compensation_and_renegotiate() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    gs.available_contracts = vec![Contract {
+        bid_deadline: None,
+        ..crate::contract::test_support::solicitation_fixture()
+    }];
+    assert!(gs.accept_contract(0).is_some());
+    let id = gs.player_company.active_contracts[0].id;
+    let ready_on = gs.player_company.customer_payloads.get(id).expect("tracked on accept").ready_on;
+    assert!(matches!(
+        gs.plan_manifest(&[0], &[]),
+        Err(ManifestError::PayloadNotReady { ready_on: r, .. }) if r == ready_on,
+    ));
+
+    // Every day slips until the payload runs into the deadline, which
+    // is then pushed back; the customer pays for the delay, up to its cap.
+    gs.balance.payload_readiness.daily_slip_chance = 1.0;
+    for _ in 0..10 {
+        gs.advance_day();
+    }
+    let cfg = gs.balance.payload_readiness.clone();
+    let p = gs.player_company.customer_payloads.get(id).unwrap().clone();
+    assert!(p.ready_on > ready_on && p.slipped_days > 0);
+    let payment = gs.player_company.active_contracts[0].payment;
+    assert!(p.compensation_paid > 0.0 && p.compensation_paid <= payment * cfg.max_compensation_fraction + 1e-6);
+    assert!(gs.event_log.iter().any(|(_, e)| matches!(e, GameEvent::ContractRenegotiated { .. })));
+    assert_eq!(gs.player_company.active_contracts[0].deadline, p.ready_on.add_days(cfg.renegotiation_margin_days));
+
+    // Once it ships the launch is no longer held for it.
+    gs.balance.payload_readiness.daily_slip_chance = 0.0;
+    while gs.date < p.ready_on {
+        gs.advance_day();
+    }
+    assert!(gs.event_log.iter().any(|(_, e)| matches!(e, GameEvent::PayloadReady { .. })));
+    assert!(!matches!(gs.plan_manifest(&[0], &[]), Err(ManifestError::PayloadNotReady { .. })));
+}
+
+#[test]
+fn test_pad_integration_limits_vehicle_size_paces_launches_and_converts() {
+    use crate::launch_site::IntegrationMode;
+    use crate::regulation::ComplianceIssue;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    let liftoff_kg = gs.player_company.rocket_projects[0].design.total_mass_kg();
+    assert_eq!(gs.player_company.launch_site.integration, IntegrationMode::Vertical);
+    assert_eq!(gs.check_pad_fits(item_id), Ok(()));
+
+    // Converting to a hangar closes the pad; it then can't take a
+    // vehicle over its limit.
+    gs.balance.launch_sites.horizontal_max_liftoff_kg = liftoff_kg - 1.0;
+    let money = gs.player_company.money;
+    gs.convert_pad().unwrap();
+    assert_eq!(gs.player_company.money, money - gs.balance.launch_sites.horizontal_conversion_cost);
+    assert!(matches!(gs.convert_pad(), Err(crate::launch_site::PadConversionError::Converting { .. })));
+    assert!(matches!(gs.plan_manifest(&[], &[]), Err(ManifestError::NotCompliant(ComplianceIssue::PadBusy { .. }))));
+    for _ in 0..gs.balance.launch_sites.conversion_days {
+        gs.advance_day();
+    }
+    assert_eq!(gs.player_company.launch_site.integration, IntegrationMode::Horizontal);
+    assert!(matches!(gs.check_pad_fits(item_id), Err(ManifestError::TooLargeForPad { .. })));
+    assert!(gs.launch_rocket(item_id, "leo", vec![], false).is_none(), "too heavy to stack");
+
+    // A vehicle that fits flies, and the pad is then tied up readying
+    // the next one for its facility's pad flow.
+    gs.balance.launch_sites.horizontal_max_liftoff_kg = liftoff_kg;
+    let (dest, payloads) = gs.build_launch_payloads(&[], &[]).unwrap();
+    gs.launch_rocket(item_id, &dest, payloads, false).expect("launched");
+    let flow = IntegrationMode::Horizontal.pad_flow_days(3, &gs.balance.launch_sites);
+    assert_eq!(gs.player_company.launch_site.pad_flow_until, Some(gs.date.add_days(flow)));
+    assert!(matches!(gs.plan_manifest(&[], &[]), Err(ManifestError::NotCompliant(ComplianceIssue::PadBusy { .. }))));
+}
+
+#[test]
+fn test_fleet_registry_gathers_stock_facilities_and_recovery_fleet() {
+    use crate::fleet::{FleetCategory, FleetStatus};
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let registry = gs.fleet_registry();
+    assert_eq!(registry.count(FleetCategory::Facility), 1);
+    assert_eq!(registry.of(FleetCategory::LaunchPad).next().unwrap().status, FleetStatus::Ready);
+    assert_eq!(registry.book_value(), gs.player_company.assets.book_value());
+
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    gs.buy_recovery_asset(crate::recovery_fleet::RecoveryAssetKind::DroneShip).unwrap();
+    let registry = gs.fleet_registry();
+    let rocket = registry.of(FleetCategory::Rocket).next().expect("rocket in stock");
+    assert_eq!(rocket.status, FleetStatus::Ready);
+    assert_eq!(rocket.location, gs.player_company.launch_site.location);
+    let ship = registry.of(FleetCategory::RecoveryAsset).next().expect("drone ship on the books");
+    assert_eq!((ship.status, ship.detail.as_str()), (FleetStatus::Busy, "in port"));
+    let build_cost = gs.player_company.manufacturing.inventory.rockets[0].build_cost;
+    assert!((registry.book_value() - gs.player_company.assets.book_value() - build_cost).abs() < 1e-6);
+    assert!(registry.at(&gs.player_company.launch_site.location).count() >= 4);
+
+    // Fixed assets age a month at each month-end.
+    let month = gs.date.month;
+    while gs.date.month == month {
+        gs.advance_day();
+    }
+    assert!(gs.fleet_registry().of(FleetCategory::Facility).all(|e| e.age_months == Some(1)));
+}
+
+#[test]
+fn test_challenge_objectives_checked_daily_in_order() {
+    use crate::challenge::{Challenge, ChallengeOutcome, Goal, Objective};
+    use crate::launch::{LaunchOutcome, LaunchRecord};
+
+    let challenge = Challenge {
+        key: "test".into(),
+        name: "Test".into(),
+        description: String::new(),
+        day_limit: 20,
+        objectives: vec![
+            Objective { goal: Goal::Reach { destination: "leo".into() }, by_day: 5 },
+            Objective { goal: Goal::Cash { amount: 1.0 }, by_day: 10 },
+        ],
+    };
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    gs.start_challenge(challenge.clone());
+    gs.advance_day();
+    gs.advance_day();
+    assert_eq!(gs.challenge.as_ref().unwrap().completed_on, Vec::<u32>::new(),
+        "cash doesn't count until orbit is reached");
+    gs.player_company.launch_history.push(LaunchRecord {
+        launch_date: gs.date,
+        rocket_name: "Test".into(),
+        contract_id: None,
+        destination: "leo".into(),
+        payload_kg: 100.0,
+        outcome: LaunchOutcome::Success,
+        flaws_activated: Vec::new(),
+        transit_anomalies: Vec::new(),
+        stage_disposals: Vec::new(),
+    });
+    let events = gs.advance_day();
+    let met = events.iter().filter(|e| matches!(e, GameEvent::ChallengeObjectiveMet { day: 3, .. })).count();
+    assert_eq!(met, 2);
+    let run = gs.challenge.as_ref().unwrap();
+    let score = run.score(&gs.balance.challenge);
+    assert_eq!(run.outcome, Some(ChallengeOutcome::Won { day: 3, score }));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ChallengeWon { score: s, .. } if *s == score)));
+
+    // Missing a deadline ends the run; the game plays on.
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    gs.start_challenge(challenge);
+    let mut failed = Vec::new();
+    for _ in 0..8 {
+        failed.extend(gs.advance_day().into_iter().filter(|e| matches!(e, GameEvent::ChallengeFailed { .. })));
+    }
+    assert_eq!(failed.len(), 1);
+    assert!(matches!(gs.challenge.as_ref().unwrap().outcome, Some(ChallengeOutcome::Failed { day: 5, .. })));
+    assert_eq!(gs.challenge.as_ref().unwrap().day, 5);
+}
+
+#[test]
+fn test_sandbox_edits_need_the_flag_and_mark_the_game() {
+    use crate::engine_project::{EngineDesignStatus, EngineProjectId};
+    use crate::rocket_project::RocketDesignStatus;
+    use crate::sandbox::{SandboxError, SandboxTarget};
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    let rp_id = setup_buildable_rocket(&mut gs);
+    let money = gs.player_company.money;
+    assert_eq!(gs.sandbox_spawn_money(1e9).unwrap_err(), SandboxError::Disabled);
+    assert_eq!(gs.player_company.money, money);
+    assert!(!gs.sandbox);
+
+    gs.balance.sandbox.enabled = true;
+    gs.sandbox_spawn_money(1e9).unwrap();
+    assert_eq!(gs.player_company.money, money + 1e9);
+    assert!(gs.sandbox, "any edit marks the game");
+
+    // Force a design through, then test it.
+    let engine = SandboxTarget::Engine(EngineProjectId(1));
+    gs.player_company.engine_projects[0].status = EngineDesignStatus::InDesign { work_completed: 0.0, work_required: 500.0 };
+    gs.sandbox_complete_design(engine).unwrap();
+    assert!(matches!(gs.player_company.engine_projects[0].status, EngineDesignStatus::Testing { .. }));
+    assert_eq!(gs.sandbox_complete_design(engine).unwrap_err(), SandboxError::NotInDesign);
+    let cycles = 5.0 * gs.balance.work.testing_cycle_work;
+    gs.sandbox_grant_testing_work(engine, cycles).unwrap();
+    assert!(gs.player_company.engine_projects[0].cumulative_testing_work >= cycles);
+    assert_eq!(gs.sandbox_grant_testing_work(SandboxTarget::Rocket(RocketProjectId(99)), 1.0).unwrap_err(),
+        SandboxError::NoSuchProject);
+
+    // Stock rockets and fly one; jump it to its last leg.
+    gs.sandbox_add_inventory(SandboxTarget::Rocket(rp_id), 2).unwrap();
+    assert_eq!(gs.player_company.manufacturing.inventory.rocket_count(rp_id), 2);
+    let rocket = &gs.player_company.manufacturing.inventory.rockets[0];
+    assert_eq!((rocket.build_cost, rocket.revision), (0.0, gs.player_company.rocket_projects[0].revision));
+    let item = rocket.item_id;
+    gs.launch_rocket(item, "geo", Vec::new(), true).expect("launches");
+    let id = gs.active_flights[0].id;
+    gs.sandbox_teleport_flight(id).unwrap();
+    let f = &gs.active_flights[0];
+    assert_eq!((f.current_leg, f.leg_days_remaining), (f.route.len() - 1, 1));
+    assert_eq!(f.current_location, f.route[f.current_leg].from);
+    gs.advance_day();
+    assert!(gs.active_flights.is_empty(), "the flight finishes its last leg the next day");
+    assert_eq!(gs.sandbox_teleport_flight(id).unwrap_err(), SandboxError::NoSuchFlight);
+
+    gs.player_company.rocket_projects[0].status = RocketDesignStatus::InDesign { work_completed: 0.0, work_required: 1.0 };
+    assert_eq!(gs.sandbox_add_inventory(SandboxTarget::Rocket(rp_id), 1).unwrap_err(), SandboxError::DesignIncomplete);
+    let edits = gs.event_log.iter().filter(|(_, e)| matches!(e, GameEvent::SandboxEdit { .. })).count();
+    assert_eq!(edits, 5);
+}
+
+#[test]
+fn test_library_archives_finished_designs_and_imports_only_in_sandbox() {
+    use crate::engine_project::EngineDesignStatus;
+    use crate::library::ArchivedDesign;
+    use crate::sandbox::SandboxError;
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let rocket = gs.archive_rocket(0).expect("a rocket in testing can be archived");
+    assert!(matches!(rocket.design, ArchivedDesign::Rocket { certified: false, .. }));
+    assert_eq!(rocket.company, "SpaceCorp");
+    let engine = gs.archive_engine(0).expect("finished engine");
+    gs.player_company.engine_projects[0].status = EngineDesignStatus::InDesign { work_completed: 0.0, work_required: 500.0 };
+    assert!(gs.archive_engine(0).is_none(), "nothing to archive mid-design");
+
+    // The library lives in its own file, outside any save.
+    let path = std::env::temp_dir().join(format!("rocket_tycoon_library_{}.json", std::process::id()));
+    let mut library = crate::save::load_library(&path).expect("a missing library is empty");
+    assert!(library.entries.is_empty());
+    library.archive(engine);
+    library.archive(rocket);
+    crate::save::save_library(&library, &path).unwrap();
+    let library = crate::save::load_library(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(library.entries.len(), 2);
+
+    let projects = gs.player_company.rocket_projects.len();
+    assert_eq!(gs.sandbox_import_archived(&library.entries[1]).unwrap_err(), SandboxError::Disabled);
+    gs.balance.sandbox.enabled = true;
+    assert_eq!(gs.sandbox_import_archived(&library.entries[0]).unwrap_err(), SandboxError::StatsOnly);
+    assert!(!gs.sandbox);
+    gs.sandbox_import_archived(&library.entries[1]).unwrap();
+    assert_eq!(gs.player_company.rocket_projects.len(), projects + 1);
+    assert!(gs.sandbox, "importing from the library is a sandbox edit");
+}
+
+#[test]
+fn test_monthly_contracts_always_include_stretch_goals() {
+    use crate::launch::{LaunchOutcome, LaunchRecord};
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    // Everywhere flown with room to spare, except the Moon.
+    let mut destinations: Vec<String> = gs.markets.iter()
+        .flat_map(|m| m.destinations.iter().map(|d| d.location_id.clone()))
+        .filter(|id| id != "lunar_orbit")
+        .collect();
+    destinations.dedup();
+    for destination in destinations {
+        gs.player_company.launch_history.push(LaunchRecord {
+            launch_date: gs.date,
+            rocket_name: "Test".into(),
+            contract_id: None,
+            destination,
+            payload_kg: 1e9,
+            outcome: LaunchOutcome::Success,
+            flaws_activated: Vec::new(),
+            transit_anomalies: Vec::new(),
+            stage_disposals: Vec::new(),
+        });
+    }
+    gs.balance.markets.stretch_contracts_per_month = 5;
+    let capability = gs.player_company.capability_profile();
+    assert!(capability.lift_to("lunar_orbit").is_none());
+    let before = gs.next_contract_id;
+    let month = gs.date.month;
+    while gs.date.month == month {
+        gs.advance_day();
+    }
+    let fresh: Vec<_> = gs.available_contracts.iter().filter(|c| c.id.0 >= before).collect();
+    let stretches: Vec<_> = fresh.iter().filter(|c| capability.is_stretch(c)).collect();
+    assert!(stretches.len() >= 5, "{} of {} new contracts are stretches", stretches.len(), fresh.len());
+    assert!(stretches.iter().all(|c| c.destination == "lunar_orbit"));
+}
+
+#[test]
+fn test_tutorial_follows_the_company_to_its_first_launch() {
+    use crate::launch::{LaunchOutcome, LaunchRecord};
+    use crate::tutorial::TutorialStep;
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    gs.start_tutorial();
+    assert!(!gs.advance_day().iter().any(|e| matches!(e, GameEvent::TutorialStepDone { .. })));
+    assert_eq!(gs.tutorial.as_ref().unwrap().current(), Some(TutorialStep::DesignEngine));
+
+    // Designing a whole rocket meets the engine step, but the script
+    // waits on a team before counting the rocket.
+    setup_buildable_rocket(&mut gs);
+    let events = gs.advance_day();
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::TutorialStepDone { .. })).count(), 1);
+    assert_eq!(gs.tutorial.as_ref().unwrap().current(), Some(TutorialStep::AssignTeam));
+    gs.player_company.engine_projects[0].teams_assigned = 1;
+    gs.advance_day();
+    assert_eq!(gs.tutorial.as_ref().unwrap().current(), Some(TutorialStep::BuildRocket));
+
+    // The step survives a save and load.
+    let json = serde_json::to_string(&gs).unwrap();
+    let mut gs: GameState = serde_json::from_str(&json).unwrap();
+    assert_eq!(gs.tutorial.as_ref().unwrap().current(), Some(TutorialStep::BuildRocket));
+
+    let balance = gs.balance.clone();
+    assert!(gs.player_company.order_rocket_build(0, &balance).is_some());
+    gs.advance_day();
+    assert_eq!(gs.tutorial.as_ref().unwrap().current(), Some(TutorialStep::Launch));
+    gs.player_company.launch_history.push(LaunchRecord {
+        launch_date: gs.date,
+        rocket_name: "Test".into(),
+        contract_id: None,
+        destination: "leo".into(),
+        payload_kg: 0.0,
+        outcome: LaunchOutcome::Failure { reason: "test".into() },
+        flaws_activated: Vec::new(),
+        transit_anomalies: Vec::new(),
+        stage_disposals: Vec::new(),
+    });
+    let events = gs.advance_day();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::TutorialFinished)), "any launch counts");
+    assert!(gs.tutorial.as_ref().unwrap().is_finished());
+    assert_eq!(gs.tutorial.as_ref().unwrap().completed_on.len(), TutorialStep::ALL.len());
+}
+
+#[test]
+fn test_advisor_flags_idle_teams_heavy_rockets_and_short_runway() {
+    use crate::advisor::{HintTopic, Severity};
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    let hints = gs.advisor_hints();
+    assert_eq!(hints.len(), 1);
+    assert_eq!(hints[0].topic, HintTopic::IdleTeams);
+    assert_eq!(hints[0].message, "Your only engineering team is idle");
+
+    let rp_id = setup_buildable_rocket(&mut gs);
+    gs.player_company.engine_projects[0].teams_assigned = 1;
+    assert!(gs.advisor_hints().is_empty(), "a flyable design and a busy team need no advice");
+
+    for stage in &mut gs.player_company.rocket_projects[0].design.stage_groups[0] {
+        stage.propellant_mass_kg *= 50.0;
+    }
+    gs.player_company.money = 0.5 * gs.player_company.monthly_salary_cost(&gs.balance.costs);
+    let hints = gs.advisor_hints();
+    assert_eq!(hints.len(), 2);
+    assert!(hints.iter().all(|h| h.severity == Severity::Critical));
+    assert!(hints.iter().any(|h| h.topic == HintTopic::LowTwr(rp_id)));
+    assert!(hints.iter().any(|h| h.topic == HintTopic::Runway && h.message.starts_with("You can't afford salaries in 1 month ")));
+}
+
+#[test]
+fn test_cash_forecast_spots_the_payroll_that_cant_be_met() {
+    use crate::forecast::CashFlowKind;
+    use crate::launch_site::PadLease;
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    let salary = gs.player_company.monthly_salary_cost(&gs.balance.costs);
+    gs.player_company.money = 1.5 * salary;
+    let forecast = gs.cash_forecast(90);
+    assert_eq!(forecast.daily.len(), 90);
+    assert_eq!(forecast.daily[0].0, gs.date.next_day());
+    let paydays: Vec<GameDate> = forecast.items.iter()
+        .filter(|i| i.kind == CashFlowKind::Salaries)
+        .map(|i| i.date)
+        .collect();
+    assert!(paydays.len() >= 2 && paydays.iter().all(|d| d.is_first_of_month()));
+    assert_eq!(forecast.insolvent_on, Some(paydays[1]), "the second payroll overdraws");
+
+    // Pad rent coming in before then covers the gap.
+    gs.player_company.launch_site.leases.push(PadLease {
+        lessee: "OrbitCo".into(),
+        start: gs.date.next_day(),
+        days: 10,
+        daily_rate: salary,
+    });
+    assert_eq!(gs.cash_forecast(90).insolvent_on, None);
+}
+
+#[test]
+fn test_batch_runs_all_or_nothing() {
+    use crate::batch::{Action, ActionError};
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    let rocket = setup_buildable_rocket(&mut gs);
+
+    // The second team only exists once the first action has run.
+    let events = gs.run_batch(&[
+        Action::HireTeam,
+        Action::AssignRocketTeams { rocket, teams: 2 },
+        Action::OrderRocket { rocket },
+    ]).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(gs.player_company.rocket_projects[0].teams_assigned, 2);
+    let orders = gs.player_company.manufacturing.orders.len();
+    assert!(orders > 0);
+    let (money, logged) = (gs.player_company.money, gs.event_log.total_pushed());
+
+    let err = gs.run_batch(&[
+        Action::OrderRocket { rocket },
+        Action::AssignRocketTeams { rocket, teams: 1 },
+    ]).unwrap_err();
+    assert_eq!((err.step, err.error), (1, ActionError::NoIdleTeam));
+    assert_eq!(gs.player_company.manufacturing.orders.len(), orders, "the order was rolled back");
+    assert_eq!(gs.player_company.money, money);
+    assert_eq!(gs.event_log.total_pushed(), logged, "nothing is logged for a failed batch");
+}
+
+#[test]
+fn test_team_allocation_spreads_teams_by_phase() {
+    use crate::allocation::WorkPhase;
+    use crate::engine_project::EngineDesignStatus;
+
+    let mut gs = GameState::new("SpaceCorp".into(), 200_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    for i in 0..3 {
+        gs.player_company.hire_team(format!("Team {}", i), &gs.balance.clone()).unwrap();
+    }
+    gs.player_company.engine_projects[0].status = EngineDesignStatus::InDesign { work_completed: 0.0, work_required: 500.0 };
+    gs.player_company.rocket_projects[0].teams_assigned = 4;
+
+    // Design work counts double testing: the design gets the first team,
+    // and its second adds less (√2 - 1) than a first anywhere else.
+    let allocation = gs.suggest_team_allocation();
+    assert_eq!(allocation.teams, 4);
+    let phases: Vec<_> = allocation.slots.iter().map(|s| s.phase).collect();
+    assert_eq!(phases, vec![WorkPhase::Design, WorkPhase::Testing, WorkPhase::Testing]);
+    let suggested: Vec<_> = allocation.slots.iter().map(|s| s.suggested).collect();
+    assert_eq!(suggested, vec![2, 1, 1]);
+    assert!(allocation.changes());
+    assert!(allocation.suggested_value() > allocation.current_value());
+
+    gs.apply_team_allocation();
+    let company = &gs.player_company;
+    assert_eq!(company.engine_projects[0].teams_assigned, 2);
+    assert_eq!(company.engine_projects[1].teams_assigned, 1);
+    assert_eq!(company.rocket_projects[0].teams_assigned, 1);
+    assert_eq!(company.unassigned_team_count(), 0);
+    assert!(!gs.suggest_team_allocation().changes(), "a best spread stays put");
+}
+
+#[test]
+fn test_ipo_and_missed_earnings_quarter() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let before = gs.player_company.money;
+    assert!(gs.ipo().is_some());
+    assert!(gs.ipo().is_none());
+    let listing = gs.player_company.listing.clone().unwrap();
+    assert!(listing.raised > 0.0);
+    assert_eq!(gs.player_company.money, before + listing.raised);
+
+    // The IPO landed on Jan 1, so the first judged quarter is Q1:
+    // salaries alone put it in the red.
+    let mut events = Vec::new();
+    while gs.date < GameDate::new(2001, 4, 1) {
+        events.extend(gs.advance_day());
+    }
+    assert!(events.iter().any(|e| matches!(e, GameEvent::EarningsReported { met: false, .. })));
+    let listing = gs.player_company.listing.as_ref().unwrap();
+    assert_eq!(listing.missed_quarters, 1);
+    assert!(listing.sentiment < 1.0);
+    assert!(gs.player_company.reputation.earnings_factor < 0.0);
+    assert_eq!(gs.player_company.valuation_history.len(), 3);
+}
+
+#[test]
+fn test_competitor_copies_engine_and_player_buys_leak() {
+    use crate::engine_project::EngineDesignStatus;
+    use crate::espionage::{LeakKind, LeakOffer};
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    assert!(!gs.competitors.is_empty());
+    let (_, engine_projects) = make_three_stage_design();
+    gs.player_company.engine_projects = engine_projects;
+    gs.balance.espionage.copy_chance_per_month = 1.0;
+    gs.balance.espionage.leak_offer_chance = 0.0;
+    let rate_before = gs.competitors[0].failure_rate;
+
+    let mut events = Vec::new();
+    gs.tick_espionage(&mut events);
+    assert!(matches!(events.as_slice(), [GameEvent::DesignCopied { .. }]));
+    assert!(gs.competitors[0].failure_rate < rate_before);
+    assert_eq!(gs.competitors[0].copied_engines.len(), 1);
+    assert!(gs.player_company.reputation.total() < 0.0);
+
+    // Test data lands on the engine with the least testing behind it.
+    gs.leak_offer = Some(LeakOffer {
+        source: "DinoSoar".into(),
+        kind: LeakKind::TestingWork { cycles: 2 },
+        price: 1_000_000.0,
+        expires: gs.date.add_days(30),
+    });
+    gs.player_company.engine_projects[0].cumulative_testing_work = 50.0;
+    let money = gs.player_company.money;
+    assert!(gs.buy_leak().is_some());
+    assert!(gs.leak_offer.is_none());
+    assert_eq!(gs.player_company.money, money - 1_000_000.0);
+    let ep = &gs.player_company.engine_projects[1];
+    let expected = 100.0 + 2.0 * gs.balance.work.testing_cycle_work;
+    assert!(matches!(ep.status, EngineDesignStatus::Testing { work_completed } if work_completed == expected));
+}
+
+#[test]
+fn test_certification_gates_contracts_and_failure_revokes() {
+    use crate::rocket_project::{Certification, RocketDesignStatus};
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let (design, _) = make_three_stage_design();
+    gs.player_company.rocket_projects.push(
+        RocketProject::new(RocketProjectId(1), design, &gs.balance),
+    );
+    gs.markets[0].requires_certification = true;
+    let market_id = gs.markets[0].id;
+    gs.available_contracts = vec![Contract {
+        market_id,
+        ..crate::contract::test_support::solicitation_fixture()
+    }];
+    assert!(gs.certification_blocks_market(market_id));
+    assert!(gs.place_bid(0, 10_000_000.0).is_none());
+
+    // Enough clean flights of the frozen revision, then the paperwork.
+    let required = gs.balance.certification.required_successes;
+    let rp = &mut gs.player_company.rocket_projects[0];
+    rp.status = RocketDesignStatus::Testing { work_completed: 0.0 };
+    for _ in 0..required {
+        assert!(gs.record_certification_flight(RocketProjectId(1), 0, true).is_none());
+    }
+    let money = gs.player_company.money;
+    assert!(matches!(gs.start_certification(0), Ok(GameEvent::CertificationStarted { .. })));
+    assert_eq!(gs.player_company.money, money - gs.balance.certification.documentation_cost);
+    gs.player_company.rocket_projects[0].certification = Certification::Certified { revision: 0 };
+    assert!(!gs.certification_blocks_market(market_id));
+    assert!(gs.place_bid(0, 10_000_000.0).is_some());
+
+    // A failure of the certified revision voids it.
+    assert!(matches!(
+        gs.record_certification_flight(RocketProjectId(1), 0, false),
+        Some(GameEvent::CertificationRevoked { .. })
+    ));
+    assert!(gs.certification_blocks_market(market_id));
+}
+
+#[test]
+fn test_engine_flaw_is_common_cause_across_designs() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let mut sibling = gs.player_company.rocket_projects[0].clone();
+    sibling.project_id = RocketProjectId(2);
+    sibling.design.name = "Sibling".into();
+    gs.player_company.rocket_projects.push(sibling);
+
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+
+    // The Lifter flaw fires on the first design's launch...
+    let (events, _) = gs.launch_rocket(
+        item_id, "leo", vec![Payload::TestMass { mass_kg: 0.0 }], false,
+    ).unwrap();
+    assert!(events.iter().any(|e| matches!(e,
+        GameEvent::CommonCauseFlaw { designs, .. } if designs.len() == 2)));
+
+    // ...and is known on the sibling without it ever flying.
+    let sibling = &gs.player_company.rocket_projects[1].design;
+    let known = gs.player_company.known_engine_flaws(sibling);
+    assert_eq!(known.len(), 1);
+    assert_eq!(known[0].0, "Lifter");
+
+    // Fixing it on the engine lineage clears it for both designs.
+    gs.player_company.engine_projects[0].flaws.clear();
+    for rp in &gs.player_company.rocket_projects {
+        assert!(gs.player_company.known_engine_flaws(&rp.design).is_empty());
+    }
+}
+
+#[test]
+fn test_short_filled_rocket_pays_less_and_flies_what_it_loaded() {
+    use crate::rocket::PropellantLoad;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    // A gentle liftoff (T/W 1.5), so there's max-Q margin to lighten into.
+    let design = &mut gs.player_company.rocket_projects[0].design;
+    let scale = 1.5 * 9.81 * design.total_mass_kg() / design.group_thrust_n(0);
+    for stage in &mut design.stage_groups[0] {
+        stage.engine.thrust_n *= scale;
+    }
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    for f in gs.player_company.engine_projects.iter_mut().flat_map(|ep| ep.flaws.iter_mut()) {
+        f.activation_chance = 0.0;
+    }
+    for r in &mut gs.player_company.manufacturing.inventory.rockets {
+        r.rocket_flaws.clear();
+    }
+    let design = gs.player_company.rocket_projects[0].design.clone();
+    let full_cost = design.liquid_propellant_cost();
+    assert!(full_cost > 0.0);
+
+    // Auto-fill loads just enough for an empty ride to LEO, keeping the
+    // performance reserve.
+    let share = gs.propellant_load_share(&design, PropellantLoad::AutoFill, "leo", 0.0);
+    let min = gs.balance.propellant_loading.min_load_fraction;
+    assert!(share >= min && share < 1.0);
+    let (_, leo_dv) = crate::location::DELTA_V_MAP
+        .shortest_path_for_rocket("earth_surface", "leo", &design, 0.0).unwrap();
+    assert!(design.with_propellant_load(share).usable_delta_v(0.0) >= leo_dv);
+    assert_eq!(gs.propellant_load_share(&design, PropellantLoad::AutoFill, "leo", 1e9), 1.0,
+        "fills up when nothing less would do");
+
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    assert!(gs.set_propellant_load(item_id, PropellantLoad::Partial(0.01)));
+    assert_eq!(gs.player_company.manufacturing.inventory.rockets[0].propellant_load, PropellantLoad::Partial(min));
+    assert!(gs.set_propellant_load(item_id, PropellantLoad::AutoFill));
+    let money = gs.player_company.money;
+    let (events, record) = gs.launch_rocket(item_id, "leo", vec![Payload::TestMass { mass_kg: 0.0 }], false).unwrap();
+    assert!(record.is_none(), "a short-filled flight still gets away");
+    assert!(events.iter().any(|e| matches!(e, GameEvent::PropellantOffloaded { .. })));
+    let loaded = design.with_propellant_load(share);
+    let short_spend = money - gs.player_company.money;
+    let flight = gs.active_flights.last().unwrap();
+    let top = loaded.stage_groups.len() - 1;
+    let loaded_kg = loaded.stage_groups[top][0].propellant_mass_kg;
+    assert!(loaded_kg < design.stage_groups[top][0].propellant_mass_kg);
+    assert_eq!(flight.rocket.stage_states[top][0].propellant_remaining_kg, loaded_kg);
+
+    // A full load pays for full tanks; the launch's other fees don't
+    // change.
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    let money = gs.player_company.money;
+    gs.launch_rocket(item_id, "leo", vec![Payload::TestMass { mass_kg: 0.0 }], false).unwrap();
+    let full_spend = money - gs.player_company.money;
+    assert!((full_spend - short_spend - (full_cost - loaded.liquid_propellant_cost())).abs() < 1e-3);
+}
+
+#[cfg(any(feature = "qa", debug_assertions))]
+#[test]
+fn test_qa_forces_launch_outcomes_and_injects_work_events() {
+    use crate::engine_project::WorkEvent;
+    use crate::flaw::{FlawId, Subsystem};
+    use crate::launch::LaunchOutcome;
+    use rand::SeedableRng;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    assert_eq!(gs.player_company.manufacturing.inventory.rockets.len(), 2);
+    // A clean vehicle, so only what QA rigs can go wrong.
+    for f in gs.player_company.engine_projects.iter_mut().flat_map(|ep| ep.flaws.iter_mut()) {
+        f.activation_chance = 0.0;
+    }
+    for r in &mut gs.player_company.manufacturing.inventory.rockets {
+        r.rocket_flaws.clear();
+    }
+
+    gs.qa_force_launch_failure(Subsystem::Turbopump);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    let (_, record) = gs.launch_rocket(item_id, "leo", vec![Payload::TestMass { mass_kg: 0.0 }], false).unwrap();
+    let record = record.expect("a forced failure ends the flight at launch");
+    assert!(matches!(record.outcome, LaunchOutcome::Failure { .. }));
+    assert!(record.flaws_activated.iter().any(|a| a.subsystem == Subsystem::Turbopump));
+    assert!(gs.qa.next_launch.is_none(), "the rig is spent on one launch");
+
+    assert!(!gs.qa_force_flaw(FlawId(9_999)));
+    assert!(gs.qa_force_flaw(FlawId(1)));
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    gs.launch_rocket(item_id, "leo", vec![Payload::TestMass { mass_kg: 0.0 }], false).unwrap();
+    assert!(gs.player_company.engine_projects.iter()
+        .flat_map(|ep| &ep.flaws)
+        .any(|f| f.id == FlawId(1) && f.discovered));
+
+    let engine = gs.player_company.engine_projects[0].project_id;
+    assert!(gs.qa_inject_work_event(engine, WorkEvent::ImprovementDiscovered { description: "QA".into() }));
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+    let events = gs.player_company.tick_daily_research(&mut rng, &gs.balance.clone()).events;
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ImprovementDiscovered { description, .. } if description == "QA")));
+    assert!(gs.player_company.qa_work_events.is_empty());
+}
+
+#[test]
+fn test_flight_software_reused_across_designs_after_adaptation() {
+    use crate::rocket_project::RocketDesignStatus;
+
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let (design, _) = make_three_stage_design();
+    for id in 1..=2 {
+        gs.player_company.hire_team(format!("Team {id}"), &gs.balance);
+        let mut rp = RocketProject::new(RocketProjectId(id), design.clone(), &gs.balance);
+        rp.status = RocketDesignStatus::Testing { work_completed: 0.0 };
+        rp.teams_assigned = 1;
+        gs.player_company.rocket_projects.push(rp);
+    }
+
+    // The first design starts a lineage; the second adapts the same one.
+    let (first, evt) = gs.player_company.adopt_flight_software(0, &gs.balance).unwrap();
+    assert!(matches!(evt, Some(GameEvent::SoftwareDesignStarted { .. })));
+    let (second, evt) = gs.player_company.adopt_flight_software(1, &gs.balance).unwrap();
+    assert_eq!(first, second);
+    assert!(evt.is_none());
+    assert_eq!(gs.player_company.software_projects.len(), 1);
+
+    // Until adaptation is done the design flies without it.
+    let company = &gs.player_company;
+    assert!(company.rocket_projects[1].flight_software().is_none());
+    let adaptation_days = gs.balance.software.adaptation_work.ceil() as u32;
+    for _ in 0..adaptation_days {
+        gs.advance_day();
+    }
+    let company = &gs.player_company;
+    assert_eq!(company.rocket_projects[1].flight_software(), Some(first));
+
+    // Maturity is the lineage's, shared by both designs.
+    gs.player_company.software_projects[0].status =
+        crate::software_project::SoftwareDesignStatus::Testing { work_completed: 0.0 };
+    gs.player_company.software_projects[0].cumulative_testing_work =
+        gs.balance.software.maturity_testing_work / 2.0;
+    let company = &gs.player_company;
+    for rp in &company.rocket_projects {
+        assert!((company.flight_software_maturity(rp, &gs.balance) - 0.5).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_integration_campaign_covers_one_flight() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    gs.player_company.hire_team("Integration".into(), &gs.balance);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+
+    let free = gs.player_company.unassigned_team_count();
+    assert!(matches!(
+        gs.start_integration_campaign(item_id, "leo", 500.0),
+        Some(GameEvent::IntegrationCampaignStarted { burn_count: 1, .. })
+    ));
+    assert_eq!(gs.player_company.unassigned_team_count(), free - 1);
+
+    for _ in 0..30 {
+        if gs.player_company.flight_preparation(item_id).unwrap().is_complete() {
+            break;
+        }
+        gs.advance_day();
+    }
+    assert!(gs.player_company.flight_preparation(item_id).unwrap().is_complete());
+    assert_eq!(gs.player_company.unassigned_team_count(), free, "team released");
+
+    // A full reduction keeps the certain Lifter flaw from firing on the
+    // validated flight, and the campaign is spent by it.
+    gs.balance.integration.risk_reduction = 1.0;
+    let (events, _) = gs.launch_rocket(
+        item_id, "leo", vec![Payload::TestMass { mass_kg: 400.0 }], false,
+    ).unwrap();
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::FlawDiscovered { .. })));
+    assert!(gs.player_company.flight_preparations.is_empty());
+}
+
+#[test]
+fn test_stored_rocket_expires_and_pays_storage() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let cfg = gs.balance.storage.clone();
+    // Idle factory floor holds it for free; overflow pays warehouse fees.
+    assert_eq!(gs.player_company.monthly_storage_cost(&cfg), 0.0);
+    gs.player_company.manufacturing.floor_space.total_units = 0;
+    let units = gs.player_company.storage_units(&cfg);
+    assert_eq!(gs.player_company.monthly_storage_cost(&cfg), units * cfg.monthly_cost_per_unit);
+    gs.player_company.manufacturing.floor_space.total_units = gs.balance.costs.starting_floor_space;
+
+    // Push the build date back past the design's shelf life.
+    let rocket = &mut gs.player_company.manufacturing.inventory.rockets[0];
+    let built = rocket.built_on.unwrap();
+    rocket.built_on = Some(GameDate::new(built.year - 10, built.month, 1));
+    let events = gs.player_company.tick_inventory_aging(gs.date, &cfg);
+    assert!(matches!(events.as_slice(), [GameEvent::HardwareExpired { .. }]));
+    assert!(gs.player_company.tick_inventory_aging(gs.date, &cfg).is_empty(), "flagged once");
+
+    // Recertification restarts the clock.
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    let money = gs.player_company.money;
+    let (cost, _) = gs.player_company.order_recertification(item_id, &gs.balance).unwrap();
+    assert_eq!(gs.player_company.money, money - cost);
+    assert!(gs.player_company.order_recertification(item_id, &gs.balance).is_none(), "already queued");
+    for _ in 0..30 {
+        if !gs.player_company.manufacturing.inventory.rockets[0].expired {
+            break;
+        }
+        gs.advance_day();
+    }
+    assert!(!gs.player_company.manufacturing.inventory.rockets[0].expired);
+}
+
+#[test]
+fn test_strip_and_scrap_stored_hardware() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let rocket = gs.player_company.manufacturing.inventory.rockets[0].clone();
+    let engine_total: u32 = gs.player_company.rocket_projects[0].design.stage_groups.iter()
+        .flatten()
+        .map(|s| s.engine_count)
+        .sum();
+
+    let money = gs.player_company.money;
+    let Some(GameEvent::EnginesStripped { engines, recovered, .. }) =
+        gs.strip_rocket_engines(rocket.item_id) else { panic!("strip failed") };
+    assert_eq!(engines, engine_total);
+    assert!(recovered > 0.0 && recovered < rocket.build_cost);
+    assert_eq!(gs.player_company.money, money + recovered);
+    let inventory = &gs.player_company.manufacturing.inventory;
+    assert!(inventory.rockets.is_empty());
+    assert_eq!(inventory.engines.len(), engine_total as usize);
+    assert!(inventory.engines.iter().all(|e| e.build_cost > 0.0 && e.built_on == rocket.built_on));
+
+    // Stripped engines can be scrapped in turn.
+    let engine = inventory.engines[0].clone();
+    let money = gs.player_company.money;
+    assert!(gs.scrap_inventory_item(engine.item_id).is_some());
+    let salvage = engine.build_cost * gs.balance.storage.scrap_recovery_fraction;
+    assert!((gs.player_company.money - (money + salvage)).abs() < 1e-6);
+    assert!(gs.scrap_inventory_item(engine.item_id).is_none(), "already gone");
+}
+
+#[test]
+fn test_retrofit_brings_stock_to_current_revision() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let flaw = gs.player_company.engine_projects[0].flaws[0].clone();
+    gs.player_company.rocket_projects[0].flaws.push(flaw);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    assert!(gs.player_company.order_retrofit(item_id, &gs.balance).is_none(), "already current");
+
+    // Revision 1 fixes the flaw the stocked rocket was built with.
+    let rp = &mut gs.player_company.rocket_projects[0];
+    rp.flaws.clear();
+    rp.revision = 1;
+    let (cost, _) = gs.player_company.order_retrofit(item_id, &gs.balance).unwrap();
+    let cfg = &gs.balance.retrofit;
+    assert_eq!(cost, cfg.flaw_rework_cost * cfg.cost_fraction);
+    assert!(gs.player_company.order_retrofit(item_id, &gs.balance).is_none(), "already queued");
+
+    for _ in 0..60 {
+        if gs.player_company.manufacturing.orders.is_empty() {
+            break;
+        }
+        gs.advance_day();
+    }
+    let rocket = &gs.player_company.manufacturing.inventory.rockets[0];
+    assert_eq!(rocket.revision, 1);
+    assert!(rocket.rocket_flaws.is_empty());
+    assert!(gs.player_company.order_retrofit(item_id, &gs.balance).is_none());
+}
+
+#[test]
+fn test_tracked_flights_report_leg_transitions() {
+    use crate::rocket::RocketId;
+    use crate::rocket_project::RocketProjectId;
+
+    let (design, _) = make_three_stage_design();
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 42);
+    let rocket = design.instantiate(RocketId(1), "leo", 0.0);
+    gs.spacecraft.push(Spacecraft {
+        id: SpacecraftId(1),
+        name: "Tracker".into(),
+        rocket,
+        design,
+        location: "leo".into(),
+        rocket_project_id: RocketProjectId(0),
+        payloads: Vec::new(),
+        health: crate::servicing::FULL_HEALTH,
+    });
+    gs.fly_spacecraft(0, "geo");
+
+    let tracked = gs.tracked_flights();
+    assert_eq!(tracked.len(), 1);
+    assert_eq!(tracked[0].destination, "geo");
+    assert_eq!(tracked[0].legs, 2, "LEO → GTO → GEO");
+    assert_eq!(tracked[0].progress, 0.0);
+
+    let mut leg_events = 0;
+    for _ in 0..60 {
+        let events = gs.advance_day();
+        leg_events += events.iter()
+            .filter(|e| matches!(e, GameEvent::FlightLegComplete { leg: 1, legs: 2, .. }))
+            .count();
+        if let Some(t) = gs.tracked_flights().first() {
+            assert!(t.progress > 0.0 && t.progress < 1.0);
+        } else {
+            break;
+        }
+    }
+    assert!(gs.active_flights.is_empty(), "flight should have arrived");
+    assert_eq!(leg_events, 1, "one transition between the two legs");
+}
+
+#[test]
+fn test_fast_forward_stops_when_the_game_pauses() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 42);
+    gs.set_speed(GameSpeed::Fast);
+    let mut plain = GameState::new("Test".into(), 200_000_000.0, 42);
+
+    let skip = gs.fast_forward(30);
+    assert_eq!(skip.days, 30);
+    assert!(!skip.interrupted);
+    assert_eq!(gs.speed, GameSpeed::Fast);
+    // Same days as advancing one at a time.
+    for _ in 0..30 {
+        plain.advance_day();
+    }
+    assert_eq!(gs.player_company.money, plain.player_company.money);
+    assert_eq!(gs.date, plain.date);
+
+    // A hired factory with nothing to build pauses the game the next day.
+    let balance = gs.balance.clone();
+    gs.player_company.hire_manufacturing_team("Shop".into(), &balance);
+    let skip = gs.fast_forward(30);
+    assert!(skip.interrupted);
+    assert_eq!(skip.days, 1);
+    assert_eq!(gs.speed, GameSpeed::Paused);
+    assert!(skip.events.iter().any(|e| matches!(e, GameEvent::ManufacturingIdle)));
+}
+
+/// Every field of `a` matches `b`'s, floats to a relative 1e-9 — the
+/// closed forms round differently than adding up days one at a time.
+fn assert_close_json(a: &serde_json::Value, b: &serde_json::Value, path: &str) {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) if x.is_f64() || y.is_f64() => {
+            let (x, y) = (x.as_f64().unwrap(), y.as_f64().unwrap());
+            assert!((x - y).abs() <= 1e-9 * x.abs().max(y.abs()).max(1.0), "{path}: {x} vs {y}");
+        }
+        (Value::Array(xs), Value::Array(ys)) => {
+            assert_eq!(xs.len(), ys.len(), "{path}: length");
+            for (i, (x, y)) in xs.iter().zip(ys).enumerate() {
+                assert_close_json(x, y, &format!("{path}[{i}]"));
+            }
+        }
+        (Value::Object(xs), Value::Object(ys)) => {
+            assert_eq!(xs.len(), ys.len(), "{path}: fields");
+            for (key, x) in xs {
+                assert_close_json(x, &ys[key], &format!("{path}.{key}"));
+            }
+        }
+        _ => assert_eq!(a, b, "{path}"),
+    }
+}
+
+#[test]
+fn test_fast_forward_takes_quiet_stretches_in_one_step() {
+    let setup = || {
+        let mut gs = GameState::new("Test".into(), 200_000_000.0, 42);
+        let balance = gs.balance.clone();
+        gs.player_company.hire_team("Alpha".into(), &balance);
+        gs.player_company.start_engine_project(
+            "Kestrel".into(),
+            crate::engine::EngineCycle::GasGenerator,
+            crate::engine_project::PropellantPreset::Kerolox,
+            1.0,
+            true, None, &balance,
+        );
+        gs.player_company.add_team_to_project(0);
+        gs.player_company.add_team_to_project(0);
+        gs.set_speed(GameSpeed::Fast);
+        gs
+    };
+    let mut skipped = setup();
+    let mut plain = setup();
+
+    // Steady design work with nothing else due goes by the week.
+    skipped.advance_day();
+    assert_eq!(skipped.quiet_days(7), 7);
+    let skip = skipped.fast_forward(120);
+    for _ in 0..1 + skip.days {
+        plain.advance_day();
+    }
+    assert!(skip.days > 30);
+    assert_eq!(skipped.date, plain.date);
+    assert_close_json(
+        &serde_json::to_value(&skipped).unwrap(),
+        &serde_json::to_value(&plain).unwrap(),
+        "game",
+    );
+
+    // A milestone inside the stretch cuts it short.
+    let mut gs = setup();
+    gs.advance_day();
+    let rate = crate::team::effective_work_rate(2);
+    if let crate::engine_project::EngineDesignStatus::InDesign { work_completed, work_required } =
+        &mut gs.player_company.engine_projects[0].status
+    {
+        *work_completed = *work_required - 3.5 * rate;
+    }
+    assert!(gs.quiet_days(7) < 3);
+}
+
+#[test]
+fn test_quarter_closes_on_the_books() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 42);
+    let balance = gs.balance.clone();
+    gs.player_company.hire_team("Alpha".into(), &balance);
+    let mut closed = Vec::new();
+    while gs.date < crate::calendar::GameDate::new(2001, 7, 2) {
+        for e in gs.advance_day() {
+            if let GameEvent::QuarterClosed { year, quarter, expenses, .. } = e {
+                closed.push((year, quarter, expenses));
+            }
+        }
+    }
+    assert_eq!(closed.iter().map(|c| (c.0, c.1)).collect::<Vec<_>>(), vec![(2001, 1), (2001, 2)]);
+    assert!(closed.iter().all(|c| c.2 > 0.0), "salaries are on the books");
+    let (_, q2_expenses) = gs.player_company.quarter_financials(2001, 2).unwrap();
+    assert_eq!(q2_expenses, closed[1].2);
+}
+
+#[test]
+fn test_pause_rule_stops_on_money_floor() {
+    let mut gs = GameState::new("Test".into(), 10_000_000.0, 42);
+    let balance = gs.balance.clone();
+    for i in 0..4 {
+        gs.player_company.hire_team(format!("Team {i}"), &balance);
+    }
+    gs.pause_rules.money_below = Some(gs.player_company.money - 1.0);
+    gs.set_speed(GameSpeed::Normal);
+
+    let skip = gs.fast_forward(60);
+    assert!(skip.interrupted, "first payroll crosses the floor");
+    assert_eq!(gs.speed, GameSpeed::Paused);
+    assert!(skip.events.iter().any(|e| matches!(e, GameEvent::SalariesPaid { .. })));
+}
+
+#[test]
+fn test_manufacturing_budget_blocks_builds_and_reports_overrun() {
+    use crate::budget::Department;
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let balance = gs.balance.clone();
+    gs.player_company.budgets.set_limit(Department::Manufacturing, Some(1.0));
+
+    // A refused build leaves no trace: no orders, no charge.
+    let money = gs.player_company.money;
+    let inventory_engines = gs.player_company.manufacturing.inventory.engines.len();
+    assert!(gs.player_company.order_rocket_build(0, &balance).is_none());
+    assert!(gs.player_company.manufacturing.orders.is_empty());
+    assert_eq!(gs.player_company.manufacturing.inventory.engines.len(), inventory_engines);
+    assert!(gs.player_company.engine_build_counts.is_empty());
+    assert_eq!(gs.player_company.money, money);
+
+    // Salaries are committed: they count past the cap and the month
+    // closes over budget.
+    gs.player_company.hire_manufacturing_team("Line".into(), &balance);
+    assert_eq!(gs.player_company.manufacturing_teams.len(), 0, "hiring is refused too");
+    gs.player_company.budgets.set_limit(Department::Manufacturing, None);
+    gs.player_company.hire_manufacturing_team("Line".into(), &balance).unwrap();
+    gs.player_company.budgets.set_limit(Department::Manufacturing, Some(1.0));
+    let mut overruns = Vec::new();
+    while gs.player_company.budgets.history.len() < 2 {
+        for e in gs.advance_day() {
+            if let GameEvent::BudgetOverrun { department, spent, .. } = e {
+                overruns.push((department, spent));
+            }
+        }
+    }
+    let first = &gs.player_company.budgets.history[0];
+    let expected: Vec<_> = first.overruns().into_iter().map(|(d, spent, _)| (d, spent)).collect();
+    assert_eq!(expected.len(), 1, "only Manufacturing is capped");
+    assert_eq!(overruns[..1], expected[..]);
+}
+
+#[test]
+fn test_contract_payment_is_credited_to_the_flying_design() {
+    use crate::contract::{Contract, ContractId, ContractStatus};
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let (design, _) = make_three_stage_design();
+    gs.player_company.rocket_projects.push(RocketProject::new(RocketProjectId(999), design.clone(), &gs.balance));
+    gs.player_company.rocket_cost_history.insert(design.id, vec![4_000_000.0, 3_000_000.0]);
+    gs.player_company.rocket_projects[0].nre_cost = 10_000_000.0;
+    gs.player_company.rocket_projects[0].flights = 1;
+    gs.player_company.rocket_projects[0].flown_build_cost = 4_000_000.0;
+    gs.player_company.active_contracts.push(Contract {
+        id: ContractId(1), name: "A".into(),
+        destination: "leo".into(), payload_kg: 100.0, payment: 9_000_000.0,
+        deadline: crate::calendar::GameDate::new(2099, 1, 1),
+        status: ContractStatus::Accepted,
+        market_id: Default::default(),
+        campaign_id: None,
+        bid_deadline: None,
+        budget_ceiling: 0.0,
+        player_bid: None,
+        pricing: crate::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
+    });
+    arrive_test_flight(&mut gs, "leo", vec![
+        Payload::ContractDelivery { contract_id: ContractId(1), payload_kg: 100.0 },
+    ]);
+
+    let pnl = gs.player_company.design_profitability(0).unwrap();
+    assert_eq!(pnl.revenue, 9_000_000.0);
+    assert_eq!(pnl.production_cost, 7_000_000.0);
+    assert_eq!(pnl.rockets_built, 2);
+    assert_eq!(pnl.per_flight_margin(), Some(5_000_000.0));
+    assert_eq!(pnl.break_even_flights(), Some(2));
+    assert_eq!(pnl.net(), -8_000_000.0);
+}
+
+#[test]
+fn test_floor_space_is_capitalized_depreciated_and_sold_at_book() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let balance = gs.balance.clone();
+    let opening = gs.player_company.assets.book_value();
+    let cost = gs.player_company.buy_floor_space(2, &balance).unwrap();
+    assert_eq!(gs.player_company.assets.book_value(), opening + cost);
+
+    // Finish construction and run a month so depreciation is charged.
+    while gs.player_company.manufacturing.floor_space.under_construction.len() == 1
+        || gs.date.day != 2
+    {
+        gs.advance_day();
+    }
+    let depreciation: f64 = gs.player_company.monthly_financials.iter().map(|f| f.depreciation).sum();
+    assert!(depreciation > 0.0);
+    assert!((gs.player_company.assets.book_value() - (opening + cost - depreciation)).abs() < 1e-6);
+
+    let asset = gs.player_company.assets.assets.last().unwrap().clone();
+    let money = gs.player_company.money;
+    let units = gs.player_company.manufacturing.floor_space.total_units;
+    assert!(matches!(gs.sell_asset(asset.id), Some(GameEvent::AssetSold { .. })));
+    assert_eq!(gs.player_company.money, money + asset.book_value);
+    assert!(asset.book_value < cost);
+    assert_eq!(gs.player_company.manufacturing.floor_space.total_units, units - 2);
+    assert!(gs.sell_asset(asset.id).is_none());
+}
+
+#[test]
+fn test_rocket_branch_forks_merges_and_retires() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let balance = gs.balance.clone();
+    let company = &mut gs.player_company;
+    company.next_rocket_project_id = 2;
+    company.rocket_projects[0].cumulative_testing_work = 100.0;
+    company.rocket_projects[0].flaws = company.engine_projects[0].flaws.clone();
+    company.rocket_projects[0].flaws[0].discovered = true;
+
+    assert!(matches!(company.fork_rocket_project(0, &balance), Some(GameEvent::RocketBranchForked { .. })));
+    let parent_id = company.rocket_projects[0].project_id;
+    assert_eq!(company.rocket_branches(parent_id), vec![1]);
+    let branch = &company.rocket_projects[1];
+    assert!(branch.design.name.ends_with("Block 2"));
+    assert!(branch.flaws[0].discovered, "known flaws stay known");
+    assert_eq!(branch.cumulative_testing_work, 100.0 * balance.heritage.branch_testing_carryover);
+    assert!(company.merge_rocket_branch(0).is_none(), "the mainline isn't a branch");
+
+    // The branch fixes its flaw, then folds back as the next revision.
+    company.rocket_projects[1].flaws.clear();
+    let revision = company.rocket_projects[0].revision;
+    assert!(matches!(company.merge_rocket_branch(1), Some(GameEvent::RocketBranchMerged { .. })));
+    assert_eq!(company.rocket_projects.len(), 1);
+    assert_eq!(company.rocket_projects[0].revision, revision + 1);
+    assert!(company.rocket_projects[0].flaws.is_empty());
+    assert_eq!(company.rocket_projects[0].design.id.0, parent_id.0);
+
+    // A branch with hardware on order can't be retired.
+    company.fork_rocket_project(0, &balance).unwrap();
+    company.order_rocket_build(1, &balance).unwrap();
+    assert!(company.retire_rocket_branch(1).is_none());
+    company.manufacturing.orders.clear();
+    assert!(matches!(company.retire_rocket_branch(1), Some(GameEvent::RocketBranchRetired { .. })));
+    assert!(company.rocket_branches(parent_id).is_empty());
+}
+
+#[test]
+fn test_design_export_imports_into_another_game() {
+    use crate::design_file::DesignImportError;
+    use crate::engine::EngineCycle;
+    use crate::engine_project::PropellantPreset;
+    use crate::rocket::{RocketDesign, RocketDesignId};
+    use crate::stage::{Stage, StageId};
+
+    let mut source = GameState::new("Exporter".into(), 1_000_000_000.0, 42);
+    let balance = source.balance.clone();
+    let company = &mut source.player_company;
+    company.start_engine_project("Kestrel".into(), EngineCycle::GasGenerator, PropellantPreset::Kerolox,
+        1.0, false, None, &balance).unwrap();
+    let engine = company.engine_projects.last().unwrap().design.clone();
+    let stage = Stage {
+        id: StageId(1), name: "Core".into(),
+        engine, engine_count: 1,
+        propellant_mass_kg: 200_000.0, structural_mass_kg: 15_000.0,
+        fairing: None, power_sources: Vec::new(),
+        crossfeed: false, long_coast_kit: false, landing_kit: false, launch_abort_system: false,
+        staging: Default::default(),
+    };
+    company.start_rocket_project(RocketDesign {
+        id: RocketDesignId(company.next_rocket_project_id), name: "Shared".into(),
+        stage_groups: vec![vec![stage]], performance_reserve: Some(0.02),
+    }, &balance).unwrap();
+    let json = source.export_design(source.player_company.rocket_projects.len() - 1).unwrap();
+
+    let mut target = GameState::new("Importer".into(), 1_000_000_000.0, 7);
+    let engines_before = target.player_company.engine_projects.len();
+    let index = target.import_design(&json).unwrap();
+    let company = &target.player_company;
+    let rp = &company.rocket_projects[index];
+    assert_eq!(rp.design.name, "Shared");
+    assert!(matches!(rp.status, crate::rocket_project::RocketDesignStatus::InDesign { .. }));
+    assert_eq!(company.engine_projects.len(), engines_before + 1);
+    let ep = company.engine_projects.last().unwrap();
+    assert_eq!(rp.design.stage_groups[0][0].engine.id, ep.design.id, "stage flies the new engine");
+    assert!(matches!(target.event_log.recent(1)[0],
+        (_, GameEvent::RocketDesignImported { new_engines: 1, unit_cost, .. }) if *unit_cost > 0.0));
+
+    // An engine on a locked technology keeps the design out.
+    for tech in &mut target.technologies {
+        tech.unlocked = false;
+    }
+    let locked = json.replace("\"Kerolox\"", "\"Methalox\"");
+    let projects = target.player_company.rocket_projects.len();
+    assert!(matches!(target.import_design(&locked), Err(DesignImportError::TechnologyLocked { .. })));
+    assert_eq!(target.player_company.rocket_projects.len(), projects);
+}
+
+#[test]
+fn test_player_chosen_zero_reserve_still_disperses() {
+    let mut gs = GameState::new("Test".into(), 100_000_000.0, 42);
+    let (design, _) = make_three_stage_design();
+
+    // A design saved before reserves existed loads as legacy.
+    let mut json = serde_json::to_value(&design).unwrap();
+    json.as_object_mut().unwrap().remove("performance_reserve");
+    let legacy: RocketDesign = serde_json::from_value(json).unwrap();
+    assert_eq!(legacy.performance_reserve, None);
+    assert!(!legacy.is_dispersed());
+
+    let balance = gs.balance.clone();
+    gs.player_company.start_rocket_project(legacy, &balance);
+    let project_id = gs.player_company.rocket_projects.last().unwrap().project_id;
+
+    // Once the player picks a reserve, even 0%, launches draw dispersion.
+    assert!(gs.set_performance_reserve(project_id, 0.0));
+    let design = &gs.player_company.rocket_projects.last().unwrap().design;
+    assert_eq!(design.performance_reserve, Some(0.0));
+    assert!(design.is_dispersed());
+}
+
+#[test]
+fn test_gov_program_subsidy_milestone_and_clawback() {
+    use crate::gov_program::{GovProgram, GovProgramId, ProgramStatus, PROGRAM_TEMPLATES};
+
+    let mut gs = GameState::new("Test".into(), 100_000_000.0, 42);
+    let cfg = gs.balance.gov_programs.clone();
+    for (i, template) in PROGRAM_TEMPLATES[..2].iter().enumerate() {
+        gs.gov_programs.push(GovProgram::from_template(GovProgramId(100 + i as u64), template, gs.date, &cfg));
+    }
+    assert!(gs.commit_gov_program(GovProgramId(100)).is_some());
+    assert!(gs.commit_gov_program(GovProgramId(101)).is_some());
+    assert!(gs.commit_gov_program(GovProgramId(100)).is_none(), "already committed");
+
+    // A month's subsidy on each.
+    while !gs.date.next_day().is_first_of_month() {
+        gs.advance_day();
+    }
+    let before = gs.player_company.money;
+    let events = gs.advance_day();
+    let subsidies = events.iter()
+        .filter(|e| matches!(e, GameEvent::GovSubsidyReceived { .. }))
+        .count();
+    assert_eq!(subsidies, 2);
+    assert!(gs.player_company.money > before - gs.player_company.monthly_salary_cost(&gs.balance.costs));
+
+    // Delivering to LEO completes the single-milestone program.
+    let events = arrive_test_flight(&mut gs, "leo", vec![Payload::TestMass { mass_kg: 5_000.0 }]);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::GovProgramCompleted { .. })));
+    assert_eq!(gs.gov_programs[0].status, ProgramStatus::Completed);
+
+    // The other misses its GTO deadline and is clawed back.
+    let received = gs.gov_programs[1].subsidy_received;
+    gs.gov_programs[1].milestones[0].deadline = gs.date;
+    let events = gs.advance_day();
+    let clawback = events.iter().find_map(|e| match e {
+        GameEvent::GovProgramFailed { clawback, .. } => Some(*clawback),
+        _ => None,
+    }).expect("missed deadline fails the program");
+    assert_eq!(clawback, received * cfg.clawback_fraction);
+    assert_eq!(gs.gov_programs[1].status, ProgramStatus::Failed);
+    assert_eq!(gs.gov_programs[1].subsidy_due(), None);
+}
+
+#[test]
+fn test_crew_trains_flies_home_and_loss_stands_company_down() {
+    use crate::astronaut::AstronautStatus;
+    use crate::manufacturing::InventoryItemId;
+
+    let mut gs = GameState::new("Test".into(), 100_000_000.0, 42);
+    gs.balance.astronauts.training_days = 1;
+    let balance = gs.balance.clone();
+    for name in ["Able", "Baker"] {
+        gs.player_company.hire_astronaut(name.into(), &balance).unwrap();
+    }
+    let rocket = InventoryItemId(77);
+    let crew: Vec<_> = gs.player_company.astronauts.astronauts.iter().map(|a| a.id).collect();
+    assert!(gs.assign_crew(rocket, crew.clone()).is_err(), "candidates can't fly yet");
+
+    let events = gs.advance_day();
+    let qualified = events.iter().filter(|e| matches!(e, GameEvent::AstronautQualified { .. })).count();
+    assert_eq!(qualified, 2);
+    gs.assign_crew(rocket, crew.clone()).unwrap();
+
+    // A crewed flight comes home: career logged, crew ready again.
+    let mut flight = arrived_test_flight(&gs, "leo", vec![]);
+    flight.crew = gs.player_company.astronauts.board(rocket);
+    let events = gs.resolve_arrived_flight(flight);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::CrewReturned { .. })));
+    let able = gs.player_company.astronauts.get(crew[0]).unwrap();
+    assert_eq!(able.status, AstronautStatus::Ready);
+    assert_eq!(able.career.len(), 1);
+
+    // Losing a crew hurts reputation and stands the company down.
+    let rep = gs.player_company.reputation.total();
+    let evt = gs.lose_crew(&crew, "Carrier", "leo", gs.date).unwrap();
+    assert!(matches!(evt, GameEvent::CrewLost { ref crew, .. } if crew.len() == 2));
+    assert!(gs.player_company.reputation.total() < rep);
+    assert_eq!(gs.player_company.astronauts.stand_down_until,
+        Some(gs.date.add_days(balance.astronauts.stand_down_days)));
+    assert!(gs.assign_crew(rocket, crew).is_err(), "the lost don't fly");
+}
+
+#[test]
+fn test_pad_abort_test_and_abort_saving_a_crew() {
+    let mut gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    gs.balance.astronauts.training_days = 1;
+    let balance = gs.balance.clone();
+    let (mut design, engine_projects) = make_three_stage_design();
+    gs.player_company.engine_projects = engine_projects;
+    let mut rp = RocketProject::new(RocketProjectId(1), design.clone(), &balance);
+    rp.status = crate::rocket_project::RocketDesignStatus::Testing { work_completed: 0.0 };
+    gs.player_company.rocket_projects.push(rp.clone());
+    assert!(gs.player_company.order_pad_abort_test(0, &balance).is_none(), "no abort system fitted");
+
+    design.stage_groups.last_mut().unwrap()[0].launch_abort_system = true;
+    rp.design = design;
+    gs.player_company.rocket_projects[0] = rp;
+    gs.player_company.hire_manufacturing_team("Shop".into(), &balance);
+    let money = gs.player_company.money;
+    let (cost, _) = gs.player_company.order_pad_abort_test(0, &balance).unwrap();
+    assert_eq!(gs.player_company.money, money - cost);
+    assert!(gs.player_company.order_pad_abort_test(0, &balance).is_none(), "already queued");
+
+    let mut tested = None;
+    for _ in 0..365 {
+        tested = gs.advance_day().into_iter().find_map(|e| match e {
+            GameEvent::PadAbortTested { passed, .. } => Some(passed),
+            _ => None,
+        });
+        if tested.is_some() {
+            break;
+        }
+    }
+    let passed = tested.expect("the test fires once the order is built");
+    assert_eq!(gs.player_company.rocket_projects[0].pad_aborts_passed, passed as u32);
+
+    // A working abort brings the crew home from a booster failure; a
+    // failed one, or none at all, loses them.
+    for name in ["Able", "Baker"] {
+        gs.player_company.hire_astronaut(name.into(), &balance).unwrap();
+    }
+    gs.advance_day();
+    let crew: Vec<_> = gs.player_company.astronauts.astronauts.iter().map(|a| a.id).collect();
+    let evt = gs.crew_on_launch_failure(&crew[..1], "Capsule", "leo", Some(1.0)).unwrap();
+    assert!(matches!(evt, GameEvent::CrewAborted { .. }));
+    assert_eq!(gs.player_company.astronauts.get(crew[0]).unwrap().career.len(), 1);
+    assert_eq!(gs.player_company.astronauts.stand_down_until, None);
+
+    let evt = gs.crew_on_launch_failure(&crew[1..], "Capsule", "leo", None).unwrap();
+    assert!(matches!(evt, GameEvent::CrewLost { .. }));
+    assert!(gs.crew_on_launch_failure(&[], "Capsule", "leo", Some(1.0)).is_none());
+}
+
+#[test]
+fn test_estimated_flaw_risk_falls_with_testing() {
+    let gs = GameState::new("Test".into(), 200_000_000.0, 1);
+    let balance = gs.balance.clone();
+    let (design, engine_projects) = make_three_stage_design();
+    let mut company = gs.player_company.clone();
+    company.engine_projects = engine_projects;
+    let mut rp = RocketProject::new(RocketProjectId(1), design.clone(), &balance);
+
+    let fresh = company.estimated_flaw_risk(&design, None, &balance);
+    assert_eq!(fresh.len(), crate::flaw::Subsystem::ALL.len());
+    assert_eq!(fresh, company.estimated_flaw_risk(&design, Some(&rp), &balance),
+        "an untested project estimates like a fresh design");
+
+    rp.cumulative_testing_work = 20.0 * balance.work.testing_cycle_work;
+    let tested = company.estimated_flaw_risk(&design, Some(&rp), &balance);
+    let total = |risks: &[crate::flaw::EventRisk]| risks.iter().map(|r| r.risk).sum::<f64>();
+    assert!(total(&tested) < total(&fresh));
+}
+
+#[test]
+fn test_isru_plant_fills_depot_and_refuels_parked_spacecraft() {
+    use crate::infrastructure::IsruPlantDesign;
+    use crate::propellant::Propellant;
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let design = IsruPlantDesign::sized("Plant", 90.0, &gs.balance.isru);
+    let events = arrive_test_flight(&mut gs, "lunar_surface", vec![Payload::IsruPlant {
+        design, deploy_at: "lunar_surface".into(),
+    }]);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::IsruPlantDeployed { .. })));
+    assert_eq!(gs.infrastructure_at("lunar_surface").unwrap().isru_plants.len(), 1);
+
+    let mut events = Vec::new();
+    gs.tick_infrastructure(&mut events);
+    let depot = &gs.infrastructure_at("lunar_surface").unwrap().depot;
+    assert!((depot.stored(Propellant::LOX) - 80.0).abs() < 1e-9);
+    assert!((depot.stored(Propellant::LH2) - 10.0).abs() < 1e-9);
+
+    // A LOX-only test craft with empty tanks takes the depot's oxygen.
+    let sc = push_test_spacecraft(&mut gs, 1, "Lander", "lunar_surface");
+    gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg = 0.0;
+    let evt = gs.refuel_spacecraft(sc).unwrap();
+    assert!(matches!(evt, GameEvent::SpacecraftRefueled { propellant_kg, .. } if (propellant_kg - 80.0).abs() < 1e-9));
+    assert!((gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg - 80.0).abs() < 1e-9);
+    assert!(matches!(gs.refuel_spacecraft(sc), Err(crate::infrastructure::InfrastructureError::NothingToLoad)));
+
+    let elsewhere = push_test_spacecraft(&mut gs, 2, "Orbiter", "leo");
+    assert!(matches!(gs.refuel_spacecraft(elsewhere), Err(crate::infrastructure::InfrastructureError::NoDepot(_))));
+}
+
+#[test]
+fn test_designed_depot_replaces_stock_and_its_flaws_bite_in_service() {
+    use crate::depot_project::DepotDesignStatus;
+    use crate::flaw::{Flaw, FlawConsequence, FlawId, FlawTrigger, Subsystem};
+    use crate::infrastructure::{InfrastructureError, TransferDirection};
+    use crate::manufacturing::InventoryItemId;
+    use crate::propellant::Propellant;
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let balance = gs.balance.clone();
+    let id = gs.player_company.start_proposed_depot("Haven".into(), &balance);
+    assert!(matches!(gs.launch_depot(InventoryItemId(1), 0, "lunar_surface"), Err(InfrastructureError::DepotNotReady)));
+
+    // One docking port, and a tank flaw sure to go off on day one.
+    let project = gs.player_company.find_depot_project_mut(id).unwrap();
+    let mut design = project.design.clone();
+    design.docking_ports = 1;
+    design.capacity_kg = 300_000.0;
+    assert!(project.apply_edit(design.clone(), &balance.depot_design));
+    project.promote_to_in_design(&balance.depot_design);
+    project.status = DepotDesignStatus::Testing { work_completed: 0.0 };
+    project.flaws = vec![Flaw {
+        id: FlawId(77),
+        description: "Tank weld cracks under thermal cycling".into(),
+        consequence: FlawConsequence::StageLoss,
+        activation_chance: 1.0,
+        discovery_probability: 0.0,
+        discovered: false,
+        trigger: FlawTrigger::PerDay,
+        subsystem: Subsystem::TankStructure,
+        anomaly: None,
+    }];
+    let flaws = project.flaws.clone();
+
+    // The stock depot's stores move across to the new one.
+    let mut infra = crate::infrastructure::LocationInfrastructure::new("lunar_surface", &balance.isru);
+    infra.depot.add(Propellant::LOX, 10_000.0);
+    gs.infrastructure.push(infra);
+    let events = arrive_test_flight(&mut gs, "lunar_surface", vec![Payload::Depot {
+        design, flaws, mass_kg: 1.0, deploy_at: "lunar_surface".into(),
+    }]);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::DepotDeployed { .. })));
+    let depot = &gs.infrastructure_at("lunar_surface").unwrap().depot;
+    assert_eq!((depot.design.name.as_str(), depot.capacity_kg()), ("Haven", 300_000.0));
+    assert_eq!(depot.stored(Propellant::LOX), 10_000.0);
+
+    // One port serves one standing order a day.
+    let a = push_test_spacecraft(&mut gs, 1, "A", "lunar_surface");
+    let b = push_test_spacecraft(&mut gs, 2, "B", "lunar_surface");
+    for sc in [a, b] {
+        gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg = 0.0;
+        gs.schedule_transfer(gs.spacecraft[sc].id, TransferDirection::Fill).unwrap();
+    }
+    gs.infrastructure[0].depot.flaws.clear();
+    let mut events = Vec::new();
+    gs.tick_infrastructure(&mut events);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::PropellantTransferred { .. })).count(), 1);
+
+    // The flaw empties the depot and shows up on the design.
+    let flaws = gs.player_company.depot_projects[0].flaws.clone();
+    gs.infrastructure[0].depot.flaws = flaws;
+    let mut events = Vec::new();
+    gs.tick_infrastructure(&mut events);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::DepotFlawActivated { lost_kg, .. } if *lost_kg > 0.0)));
+    assert_eq!(gs.infrastructure[0].depot.total_kg(), 0.0);
+    assert_eq!(gs.player_company.depot_projects[0].discovered_flaw_count(), 1);
+}
+
+#[test]
+fn test_worn_assets_stop_until_a_servicing_kit_arrives() {
+    use crate::infrastructure::IsruPlantDesign;
+    use crate::servicing::{ServiceTarget, ServicingKit, FULL_HEALTH};
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let design = IsruPlantDesign::sized("Plant", 90.0, &gs.balance.isru);
+    arrive_test_flight(&mut gs, "lunar_surface", vec![Payload::IsruPlant {
+        design, deploy_at: "lunar_surface".into(),
+    }]);
+    let sc = push_test_spacecraft(&mut gs, 1, "Lander", "lunar_surface");
+    gs.infrastructure[0].isru_plants[0].health = 1e-6;
+    gs.spacecraft[sc].health = 1e-6;
+    gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg = 0.0;
+
+    let mut events = Vec::new();
+    gs.tick_asset_wear(&mut events);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::AssetWornOut { .. })).count(), 2);
+    gs.tick_infrastructure(&mut events);
+    assert_eq!(gs.infrastructure[0].depot.total_kg(), 0.0, "a worn-out plant makes nothing");
+    gs.fly_spacecraft(sc, "lunar_orbit");
+    assert_eq!(gs.spacecraft.len(), 1, "a worn-out spacecraft stays put");
+    assert!(gs.most_worn_asset().is_some());
+
+    let cfg = gs.balance.servicing.clone();
+    let plant_kit = ServicingKit::new(
+        ServiceTarget::IsruPlants { location: "lunar_surface".into() }, "Plants".into(), 0.0, &cfg,
+    );
+    let sc_target = ServiceTarget::Spacecraft { id: gs.spacecraft[sc].id, location: "lunar_surface".into() };
+    let sc_kit = ServicingKit::new(sc_target, "Lander".into(), 50.0, &cfg);
+    let gone_kit = ServicingKit::new(
+        ServiceTarget::Spacecraft { id: SpacecraftId(999), location: "lunar_surface".into() },
+        "Ghost".into(), 0.0, &cfg,
+    );
+    let events = arrive_test_flight(&mut gs, "lunar_surface", vec![
+        Payload::ServicingKit(plant_kit), Payload::ServicingKit(sc_kit), Payload::ServicingKit(gone_kit),
+    ]);
+    assert_eq!(gs.infrastructure[0].isru_plants[0].health, FULL_HEALTH);
+    assert_eq!(gs.spacecraft[sc].health, FULL_HEALTH);
+    assert!((gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg - 50.0).abs() < 1e-9);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::AssetServiced { propellant_kg, .. } if (*propellant_kg - 50.0).abs() < 1e-9)));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ServicingMissed { .. })));
+}
+
+#[test]
+fn test_botched_approaches_lose_the_kit_and_collisions_damage_the_target() {
+    use crate::infrastructure::IsruPlantDesign;
+    use crate::servicing::{ServiceTarget, ServicingKit, FULL_HEALTH};
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let design = IsruPlantDesign::sized("Plant", 90.0, &gs.balance.isru);
+    let plant = |design: &IsruPlantDesign| Payload::IsruPlant {
+        design: design.clone(), deploy_at: "lunar_surface".into(),
+    };
+    // Setting a plant down on bare ground docks with nothing.
+    let mut flight = arrived_test_flight(&gs, "lunar_surface", vec![plant(&design)]);
+    flight.docking_risk = 1.0;
+    gs.resolve_arrived_flight(flight);
+    assert_eq!(gs.infrastructure[0].isru_plants.len(), 1);
+
+    let sc = push_test_spacecraft(&mut gs, 1, "Lander", "lunar_surface");
+    gs.spacecraft[sc].health = 0.5;
+    let target = ServiceTarget::Spacecraft { id: gs.spacecraft[sc].id, location: "lunar_surface".into() };
+    let kit = ServicingKit::new(target, "Lander".into(), 0.0, &gs.balance.servicing);
+
+    gs.balance.docking.collision_share = 0.0;
+    let mut flight = arrived_test_flight(&gs, "lunar_surface", vec![Payload::ServicingKit(kit.clone()), plant(&design)]);
+    flight.docking_risk = 1.0;
+    let events = gs.resolve_arrived_flight(flight);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::DockingAborted { .. })).count(), 2);
+    assert_eq!(gs.spacecraft[sc].health, 0.5, "an aborted kit services nothing");
+    assert_eq!(gs.infrastructure[0].isru_plants.len(), 1, "a plant that can't berth is lost");
+
+    gs.balance.docking.collision_share = 1.0;
+    let mut flight = arrived_test_flight(&gs, "lunar_surface", vec![Payload::ServicingKit(kit.clone())]);
+    flight.docking_risk = 1.0;
+    let events = gs.resolve_arrived_flight(flight);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::DockingCollision { target, .. } if target == "Lander")));
+    assert!((gs.spacecraft[sc].health - (0.5 - gs.balance.docking.collision_damage)).abs() < 1e-9);
+
+    // With no approach risk the kit always makes it aboard.
+    arrive_test_flight(&mut gs, "lunar_surface", vec![Payload::ServicingKit(kit)]);
+    assert_eq!(gs.spacecraft[sc].health, FULL_HEALTH);
+}
+
+#[test]
+fn test_constellation_earns_by_completeness_and_plans_replacements() {
+    use crate::constellation::ConstellationError;
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let a = push_test_spacecraft(&mut gs, 1, "Sat", "geo");
+    let b = push_test_spacecraft(&mut gs, 2, "Sat", "geo");
+    for i in [a, b] {
+        gs.spacecraft[i].rocket_project_id = RocketProjectId(7);
+    }
+    let design = gs.spacecraft[a].design.clone();
+    gs.player_company.rocket_projects.push(RocketProject::new(RocketProjectId(7), design, &gs.balance));
+
+    assert!(matches!(gs.form_constellation("Net".into(), "geo", RocketProjectId(7), 0), Err(ConstellationError::NoSlots)));
+    gs.form_constellation("Net".into(), "geo", RocketProjectId(7), 3).unwrap();
+    assert!(matches!(
+        gs.form_constellation("Again".into(), "geo", RocketProjectId(7), 3),
+        Err(ConstellationError::AlreadyFormed(_))
+    ));
+
+    // Two of three slots filled: two thirds of the revenue, and one
+    // replacement needed today.
+    let money = gs.player_company.money;
+    let mut events = Vec::new();
+    gs.tick_constellations(&mut events);
+    let full = gs.balance.constellation.revenue_per_satellite_per_day * 3.0;
+    assert!((gs.player_company.money - money - full * 2.0 / 3.0).abs() < 1e-6);
+    assert_eq!(gs.replacement_tasks().len(), 1);
+    assert_eq!(gs.replacement_tasks()[0].needed_by, gs.date);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::ReplacementNeeded { .. })).count(), 1);
+
+    // A member about to wear out opens a second task; auto-replace
+    // with nothing in inventory launches nothing.
+    gs.spacecraft[b].health = 10.0 * gs.balance.servicing.spacecraft_wear_per_day;
+    assert!(gs.set_constellation_auto_replace(gs.constellations[0].id, true));
+    let mut events = Vec::new();
+    gs.tick_constellations(&mut events);
+    assert_eq!(gs.replacement_tasks()[1].needed_by, gs.date.add_days(10));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ReplacementNeeded { .. })));
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::ReplacementLaunched { .. })));
+}
+
+#[test]
+fn test_transfer_orders_move_propellant_and_show_in_depot_reports() {
+    use crate::infrastructure::{IsruPlantDesign, TransferDirection};
+    use crate::propellant::Propellant;
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let design = IsruPlantDesign::sized("Plant", 90.0, &gs.balance.isru);
+    gs.deploy_isru_plant(design, "lunar_surface");
+    let sc = push_test_spacecraft(&mut gs, 1, "Lander", "lunar_surface");
+    gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg = 0.0;
+    let id = gs.spacecraft[sc].id;
+    assert!(gs.schedule_transfer(SpacecraftId(99), TransferDirection::Fill).is_err());
+    gs.schedule_transfer(id, TransferDirection::Fill).unwrap();
+
+    // Today's 80 kg of oxygen goes straight into the lander's tank.
+    let mut events = Vec::new();
+    gs.tick_infrastructure(&mut events);
+    assert!(events.iter().any(|e| matches!(e,
+        GameEvent::PropellantTransferred { to_depot: false, propellant_kg, .. } if (*propellant_kg - 80.0).abs() < 1e-9)));
+    assert_eq!(gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg, 80.0);
+
+    // Switching the order to offload drains it back.
+    gs.schedule_transfer(id, TransferDirection::Offload).unwrap();
+    assert_eq!(gs.transfer_orders.len(), 1, "one order per spacecraft");
+    gs.tick_infrastructure(&mut Vec::new());
+    assert_eq!(gs.spacecraft[sc].rocket.stage_states[0][0].propellant_remaining_kg, 0.0);
+
+    let inbound = arrived_test_flight(&gs, "lunar_surface", vec![]);
+    gs.active_flights.push(inbound);
+    let reports = gs.depot_reports();
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert!((report.inventories.iter().find(|(p, _)| *p == Propellant::LOX).unwrap().1 - 160.0).abs() < 1e-9);
+    assert!((report.fill_rate_kg_per_day - 90.0).abs() < 1e-9);
+    assert_eq!(report.inbound.len(), 1);
+    assert_eq!(report.transfer_orders.len(), 1);
+
+    assert!(gs.cancel_transfer(id).is_some());
+    assert!(gs.transfer_orders.is_empty());
+}
+
+#[test]
+fn test_expended_carrier_salvages_compatible_residuals_into_depot() {
+    use crate::infrastructure::IsruPlantDesign;
+    use crate::propellant::Propellant;
+
+    let mut gs = GameState::new("T".into(), 1.0, 0);
+    let design = IsruPlantDesign::sized("Plant", 90.0, &gs.balance.isru);
+    gs.deploy_isru_plant(design, "lunar_surface");
+
+    // A LOX-only carrier arrives with 100 kg left and is expended.
+    push_test_spacecraft(&mut gs, 1, "Tanker", "lunar_surface");
+    let tanker = gs.spacecraft.pop().unwrap();
+    let mut flight = arrived_test_flight(&gs, "lunar_surface", vec![]);
+    flight.design = tanker.design.clone();
+    flight.rocket = tanker.rocket.clone();
+    let events = gs.resolve_arrived_flight(flight);
+    assert!(events.iter().any(|e| matches!(e,
+        GameEvent::PropellantSalvaged { propellant_kg, .. } if (*propellant_kg - 100.0).abs() < 1e-9)));
+    assert_eq!(gs.infrastructure_at("lunar_surface").unwrap().depot.stored(Propellant::LOX), 100.0);
+
+    // The lunar depot has no kerosene plumbing, so an RP-1 stage keeps its load.
+    let mut flight = arrived_test_flight(&gs, "lunar_surface", vec![]);
+    flight.design = tanker.design;
+    flight.design.stage_groups[0][0].engine.propellant_mix[0].propellant = Propellant::RP1;
+    flight.rocket = tanker.rocket;
+    let events = gs.resolve_arrived_flight(flight);
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::PropellantSalvaged { .. })));
+    assert_eq!(gs.infrastructure_at("lunar_surface").unwrap().depot.total_kg(), 100.0);
+}
+
+#[test]
+fn test_external_review_finds_hidden_flaws_without_team_time() {
+    use crate::external_review::{ReviewError, ReviewTarget};
+    use rand::SeedableRng;
+
+    let mut gs = GameState::new("T".into(), 10_000_000.0, 0);
+    setup_buildable_rocket(&mut gs);
+    let engine = ReviewTarget::Engine(crate::engine_project::EngineProjectId(1));
+    let fee = crate::external_review::engine_fee(6, &gs.balance.external_review);
+
+    let money = gs.player_company.money;
+    gs.order_external_review(engine).unwrap();
+    assert_eq!(gs.player_company.money, money - fee);
+    assert_eq!(gs.order_external_review(engine).unwrap_err(), ReviewError::AlreadyUnderReview);
+    assert_eq!(gs.order_external_review(ReviewTarget::Rocket(RocketProjectId(99))).unwrap_err(),
+        ReviewError::NoSuchProject);
+    gs.player_company.money = 1_000_000.0;
+    assert!(matches!(gs.order_external_review(ReviewTarget::Rocket(RocketProjectId(1))),
+        Err(ReviewError::CantAfford { .. })));
+
+    let balance = gs.balance.clone();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let mut events = Vec::new();
+    for _ in 0..balance.external_review.duration_days {
+        assert!(!gs.player_company.engine_projects[0].flaws[0].discovered);
+        events.extend(gs.player_company.tick_daily_research(&mut rng, &balance).events);
+    }
+    assert!(gs.player_company.engine_projects[0].flaws[0].discovered,
+        "the review guarantees at least one of the hidden flaws");
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ExternalReviewComplete { flaws_found: 1, .. })));
+    assert!(events.iter().any(|e| matches!(e, GameEvent::FlawDiscovered { .. })));
+    assert!(gs.player_company.external_reviews.is_empty());
+}
+
+#[test]
+fn test_morale_moves_with_outcomes_and_drives_work_and_attrition() {
+    use crate::team::{MoraleCause, NEUTRAL_MORALE};
+    use rand::SeedableRng;
+
+    let mut gs = GameState::new("T".into(), 10_000_000.0, 0);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.teams.clear();
+    for name in ["A", "B", "C"] {
+        gs.player_company.hire_team(name.into(), &gs.balance);
+    }
+    for _ in 0..3 {
+        assert!(gs.player_company.add_team_to_project(0));
+    }
+    let cfg = gs.balance.morale.clone();
+    assert!((gs.player_company.average_morale() - NEUTRAL_MORALE).abs() < 1e-9);
+
+    // Shocks move every team; recovery drifts them back to neutral.
+    assert!(matches!(gs.shift_morale(MoraleCause::LaunchFailure), GameEvent::MoraleChanged { .. }));
+    assert!((gs.player_company.average_morale() - (NEUTRAL_MORALE + cfg.launch_failure)).abs() < 1e-9);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+    for _ in 0..60 {
+        assert!(gs.player_company.tick_morale(&mut rng, &cfg).is_empty());
+    }
+    assert!((gs.player_company.average_morale() - NEUTRAL_MORALE).abs() < 1e-9);
+
+    // Each assignment works at its team's efficiency: full at neutral,
+    // more in crunch, less when demoralized. An idle team's morale
+    // touches nobody's work.
+    let efficiencies = |gs: &GameState| gs.player_company.assignment_efficiencies(&cfg);
+    assert_eq!(efficiencies(&gs), vec![1.0; 3]);
+    gs.toggle_crunch();
+    assert!(efficiencies(&gs).iter().all(|e| (e - (1.0 + cfg.crunch_bonus)).abs() < 1e-9));
+    gs.toggle_crunch();
+    gs.player_company.hire_team("Idle".into(), &gs.balance);
+    gs.player_company.teams[3].morale = 0.0;
+    assert_eq!(efficiencies(&gs), vec![1.0; 3]);
+    gs.player_company.teams.pop();
+    for team in &mut gs.player_company.teams {
+        team.morale = NEUTRAL_MORALE - 0.2;
+    }
+    let demoralized = crate::team::morale_efficiency(NEUTRAL_MORALE - 0.2, &cfg);
+    assert!(demoralized < 1.0);
+    assert_eq!(efficiencies(&gs), vec![demoralized; 3]);
+    use crate::engine_project::EngineDesignStatus;
+    gs.player_company.engine_projects[0].status = EngineDesignStatus::InDesign { work_completed: 0.0, work_required: 1e6 };
+    gs.player_company.tick_daily_research(&mut rng, &gs.balance.clone());
+    let EngineDesignStatus::InDesign { work_completed, .. } = gs.player_company.engine_projects[0].status else {
+        panic!("still in design");
+    };
+    assert!((work_completed - crate::team::effective_work_rate(3) * demoralized).abs() < 1e-9);
+
+    // A team at rock bottom quits, and its assignment goes with it.
+    let mut sure_quit = cfg.clone();
+    sure_quit.attrition_per_day = 1.0;
+    gs.player_company.teams[0].morale = 0.0;
+    let events = gs.player_company.tick_morale(&mut rng, &sure_quit);
+    assert!(matches!(events.as_slice(), [crate::engine_project::WorkEvent::TeamResigned { .. }]));
+    assert_eq!(gs.player_company.team_count(), 2);
+    assert_eq!(gs.player_company.engine_projects[0].teams_assigned, 2);
+
+    // Firing costs the survivors morale.
+    let before = gs.player_company.average_morale();
+    assert!(matches!(gs.fire_team(), Some(GameEvent::TeamFired { .. })));
+    assert_eq!(gs.player_company.team_count(), 1);
+    assert_eq!(gs.player_company.engine_projects[0].teams_assigned, 1);
+    assert!(gs.player_company.average_morale() < before);
+}
+
+#[test]
+fn test_furlough_cuts_pay_and_recall_costs_morale_and_ramp_up() {
+    use crate::team::NEUTRAL_MORALE;
+    use rand::SeedableRng;
+
+    let mut gs = GameState::new("T".into(), 10_000_000.0, 0);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.teams.clear();
+    for name in ["A", "B"] {
+        gs.player_company.hire_team(name.into(), &gs.balance);
+    }
+    assert!(gs.player_company.add_team_to_project(0));
+    let costs = gs.balance.costs.clone();
+    let cfg = gs.balance.morale.clone();
+    let full = gs.player_company.monthly_salary_split(&costs).0;
+
+    // Only the idle team can go, and it stays on the books at reduced pay.
+    assert!(matches!(gs.furlough_team(), Some(GameEvent::TeamFurloughed { team_name }) if team_name == "B"));
+    assert!(gs.furlough_team().is_none(), "A is busy");
+    assert_eq!(gs.player_company.team_count(), 2);
+    assert_eq!(gs.player_company.unassigned_team_count(), 0);
+    assert!(!gs.player_company.add_team_to_project(0));
+    let salary = costs.engineering_monthly_salary;
+    assert!((gs.player_company.monthly_salary_split(&costs).0
+        - (full - salary * (1.0 - costs.furlough_salary_fraction))).abs() < 1e-6);
+
+    // Recalled, it's back on full pay but unhappy and slow for a while.
+    assert!(matches!(gs.recall_team(), Some(GameEvent::TeamRecalled { .. })));
+    assert!(gs.recall_team().is_none());
+    assert_eq!(gs.player_company.monthly_salary_split(&costs).0, full);
+    let b = &gs.player_company.teams[1];
+    assert!((b.morale - (NEUTRAL_MORALE + cfg.recall)).abs() < 1e-9);
+    assert_eq!(b.ramp_up_days, cfg.recall_ramp_days);
+    assert!(b.efficiency(&cfg) < crate::team::morale_efficiency(b.morale, &cfg));
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+    for _ in 0..cfg.recall_ramp_days {
+        gs.player_company.tick_morale(&mut rng, &cfg);
+    }
+    assert_eq!(gs.player_company.teams[1].ramp_up_days, 0);
+}
+
+#[test]
+fn test_press_events_amplify_launch_fame_and_sentiment_shapes_contract_rates() {
+    use crate::marketing::{MarketingError, PressKind};
+
+    let mut gs = GameState::new("T".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+
+    let money = gs.player_company.money;
+    assert!(matches!(gs.schedule_press_event(item_id, PressKind::Livestream),
+        Ok(GameEvent::PressEventScheduled { .. })));
+    assert_eq!(gs.player_company.money, money - gs.balance.marketing.livestream_cost);
+    assert_eq!(gs.schedule_press_event(item_id, PressKind::Briefing).unwrap_err(),
+        MarketingError::AlreadyScheduled);
+    assert_eq!(gs.schedule_press_event(crate::manufacturing::InventoryItemId(999), PressKind::Briefing)
+        .unwrap_err(), MarketingError::NoSuchRocket);
+
+    // A livestreamed success earns extra fame on top of the ordinary
+    // success credit and lifts media sentiment.
+    let press = gs.player_company.marketing.take_press_event(item_id);
+    let rep = gs.player_company.reputation.total();
+    let evt = gs.publicize_launch(press, true);
+    assert!(matches!(evt, Some(GameEvent::PressCoverage { success: true, .. })));
+    let extra = (gs.balance.marketing.livestream_success_mult - 1.0) * gs.balance.reputation.success_gain;
+    assert!((gs.player_company.reputation.total() - rep - extra).abs() < 1e-9);
+    assert!(gs.player_company.marketing.sentiment > 0.0);
+
+    // Good press raises every market's rates; an unpublicized failure
+    // makes no coverage event but still costs sentiment.
+    gs.apply_media_sentiment();
+    assert!(gs.markets.iter().all(|m| m.rate_multiplier(1.0) > 1.0));
+    let sentiment = gs.player_company.marketing.sentiment;
+    assert!(gs.publicize_launch(None, false).is_none());
+    assert!(gs.player_company.marketing.sentiment < sentiment);
+
+    assert!(matches!(gs.start_pr_campaign(), Ok(GameEvent::PrCampaignStarted { .. })));
+    assert!(gs.player_company.marketing.pr_active());
+}
+
+#[test]
+fn test_space_race_start_holds_back_markets_and_technology() {
+    use crate::technology::TECH_METHALOX;
+
+    let mut balance = crate::balance_config::BalanceConfig::default();
+    crate::era::apply(crate::era::preset("space_race").unwrap(), &mut balance);
+    let mut gs = GameState::with_balance("T".into(), 7, balance);
+    assert_eq!(gs.date, GameDate::new(1961, 1, 1));
+    let geo = gs.markets.iter().find(|m| m.id == contract::MARKET_GEO_COMSATS).unwrap();
+    assert!(!geo.active, "no commercial comsats before Early Bird");
+    let methalox = |gs: &GameState| gs.technologies.iter().find(|t| t.id == TECH_METHALOX).unwrap().unlocked;
+    assert!(!methalox(&gs));
+
+    let mut events = Vec::new();
+    gs.date = GameDate::new(2004, 1, 1);
+    gs.check_tech_unlocks(&mut events);
+    assert!(!methalox(&gs));
+    gs.date = GameDate::new(2005, 1, 1);
+    gs.check_tech_unlocks(&mut events);
+    assert!(methalox(&gs), "methalox arrives on the era's timeline");
+
+    gs.date = GameDate::new(1965, 1, 1);
+    gs.check_market_events();
+    let geo = gs.markets.iter().find(|m| m.id == contract::MARKET_GEO_COMSATS).unwrap();
+    assert!(geo.active);
+}
+
+#[test]
+fn test_countdown_scrub_spends_nothing_and_a_clear_count_lifts_off() {
+    use crate::countdown::{CountdownOutcome, CountdownSignal};
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+    let contract = push_contract(&mut gs, 1, "leo");
+    gs.balance.countdown.weather_no_go_chance = 0.0;
+    gs.balance.countdown.tanking_abort_chance = 0.0;
+
+    // A scrub leaves the vehicle and the contract where they were.
+    gs.begin_countdown(item_id, &[contract], &[], false).unwrap();
+    assert_eq!(gs.begin_countdown(item_id, &[], &[], false), Err(ManifestError::CountdownUnderway));
+    gs.step_countdown(30);
+    assert!(matches!(gs.scrub_countdown("test"), Some(GameEvent::LaunchScrubbed { .. })));
+    assert!(gs.countdown.is_none());
+    assert_eq!(gs.player_company.manufacturing.inventory.rockets.len(), 1);
+    assert_eq!(gs.player_company.active_contracts.len(), 1);
+
+    // A count still running at day's end loses its window.
+    gs.begin_countdown(item_id, &[contract], &[], false).unwrap();
+    let events = gs.advance_day();
+    assert!(events.iter().any(|e| matches!(e, GameEvent::LaunchScrubbed { reason, .. } if reason == "launch window closed")));
+
+    gs.begin_countdown(item_id, &[contract], &[], false).unwrap();
+    let (signals, outcome) = gs.step_countdown(gs.balance.countdown.window_min);
+    assert_eq!(signals.last(), Some(&CountdownSignal::Liftoff));
+    assert!(matches!(outcome, CountdownOutcome::Launched(..)));
+    assert!(gs.countdown.is_none());
+    assert!(gs.player_company.manufacturing.inventory.rockets.is_empty());
+}
+
+#[test]
+fn test_enforced_commit_criteria_refuse_and_advisory_ones_warn() {
+    use crate::commit_criteria::Criterion;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    gs.player_company.order_rocket_build(0, &gs.balance).unwrap();
+    run_manufacturing_to_rocket(&mut gs);
+    let item_id = gs.player_company.manufacturing.inventory.rockets[0].item_id;
+
+    let criteria = &mut gs.player_company.rocket_projects[0].commit_criteria;
+    criteria.min_engine_testing_cycles = 1_000;
+    criteria.min_flight_reserve = Some(0.0);
+    let checklist = gs.check_commit_criteria(item_id, &[], &[]).unwrap();
+    assert_eq!(checklist.failures(), vec![Criterion::EngineTesting], "advisory: handed back, not refused");
+
+    gs.player_company.rocket_projects[0].commit_criteria.enforced = true;
+    assert_eq!(
+        gs.begin_countdown(item_id, &[], &[], false),
+        Err(ManifestError::CommitCriteriaNotMet(vec![Criterion::EngineTesting])),
+    );
+    assert!(gs.countdown.is_none());
+
+    gs.player_company.rocket_projects[0].commit_criteria.min_engine_testing_cycles = 0;
+    assert!(gs.begin_countdown(item_id, &[], &[], false).is_ok());
+}
+
+#[test]
+fn test_spent_stages_fall_downrange_and_upper_stages_deorbit_or_count_as_debris() {
+    use crate::disposal::{StageFate, UpperStageDisposal};
+    use crate::flight::FlightId;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let rp_id = setup_buildable_rocket(&mut gs);
+    let design = gs.player_company.rocket_projects[0].design.clone();
+    let cfg = gs.balance.disposal.clone();
+
+    // Every launch pays to clear the zone; a stray stage is fined and
+    // closes the site for a review.
+    let before = gs.player_company.money;
+    let (dropped, events) = gs.drop_first_stage(FlightId(1), &design);
+    assert_eq!(dropped.unwrap().fate, StageFate::Downrange { zone: cfg.downrange_zone.clone(), cleared: true });
+    assert!(events.is_empty());
+    assert_eq!(gs.player_company.money, before - cfg.downrange_clearance_fee);
+
+    gs.balance.disposal.stray_stage_chance = 1.0;
+    let (dropped, events) = gs.drop_first_stage(FlightId(2), &design);
+    assert!(matches!(dropped.unwrap().fate, StageFate::Downrange { cleared: false, .. }));
+    assert!(matches!(events.as_slice(), [
+        GameEvent::RegulatoryFine { .. },
+        GameEvent::EnvironmentalReview { .. },
+    ]));
+
+    // The first two stages are spent on the way up; the third deorbits
+    // itself from what's left in its tanks.
+    let flown = |design: &crate::rocket::RocketDesign| {
+        let mut rocket = design.instantiate(crate::rocket::RocketId(1), "leo", 0.0);
+        for group in &mut rocket.stage_states[..2] {
+            for stage in group {
+                stage.attached = false;
+            }
+        }
+        rocket
+    };
+    let mut disposals = vec![crate::disposal::StageDisposal {
+        stage_group: 0,
+        fate: StageFate::Downrange { zone: cfg.downrange_zone.clone(), cleared: true },
+    }];
+    let events = gs.dispose_final_stage(rp_id, &design, &mut flown(&design), "leo", &mut disposals);
+    assert!(events.is_empty());
+    let fates: Vec<_> = disposals.iter().map(|d| (d.stage_group, d.fate.clone())).collect();
+    assert_eq!(fates[1..], [(1, StageFate::Reentered), (2, StageFate::Deorbited)]);
+    assert_eq!(gs.player_company.compliance.orbital_debris, 0);
+
+    // Abandoned stages in Earth orbit are debris, fined past the allowance.
+    gs.player_company.rocket_projects[0].disposal = UpperStageDisposal::Abandon;
+    gs.balance.disposal.debris_allowance = 0;
+    let before = gs.player_company.money;
+    let mut disposals = Vec::new();
+    let events = gs.dispose_final_stage(rp_id, &design, &mut flown(&design), "leo", &mut disposals);
+    assert!(matches!(disposals.last().unwrap().fate, StageFate::Debris { .. }));
+    assert!(matches!(events.as_slice(), [GameEvent::RegulatoryFine { .. }]));
+    assert_eq!(gs.player_company.compliance.orbital_debris, 1);
+    assert_eq!(gs.player_company.money, before - cfg.orbital_debris_fine);
+}
+
+#[test]
+fn test_market_report_projects_growing_payloads_against_the_fleet() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    gs.balance.market_trend.payload_growth_per_year = 0.5;
+    let report = gs.market_report();
+    assert!(!report.outlooks.is_empty());
+    assert!(report.outlooks.iter().all(|o| o.capability_kg == 0.0 && o.covered_now() == 0.0));
+    assert!(report.outlooks.iter().all(|o| o.payload_then_kg.1 > o.payload_now_kg.1));
+    assert!(report.outlooks.iter().all(|o| o.rate_per_kg_then < o.rate_per_kg_now));
+
+    // A design that covers today's smallest payloads won't cover the
+    // heavier ones the same customers will want.
+    setup_buildable_rocket(&mut gs);
+    let report = gs.market_report();
+    assert!(report.outlooks.iter().any(|o| o.capability_kg > 0.0));
+    assert!(report.falling_behind().count() > 0);
+    assert!(report.falling_behind().all(|o| o.covered_then() < o.covered_now()));
+
+    // Today's trend is measured from the game's start.
+    assert_eq!(gs.market_trend(0.0), crate::market_trend::MarketTrend::default());
+    gs.date = gs.start_date.add_days(730);
+    assert!((gs.market_trend(0.0).payload_mult - 2.25).abs() < 0.01);
+}
+
+#[test]
+fn test_deliveries_saturate_a_segment_and_neglect_earns_a_premium() {
+    let mut quiet = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let market = quiet.markets.iter().find(|m| m.active).unwrap();
+    let (market_id, location) = (market.id, market.destinations[0].location_id.clone());
+    let mut flooded = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    for _ in 0..200 {
+        flooded.demand.record_delivery(market_id, &location);
+    }
+    let segment = |gs: &GameState| gs.market_report().outlooks.into_iter()
+        .find(|o| o.market_id == market_id && o.location_id == location)
+        .unwrap();
+    assert!(segment(&flooded).demand.is_saturated());
+    assert!(segment(&quiet).demand.rate_mult > 1.0, "nobody has flown there yet");
+
+    // Same draws either way: the flooded segment issues fewer of them,
+    // each for less.
+    let issued = |gs: &mut GameState, flood: u32| -> Vec<(crate::contract::ContractId, f64)> {
+        let mut issued = Vec::new();
+        for _ in 0..12 {
+            let month = gs.date.month;
+            while gs.date.month == month {
+                gs.advance_day();
+            }
+            issued.extend(gs.available_contracts.iter()
+                .filter(|c| c.market_id == market_id && c.destination == location)
+                .filter(|c| !issued.iter().any(|(id, _)| *id == c.id))
+                .map(|c| (c.id, c.payment))
+                .collect::<Vec<_>>());
+            for _ in 0..flood {
+                gs.demand.record_delivery(market_id, &location);
+            }
+        }
+        issued
+    };
+    let (quiet_issued, flooded_issued) = (issued(&mut quiet, 0), issued(&mut flooded, 20));
+    assert!(!quiet_issued.is_empty());
+    assert!(flooded_issued.len() < quiet_issued.len());
+    for (id, payment) in flooded_issued {
+        let quiet_payment = quiet_issued.iter().find(|(q, _)| *q == id).unwrap().1;
+        assert!(payment < quiet_payment);
+    }
+}
+
+#[test]
+fn test_subcontracted_launch_is_paid_for_now_and_settled_when_the_provider_flies() {
+    use crate::subcontract::SubcontractError;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    push_contract(&mut gs, 1, "leo");
+    push_contract(&mut gs, 2, "lunar_orbit");
+    let rep = gs.player_company.reputation.total();
+
+    assert_eq!(gs.subcontract_launch(1, 0).unwrap_err(), SubcontractError::DestinationNotServed);
+    gs.player_company.active_contracts[1].deadline = gs.date.add_days(10);
+    assert!(matches!(gs.subcontract_launch(1, 2).unwrap_err(), SubcontractError::TooLate { .. }));
+    gs.player_company.active_contracts.remove(1);
+
+    let quote = gs.launch_providers[0].quote(&gs.player_company.active_contracts[0]);
+    let price = gs.launch_providers[0].price;
+    let before = gs.player_company.money;
+    assert!(matches!(gs.subcontract_launch(0, 0), Ok(GameEvent::ContractSubcontracted { .. })));
+    assert_eq!(gs.player_company.money, before - quote);
+    assert!(gs.player_company.active_contracts.is_empty(), "the contract leaves the books");
+    assert!(gs.launch_providers[0].price > price, "the next booking costs more");
+
+    // The one-slot provider can't take a second payload this year.
+    push_contract(&mut gs, 3, "leo");
+    push_contract(&mut gs, 4, "leo");
+    gs.subcontract_launch(0, 2).unwrap();
+    assert_eq!(gs.subcontract_launch(0, 2).unwrap_err(), SubcontractError::FullyBooked);
+
+    let launch_date = gs.player_company.subcontracts[0].launch_date;
+    let mut delivered = Vec::new();
+    while gs.date < launch_date {
+        delivered.extend(gs.advance_day().into_iter()
+            .filter(|e| matches!(e, GameEvent::SubcontractDelivered { .. })));
+    }
+    assert!(matches!(delivered.as_slice(), [GameEvent::SubcontractDelivered { payment, .. }] if *payment == 10_000_000.0));
+    assert_eq!(gs.player_company.subcontracts.len(), 1);
+    assert!(gs.player_company.reputation.total() > rep);
+}
+
+#[test]
+fn test_capacity_plan_matches_the_orders_a_build_queues_and_sizes_the_gap() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let balance = gs.balance.clone();
+    let company = &mut gs.player_company;
+
+    // One build a year: no learning to average, so the plan is exactly
+    // the work a build order queues.
+    let plan = company.capacity_plan(0, 1.0 / 12.0, &balance).unwrap();
+    company.order_rocket_build(0, &balance).unwrap();
+    let queued: f64 = company.manufacturing.orders.iter().map(|o| o.work_required).sum();
+    assert!((plan.work_per_rocket - queued).abs() < 1e-6);
+    assert!(plan.lead_time_days > 0.0 && plan.lead_time_days < queued);
+    assert!(!plan.engine_lines.is_empty());
+
+    let busy = company.capacity_plan(0, 4.0, &balance).unwrap();
+    assert!(busy.work_per_rocket < queued, "a year at cadence rides the learning curve");
+    assert!(busy.teams_needed > plan.teams_needed);
+    assert!(!busy.is_covered());
+    assert_eq!(
+        busy.expansion_cost,
+        busy.team_gap() as f64 * balance.costs.manufacturing_hiring_cost
+            + busy.floor_space_gap() as f64 * balance.costs.floor_space_cost,
+    );
+    assert!(company.capacity_plan(0, 0.0, &balance).is_none());
+}
+
+#[test]
+fn test_sized_manufacturing_teams_merge_convert_and_spread_by_headcount() {
+    use crate::team::TeamSize;
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    setup_buildable_rocket(&mut gs);
+    let balance = gs.balance.clone();
+    let costs = &balance.costs;
+    let company = &mut gs.player_company;
+
+    let money = company.money;
+    company.hire_sized_manufacturing_team("A".into(), TeamSize::Small, &balance).unwrap();
+    company.hire_sized_manufacturing_team("B".into(), TeamSize::Small, &balance).unwrap();
+    assert_eq!(money - company.money, 2.0 * TeamSize::Small.hiring_cost(costs));
+
+    // Two smalls make a medium, paid as one.
+    assert_eq!(company.merge_manufacturing_teams(0, 1, &balance).as_deref(), Some("A"));
+    assert_eq!(company.manufacturing_teams.len(), 1);
+    assert_eq!(company.manufacturing_teams[0].size, TeamSize::Medium);
+    assert_eq!(company.monthly_salary_split(costs).1, costs.manufacturing_monthly_salary);
+    assert!(company.merge_manufacturing_teams(0, 0, &balance).is_none());
+
+    let money = company.money;
+    let cost = company.convert_manufacturing_team(0, TeamSize::Large, &balance).unwrap();
+    assert_eq!(cost, TeamSize::Large.hiring_cost(costs) - TeamSize::Medium.hiring_cost(costs));
+    assert_eq!(money - company.money, cost);
+    company.hire_sized_manufacturing_team("C".into(), TeamSize::Small, &balance).unwrap();
+    company.hire_sized_manufacturing_team("D".into(), TeamSize::Small, &balance).unwrap();
+
+    // The large team takes the first order; both smalls then go to the
+    // other, whose crew is still short of it.
+    company.order_rocket_build(0, &balance).unwrap();
+    company.manufacturing.orders.retain(|o| !o.waiting_for_prerequisites);
+    company.manufacturing.orders.truncate(2);
+    assert_eq!(company.manufacturing.orders.len(), 2);
+    company.auto_assign_idle_manufacturing_teams(costs);
+    let mixes: Vec<_> = company.manufacturing.orders.iter().map(|o| o.team_mix().to_string()).collect();
+    assert_eq!(mixes, ["1L", "2S"]);
+    assert_eq!(company.unassigned_manufacturing_team_count(), 0);
+
+    // A busy team can't be resized, and removal frees the smallest.
+    assert!(company.convert_manufacturing_team(0, TeamSize::Small, &balance).is_none());
+    assert!(company.remove_team_from_manufacturing_order(1));
+    assert_eq!(company.unassigned_manufacturing_teams_of_size(TeamSize::Small), 1);
+}
+
+#[test]
+fn test_salaries_inflate_each_january_and_experienced_teams_ask_for_raises() {
+    let mut balance = crate::balance_config::BalanceConfig::default();
+    balance.payroll.renegotiation_years = 1;
+    balance.payroll.refused_raise_quit_chance = 1.0;
+    let mut gs = GameState::with_balance("T".into(), 0, balance);
+    gs.player_company.money = 1e12;
+    gs.player_company.hire_team("B".into(), &gs.balance);
+    let payroll = gs.balance.payroll.clone();
+    let salary = gs.player_company.teams[0].monthly_salary;
+
+    let mut events = Vec::new();
+    while gs.date.year == gs.start_date.year {
+        events.extend(gs.advance_day());
+    }
+    let inflated = salary * (1.0 + payroll.salary_inflation);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::SalariesInflated { .. })));
+    assert!((gs.player_company.teams[0].monthly_salary - inflated).abs() < 1e-6);
+    assert!((gs.player_company.wage_index - 1.0 - payroll.salary_inflation).abs() < 1e-9);
+
+    // A year in, both teams ask for a raise on their anniversary.
+    while gs.pending_raise_index().is_none() {
+        gs.advance_day();
+    }
+    assert!(gs.player_company.teams.iter().all(|t| t.raise_demand.is_some()));
+    let ask = gs.player_company.teams[0].raise_demand.unwrap().monthly_raise;
+    assert!((ask - inflated * payroll.raise_per_experience_year).abs() < 1e-6);
+    assert!(matches!(gs.accept_raise(0), Some(GameEvent::RaiseGranted { .. })));
+    assert!((gs.player_company.teams[0].monthly_salary - inflated - ask).abs() < 1e-6);
+    assert!(gs.accept_raise(0).is_none(), "answered already");
+
+    // Left unanswered, the other request lapses into a refusal.
+    let deadline = gs.player_company.teams[1].raise_demand.unwrap().deadline;
+    let mut refused = Vec::new();
+    while gs.date < deadline {
+        refused.extend(gs.advance_day().into_iter()
+            .filter(|e| matches!(e, GameEvent::RaiseRefused { .. })));
+    }
+    assert!(matches!(refused.as_slice(), [GameEvent::RaiseRefused { quit: true, .. }]));
+    assert_eq!(gs.player_company.team_count(), 1);
+}
+
+#[test]
+fn test_science_contract_pays_on_arrival_then_for_data_until_the_asset_fails() {
+    use crate::contract::{ContractId, ContractPricing};
+    use crate::event::GameEvent;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    for (id, destination) in [(1, "sso"), (2, "l2")] {
+        let i = push_contract(&mut gs, id, destination);
+        gs.player_company.active_contracts[i].pricing = ContractPricing::DataReturn { upfront_share: 0.3, months: 12 };
+    }
+    let money = gs.player_company.money;
+    let events = arrive_test_flight(&mut gs, "sso", vec![
+        Payload::ContractDelivery { contract_id: ContractId(1), payload_kg: 1_000.0 },
+    ]);
+    assert_eq!(gs.player_company.money, money + 3_000_000.0, "only the upfront share on arrival");
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ScienceCampaignStarted { months: 12, .. })));
+    assert_eq!(gs.science_missions.len(), 1);
+
+    // A long-lived asset in low orbit is heard only on passes.
+    gs.science_missions[0].lifetime_days = 1_000;
+    let money = gs.player_company.money;
+    let mut events = Vec::new();
+    for _ in 0..360 {
+        gs.tick_science(&mut events);
+    }
+    let full = 7_000_000.0 * (1.0 + gs.balance.markets.science_data_premium);
+    let heard = full * gs.balance.science.low_orbit_coverage;
+    assert!((gs.player_company.money - money - heard).abs() < 1.0);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ScienceCampaignComplete { .. })));
+    assert!(gs.science_missions.is_empty());
+
+    // One that dies early stops paying.
+    arrive_test_flight(&mut gs, "l2", vec![
+        Payload::ContractDelivery { contract_id: ContractId(2), payload_kg: 1_000.0 },
+    ]);
+    gs.science_missions[0].lifetime_days = 30;
+    let money = gs.player_company.money;
+    let mut events = Vec::new();
+    for _ in 0..60 {
+        gs.tick_science(&mut events);
+    }
+    let month = full / 12.0 * gs.balance.science.deep_space_coverage;
+    assert!((gs.player_company.money - money - month).abs() < 1.0);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::ScienceAssetLost { .. })));
+    assert!(gs.science_missions.is_empty());
+}
+
+#[test]
+fn test_moving_the_pad_trades_geo_performance_for_polar_orbits() {
+    use crate::launch_site::{PadLease, RelocationError};
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    let (design, _) = make_three_stage_design();
+    let (sso, geo) = (gs.max_payload_from_pad(&design, "sso"), gs.max_payload_from_pad(&design, "geo"));
+    assert_eq!(sso, crate::rocket_project::max_payload_to(&design, "earth_surface", "sso"));
+
+    gs.player_company.launch_site.leases.push(PadLease {
+        lessee: "Orbital Freight".into(), start: gs.date.add_days(10), days: 3, daily_rate: 1.0,
+    });
+    assert_eq!(gs.relocate_launch_site("kodiak").unwrap_err(), RelocationError::LeasesBooked);
+    gs.player_company.launch_site.leases.clear();
+    assert_eq!(gs.relocate_launch_site("cape").unwrap_err(), RelocationError::AlreadyThere);
+    assert_eq!(gs.relocate_launch_site("atlantis").unwrap_err(), RelocationError::UnknownSite);
+
+    let money = gs.player_company.money;
+    assert!(matches!(gs.relocate_launch_site("kodiak"), Ok(GameEvent::LaunchSiteRelocated { .. })));
+    assert_eq!(gs.player_company.money, money - gs.balance.launch_sites.relocation_cost);
+    assert!(gs.max_payload_from_pad(&design, "sso") > sso, "Kodiak's range flies polar");
+    assert!(gs.max_payload_from_pad(&design, "geo") < geo, "GEO is a long way below Kodiak");
+    let plan = crate::integration::MissionPlan::new("geo", 100.0, gs.plane_change_dv("geo")).unwrap();
+    assert!(plan.plane_change_dv > 0.0);
+}
+
+#[test]
+fn test_off_target_insertion_is_corrected_from_reserve_or_discounted() {
+    use crate::contract::ContractId;
+    use crate::event::GameEvent;
+
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    for id in 1..=3 {
+        let i = push_contract(&mut gs, id, "leo");
+        gs.player_company.active_contracts[i].insertion_tolerance = Some(20.0);
+    }
+    let delivery = |id| vec![Payload::ContractDelivery { contract_id: ContractId(id), payload_kg: 1_000.0 }];
+
+    // Within tolerance pays in full.
+    let mut flight = arrived_test_flight(&gs, "leo", delivery(1));
+    flight.insertion_error = 15.0;
+    let money = gs.player_company.money;
+    gs.resolve_arrived_flight(flight);
+    assert_eq!(gs.player_company.money, money + 10_000_000.0);
+
+    // An empty carrier can't trim, so the customer discounts the miss.
+    let mut flight = arrived_test_flight(&gs, "leo", delivery(2));
+    flight.insertion_error = 60.0;
+    let money = gs.player_company.money;
+    let events = gs.resolve_arrived_flight(flight);
+    let withheld = 10_000_000.0 * 40.0 * gs.balance.markets.insertion_penalty_per_m_s;
+    assert!((gs.player_company.money - (money + 10_000_000.0 - withheld)).abs() < 1e-3);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::InsertionOutOfTolerance { .. })));
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::InsertionCorrected { .. })));
+
+    // One with propellant left burns it to bring the payload in.
+    setup_buildable_rocket(&mut gs);
+    let design = gs.player_company.rocket_projects[0].design.clone();
+    let mut flight = arrived_test_flight(&gs, "leo", delivery(3));
+    flight.rocket = design.instantiate(crate::rocket::RocketId(1), "leo", 1_000.0);
+    flight.design = design;
+    flight.insertion_error = 60.0;
+    let dv_before = flight.rocket.remaining_delta_v(&flight.design);
+    assert!(dv_before > 40.0);
+    let money = gs.player_company.money;
+    let events = gs.resolve_arrived_flight(flight);
+    assert_eq!(gs.player_company.money, money + 10_000_000.0);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::InsertionCorrected { dv, .. } if (*dv - 40.0).abs() < 1e-6)));
+}
+
+#[test]
+fn test_world_market_reprices_vendor_engines_for_every_company() {
+    let mut gs = GameState::new("Test".into(), 1_000_000_000.0, 42);
+    gs.date = GameDate::new(2001, 3, 2);
+    let before = gs.player_company.third_party_catalog[0].purchase_cost_per_unit;
+    gs.tick_world_market();
+    assert_eq!(gs.player_company.third_party_catalog[0].purchase_cost_per_unit, before,
+        "a new world holds its prices");
+
+    // A settled world on a buying spree bids vendor engines up.
+    gs.world_market.months_recorded = gs.balance.world_market.settling_months + 1;
+    gs.world_market.engine_rate = Some(gs.balance.world_market.baseline_engines_per_month * 3.0);
+    for _ in 0..30 {
+        gs.tick_world_market();
+    }
+    let index = gs.world_market.prices.engines;
+    assert!(index > 1.0);
+    let after = gs.player_company.third_party_catalog[0].purchase_cost_per_unit;
+    assert!((after - before * index).abs() < 1e-3);
+}
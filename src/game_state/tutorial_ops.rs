@@ -0,0 +1,53 @@
+//! Playing the tutorial (see [`crate::tutorial`]): starting it and
+//! moving it on at the end of each day.
+
+use crate::engine_project::EngineDesignStatus;
+use crate::event::GameEvent;
+use crate::manufacturing::ManufacturingOrderType;
+use crate::tutorial::{Tutorial, TutorialProgress};
+
+use super::*;
+
+impl GameState {
+    /// Play the tutorial from its first step. Replaces one already going.
+    pub fn start_tutorial(&mut self) {
+        self.tutorial = Some(Tutorial::new(self.date));
+    }
+
+    /// Where the player stands on everything the tutorial asks for.
+    pub fn tutorial_progress(&self) -> TutorialProgress {
+        let company = &self.player_company;
+        let manufacturing = &company.manufacturing;
+        let building = manufacturing.orders.iter()
+            .filter(|o| matches!(o.order_type, ManufacturingOrderType::RocketIntegration { .. }))
+            .count();
+        TutorialProgress {
+            engine_designs: company.engine_projects.iter()
+                .filter(|ep| !matches!(ep.status, EngineDesignStatus::Proposed { .. }))
+                .count() as u32,
+            teams_assigned: company.assigned_team_count(),
+            rocket_designs: company.rocket_projects.len() as u32,
+            rocket_builds: (building + manufacturing.inventory.rockets.len() + company.launch_history.len()) as u32,
+            launches: company.launch_history.len() as u32,
+        }
+    }
+
+    /// End of day: move the tutorial past any steps now done.
+    pub(super) fn tick_tutorial(&mut self, events: &mut Vec<GameEvent>) {
+        if self.tutorial.as_ref().is_none_or(|t| t.is_finished()) {
+            return;
+        }
+        let progress = self.tutorial_progress();
+        let Some(tutorial) = self.tutorial.as_mut() else { return };
+        let mut done: Vec<GameEvent> = tutorial.advance(&progress, self.date).iter()
+            .map(|step| GameEvent::TutorialStepDone { step: step.title().to_string() })
+            .collect();
+        if !done.is_empty() && tutorial.is_finished() {
+            done.push(GameEvent::TutorialFinished);
+        }
+        for evt in done {
+            self.event_log.push(self.date, evt.clone());
+            events.push(evt);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Government space programs. Every so often an agency announces a
+//! program — lunar cargo, say — with capability milestones and fixed
+//! deadlines. A company that commits before the response window closes
+//! draws a monthly subsidy for the program's term; a milestone is met
+//! by a successful flight delivering enough mass to its destination.
+//! Missing a deadline ends the program and claws back part of what was
+//! paid.
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::GovProgramsConfig;
+use crate::calendar::GameDate;
+
+/// Unique identifier for a government program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GovProgramId(pub u64);
+
+/// A program the agency can announce.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgramTemplate {
+    pub name: &'static str,
+    /// (destination, payload kg, days from announcement to deadline).
+    pub milestones: &'static [(&'static str, f64, u32)],
+    pub monthly_subsidy: f64,
+    pub term_months: u32,
+}
+
+pub const PROGRAM_TEMPLATES: &[ProgramTemplate] = &[
+    ProgramTemplate {
+        name: "Commercial LEO Access Program",
+        milestones: &[("leo", 5_000.0, 540)],
+        monthly_subsidy: 1_000_000.0,
+        term_months: 24,
+    },
+    ProgramTemplate {
+        name: "Geostationary Access Initiative",
+        milestones: &[("gto", 3_000.0, 540), ("geo", 2_000.0, 1_080)],
+        monthly_subsidy: 1_200_000.0,
+        term_months: 30,
+    },
+    ProgramTemplate {
+        name: "Lunar Cargo Program",
+        milestones: &[("lunar_orbit", 1_000.0, 720), ("lunar_surface", 500.0, 1_440)],
+        monthly_subsidy: 1_500_000.0,
+        term_months: 36,
+    },
+    ProgramTemplate {
+        name: "Mars Pathfinder Program",
+        milestones: &[("mars_transfer", 1_000.0, 1_080), ("mars_orbit_200km", 500.0, 1_800)],
+        monthly_subsidy: 2_000_000.0,
+        term_months: 48,
+    },
+];
+
+/// One capability the program pays for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Milestone {
+    pub destination: String,
+    pub payload_kg: f64,
+    pub deadline: GameDate,
+    pub met_on: Option<GameDate>,
+}
+
+impl Milestone {
+    pub fn is_met(&self) -> bool {
+        self.met_on.is_some()
+    }
+}
+
+/// Where a program stands with the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProgramStatus {
+    /// Open for commitment until `respond_by`.
+    Announced { respond_by: GameDate },
+    /// Signed on; subsidy flowing, milestones outstanding.
+    Committed,
+    /// Every milestone met; the rest of the subsidy is safe.
+    Completed,
+    /// A deadline was missed and the subsidy clawed back.
+    Failed,
+}
+
+/// A government program, announced or under way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GovProgram {
+    pub id: GovProgramId,
+    pub name: String,
+    pub milestones: Vec<Milestone>,
+    pub monthly_subsidy: f64,
+    pub term_months: u32,
+    pub months_paid: u32,
+    /// Subsidy received so far — what a clawback is figured on.
+    pub subsidy_received: f64,
+    pub status: ProgramStatus,
+}
+
+impl GovProgram {
+    /// Announce a program from `template` today.
+    pub fn from_template(
+        id: GovProgramId,
+        template: &ProgramTemplate,
+        today: GameDate,
+        cfg: &GovProgramsConfig,
+    ) -> Self {
+        GovProgram {
+            id,
+            name: template.name.to_string(),
+            milestones: template.milestones.iter()
+                .map(|&(destination, payload_kg, days)| Milestone {
+                    destination: destination.to_string(),
+                    payload_kg,
+                    deadline: today.add_days(days),
+                    met_on: None,
+                })
+                .collect(),
+            monthly_subsidy: template.monthly_subsidy * cfg.subsidy_scale,
+            term_months: template.term_months,
+            months_paid: 0,
+            subsidy_received: 0.0,
+            status: ProgramStatus::Announced { respond_by: today.add_days(cfg.response_window_days) },
+        }
+    }
+
+    /// Announced or still paying out — the agency won't announce the
+    /// same program again while it is.
+    pub fn is_live(&self) -> bool {
+        match self.status {
+            ProgramStatus::Announced { .. } | ProgramStatus::Committed => true,
+            ProgramStatus::Completed => self.months_paid < self.term_months,
+            ProgramStatus::Failed => false,
+        }
+    }
+
+    /// The subsidy payment due this month, if the stream is running.
+    pub fn subsidy_due(&self) -> Option<f64> {
+        let running = matches!(self.status, ProgramStatus::Committed | ProgramStatus::Completed);
+        (running && self.months_paid < self.term_months).then_some(self.monthly_subsidy)
+    }
+
+    /// Record one month's subsidy as paid.
+    pub fn pay_subsidy(&mut self, amount: f64) {
+        self.months_paid += 1;
+        self.subsidy_received += amount;
+    }
+
+    /// Check a successful delivery against the outstanding milestones.
+    /// Returns the indices newly met; completes the program when none
+    /// remain. Only committed programs count deliveries.
+    pub fn record_delivery(&mut self, destination: &str, payload_kg: f64, date: GameDate) -> Vec<usize> {
+        if self.status != ProgramStatus::Committed {
+            return Vec::new();
+        }
+        let mut met = Vec::new();
+        for (i, m) in self.milestones.iter_mut().enumerate() {
+            if !m.is_met() && m.destination == destination && payload_kg >= m.payload_kg {
+                m.met_on = Some(date);
+                met.push(i);
+            }
+        }
+        if self.milestones.iter().all(Milestone::is_met) {
+            self.status = ProgramStatus::Completed;
+        }
+        met
+    }
+
+    /// The first outstanding milestone whose deadline has passed.
+    pub fn missed_milestone(&self, today: GameDate) -> Option<&Milestone> {
+        if self.status != ProgramStatus::Committed {
+            return None;
+        }
+        self.milestones.iter().find(|m| !m.is_met() && m.deadline < today)
+    }
+
+    /// What the agency takes back when the program fails.
+    pub fn clawback(&self, cfg: &GovProgramsConfig) -> f64 {
+        self.subsidy_received * cfg.clawback_fraction
+    }
+}
+
+/// Roll this month's announcement. At most one program runs per
+/// template at a time; `live` holds the names already announced or
+/// under way.
+pub fn announce(
+    rng: &mut StdRng,
+    live: &[&str],
+    next_id: &mut u64,
+    today: GameDate,
+    cfg: &GovProgramsConfig,
+) -> Option<GovProgram> {
+    if rng.gen::<f64>() >= cfg.announce_chance_per_month {
+        return None;
+    }
+    let open: Vec<&ProgramTemplate> = PROGRAM_TEMPLATES.iter()
+        .filter(|t| !live.contains(&t.name))
+        .collect();
+    if open.is_empty() {
+        return None;
+    }
+    let template = open[rng.gen_range(0..open.len())];
+    let id = GovProgramId(*next_id);
+    *next_id += 1;
+    Some(GovProgram::from_template(id, template, today, cfg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestones_complete_or_miss_and_clawback() {
+        let cfg = GovProgramsConfig::default();
+        let start = GameDate::new(2001, 1, 1);
+        let mut p = GovProgram::from_template(GovProgramId(1), &PROGRAM_TEMPLATES[2], start, &cfg);
+        assert_eq!(p.subsidy_due(), None, "no subsidy before commitment");
+        assert!(p.record_delivery("lunar_orbit", 5_000.0, start).is_empty());
+
+        p.status = ProgramStatus::Committed;
+        let due = p.subsidy_due().unwrap();
+        p.pay_subsidy(due);
+        p.pay_subsidy(due);
+
+        assert!(p.record_delivery("lunar_orbit", 900.0, start).is_empty(), "too light");
+        assert_eq!(p.record_delivery("lunar_orbit", 1_000.0, start), vec![0]);
+        assert_eq!(p.status, ProgramStatus::Committed);
+
+        let late = p.milestones[1].deadline.next_day();
+        assert_eq!(p.missed_milestone(late).unwrap().destination, "lunar_surface");
+        assert_eq!(p.clawback(&cfg), 2.0 * due * cfg.clawback_fraction);
+
+        assert_eq!(p.record_delivery("lunar_surface", 500.0, start), vec![1]);
+        assert_eq!(p.status, ProgramStatus::Completed);
+        assert!(p.missed_milestone(late).is_none());
+        assert!(p.subsidy_due().is_some(), "completed programs keep paying out the term");
+    }
+}
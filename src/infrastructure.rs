@@ -0,0 +1,640 @@
+//! Fixed infrastructure at off-Earth locations: propellant depots and
+//! the ISRU (in-situ resource utilization) plants that fill them.
+//!
+//! An ISRU plant flies as a payload like any other; on arrival at an
+//! ISRU site it is set down and starts producing that site's propellant
+//! mix every day into the location's depot, which spacecraft parked
+//! there can refuel from.
+//!
+//! A depot runs on its solar arrays. Its cryocoolers get power first to
+//! hold cryogenic stores against heat leak; the ISRU plants share what's
+//! left. A [`PowerBudget`] works this out from a [`DepotDesign`], so a
+//! designer can preview it before anything flies: too little power slows
+//! production, too little cooling lets the cryogenics boil off.
+//!
+//! Every location starts with a stock depot. A better one is designed
+//! and tested like any other hardware (see [`crate::depot_project`])
+//! and flown out to replace it.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::{DepotDesignConfig, IsruConfig};
+use crate::calendar::GameDate;
+use crate::flaw::Flaw;
+use crate::propellant::Propellant;
+use crate::rocket::{Rocket, RocketDesign, StageState};
+use crate::stage::Stage;
+
+/// Why an infrastructure operation couldn't go ahead.
+#[derive(Debug, Clone)]
+pub enum InfrastructureError {
+    NotAnIsruSite(String),
+    CantAfford { cost: f64 },
+    RocketMissing,
+    PadLeased { lessee: String, free_on: GameDate },
+    NotCompliant(crate::regulation::ComplianceIssue),
+    /// Nothing has been built at the spacecraft's location.
+    NoDepot(String),
+    /// The depot holds none of what the spacecraft burns, or its tanks
+    /// are already full.
+    NothingToLoad,
+    /// Depot designs can only be built once they're in testing.
+    DepotNotReady,
+    CantAffordDepot { cost: f64 },
+}
+
+impl fmt::Display for InfrastructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InfrastructureError::NotAnIsruSite(loc) => write!(f, "ISRU plants can't work at {}", loc),
+            InfrastructureError::CantAfford { cost } =>
+                write!(f, "An ISRU plant costs {}", crate::resources::format_money(*cost)),
+            InfrastructureError::RocketMissing => write!(f, "No rocket selected"),
+            InfrastructureError::PadLeased { lessee, free_on } =>
+                write!(f, "Pad leased to {} — free again on {}", lessee, free_on),
+            InfrastructureError::NotCompliant(issue) => write!(f, "{}", issue),
+            InfrastructureError::NoDepot(loc) => write!(f, "No depot at {}", loc),
+            InfrastructureError::NothingToLoad => write!(f, "Nothing to load"),
+            InfrastructureError::DepotNotReady => write!(f, "Depot designs fly once they reach testing"),
+            InfrastructureError::CantAffordDepot { cost } =>
+                write!(f, "That depot costs {}", crate::resources::format_money(*cost)),
+        }
+    }
+}
+
+/// An ISRU plant's size and output. Mass and cost scale with daily
+/// output; the products depend on where it is set down.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IsruPlantDesign {
+    pub name: String,
+    /// Propellant produced per day, in kg, before the depot caps it.
+    pub output_kg_per_day: f64,
+    pub mass_kg: f64,
+    pub unit_cost: f64,
+}
+
+impl IsruPlantDesign {
+    /// A plant sized for `output_kg_per_day`.
+    pub fn sized(name: impl Into<String>, output_kg_per_day: f64, cfg: &IsruConfig) -> Self {
+        IsruPlantDesign {
+            name: name.into(),
+            output_kg_per_day,
+            mass_kg: output_kg_per_day * cfg.plant_mass_per_daily_kg,
+            unit_cost: output_kg_per_day * cfg.plant_cost_per_daily_kg,
+        }
+    }
+}
+
+/// A plant set down at a site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IsruPlant {
+    pub design: IsruPlantDesign,
+    pub deployed_on: GameDate,
+    /// Lifetime output, in kg.
+    pub produced_kg: f64,
+    /// Wears down day by day; output scales with it.
+    #[serde(default = "crate::servicing::full_health")]
+    pub health: f64,
+}
+
+/// Docking ports on the stock depot.
+pub const STOCK_DOCKING_PORTS: u32 = 2;
+
+fn stock_docking_ports() -> u32 {
+    STOCK_DOCKING_PORTS
+}
+
+/// A depot's tankage, solar arrays and cryocoolers, sized at design time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepotDesign {
+    pub name: String,
+    pub capacity_kg: f64,
+    /// Solar array output at 1 AU, in kW; falls off as 1/AU².
+    pub solar_array_kw_at_1au: f64,
+    /// Heat the cryocoolers can lift, in kW (thermal).
+    pub cryocooler_kw: f64,
+    /// Propellants the depot has tanks and plumbing for. Empty takes
+    /// anything (depots from older saves).
+    #[serde(default)]
+    pub plumbed: Vec<Propellant>,
+    /// Share of the cryogenic heat leak the insulation blocks.
+    #[serde(default)]
+    pub insulation: f64,
+    /// Spacecraft that can transfer propellant at once.
+    #[serde(default = "stock_docking_ports")]
+    pub docking_ports: u32,
+}
+
+impl DepotDesign {
+    /// The stock depot for a site `sun_distance_au` from the Sun, its
+    /// arrays sized to deliver `cfg.depot_power_kw` there, plumbed for
+    /// `plumbed`.
+    pub fn stock(cfg: &IsruConfig, sun_distance_au: f64, plumbed: Vec<Propellant>) -> Self {
+        DepotDesign {
+            name: "Stock Depot".into(),
+            capacity_kg: cfg.depot_capacity_kg,
+            solar_array_kw_at_1au: cfg.depot_power_kw * sun_distance_au.powi(2),
+            cryocooler_kw: cfg.depot_cryocooler_kw,
+            plumbed,
+            insulation: 0.0,
+            docking_ports: STOCK_DOCKING_PORTS,
+        }
+    }
+
+    /// Launch mass: a fixed bus plus tanks, insulation, arrays,
+    /// cryocoolers and ports.
+    pub fn mass_kg(&self, cfg: &DepotDesignConfig) -> f64 {
+        cfg.base_mass_kg
+            + self.capacity_kg * (cfg.tank_mass_per_kg + self.insulation * cfg.insulation_mass_per_kg)
+            + self.solar_array_kw_at_1au * cfg.array_mass_per_kw
+            + self.cryocooler_kw * cfg.cryocooler_mass_per_kw
+            + self.docking_ports as f64 * cfg.port_mass_kg
+    }
+
+    /// What building one costs, from the same specs as its mass.
+    pub fn unit_cost(&self, cfg: &DepotDesignConfig) -> f64 {
+        cfg.base_cost
+            + self.capacity_kg * (cfg.tank_cost_per_kg + self.insulation * cfg.insulation_cost_per_kg)
+            + self.solar_array_kw_at_1au * cfg.array_cost_per_kw
+            + self.cryocooler_kw * cfg.cryocooler_cost_per_kw
+            + self.docking_ports as f64 * cfg.port_cost
+    }
+
+    pub fn accepts(&self, propellant: Propellant) -> bool {
+        self.plumbed.is_empty() || self.plumbed.contains(&propellant)
+    }
+
+    pub fn solar_output_kw(&self, sun_distance_au: f64) -> f64 {
+        self.solar_array_kw_at_1au / sun_distance_au.max(0.1).powi(2)
+    }
+
+    /// Power and thermal balance with `cryo_kg` of cryogenic propellant
+    /// aboard and plants drawing `isru_demand_kw` at full output.
+    pub fn budget(
+        &self,
+        sun_distance_au: f64,
+        cryo_kg: f64,
+        isru_demand_kw: f64,
+        cfg: &IsruConfig,
+    ) -> PowerBudget {
+        let supply_kw = self.solar_output_kw(sun_distance_au);
+        let heat_load_kw = cryo_kg / 1_000.0 * cfg.cryo_heat_leak_w_per_tonne / 1_000.0
+            * (1.0 - self.insulation);
+        let lift_kw = heat_load_kw.min(self.cryocooler_kw);
+        let cooling_demand_kw = lift_kw * cfg.cryocooler_w_per_w;
+        let cooling_kw = cooling_demand_kw.min(supply_kw);
+        let cooling_fraction = if heat_load_kw <= 0.0 {
+            1.0
+        } else if cooling_demand_kw <= 0.0 {
+            0.0
+        } else {
+            lift_kw * (cooling_kw / cooling_demand_kw) / heat_load_kw
+        };
+        let isru_fraction = if isru_demand_kw <= 0.0 {
+            1.0
+        } else {
+            ((supply_kw - cooling_kw) / isru_demand_kw).clamp(0.0, 1.0)
+        };
+        PowerBudget {
+            supply_kw,
+            cooling_demand_kw,
+            isru_demand_kw,
+            heat_load_kw,
+            cooling_fraction,
+            isru_fraction,
+            boiloff_per_day: cfg.uncooled_boiloff_per_day * (1.0 - cooling_fraction),
+        }
+    }
+}
+
+/// How a depot's power and cooling stretch on a given day.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PowerBudget {
+    pub supply_kw: f64,
+    /// Electrical draw of the cryocoolers at the current heat load.
+    pub cooling_demand_kw: f64,
+    /// Electrical draw of the ISRU plants at full output.
+    pub isru_demand_kw: f64,
+    /// Heat leaking into the cryogenic stores, in kW (thermal).
+    pub heat_load_kw: f64,
+    /// Share of the heat load the cryocoolers remove.
+    pub cooling_fraction: f64,
+    /// Share of full output the ISRU plants run at.
+    pub isru_fraction: f64,
+    /// Daily fraction of cryogenic stores boiling off.
+    pub boiloff_per_day: f64,
+}
+
+impl PowerBudget {
+    pub fn demand_kw(&self) -> f64 {
+        self.cooling_demand_kw + self.isru_demand_kw
+    }
+}
+
+/// Propellant stored at a location, by type, under its design's capacity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Depot {
+    pub design: DepotDesign,
+    pub stores: Vec<(Propellant, f64)>,
+    /// Flaws flown up with a player-designed depot, rolled daily. The
+    /// stock depot has none.
+    #[serde(default)]
+    pub flaws: Vec<Flaw>,
+}
+
+impl Depot {
+    pub fn new(design: DepotDesign) -> Self {
+        Depot { design, stores: Vec::new(), flaws: Vec::new() }
+    }
+
+    pub fn capacity_kg(&self) -> f64 {
+        self.design.capacity_kg
+    }
+
+    pub fn stored(&self, propellant: Propellant) -> f64 {
+        self.stores.iter().find(|(p, _)| *p == propellant).map_or(0.0, |(_, kg)| *kg)
+    }
+
+    pub fn total_kg(&self) -> f64 {
+        self.stores.iter().map(|(_, kg)| kg).sum()
+    }
+
+    pub fn free_kg(&self) -> f64 {
+        (self.capacity_kg() - self.total_kg()).max(0.0)
+    }
+
+    pub fn cryogenic_kg(&self) -> f64 {
+        self.stores.iter().filter(|(p, _)| p.is_cryogenic()).map(|(_, kg)| kg).sum()
+    }
+
+    /// Store up to `kg`; returns how much fit (nothing the depot isn't
+    /// plumbed for).
+    pub fn add(&mut self, propellant: Propellant, kg: f64) -> f64 {
+        if !self.design.accepts(propellant) {
+            return 0.0;
+        }
+        let accepted = kg.max(0.0).min(self.free_kg());
+        if accepted > 0.0 {
+            match self.stores.iter_mut().find(|(p, _)| *p == propellant) {
+                Some((_, stored)) => *stored += accepted,
+                None => self.stores.push((propellant, accepted)),
+            }
+        }
+        accepted
+    }
+
+    /// Lose `fraction` of what's stored, cryogenics only if
+    /// `cryogenic_only`. Returns the kg lost.
+    pub fn spill(&mut self, fraction: f64, cryogenic_only: bool) -> f64 {
+        let mut lost = 0.0;
+        for (propellant, kg) in &mut self.stores {
+            if !cryogenic_only || propellant.is_cryogenic() {
+                let gone = *kg * fraction.clamp(0.0, 1.0);
+                *kg -= gone;
+                lost += gone;
+            }
+        }
+        lost
+    }
+
+    /// Draw up to `kg`; returns how much was taken.
+    pub fn take(&mut self, propellant: Propellant, kg: f64) -> f64 {
+        let Some((_, stored)) = self.stores.iter_mut().find(|(p, _)| *p == propellant) else {
+            return 0.0;
+        };
+        let taken = kg.max(0.0).min(*stored);
+        *stored -= taken;
+        taken
+    }
+
+    /// Top up every attached, liquid-fuelled stage of `rocket` from the
+    /// depot, drawing each stage's propellants in its engine's mix ratio
+    /// (so the scarcest component limits the fill). Returns kg loaded.
+    pub fn refuel(&mut self, design: &RocketDesign, rocket: &mut Rocket) -> f64 {
+        let mut loaded = 0.0;
+        for_each_liquid_stage(design, rocket, |stage, state| {
+            let mix = &stage.engine.propellant_mix;
+            let missing = (stage.propellant_mass_kg - state.propellant_remaining_kg).max(0.0);
+            let fill = mix.iter()
+                .filter(|f| f.mass_fraction > 0.0)
+                .map(|f| self.stored(f.propellant) / (missing * f.mass_fraction))
+                .fold(1.0_f64, f64::min);
+            if missing <= 0.0 || fill <= 0.0 {
+                return;
+            }
+            for f in mix {
+                loaded += self.take(f.propellant, missing * fill * f.mass_fraction);
+            }
+            state.propellant_remaining_kg += missing * fill;
+        });
+        loaded
+    }
+
+    /// Drain every attached, liquid-fuelled stage of `rocket` into the
+    /// depot, split back into its propellants by mix ratio, until the
+    /// depot is full. Stages burning anything the depot isn't plumbed
+    /// for keep their propellant. Returns kg moved.
+    pub fn offload(&mut self, design: &RocketDesign, rocket: &mut Rocket) -> f64 {
+        let mut moved = 0.0;
+        for_each_liquid_stage(design, rocket, |stage, state| {
+            let mix = &stage.engine.propellant_mix;
+            if !mix.iter().all(|f| self.design.accepts(f.propellant)) {
+                return;
+            }
+            let kg = state.propellant_remaining_kg.min(self.free_kg());
+            if kg <= 0.0 {
+                return;
+            }
+            for f in &stage.engine.propellant_mix {
+                self.add(f.propellant, kg * f.mass_fraction);
+            }
+            state.propellant_remaining_kg -= kg;
+            moved += kg;
+        });
+        moved
+    }
+}
+
+/// Propellant in `rocket`'s attached liquid-fuelled stages: what a depot
+/// could take off it. Solid motors can't be drained.
+pub fn transferable_kg(design: &RocketDesign, rocket: &Rocket) -> f64 {
+    let mut total = 0.0;
+    for (gi, group) in design.stage_groups.iter().enumerate() {
+        for (si, stage) in group.iter().enumerate() {
+            let Some(state) = rocket.stage_states.get(gi).and_then(|g| g.get(si)) else {
+                continue;
+            };
+            if state.attached && plumbable(stage) {
+                total += state.propellant_remaining_kg;
+            }
+        }
+    }
+    total
+}
+
+/// Whether a stage's tanks can be piped to and from a depot.
+fn plumbable(stage: &Stage) -> bool {
+    let mix = &stage.engine.propellant_mix;
+    !mix.is_empty() && mix.iter().all(|f| f.propellant != Propellant::SolidMix)
+}
+
+/// Run `f` on every attached, liquid-fuelled stage of `rocket`.
+pub(crate) fn for_each_liquid_stage(
+    design: &RocketDesign,
+    rocket: &mut Rocket,
+    mut f: impl FnMut(&Stage, &mut StageState),
+) {
+    for (gi, group) in design.stage_groups.iter().enumerate() {
+        for (si, stage) in group.iter().enumerate() {
+            let Some(state) = rocket.stage_states.get_mut(gi).and_then(|g| g.get_mut(si)) else {
+                continue;
+            };
+            if state.attached && plumbable(stage) {
+                f(stage, state);
+            }
+        }
+    }
+}
+
+/// Which way a standing transfer order moves propellant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferDirection {
+    /// Keep the spacecraft's tanks topped up from the depot.
+    Fill,
+    /// Empty the spacecraft's tanks into the depot.
+    Offload,
+}
+
+/// A standing order to move propellant between a spacecraft and the
+/// depot where it's parked, run every day the two are co-located.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferOrder {
+    pub spacecraft_id: crate::game_state::SpacecraftId,
+    pub direction: TransferDirection,
+}
+
+/// A depot's full accounting, for a front end's propellant table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepotReport {
+    pub location: String,
+    pub capacity_kg: f64,
+    pub total_kg: f64,
+    /// Stock by propellant type.
+    pub inventories: Vec<(Propellant, f64)>,
+    /// What the ISRU plants stored yesterday.
+    pub fill_rate_kg_per_day: f64,
+    /// What boiled off yesterday.
+    pub boiloff_kg_last_day: f64,
+    pub power: PowerBudget,
+    /// Player flights bound here, soonest first.
+    pub inbound: Vec<InboundFlight>,
+    pub transfer_orders: Vec<TransferOrder>,
+}
+
+/// A player flight heading for a depot's location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InboundFlight {
+    pub flight_id: crate::flight::FlightId,
+    pub rocket_name: String,
+    pub eta_days: u32,
+    /// Liquid propellant aboard that could be offloaded on arrival.
+    pub propellant_kg: f64,
+}
+
+/// Everything the player has built at one location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationInfrastructure {
+    pub location: String,
+    pub depot: Depot,
+    pub isru_plants: Vec<IsruPlant>,
+    /// Yesterday's ISRU output into the depot, in kg.
+    #[serde(default)]
+    pub last_produced_kg: f64,
+    /// Yesterday's boiloff, in kg.
+    #[serde(default)]
+    pub last_boiloff_kg: f64,
+}
+
+impl LocationInfrastructure {
+    /// A stock depot and no plants yet.
+    pub fn new(location: impl Into<String>, cfg: &IsruConfig) -> Self {
+        let location = location.into();
+        let plumbed = cfg.products_at(&location)
+            .map(|products| products.iter().map(|&(p, _)| p).collect())
+            .unwrap_or_default();
+        let depot = Depot::new(DepotDesign::stock(cfg, sun_distance_au(&location), plumbed));
+        LocationInfrastructure {
+            location,
+            depot,
+            isru_plants: Vec::new(),
+            last_produced_kg: 0.0,
+            last_boiloff_kg: 0.0,
+        }
+    }
+
+    /// Today's power and thermal balance.
+    pub fn power_budget(&self, cfg: &IsruConfig) -> PowerBudget {
+        self.depot.design.budget(
+            sun_distance_au(&self.location),
+            self.depot.cryogenic_kg(),
+            self.isru_output_kg_per_day() * cfg.plant_power_kw_per_daily_kg,
+            cfg,
+        )
+    }
+
+    /// One day of ISRU production into the depot, split by the site's
+    /// product mix and throttled by the power left after cooling.
+    /// Returns the kg stored (less than the plants' output once the
+    /// depot fills).
+    pub fn produce_day(&mut self, cfg: &IsruConfig) -> f64 {
+        let Some(products) = cfg.products_at(&self.location) else {
+            return 0.0;
+        };
+        let throttle = self.power_budget(cfg).isru_fraction;
+        let mut stored = 0.0;
+        for plant in &mut self.isru_plants {
+            let mut made = 0.0;
+            for &(propellant, fraction) in products {
+                made += self.depot.add(propellant, plant.design.output_kg_per_day * plant.health * throttle * fraction);
+            }
+            plant.produced_kg += made;
+            stored += made;
+        }
+        self.last_produced_kg = stored;
+        stored
+    }
+
+    /// One day of boiloff from the cryogenic stores the coolers can't
+    /// hold. Returns the kg lost.
+    pub fn boil_off_day(&mut self, cfg: &IsruConfig) -> f64 {
+        let rate = self.power_budget(cfg).boiloff_per_day;
+        self.last_boiloff_kg = 0.0;
+        if rate <= 0.0 {
+            return 0.0;
+        }
+        let mut lost = 0.0;
+        for (propellant, kg) in &mut self.depot.stores {
+            if propellant.is_cryogenic() {
+                let gone = *kg * rate;
+                *kg -= gone;
+                lost += gone;
+            }
+        }
+        self.last_boiloff_kg = lost;
+        lost
+    }
+
+    /// Whether the boiloff rate holds from day to day: the stores are
+    /// either fully cooled or not cooled at all. In between, cooling
+    /// catches up as the stores shrink.
+    pub fn boiloff_steady(&self, cfg: &IsruConfig) -> bool {
+        let budget = self.power_budget(cfg);
+        budget.boiloff_per_day <= 0.0 || budget.cooling_fraction <= 0.0
+    }
+
+    /// `days` days of boiloff at once, at a rate [`Self::boiloff_steady`]
+    /// says holds. Returns the kg lost.
+    pub fn boil_off_days(&mut self, days: u32, cfg: &IsruConfig) -> f64 {
+        let rate = self.power_budget(cfg).boiloff_per_day;
+        self.last_boiloff_kg = 0.0;
+        if rate <= 0.0 || days == 0 {
+            return 0.0;
+        }
+        let mut lost = 0.0;
+        for (propellant, kg) in &mut self.depot.stores {
+            if propellant.is_cryogenic() {
+                let before_last = *kg * (1.0 - rate).powi(days as i32 - 1);
+                let gone_last = before_last * rate;
+                lost += *kg - (before_last - gone_last);
+                *kg = before_last - gone_last;
+                self.last_boiloff_kg += gone_last;
+            }
+        }
+        lost
+    }
+
+    /// Combined daily output of the plants here, in kg.
+    pub fn isru_output_kg_per_day(&self) -> f64 {
+        self.isru_plants.iter().map(|p| p.design.output_kg_per_day).sum()
+    }
+
+    /// Health of the most worn plant here, if there are any.
+    pub fn lowest_plant_health(&self) -> Option<f64> {
+        self.isru_plants.iter().map(|p| p.health).min_by(f64::total_cmp)
+    }
+}
+
+fn sun_distance_au(location: &str) -> f64 {
+    crate::location::DELTA_V_MAP.location(location).map_or(1.0, |l| l.sun_distance_au())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plant_fills_depot_in_site_mix_and_stops_at_capacity() {
+        let cfg = IsruConfig {
+            depot_capacity_kg: 1_000.0,
+            depot_power_kw: 200.0,
+            ..IsruConfig::default()
+        };
+        let mut infra = LocationInfrastructure::new("lunar_surface", &cfg);
+        infra.isru_plants.push(IsruPlant {
+            design: IsruPlantDesign::sized("Test Plant", 300.0, &cfg),
+            deployed_on: GameDate::new(2001, 1, 1),
+            produced_kg: 0.0,
+            health: crate::servicing::FULL_HEALTH,
+        });
+
+        let day = infra.produce_day(&cfg);
+        assert!((day - 300.0).abs() < 1e-9);
+        // Lunar water electrolysis: 8 parts oxygen to 1 part hydrogen.
+        let ratio = infra.depot.stored(Propellant::LOX) / infra.depot.stored(Propellant::LH2);
+        assert!((ratio - 8.0).abs() < 0.01, "ratio {ratio}");
+
+        for _ in 0..5 {
+            infra.produce_day(&cfg);
+        }
+        assert!((infra.depot.total_kg() - 1_000.0).abs() < 1e-9);
+        assert_eq!(infra.produce_day(&cfg), 0.0, "a full depot stops production");
+
+        // Not an ISRU site: plants there make nothing.
+        let mut orbit = LocationInfrastructure::new("leo", &cfg);
+        orbit.isru_plants = infra.isru_plants.clone();
+        assert_eq!(orbit.produce_day(&cfg), 0.0);
+    }
+
+    #[test]
+    fn test_power_shortfall_throttles_plants_and_lets_stores_boil() {
+        let cfg = IsruConfig::default();
+        let design = DepotDesign::stock(&cfg, 1.0, Vec::new());
+        // One stock plant and a full depot fit in the budget.
+        let fits = design.budget(1.0, 200_000.0, 40.0, &cfg);
+        assert!((fits.demand_kw() - 44.0).abs() < 1e-9);
+        assert_eq!(fits.isru_fraction, 1.0);
+        assert_eq!(fits.boiloff_per_day, 0.0);
+        // Two plants share what's left after the coolers.
+        let short = design.budget(1.0, 200_000.0, 80.0, &cfg);
+        assert!((short.isru_fraction - 0.7).abs() < 1e-9);
+        assert_eq!(short.cooling_fraction, 1.0);
+        // At 2 AU the same arrays give a quarter of the power.
+        assert!((design.solar_output_kw(2.0) - 15.0).abs() < 1e-9);
+        // Insulation blocks its share of the heat leak.
+        let insulated = DepotDesign { insulation: 0.5, ..design.clone() };
+        assert!((insulated.budget(1.0, 200_000.0, 0.0, &cfg).heat_load_kw - 0.1).abs() < 1e-9);
+
+        // Without cryocoolers, stored cryogenics boil off at the base rate.
+        let mut infra = LocationInfrastructure::new("lunar_surface", &cfg);
+        infra.depot.design.cryocooler_kw = 0.0;
+        infra.depot.design.plumbed.push(Propellant::UDMH);
+        infra.depot.add(Propellant::LOX, 10_000.0);
+        infra.depot.add(Propellant::UDMH, 1_000.0);
+        let lost = infra.boil_off_day(&cfg);
+        assert!((lost - 10_000.0 * cfg.uncooled_boiloff_per_day).abs() < 1e-9);
+        assert_eq!(infra.depot.stored(Propellant::UDMH), 1_000.0, "storables don't boil");
+        assert_eq!(infra.depot.add(Propellant::RP1, 1_000.0), 0.0, "not plumbed for kerosene");
+    }
+}
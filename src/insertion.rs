@@ -0,0 +1,53 @@
+//! Orbit-insertion accuracy. No launch puts its payload exactly where
+//! it was aimed: each flight carries an insertion error, drawn at
+//! launch from how mature its flight software is and how many hidden
+//! avionics or guidance flaws ride along. Contracts that specify a
+//! tolerance either get trimmed into it by a correction burn on what the
+//! upper stages have left, or pay less for the miss.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::balance_config::InsertionConfig;
+
+/// Typical insertion error (m/s, one sigma) for a flight whose software
+/// was at `software_maturity` with `hidden_flaws` approach flaws aboard
+/// (see [`crate::docking::hidden_approach_flaws`]).
+pub fn dispersion_m_s(software_maturity: f64, hidden_flaws: usize, cfg: &InsertionConfig) -> f64 {
+    cfg.base_dispersion_m_s * (1.0 - cfg.maturity_mitigation * software_maturity.clamp(0.0, 1.0))
+        + cfg.hidden_flaw_dispersion_m_s * hidden_flaws as f64
+}
+
+/// Draw a flight's insertion error (m/s) for a dispersion of `sigma`:
+/// the size of a normally distributed miss.
+pub fn draw_error(sigma: f64, rng: &mut StdRng) -> f64 {
+    // Box-Muller; 1 - u keeps the log away from zero.
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+    (z * sigma).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_mature_software_and_clean_avionics_insert_more_accurately() {
+        let cfg = InsertionConfig::default();
+        let raw = dispersion_m_s(0.0, 0, &cfg);
+        let mature = dispersion_m_s(1.0, 0, &cfg);
+        assert!(mature < raw);
+        assert!(dispersion_m_s(1.0, 2, &cfg) > mature);
+
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(draw_error(0.0, &mut rng), 0.0);
+        let draws: Vec<f64> = (0..2_000).map(|_| draw_error(raw, &mut rng)).collect();
+        assert!(draws.iter().all(|&e| e >= 0.0));
+        // A half-normal's mean is sigma * sqrt(2 / pi).
+        let mean = draws.iter().sum::<f64>() / draws.len() as f64;
+        let expected = raw * (2.0 / std::f64::consts::PI).sqrt();
+        assert!((mean - expected).abs() < expected * 0.1, "mean {mean} vs {expected}");
+    }
+}
@@ -0,0 +1,128 @@
+//! Pre-launch integration campaigns.
+//!
+//! Before a specific mission, engineers can spend a few days checking
+//! one built rocket against that mission's plan — payload, destination,
+//! and the burns it takes to get there. A finished campaign makes that
+//! one flight less likely to hit a flaw. It covers only that vehicle on
+//! that plan and is spent at launch, whether or not the plan held.
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::IntegrationConfig;
+use crate::manufacturing::InventoryItemId;
+
+/// The mission a campaign validates against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissionPlan {
+    pub destination: String,
+    pub payload_kg: f64,
+    /// Legs from the pad to the destination, plus the plane change if
+    /// the pad needs one.
+    pub burn_count: u32,
+    /// Delta-v the pad's site spends on plane changes over the map's
+    /// figures (see `LaunchSite::plane_change_dv`).
+    #[serde(default)]
+    pub plane_change_dv: f64,
+}
+
+impl MissionPlan {
+    /// Plan for flying `payload_kg` from the pad to `destination`, with
+    /// `plane_change_dv` to get into its plane. None if the destination
+    /// can't be reached.
+    pub fn new(destination: &str, payload_kg: f64, plane_change_dv: f64) -> Option<Self> {
+        let (path, _) = crate::location::DELTA_V_MAP
+            .shortest_path("earth_surface", destination, payload_kg)?;
+        let legs = path.len().saturating_sub(1) as u32;
+        Some(MissionPlan {
+            destination: destination.to_string(),
+            payload_kg,
+            burn_count: legs + u32::from(plane_change_dv > 0.0),
+            plane_change_dv,
+        })
+    }
+
+    /// True if a flight to `destination` with `payload_kg` aboard is
+    /// the mission this plan validated: same destination, no heavier.
+    pub fn covers(&self, destination: &str, payload_kg: f64) -> bool {
+        self.destination == destination && payload_kg <= self.payload_kg
+    }
+}
+
+/// Per-flight preparation state: an integration campaign on one
+/// rocket in inventory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightPreparation {
+    pub rocket_item_id: InventoryItemId,
+    pub plan: MissionPlan,
+    pub work_completed: f64,
+    pub work_required: f64,
+    /// Engineering teams on the campaign; released when it finishes.
+    pub teams_assigned: u32,
+}
+
+impl FlightPreparation {
+    pub fn new(rocket_item_id: InventoryItemId, plan: MissionPlan, cfg: &IntegrationConfig) -> Self {
+        let work_required = cfg.base_work + cfg.work_per_burn * plan.burn_count as f64;
+        FlightPreparation {
+            rocket_item_id,
+            plan,
+            work_completed: 0.0,
+            work_required,
+            teams_assigned: 0,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.work_completed >= self.work_required
+    }
+
+    /// One day of campaign work, done at `efficiency` of a full day's.
+    /// Returns true on the day it finishes.
+    pub fn apply_daily_work(&mut self, efficiency: f64) -> bool {
+        if self.teams_assigned == 0 || self.is_complete() {
+            return false;
+        }
+        self.work_completed += crate::team::effective_work_rate(self.teams_assigned) * efficiency;
+        if self.is_complete() {
+            self.teams_assigned = 0;
+            return true;
+        }
+        false
+    }
+
+    /// Multiplier on vehicle flaw activation chances for a flight to
+    /// `destination` with `payload_kg`: reduced only when the finished
+    /// campaign covers that flight.
+    pub fn activation_factor(&self, destination: &str, payload_kg: f64, cfg: &IntegrationConfig) -> f64 {
+        if self.is_complete() && self.plan.covers(destination, payload_kg) {
+            1.0 - cfg.risk_reduction
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_campaign_covers_only_its_mission() {
+        let cfg = IntegrationConfig::default();
+        let plan = MissionPlan::new("leo", 500.0, 0.0).unwrap();
+        assert_eq!(plan.burn_count, 1);
+        assert_eq!(MissionPlan::new("leo", 500.0, 300.0).unwrap().burn_count, 2, "the plane change is a burn");
+        let mut prep = FlightPreparation::new(InventoryItemId(1), plan, &cfg);
+        assert_eq!(prep.work_required, cfg.base_work + cfg.work_per_burn);
+
+        // Unfinished campaigns give nothing.
+        assert_eq!(prep.activation_factor("leo", 500.0, &cfg), 1.0);
+        prep.teams_assigned = 1;
+        while !prep.apply_daily_work(1.0) {}
+        assert_eq!(prep.teams_assigned, 0, "teams released on completion");
+
+        assert_eq!(prep.activation_factor("leo", 400.0, &cfg), 1.0 - cfg.risk_reduction);
+        assert_eq!(prep.activation_factor("leo", 600.0, &cfg), 1.0);
+        assert_eq!(prep.activation_factor("gto", 400.0, &cfg), 1.0);
+    }
+}
@@ -6,7 +6,7 @@ use crate::calendar::GameDate;
 use crate::contract::ContractId;
 use crate::engine::EngineId;
 use crate::engine_project::{EngineProject, EngineSource};
-use crate::flaw::FlawConsequence;
+use crate::flaw::{FlawConsequence, Subsystem};
 use crate::reactor::ReactorId;
 use crate::rocket::RocketDesign;
 use crate::third_party::ContractedEngine;
@@ -17,6 +17,8 @@ pub struct FlawActivation {
     pub flaw_description: String,
     pub consequence: FlawConsequence,
     pub engine_name: String,
+    #[serde(default)]
+    pub subsystem: Subsystem,
 }
 
 /// Record of a launch attempt.
@@ -29,6 +31,12 @@ pub struct LaunchRecord {
     pub payload_kg: f64,
     pub outcome: LaunchOutcome,
     pub flaws_activated: Vec<FlawActivation>,
+    /// The flight's in-transit telemetry log.
+    #[serde(default)]
+    pub transit_anomalies: Vec<crate::flight::TransitAnomaly>,
+    /// Where the spent stages ended up.
+    #[serde(default)]
+    pub stage_disposals: Vec<crate::disposal::StageDisposal>,
 }
 
 /// Outcome of a launch.
@@ -61,12 +69,15 @@ pub struct LaunchSimResult {
 /// The simulation:
 /// 1. Rolls activation for each flaw (engine projects + rocket project + contracted engines)
 /// 2. Applies consequences to a cloned design
-/// 3. Computes delta-v with degraded performance
-/// 4. Compares to required delta-v for the destination
+/// 3. Computes delta-v with degraded performance and a random dispersion
+/// 4. Compares to required delta-v for the destination, plus the
+///    `plane_change_dv` the launch site spends getting into its plane
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_launch(
     design: &RocketDesign,
     destination: &str,
     payload_kg: f64,
+    plane_change_dv: f64,
     engine_projects: &[EngineProject],
     rocket_flaws: &[crate::flaw::Flaw],
     contracted_engines: &[ContractedEngine],
@@ -81,7 +92,7 @@ pub fn simulate_launch(
     // planner (so e.g. an ion upper stage uses spiral dv on transfers).
     let required_dv = crate::location::DELTA_V_MAP
         .shortest_path_for_rocket("earth_surface", destination, design, payload_kg)
-        .map(|(_, dv)| dv)
+        .map(|(_, dv)| dv + plane_change_dv)
         .unwrap_or(f64::INFINITY);
 
     // Only roll flaws for the first stage group (group 0) at launch.
@@ -111,6 +122,7 @@ pub fn simulate_launch(
                             flaw_description: flaw.description.clone(),
                             consequence: flaw.consequence.clone(),
                             engine_name: stage.engine.name.clone(),
+                            subsystem: flaw.subsystem,
                         });
                         discovered_indices.push(fi);
                         apply_consequence_to_stage(
@@ -138,6 +150,7 @@ pub fn simulate_launch(
                             flaw_description: flaw.description.clone(),
                             consequence: flaw.consequence.clone(),
                             engine_name: stage.engine.name.clone(),
+                            subsystem: flaw.subsystem,
                         });
                         discovered_indices.push(fi);
                         apply_consequence_to_stage(
@@ -176,6 +189,7 @@ pub fn simulate_launch(
                     flaw_description: flaw.description.clone(),
                     consequence: flaw.consequence.clone(),
                     engine_name,
+                    subsystem: flaw.subsystem,
                 });
                 // Pick a random stage within the group
                 let si = if !degraded.stage_groups[gi].is_empty() {
@@ -187,6 +201,27 @@ pub fn simulate_launch(
         }
     }
 
+    // Structural loads: a stack flown past its max-Q or burnout-g rating
+    // may break up on ascent regardless of flaws. Only roll when there's
+    // a risk, so in-spec designs draw the same random stream as before.
+    let loads = design.structural_loads(payload_kg, "earth_surface");
+    let structural_risk = loads.failure_risk();
+    let mut structural_failure: Option<String> = None;
+    if structural_risk > 0.0 && rng.gen::<f64>() < structural_risk {
+        let reason = format!("Structural failure: {}", loads.violations().join("; "));
+        let engine_name = design.stage_groups.first()
+            .and_then(|g| g.first())
+            .map(|s| s.engine.name.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        activations.push(FlawActivation {
+            flaw_description: reason.clone(),
+            consequence: FlawConsequence::StageLoss,
+            engine_name,
+            subsystem: Subsystem::TankStructure,
+        });
+        structural_failure = Some(reason);
+    }
+
     // Check overexpansion destruction risk for first stage group
     // (burning at sea level, 101325 Pa)
     let ambient = 101_325.0_f64;
@@ -214,6 +249,7 @@ pub fn simulate_launch(
                             ),
                             consequence: FlawConsequence::StageLoss,
                             engine_name,
+                            subsystem: Subsystem::Combustion,
                         });
                         stage.engine_count = 0;
                         stage.engine.thrust_n = 0.0;
@@ -231,6 +267,7 @@ pub fn simulate_launch(
                             ),
                             consequence: FlawConsequence::EngineLoss,
                             engine_name,
+                            subsystem: Subsystem::Combustion,
                         });
                         stage.engine_count = surviving;
                     }
@@ -250,11 +287,22 @@ pub fn simulate_launch(
         }
     }
 
-    // Compute degraded delta-v
-    let degraded_dv = degraded.total_delta_v(payload_kg);
+    // Compute degraded delta-v, then apply this flight's performance
+    // dispersion. Missions are planned against usable (reserve-held)
+    // delta-v, so a cold flight only falls short once it eats through
+    // the design's performance reserve — even a zero one. Designs from
+    // saves that predate reserves fly undispersed, as they always have.
+    let dispersion = if design.is_dispersed() {
+        crate::flaw::gaussian_sample(0.0, crate::rocket::PERFORMANCE_DISPERSION_SIGMA, rng)
+    } else {
+        0.0
+    };
+    let degraded_dv = degraded.total_delta_v(payload_kg) * (1.0 + dispersion);
 
     // Determine outcome
-    let outcome = if degraded_dv >= required_dv {
+    let outcome = if let Some(reason) = structural_failure {
+        LaunchOutcome::Failure { reason }
+    } else if degraded_dv >= required_dv {
         LaunchOutcome::Success
     } else if degraded_dv >= required_dv * 0.95 {
         let shortfall = ((1.0 - degraded_dv / required_dv) * 100.0).round();
@@ -263,10 +311,12 @@ pub fn simulate_launch(
         }
     } else {
         // Check if it was a stage loss
-        let stage_lost = activations.iter().any(|a| matches!(a.consequence, FlawConsequence::StageLoss));
-        if stage_lost {
+        let stage_loss = activations.iter()
+            .find(|a| matches!(a.consequence, FlawConsequence::StageLoss));
+        if let Some(loss) = stage_loss {
             LaunchOutcome::Failure {
-                reason: "Stage loss during flight".to_string(),
+                reason: format!("Stage loss at {} ({})",
+                    loss.subsystem.launch_event(), loss.subsystem),
             }
         } else {
             let shortfall = ((1.0 - degraded_dv / required_dv) * 100.0).round();
@@ -287,6 +337,70 @@ pub fn simulate_launch(
     }
 }
 
+/// Hidden flaws that stayed quiet on a successful launch but left a
+/// telemetry trace, as (flaw index, hint).
+#[derive(Debug, Default)]
+pub struct NearMisses {
+    pub engine: Vec<(EngineId, Vec<(usize, String)>)>,
+    pub rocket: Vec<(usize, String)>,
+}
+
+/// Roll near-misses for the first stage group's un-triggered, hidden
+/// per-flight flaws after a successful launch. Each flaw shows up with
+/// `near_miss_ratio` times its activation chance. Draws nothing on a
+/// failed or partial launch.
+pub fn roll_near_misses(
+    design: &RocketDesign,
+    engine_projects: &[EngineProject],
+    rocket_flaws: &[crate::flaw::Flaw],
+    sim: &LaunchSimResult,
+    near_miss_ratio: f64,
+    rng: &mut StdRng,
+) -> NearMisses {
+    let mut near_misses = NearMisses::default();
+    if !matches!(sim.outcome, LaunchOutcome::Success) {
+        return near_misses;
+    }
+    let candidate = |flaw: &crate::flaw::Flaw| {
+        !flaw.discovered && flaw.anomaly.is_none() && flaw.trigger == crate::flaw::FlawTrigger::PerFlight
+    };
+    let mut seen: Vec<EngineId> = Vec::new();
+    for stage in design.stage_groups.first().into_iter().flatten() {
+        if seen.contains(&stage.engine.id) {
+            continue;
+        }
+        seen.push(stage.engine.id);
+        let Some(ep) = engine_projects.iter().find(|ep| ep.design.id == stage.engine.id) else {
+            continue;
+        };
+        let fired: &[usize] = sim.engine_flaw_discoveries.iter()
+            .find(|(id, _)| *id == stage.engine.id)
+            .map_or(&[], |(_, indices)| indices.as_slice());
+        let mut hints = Vec::new();
+        for (fi, flaw) in ep.flaws.iter().enumerate() {
+            if !candidate(flaw) || fired.contains(&fi) {
+                continue;
+            }
+            let effective_p = 1.0 - (1.0 - flaw.activation_chance).powi(stage.engine_count as i32);
+            if rng.gen::<f64>() < (effective_p * near_miss_ratio).min(1.0) {
+                hints.push((fi, flaw.anomaly_hint(1)));
+            }
+        }
+        if !hints.is_empty() {
+            near_misses.engine.push((stage.engine.id, hints));
+        }
+    }
+    for (fi, flaw) in rocket_flaws.iter().enumerate() {
+        if !candidate(flaw) || sim.rocket_flaw_discoveries.contains(&fi) {
+            continue;
+        }
+        if rng.gen::<f64>() < (flaw.activation_chance * near_miss_ratio).min(1.0) {
+            near_misses.rocket.push((fi, flaw.anomaly_hint(1)));
+        }
+    }
+    near_misses
+}
+
 /// Apply a flaw consequence to a specific stage in a cloned design.
 /// PerformanceDegradation and EngineLoss are scoped to the specific stage.
 /// StageLoss removes the entire stage from its group.
@@ -420,6 +534,11 @@ mod tests {
             structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         }
     }
 
@@ -431,6 +550,7 @@ mod tests {
                 vec![make_stage(1)],
                 vec![make_stage(2)],
             ],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -464,7 +584,7 @@ mod tests {
         let mut rng = StdRng::seed_from_u64(42);
 
         let result = simulate_launch(
-            &design, "leo", 0.0,
+            &design, "leo", 0.0, 0.0,
             &[ep1, ep2], &rp.flaws, &[], &mut rng,
         );
 
@@ -472,6 +592,36 @@ mod tests {
         assert!(result.flaws_activated.is_empty());
     }
 
+    #[test]
+    fn test_near_miss_hints_hidden_flaw_on_success_only() {
+        let design = make_design();
+        let flaw = Flaw {
+            id: FlawId(1),
+            description: "Turbopump bearing wear".into(),
+            consequence: FlawConsequence::EngineLoss,
+            activation_chance: 0.5,
+            discovery_probability: 0.1,
+            discovered: false, trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Turbopump,
+            anomaly: None,
+        };
+        let quiet = [make_engine_project(1, vec![]), make_engine_project(2, vec![])];
+        let flawed = [make_engine_project(1, vec![flaw]), make_engine_project(2, vec![])];
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut sim = simulate_launch(&design, "leo", 0.0, 0.0, &quiet, &[], &[], &mut rng);
+        assert!(matches!(sim.outcome, LaunchOutcome::Success));
+
+        // Ratio 2 on a 50% flaw makes the near-miss certain.
+        let near = roll_near_misses(&design, &flawed, &[], &sim, 2.0, &mut rng);
+        assert_eq!(near.engine.len(), 1);
+        assert_eq!(near.engine[0].1, vec![(0, "Vibration spike in stage 1, cause unknown".to_string())]);
+        assert!(near.rocket.is_empty());
+
+        sim.outcome = LaunchOutcome::PartialFailure { reason: "short".into() };
+        let near = roll_near_misses(&design, &flawed, &[], &sim, 2.0, &mut rng);
+        assert!(near.engine.is_empty());
+    }
+
     #[test]
     fn test_launch_with_guaranteed_flaw() {
         let design = make_design();
@@ -482,6 +632,8 @@ mod tests {
             activation_chance: 1.0, // guaranteed activation
             discovery_probability: 0.5,
             discovered: false, trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         };
         let ep1 = make_engine_project(1, vec![flaw]);
         let ep2 = make_engine_project(2, vec![]);
@@ -489,7 +641,7 @@ mod tests {
         let mut rng = StdRng::seed_from_u64(42);
 
         let result = simulate_launch(
-            &design, "leo", 0.0,
+            &design, "leo", 0.0, 0.0,
             &[ep1, ep2], &rp.flaws, &[], &mut rng,
         );
 
@@ -509,6 +661,8 @@ mod tests {
             activation_chance: 1.0,
             discovery_probability: 0.5,
             discovered: false, trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         };
         let ep1 = make_engine_project(1, vec![flaw]);
         let ep2 = make_engine_project(2, vec![]);
@@ -517,7 +671,7 @@ mod tests {
 
         // With a heavy payload, losing a stage should cause failure
         let result = simulate_launch(
-            &design, "gto", 5000.0,
+            &design, "gto", 5000.0, 0.0,
             &[ep1, ep2], &rp.flaws, &[], &mut rng,
         );
 
@@ -537,12 +691,14 @@ mod tests {
             activation_chance: 1.0,
             discovery_probability: 0.5,
             discovered: false, trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         };
         let rp = make_rocket_project(design.clone(), vec![flaw]);
         let mut rng = StdRng::seed_from_u64(42);
 
         let result = simulate_launch(
-            &design, "leo", 0.0,
+            &design, "leo", 0.0, 0.0,
             &[ep1, ep2], &rp.flaws, &[], &mut rng,
         );
 
@@ -550,6 +706,63 @@ mod tests {
         assert_eq!(result.rocket_flaw_discoveries.len(), 1);
     }
 
+    #[test]
+    fn test_overloaded_solid_booster_breaks_up() {
+        // A hot solid motor with a light casing pulls far past both the
+        // burnout-g and max-Q limits — the stack fails structurally no
+        // matter how much delta-v it has.
+        let mut stage = make_stage(1);
+        stage.engine.thrust_n = 5_000_000.0;
+        stage.engine.propellant_mix = vec![
+            PropellantFraction { propellant: Propellant::SolidMix, mass_fraction: 1.0 },
+        ];
+        let design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Firecracker".into(),
+            stage_groups: vec![vec![stage], vec![make_stage(2)]],
+            performance_reserve: Some(0.0),
+        };
+        assert!(design.structural_loads(0.0, "earth_surface").failure_risk() >= 1.0);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = simulate_launch(
+            &design, "leo", 0.0, 0.0, &[], &[], &[], &mut rng,
+        );
+
+        match result.outcome {
+            LaunchOutcome::Failure { reason } =>
+                assert!(reason.starts_with("Structural failure"), "got {reason}"),
+            other => panic!("expected structural failure, got {other:?}"),
+        }
+        assert!(matches!(result.flaws_activated[0].consequence, FlawConsequence::StageLoss));
+    }
+
+    #[test]
+    fn test_performance_reserve_absorbs_dispersion() {
+        // Flown at the exact payload the design can plan for, a vehicle
+        // with a 0% reserve still draws dispersion and comes up short on
+        // roughly every cold flight; a 5% reserve (five sigma of
+        // dispersion) always makes it. Only a legacy design with no
+        // reserve set flies undispersed, so every seed flies alike.
+        let count_short = |reserve: Option<f64>| {
+            let mut design = make_design();
+            design.performance_reserve = reserve;
+            let payload = crate::rocket_project::max_payload_to(&design, "earth_surface", "leo");
+            assert!(payload > 0.0);
+            (0..200u64)
+                .filter(|&seed| {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let result = simulate_launch(&design, "leo", payload, 0.0, &[], &[], &[], &mut rng);
+                    !matches!(result.outcome, LaunchOutcome::Success)
+                })
+                .count()
+        };
+        let short_without = count_short(Some(0.0));
+        assert!((50..150).contains(&short_without), "got {short_without}/200");
+        assert_eq!(count_short(Some(0.05)), 0);
+        assert!(matches!(count_short(None), 0 | 200));
+    }
+
     fn reactor_stage(engine_id: u64, reactor_id: u64) -> Stage {
         use crate::power::PowerSource;
         use crate::reactor::{EnrichmentLevel, ReactorDesign, ReactorId};
@@ -567,6 +780,7 @@ mod tests {
             id: RocketDesignId(1),
             name: "R".into(),
             stage_groups: vec![vec![reactor_stage(1, 50)]],
+            performance_reserve: Some(0.0),
         };
         let steady_before = match &design.stage_groups[0][0].power_sources[0].kind {
             PowerSourceKind::Reactor { design } => design.steady_w,
@@ -612,6 +826,8 @@ mod tests {
             activation_chance: 0.0,
             discovery_probability: 0.5,
             discovered: false, trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         };
         let ep1 = make_engine_project(1, vec![flaw]);
         let ep2 = make_engine_project(2, vec![]);
@@ -619,7 +835,7 @@ mod tests {
         let mut rng = StdRng::seed_from_u64(42);
 
         let result = simulate_launch(
-            &design, "leo", 0.0,
+            &design, "leo", 0.0, 0.0,
             &[ep1, ep2], &rp.flaws, &[], &mut rng,
         );
 
@@ -0,0 +1,494 @@
+//! The player's launch site and its pad calendar.
+//!
+//! One pad, shared between the player's own launches and leases to
+//! outside operators. A leased day belongs to the lessee: the player
+//! can't launch on it, but collects the daily rate. The site also
+//! remembers its recent launches for the regulator's cadence limit.
+//!
+//! Where the pad sits matters too. A site can't launch straight into an
+//! orbit inclined less than its latitude, and its range only clears
+//! some launch azimuths, so orbits outside that band cost a plane
+//! change (see [`SiteLocation::plane_change_dv`]).
+//!
+//! How the pad integrates its vehicles sets its pace and its limits. A
+//! vertical assembly building stacks rockets of any size but ties the
+//! pad up longer between launches; a horizontal hangar turns small
+//! vehicles around fast but can't lift the biggest. Converting from one
+//! to the other closes the pad for a while.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::LaunchSitesConfig;
+use crate::calendar::GameDate;
+
+/// A place the company can put its pad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SiteLocation {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub latitude_deg: f64,
+    /// Steepest inclination the range's safe launch azimuths reach
+    /// directly; steeper orbits need a dogleg.
+    pub max_inclination_deg: f64,
+}
+
+/// Every site the pad can be built at.
+pub const SITE_LOCATIONS: &[SiteLocation] = &[
+    SiteLocation { id: "cape", name: "Cape Canaveral", latitude_deg: 28.5, max_inclination_deg: 57.0 },
+    SiteLocation { id: "boca_chica", name: "Boca Chica", latitude_deg: 25.9, max_inclination_deg: 50.0 },
+    SiteLocation { id: "vandenberg", name: "Vandenberg", latitude_deg: 34.7, max_inclination_deg: 104.0 },
+    SiteLocation { id: "kodiak", name: "Kodiak", latitude_deg: 57.4, max_inclination_deg: 110.0 },
+];
+
+/// The site the delta-v map's launch figures assume, and where every
+/// company starts.
+pub const REFERENCE_SITE: &str = "cape";
+
+pub fn site_location(id: &str) -> Option<&'static SiteLocation> {
+    SITE_LOCATIONS.iter().find(|s| s.id == id)
+}
+
+impl SiteLocation {
+    /// Delta-v this site spends changing plane to reach `destination`:
+    /// a dogleg for orbits steeper than its range allows, and for
+    /// orbits inclined below its latitude either a dogleg or an apogee
+    /// plane change.
+    pub fn plane_change_dv(&self, destination: &str, cfg: &LaunchSitesConfig) -> f64 {
+        let Some(plane) = crate::location::orbit_plane(destination) else {
+            return 0.0;
+        };
+        let below = (self.latitude_deg - plane.inclination_deg).max(0.0);
+        let beyond = (plane.inclination_deg - self.max_inclination_deg).max(0.0);
+        let below_rate = if plane.change_at_apogee {
+            cfg.apogee_plane_change_dv_per_deg
+        } else {
+            cfg.dogleg_dv_per_deg
+        };
+        below * below_rate + beyond * cfg.dogleg_dv_per_deg
+    }
+}
+
+fn default_location() -> String {
+    REFERENCE_SITE.to_string()
+}
+
+/// How vehicles are put together and brought to the pad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum IntegrationMode {
+    /// Stacked upright in an assembly building and rolled out standing.
+    #[default]
+    Vertical,
+    /// Mated on its side in a hangar, rolled out and raised on the pad.
+    Horizontal,
+}
+
+impl IntegrationMode {
+    pub fn other(self) -> IntegrationMode {
+        match self {
+            IntegrationMode::Vertical => IntegrationMode::Horizontal,
+            IntegrationMode::Horizontal => IntegrationMode::Vertical,
+        }
+    }
+
+    /// Heaviest vehicle at liftoff the facility can handle.
+    pub fn max_liftoff_kg(self, cfg: &LaunchSitesConfig) -> f64 {
+        match self {
+            IntegrationMode::Vertical => cfg.vertical_max_liftoff_kg,
+            IntegrationMode::Horizontal => cfg.horizontal_max_liftoff_kg,
+        }
+    }
+
+    /// Days the pad is tied up bringing up and readying a vehicle of
+    /// `stages` stages.
+    pub fn pad_flow_days(self, stages: usize, cfg: &LaunchSitesConfig) -> u32 {
+        let (base, per_stage) = match self {
+            IntegrationMode::Vertical => (cfg.vertical_pad_days_base, cfg.vertical_pad_days_per_stage),
+            IntegrationMode::Horizontal => (cfg.horizontal_pad_days_base, cfg.horizontal_pad_days_per_stage),
+        };
+        base + per_stage * stages as u32
+    }
+
+    /// Cost of converting the pad to this mode.
+    pub fn conversion_cost(self, cfg: &LaunchSitesConfig) -> f64 {
+        match self {
+            IntegrationMode::Vertical => cfg.vertical_conversion_cost,
+            IntegrationMode::Horizontal => cfg.horizontal_conversion_cost,
+        }
+    }
+}
+
+impl fmt::Display for IntegrationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrationMode::Vertical => write!(f, "vertical"),
+            IntegrationMode::Horizontal => write!(f, "horizontal"),
+        }
+    }
+}
+
+/// A conversion of the pad's integration facility under way.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PadConversion {
+    pub to: IntegrationMode,
+    pub ready_on: GameDate,
+}
+
+/// Why the pad couldn't be converted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PadConversionError {
+    /// A conversion is already under way.
+    Converting { ready_on: GameDate },
+    /// Signed leases need the pad as it is until they run out.
+    LeasesBooked,
+    CantAfford { cost: f64 },
+}
+
+impl fmt::Display for PadConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PadConversionError::Converting { ready_on } => write!(f, "Pad conversion already under way until {}", ready_on),
+            PadConversionError::LeasesBooked => write!(f, "Signed pad leases hold the pad as it is"),
+            PadConversionError::CantAfford { cost } => {
+                write!(f, "Converting the pad costs {}", crate::resources::format_money(*cost))
+            }
+        }
+    }
+}
+
+/// A block of pad days rented to another operator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PadLease {
+    pub lessee: String,
+    /// First leased day.
+    pub start: GameDate,
+    /// Number of consecutive leased days (at least 1).
+    pub days: u32,
+    /// Income per leased day.
+    pub daily_rate: f64,
+}
+
+impl PadLease {
+    /// First day after the lease ends.
+    pub fn end(&self) -> GameDate {
+        self.start.add_days(self.days)
+    }
+
+    /// True if `date` falls inside the lease.
+    pub fn covers(&self, date: GameDate) -> bool {
+        date >= self.start && date < self.end()
+    }
+
+    /// True if the two leases share any day.
+    pub fn overlaps(&self, other: &PadLease) -> bool {
+        self.start < other.end() && other.start < self.end()
+    }
+
+    /// Income over the whole lease.
+    pub fn total_value(&self) -> f64 {
+        self.daily_rate * self.days as f64
+    }
+}
+
+/// Why a lease couldn't be signed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PadLeaseError {
+    /// The offer index doesn't exist (already taken or expired).
+    OfferMissing,
+    /// The lease would start before today.
+    StartPassed,
+    /// The requested days overlap an existing lease.
+    Conflict { lessee: String, start: GameDate },
+    /// The pad is being rebuilt when the lease would start.
+    Rebuilding { ready_on: GameDate },
+}
+
+impl fmt::Display for PadLeaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PadLeaseError::OfferMissing => write!(f, "Lease offer no longer available"),
+            PadLeaseError::StartPassed => write!(f, "Lease start date has passed"),
+            PadLeaseError::Conflict { lessee, start } => {
+                write!(f, "Pad already leased to {} from {}", lessee, start)
+            }
+            PadLeaseError::Rebuilding { ready_on } => write!(f, "Pad being rebuilt until {}", ready_on),
+        }
+    }
+}
+
+/// Why the pad couldn't be moved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelocationError {
+    UnknownSite,
+    AlreadyThere,
+    /// Signed leases tie the pad where it is until they run out.
+    LeasesBooked,
+    /// The move costs more than the company has or the Facilities
+    /// budget allows.
+    CantAfford { cost: f64 },
+}
+
+impl fmt::Display for RelocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelocationError::UnknownSite => write!(f, "No such launch site"),
+            RelocationError::AlreadyThere => write!(f, "The pad is already there"),
+            RelocationError::LeasesBooked => write!(f, "Signed pad leases hold the pad where it is"),
+            RelocationError::CantAfford { cost } => {
+                write!(f, "Moving the pad costs {}", crate::resources::format_money(*cost))
+            }
+        }
+    }
+}
+
+/// The player's launch site: where it is, the pad calendar of signed
+/// leases and its recent launch dates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaunchSite {
+    /// The [`SiteLocation`] the pad is built at.
+    #[serde(default = "default_location")]
+    pub location: String,
+    /// Signed leases, sorted by start date. Finished leases are pruned
+    /// by `expire_leases`.
+    #[serde(default)]
+    pub leases: Vec<PadLease>,
+    /// Dates of the player's recent launches from this pad, oldest
+    /// first. Trimmed to the cadence window as launches are recorded.
+    #[serde(default)]
+    pub recent_launches: Vec<GameDate>,
+    /// A pad destroyed by a launch failure is closed until its rebuild
+    /// finishes on this day.
+    #[serde(default)]
+    pub rebuild_until: Option<GameDate>,
+    /// Whether the pad is insured against destruction (see
+    /// [`crate::pad_failure`]).
+    #[serde(default)]
+    pub insured: bool,
+    /// How the pad's facility integrates vehicles.
+    #[serde(default)]
+    pub integration: IntegrationMode,
+    /// A conversion to the other mode, closing the pad until it's done.
+    #[serde(default)]
+    pub conversion: Option<PadConversion>,
+    /// The pad is busy readying the next vehicle after a launch until
+    /// this day.
+    #[serde(default)]
+    pub pad_flow_until: Option<GameDate>,
+}
+
+impl Default for LaunchSite {
+    fn default() -> Self {
+        LaunchSite {
+            location: default_location(),
+            leases: Vec::new(),
+            recent_launches: Vec::new(),
+            rebuild_until: None,
+            insured: false,
+            integration: IntegrationMode::default(),
+            conversion: None,
+            pad_flow_until: None,
+        }
+    }
+}
+
+impl LaunchSite {
+    /// Where the pad is. Unknown ids fall back to the reference site.
+    pub fn site_location(&self) -> &'static SiteLocation {
+        site_location(&self.location)
+            .or_else(|| site_location(REFERENCE_SITE))
+            .expect("reference site is in the catalog")
+    }
+
+    /// Delta-v a launch to `destination` from this pad spends on plane
+    /// changes beyond what the reference site would, which the delta-v
+    /// map already allows for. Negative where this pad is better placed.
+    pub fn plane_change_dv(&self, destination: &str, cfg: &LaunchSitesConfig) -> f64 {
+        let reference = site_location(REFERENCE_SITE).expect("reference site is in the catalog");
+        self.site_location().plane_change_dv(destination, cfg) - reference.plane_change_dv(destination, cfg)
+    }
+
+    /// True while the pad is being rebuilt on `date`.
+    pub fn is_rebuilding(&self, date: GameDate) -> bool {
+        self.rebuild_until.is_some_and(|until| date < until)
+    }
+
+    /// The day the pad is free of a conversion or the flow of its last
+    /// launch, if it isn't on `date`.
+    pub fn pad_busy_until(&self, date: GameDate) -> Option<GameDate> {
+        self.conversion.map(|c| c.ready_on)
+            .into_iter()
+            .chain(self.pad_flow_until)
+            .filter(|&until| date < until)
+            .max()
+    }
+
+    /// Whether the facility can handle a vehicle of `liftoff_kg`.
+    pub fn fits(&self, liftoff_kg: f64, cfg: &LaunchSitesConfig) -> bool {
+        liftoff_kg <= self.integration.max_liftoff_kg(cfg)
+    }
+
+    /// The lease occupying the pad on `date`, if any.
+    pub fn lease_on(&self, date: GameDate) -> Option<&PadLease> {
+        self.leases.iter().find(|l| l.covers(date))
+    }
+
+    /// True if the pad is leased out on `date`.
+    pub fn is_leased(&self, date: GameDate) -> bool {
+        self.lease_on(date).is_some()
+    }
+
+    /// First day on or after `date` the pad is free for the player.
+    pub fn next_free_day(&self, date: GameDate) -> GameDate {
+        let mut d = date;
+        while let Some(lease) = self.lease_on(d) {
+            d = lease.end();
+        }
+        d
+    }
+
+    /// Add a lease to the calendar, rejecting overlaps and past starts.
+    pub fn add_lease(&mut self, lease: PadLease, today: GameDate) -> Result<(), PadLeaseError> {
+        if lease.start < today {
+            return Err(PadLeaseError::StartPassed);
+        }
+        if let Some(ready_on) = self.rebuild_until.filter(|&until| lease.start < until) {
+            return Err(PadLeaseError::Rebuilding { ready_on });
+        }
+        if let Some(existing) = self.leases.iter().find(|l| l.overlaps(&lease)) {
+            return Err(PadLeaseError::Conflict {
+                lessee: existing.lessee.clone(),
+                start: existing.start,
+            });
+        }
+        self.leases.push(lease);
+        self.leases.sort_by_key(|l| l.start);
+        Ok(())
+    }
+
+    /// Drop a lease that hasn't started yet. Running leases can't be
+    /// cancelled — the lessee's vehicle is already on the pad.
+    pub fn cancel_lease(&mut self, index: usize, today: GameDate) -> Option<PadLease> {
+        if self.leases.get(index).is_some_and(|l| l.start > today) {
+            Some(self.leases.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Record a launch from the pad, forgetting launches that have
+    /// fallen out of the `window_days` cadence window.
+    pub fn record_launch(&mut self, date: GameDate, window_days: u32) {
+        self.recent_launches.retain(|d| d.add_days(window_days) > date);
+        self.recent_launches.push(date);
+    }
+
+    /// First day the pad is allowed another launch under a cadence limit
+    /// of `max_launches` per `window_days`, or None if `date` is clear.
+    pub fn next_cadence_slot(&self, date: GameDate, window_days: u32, max_launches: u32) -> Option<GameDate> {
+        let in_window: Vec<GameDate> = self.recent_launches.iter()
+            .copied()
+            .filter(|d| d.add_days(window_days) > date)
+            .collect();
+        if (in_window.len() as u32) < max_launches {
+            return None;
+        }
+        // Wait for enough of the window's launches to age out.
+        let idx = in_window.len() - max_launches as usize;
+        Some(in_window[idx].add_days(window_days))
+    }
+
+    /// Remove leases that ended on or before `today`, returning them.
+    pub fn expire_leases(&mut self, today: GameDate) -> Vec<PadLease> {
+        let (done, active): (Vec<_>, Vec<_>) =
+            self.leases.drain(..).partition(|l| l.end() <= today);
+        self.leases = active;
+        done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lease(lessee: &str, start: GameDate, days: u32) -> PadLease {
+        PadLease { lessee: lessee.to_string(), start, days, daily_rate: 10_000.0 }
+    }
+
+    #[test]
+    fn test_pad_calendar_rejects_overlaps() {
+        let today = GameDate::new(2001, 1, 1);
+        let mut site = LaunchSite::default();
+        site.add_lease(lease("A", GameDate::new(2001, 1, 10), 5), today).unwrap();
+
+        // Jan 14 is the last day of A's lease.
+        let err = site.add_lease(lease("B", GameDate::new(2001, 1, 14), 3), today);
+        assert!(matches!(err, Err(PadLeaseError::Conflict { .. })));
+        // Back-to-back is fine.
+        site.add_lease(lease("B", GameDate::new(2001, 1, 15), 3), today).unwrap();
+        assert_eq!(site.add_lease(lease("C", GameDate::new(2000, 12, 1), 3), today),
+            Err(PadLeaseError::StartPassed));
+
+        assert!(!site.is_leased(GameDate::new(2001, 1, 9)));
+        assert!(site.is_leased(GameDate::new(2001, 1, 10)));
+        assert!(site.is_leased(GameDate::new(2001, 1, 17)));
+        assert_eq!(site.next_free_day(GameDate::new(2001, 1, 12)), GameDate::new(2001, 1, 18));
+
+        let done = site.expire_leases(GameDate::new(2001, 1, 15));
+        assert_eq!(done.len(), 1);
+        assert_eq!(done[0].lessee, "A");
+        assert_eq!(site.leases.len(), 1);
+    }
+
+    #[test]
+    fn test_plane_changes_favor_low_sites_for_geo_and_polar_ranges_for_sso() {
+        let cfg = LaunchSitesConfig::default();
+        let at = |id: &str| LaunchSite { location: id.to_string(), ..LaunchSite::default() };
+        let cape = at("cape");
+        assert_eq!(cape.plane_change_dv("geo", &cfg), 0.0, "the map assumes the Cape");
+        assert_eq!(cape.plane_change_dv("leo", &cfg), 0.0);
+        assert!(cape.site_location().plane_change_dv("sso", &cfg) > 0.0, "SSO needs a dogleg from the Cape");
+
+        let kodiak = at("kodiak");
+        assert!(kodiak.plane_change_dv("sso", &cfg) < 0.0);
+        assert!(kodiak.plane_change_dv("geo", &cfg) > 0.0);
+        assert!(kodiak.plane_change_dv("meo", &cfg) > 0.0, "MEO sits below Kodiak's latitude");
+        assert_eq!(kodiak.plane_change_dv("lunar_orbit", &cfg), 0.0);
+
+        let boca = at("boca_chica");
+        assert!(boca.plane_change_dv("geo", &cfg) < 0.0);
+        assert!(boca.plane_change_dv("sso", &cfg) > 0.0);
+        assert_eq!(at("atlantis").site_location().id, REFERENCE_SITE);
+    }
+
+    #[test]
+    fn test_integration_modes_trade_pad_time_against_vehicle_size() {
+        let cfg = LaunchSitesConfig::default();
+        let (v, h) = (IntegrationMode::Vertical, IntegrationMode::Horizontal);
+        assert!(h.pad_flow_days(2, &cfg) < v.pad_flow_days(2, &cfg), "hangars turn small rockets fast");
+        assert!(h.max_liftoff_kg(&cfg) < v.max_liftoff_kg(&cfg));
+
+        let date = GameDate::new(2001, 1, 1);
+        let mut site = LaunchSite { pad_flow_until: Some(date.add_days(5)), ..LaunchSite::default() };
+        assert_eq!(site.pad_busy_until(date), Some(date.add_days(5)));
+        site.conversion = Some(PadConversion { to: h, ready_on: date.add_days(90) });
+        assert_eq!(site.pad_busy_until(date), Some(date.add_days(90)));
+        assert_eq!(site.pad_busy_until(date.add_days(90)), None);
+    }
+
+    #[test]
+    fn test_cadence_slot_waits_for_window() {
+        let mut site = LaunchSite::default();
+        let d1 = GameDate::new(2001, 1, 1);
+        let d2 = GameDate::new(2001, 1, 10);
+        site.record_launch(d1, 30);
+        assert_eq!(site.next_cadence_slot(d1, 30, 2), None);
+        site.record_launch(d2, 30);
+        assert_eq!(site.next_cadence_slot(d2, 30, 2), Some(GameDate::new(2001, 1, 31)));
+        assert_eq!(site.next_cadence_slot(GameDate::new(2001, 1, 31), 30, 2), None);
+
+        // Old launches are trimmed when a new one is recorded.
+        site.record_launch(GameDate::new(2001, 3, 1), 30);
+        assert_eq!(site.recent_launches, vec![GameDate::new(2001, 3, 1)]);
+    }
+}
@@ -8,25 +8,77 @@ pub mod location;
 pub mod path_planning;
 pub mod calendar;
 pub mod event;
+pub mod locale;
+pub mod units;
 pub mod seed;
 pub mod balance;
 pub mod balance_config;
+pub mod content;
 pub mod flaw;
 pub mod team;
+pub mod astronaut;
 pub mod engine_project;
 pub mod reactor_project;
 pub mod structure;
 pub mod resources;
 pub mod rocket_project;
+pub mod design_file;
+pub mod software_project;
+pub mod depot_project;
 pub mod manufacturing;
+pub mod capacity;
 pub mod third_party;
+pub mod external_review;
+pub mod marketing;
+pub mod era;
+pub mod map_variant;
+pub mod servicing;
+pub mod docking;
+pub mod insertion;
+pub mod constellation;
+pub mod countdown;
+pub mod commit_criteria;
+pub mod disposal;
+#[cfg(any(feature = "qa", debug_assertions))]
+pub mod qa;
 pub mod contract;
+pub mod market;
+pub mod market_trend;
+pub mod demand;
+pub mod science;
+pub mod subcontract;
+pub mod bidding;
+pub mod gov_program;
+pub mod assets;
+pub mod budget;
 pub mod company;
 pub mod competitor;
+pub mod espionage;
 pub mod reputation;
+pub mod regulation;
 pub mod launch;
+pub mod launch_site;
+pub mod pad_failure;
+pub mod booster_reuse;
+pub mod recovery_fleet;
+pub mod payload_readiness;
+pub mod fleet;
+pub mod challenge;
+pub mod sandbox;
+pub mod library;
+pub mod codex;
+pub mod tutorial;
+pub mod advisor;
+pub mod forecast;
+pub mod batch;
+pub mod allocation;
+pub mod design_resource;
+pub mod silhouette;
+pub mod integration;
 pub mod flight;
+pub mod infrastructure;
 pub mod economy;
+pub mod valuation;
 pub mod technology;
 pub mod game_state;
 pub mod policy;
@@ -0,0 +1,132 @@
+//! The company library: a museum of the player's designs kept across
+//! playthroughs. Freezing an engine or rocket revision copies its
+//! stats into a library file beside the saves, where it outlives the
+//! game it came from. Entries are for browsing; a rocket's entry keeps
+//! its design file, but bringing one into a game is a sandbox edit
+//! (see `GameState::sandbox_import_archived`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::GameDate;
+use crate::design_file::DesignFile;
+use crate::engine::EngineCycle;
+use crate::engine_project::PropellantPreset;
+use crate::resources::format_money;
+
+/// What kind of design an entry is, with the stats kept for it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ArchivedDesign {
+    Engine {
+        cycle: EngineCycle,
+        preset: PropellantPreset,
+        thrust_n: f64,
+        isp_s: f64,
+        mass_kg: f64,
+    },
+    Rocket {
+        stages: usize,
+        liftoff_kg: f64,
+        flights: u32,
+        flight_revenue: f64,
+        certified: bool,
+        file: DesignFile,
+    },
+}
+
+/// One frozen design revision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryEntry {
+    pub name: String,
+    pub revision: u32,
+    /// The company that designed it.
+    pub company: String,
+    /// Game date it was archived on.
+    pub archived_on: GameDate,
+    /// Flaws found and not yet fixed when archived.
+    pub known_flaws: u32,
+    pub testing_work: f64,
+    pub design: ArchivedDesign,
+}
+
+impl LibraryEntry {
+    /// One line of the entry's headline stats.
+    pub fn summary(&self) -> String {
+        let stats = match &self.design {
+            ArchivedDesign::Engine { preset, thrust_n, isp_s, mass_kg, .. } => format!(
+                "{} engine, {:.0} kN, {:.0} s, {:.0} kg",
+                preset.name(), thrust_n / 1000.0, isp_s, mass_kg,
+            ),
+            ArchivedDesign::Rocket { stages, liftoff_kg, flights, flight_revenue, certified, .. } => format!(
+                "{}-stage rocket, {:.0} t, {} flights, {} earned{}",
+                stages, liftoff_kg / 1000.0, flights, format_money(*flight_revenue),
+                if *certified { ", certified" } else { "" },
+            ),
+        };
+        format!("{} Rev {} ({}, {}) — {}", self.name, self.revision, self.company, self.archived_on.year, stats)
+    }
+}
+
+/// Every archived design, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Library {
+    pub entries: Vec<LibraryEntry>,
+}
+
+impl Library {
+    /// Add `entry`, replacing an earlier copy of the same revision from
+    /// the same company. True if it replaced one.
+    pub fn archive(&mut self, entry: LibraryEntry) -> bool {
+        let same = |e: &LibraryEntry| {
+            e.company == entry.company && e.name == entry.name && e.revision == entry.revision
+                && std::mem::discriminant(&e.design) == std::mem::discriminant(&entry.design)
+        };
+        let replaced = self.entries.iter().any(same);
+        self.entries.retain(|e| !same(e));
+        self.entries.push(entry);
+        replaced
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<LibraryEntry> {
+        (index < self.entries.len()).then(|| self.entries.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine(company: &str, revision: u32) -> LibraryEntry {
+        LibraryEntry {
+            name: "Kestrel".into(),
+            revision,
+            company: company.into(),
+            archived_on: GameDate::new(2031, 5, 1),
+            known_flaws: 0,
+            testing_work: 300.0,
+            design: ArchivedDesign::Engine {
+                cycle: EngineCycle::GasGenerator,
+                preset: PropellantPreset::Kerolox,
+                thrust_n: 800_000.0,
+                isp_s: 290.0,
+                mass_kg: 900.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_archiving_a_revision_again_replaces_it() {
+        let mut library = Library::default();
+        assert!(!library.archive(engine("SpaceCorp", 1)));
+        assert!(!library.archive(engine("SpaceCorp", 2)));
+        assert!(!library.archive(engine("OrbitCo", 2)), "another company's engine is its own entry");
+        let mut better = engine("SpaceCorp", 1);
+        better.testing_work = 900.0;
+        assert!(library.archive(better));
+        assert_eq!(library.entries.len(), 3);
+        assert_eq!(library.entries[2].testing_work, 900.0, "the new copy goes to the end");
+        assert_eq!(library.entries[0].summary(), "Kestrel Rev 2 (SpaceCorp, 2031) — Kerolox engine, 800 kN, 290 s, 900 kg");
+        assert!(library.remove(3).is_none());
+        assert_eq!(library.remove(0).map(|e| e.revision), Some(2));
+    }
+}
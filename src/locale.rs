@@ -0,0 +1,195 @@
+//! Localization layer for generated strings.
+//!
+//! Game code builds player-facing text as a [`Text`]: a stable key,
+//! named arguments, and the English fallback the terminal UI shows.
+//! A front end with its own language loads a [`LocaleTable`] (a TOML
+//! file of `key = "template"` entries, nested tables flattened to
+//! dotted keys) and renders each `Text` through it; any key the table
+//! lacks falls back to the English string, so a partial translation
+//! is always safe.
+//!
+//! Key namespaces:
+//! - `event.<variant>` — one per [`crate::event::GameEvent`] variant,
+//!   with the variant's fields as arguments.
+//! - `contract.name`, `contract.campaign_flight`, `contract.prefix.<slug>`
+//! - `market.<id>.name`, `market.<id>.description`
+//! - `location.<id>`
+//! - `flaw.<slug>`, `subsystem.<slug>`, `consequence.<slug>`
+//! - `testing.<slug>` — the testing tiers ("Untested" … "Thoroughly Tested").
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Player-facing text as a translation key plus arguments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Text {
+    /// Stable lookup key. Empty for free-form text (player-chosen
+    /// names, pre-localization saves), which always renders verbatim.
+    pub key: String,
+    pub args: Vec<TextArg>,
+    /// The English rendering.
+    pub fallback: String,
+}
+
+/// A named template argument. A keyed argument is itself translatable
+/// (a location or prefix name inside a contract title).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextArg {
+    pub name: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl Text {
+    pub fn new(key: impl Into<String>, fallback: impl Into<String>) -> Self {
+        Text { key: key.into(), args: Vec::new(), fallback: fallback.into() }
+    }
+
+    /// Untranslatable text, rendered as-is in every locale.
+    pub fn literal(text: impl Into<String>) -> Self {
+        Text::new(String::new(), text)
+    }
+
+    /// A catalog label keyed by its own English text: `namespace.<slug>`.
+    pub fn label(namespace: &str, english: &str) -> Self {
+        Text::new(format!("{namespace}.{}", slug(english)), english)
+    }
+
+    pub fn arg(mut self, name: &str, value: impl ToString) -> Self {
+        self.args.push(TextArg { name: name.into(), value: value.to_string(), key: None });
+        self
+    }
+
+    /// An argument whose value is looked up under `key` first.
+    pub fn keyed_arg(mut self, name: &str, key: impl Into<String>, value: impl ToString) -> Self {
+        self.args.push(TextArg { name: name.into(), value: value.to_string(), key: Some(key.into()) });
+        self
+    }
+}
+
+impl std::fmt::Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.fallback)
+    }
+}
+
+/// Lowercase ASCII alphanumerics joined by single underscores:
+/// "Turbopump seal leak" → `turbopump_seal_leak`.
+pub fn slug(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    while out.ends_with('_') {
+        out.pop();
+    }
+    out
+}
+
+/// Translated templates for one language. Templates name arguments in
+/// braces: `"{prefix} nach {destination}"`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocaleTable {
+    strings: HashMap<String, String>,
+}
+
+impl LocaleTable {
+    pub fn from_toml_str(text: &str) -> Result<Self, String> {
+        let value: toml::Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut strings = HashMap::new();
+        flatten(&value, "", &mut strings)?;
+        Ok(LocaleTable { strings })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("reading {}: {e}", path.display()))?;
+        Self::from_toml_str(&text).map_err(|e| format!("parsing {}: {e}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.strings.get(key).map(String::as_str)
+    }
+
+    /// Render `text` in this locale, falling back to English for a
+    /// missing key (and to the raw value for a missing argument key).
+    pub fn render(&self, text: &Text) -> String {
+        let Some(template) = self.get(&text.key) else {
+            return text.fallback.clone();
+        };
+        let mut out = template.to_string();
+        for arg in &text.args {
+            let value = arg.key.as_deref().and_then(|k| self.get(k)).unwrap_or(&arg.value);
+            out = out.replace(&format!("{{{}}}", arg.name), value);
+        }
+        out
+    }
+}
+
+fn flatten(table: &toml::Table, prefix: &str, out: &mut HashMap<String, String>) -> Result<(), String> {
+    for (k, v) in table {
+        let key = if prefix.is_empty() { k.clone() } else { format!("{prefix}.{k}") };
+        match v {
+            toml::Value::String(s) => {
+                out.insert(key, s.clone());
+            }
+            toml::Value::Table(t) => flatten(t, &key, out)?,
+            _ => return Err(format!("`{key}` must be a string")),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("Turbopump seal leak"), "turbopump_seal_leak");
+        assert_eq!(slug("RP-1 (Kerosene)"), "rp_1_kerosene");
+        assert_eq!(slug("  Well Tested "), "well_tested");
+    }
+
+    #[test]
+    fn test_render_translates_with_fallback() {
+        let table = LocaleTable::from_toml_str(r#"
+            [contract]
+            name = "{prefix} nach {destination}"
+            prefix.comsat = "Komsat"
+            [location]
+            leo = "Niedrige Erdumlaufbahn"
+        "#).unwrap();
+        assert_eq!(table.len(), 3);
+
+        let name = Text::new("contract.name", "ComSat to Low Earth Orbit")
+            .keyed_arg("prefix", "contract.prefix.comsat", "ComSat")
+            .keyed_arg("destination", "location.leo", "Low Earth Orbit");
+        assert_eq!(table.render(&name), "Komsat nach Niedrige Erdumlaufbahn");
+
+        // Missing key → English; missing argument key → raw value.
+        let gto = Text::new("contract.name", "ComSat to GTO")
+            .keyed_arg("prefix", "contract.prefix.comsat", "ComSat")
+            .keyed_arg("destination", "location.gto", "GTO");
+        assert_eq!(table.render(&gto), "Komsat nach GTO");
+        assert_eq!(table.render(&Text::label("testing", "Well Tested")), "Well Tested");
+        assert_eq!(table.render(&Text::literal("Falcon")), "Falcon");
+
+        assert!(LocaleTable::from_toml_str("event = 3").is_err());
+    }
+}
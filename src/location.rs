@@ -1,6 +1,9 @@
 use std::collections::BinaryHeap;
 use std::cmp::Ordering;
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use serde::{Deserialize, Serialize};
 
 /// Physical properties of a surface (planet or moon)
 #[derive(Debug, Clone)]
@@ -61,7 +64,33 @@ pub fn parent_body_sun_distance_au(parent: &str) -> f64 {
     }
 }
 
+/// The plane an Earth orbit has to be flown into, for orbits that
+/// need a particular one. Anything else (LEO, transfer orbits,
+/// cislunar points) is reached in whatever plane the launch leaves in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitPlane {
+    pub inclination_deg: f64,
+    /// Plane changes happen at the transfer orbit's apogee, where
+    /// they're cheap, rather than as a dogleg on the way up.
+    pub change_at_apogee: bool,
+}
+
+/// The plane `location_id` sits in, if it has to be a particular one.
+pub fn orbit_plane(location_id: &str) -> Option<OrbitPlane> {
+    let (inclination_deg, change_at_apogee) = match location_id {
+        "sso" => (98.0, false),
+        "meo" => (55.0, false),
+        "geo" => (0.0, true),
+        _ => return None,
+    };
+    Some(OrbitPlane { inclination_deg, change_at_apogee })
+}
+
 impl Location {
+    pub fn name_text(&self) -> crate::locale::Text {
+        crate::locale::Text::new(format!("location.{}", self.id), self.display_name)
+    }
+
     /// Distance from the Sun in AU. Heliocentric "X_transfer" and
     /// "X_escape" nodes (parent_body = "sun") look up X's heliocentric
     /// distance so the burn at that node sees the right solar flux —
@@ -108,14 +137,18 @@ pub struct Transfer {
     pub low_thrust_delta_v: Option<f64>,
 }
 
+/// Drag loss (m/s) of a rocket of `AERO_DRAG_REFERENCE_MASS_KG`.
+pub const AERO_DRAG_BASE_LOSS: f64 = 300.0;
+/// Liftoff mass the base drag loss is quoted for (~Falcon 9 class).
+pub const AERO_DRAG_REFERENCE_MASS_KG: f64 = 500_000.0;
+/// How drag loss scales with liftoff mass: loss ∝ (reference / mass)^this.
+pub const AERO_DRAG_MASS_EXPONENT: f64 = 0.2;
+
 /// Estimate aerodynamic drag loss for a launch through atmosphere.
 /// Larger rockets have proportionally less drag loss (better ballistic coefficient).
 pub fn aero_drag_loss(rocket_mass_kg: f64) -> f64 {
-    // Drag loss model: base 300 m/s scaled by (reference_mass / actual_mass)^0.2
     // Heavier rockets push through atmosphere more efficiently.
-    let reference_mass = 500_000.0; // ~Falcon 9 class
-    let base_loss = 300.0;
-    base_loss * (reference_mass / rocket_mass_kg.max(1.0)).powf(0.2)
+    AERO_DRAG_BASE_LOSS * (AERO_DRAG_REFERENCE_MASS_KG / rocket_mass_kg.max(1.0)).powf(AERO_DRAG_MASS_EXPONENT)
 }
 
 impl Transfer {
@@ -147,6 +180,52 @@ impl Transfer {
     }
 }
 
+/// A location laid out for an orbit-map display. Coordinates are
+/// schematic: `x` is heliocentric distance in AU (moons nudged outward
+/// of their planet), `y` is gravity-well depth in km/s below the
+/// heliocentric transfer nodes (0 at the top, surfaces lowest).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapNode {
+    pub id: String,
+    pub display_name: String,
+    pub short_name: String,
+    pub parent_body: String,
+    /// "surface", "orbit", or "lagrange_point".
+    pub kind: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// One directed transfer for an orbit-map display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapEdge {
+    pub from: String,
+    pub to: String,
+    /// Nominal delta-v, before any drag loss.
+    pub delta_v: f64,
+    pub transit_days: u32,
+    pub through_atmosphere: bool,
+    pub can_aerobrake: bool,
+    /// Low-thrust cost, or None when low-thrust craft can't use the edge.
+    pub low_thrust_delta_v: Option<f64>,
+}
+
+/// The whole delta-v graph as plain data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapGraph {
+    pub nodes: Vec<MapNode>,
+    pub edges: Vec<MapEdge>,
+}
+
+/// Moons sit just outward of their planet's column on the map.
+fn satellite_offset_au(parent: &str) -> f64 {
+    match parent {
+        "moon" | "phobos" => 0.08,
+        "deimos" => 0.16,
+        _ => 0.0,
+    }
+}
+
 /// The delta-v map: a directed graph of locations connected by transfers
 pub struct DeltaVMap {
     locations: Vec<Location>,
@@ -631,6 +710,89 @@ impl DeltaVMap {
     pub fn location_at(&self, index: usize) -> Option<&Location> {
         self.locations.get(index)
     }
+
+    /// The graph as plain data for an orbit-map display.
+    pub fn graph(&self) -> MapGraph {
+        // Well depth: cheapest climb (drag included, at the drag
+        // reference mass) to any heliocentric node.
+        let helio: Vec<&str> = self.locations.iter()
+            .filter(|l| l.parent_body == "sun")
+            .map(|l| l.id)
+            .collect();
+        let nodes = self.locations.iter().map(|l| {
+            let depth = if l.parent_body == "sun" {
+                0.0
+            } else {
+                helio.iter()
+                    .filter_map(|h| self.shortest_path(l.id, h, AERO_DRAG_REFERENCE_MASS_KG))
+                    .map(|(_, dv)| dv)
+                    .fold(f64::INFINITY, f64::min)
+            };
+            let kind = match l.location_type {
+                LocationType::Surface(_) => "surface",
+                LocationType::Orbit => "orbit",
+                LocationType::LagrangePoint => "lagrange_point",
+            };
+            MapNode {
+                id: l.id.to_string(),
+                display_name: l.display_name.to_string(),
+                short_name: l.short_name.to_string(),
+                parent_body: l.parent_body.to_string(),
+                kind: kind.to_string(),
+                x: l.sun_distance_au() + satellite_offset_au(l.parent_body),
+                y: if depth.is_finite() { -depth / 1_000.0 } else { 0.0 },
+            }
+        }).collect();
+        let edges = self.transfers.iter().map(|t| MapEdge {
+            from: t.from.to_string(),
+            to: t.to.to_string(),
+            delta_v: t.delta_v,
+            transit_days: t.transit_days,
+            through_atmosphere: t.through_atmosphere,
+            can_aerobrake: t.can_aerobrake,
+            low_thrust_delta_v: t.low_thrust_ok.then(|| t.low_thrust_delta_v.unwrap_or(t.delta_v)),
+        }).collect();
+        MapGraph { nodes, edges }
+    }
+
+    /// Add a node (content packs; the built-in graph uses the literal
+    /// tables in `earth_moon`).
+    pub fn add_location(&mut self, location: Location) {
+        self.locations.push(location);
+    }
+
+    /// Add a high-thrust-only symmetric edge pair (a ↔ b).
+    pub fn add_impulsive_pair(&mut self, a: &'static str, b: &'static str, dv: f64, days: u32) {
+        add_impulsive_pair(&mut self.transfers, a, b, dv, days);
+    }
+
+    /// Add a low-thrust-friendly symmetric edge pair (a ↔ b).
+    pub fn add_spiral_pair(
+        &mut self, a: &'static str, b: &'static str, dv: f64, lt_dv: Option<f64>, days: u32,
+    ) {
+        add_spiral_pair(&mut self.transfers, a, b, dv, lt_dv, days);
+    }
+
+    /// Add a surface ↔ orbit launch/landing pair.
+    pub fn add_ground_pair(
+        &mut self, surface: &'static str, orbit: &'static str, dv: f64, days: u32,
+        has_atm: bool, lt_dv: Option<f64>,
+    ) {
+        add_ground_pair(&mut self.transfers, surface, orbit, dv, days, has_atm, lt_dv);
+    }
+
+    /// Scale both directions of the a ↔ b edge (high- and low-thrust
+    /// costs alike). Map variants use this to perturb the built-in graph.
+    pub fn scale_pair(&mut self, a: &str, b: &str, factor: f64) {
+        for t in &mut self.transfers {
+            if (t.from == a && t.to == b) || (t.from == b && t.to == a) {
+                t.delta_v *= factor;
+                if let Some(lt) = &mut t.low_thrust_delta_v {
+                    *lt *= factor;
+                }
+            }
+        }
+    }
 }
 
 /// Velocity at which the rocket begins pitching from vertical (gravity turn initiation).
@@ -726,8 +888,35 @@ pub fn surface_location_ids() -> &'static [&'static str] {
     &["earth_surface", "lunar_surface"]
 }
 
-/// Global delta-v map instance
-pub static DELTA_V_MAP: LazyLock<DeltaVMap> = LazyLock::new(DeltaVMap::earth_moon);
+static DELTA_V_MAP_BUILT: AtomicBool = AtomicBool::new(false);
+
+/// Global delta-v map instance: the built-in graph plus any installed
+/// content pack's nodes and edges, perturbed by the installed map
+/// variant if the world has one.
+pub static DELTA_V_MAP: LazyLock<DeltaVMap> = LazyLock::new(|| {
+    DELTA_V_MAP_BUILT.store(true, AtomicOrdering::SeqCst);
+    let mut map = base_map();
+    if let Some(variant) = crate::map_variant::installed() {
+        variant.apply_to_map(&mut map);
+    }
+    map
+});
+
+/// A fresh copy of the map a variant is generated against: the
+/// built-in graph plus installed content, unperturbed.
+pub fn base_map() -> DeltaVMap {
+    let mut map = DeltaVMap::earth_moon();
+    if let Some(pack) = crate::content::installed() {
+        pack.apply_to_map(&mut map);
+    }
+    map
+}
+
+/// Whether `DELTA_V_MAP` has been built (content installed after this
+/// point would never reach it).
+pub fn delta_v_map_built() -> bool {
+    DELTA_V_MAP_BUILT.load(AtomicOrdering::SeqCst)
+}
 
 #[cfg(test)]
 mod tests {
@@ -807,6 +996,22 @@ mod tests {
         assert_eq!(map.location_count(), 50);
     }
 
+    #[test]
+    fn test_graph_lays_out_wells_and_edges() {
+        let map = DeltaVMap::earth_moon();
+        let g = map.graph();
+        assert_eq!(g.nodes.len(), map.location_count());
+        let node = |id: &str| g.nodes.iter().find(|n| n.id == id).unwrap();
+        assert!(node("earth_surface").y < node("leo").y);
+        assert!(node("leo").y < node("geo").y);
+        assert_eq!(node("mars_transfer").y, 0.0);
+        assert!(node("lunar_surface").x > node("earth_surface").x);
+        assert!(node("mars_surface").x > node("lunar_surface").x);
+        let ascent = g.edges.iter().find(|e| e.from == "earth_surface" && e.to == "leo").unwrap();
+        assert!(ascent.through_atmosphere);
+        assert!(g.nodes.iter().all(|n| n.y.is_finite() && n.y <= 0.0));
+    }
+
     #[test]
     fn test_location_lookup() {
         let map = DeltaVMap::earth_moon();
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use serde::{Serialize, Deserialize};
 
-use crate::engine::EngineId;
+use crate::balance_config::StorageConfig;
+use crate::calendar::GameDate;
+use crate::engine::{EngineDesign, EngineId};
 use crate::engine_project::EngineSource;
 use crate::resources;
-use crate::rocket::RocketDesignId;
+use crate::rocket::{RocketDesign, RocketDesignId};
 use crate::rocket_project::RocketProjectId;
 use crate::team;
 
@@ -52,9 +56,14 @@ impl FloorSpace {
 
     /// Advance one day. Returns number of units completed.
     pub fn advance_day(&mut self) -> u32 {
+        self.advance_days(1)
+    }
+
+    /// Advance `days` days. Returns number of units completed.
+    pub fn advance_days(&mut self, days: u32) -> u32 {
         let mut completed = 0;
         self.under_construction.retain_mut(|order| {
-            order.days_remaining = order.days_remaining.saturating_sub(1);
+            order.days_remaining = order.days_remaining.saturating_sub(days);
             if order.days_remaining == 0 {
                 completed += order.units;
                 false
@@ -81,8 +90,11 @@ pub enum ManufacturingOrderType {
         complexity: u32,
         /// Revision at time of order placement.
         revision: u32,
-        /// Flaw snapshot at time of order placement.
-        flaws: Vec<crate::flaw::Flaw>,
+        /// Engine flaws present at order placement, by id. The flaws
+        /// themselves live on the engine lineage. Older saves stored
+        /// the flaws whole, as `flaws`.
+        #[serde(default, alias = "flaws", deserialize_with = "crate::flaw::ids_or_flaws")]
+        flaw_ids: Vec<crate::flaw::FlawId>,
         /// Actualized improvements at time of order placement.
         improvements: Vec<crate::engine_project::EngineImprovement>,
     },
@@ -104,6 +116,38 @@ pub enum ManufacturingOrderType {
         revision: u32,
         /// Rocket project flaw snapshot at integration time.
         rocket_flaws: Vec<crate::flaw::Flaw>,
+        /// The design as ordered; None for orders from older saves.
+        #[serde(default)]
+        design: Option<RocketDesign>,
+        /// Reflown boosters it was stacked with, by first-group stage
+        /// index. Filled in as the stages are consumed.
+        #[serde(default)]
+        boosters: Vec<(usize, crate::booster_reuse::BoosterLife)>,
+    },
+    /// Rework a stored rocket up to its project's current revision.
+    Retrofit {
+        item_id: InventoryItemId,
+        rocket_name: String,
+        /// Revision, flaws and design the rocket leaves with.
+        revision: u32,
+        rocket_flaws: Vec<crate::flaw::Flaw>,
+        design: RocketDesign,
+    },
+    /// Inspect and refurbish a stored item past its shelf life.
+    Recertification {
+        item_id: InventoryItemId,
+        item_name: String,
+    },
+    /// Tear down a landed booster to reveal how worn it is.
+    BoosterInspection {
+        item_id: InventoryItemId,
+        item_name: String,
+    },
+    /// Stack a boilerplate capsule on a design's abort system and fire
+    /// it off the pad.
+    PadAbortTest {
+        rocket_project_id: RocketProjectId,
+        rocket_name: String,
     },
 }
 
@@ -114,6 +158,10 @@ impl ManufacturingOrderType {
             ManufacturingOrderType::Engine { engine_name, .. } => engine_name.clone(),
             ManufacturingOrderType::Stage { stage_name, .. } => stage_name.clone(),
             ManufacturingOrderType::RocketIntegration { rocket_name, .. } => rocket_name.clone(),
+            ManufacturingOrderType::Retrofit { rocket_name, .. } => rocket_name.clone(),
+            ManufacturingOrderType::Recertification { item_name, .. } => item_name.clone(),
+            ManufacturingOrderType::BoosterInspection { item_name, .. } => item_name.clone(),
+            ManufacturingOrderType::PadAbortTest { rocket_name, .. } => rocket_name.clone(),
         }
     }
 }
@@ -133,11 +181,21 @@ pub struct ManufacturingOrder {
     #[serde(default)]
     pub labor_cost: f64,
     pub teams_assigned: u32,
+    /// Of `teams_assigned`, how many are small and large teams; the
+    /// rest are medium.
+    #[serde(default)]
+    pub small_teams: u32,
+    #[serde(default)]
+    pub large_teams: u32,
     pub floor_space_used: u32,
     /// If true, this order is waiting for prerequisite items in inventory.
     pub waiting_for_prerequisites: bool,
     /// How many of this design have been built before (for learning curve).
     pub prior_builds: u32,
+    /// Build date of the oldest consumed component; the finished item
+    /// ages from here, not from its own completion.
+    #[serde(default)]
+    pub oldest_component: Option<GameDate>,
 }
 
 /// Events emitted by manufacturing processing.
@@ -165,6 +223,27 @@ pub enum ManufacturingEvent {
     FloorSpaceComplete {
         units: u32,
     },
+    Recertified {
+        order_id: ManufacturingOrderId,
+        item_name: String,
+    },
+    /// A booster teardown is done and its wear revealed.
+    BoosterInspected {
+        order_id: ManufacturingOrderId,
+        item_id: InventoryItemId,
+        item_name: String,
+    },
+    Retrofitted {
+        order_id: ManufacturingOrderId,
+        rocket_name: String,
+        revision: u32,
+    },
+    /// A pad-abort test is stacked and ready to fire.
+    PadAbortTestReady {
+        order_id: ManufacturingOrderId,
+        rocket_project_id: RocketProjectId,
+        rocket_name: String,
+    },
 }
 
 impl ManufacturingOrder {
@@ -180,7 +259,7 @@ impl ManufacturingOrder {
         preset: crate::engine_project::PropellantPreset,
         prior_builds: u32,
         revision: u32,
-        flaws: Vec<crate::flaw::Flaw>,
+        flaw_ids: Vec<crate::flaw::FlawId>,
         improvements: Vec<crate::engine_project::EngineImprovement>,
         balance_cfg: &crate::balance_config::BalanceConfig,
     ) -> Self {
@@ -197,7 +276,7 @@ impl ManufacturingOrder {
                 engine_mass_kg,
                 complexity,
                 revision,
-                flaws,
+                flaw_ids,
                 improvements,
             },
             work_completed: 0.0,
@@ -205,9 +284,12 @@ impl ManufacturingOrder {
             material_cost,
             labor_cost: 0.0,
             teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
             floor_space_used: 1,
             waiting_for_prerequisites: false,
             prior_builds,
+            oldest_component: None,
         }
     }
 
@@ -243,12 +325,21 @@ impl ManufacturingOrder {
             material_cost,
             labor_cost: 0.0,
             teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
             floor_space_used: 1,
             waiting_for_prerequisites: true, // wait for engines
             prior_builds,
+            oldest_component: None,
         }
     }
 
+    /// Take the commonality discount off a family stage's order.
+    pub fn apply_commonality(&mut self, cfg: &crate::balance_config::CommonalityConfig) {
+        self.work_required *= 1.0 - cfg.work_discount;
+        self.material_cost *= 1.0 - cfg.material_discount;
+    }
+
     /// Create a rocket integration order.
     #[allow(clippy::too_many_arguments)] // constructor-style, callers read positionally with names at the call site
     pub fn new_integration(
@@ -260,6 +351,7 @@ impl ManufacturingOrder {
         prior_builds: u32,
         revision: u32,
         rocket_flaws: Vec<crate::flaw::Flaw>,
+        design: Option<RocketDesign>,
         balance_cfg: &crate::balance_config::BalanceConfig,
     ) -> Self {
         let base_work = balance_cfg.work.rocket_integration_work(total_stages);
@@ -275,15 +367,129 @@ impl ManufacturingOrder {
                 total_stages,
                 revision,
                 rocket_flaws,
+                design,
+                boosters: Vec::new(),
             },
             work_completed: 0.0,
             work_required: base_work * learning,
             material_cost,
             labor_cost: 0.0,
             teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
             floor_space_used: total_stages, // scales with rocket size
             waiting_for_prerequisites: true, // wait for all stages
             prior_builds,
+            oldest_component: None,
+        }
+    }
+
+    /// Create a retrofit order bringing the stored rocket `item_id` up
+    /// to `revision`. Work and materials come from the caller's quote.
+    #[allow(clippy::too_many_arguments)] // constructor-style, callers read positionally with names at the call site
+    pub fn new_retrofit(
+        id: ManufacturingOrderId,
+        item_id: InventoryItemId,
+        rocket_name: String,
+        revision: u32,
+        rocket_flaws: Vec<crate::flaw::Flaw>,
+        design: RocketDesign,
+        work_required: f64,
+        material_cost: f64,
+    ) -> Self {
+        ManufacturingOrder {
+            id,
+            order_type: ManufacturingOrderType::Retrofit {
+                item_id, rocket_name, revision, rocket_flaws, design,
+            },
+            work_completed: 0.0,
+            work_required,
+            material_cost,
+            labor_cost: 0.0,
+            teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
+            floor_space_used: 1,
+            waiting_for_prerequisites: false,
+            prior_builds: 0,
+            oldest_component: None,
+        }
+    }
+
+    /// Create a recertification order for a stored item that cost
+    /// `build_cost` to make.
+    pub fn new_recertification(
+        id: ManufacturingOrderId,
+        item_id: InventoryItemId,
+        item_name: String,
+        build_cost: f64,
+        cfg: &StorageConfig,
+    ) -> Self {
+        ManufacturingOrder {
+            id,
+            order_type: ManufacturingOrderType::Recertification { item_id, item_name },
+            work_completed: 0.0,
+            work_required: cfg.recertification_work,
+            material_cost: build_cost * cfg.recertification_cost_fraction,
+            labor_cost: 0.0,
+            teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
+            floor_space_used: 1,
+            waiting_for_prerequisites: false,
+            prior_builds: 0,
+            oldest_component: None,
+        }
+    }
+
+    /// Create a teardown inspection order for a landed booster that cost
+    /// `build_cost` to put back in stock.
+    pub fn new_booster_inspection(
+        id: ManufacturingOrderId,
+        item_id: InventoryItemId,
+        item_name: String,
+        build_cost: f64,
+        cfg: &crate::balance_config::BoosterReuseConfig,
+    ) -> Self {
+        ManufacturingOrder {
+            id,
+            order_type: ManufacturingOrderType::BoosterInspection { item_id, item_name },
+            work_completed: 0.0,
+            work_required: cfg.inspection_work,
+            material_cost: build_cost * cfg.inspection_cost_fraction,
+            labor_cost: 0.0,
+            teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
+            floor_space_used: 1,
+            waiting_for_prerequisites: false,
+            prior_builds: 0,
+            oldest_component: None,
+        }
+    }
+
+    /// Create a pad-abort test order for a design's abort system.
+    pub fn new_pad_abort_test(
+        id: ManufacturingOrderId,
+        rocket_project_id: RocketProjectId,
+        rocket_name: String,
+        material_cost: f64,
+        cfg: &crate::balance_config::LaunchAbortConfig,
+    ) -> Self {
+        ManufacturingOrder {
+            id,
+            order_type: ManufacturingOrderType::PadAbortTest { rocket_project_id, rocket_name },
+            work_completed: 0.0,
+            work_required: cfg.pad_abort_test_work,
+            material_cost,
+            labor_cost: 0.0,
+            teams_assigned: 0,
+            small_teams: 0,
+            large_teams: 0,
+            floor_space_used: 1,
+            waiting_for_prerequisites: false,
+            prior_builds: 0,
+            oldest_component: None,
         }
     }
 
@@ -293,6 +499,10 @@ impl ManufacturingOrder {
             ManufacturingOrderType::Engine { engine_name, .. } => engine_name,
             ManufacturingOrderType::Stage { stage_name, .. } => stage_name,
             ManufacturingOrderType::RocketIntegration { rocket_name, .. } => rocket_name,
+            ManufacturingOrderType::Retrofit { rocket_name, .. } => rocket_name,
+            ManufacturingOrderType::Recertification { item_name, .. } => item_name,
+            ManufacturingOrderType::BoosterInspection { item_name, .. } => item_name,
+            ManufacturingOrderType::PadAbortTest { rocket_name, .. } => rocket_name,
         }
     }
 
@@ -302,23 +512,79 @@ impl ManufacturingOrder {
             ManufacturingOrderType::Engine { .. } => "Engine",
             ManufacturingOrderType::Stage { .. } => "Stage",
             ManufacturingOrderType::RocketIntegration { .. } => "Integration",
+            ManufacturingOrderType::Retrofit { .. } => "Retrofit",
+            ManufacturingOrderType::Recertification { .. } => "Recert",
+            ManufacturingOrderType::BoosterInspection { .. } => "Inspect",
+            ManufacturingOrderType::PadAbortTest { .. } => "Pad abort",
         }
     }
 
     /// Apply one day of manufacturing work. Returns true if completed.
     pub fn apply_daily_work(&mut self, costs: &crate::balance_config::CostsConfig) -> bool {
+        self.apply_work_days(1, costs)
+    }
+
+    /// Apply `days` days of manufacturing work at the current staffing.
+    /// Returns true if completed.
+    pub fn apply_work_days(&mut self, days: u32, costs: &crate::balance_config::CostsConfig) -> bool {
         if self.waiting_for_prerequisites || self.teams_assigned == 0 {
             return false;
         }
-        let work = team::manufacturing_work_rate(self.teams_assigned);
-        self.work_completed += work;
-        // Attribute one team-day of salary per assigned team. 30 days/month
+        let mix = self.team_mix();
+        let work = team::manufacturing_team_efficiency(mix, costs);
+        self.work_completed += work * days as f64;
+        // Attribute a day of each assigned team's salary. 30 days/month
         // is the same approximation used by the salary-deduction path.
-        let daily_salary = costs.manufacturing_monthly_salary / 30.0;
-        self.labor_cost += self.teams_assigned as f64 * daily_salary;
+        self.labor_cost += mix.monthly_salary(costs) / 30.0 * days as f64;
         self.work_completed >= self.work_required
     }
 
+    /// Days of work this order can take at its current staffing with a
+    /// day to spare before it completes; unbounded while it isn't
+    /// being worked.
+    pub fn quiet_days(&self, costs: &crate::balance_config::CostsConfig) -> u32 {
+        if self.waiting_for_prerequisites || self.teams_assigned == 0 {
+            return u32::MAX;
+        }
+        let rate = team::manufacturing_team_efficiency(self.team_mix(), costs);
+        if rate <= 0.0 {
+            return u32::MAX;
+        }
+        let days_left = ((self.work_required - self.work_completed) / rate).ceil();
+        (days_left - 2.0).max(0.0) as u32
+    }
+
+    /// The sizes of the teams on this order.
+    pub fn team_mix(&self) -> team::TeamMix {
+        team::TeamMix {
+            small: self.small_teams,
+            medium: self.teams_assigned.saturating_sub(self.small_teams + self.large_teams),
+            large: self.large_teams,
+        }
+    }
+
+    /// Put a team of `size` on the order.
+    pub fn add_team(&mut self, size: team::TeamSize) {
+        self.teams_assigned += 1;
+        match size {
+            team::TeamSize::Small => self.small_teams += 1,
+            team::TeamSize::Medium => {}
+            team::TeamSize::Large => self.large_teams += 1,
+        }
+    }
+
+    /// Take the smallest team off the order, returning its size.
+    pub fn release_team(&mut self) -> Option<team::TeamSize> {
+        let size = team::TeamSize::ALL.iter().rev().copied().find(|&s| self.team_mix().of(s) > 0)?;
+        self.teams_assigned -= 1;
+        match size {
+            team::TeamSize::Small => self.small_teams -= 1,
+            team::TeamSize::Medium => {}
+            team::TeamSize::Large => self.large_teams -= 1,
+        }
+        Some(size)
+    }
+
     /// Progress as a fraction 0.0-1.0.
     pub fn progress(&self) -> f64 {
         if self.work_required <= 0.0 {
@@ -343,12 +609,20 @@ pub struct InventoryEngine {
     /// Revision of the engine project when this was built.
     #[serde(default)]
     pub revision: u32,
-    /// Snapshot of flaws at build time.
-    #[serde(default)]
-    pub flaws: Vec<crate::flaw::Flaw>,
+    /// Engine flaws present at build time, by id. Discovery and fixes
+    /// happen on the engine lineage, so resolve these against it.
+    #[serde(default, alias = "flaws", deserialize_with = "crate::flaw::ids_or_flaws")]
+    pub flaw_ids: Vec<crate::flaw::FlawId>,
     /// Snapshot of actualized improvements at build time.
     #[serde(default)]
     pub improvements: Vec<crate::engine_project::EngineImprovement>,
+    /// Build date of the oldest aging part in this item. None until
+    /// the daily aging tick stamps it (instant deliveries, older saves).
+    #[serde(default)]
+    pub built_on: Option<GameDate>,
+    /// Past its shelf life: flies with an aging risk until recertified.
+    #[serde(default)]
+    pub expired: bool,
 }
 
 /// A built stage in inventory.
@@ -362,6 +636,17 @@ pub struct InventoryStage {
     /// Manufacturing cost of this stage (including consumed engine costs).
     #[serde(default)]
     pub build_cost: f64,
+    /// Build date of the oldest aging part in this item. None until
+    /// the daily aging tick stamps it (instant deliveries, older saves).
+    #[serde(default)]
+    pub built_on: Option<GameDate>,
+    /// Past its shelf life: flies with an aging risk until recertified.
+    #[serde(default)]
+    pub expired: bool,
+    /// Serial and history of a landed booster back in stock; None for
+    /// a stage built new.
+    #[serde(default)]
+    pub booster: Option<crate::booster_reuse::BoosterLife>,
 }
 
 /// An integrated rocket ready for launch.
@@ -380,6 +665,23 @@ pub struct InventoryRocket {
     /// Snapshot of rocket project flaws at build time.
     #[serde(default)]
     pub rocket_flaws: Vec<crate::flaw::Flaw>,
+    /// The design as built. None for stock from older saves, which
+    /// flies its project's current design.
+    #[serde(default)]
+    pub design: Option<RocketDesign>,
+    /// Build date of the oldest aging part in this item. None until
+    /// the daily aging tick stamps it (instant deliveries, older saves).
+    #[serde(default)]
+    pub built_on: Option<GameDate>,
+    /// Past its shelf life: flies with an aging risk until recertified.
+    #[serde(default)]
+    pub expired: bool,
+    /// How full its liquid tanks are loaded on launch day.
+    #[serde(default)]
+    pub propellant_load: crate::rocket::PropellantLoad,
+    /// Reflown boosters in its first group, by stage index.
+    #[serde(default)]
+    pub boosters: Vec<(usize, crate::booster_reuse::BoosterLife)>,
 }
 
 /// Inventory of manufactured items.
@@ -449,6 +751,94 @@ impl Inventory {
         let idx = self.rockets.iter().position(|r| r.item_id == item_id)?;
         Some(self.rockets.remove(idx))
     }
+
+    /// Name, build cost and expiry of any stored item by id.
+    pub fn item_summary(&self, item_id: InventoryItemId) -> Option<(&str, f64, bool)> {
+        self.engines.iter()
+            .find(|e| e.item_id == item_id)
+            .map(|e| (e.engine_name.as_str(), e.build_cost, e.expired))
+            .or_else(|| self.stages.iter()
+                .find(|s| s.item_id == item_id)
+                .map(|s| (s.stage_name.as_str(), s.build_cost, s.expired)))
+            .or_else(|| self.rockets.iter()
+                .find(|r| r.item_id == item_id)
+                .map(|r| (r.rocket_name.as_str(), r.build_cost, r.expired)))
+    }
+
+    /// Remove any stored item by id. Returns its name and build cost.
+    pub fn remove_item(&mut self, item_id: InventoryItemId) -> Option<(String, f64)> {
+        if let Some(i) = self.engines.iter().position(|e| e.item_id == item_id) {
+            let e = self.engines.remove(i);
+            return Some((e.engine_name, e.build_cost));
+        }
+        if let Some(i) = self.stages.iter().position(|s| s.item_id == item_id) {
+            let s = self.stages.remove(i);
+            return Some((s.stage_name, s.build_cost));
+        }
+        self.take_rocket(item_id).map(|r| (r.rocket_name, r.build_cost))
+    }
+
+    /// Record a teardown inspection of the stored booster `item_id`:
+    /// its wear is revealed and the teardown counts as a refurbishment.
+    /// None if it's gone.
+    pub fn inspect_booster(&mut self, item_id: InventoryItemId, date: GameDate) -> Option<&crate::booster_reuse::BoosterLife> {
+        let life = self.stages.iter_mut()
+            .find(|s| s.item_id == item_id)?
+            .booster.as_mut()?;
+        life.wear_revealed = true;
+        life.refurbished_on.push(date);
+        Some(life)
+    }
+
+    /// Restart the shelf-life clock of a stored item. False if it's gone.
+    pub fn recertify(&mut self, item_id: InventoryItemId, date: GameDate) -> bool {
+        let slot = self.engines.iter_mut()
+            .find(|e| e.item_id == item_id)
+            .map(|e| (&mut e.built_on, &mut e.expired))
+            .or_else(|| self.stages.iter_mut()
+                .find(|s| s.item_id == item_id)
+                .map(|s| (&mut s.built_on, &mut s.expired)))
+            .or_else(|| self.rockets.iter_mut()
+                .find(|r| r.item_id == item_id)
+                .map(|r| (&mut r.built_on, &mut r.expired)));
+        match slot {
+            Some((built_on, expired)) => {
+                *built_on = Some(date);
+                *expired = false;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Days `engine` hardware keeps in storage before it needs
+/// recertifying: solid grain cracks and debonds, and cryogenic seals
+/// and insulation degrade. Storable-propellant hardware keeps.
+pub fn shelf_life_days(engine: &EngineDesign, cfg: &StorageConfig) -> Option<u32> {
+    if engine.is_solid() {
+        Some(cfg.solid_shelf_life_days)
+    } else if engine.propellant_mix.iter().any(|f| f.propellant.is_cryogenic()) {
+        Some(cfg.cryogenic_shelf_life_days)
+    } else {
+        None
+    }
+}
+
+/// The pseudo-flaw an expired vehicle flies with.
+pub fn aging_flaw(cfg: &StorageConfig) -> crate::flaw::Flaw {
+    use crate::flaw::{Flaw, FlawConsequence, FlawId, FlawTrigger, Subsystem};
+    Flaw {
+        id: FlawId(u64::MAX - 1),
+        description: "Hardware flown past its shelf life".into(),
+        consequence: FlawConsequence::StageLoss,
+        activation_chance: cfg.expired_failure_risk,
+        discovery_probability: 0.0,
+        discovered: true,
+        trigger: FlawTrigger::PerFlight,
+        subsystem: Subsystem::TankStructure,
+        anomaly: None,
+    }
 }
 
 // ── Manufacturing state ──
@@ -461,6 +851,13 @@ pub struct Manufacturing {
     pub inventory: Inventory,
     pub next_order_id: u64,
     pub next_inventory_id: u64,
+    /// Recovered fairings awaiting reuse, per rocket project. A fairing
+    /// only fits later builds of the design it flew on.
+    #[serde(default)]
+    pub recovered_fairings: HashMap<RocketProjectId, u32>,
+    /// Last serial given to a landed booster.
+    #[serde(default)]
+    pub last_booster_serial: u64,
 }
 
 impl Manufacturing {
@@ -471,6 +868,8 @@ impl Manufacturing {
             inventory: Inventory::new(),
             next_order_id: 1,
             next_inventory_id: 1,
+            recovered_fairings: HashMap::new(),
+            last_booster_serial: 0,
         }
     }
 
@@ -488,6 +887,47 @@ impl Manufacturing {
         id
     }
 
+    /// Put a recovered fairing into the pool for `project`.
+    pub fn add_recovered_fairing(&mut self, project: RocketProjectId) {
+        *self.recovered_fairings.entry(project).or_insert(0) += 1;
+    }
+
+    /// Take a recovered fairing for `project` out of the pool, if any.
+    pub fn take_recovered_fairing(&mut self, project: RocketProjectId) -> bool {
+        match self.recovered_fairings.get_mut(&project) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Serial for a booster landing for the first time.
+    pub fn next_booster_serial(&mut self) -> u64 {
+        self.last_booster_serial += 1;
+        self.last_booster_serial
+    }
+
+    /// Whether a landed booster in stock can stand in for a new
+    /// first-group stage `stage_index` of `project`: there's one
+    /// stored, and stages stored or being built outnumber the
+    /// integrations still waiting on one.
+    pub fn has_spare_booster(&self, project: RocketProjectId, stage_index: usize) -> bool {
+        let slot = |pid: RocketProjectId, gi: usize, si: usize| pid == project && gi == 0 && si == stage_index;
+        let landed = self.inventory.stages.iter()
+            .any(|s| s.booster.is_some() && slot(s.rocket_project_id, s.group_index, s.stage_index));
+        let building = self.orders.iter()
+            .filter(|o| matches!(&o.order_type, ManufacturingOrderType::Stage { rocket_project_id, group_index, stage_index, .. }
+                if slot(*rocket_project_id, *group_index, *stage_index)))
+            .count();
+        let waiting = self.orders.iter()
+            .filter(|o| o.waiting_for_prerequisites && matches!(&o.order_type,
+                ManufacturingOrderType::RocketIntegration { rocket_project_id, .. } if *rocket_project_id == project))
+            .count();
+        landed && self.inventory.stage_count(project, 0, stage_index) + building > waiting
+    }
+
     /// Floor space currently in use by active (non-waiting) orders.
     pub fn floor_space_in_use(&self) -> u32 {
         self.orders.iter()
@@ -506,8 +946,19 @@ impl Manufacturing {
         self.orders.iter().map(|o| o.teams_assigned).sum()
     }
 
+    /// Teams of `size` assigned across all orders.
+    pub fn teams_of_size_assigned(&self, size: team::TeamSize) -> u32 {
+        self.orders.iter().map(|o| o.team_mix().of(size)).sum()
+    }
+
     /// Add a team to an order. Returns true if successful.
     pub fn add_team_to_order(&mut self, order_index: usize, available_teams: u32) -> bool {
+        self.add_sized_team_to_order(order_index, team::TeamSize::Medium, available_teams)
+    }
+
+    /// Add a team of `size` to an order, given how many teams of that
+    /// size are free. Returns true if successful.
+    pub fn add_sized_team_to_order(&mut self, order_index: usize, size: team::TeamSize, available_teams: u32) -> bool {
         if available_teams == 0 || order_index >= self.orders.len() {
             return false;
         }
@@ -515,25 +966,39 @@ impl Manufacturing {
         if order.waiting_for_prerequisites {
             return false;
         }
-        order.teams_assigned += 1;
+        order.add_team(size);
         true
     }
 
-    /// Remove a team from an order. Returns true if successful.
+    /// Remove a team from an order, the smallest first. Returns true if
+    /// successful.
     pub fn remove_team_from_order(&mut self, order_index: usize) -> bool {
-        if order_index >= self.orders.len() {
-            return false;
-        }
-        let order = &mut self.orders[order_index];
-        if order.teams_assigned == 0 {
-            return false;
+        self.orders.get_mut(order_index)
+            .is_some_and(|order| order.release_team().is_some())
+    }
+
+    /// Days that pass with nothing finishing: no floor space opens and
+    /// no order completes.
+    pub fn quiet_days(&self, costs: &crate::balance_config::CostsConfig) -> u32 {
+        let floor = self.floor_space.under_construction.iter()
+            .map(|o| o.days_remaining.saturating_sub(1));
+        let orders = self.orders.iter().map(|o| o.quiet_days(costs));
+        floor.chain(orders).min().unwrap_or(u32::MAX)
+    }
+
+    /// `days` days of construction and work at once, for a stretch
+    /// [`Self::quiet_days`] allows.
+    pub fn advance_quiet_days(&mut self, days: u32, costs: &crate::balance_config::CostsConfig) {
+        let opened = self.floor_space.advance_days(days);
+        debug_assert_eq!(opened, 0, "floor space opened in a quiet stretch");
+        for order in &mut self.orders {
+            let done = order.apply_work_days(days, costs);
+            debug_assert!(!done, "order completed in a quiet stretch");
         }
-        order.teams_assigned -= 1;
-        true
     }
 
     /// Process one day of manufacturing work. Returns events.
-    pub fn advance_day(&mut self, costs: &crate::balance_config::CostsConfig) -> Vec<ManufacturingEvent> {
+    pub fn advance_day(&mut self, costs: &crate::balance_config::CostsConfig, date: GameDate) -> Vec<ManufacturingEvent> {
         let mut events = Vec::new();
 
         // Process floor space construction
@@ -553,6 +1018,52 @@ impl Manufacturing {
         // Handle completed orders (in reverse to preserve indices)
         for &i in completed_indices.iter().rev() {
             let order = self.orders.remove(i);
+            // Retrofits, recertifications and inspections rework an existing item
+            // rather than making one. It may have flown or been
+            // consumed meanwhile.
+            match &order.order_type {
+                ManufacturingOrderType::PadAbortTest { rocket_project_id, rocket_name } => {
+                    events.push(ManufacturingEvent::PadAbortTestReady {
+                        order_id: order.id,
+                        rocket_project_id: *rocket_project_id,
+                        rocket_name: rocket_name.clone(),
+                    });
+                    continue;
+                }
+                ManufacturingOrderType::Recertification { item_id, item_name } => {
+                    self.inventory.recertify(*item_id, date);
+                    events.push(ManufacturingEvent::Recertified {
+                        order_id: order.id,
+                        item_name: item_name.clone(),
+                    });
+                    continue;
+                }
+                ManufacturingOrderType::BoosterInspection { item_id, item_name } => {
+                    if self.inventory.inspect_booster(*item_id, date).is_some() {
+                        events.push(ManufacturingEvent::BoosterInspected {
+                            order_id: order.id,
+                            item_id: *item_id,
+                            item_name: item_name.clone(),
+                        });
+                    }
+                    continue;
+                }
+                ManufacturingOrderType::Retrofit { item_id, rocket_name, revision, rocket_flaws, design } => {
+                    if let Some(r) = self.inventory.rockets.iter_mut().find(|r| r.item_id == *item_id) {
+                        r.revision = *revision;
+                        r.rocket_flaws = rocket_flaws.clone();
+                        r.design = Some(design.clone());
+                        r.build_cost += order.material_cost + order.labor_cost;
+                    }
+                    events.push(ManufacturingEvent::Retrofitted {
+                        order_id: order.id,
+                        rocket_name: rocket_name.clone(),
+                        revision: *revision,
+                    });
+                    continue;
+                }
+                _ => {}
+            }
             let item_id = self.next_inventory_id();
 
             // Inventory build_cost is the full attributed cost: this order's
@@ -560,8 +1071,9 @@ impl Manufacturing {
             // children's full build_cost via try_unblock) plus this order's
             // own labor.
             let total_build_cost = order.material_cost + order.labor_cost;
+            let built_on = Some(order.oldest_component.map_or(date, |d| d.min(date)));
             match &order.order_type {
-                ManufacturingOrderType::Engine { source, engine_id, engine_name, revision, flaws, improvements, .. } => {
+                ManufacturingOrderType::Engine { source, engine_id, engine_name, revision, flaw_ids, improvements, .. } => {
                     self.inventory.engines.push(InventoryEngine {
                         item_id,
                         source: *source,
@@ -569,8 +1081,10 @@ impl Manufacturing {
                         engine_name: engine_name.clone(),
                         build_cost: total_build_cost,
                         revision: *revision,
-                        flaws: flaws.clone(),
+                        flaw_ids: flaw_ids.clone(),
                         improvements: improvements.clone(),
+                        built_on,
+                        expired: false,
                     });
                     events.push(ManufacturingEvent::EngineBuilt {
                         order_id: order.id,
@@ -588,6 +1102,9 @@ impl Manufacturing {
                         stage_index: *stage_index,
                         stage_name: stage_name.clone(),
                         build_cost: total_build_cost,
+                        built_on,
+                        expired: false,
+                        booster: None,
                     });
                     events.push(ManufacturingEvent::StageBuilt {
                         order_id: order.id,
@@ -595,7 +1112,7 @@ impl Manufacturing {
                         stage_name: stage_name.clone(),
                     });
                 }
-                ManufacturingOrderType::RocketIntegration { rocket_project_id, design_id, rocket_name, revision, rocket_flaws, .. } => {
+                ManufacturingOrderType::RocketIntegration { rocket_project_id, design_id, rocket_name, revision, rocket_flaws, design, boosters, .. } => {
                     self.inventory.rockets.push(InventoryRocket {
                         item_id,
                         rocket_project_id: *rocket_project_id,
@@ -604,6 +1121,11 @@ impl Manufacturing {
                         build_cost: total_build_cost,
                         revision: *revision,
                         rocket_flaws: rocket_flaws.clone(),
+                        design: design.clone(),
+                        built_on,
+                        expired: false,
+                        propellant_load: Default::default(),
+                        boosters: boosters.clone(),
                     });
                     events.push(ManufacturingEvent::RocketIntegrated {
                         order_id: order.id,
@@ -613,6 +1135,10 @@ impl Manufacturing {
                         build_cost: total_build_cost,
                     });
                 }
+                ManufacturingOrderType::Retrofit { .. }
+                | ManufacturingOrderType::Recertification { .. }
+                | ManufacturingOrderType::BoosterInspection { .. }
+                | ManufacturingOrderType::PadAbortTest { .. } => unreachable!("handled above"),
             }
         }
 
@@ -644,6 +1170,10 @@ impl Manufacturing {
                     // Integration needs all stages — checked by Company layer
                     false // leave blocked, Company will unblock
                 }
+                ManufacturingOrderType::Retrofit { .. }
+                | ManufacturingOrderType::Recertification { .. }
+                | ManufacturingOrderType::BoosterInspection { .. }
+                | ManufacturingOrderType::PadAbortTest { .. } => true,
             };
 
             if can_unblock {
@@ -674,6 +1204,100 @@ impl Manufacturing {
     }
 }
 
+// ── Commonality ──
+
+/// What makes stages common: the lineage their engine descends from
+/// (a scaled variant counts as its parent's) and their tank diameter,
+/// which sets the tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommonalityKey {
+    pub engine_lineage: EngineSource,
+    pub diameter_mm: u32,
+}
+
+impl CommonalityKey {
+    pub fn new(engine_lineage: EngineSource, diameter_m: f64) -> Self {
+        CommonalityKey { engine_lineage, diameter_mm: (diameter_m * 1000.0).round() as u32 }
+    }
+
+    pub fn diameter_m(&self) -> f64 {
+        self.diameter_mm as f64 / 1000.0
+    }
+}
+
+/// One stage of one design, as the analysis sees it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonStage {
+    pub rocket_project_id: RocketProjectId,
+    pub group_index: usize,
+    pub stage_index: usize,
+    pub key: CommonalityKey,
+    pub engine_name: String,
+    /// Times this stage has been built.
+    pub builds: u32,
+}
+
+/// Stages across several designs built as one line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonalityFamily {
+    pub key: CommonalityKey,
+    pub engine_name: String,
+    pub stages: Vec<CommonStage>,
+    /// Builds of every member stage: the learning they share.
+    pub builds: u32,
+}
+
+impl CommonalityFamily {
+    /// Distinct designs in the family.
+    pub fn design_count(&self) -> usize {
+        let mut designs: Vec<RocketProjectId> = self.stages.iter().map(|s| s.rocket_project_id).collect();
+        designs.sort_by_key(|id| id.0);
+        designs.dedup();
+        designs.len()
+    }
+}
+
+/// Which stages of the company's designs share a line. Built on demand
+/// from the designs (see `Company::commonality_analysis`); nothing
+/// here is saved.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommonalityAnalysis {
+    pub families: Vec<CommonalityFamily>,
+}
+
+impl CommonalityAnalysis {
+    /// Group `stages` by key, keeping the groups that span at least
+    /// `min_designs` designs. Families come out in order of first
+    /// appearance.
+    pub fn of(stages: impl IntoIterator<Item = CommonStage>, min_designs: usize) -> Self {
+        let mut families: Vec<CommonalityFamily> = Vec::new();
+        for stage in stages {
+            match families.iter_mut().find(|f| f.key == stage.key) {
+                Some(family) => {
+                    family.builds += stage.builds;
+                    family.stages.push(stage);
+                }
+                None => families.push(CommonalityFamily {
+                    key: stage.key,
+                    engine_name: stage.engine_name.clone(),
+                    builds: stage.builds,
+                    stages: vec![stage],
+                }),
+            }
+        }
+        families.retain(|f| f.design_count() >= min_designs);
+        CommonalityAnalysis { families }
+    }
+
+    /// The family stage (`group_index`, `stage_index`) of the design
+    /// belongs to, if any.
+    pub fn family_of(&self, rocket_project_id: RocketProjectId, group_index: usize, stage_index: usize) -> Option<&CommonalityFamily> {
+        self.families.iter().find(|f| f.stages.iter().any(|s| {
+            s.rocket_project_id == rocket_project_id && s.group_index == group_index && s.stage_index == stage_index
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -761,7 +1385,7 @@ mod tests {
             "Falcon".into(),
             2,
             0,
-            0, Vec::new(),
+            0, Vec::new(), None,
             &bal(),
         );
         assert!(order.work_required > 0.0);
@@ -770,6 +1394,64 @@ mod tests {
         assert_eq!(order.floor_space_used, 2);
     }
 
+    #[test]
+    fn test_engine_orders_from_older_saves_keep_their_flaws() {
+        let flaw = crate::flaw::Flaw {
+            id: crate::flaw::FlawId(7),
+            description: "Turbopump seal leak".into(),
+            consequence: crate::flaw::FlawConsequence::EngineLoss,
+            activation_chance: 0.1,
+            discovery_probability: 0.1,
+            discovered: false,
+            trigger: crate::flaw::FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Turbopump,
+            anomaly: None,
+        };
+        let order = ManufacturingOrder::new_engine(
+            ManufacturingOrderId(1), test_source(), EngineId(1), "Merlin".into(), 500.0, 6,
+            crate::engine_project::PropellantPreset::Kerolox, 0, 0, vec![flaw.id], Vec::new(), &bal(),
+        );
+        let mut json = serde_json::to_value(&order).unwrap();
+        let engine = &mut json["order_type"]["Engine"];
+        let ids = engine.as_object_mut().unwrap().remove("flaw_ids").unwrap();
+        assert_eq!(ids, serde_json::json!([7]));
+        engine["flaws"] = serde_json::to_value(vec![flaw]).unwrap();
+
+        let old: ManufacturingOrder = serde_json::from_value(json.clone()).unwrap();
+        assert!(matches!(&old.order_type, ManufacturingOrderType::Engine { flaw_ids, .. } if flaw_ids == &[crate::flaw::FlawId(7)]));
+        let reloaded: ManufacturingOrder = serde_json::from_value(serde_json::to_value(&old).unwrap()).unwrap();
+        assert!(matches!(&reloaded.order_type, ManufacturingOrderType::Engine { flaw_ids, .. } if flaw_ids == &[crate::flaw::FlawId(7)]));
+        json["order_type"]["Engine"].as_object_mut().unwrap().remove("flaws");
+        let bare: ManufacturingOrder = serde_json::from_value(json).unwrap();
+        assert!(matches!(&bare.order_type, ManufacturingOrderType::Engine { flaw_ids, .. } if flaw_ids.is_empty()));
+    }
+
+    #[test]
+    fn test_commonality_groups_stages_across_designs() {
+        let stage = |project: u64, group_index: usize, engine: u64, diameter_m: f64, builds: u32| CommonStage {
+            rocket_project_id: RocketProjectId(project),
+            group_index,
+            stage_index: 0,
+            key: CommonalityKey::new(EngineSource::PlayerDesign(EngineProjectId(engine)), diameter_m),
+            engine_name: "Kestrel".into(),
+            builds,
+        };
+        let analysis = CommonalityAnalysis::of([
+            stage(1, 0, 1, 3.5, 4),
+            stage(1, 1, 1, 3.5, 4),
+            stage(2, 0, 1, 3.5, 2),
+            stage(2, 1, 2, 3.5, 2),
+            stage(3, 0, 1, 2.0, 7),
+        ], 2);
+        assert_eq!(analysis.families.len(), 1, "a wider tank or another engine is a different line");
+        let family = &analysis.families[0];
+        assert_eq!((family.design_count(), family.stages.len(), family.builds), (2, 3, 10));
+        assert!(analysis.family_of(RocketProjectId(2), 0, 0).is_some());
+        assert!(analysis.family_of(RocketProjectId(2), 1, 0).is_none());
+        assert!(CommonalityAnalysis::of([stage(1, 0, 1, 3.5, 4), stage(1, 1, 1, 3.5, 4)], 2).families.is_empty(),
+            "one design alone isn't a family");
+    }
+
     #[test]
     fn test_learning_curve_reduces_cost() {
         let first = ManufacturingOrder::new_engine(
@@ -808,7 +1490,7 @@ mod tests {
 
         let mut engine_built = false;
         for _ in 0..500 {
-            let events = mfg.advance_day(&costs());
+            let events = mfg.advance_day(&costs(), GameDate::default_start());
             for evt in &events {
                 if matches!(evt, ManufacturingEvent::EngineBuilt { .. }) {
                     engine_built = true;
@@ -830,14 +1512,16 @@ mod tests {
             source: test_source(),
             engine_id: EngineId(1),
             engine_name: "Merlin".into(),
-            build_cost: 0.0, revision: 0, flaws: Vec::new(), improvements: Vec::new(),
+            build_cost: 0.0, revision: 0, flaw_ids: Vec::new(), improvements: Vec::new(),
+            built_on: None, expired: false,
         });
         inv.engines.push(InventoryEngine {
             item_id: InventoryItemId(2),
             source: test_source(),
             engine_id: EngineId(2),
             engine_name: "Merlin".into(),
-            build_cost: 0.0, revision: 0, flaws: Vec::new(), improvements: Vec::new(),
+            build_cost: 0.0, revision: 0, flaw_ids: Vec::new(), improvements: Vec::new(),
+            built_on: None, expired: false,
         });
 
         assert_eq!(inv.engine_count(test_source()), 2);
@@ -890,7 +1574,7 @@ mod tests {
 
         // Advance some days
         for _ in 0..10 {
-            mfg.advance_day(&costs());
+            mfg.advance_day(&costs(), GameDate::default_start());
         }
 
         // Should have made no progress (waiting for prerequisites)
@@ -909,7 +1593,7 @@ mod tests {
         mfg.orders.push(order);
 
         for _ in 0..10 {
-            mfg.advance_day(&costs());
+            mfg.advance_day(&costs(), GameDate::default_start());
         }
 
         assert!(mfg.orders[0].work_completed > 0.0, "Should have made progress");
@@ -932,4 +1616,43 @@ mod tests {
         order.work_completed = order.work_required;
         assert!((order.progress() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_recertification_restarts_shelf_clock() {
+        let mut mfg = Manufacturing::new(&costs());
+        let id = mfg.next_order_id();
+        let mut order = ManufacturingOrder::new_engine(
+            id, test_source(), EngineId(1),
+            "Merlin".into(), 500.0, 6,
+            crate::engine_project::PropellantPreset::Kerolox, 0,
+            0, Vec::new(), Vec::new(),
+            &bal(),
+        );
+        order.work_completed = order.work_required;
+        order.teams_assigned = 1;
+        mfg.orders.push(order);
+        let built = GameDate::default_start();
+        mfg.advance_day(&costs(), built);
+        assert_eq!(mfg.inventory.engines[0].built_on, Some(built));
+
+        let item_id = mfg.inventory.engines[0].item_id;
+        mfg.inventory.engines[0].expired = true;
+        let id = mfg.next_order_id();
+        let mut recert = ManufacturingOrder::new_recertification(
+            id, item_id, "Merlin".into(), mfg.inventory.engines[0].build_cost, &bal().storage,
+        );
+        recert.teams_assigned = 4;
+        mfg.orders.push(recert);
+        let later = built.add_days(2000);
+        let mut done = false;
+        for _ in 0..100 {
+            done |= mfg.advance_day(&costs(), later).iter()
+                .any(|e| matches!(e, ManufacturingEvent::Recertified { .. }));
+            if done { break; }
+        }
+        assert!(done);
+        assert_eq!(mfg.inventory.engines.len(), 1, "refreshes the item, makes no new one");
+        assert_eq!(mfg.inventory.engines[0].built_on, Some(later));
+        assert!(!mfg.inventory.engines[0].expired);
+    }
 }
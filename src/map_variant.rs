@@ -0,0 +1,323 @@
+//! Procedural variants of the delta-v map.
+//!
+//! A world created with `map_variant.enabled` gets its own map, rolled
+//! from the world seed: orbital transfer costs are scaled up or down,
+//! a few extra stations join the graph, and some generated near-Earth
+//! asteroids appear off Earth escape. The cheapest architecture to a
+//! given destination changes from one playthrough to the next.
+//!
+//! A variant is plain data in the shape of a content pack plus a list
+//! of edge scales, so a save carries the exact map it was started
+//! with. Like content, it is global: [`install`] it before anything
+//! touches [`crate::location::DELTA_V_MAP`].
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::MapVariantConfig;
+use crate::content::{
+    ContentPack, LocationDef, LocationKind, SurfaceDef, TransferDef, TransferKind,
+};
+use crate::location::{self, DeltaVMap, AERO_DRAG_REFERENCE_MASS_KG};
+use crate::seed::GameSeed;
+
+/// One world's changes to the base map.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapVariant {
+    /// World seed the variant was rolled from (informational).
+    pub seed: u64,
+    /// Cost factors for existing edges, applied to both directions.
+    pub scales: Vec<EdgeScale>,
+    pub locations: Vec<LocationDef>,
+    pub transfers: Vec<TransferDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeScale {
+    pub from: String,
+    pub to: String,
+    pub factor: f64,
+}
+
+/// A station the generator may add, with the edges that tie it in.
+struct StationSpec {
+    id: &'static str,
+    display_name: &'static str,
+    short_name: &'static str,
+    parent_body: &'static str,
+    kind: LocationKind,
+    /// (neighbor, delta-v, transit days); all spiral edges.
+    links: &'static [(&'static str, f64, u32)],
+}
+
+const STATIONS: &[StationSpec] = &[
+    StationSpec {
+        id: "heo", display_name: "High Earth Orbit", short_name: "HEO",
+        parent_body: "earth", kind: LocationKind::Orbit,
+        links: &[("leo", 2900.0, 1), ("earth_escape", 450.0, 2)],
+    },
+    StationSpec {
+        id: "nrho", display_name: "Lunar Halo Orbit", short_name: "NRHO",
+        parent_body: "moon", kind: LocationKind::Orbit,
+        links: &[("l2", 100.0, 3), ("lunar_orbit", 730.0, 3)],
+    },
+    StationSpec {
+        id: "sun_earth_l1", display_name: "Sun-Earth L1", short_name: "SEL1",
+        parent_body: "earth", kind: LocationKind::LagrangePoint,
+        links: &[("earth_escape", 150.0, 25), ("leo", 3200.0, 30)],
+    },
+    StationSpec {
+        id: "sun_earth_l2", display_name: "Sun-Earth L2", short_name: "SEL2",
+        parent_body: "earth", kind: LocationKind::LagrangePoint,
+        links: &[("earth_escape", 150.0, 30), ("l2", 350.0, 20)],
+    },
+];
+
+impl MapVariant {
+    /// Roll `seed`'s variant of `base` (the built-in map plus content;
+    /// see [`location::base_map`]). Deterministic: the same seed, base
+    /// and config always give the same variant.
+    pub fn generate(seed: &GameSeed, base: &DeltaVMap, cfg: &MapVariantConfig) -> Self {
+        let mut variant = MapVariant { seed: seed.seed(), ..Default::default() };
+        let spread = cfg.transfer_spread.clamp(0.0, 0.9);
+        let roll = |rng: &mut rand::rngs::StdRng| 1.0 + spread * rng.gen_range(-1.0..=1.0);
+
+        // Orbital edges only: launches and landings stay physical.
+        let mut seen = HashSet::new();
+        for i in 0..base.location_count() {
+            let Some(loc) = base.location_at(i) else { continue };
+            for t in base.transfers_from(loc.id) {
+                let ground = base.surface_properties(t.from).is_some()
+                    || base.surface_properties(t.to).is_some();
+                let (a, b) = if t.from < t.to { (t.from, t.to) } else { (t.to, t.from) };
+                if ground || !seen.insert((a, b)) {
+                    continue;
+                }
+                let mut rng = seed.world_query(&format!("map_variant.scale.{a}.{b}"));
+                // Four decimals keep saves readable and round-trip exactly.
+                let factor = (roll(&mut rng) * 1e4).round() / 1e4;
+                variant.scales.push(EdgeScale { from: a.into(), to: b.into(), factor });
+            }
+        }
+
+        let mut rng = seed.world_query("map_variant.stations");
+        let mut candidates: Vec<&StationSpec> = STATIONS.iter()
+            .filter(|s| base.location(s.id).is_none())
+            .filter(|s| s.links.iter().all(|(n, _, _)| base.location(n).is_some()))
+            .collect();
+        candidates.shuffle(&mut rng);
+        let count = rng.gen_range(0..=cfg.max_stations as usize).min(candidates.len());
+        for s in &candidates[..count] {
+            variant.locations.push(LocationDef {
+                id: s.id.into(),
+                display_name: s.display_name.into(),
+                short_name: s.short_name.into(),
+                parent_body: s.parent_body.into(),
+                kind: s.kind,
+                surface: None,
+            });
+            for &(neighbor, dv, days) in s.links {
+                variant.transfers.push(TransferDef {
+                    from: s.id.into(),
+                    to: neighbor.into(),
+                    delta_v: (dv * roll(&mut rng)).round(),
+                    transit_days: days,
+                    kind: TransferKind::Spiral,
+                    low_thrust_delta_v: None,
+                });
+            }
+        }
+
+        if cfg.max_asteroids > 0 && base.location("earth_escape").is_some() {
+            let mut rng = seed.world_query("map_variant.asteroids");
+            let count = rng.gen_range(1..=cfg.max_asteroids);
+            for _ in 0..count {
+                variant.add_asteroid(&mut rng, base);
+            }
+        }
+        variant
+    }
+
+    /// A generated NEA: transfer → capture → orbit → surface off Earth
+    /// escape, built like the Eros and Bennu branches.
+    fn add_asteroid(&mut self, rng: &mut rand::rngs::StdRng, base: &DeltaVMap) {
+        const LETTERS: &[u8] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+        let (designation, body) = loop {
+            let year = rng.gen_range(1990..2020);
+            let a = LETTERS[rng.gen_range(0..LETTERS.len())] as char;
+            let b = LETTERS[rng.gen_range(0..LETTERS.len())] as char;
+            let n = rng.gen_range(1..100);
+            let tag = format!("{a}{b}{n}");
+            let body = format!("nea_{year}{}", tag.to_lowercase());
+            let taken = base.location(&format!("{body}_transfer")).is_some()
+                || self.locations.iter().any(|l| l.parent_body == body);
+            if !taken {
+                break ((year, tag), body);
+            }
+        };
+        let (year, tag) = designation;
+        let gravity = (rng.gen_range(0.00005..0.006_f64) * 1e6).round() / 1e6;
+        let radius = rng.gen_range(200.0..15_000.0_f64).round();
+        let escape_dv = rng.gen_range(300.0..1500.0_f64).round();
+        let capture_dv = rng.gen_range(10.0..60.0_f64).round();
+        let landing_dv = rng.gen_range(2.0..12.0_f64).round();
+        let transfer_days = rng.gen_range(100..300);
+
+        let node = |suffix: &str, display: &str, short: &str, parent: &str, kind| LocationDef {
+            id: format!("{body}_{suffix}"),
+            display_name: format!("{year} {tag} {display}"),
+            short_name: short.into(),
+            parent_body: parent.into(),
+            kind,
+            surface: None,
+        };
+        self.locations.push(node("transfer", "Transfer", &format!("{tag}T"), "sun", LocationKind::Orbit));
+        self.locations.push(node("capture", "Capture", &format!("{tag}C"), &body, LocationKind::Orbit));
+        self.locations.push(node("orbit", "Orbit", &format!("{tag}O"), &body, LocationKind::Orbit));
+        self.locations.push(LocationDef {
+            surface: Some(SurfaceDef {
+                gravity_m_s2: gravity,
+                radius_m: radius,
+                atmosphere_density: 0.0,
+                ambient_pressure_pa: 0.0,
+            }),
+            ..node("surface", "Surface", &tag, &body, LocationKind::Surface)
+        });
+
+        let edge = |from: String, to: String, dv: f64, lt: Option<f64>, days, kind| TransferDef {
+            from, to, delta_v: dv, transit_days: days, kind, low_thrust_delta_v: lt,
+        };
+        self.transfers.push(edge(
+            "earth_escape".into(), format!("{body}_transfer"),
+            escape_dv, Some(escape_dv * 1.5), transfer_days, TransferKind::Spiral,
+        ));
+        self.transfers.push(edge(
+            format!("{body}_transfer"), format!("{body}_capture"),
+            capture_dv, Some(capture_dv * 1.5), 30, TransferKind::Spiral,
+        ));
+        self.transfers.push(edge(
+            format!("{body}_capture"), format!("{body}_orbit"), 5.0, None, 0, TransferKind::Spiral,
+        ));
+        // Only the faintest gravity lets ion drives land (cf. Bennu).
+        let lt_landing = (gravity < 0.0002).then_some(landing_dv);
+        self.transfers.push(edge(
+            format!("{body}_surface"), format!("{body}_orbit"),
+            landing_dv, lt_landing, 0, TransferKind::Ground,
+        ));
+    }
+
+    /// The variant's additions as a content pack.
+    fn additions(&self) -> ContentPack {
+        ContentPack {
+            locations: self.locations.clone(),
+            transfers: self.transfers.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Scale `map`'s edges, then add the variant's nodes and edges.
+    pub fn apply_to_map(&self, map: &mut DeltaVMap) {
+        for s in &self.scales {
+            map.scale_pair(&s.from, &s.to, s.factor);
+        }
+        self.additions().apply_to_map(map);
+    }
+
+    /// Check the variant against the map it will be applied to: every
+    /// scale names an existing edge, the additions pass content
+    /// validation, and every node of the result can still be reached
+    /// from Earth's surface and return to low Earth orbit.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut map = location::base_map();
+        for s in &self.scales {
+            if map.transfer(&s.from, &s.to).is_none() {
+                return Err(format!("scaled edge {} → {} doesn't exist", s.from, s.to));
+            }
+            if !(s.factor.is_finite() && s.factor > 0.0) {
+                return Err(format!("edge {} → {}: scale {} must be positive", s.from, s.to, s.factor));
+            }
+        }
+        self.additions().validate(&Default::default())?;
+        self.apply_to_map(&mut map);
+        for i in 0..map.location_count() {
+            let Some(id) = map.location_at(i).map(|l| l.id) else { continue };
+            let there = map.shortest_path("earth_surface", id, AERO_DRAG_REFERENCE_MASS_KG).is_some();
+            let back = id == "suborbital" || map.shortest_path(id, "leo", AERO_DRAG_REFERENCE_MASS_KG).is_some();
+            if !(there && back) {
+                return Err(format!("location `{id}` is cut off from Earth"));
+            }
+        }
+        Ok(())
+    }
+}
+
+static INSTALLED: OnceLock<MapVariant> = OnceLock::new();
+
+/// Make `variant` the process-wide map variant. Fails if one is
+/// already installed, the delta-v map has already been built, or the
+/// variant doesn't validate.
+pub fn install(variant: MapVariant) -> Result<(), String> {
+    if location::delta_v_map_built() {
+        return Err("a map variant must be installed before the delta-v map is first used".into());
+    }
+    variant.validate()?;
+    INSTALLED.set(variant).map_err(|_| "a map variant is already installed".to_string())
+}
+
+/// The installed map variant, if any.
+pub fn installed() -> Option<&'static MapVariant> {
+    INSTALLED.get()
+}
+
+/// Roll and install the variant a new world on `seed_value` will use,
+/// if `cfg` asks for one. Call before creating the `GameState`.
+pub fn prepare_new_world(seed_value: u64, cfg: &MapVariantConfig) -> Result<(), String> {
+    if !cfg.enabled {
+        return Ok(());
+    }
+    install(MapVariant::generate(&GameSeed::new(seed_value), &location::base_map(), cfg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variants_are_deterministic_connected_and_serializable() {
+        let cfg = MapVariantConfig { enabled: true, ..Default::default() };
+        let base = DeltaVMap::earth_moon();
+        let mut differ = false;
+        for seed in 1..=12 {
+            let variant = MapVariant::generate(&GameSeed::new(seed), &base, &cfg);
+            assert_eq!(variant, MapVariant::generate(&GameSeed::new(seed), &base, &cfg));
+            variant.validate().unwrap_or_else(|e| panic!("seed {seed}: {e}"));
+            assert!(!variant.locations.is_empty(), "at least one asteroid");
+            assert!(variant.scales.iter().all(|s| (0.85..=1.15).contains(&s.factor)));
+            // Launch legs keep their physics.
+            assert!(!variant.scales.iter().any(|s| s.from == "earth_surface" || s.to == "earth_surface"));
+
+            let json = serde_json::to_string(&variant).unwrap();
+            let back: MapVariant = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, variant);
+
+            let mut map = DeltaVMap::earth_moon();
+            variant.apply_to_map(&mut map);
+            assert_eq!(map.location_count(), 50 + variant.locations.len());
+            let leo_gto = variant.scales.iter().find(|s| s.from == "gto" && s.to == "leo").unwrap();
+            let dv = map.transfer("leo", "gto").unwrap().delta_v;
+            assert!((dv - 2440.0 * leo_gto.factor).abs() < 1e-6);
+            differ |= variant != MapVariant::generate(&GameSeed::new(1), &base, &cfg);
+        }
+        assert!(differ, "different seeds should give different maps");
+
+        // A variant that strands a node is rejected.
+        let mut broken = MapVariant::generate(&GameSeed::new(3), &base, &cfg);
+        broken.transfers.retain(|t| !t.from.ends_with("_transfer") && t.from != "earth_escape");
+        assert!(broken.validate().is_err());
+    }
+}
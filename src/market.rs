@@ -0,0 +1,201 @@
+//! The world market every company trades in. Launch prices, propellant,
+//! third-party engines and customer demand are shared: the player's
+//! and competitors' activity all feed one record, and each day's prices
+//! drift toward what that activity supports. A busy launch market gets
+//! cheaper to buy launches in and draws more customers; heavy flying
+//! bids propellant up; a run on vendor engines raises their price.
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::WorldMarketConfig;
+
+/// Prices and demand relative to a quiet baseline world (1.0).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PriceIndices {
+    /// Multiplier on what customers pay for a launch.
+    pub launch: f64,
+    /// Multiplier on what propellant costs.
+    pub propellant: f64,
+    /// Multiplier on third-party engine prices.
+    pub engines: f64,
+    /// Multiplier on how many customers are shopping.
+    pub demand: f64,
+}
+
+impl Default for PriceIndices {
+    fn default() -> Self {
+        PriceIndices { launch: 1.0, propellant: 1.0, engines: 1.0, demand: 1.0 }
+    }
+}
+
+/// Aggregate activity across every company, and the prices it sets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldMarket {
+    pub prices: PriceIndices,
+    /// Launches and propellant flown so far this month.
+    pub month_launches: u32,
+    pub month_propellant_kg: f64,
+    /// Vendor engines bought by every company, all time; the month's
+    /// purchases are the rise since the last month start.
+    pub engines_bought: u32,
+    pub engines_bought_at_month_start: u32,
+    /// Monthly activity averaged over `WorldMarketConfig::memory_months`.
+    /// None until the first month closes: a new world starts at its
+    /// baseline.
+    pub launch_rate: Option<f64>,
+    pub propellant_rate: Option<f64>,
+    pub engine_rate: Option<f64>,
+    /// Months folded into the averages so far.
+    pub months_recorded: u32,
+}
+
+impl Default for WorldMarket {
+    fn default() -> Self {
+        WorldMarket {
+            prices: PriceIndices::default(),
+            month_launches: 0,
+            month_propellant_kg: 0.0,
+            engines_bought: 0,
+            engines_bought_at_month_start: 0,
+            launch_rate: None,
+            propellant_rate: None,
+            engine_rate: None,
+            months_recorded: 0,
+        }
+    }
+}
+
+impl WorldMarket {
+    /// Any company flew a launch loaded with `propellant_kg`.
+    pub fn record_launch(&mut self, propellant_kg: f64) {
+        self.month_launches += 1;
+        self.month_propellant_kg += propellant_kg;
+    }
+
+    /// The day's tick, before any company acts. `engines_bought` is
+    /// every company's all-time vendor engine purchases; on the first
+    /// of the month the month's activity folds into the averages.
+    /// Returns the engine index's change as a ratio, for re-pricing
+    /// engines already on offer.
+    pub fn tick_day(&mut self, new_month: bool, engines_bought: u32, cfg: &WorldMarketConfig) -> f64 {
+        self.engines_bought = engines_bought;
+        if new_month {
+            let keep = 1.0 - 1.0 / cfg.memory_months.max(1.0);
+            let fold = |rate: Option<f64>, baseline: f64, month: f64| {
+                Some(rate.unwrap_or(baseline) * keep + month * (1.0 - keep))
+            };
+            let month_engines = engines_bought.saturating_sub(self.engines_bought_at_month_start);
+            self.launch_rate = fold(self.launch_rate, cfg.baseline_launches_per_month, self.month_launches as f64);
+            self.propellant_rate = fold(
+                self.propellant_rate, cfg.baseline_propellant_kg_per_month, self.month_propellant_kg,
+            );
+            self.engine_rate = fold(self.engine_rate, cfg.baseline_engines_per_month, month_engines as f64);
+            self.month_launches = 0;
+            self.month_propellant_kg = 0.0;
+            self.engines_bought_at_month_start = engines_bought;
+            self.months_recorded += 1;
+        }
+        // Prices hold while a new world's market finds its feet.
+        if self.months_recorded <= cfg.settling_months {
+            return 1.0;
+        }
+
+        let target = self.target_prices(cfg);
+        let ease = |now: f64, target: f64| now + (target - now) * cfg.daily_price_adjustment;
+        let engines_before = self.prices.engines;
+        self.prices = PriceIndices {
+            launch: ease(self.prices.launch, target.launch),
+            propellant: ease(self.prices.propellant, target.propellant),
+            engines: ease(self.prices.engines, target.engines),
+            demand: ease(self.prices.demand, target.demand),
+        };
+        self.prices.engines / engines_before
+    }
+
+    /// `days` mid-month days of [`Self::tick_day`] at once: prices ease
+    /// toward a target that holds until the month closes. Returns the
+    /// engine index's change over the stretch, as a ratio.
+    pub fn ease_days(&mut self, days: u32, engines_bought: u32, cfg: &WorldMarketConfig) -> f64 {
+        self.engines_bought = engines_bought;
+        if self.months_recorded <= cfg.settling_months {
+            return 1.0;
+        }
+        let target = self.target_prices(cfg);
+        let keep = (1.0 - cfg.daily_price_adjustment).powi(days as i32);
+        let ease = |now: f64, target: f64| target + (now - target) * keep;
+        let engines_before = self.prices.engines;
+        self.prices = PriceIndices {
+            launch: ease(self.prices.launch, target.launch),
+            propellant: ease(self.prices.propellant, target.propellant),
+            engines: ease(self.prices.engines, target.engines),
+            demand: ease(self.prices.demand, target.demand),
+        };
+        self.prices.engines / engines_before
+    }
+
+    /// Where prices settle for the activity averaged so far.
+    pub fn target_prices(&self, cfg: &WorldMarketConfig) -> PriceIndices {
+        // Activity against the baseline: 0 = quiet, +1 = twice as busy.
+        let busy = |rate: Option<f64>, baseline: f64| {
+            rate.map_or(0.0, |r| if baseline > 0.0 { r / baseline - 1.0 } else { 0.0 })
+        };
+        let clamp = |index: f64| index.clamp(cfg.min_index, cfg.max_index);
+        let launches = busy(self.launch_rate, cfg.baseline_launches_per_month);
+        PriceIndices {
+            launch: clamp(1.0 - cfg.launch_price_elasticity * launches),
+            propellant: clamp(1.0 + cfg.propellant_price_elasticity
+                * busy(self.propellant_rate, cfg.baseline_propellant_kg_per_month)),
+            engines: clamp(1.0 + cfg.engine_price_elasticity
+                * busy(self.engine_rate, cfg.baseline_engines_per_month)),
+            demand: clamp(1.0 + cfg.demand_elasticity * launches),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_world_cheapens_launches_and_bids_up_inputs() {
+        let cfg = WorldMarketConfig::default();
+        let mut market = WorldMarket::default();
+        for _ in 0..30 {
+            market.tick_day(false, 0, &cfg);
+        }
+        assert_eq!(market.prices, PriceIndices::default(), "a new world sits at its baseline");
+        for _ in 0..cfg.settling_months {
+            market.tick_day(true, 0, &cfg);
+        }
+        assert_eq!(market.prices, PriceIndices::default(), "and holds there while it settles");
+
+        let mut bought = 0;
+        for _ in 0..24 {
+            for _ in 0..(cfg.baseline_launches_per_month * 4.0) as u32 {
+                market.record_launch(cfg.baseline_propellant_kg_per_month);
+            }
+            bought += (cfg.baseline_engines_per_month * 4.0) as u32;
+            market.tick_day(true, bought, &cfg);
+            for _ in 1..30 {
+                market.tick_day(false, bought, &cfg);
+            }
+        }
+        assert!(market.prices.launch < 1.0);
+        assert!(market.prices.demand > 1.0);
+        assert!(market.prices.propellant > 1.0);
+        assert!(market.prices.engines > 1.0);
+        assert!(market.prices.launch >= cfg.min_index && market.prices.propellant <= cfg.max_index);
+
+        // A dead world drifts the other way.
+        for _ in 0..48 {
+            market.tick_day(true, bought, &cfg);
+            for _ in 1..30 {
+                market.tick_day(false, bought, &cfg);
+            }
+        }
+        assert!(market.prices.launch > 1.0);
+        assert!(market.prices.propellant < 1.0);
+        assert!(market.prices.engines < 1.0);
+    }
+}
@@ -0,0 +1,124 @@
+//! Market evolution. Customers' payloads grow heavier and the price
+//! they expect to pay per kg drifts as the years of a game pass, so a
+//! design sized for the opening market falls behind it. Contract
+//! generation scales each destination's template by the trend for the
+//! current date; the market report projects each destination a few
+//! years out against what the company's designs can lift.
+
+use crate::balance_config::MarketTrendConfig;
+use crate::contract::{MarketDestination, MarketId};
+
+/// Multipliers on a market destination's template at one point in a
+/// game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketTrend {
+    pub payload_mult: f64,
+    pub rate_per_kg_mult: f64,
+}
+
+impl Default for MarketTrend {
+    fn default() -> Self {
+        MarketTrend { payload_mult: 1.0, rate_per_kg_mult: 1.0 }
+    }
+}
+
+impl MarketTrend {
+    /// The trend `years` into the game.
+    pub fn at(years: f64, cfg: &MarketTrendConfig) -> Self {
+        let years = years.max(0.0);
+        MarketTrend {
+            payload_mult: (1.0 + cfg.payload_growth_per_year).max(0.0).powf(years),
+            rate_per_kg_mult: (1.0 + cfg.rate_per_kg_change_per_year).max(0.0).powf(years),
+        }
+    }
+
+    /// `dest`'s payload range under this trend.
+    pub fn payload_range(&self, dest: &MarketDestination) -> (f64, f64) {
+        (dest.min_payload_kg * self.payload_mult, dest.max_payload_kg * self.payload_mult)
+    }
+
+    /// `dest`'s reference price per kg under this trend.
+    pub fn rate_per_kg(&self, dest: &MarketDestination) -> f64 {
+        dest.rate_per_kg * self.rate_per_kg_mult
+    }
+}
+
+/// Share of a destination's contracts, payloads drawn uniformly from
+/// `range`, that a vehicle lifting `capability_kg` can fly.
+pub fn share_covered(range: (f64, f64), capability_kg: f64) -> f64 {
+    let (min, max) = range;
+    if capability_kg >= max {
+        1.0
+    } else if capability_kg < min || max <= min {
+        0.0
+    } else {
+        (capability_kg - min) / (max - min)
+    }
+}
+
+/// One market destination today and `MarketReport::years_ahead` from
+/// now.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DestinationOutlook {
+    pub market_id: MarketId,
+    pub market_name: String,
+    pub location_id: String,
+    pub display_name: String,
+    pub payload_now_kg: (f64, f64),
+    pub payload_then_kg: (f64, f64),
+    /// Reference price per kg, before economy and market modifiers.
+    pub rate_per_kg_now: f64,
+    pub rate_per_kg_then: f64,
+    /// Most any of the company's flight-ready designs can put there.
+    pub capability_kg: f64,
+    /// How saturated the segment is by every company's deliveries.
+    pub demand: crate::demand::SegmentOutlook,
+}
+
+impl DestinationOutlook {
+    pub fn covered_now(&self) -> f64 {
+        share_covered(self.payload_now_kg, self.capability_kg)
+    }
+
+    pub fn covered_then(&self) -> f64 {
+        share_covered(self.payload_then_kg, self.capability_kg)
+    }
+}
+
+/// What each active market will want a few years from now.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketReport {
+    pub years_ahead: u32,
+    pub outlooks: Vec<DestinationOutlook>,
+    /// Today's shared launch, propellant and engine prices.
+    pub world: crate::market::PriceIndices,
+}
+
+impl MarketReport {
+    /// Destinations the fleet serves today but will serve less of.
+    pub fn falling_behind(&self) -> impl Iterator<Item = &DestinationOutlook> {
+        self.outlooks.iter().filter(|o| o.covered_now() > 0.0 && o.covered_then() < o.covered_now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_compounds_and_coverage_shrinks_as_payloads_grow() {
+        let cfg = MarketTrendConfig {
+            payload_growth_per_year: 0.10,
+            rate_per_kg_change_per_year: -0.10,
+            report_years: 3,
+        };
+        assert_eq!(MarketTrend::at(0.0, &cfg), MarketTrend::default());
+        let trend = MarketTrend::at(2.0, &cfg);
+        assert!((trend.payload_mult - 1.21).abs() < 1e-9);
+        assert!((trend.rate_per_kg_mult - 0.81).abs() < 1e-9);
+
+        assert_eq!(share_covered((1_000.0, 3_000.0), 3_000.0), 1.0);
+        assert_eq!(share_covered((1_000.0, 3_000.0), 2_000.0), 0.5);
+        assert_eq!(share_covered((1_000.0, 3_000.0), 500.0), 0.0);
+    }
+}
@@ -0,0 +1,206 @@
+//! Marketing: PR campaigns, press events and media sentiment.
+//!
+//! Money spent on publicity buys fame: a PR campaign lifts media
+//! sentiment while it runs and sweetens every success, and a press
+//! briefing or livestream scheduled for a specific rocket multiplies
+//! the fame from that launch — and the damage if it blows up on
+//! camera. Sentiment drifts back toward neutral on its own and feeds
+//! the monthly contract draw as a rate modifier on every market.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::MarketingConfig;
+use crate::calendar::GameDate;
+use crate::manufacturing::InventoryItemId;
+
+/// Monthly sentiment samples kept for the trend readout.
+const SENTIMENT_HISTORY_MONTHS: usize = 36;
+
+/// How loudly a launch is publicized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PressKind {
+    /// Reporters at the site and a briefing afterwards.
+    Briefing,
+    /// The whole launch streamed live.
+    Livestream,
+}
+
+impl fmt::Display for PressKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PressKind::Briefing => write!(f, "press briefing"),
+            PressKind::Livestream => write!(f, "livestream"),
+        }
+    }
+}
+
+/// Publicity booked for one rocket's launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PressEvent {
+    pub rocket_item_id: InventoryItemId,
+    pub rocket_name: String,
+    pub kind: PressKind,
+}
+
+/// The company's publicity state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Marketing {
+    /// Media sentiment, -1 (hostile) to 1 (adoring); 0 is neutral.
+    pub sentiment: f64,
+    /// Days left on the running PR campaign (0 = none).
+    pub pr_days_remaining: u32,
+    pub press_events: Vec<PressEvent>,
+    /// (first of month, sentiment), oldest first.
+    pub sentiment_history: Vec<(GameDate, f64)>,
+    /// A press crisis waiting on the company's answer.
+    pub crisis: Option<crate::pad_failure::PressCrisis>,
+}
+
+/// Why a marketing purchase was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarketingError {
+    NoSuchRocket,
+    AlreadyScheduled,
+    CantAfford { cost: f64 },
+    NoCrisis,
+}
+
+impl fmt::Display for MarketingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarketingError::NoSuchRocket => write!(f, "No rocket in inventory selected"),
+            MarketingError::AlreadyScheduled => write!(f, "That launch already has press booked"),
+            MarketingError::CantAfford { cost } =>
+                write!(f, "That costs {}", crate::resources::format_money(*cost)),
+            MarketingError::NoCrisis => write!(f, "No press crisis to answer"),
+        }
+    }
+}
+
+impl Marketing {
+    pub fn pr_active(&self) -> bool {
+        self.pr_days_remaining > 0
+    }
+
+    pub fn press_event(&self, item_id: InventoryItemId) -> Option<&PressEvent> {
+        self.press_events.iter().find(|p| p.rocket_item_id == item_id)
+    }
+
+    /// Remove and return the press booked for `item_id`; called as it
+    /// launches.
+    pub fn take_press_event(&mut self, item_id: InventoryItemId) -> Option<PressEvent> {
+        let idx = self.press_events.iter().position(|p| p.rocket_item_id == item_id)?;
+        Some(self.press_events.remove(idx))
+    }
+
+    /// One day: sentiment relaxes toward neutral, and a running PR
+    /// campaign pushes it up.
+    pub fn tick_day(&mut self, cfg: &MarketingConfig) {
+        self.sentiment *= 1.0 - cfg.sentiment_decay_per_day;
+        if self.pr_days_remaining > 0 {
+            self.pr_days_remaining -= 1;
+            self.sentiment += cfg.pr_sentiment_per_day;
+        }
+        self.sentiment = self.sentiment.clamp(-1.0, 1.0);
+    }
+
+    /// `days` days of sentiment fading with no PR push running.
+    pub fn fade_days(&mut self, days: u32, cfg: &MarketingConfig) {
+        debug_assert_eq!(self.pr_days_remaining, 0);
+        self.sentiment *= (1.0 - cfg.sentiment_decay_per_day).powi(days as i32);
+    }
+
+    /// Sample sentiment for the trend readout.
+    pub fn record_month(&mut self, date: GameDate) {
+        self.sentiment_history.push((date, self.sentiment));
+        if self.sentiment_history.len() > SENTIMENT_HISTORY_MONTHS {
+            self.sentiment_history.remove(0);
+        }
+    }
+
+    /// Fame multiplier on a successful launch with `press` booked.
+    pub fn fame_multiplier(&self, press: Option<PressKind>, cfg: &MarketingConfig) -> f64 {
+        let press_mult = match press {
+            None => 1.0,
+            Some(PressKind::Briefing) => cfg.briefing_success_mult,
+            Some(PressKind::Livestream) => cfg.livestream_success_mult,
+        };
+        let pr_mult = if self.pr_active() { cfg.pr_success_mult } else { 1.0 };
+        press_mult * pr_mult
+    }
+
+    /// Multiplier on the reputation penalty for a failure with `press`
+    /// booked.
+    pub fn failure_multiplier(press: Option<PressKind>, cfg: &MarketingConfig) -> f64 {
+        match press {
+            None => 1.0,
+            Some(PressKind::Briefing) => cfg.briefing_failure_mult,
+            Some(PressKind::Livestream) => cfg.livestream_failure_mult,
+        }
+    }
+
+    /// Move sentiment for a launch outcome, scaled by how many people
+    /// were watching. Unpublicized successes don't make the news;
+    /// failures always do.
+    pub fn on_launch(&mut self, press: Option<PressKind>, success: bool, cfg: &MarketingConfig) {
+        let reach = match press {
+            None => 1.0,
+            Some(PressKind::Briefing) => cfg.briefing_reach,
+            Some(PressKind::Livestream) => cfg.livestream_reach,
+        };
+        let delta = match (press, success) {
+            (None, true) => 0.0,
+            (Some(_), true) => cfg.success_sentiment * reach,
+            (_, false) => cfg.failure_sentiment * reach,
+        };
+        self.sentiment = (self.sentiment + delta).clamp(-1.0, 1.0);
+    }
+
+    /// Move sentiment by `delta`, within its bounds.
+    pub fn shift_sentiment(&mut self, delta: f64) {
+        self.sentiment = (self.sentiment + delta).clamp(-1.0, 1.0);
+    }
+
+    /// Rate multiplier sentiment puts on newly generated contracts.
+    pub fn contract_rate_mult(&self, cfg: &MarketingConfig) -> f64 {
+        1.0 + self.sentiment * cfg.contract_rate_swing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_press_multiplies_fame_and_failures_and_sentiment_relaxes() {
+        let cfg = MarketingConfig::default();
+        let mut m = Marketing::default();
+        assert_eq!(m.fame_multiplier(None, &cfg), 1.0);
+        assert!(m.fame_multiplier(Some(PressKind::Livestream), &cfg)
+            > m.fame_multiplier(Some(PressKind::Briefing), &cfg));
+        assert!(Marketing::failure_multiplier(Some(PressKind::Livestream), &cfg) > 1.0);
+
+        m.pr_days_remaining = 10;
+        let with_pr = m.fame_multiplier(Some(PressKind::Briefing), &cfg);
+        assert!((with_pr - cfg.briefing_success_mult * cfg.pr_success_mult).abs() < 1e-9);
+        for _ in 0..10 {
+            m.tick_day(&cfg);
+        }
+        assert!(!m.pr_active());
+        let after_pr = m.sentiment;
+        assert!(after_pr > 0.0);
+        assert!(m.contract_rate_mult(&cfg) > 1.0);
+
+        m.on_launch(Some(PressKind::Livestream), false, &cfg);
+        assert!(m.sentiment < 0.0, "a failure on camera wipes out a campaign");
+        let low = m.sentiment;
+        for _ in 0..100 {
+            m.tick_day(&cfg);
+        }
+        assert!(m.sentiment > low && m.sentiment < 0.0);
+        assert!(m.contract_rate_mult(&cfg) < 1.0);
+    }
+}
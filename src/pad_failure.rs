@@ -0,0 +1,78 @@
+//! Catastrophic pad failures. A heavy rocket that blows up at ignition
+//! or liftoff with its tanks full doesn't just lose the vehicle: the
+//! pad goes with it. What follows is an arc across several systems —
+//! a long rebuild with the pad closed, a regulatory stand-down while the
+//! mishap is investigated, a press crisis the company has a few days to
+//! answer, and an insurance claim if the pad was covered.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::PadFailureConfig;
+use crate::calendar::GameDate;
+use crate::flaw::{FlawConsequence, Subsystem};
+use crate::launch::FlawActivation;
+
+/// Whether a failed launch took the pad with it: a stage lost to an
+/// engine-start or main-engine fault (on or just off the pad) with at
+/// least `PadFailureConfig::heavy_propellant_kg` loaded.
+pub fn destroys_pad(activations: &[FlawActivation], loaded_propellant_kg: f64, cfg: &PadFailureConfig) -> bool {
+    loaded_propellant_kg >= cfg.heavy_propellant_kg
+        && activations.iter().any(|a| {
+            matches!(a.consequence, FlawConsequence::StageLoss)
+                && matches!(a.subsystem, Subsystem::Turbopump | Subsystem::Combustion)
+        })
+}
+
+/// How the company answers the press after a pad disaster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrisisResponse {
+    /// Open the investigation to reporters: costly, but wins back
+    /// sentiment and some reputation.
+    Transparent,
+    /// Talk it down: cheap and quick, but it may backfire.
+    Spin,
+}
+
+impl fmt::Display for CrisisResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrisisResponse::Transparent => write!(f, "transparent briefing"),
+            CrisisResponse::Spin => write!(f, "spin"),
+        }
+    }
+}
+
+/// A press crisis awaiting the company's answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PressCrisis {
+    pub rocket_name: String,
+    /// Past this day, silence is the answer.
+    pub respond_by: GameDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage_loss(subsystem: Subsystem) -> FlawActivation {
+        FlawActivation {
+            flaw_description: "test".into(),
+            consequence: FlawConsequence::StageLoss,
+            engine_name: "E".into(),
+            subsystem,
+        }
+    }
+
+    #[test]
+    fn test_only_heavy_full_rockets_lost_near_the_pad_destroy_it() {
+        let cfg = PadFailureConfig::default();
+        let heavy = cfg.heavy_propellant_kg;
+        assert!(destroys_pad(&[stage_loss(Subsystem::Turbopump)], heavy, &cfg));
+        assert!(destroys_pad(&[stage_loss(Subsystem::Combustion)], heavy, &cfg));
+        assert!(!destroys_pad(&[stage_loss(Subsystem::Turbopump)], heavy * 0.5, &cfg));
+        assert!(!destroys_pad(&[stage_loss(Subsystem::TankStructure)], heavy, &cfg));
+        assert!(!destroys_pad(&[], heavy, &cfg));
+    }
+}
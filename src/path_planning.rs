@@ -254,6 +254,9 @@ pub enum MissionPlan {
     /// (low-thrust vs high-thrust) can't fly any route to the
     /// destination. The player needs to change engine type.
     ClassMismatch { available_dv: f64 },
+    /// The rocket could make the trip, but only over a coast longer
+    /// than its final stage survives. The player needs a long-coast kit.
+    CoastTooLong { max_coast_days: u32, required_days: u32 },
 }
 
 impl DeltaVMap {
@@ -270,12 +273,28 @@ impl DeltaVMap {
         if let Some((path, dv)) = self.shortest_path_for_rocket(from, to, design, payload_mass_kg) {
             return MissionPlan::Reachable { path, dv };
         }
+        if !design.stage_groups.is_empty() {
+            let initial_dv = full_group_dv(design, 0, payload_mass_kg);
+            if let Some((path, _)) = self.astar_search(
+                from, to, design, payload_mass_kg, 0, initial_dv, u32::MAX,
+            ) {
+                let required_days = path.windows(2)
+                    .filter_map(|w| self.transfer(w[0], w[1]))
+                    .map(|t| t.transit_days)
+                    .max()
+                    .unwrap_or(0);
+                return MissionPlan::CoastTooLong {
+                    max_coast_days: design.max_coast_days(),
+                    required_days,
+                };
+            }
+        }
         let rocket_mass = design.total_mass_kg() + payload_mass_kg;
         // Disconnected in the underlying graph?
         if self.shortest_path(from, to, rocket_mass).is_none() {
             return MissionPlan::NoGraphPath;
         }
-        let available_dv = design.total_delta_v(payload_mass_kg);
+        let available_dv = design.usable_delta_v(payload_mass_kg);
         // Cheapest route restricted to the rocket's thrust class. For
         // low-thrust designs (always single-stage by designer rule) this
         // is the low-thrust subgraph. For chemical-only designs every
@@ -310,6 +329,8 @@ impl DeltaVMap {
     /// rocket's stage stack.
     ///
     /// Atmospheric drag is computed against the full rocket+payload mass.
+    /// Transfers whose coast outlasts the design's final stage (see
+    /// `RocketDesign::max_coast_days`) are off-limits.
     pub fn shortest_path_for_rocket(
         &self,
         from: &str,
@@ -321,7 +342,9 @@ impl DeltaVMap {
             return None;
         }
         let initial_dv = full_group_dv(design, 0, payload_mass_kg);
-        self.astar_search(from, to, design, payload_mass_kg, 0, initial_dv)
+        self.astar_search(
+            from, to, design, payload_mass_kg, 0, initial_dv, design.max_coast_days(),
+        )
     }
 
     /// Stage-aware shortest-path planner starting from a partial rocket
@@ -347,9 +370,11 @@ impl DeltaVMap {
         let initial_dv = rocket.group_remaining_delta_v(design, active_stage);
         self.astar_search(
             from, to, design, rocket.payload_mass_kg, active_stage, initial_dv,
+            design.max_coast_days(),
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn astar_search(
         &self,
         from: &str,
@@ -358,6 +383,7 @@ impl DeltaVMap {
         payload_mass_kg: f64,
         initial_active_stage: usize,
         initial_dv_left: f64,
+        max_coast_days: u32,
     ) -> Option<(Vec<&'static str>, f64)> {
         let from_idx = self.locations().iter().position(|l| l.id == from)?;
         let to_idx = self.locations().iter().position(|l| l.id == to)?;
@@ -416,6 +442,9 @@ impl DeltaVMap {
 
             let loc_id = self.location_at(state.loc_idx).unwrap().id;
             for transfer in self.transfers_from(loc_id) {
+                if transfer.transit_days > max_coast_days {
+                    continue;
+                }
                 let next_idx = match self.locations().iter().position(|l| l.id == transfer.to) {
                     Some(i) => i,
                     None => continue,
@@ -510,6 +539,11 @@ mod tests {
             propellant_mass_kg: prop, structural_mass_kg: dry,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         }
     }
 
@@ -520,6 +554,7 @@ mod tests {
         RocketDesign {
             id: RocketDesignId(1), name: "TwoChem".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -531,6 +566,7 @@ mod tests {
         RocketDesign {
             id: RocketDesignId(2), name: "ChemIon".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -555,6 +591,32 @@ mod tests {
             "new={} old={}", ndv, odv);
     }
 
+    #[test]
+    fn shortfall_reports_usable_delta_v() {
+        let mut design = two_stage_chemical();
+        design.performance_reserve = Some(0.05);
+        let plan = DELTA_V_MAP.plan_mission("earth_surface", "mercury_surface", &design, 1_000.0);
+        let MissionPlan::DvShortfall { available_dv, .. } = plan else {
+            panic!("expected a shortfall, got {plan:?}");
+        };
+        assert_eq!(available_dv, design.usable_delta_v(1_000.0));
+        assert!(available_dv < design.total_delta_v(1_000.0));
+    }
+
+    #[test]
+    fn lunar_coast_needs_long_coast_kit() {
+        // LEO → lunar orbit is a 4-day coast; a bare upper stage dies on
+        // the way, a kitted one relights for capture.
+        let mut design = two_stage_chemical();
+        let plan = DELTA_V_MAP.plan_mission("earth_surface", "lunar_orbit", &design, 1_000.0);
+        assert!(matches!(plan, MissionPlan::CoastTooLong { required_days, .. } if required_days >= 4),
+            "expected coast failure, got {:?}", plan);
+
+        design.stage_groups[1][0].long_coast_kit = true;
+        let plan = DELTA_V_MAP.plan_mission("earth_surface", "lunar_orbit", &design, 1_000.0);
+        assert!(matches!(plan, MissionPlan::Reachable { .. }), "got {:?}", plan);
+    }
+
     #[test]
     fn unreachable_goal_returns_none() {
         // Tiny 2-stage chemical rocket: insufficient dv to reach Eros even
@@ -564,6 +626,7 @@ mod tests {
         let design = RocketDesign {
             id: RocketDesignId(99), name: "Tiny".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
         let result = DELTA_V_MAP.shortest_path_for_rocket(
             "earth_surface", "eros_surface", &design, 100.0,
@@ -623,6 +686,7 @@ mod tests {
         let design = RocketDesign {
             id: RocketDesignId(10), name: "SmallS1+BigS2".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
 
         // Sanity: stage 1 alone shouldn't reach LEO.
@@ -653,6 +717,7 @@ mod tests {
         let design = RocketDesign {
             id: RocketDesignId(11), name: "TinyChem+Ion".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
         let result = DELTA_V_MAP.shortest_path_for_rocket(
             "earth_surface", "leo", &design, 100.0,
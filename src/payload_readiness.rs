@@ -0,0 +1,131 @@
+//! Customer-furnished payloads. A customer's satellite has its own
+//! schedule: each accepted contract's payload is promised ready on
+//! some day before the deadline, and that day can slip. A launch can't
+//! carry a payload that hasn't shipped. The customer pays a little
+//! for each day it slips, and when a slip runs into the deadline the
+//! contract is renegotiated rather than left to expire.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::PayloadReadinessConfig;
+use crate::calendar::GameDate;
+use crate::contract::ContractId;
+
+/// The readiness of one contract's payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayloadReadiness {
+    pub contract_id: ContractId,
+    /// Day the customer ships the payload to the pad.
+    pub ready_on: GameDate,
+    /// Total days it has slipped since the contract was signed.
+    pub slipped_days: u32,
+    /// Slip compensation the customer has paid so far.
+    pub compensation_paid: f64,
+}
+
+/// One slip of a payload's ready date.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slip {
+    pub days: u32,
+    pub ready_on: GameDate,
+    /// Compensation due for it, within the contract's cap.
+    pub compensation: f64,
+}
+
+/// Readiness of the payloads of the company's accepted contracts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomerPayloads {
+    pub payloads: Vec<PayloadReadiness>,
+}
+
+impl CustomerPayloads {
+    /// Start tracking a contract signed on `date` with `deadline`: its
+    /// payload is promised ready somewhere in the first part of the
+    /// time left.
+    pub fn track(
+        &mut self,
+        contract_id: ContractId,
+        date: GameDate,
+        deadline: GameDate,
+        rng: &mut impl Rng,
+        cfg: &PayloadReadinessConfig,
+    ) -> GameDate {
+        let span = date.days_until(&deadline) as f64;
+        let fraction = rng.gen_range(cfg.ready_fraction_min..=cfg.ready_fraction_max);
+        let ready_on = date.add_days((span * fraction).round() as u32);
+        self.payloads.retain(|p| p.contract_id != contract_id);
+        self.payloads.push(PayloadReadiness { contract_id, ready_on, slipped_days: 0, compensation_paid: 0.0 });
+        ready_on
+    }
+
+    pub fn get(&self, contract_id: ContractId) -> Option<&PayloadReadiness> {
+        self.payloads.iter().find(|p| p.contract_id == contract_id)
+    }
+
+    /// Whether the payload can fly on `date`. Contracts signed before
+    /// payloads were tracked are always ready.
+    pub fn is_ready(&self, contract_id: ContractId, date: GameDate) -> bool {
+        self.get(contract_id).is_none_or(|p| date >= p.ready_on)
+    }
+
+    /// Roll today's chance that a payload still in the customer's
+    /// hands slips. `payment` caps the compensation owed.
+    pub fn roll_slip(
+        &mut self,
+        contract_id: ContractId,
+        date: GameDate,
+        payment: f64,
+        rng: &mut impl Rng,
+        cfg: &PayloadReadinessConfig,
+    ) -> Option<Slip> {
+        let p = self.payloads.iter_mut().find(|p| p.contract_id == contract_id)?;
+        if date >= p.ready_on || rng.gen::<f64>() >= cfg.daily_slip_chance {
+            return None;
+        }
+        let days = rng.gen_range(cfg.slip_days_min..=cfg.slip_days_max);
+        p.ready_on = p.ready_on.add_days(days);
+        p.slipped_days += days;
+        let cap = payment * cfg.max_compensation_fraction;
+        let compensation = (days as f64 * cfg.compensation_per_day).min(cap - p.compensation_paid).max(0.0);
+        p.compensation_paid += compensation;
+        Some(Slip { days, ready_on: p.ready_on, compensation })
+    }
+
+    /// Stop tracking contracts no longer active.
+    pub fn prune(&mut self, active: impl Fn(ContractId) -> bool) {
+        self.payloads.retain(|p| active(p.contract_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_slips_push_the_ready_date_and_compensation_is_capped() {
+        let cfg = PayloadReadinessConfig { daily_slip_chance: 1.0, ..Default::default() };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let date = GameDate::new(2030, 1, 1);
+        let deadline = date.add_days(365);
+        let mut payloads = CustomerPayloads::default();
+        let ready_on = payloads.track(ContractId(1), date, deadline, &mut rng, &cfg);
+        assert!(ready_on > date && ready_on < deadline);
+        assert!(!payloads.is_ready(ContractId(1), date));
+        assert!(payloads.is_ready(ContractId(2), date), "untracked contracts are ready");
+
+        let payment = 10_000_000.0;
+        let mut paid = 0.0;
+        for _ in 0..10 {
+            let slip = payloads.roll_slip(ContractId(1), date, payment, &mut rng, &cfg).expect("always slips");
+            assert!(slip.days >= cfg.slip_days_min && slip.days <= cfg.slip_days_max);
+            paid += slip.compensation;
+        }
+        let p = payloads.get(ContractId(1)).unwrap();
+        assert_eq!(p.ready_on, ready_on.add_days(p.slipped_days));
+        assert!((paid - payment * cfg.max_compensation_fraction).abs() < 1e-6);
+        assert!(payloads.roll_slip(ContractId(1), p.ready_on, payment, &mut rng, &cfg).is_none(), "shipped payloads don't slip");
+    }
+}
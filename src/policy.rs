@@ -16,8 +16,9 @@ use crate::engine::EngineCycle;
 use crate::engine_project::{EngineDesignStatus, EngineProjectId, PropellantPreset};
 use crate::flight::Payload;
 use crate::game_state::GameState;
+use crate::regulation::LicenseClass;
 use crate::rocket::{RocketDesign, RocketDesignId};
-use crate::rocket_project::{RocketDesignStatus, RocketProjectId};
+use crate::rocket_project::{Certification, RocketDesignStatus, RocketProjectId};
 use crate::stage::{Stage, StageId};
 
 pub trait CompanyPolicy {
@@ -91,7 +92,8 @@ impl BasicPolicy {
     }
 
     /// Start revisions on any Testing project with discovered flaws
-    /// (or pending improvements / tech deficiencies for engines).
+    /// (or pending improvements / tech deficiencies for engines), and
+    /// patches on flight software with known bugs.
     fn revise_discovered_flaws(game: &mut GameState) {
         let company = &mut game.player_company;
         for i in 0..company.engine_projects.len() {
@@ -110,6 +112,33 @@ impl BasicPolicy {
                 company.start_rocket_revision(i);
             }
         }
+        for i in 0..company.software_projects.len() {
+            if company.software_projects[i].discovered_flaw_count() > 0 {
+                company.start_software_revision(i);
+            }
+        }
+    }
+
+    /// Certify a design with a long enough flight record once a market
+    /// that only takes certified designs opens.
+    fn certify_proven_designs(game: &mut GameState) {
+        let cfg = &game.balance.certification;
+        if game.player_company.money < MONEY_FLOOR + cfg.documentation_cost {
+            return;
+        }
+        let wanted = game.markets.iter()
+            .any(|m| m.active && game.certification_blocks_market(m.id));
+        if !wanted {
+            return;
+        }
+        let ready = game.player_company.rocket_projects.iter().position(|p| {
+            matches!(p.status, RocketDesignStatus::Testing { .. })
+                && p.certification == Certification::Uncertified
+                && p.success_streak >= cfg.required_successes
+        });
+        if let Some(i) = ready {
+            let _ = game.start_certification(i);
+        }
     }
 }
 
@@ -201,6 +230,10 @@ impl BasicPolicy {
     }
 
     fn ensure_teams(&self, game: &mut GameState) {
+        // Experienced teams are worth their raises.
+        while let Some(index) = game.pending_raise_index() {
+            game.accept_raise(index);
+        }
         if game.player_company.money < MONEY_FLOOR {
             return;
         }
@@ -257,7 +290,8 @@ impl BasicPolicy {
     /// Put idle engineering teams to work: the rocket project first
     /// (it gates the pipeline — 2 teams while designing/revising, 1
     /// while testing), then one team per engine project so testing
-    /// keeps discovering flaws and revisions actually progress.
+    /// keeps discovering flaws and revisions actually progress, then
+    /// a spare team to flight software.
     fn assign_idle_engineers(&self, game: &mut GameState) {
         let company = &mut game.player_company;
         if let Some(ri) = self.rocket.and_then(|rid|
@@ -280,6 +314,11 @@ impl BasicPolicy {
                 company.add_team_to_project(i);
             }
         }
+        for i in 0..company.software_projects.len() {
+            if company.software_projects[i].teams_assigned == 0 {
+                company.add_team_to_software_project(i);
+            }
+        }
     }
 
     /// Fixed two-stage template: one kerolox booster engine under a
@@ -312,6 +351,11 @@ impl BasicPolicy {
             structural_mass_kg: 3_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let mut s2 = Stage {
             id: StageId(2),
@@ -322,6 +366,11 @@ impl BasicPolicy {
             structural_mass_kg: 800.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         // Cover housekeeping power like the designer's default panels.
         s1.power_sources.push(crate::power::solar_panel_for_stage_demand(&s1));
@@ -331,6 +380,7 @@ impl BasicPolicy {
             id: RocketDesignId(company.next_rocket_project_id),
             name: "BLV-1".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(crate::rocket::DEFAULT_PERFORMANCE_RESERVE),
         })
     }
 
@@ -345,6 +395,10 @@ impl BasicPolicy {
             game.event_log.push(game.date, evt);
             self.rocket = game.player_company.rocket_projects.last()
                 .map(|p| p.project_id);
+            let index = game.player_company.rocket_projects.len() - 1;
+            if let Some((_, Some(evt))) = game.player_company.adopt_flight_software(index, &game.balance) {
+                game.event_log.push(game.date, evt);
+            }
         }
     }
 
@@ -370,8 +424,7 @@ impl BasicPolicy {
         let kg = self.rocket
             .and_then(|rid| game.player_company.rocket_projects.iter()
                 .find(|p| p.project_id == rid))
-            .map(|p| crate::rocket_project::max_payload_to(
-                &p.design, "earth_surface", dest))
+            .map(|p| game.max_payload_from_pad(&p.design, dest))
             .unwrap_or(0.0);
         self.capability.insert(dest.to_string(), kg);
         kg
@@ -414,11 +467,14 @@ impl BasicPolicy {
         }
 
         // 1) An already-accepted contract not currently flying (e.g. a
-        //    previous attempt blew up) takes priority.
+        //    previous attempt blew up) whose payload is at the pad
+        //    takes priority.
         let in_flight = Self::contracts_in_flight(game);
+        let payloads = &game.player_company.customer_payloads;
         let pending = game.player_company.active_contracts.iter()
             .position(|c| matches!(c.status, ContractStatus::Accepted)
-                && !in_flight.contains(&c.id));
+                && !in_flight.contains(&c.id)
+                && payloads.is_ready(c.id, game.date));
 
         let active_index = match pending {
             Some(i) => i,
@@ -468,6 +524,9 @@ impl BasicPolicy {
 
         let destination = game.player_company.active_contracts[active_index]
             .destination.clone();
+        // Licenses take months; apply as soon as a contract needs one
+        // (a no-op once held or pending).
+        game.apply_for_license(LicenseClass::for_destination(&destination));
         let Ok((dest, payloads)) = game.build_launch_payloads(&[active_index], &[])
         else {
             return;
@@ -488,6 +547,7 @@ impl CompanyPolicy for BasicPolicy {
         self.ensure_teams(game);
         self.ensure_engine_projects(game);
         Self::revise_discovered_flaws(game);
+        Self::certify_proven_designs(game);
         self.assign_idle_engineers(game);
         self.maybe_design_rocket(game);
         self.maybe_enable_auto_build(game);
@@ -516,6 +516,11 @@ mod tests {
             propellant_mass_kg: 1000.0, structural_mass_kg: 200.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         }
     }
 
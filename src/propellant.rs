@@ -14,8 +14,11 @@ pub enum Propellant {
 }
 
 impl Propellant {
-    /// Density in kg/L
+    /// Density in kg/L (a content pack may retune it).
     pub fn density_kg_per_l(&self) -> f64 {
+        if let Some(d) = crate::content::propellant_stats(*self).and_then(|s| s.density_kg_per_l) {
+            return d;
+        }
         match self {
             Propellant::LOX => 1.141,
             Propellant::RP1 => 0.82,
@@ -33,8 +36,11 @@ impl Propellant {
         matches!(self, Propellant::LOX | Propellant::LH2 | Propellant::Methane)
     }
 
-    /// Cost per kilogram in dollars
+    /// Cost per kilogram in dollars (a content pack may retune it).
     pub fn cost_per_kg(&self) -> f64 {
+        if let Some(c) = crate::content::propellant_stats(*self).and_then(|s| s.cost_per_kg) {
+            return c;
+        }
         match self {
             Propellant::LOX => 0.16,
             Propellant::RP1 => 1.10,
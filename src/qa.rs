@@ -0,0 +1,77 @@
+//! QA fault injection. Debug builds, and release builds with the `qa`
+//! feature, can force the next launch's outcome or inject engine work
+//! events, so the UI flows for rare outcomes can be exercised on
+//! demand. Nothing here is saved.
+
+use crate::engine_project::{EngineProject, EngineProjectId, WorkEvent};
+use crate::flaw::{Flaw, FlawConsequence, FlawId, FlawTrigger, Subsystem};
+use crate::launch::{LaunchOutcome, LaunchSimResult};
+
+/// What the next launch is rigged to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForcedOutcome {
+    /// Lose the first stage at this subsystem's launch event.
+    FailAt(Subsystem),
+    /// Fire this engine, rocket or software flaw. Engine flaws only
+    /// roll at launch for engines on the first stage.
+    TriggerFlaw(FlawId),
+}
+
+/// QA controls held by the game state.
+#[derive(Debug, Clone, Default)]
+pub struct QaControls {
+    pub next_launch: Option<ForcedOutcome>,
+}
+
+/// Engine work events waiting to be handed to the next R&D tick.
+pub type InjectedWork = Vec<(EngineProjectId, WorkEvent)>;
+
+/// The stand-in flaw a forced failure flies with.
+pub fn forced_failure_flaw(subsystem: Subsystem) -> Flaw {
+    Flaw {
+        id: FlawId(u64::MAX),
+        description: format!("QA: forced failure at {}", subsystem.launch_event()),
+        consequence: FlawConsequence::StageLoss,
+        activation_chance: 1.0,
+        discovery_probability: 0.0,
+        discovered: false,
+        trigger: FlawTrigger::PerFlight,
+        subsystem,
+        anomaly: None,
+    }
+}
+
+/// Settle a rigged launch's outcome: a forced failure fails even when
+/// the upper stages have the margin to make orbit without the first.
+pub fn force_outcome(outcome: &ForcedOutcome, sim: &mut LaunchSimResult) {
+    if let ForcedOutcome::FailAt(subsystem) = outcome {
+        sim.outcome = LaunchOutcome::Failure {
+            reason: format!("Stage loss at {} ({})", subsystem.launch_event(), subsystem),
+        };
+    }
+}
+
+/// Rig a launch's flaws for `outcome`. `engines` is the launch's own
+/// copy of the engine projects, made from `company_engines` when a
+/// forced engine flaw needs one.
+pub fn rig_launch(
+    outcome: &ForcedOutcome,
+    flaws: &mut Vec<Flaw>,
+    engines: &mut Option<Vec<EngineProject>>,
+    company_engines: &[EngineProject],
+) {
+    match outcome {
+        ForcedOutcome::FailAt(subsystem) => flaws.push(forced_failure_flaw(*subsystem)),
+        ForcedOutcome::TriggerFlaw(id) => {
+            for f in flaws.iter_mut().filter(|f| f.id == *id) {
+                f.activation_chance = 1.0;
+            }
+            if company_engines.iter().any(|ep| ep.flaws.iter().any(|f| f.id == *id)) {
+                let engines = engines.get_or_insert_with(|| company_engines.to_vec());
+                for f in engines.iter_mut().flat_map(|ep| ep.flaws.iter_mut()).filter(|f| f.id == *id) {
+                    f.activation_chance = 1.0;
+                }
+            }
+        }
+    }
+}
@@ -67,8 +67,9 @@ impl EnrichmentLevel {
         }
     }
 
-    /// Total reputation required to design at this enrichment. LEU is
-    /// always available; MEU and HEU sit behind serious prestige walls.
+    /// Government reputation required to design at this enrichment.
+    /// LEU is always available; MEU and HEU sit behind serious
+    /// prestige walls.
     pub fn min_reputation(self, rep_cfg: &crate::balance_config::ReputationConfig) -> f64 {
         match self {
             EnrichmentLevel::Leu => 0.0,
@@ -224,13 +224,14 @@ impl ReactorProject {
         }
     }
 
-    /// Apply one day of work. Returns any work events for the
-    /// game-state loop to log. Mirrors `EngineProject::apply_daily_work`:
+    /// Apply one day of work, done at `efficiency` of a full day's.
+    /// Returns any work events for the game-state loop to log. Mirrors `EngineProject::apply_daily_work`:
     /// design completion generates flaws, testing discovers flaws and
     /// improvements, and revision removes flaws / actualizes
     /// improvements / attempts tech-deficiency fixes.
     pub fn apply_daily_work(
         &mut self,
+        efficiency: f64,
         rng: &mut StdRng,
         next_flaw_id: &mut u64,
         balance_cfg: &BalanceConfig,
@@ -238,7 +239,7 @@ impl ReactorProject {
         if self.teams_assigned == 0 {
             return Vec::new();
         }
-        let work = crate::team::effective_work_rate(self.teams_assigned);
+        let work = crate::team::effective_work_rate(self.teams_assigned) * efficiency;
         let mut events = Vec::new();
 
         match &mut self.status {
@@ -260,7 +261,9 @@ impl ReactorProject {
                 self.cumulative_testing_work += work;
                 while *work_completed >= balance_cfg.work.testing_cycle_work {
                     *work_completed -= balance_cfg.work.testing_cycle_work;
-                    let discovered = flaw::roll_discoveries_with_rng(&mut self.flaws, rng);
+                    let discovered = flaw::roll_discoveries_with_rng(
+                        &mut self.flaws, rng, balance_cfg.flaws.anomaly_discovery_multiplier,
+                    );
                     for idx in discovered {
                         events.push(ReactorWorkEvent::FlawDiscovered {
                             flaw_description: self.flaws[idx].description.clone(),
@@ -285,9 +288,13 @@ impl ReactorProject {
             } => {
                 *work_completed += work;
                 // Process flaws first.
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_flaw_indices.is_empty() {
-                    *work_completed -= balance_cfg.work.flaw_revision_work;
-                    let fi = remaining_flaw_indices.remove(0);
+                while let Some(&fi) = remaining_flaw_indices.first() {
+                    let fix_work = self.flaws[fi].fix_work(balance_cfg.work.flaw_revision_work);
+                    if *work_completed < fix_work {
+                        break;
+                    }
+                    *work_completed -= fix_work;
+                    remaining_flaw_indices.remove(0);
                     self.flaws.remove(fi);
                     events.push(ReactorWorkEvent::RevisionComplete);
                     for idx in remaining_flaw_indices.iter_mut() {
@@ -297,7 +304,10 @@ impl ReactorProject {
                     }
                 }
                 // Then actualize improvements.
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_improvement_indices.is_empty() {
+                while remaining_flaw_indices.is_empty()
+                    && *work_completed >= balance_cfg.work.flaw_revision_work
+                    && !remaining_improvement_indices.is_empty()
+                {
                     *work_completed -= balance_cfg.work.flaw_revision_work;
                     let ii = remaining_improvement_indices.remove(0);
                     if let Some(imp) = self.improvements.get_mut(ii) {
@@ -321,7 +331,10 @@ impl ReactorProject {
                     }
                 }
                 // Then attempt tech deficiency fixes (resolved by game_state).
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_tech_deficiency_ids.is_empty() {
+                while remaining_flaw_indices.is_empty()
+                    && *work_completed >= balance_cfg.work.flaw_revision_work
+                    && !remaining_tech_deficiency_ids.is_empty()
+                {
                     *work_completed -= balance_cfg.work.flaw_revision_work;
                     let def_id = remaining_tech_deficiency_ids.remove(0);
                     events.push(ReactorWorkEvent::TechDeficiencyAttempted { deficiency_id: def_id });
@@ -392,6 +405,11 @@ impl ReactorProject {
             _ => "Thoroughly Tested",
         }
     }
+
+    /// `testing_level` as a localizable `testing.<tier>` label.
+    pub fn testing_level_text(&self, balance_cfg: &BalanceConfig) -> crate::locale::Text {
+        crate::locale::Text::label("testing", self.testing_level(balance_cfg))
+    }
 }
 
 /// Events bubbled up from `apply_daily_work` to the game-state loop.
@@ -452,7 +470,7 @@ mod tests {
         );
         p.teams_assigned = 2;
         let mut next_flaw = 1u64;
-        let events = p.apply_daily_work(&mut rng(), &mut next_flaw, &bal());
+        let events = p.apply_daily_work(1.0, &mut rng(), &mut next_flaw, &bal());
         assert!(events.is_empty());
         assert!(matches!(p.status, ReactorDesignStatus::Proposed { .. }));
     }
@@ -487,7 +505,7 @@ mod tests {
         // Hard cap iterations so a runaway loop fails the test rather
         // than the process.
         for _ in 0..10_000 {
-            let events = p.apply_daily_work(&mut rng(), &mut next_flaw, &bal());
+            let events = p.apply_daily_work(1.0, &mut rng(), &mut next_flaw, &bal());
             if events.iter().any(|e| matches!(e, ReactorWorkEvent::DesignComplete { .. })) {
                 saw_complete = true;
                 break;
@@ -511,7 +529,7 @@ mod tests {
             let mut r = StdRng::seed_from_u64(seed);
             let mut next_flaw = 1u64;
             for _ in 0..10_000 {
-                let events = p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+                let events = p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
                 if events.iter().any(|e| matches!(e, ReactorWorkEvent::DesignComplete { .. })) {
                     break;
                 }
@@ -535,7 +553,7 @@ mod tests {
         let mut next_flaw = 1u64;
         // Advance to Testing.
         for _ in 0..10_000 {
-            let events = p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+            let events = p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
             if events.iter().any(|e| matches!(e, ReactorWorkEvent::DesignComplete { .. })) {
                 break;
             }
@@ -548,7 +566,7 @@ mod tests {
         let total = p.flaws.len();
         let mut discovered_any = false;
         for _ in 0..200 {
-            let events = p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+            let events = p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
             if events.iter().any(|e| matches!(e, ReactorWorkEvent::FlawDiscovered { .. })) {
                 discovered_any = true;
             }
@@ -577,6 +595,8 @@ mod tests {
             discovery_probability: 0.5,
             discovered: true,
             trigger: crate::flaw::FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Power,
+            anomaly: None,
         });
         p.teams_assigned = 4;
 
@@ -586,7 +606,7 @@ mod tests {
         let mut r = rng();
         let mut next_flaw = 2u64;
         for _ in 0..50 {
-            p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+            p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
             if matches!(p.status, ReactorDesignStatus::Testing { .. }) {
                 break;
             }
@@ -614,7 +634,7 @@ mod tests {
         let mut r = rng();
         let mut next_flaw = 1u64;
         for _ in 0..50 {
-            p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+            p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
             if matches!(p.status, ReactorDesignStatus::Testing { .. }) {
                 break;
             }
@@ -640,7 +660,7 @@ mod tests {
         let mut r = rng();
         let mut next_flaw = 1u64;
         for _ in 0..50 {
-            p.apply_daily_work(&mut r, &mut next_flaw, &bal());
+            p.apply_daily_work(1.0, &mut r, &mut next_flaw, &bal());
             if matches!(p.status, ReactorDesignStatus::Testing { .. }) {
                 break;
             }
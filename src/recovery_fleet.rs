@@ -0,0 +1,264 @@
+//! The assets boosters land on. A landing zone by the pad is always in
+//! place but high winds can close it; a drone ship has to sail out to
+//! the landing area before a launch and can only take a landing in
+//! moderate seas. Either is tied up for a turnaround after catching a
+//! booster — a drone ship sails home with it and must be sent out
+//! again. A booster with nowhere to land is expended.
+
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::RecoveryFleetConfig;
+use crate::calendar::GameDate;
+
+/// Unique identifier for a recovery asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecoveryAssetId(pub u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecoveryAssetKind {
+    LandingZone,
+    DroneShip,
+}
+
+impl fmt::Display for RecoveryAssetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecoveryAssetKind::LandingZone => write!(f, "Landing zone"),
+            RecoveryAssetKind::DroneShip => write!(f, "Drone ship"),
+        }
+    }
+}
+
+/// A landing zone or drone ship the company owns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryAsset {
+    pub id: RecoveryAssetId,
+    pub kind: RecoveryAssetKind,
+    pub name: String,
+    /// When a drone ship sent out reaches the landing area. None while
+    /// it's in port; unused for landing zones.
+    pub on_station_from: Option<GameDate>,
+    /// Tied up turning around a landed booster until this day.
+    pub busy_until: Option<GameDate>,
+}
+
+impl RecoveryAsset {
+    fn on_station(&self, date: GameDate) -> bool {
+        match self.kind {
+            RecoveryAssetKind::LandingZone => true,
+            RecoveryAssetKind::DroneShip => self.on_station_from.is_some_and(|d| date >= d),
+        }
+    }
+
+    /// Free to catch a booster launched on `date`, weather permitting.
+    pub fn available(&self, date: GameDate) -> bool {
+        self.busy_until.is_none_or(|until| date >= until) && self.on_station(date)
+    }
+
+    /// Where the asset is in its schedule, for display.
+    pub fn status(&self, date: GameDate) -> String {
+        match (self.busy_until.filter(|until| date < *until), self.on_station_from) {
+            (Some(until), _) => format!("turning around until {}", until),
+            (None, _) if self.on_station(date) => "ready".to_string(),
+            (None, Some(from)) => format!("sailing, on station {}", from),
+            (None, None) => "in port".to_string(),
+        }
+    }
+}
+
+/// Why a booster couldn't land and was expended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpendReason {
+    NoAsset,
+    WeatherScrub,
+    RoughSeas,
+}
+
+impl fmt::Display for ExpendReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpendReason::NoAsset => write!(f, "no recovery asset on station"),
+            ExpendReason::WeatherScrub => write!(f, "high winds closed the landing zone"),
+            ExpendReason::RoughSeas => write!(f, "seas too rough for the drone ship"),
+        }
+    }
+}
+
+/// The asset a booster is landing on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandingSlot {
+    pub kind: RecoveryAssetKind,
+    pub name: String,
+    /// Sea state at a drone ship; 0 on land.
+    pub sea_state: u32,
+}
+
+/// Why a recovery fleet order was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecoveryFleetError {
+    NoSuchAsset,
+    NotADroneShip,
+    AlreadyAtSea,
+    CantAfford { cost: f64 },
+}
+
+impl fmt::Display for RecoveryFleetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecoveryFleetError::NoSuchAsset => write!(f, "No such recovery asset"),
+            RecoveryFleetError::NotADroneShip => write!(f, "Only drone ships put to sea"),
+            RecoveryFleetError::AlreadyAtSea => write!(f, "That drone ship is already at sea"),
+            RecoveryFleetError::CantAfford { cost } =>
+                write!(f, "That costs {}", crate::resources::format_money(*cost)),
+        }
+    }
+}
+
+/// The company's landing zones and drone ships.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecoveryFleet {
+    pub assets: Vec<RecoveryAsset>,
+    pub next_id: u64,
+}
+
+impl RecoveryFleet {
+    /// Add a newly bought asset, named for its kind and number.
+    pub fn add(&mut self, kind: RecoveryAssetKind) -> &RecoveryAsset {
+        let number = self.assets.iter().filter(|a| a.kind == kind).count() + 1;
+        let id = RecoveryAssetId(self.next_id);
+        self.next_id += 1;
+        self.assets.push(RecoveryAsset {
+            id,
+            kind,
+            name: format!("{} {}", kind, number),
+            on_station_from: None,
+            busy_until: None,
+        });
+        self.assets.last().expect("just pushed")
+    }
+
+    /// Send drone ship `id` out to the landing area, leaving once any
+    /// turnaround is done. Returns the day it's on station.
+    pub fn send_out(&mut self, id: RecoveryAssetId, date: GameDate, cfg: &RecoveryFleetConfig) -> Result<GameDate, RecoveryFleetError> {
+        let asset = self.assets.iter_mut().find(|a| a.id == id).ok_or(RecoveryFleetError::NoSuchAsset)?;
+        if asset.kind != RecoveryAssetKind::DroneShip {
+            return Err(RecoveryFleetError::NotADroneShip);
+        }
+        if asset.on_station_from.is_some() {
+            return Err(RecoveryFleetError::AlreadyAtSea);
+        }
+        let leaves = asset.busy_until.map_or(date, |until| until.max(date));
+        let on_station = leaves.add_days(cfg.drone_ship_transit_days);
+        asset.on_station_from = Some(on_station);
+        Ok(on_station)
+    }
+
+    /// Find an asset to catch a booster launched on `date`: a landing
+    /// zone if the winds allow, else a drone ship on station in seas it
+    /// can take (rolled up to `max_sea_state`). The asset claimed goes
+    /// into turnaround; a drone ship sails home with the booster.
+    pub fn claim(
+        &mut self,
+        date: GameDate,
+        max_sea_state: u32,
+        rng: &mut impl Rng,
+        cfg: &RecoveryFleetConfig,
+    ) -> Result<LandingSlot, ExpendReason> {
+        let mut reason = ExpendReason::NoAsset;
+        let mut order: Vec<usize> = (0..self.assets.len())
+            .filter(|&i| self.assets[i].available(date))
+            .collect();
+        order.sort_by_key(|&i| self.assets[i].kind != RecoveryAssetKind::LandingZone);
+        for i in order {
+            let asset = &mut self.assets[i];
+            let (sea_state, turnaround) = match asset.kind {
+                RecoveryAssetKind::LandingZone => {
+                    if rng.gen::<f64>() < cfg.landing_zone_weather_scrub {
+                        reason = ExpendReason::WeatherScrub;
+                        continue;
+                    }
+                    (0, cfg.landing_zone_turnaround_days)
+                }
+                RecoveryAssetKind::DroneShip => {
+                    let sea_state = rng.gen_range(0..=max_sea_state);
+                    if sea_state > cfg.drone_ship_max_sea_state {
+                        reason = ExpendReason::RoughSeas;
+                        continue;
+                    }
+                    asset.on_station_from = None;
+                    (sea_state, cfg.drone_ship_turnaround_days)
+                }
+            };
+            asset.busy_until = Some(date.add_days(turnaround));
+            return Ok(LandingSlot { kind: asset.kind, name: asset.name.clone(), sea_state });
+        }
+        Err(reason)
+    }
+
+    /// Upkeep of the whole fleet for a month.
+    pub fn monthly_upkeep(&self, cfg: &RecoveryFleetConfig) -> f64 {
+        self.assets.iter()
+            .map(|a| match a.kind {
+                RecoveryAssetKind::LandingZone => cfg.landing_zone_upkeep_per_month,
+                RecoveryAssetKind::DroneShip => cfg.drone_ship_upkeep_per_month,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_drone_ship_must_sail_out_and_turn_around_after_a_catch() {
+        let cfg = RecoveryFleetConfig { drone_ship_max_sea_state: 6, ..Default::default() };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let date = GameDate::new(2030, 1, 1);
+        let mut fleet = RecoveryFleet::default();
+        let ship = fleet.add(RecoveryAssetKind::DroneShip).id;
+
+        assert_eq!(fleet.claim(date, 6, &mut rng, &cfg), Err(ExpendReason::NoAsset), "still in port");
+        let on_station = fleet.send_out(ship, date, &cfg).unwrap();
+        assert_eq!(fleet.send_out(ship, date, &cfg), Err(RecoveryFleetError::AlreadyAtSea));
+        assert!(fleet.claim(date, 6, &mut rng, &cfg).is_err(), "not there yet");
+
+        let slot = fleet.claim(on_station, 6, &mut rng, &cfg).expect("on station");
+        assert_eq!(slot.kind, RecoveryAssetKind::DroneShip);
+        let unloaded = on_station.add_days(cfg.drone_ship_turnaround_days);
+        assert_eq!(fleet.assets[0].status(on_station), format!("turning around until {}", unloaded));
+        // It has to be sent out again, and leaves once it's unloaded.
+        let next = fleet.send_out(ship, on_station, &cfg).unwrap();
+        assert_eq!(next, unloaded.add_days(cfg.drone_ship_transit_days));
+    }
+
+    #[test]
+    fn test_landing_zone_is_preferred_and_weather_or_seas_can_close_everything() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let date = GameDate::new(2030, 1, 1);
+        let mut fleet = RecoveryFleet::default();
+        fleet.add(RecoveryAssetKind::LandingZone);
+        let ship = fleet.add(RecoveryAssetKind::DroneShip).id;
+        let calm = RecoveryFleetConfig { landing_zone_weather_scrub: 0.0, ..Default::default() };
+        fleet.send_out(ship, date, &calm).unwrap();
+        let later = date.add_days(calm.drone_ship_transit_days);
+        assert_eq!(fleet.clone().claim(later, 0, &mut rng, &calm).unwrap().kind, RecoveryAssetKind::LandingZone);
+
+        // Winds close the landing zone; the drone ship then takes what
+        // the sea allows.
+        let storm = RecoveryFleetConfig { landing_zone_weather_scrub: 1.0, drone_ship_max_sea_state: 2, ..Default::default() };
+        let outcomes: Vec<_> = (0..20)
+            .map(|_| fleet.clone().claim(later, 6, &mut rng, &storm))
+            .collect();
+        assert!(outcomes.contains(&Err(ExpendReason::RoughSeas)));
+        assert!(outcomes.iter().flatten().all(|s| s.kind == RecoveryAssetKind::DroneShip && s.sea_state <= 2));
+        let mut lz_only = RecoveryFleet::default();
+        lz_only.add(RecoveryAssetKind::LandingZone);
+        assert_eq!(lz_only.claim(later, 6, &mut rng, &storm), Err(ExpendReason::WeatherScrub));
+    }
+}
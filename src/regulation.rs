@@ -0,0 +1,298 @@
+//! Launch licensing and environmental compliance.
+//!
+//! Every launch needs a license for its destination class; licenses
+//! beyond Earth orbit take an application fee and lead time. Solid
+//! motors and failure debris trigger environmental reviews that close
+//! the site for a while, and the site has a launch cadence limit. Any
+//! open issue blocks the launch — see [`Compliance::check`].
+//!
+//! Export-controlled payloads are a separate regime: only a company
+//! with a security clearance may compete for them, and each one's
+//! launch needs export paperwork filed ahead of time. Flying one
+//! before its paperwork clears is a violation, fined and punished with
+//! a spell out of the controlled contract pool.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::RegulationConfig;
+use crate::calendar::GameDate;
+use crate::contract::ContractId;
+use crate::launch_site::LaunchSite;
+
+/// Destination classes the regulator licenses separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LicenseClass {
+    /// Anywhere bound to Earth (LEO through GEO, Earth-Moon Lagrange points).
+    EarthOrbit,
+    /// Lunar orbit and surface.
+    Cislunar,
+    /// Everything heliocentric or beyond.
+    DeepSpace,
+}
+
+impl LicenseClass {
+    /// The class a destination falls under, by its parent body.
+    pub fn for_destination(destination: &str) -> LicenseClass {
+        match crate::location::DELTA_V_MAP.location(destination).map(|l| l.parent_body) {
+            Some("earth") | None => LicenseClass::EarthOrbit,
+            Some("moon") => LicenseClass::Cislunar,
+            Some(_) => LicenseClass::DeepSpace,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LicenseClass::EarthOrbit => "Earth orbit",
+            LicenseClass::Cislunar => "Cislunar",
+            LicenseClass::DeepSpace => "Deep space",
+        }
+    }
+}
+
+impl fmt::Display for LicenseClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// A license application working its way through the regulator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LicenseApplication {
+    pub class: LicenseClass,
+    /// Day the license is granted.
+    pub ready_on: GameDate,
+}
+
+/// Export paperwork filed for one controlled contract's launch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportFiling {
+    pub contract_id: ContractId,
+    /// Day the paperwork clears; launching earlier is a violation.
+    pub ready_on: GameDate,
+}
+
+/// Why the regulator won't clear a launch today.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComplianceIssue {
+    /// No license (and no application) for the destination class.
+    Unlicensed { class: LicenseClass },
+    /// The application hasn't been granted yet.
+    LicensePending { class: LicenseClass, ready_on: GameDate },
+    /// The site is closed for an environmental review.
+    UnderReview { until: GameDate },
+    /// The site has hit its launch cadence limit.
+    CadenceLimit { next_slot: GameDate },
+    /// The pad is being rebuilt after a launch destroyed it.
+    PadRebuilding { ready_on: GameDate },
+    /// Launches are stood down while a mishap is investigated.
+    StandDown { until: GameDate },
+    /// The pad is being converted or readied after the last launch.
+    PadBusy { until: GameDate },
+}
+
+impl fmt::Display for ComplianceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComplianceIssue::Unlicensed { class } =>
+                write!(f, "No {} launch license", class),
+            ComplianceIssue::LicensePending { class, ready_on } =>
+                write!(f, "{} license pending until {}", class, ready_on),
+            ComplianceIssue::UnderReview { until } =>
+                write!(f, "Site closed for environmental review until {}", until),
+            ComplianceIssue::CadenceLimit { next_slot } =>
+                write!(f, "Launch cadence limit reached — next slot {}", next_slot),
+            ComplianceIssue::PadRebuilding { ready_on } =>
+                write!(f, "Pad being rebuilt until {}", ready_on),
+            ComplianceIssue::StandDown { until } =>
+                write!(f, "Launches stood down for mishap investigation until {}", until),
+            ComplianceIssue::PadBusy { until } =>
+                write!(f, "Pad busy until {}", until),
+        }
+    }
+}
+
+/// The company's standing with the regulator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Compliance {
+    /// Granted licenses. Every company starts with Earth orbit.
+    pub licenses: Vec<LicenseClass>,
+    #[serde(default)]
+    pub applications: Vec<LicenseApplication>,
+    /// Environmental review closing the site, if one is running.
+    #[serde(default)]
+    pub review_until: Option<GameDate>,
+    /// Solid-motor launches since the last review.
+    #[serde(default)]
+    pub solid_launches_since_review: u32,
+    /// Spent stages the company has left in Earth orbit.
+    #[serde(default)]
+    pub orbital_debris: u32,
+    /// Whether the company's facility holds a security clearance, and
+    /// the day a clearance upgrade under way completes.
+    #[serde(default)]
+    pub security_clearance: bool,
+    #[serde(default)]
+    pub clearance_ready_on: Option<GameDate>,
+    /// Export paperwork for accepted controlled contracts.
+    #[serde(default)]
+    pub export_filings: Vec<ExportFiling>,
+    /// Barred from export-controlled contracts until this day after a
+    /// violation.
+    #[serde(default)]
+    pub export_ban_until: Option<GameDate>,
+    /// Launches stood down until this day while a mishap is
+    /// investigated.
+    #[serde(default)]
+    pub stand_down_until: Option<GameDate>,
+}
+
+impl Default for Compliance {
+    fn default() -> Self {
+        Compliance {
+            licenses: vec![LicenseClass::EarthOrbit],
+            applications: Vec::new(),
+            review_until: None,
+            solid_launches_since_review: 0,
+            orbital_debris: 0,
+            security_clearance: false,
+            clearance_ready_on: None,
+            export_filings: Vec::new(),
+            export_ban_until: None,
+            stand_down_until: None,
+        }
+    }
+}
+
+impl Compliance {
+    pub fn has_license(&self, class: LicenseClass) -> bool {
+        self.licenses.contains(&class)
+    }
+
+    pub fn application(&self, class: LicenseClass) -> Option<&LicenseApplication> {
+        self.applications.iter().find(|a| a.class == class)
+    }
+
+    /// Move applications whose lead time has elapsed into licenses,
+    /// returning the newly granted classes.
+    pub fn grant_due(&mut self, today: GameDate) -> Vec<LicenseClass> {
+        let (due, pending): (Vec<_>, Vec<_>) =
+            self.applications.drain(..).partition(|a| a.ready_on <= today);
+        self.applications = pending;
+        let granted: Vec<LicenseClass> = due.into_iter().map(|a| a.class).collect();
+        self.licenses.extend(granted.iter().copied());
+        granted
+    }
+
+    /// Complete a clearance upgrade whose lead time has elapsed.
+    /// Returns true on the day it's granted.
+    pub fn grant_clearance_due(&mut self, today: GameDate) -> bool {
+        if self.clearance_ready_on.is_some_and(|d| d <= today) {
+            self.clearance_ready_on = None;
+            self.security_clearance = true;
+            return true;
+        }
+        false
+    }
+
+    /// Whether the company may compete for export-controlled work on
+    /// `date`: cleared, and not serving a ban.
+    pub fn export_eligible(&self, date: GameDate) -> bool {
+        self.security_clearance && self.export_ban_until.is_none_or(|until| date >= until)
+    }
+
+    /// The paperwork filed for a controlled contract, if any.
+    pub fn export_filing(&self, contract_id: ContractId) -> Option<&ExportFiling> {
+        self.export_filings.iter().find(|f| f.contract_id == contract_id)
+    }
+
+    /// Check a launch to `destination` on `date` against licenses, the
+    /// pad's condition, stand-downs and reviews, and the site's cadence
+    /// limit.
+    pub fn check(
+        &self,
+        destination: &str,
+        date: GameDate,
+        site: &LaunchSite,
+        cfg: &RegulationConfig,
+    ) -> Result<(), ComplianceIssue> {
+        let class = LicenseClass::for_destination(destination);
+        if !self.has_license(class) {
+            return Err(match self.application(class) {
+                Some(app) => ComplianceIssue::LicensePending { class, ready_on: app.ready_on },
+                None => ComplianceIssue::Unlicensed { class },
+            });
+        }
+        if let Some(ready_on) = site.rebuild_until.filter(|&until| date < until) {
+            return Err(ComplianceIssue::PadRebuilding { ready_on });
+        }
+        if let Some(until) = self.stand_down_until.filter(|&until| date < until) {
+            return Err(ComplianceIssue::StandDown { until });
+        }
+        if let Some(until) = site.pad_busy_until(date) {
+            return Err(ComplianceIssue::PadBusy { until });
+        }
+        if let Some(until) = self.review_until.filter(|&until| date < until) {
+            return Err(ComplianceIssue::UnderReview { until });
+        }
+        if let Some(next_slot) =
+            site.next_cadence_slot(date, cfg.cadence_window_days, cfg.max_launches_per_window)
+        {
+            return Err(ComplianceIssue::CadenceLimit { next_slot });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_license_classes_by_parent_body() {
+        assert_eq!(LicenseClass::for_destination("leo"), LicenseClass::EarthOrbit);
+        assert_eq!(LicenseClass::for_destination("l2"), LicenseClass::EarthOrbit);
+        assert_eq!(LicenseClass::for_destination("lunar_surface"), LicenseClass::Cislunar);
+        assert_eq!(LicenseClass::for_destination("mars_orbit_200km"), LicenseClass::DeepSpace);
+    }
+
+    #[test]
+    fn test_check_reports_first_issue() {
+        let cfg = RegulationConfig::default();
+        let site = LaunchSite::default();
+        let today = GameDate::new(2001, 1, 1);
+        let mut c = Compliance::default();
+        assert_eq!(c.check("leo", today, &site, &cfg), Ok(()));
+        assert_eq!(c.check("lunar_orbit", today, &site, &cfg),
+            Err(ComplianceIssue::Unlicensed { class: LicenseClass::Cislunar }));
+
+        let ready_on = today.add_days(10);
+        c.applications.push(LicenseApplication { class: LicenseClass::Cislunar, ready_on });
+        assert_eq!(c.check("lunar_orbit", today, &site, &cfg),
+            Err(ComplianceIssue::LicensePending { class: LicenseClass::Cislunar, ready_on }));
+        assert_eq!(c.grant_due(ready_on), vec![LicenseClass::Cislunar]);
+        assert_eq!(c.check("lunar_orbit", ready_on, &site, &cfg), Ok(()));
+
+        c.review_until = Some(ready_on.add_days(5));
+        assert!(matches!(c.check("leo", ready_on, &site, &cfg),
+            Err(ComplianceIssue::UnderReview { .. })));
+        assert_eq!(c.check("leo", ready_on.add_days(5), &site, &cfg), Ok(()));
+    }
+
+    #[test]
+    fn test_export_eligibility_needs_clearance_and_no_ban() {
+        let today = GameDate::new(2001, 1, 1);
+        let mut c = Compliance::default();
+        assert!(!c.export_eligible(today));
+
+        c.clearance_ready_on = Some(today.add_days(30));
+        assert!(!c.grant_clearance_due(today));
+        assert!(c.grant_clearance_due(today.add_days(30)));
+        assert!(c.export_eligible(today));
+
+        c.export_ban_until = Some(today.add_days(100));
+        assert!(!c.export_eligible(today.add_days(99)));
+        assert!(c.export_eligible(today.add_days(100)));
+    }
+}
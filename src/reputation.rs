@@ -1,12 +1,40 @@
 use serde::{Serialize, Deserialize};
 
-use crate::balance_config::ReputationConfig;
+use crate::balance_config::{ReputationConfig, SegmentWeights};
+
+/// Which audience a reputation score speaks for. Each market bids on
+/// one segment's score; the headline `Reputation::total` is the
+/// unweighted sum of factors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReputationSegment {
+    /// Satellite operators and other paying commercial customers.
+    #[default]
+    Commercial,
+    /// Space agencies and defense customers.
+    Government,
+    /// Public opinion — what crewed programs answer to.
+    Public,
+}
+
+impl ReputationSegment {
+    pub const ALL: [ReputationSegment; 3] =
+        [ReputationSegment::Commercial, ReputationSegment::Government, ReputationSegment::Public];
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ReputationSegment::Commercial => "Commercial",
+            ReputationSegment::Government => "Government",
+            ReputationSegment::Public => "Public",
+        }
+    }
+}
 
 /// Factor-based reputation tracking.
 ///
-/// Total reputation is the sum of four independent factors, each with
-/// its own accumulation and decay rules. The deltas and decay factors
-/// live in `balance_config::ReputationConfig`.
+/// Total reputation is the sum of six independent factors, each with
+/// its own accumulation and decay rules. Segment scores weight the same
+/// factors per audience (see `SegmentWeights`). The deltas, decay
+/// factors and weights live in `balance_config::ReputationConfig`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Reputation {
     /// Gains per successful launch, loses per failure. Decays each launch.
@@ -17,6 +45,14 @@ pub struct Reputation {
     pub drought_factor: f64,
     /// Penalized per expired accepted contract. Decays each contract launch.
     pub expiry_factor: f64,
+    /// Penalized per missed earnings quarter once public. Decays each
+    /// met quarter.
+    #[serde(default)]
+    pub earnings_factor: f64,
+    /// Penalized per crew lost. Never decays on its own; each
+    /// successful launch wears it down.
+    #[serde(default)]
+    pub crew_factor: f64,
 }
 
 impl Default for Reputation {
@@ -32,12 +68,31 @@ impl Reputation {
             lost_payload_factor: 0.0,
             drought_factor: 0.0,
             expiry_factor: 0.0,
+            earnings_factor: 0.0,
+            crew_factor: 0.0,
         }
     }
 
-    /// Current total reputation score.
+    /// Current total reputation score — the headline figure.
     pub fn total(&self) -> f64 {
         self.success_factor + self.lost_payload_factor + self.drought_factor + self.expiry_factor
+            + self.earnings_factor + self.crew_factor
+    }
+
+    /// Reputation with one audience: the factors under that segment's
+    /// weights.
+    pub fn segment(&self, segment: ReputationSegment, cfg: &ReputationConfig) -> f64 {
+        let w: &SegmentWeights = match segment {
+            ReputationSegment::Commercial => &cfg.commercial_weights,
+            ReputationSegment::Government => &cfg.government_weights,
+            ReputationSegment::Public => &cfg.public_weights,
+        };
+        w.success * self.success_factor
+            + w.lost_payload * self.lost_payload_factor
+            + w.drought * self.drought_factor
+            + w.expiry * self.expiry_factor
+            + w.earnings * self.earnings_factor
+            + w.crew * self.crew_factor
     }
 
     /// Called on a successful launch.
@@ -45,6 +100,7 @@ impl Reputation {
         // Decay existing factors
         self.success_factor *= cfg.success_decay;
         self.lost_payload_factor *= cfg.lost_payload_decay;
+        self.crew_factor *= cfg.crew_decay;
         // Add success bonus
         self.success_factor += cfg.success_gain;
         // Reset drought
@@ -82,12 +138,51 @@ impl Reputation {
         self.expiry_factor *= cfg.expiry_decay;
     }
 
+    /// Called when an outside provider delivers a contract the company
+    /// subcontracted: the customer is served, but the company gets
+    /// only `fame_share` of the credit for the launch.
+    pub fn on_subcontracted_delivery(&mut self, cfg: &ReputationConfig, fame_share: f64) {
+        self.expiry_factor *= cfg.expiry_decay;
+        self.success_factor += cfg.success_gain * fame_share;
+    }
+
     /// Called when an accepted contract expires without successful
     /// launch. `severity` scales the penalty by the contract's market.
     pub fn on_contract_expired(&mut self, cfg: &ReputationConfig, severity: f64) {
         self.expiry_factor -= cfg.expiry_penalty * severity;
     }
 
+    /// Called on a quarterly earnings report (listed companies only).
+    pub fn on_earnings_report(&mut self, cfg: &ReputationConfig, met: bool) {
+        if met {
+            self.earnings_factor *= cfg.earnings_decay;
+        } else {
+            self.earnings_factor -= cfg.earnings_miss_penalty;
+        }
+    }
+
+    /// Called when a launch succeeds in front of the press: the extra
+    /// fame beyond an ordinary success (`fame_mult` 1.0 adds nothing).
+    pub fn on_press_coverage(&mut self, cfg: &ReputationConfig, fame_mult: f64) {
+        self.success_factor += (fame_mult - 1.0) * cfg.success_gain;
+    }
+
+    /// Called when a competitor copies one of the company's designs.
+    pub fn on_design_copied(&mut self, penalty: f64) {
+        self.success_factor -= penalty;
+    }
+
+    /// Called when a pad disaster makes the news (`penalty` > 0), or a
+    /// good answer to the press wins some of it back (`penalty` < 0).
+    pub fn on_press_crisis(&mut self, penalty: f64) {
+        self.success_factor -= penalty;
+    }
+
+    /// Called when a crew is lost, on top of the launch failure itself.
+    pub fn on_crew_loss(&mut self, cfg: &ReputationConfig) {
+        self.crew_factor -= cfg.crew_loss_penalty;
+    }
+
     /// Called on each year anniversary without a launch.
     pub fn on_year_without_launch(&mut self, cfg: &ReputationConfig) {
         self.drought_factor -= cfg.drought_penalty;
@@ -171,6 +266,29 @@ mod tests {
         assert!((lenient.expiry_factor - (-cfg().expiry_penalty * 0.7)).abs() < 1e-9);
     }
 
+    #[test]
+    fn test_segments_weigh_events_differently() {
+        let mut rep = Reputation::new();
+        rep.on_crew_loss(&cfg());
+        let public = rep.segment(ReputationSegment::Public, &cfg());
+        let commercial = rep.segment(ReputationSegment::Commercial, &cfg());
+        assert!(public < commercial, "a crew accident hits public opinion hardest");
+        assert!((rep.total() + cfg().crew_loss_penalty).abs() < 1e-9);
+
+        let mut rep = Reputation::new();
+        rep.on_contract_expired(&cfg(), 1.0);
+        let public = rep.segment(ReputationSegment::Public, &cfg());
+        let commercial = rep.segment(ReputationSegment::Commercial, &cfg());
+        assert!(commercial < public, "missed deadlines hit commercial customers hardest");
+
+        // Launch successes count the same everywhere.
+        let mut rep = Reputation::new();
+        rep.on_launch_success(&cfg());
+        for seg in ReputationSegment::ALL {
+            assert_eq!(rep.segment(seg, &cfg()), rep.total());
+        }
+    }
+
     #[test]
     fn test_recovery_from_failure() {
         let mut rep = Reputation::new();
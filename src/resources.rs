@@ -211,6 +211,36 @@ pub fn rocket_integration_bom() -> BillOfMaterials {
 /// Fixed mass for rocket integration hardware (kg).
 pub const ROCKET_INTEGRATION_MASS_KG: f64 = 800.0;
 
+/// BOM for a payload fairing.
+/// Composite sandwich shells with a pyrotechnic separation system.
+pub fn fairing_bom() -> BillOfMaterials {
+    BillOfMaterials {
+        fractions: vec![
+            (Resource::Composites, 0.60),
+            (Resource::Aluminium, 0.25),
+            (Resource::Steel, 0.05),
+            (Resource::Wiring, 0.05),
+            (Resource::Plumbing, 0.04),
+            (Resource::Electronics, 0.01),
+        ],
+    }
+}
+
+/// BOM for a launch abort system: solid escape motors on a steel and
+/// composite tower.
+pub fn abort_system_bom() -> BillOfMaterials {
+    BillOfMaterials {
+        fractions: vec![
+            (Resource::SolidPropellant, 0.45),
+            (Resource::Steel, 0.20),
+            (Resource::Composites, 0.15),
+            (Resource::Superalloys, 0.10),
+            (Resource::Electronics, 0.05),
+            (Resource::Wiring, 0.05),
+        ],
+    }
+}
+
 /// Cost to manufacture an engine of given mass and propellant type.
 pub fn engine_material_cost(preset: PropellantPreset, engine_mass_kg: f64, prices: &ResourcePrices) -> f64 {
     engine_bom(preset).material_cost(engine_mass_kg, prices)
@@ -221,6 +251,16 @@ pub fn tank_material_cost(structural_mass_kg: f64, prices: &ResourcePrices) -> f
     tank_bom().material_cost(structural_mass_kg, prices)
 }
 
+/// Cost to manufacture a new payload fairing of given mass.
+pub fn fairing_material_cost(fairing_mass_kg: f64, prices: &ResourcePrices) -> f64 {
+    fairing_bom().material_cost(fairing_mass_kg, prices)
+}
+
+/// Cost to manufacture a launch abort system of given mass.
+pub fn abort_system_material_cost(mass_kg: f64, prices: &ResourcePrices) -> f64 {
+    abort_system_bom().material_cost(mass_kg, prices)
+}
+
 /// Fixed cost for stage assembly (wiring, avionics, etc.).
 pub fn stage_assembly_cost(prices: &ResourcePrices) -> f64 {
     stage_assembly_bom().material_cost(STAGE_ASSEMBLY_MASS_KG, prices)
@@ -231,42 +271,15 @@ pub fn rocket_integration_cost(prices: &ResourcePrices) -> f64 {
     rocket_integration_bom().material_cost(ROCKET_INTEGRATION_MASS_KG, prices)
 }
 
-/// Format a dollar amount for display (e.g. "$1.5M", "$300K").
 /// Format a money amount with comma thousands separators, e.g. $1,234,567.
 /// Used where exact amounts matter more than compactness (event log).
 pub fn format_money_exact(amount: f64) -> String {
-    let n = amount.round() as i64;
-    let s = n.unsigned_abs().to_string();
-    let mut out = String::new();
-    for (i, c) in s.chars().enumerate() {
-        if i > 0 && (s.len() - i).is_multiple_of(3) {
-            out.push(',');
-        }
-        out.push(c);
-    }
-    if n < 0 { format!("-${}", out) } else { format!("${}", out) }
+    crate::units::money_exact(amount, &crate::units::NumberFormat::ENGLISH)
 }
 
+/// Format a dollar amount for display (e.g. "$1.5M", "$300K").
 pub fn format_money(amount: f64) -> String {
-    if amount >= 1_000_000_000.0 {
-        format!("${:.1}B", amount / 1_000_000_000.0)
-    } else if amount >= 1_000_000.0 {
-        format!("${:.1}M", amount / 1_000_000.0)
-    } else if amount >= 1_000.0 {
-        format!("${:.0}K", amount / 1_000.0)
-    } else if amount < 0.0 {
-        if amount <= -1_000_000_000.0 {
-            format!("-${:.1}B", (-amount) / 1_000_000_000.0)
-        } else if amount <= -1_000_000.0 {
-            format!("-${:.1}M", (-amount) / 1_000_000.0)
-        } else if amount <= -1_000.0 {
-            format!("-${:.0}K", (-amount) / 1_000.0)
-        } else {
-            format!("-${:.0}", -amount)
-        }
-    } else {
-        format!("${:.0}", amount)
-    }
+    crate::units::money(amount, &crate::units::NumberFormat::ENGLISH)
 }
 
 #[cfg(test)]
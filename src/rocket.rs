@@ -1,7 +1,39 @@
 use serde::{Serialize, Deserialize};
 
+use crate::balance_config::ResourcePrices;
+use crate::engine_project::PropellantPreset;
 use crate::location::{self, DELTA_V_MAP};
-use crate::stage::Stage;
+use crate::resources;
+use crate::stage::{Stage, StagingMode};
+
+/// Peak acceleration (g) a stage that cannot throttle may pull at
+/// burnout. Liquid stages throttle back as their tanks empty, so only
+/// solid motors are held to this.
+pub const MAX_UNTHROTTLED_ACCEL_G: f64 = 6.0;
+
+/// Peak dynamic pressure (Pa) the airframe and fairing are rated for on
+/// an atmospheric ascent.
+pub const MAX_Q_LIMIT_PA: f64 = 45_000.0;
+
+/// Ascent failure chance per unit of fractional overage on a structural
+/// limit: 10% over the rating is a 20% chance the stack breaks up.
+pub const STRUCTURAL_RISK_PER_OVERAGE: f64 = 2.0;
+
+/// Density scale height of the launch atmosphere, used by the max-Q
+/// estimate.
+const ATMOSPHERE_SCALE_HEIGHT_M: f64 = 8_500.0;
+
+/// Flight performance reserve given to new designs: the fraction of
+/// delta-v held back from mission planning to absorb launch dispersions.
+pub const DEFAULT_PERFORMANCE_RESERVE: f64 = 0.02;
+
+/// Largest performance reserve a design may carry.
+pub const MAX_PERFORMANCE_RESERVE: f64 = 0.15;
+
+/// One-sigma launch performance dispersion as a fraction of delta-v.
+/// Engines run a little hot or cold, tanks load a little short — the
+/// reserve is what covers the cold side.
+pub const PERFORMANCE_DISPERSION_SIGMA: f64 = 0.01;
 
 /// Unique identifier for a rocket design.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -11,6 +43,29 @@ pub struct RocketDesignId(pub u64);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RocketId(pub u64);
 
+/// How full a rocket's liquid tanks are loaded for a launch. Solid
+/// motors are cast full and always fly that way.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum PropellantLoad {
+    #[default]
+    Full,
+    /// This share of every liquid stage's tanks.
+    Partial(f64),
+    /// Just enough for the mission at hand, keeping the design's
+    /// performance reserve.
+    AutoFill,
+}
+
+impl PropellantLoad {
+    pub fn label(self) -> String {
+        match self {
+            PropellantLoad::Full => "full".into(),
+            PropellantLoad::Partial(share) => format!("{:.0}%", share * 100.0),
+            PropellantLoad::AutoFill => "auto-fill".into(),
+        }
+    }
+}
+
 /// A rocket design blueprint.
 ///
 /// `stage_groups` is a Vec of sequential groups. Each group is a Vec of stages
@@ -24,6 +79,13 @@ pub struct RocketDesign {
     pub id: RocketDesignId,
     pub name: String,
     pub stage_groups: Vec<Vec<Stage>>,
+    /// Flight performance reserve: the fraction of delta-v kept as
+    /// unusable margin when planning missions (see `usable_delta_v`).
+    /// Launch dispersions draw against it. `None` only for designs
+    /// loaded from saves that predate reserves, which hold nothing back
+    /// and fly without dispersion until the player sets one.
+    #[serde(default)]
+    pub performance_reserve: Option<f64>,
 }
 
 /// Runtime state for a single stage within a rocket instance.
@@ -49,6 +111,48 @@ pub struct BurnResult {
     pub groups_jettisoned: Vec<usize>,
 }
 
+/// Peak ascent loads for a design, from [`RocketDesign::structural_loads`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StructuralLoads {
+    /// Estimated peak dynamic pressure on the first group's ascent (Pa).
+    /// Zero when launching from an airless body.
+    pub max_q_pa: f64,
+    /// Highest burnout acceleration (g) of any stage that can't throttle.
+    pub peak_unthrottled_accel_g: f64,
+}
+
+impl StructuralLoads {
+    /// Human-readable limit violations (empty = within limits).
+    pub fn violations(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.max_q_pa > MAX_Q_LIMIT_PA {
+            problems.push(format!(
+                "Max-Q {:.0} kPa exceeds the {:.0} kPa structural rating",
+                self.max_q_pa / 1000.0, MAX_Q_LIMIT_PA / 1000.0,
+            ));
+        }
+        if self.peak_unthrottled_accel_g > MAX_UNTHROTTLED_ACCEL_G {
+            problems.push(format!(
+                "Unthrottled burnout acceleration {:.1} g exceeds the {:.0} g limit",
+                self.peak_unthrottled_accel_g, MAX_UNTHROTTLED_ACCEL_G,
+            ));
+        }
+        problems
+    }
+
+    /// Chance the vehicle breaks up on ascent under these loads. Zero
+    /// within limits; each overage adds an independent risk that scales
+    /// with how far past the rating it is.
+    pub fn failure_risk(&self) -> f64 {
+        let over = |value: f64, limit: f64| {
+            ((value / limit - 1.0) * STRUCTURAL_RISK_PER_OVERAGE).clamp(0.0, 1.0)
+        };
+        let q_risk = over(self.max_q_pa, MAX_Q_LIMIT_PA);
+        let accel_risk = over(self.peak_unthrottled_accel_g, MAX_UNTHROTTLED_ACCEL_G);
+        1.0 - (1.0 - q_risk) * (1.0 - accel_risk)
+    }
+}
+
 /// Effective steady-state output of one power source on a given stage.
 /// Same as `PowerSource::steady_output_w` except fuel cells return 0 if
 /// the host stage's engine has propellant the cell can't burn (solid
@@ -76,7 +180,37 @@ pub struct Rocket {
     pub stage_states: Vec<Vec<StageState>>,
 }
 
+/// Stage-level changes between two snapshots of one design.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DesignDiff {
+    /// (group, index) of each stage whose hardware differs.
+    pub changed_stages: Vec<(usize, usize)>,
+}
+
+impl DesignDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changed_stages.is_empty()
+    }
+}
+
 impl RocketDesign {
+    /// Diff this snapshot against a `newer` one of the same design.
+    /// None if the stage layout itself changed — that vehicle can't be
+    /// reworked into the new one.
+    pub fn diff(&self, newer: &RocketDesign) -> Option<DesignDiff> {
+        if self.stage_groups.len() != newer.stage_groups.len()
+            || self.stage_groups.iter().zip(&newer.stage_groups).any(|(a, b)| a.len() != b.len())
+        {
+            return None;
+        }
+        let changed_stages = self.stage_groups.iter().zip(&newer.stage_groups).enumerate()
+            .flat_map(|(gi, (old, new))| old.iter().zip(new).enumerate()
+                .filter(|(_, (a, b))| !a.same_hardware(b))
+                .map(move |(si, _)| (gi, si)))
+            .collect();
+        Some(DesignDiff { changed_stages })
+    }
+
     /// Total wet mass of the entire vehicle (excluding payload).
     pub fn total_mass_kg(&self) -> f64 {
         self.stage_groups.iter()
@@ -156,6 +290,11 @@ impl RocketDesign {
     }
 
     /// Validate the design. Returns a list of problems (empty = valid).
+    ///
+    /// Structural loads are checked for an empty vehicle launched from
+    /// Earth's surface — the lightest stack sees the highest max-Q and
+    /// burnout acceleration, so a design that passes empty is safe with
+    /// any payload.
     pub fn validate(&self) -> Vec<String> {
         let mut errors = Vec::new();
         if self.stage_groups.is_empty() {
@@ -165,10 +304,155 @@ impl RocketDesign {
             if group.is_empty() {
                 errors.push(format!("Stage group {} is empty", gi));
             }
+            for (si, stage) in group.iter().enumerate() {
+                if stage.crossfeed {
+                    if si == 0 {
+                        errors.push(format!("Stage group {} core cannot crossfeed to itself", gi));
+                    } else if let Some(problem) = stage.crossfeed_problem(&group[0]) {
+                        errors.push(format!("Stage group {} booster {}: {}", gi, si, problem));
+                    }
+                } else if stage.engine_count == 0 {
+                    errors.push(format!("Stage group {} stage {} has no engines", gi, si));
+                }
+            }
         }
+        errors.extend(self.structural_loads(0.0, "earth_surface").violations());
         errors
     }
 
+    /// Physics sanity checks on the delta-v model itself, as opposed to
+    /// the player-facing rules in `validate`. Returns the invariants this
+    /// design breaks (empty = all hold); a non-empty result means the
+    /// model regressed, not that the design is bad. Each group is
+    /// checked carrying the real stack above it:
+    ///
+    /// - usable delta-v never exceeds ideal;
+    /// - more propellant in a group's last-burning stage never lowers the
+    ///   group's delta-v, when its engines share an Isp;
+    /// - strapping on a mirror of a group's stages never lowers it;
+    /// - another engine never makes a stage lighter or cheaper to build.
+    pub fn validate_invariants(&self) -> Vec<String> {
+        let mut broken = Vec::new();
+        let ideal = self.total_delta_v(0.0);
+        if self.usable_delta_v(0.0) > ideal + 1e-6 {
+            broken.push("Usable delta-v exceeds ideal".into());
+        }
+        let masses_above = self.masses_above(0.0);
+        let prices = ResourcePrices::default();
+        for (gi, group) in self.stage_groups.iter().enumerate() {
+            if group.is_empty() {
+                continue;
+            }
+            let above = masses_above[gi];
+            let group_dv = |g: &[Stage]| if g.len() == 1 {
+                g[0].delta_v(above)
+            } else {
+                phased_parallel_delta_v(g, above)
+            };
+            let base = group_dv(group);
+            if !base.is_finite() {
+                continue;
+            }
+            let floor = base - 1e-6 * base.abs().max(1.0);
+
+            for (si, stage) in group.iter().enumerate() {
+                let more_engines = Stage { engine_count: stage.engine_count + 1, ..stage.clone() };
+                if more_engines.dry_mass_kg() < stage.dry_mass_kg() {
+                    broken.push(format!("Stage group {} stage {}: another engine lowers dry mass", gi, si));
+                }
+                // Priced as a kerolox engine at default prices; any
+                // non-negative unit cost must do.
+                let engine_cost = resources::engine_material_cost(PropellantPreset::Kerolox, stage.engine.mass_kg, &prices);
+                if more_engines.material_cost(engine_cost, &prices) < stage.material_cost(engine_cost, &prices) {
+                    broken.push(format!("Stage group {} stage {}: another engine lowers build cost", gi, si));
+                }
+            }
+            // Crossfeed reshuffles burn order; the checks below assume
+            // each stage drains its own tank.
+            if group.iter().any(|s| s.crossfeed) {
+                continue;
+            }
+
+            // Extra propellant only reliably pays in the stage that burns
+            // out last, at one Isp: anywhere else it also keeps spent
+            // structure (or low-Isp mass) aboard longer.
+            let one_isp = group.iter().all(|s| s.engine.isp_s == group[0].engine.isp_s);
+            let last = (0..group.len())
+                .max_by(|&a, &b| group[a].burn_time_s().total_cmp(&group[b].burn_time_s()));
+            if let (true, Some(si)) = (one_isp, last) {
+                let mut fuller = group.clone();
+                fuller[si].propellant_mass_kg += (fuller[si].propellant_mass_kg * 0.1).max(1.0);
+                if group_dv(&fuller) < floor {
+                    broken.push(format!("Stage group {} stage {}: more propellant lowers delta-v", gi, si));
+                }
+            }
+
+            // A mirrored set of boosters splits the load above over twice
+            // the stack at the same mass ratios. (A lone extra copy of the
+            // core can legitimately lose delta-v beside uneven boosters.)
+            let mut boosted = group.clone();
+            boosted.extend(group.iter().cloned());
+            if group_dv(&boosted) < floor {
+                broken.push(format!("Stage group {}: mirroring its boosters lowers delta-v", gi));
+            }
+        }
+        broken
+    }
+
+    /// Peak ascent loads carrying `payload_kg` from `launch_from`.
+    ///
+    /// Max-Q treats the first group's liftoff acceleration as constant
+    /// through an exponential atmosphere, where dynamic pressure peaks
+    /// one scale height up at `rho0 * a * H / e`. Burnout acceleration
+    /// is taken at the instant each non-throttleable stage exhausts its
+    /// propellant, with any longer-burning parallel stages still partly
+    /// fuelled.
+    pub fn structural_loads(&self, payload_kg: f64, launch_from: &str) -> StructuralLoads {
+        let mut loads = StructuralLoads { max_q_pa: 0.0, peak_unthrottled_accel_g: 0.0 };
+
+        let total_mass = self.total_mass_kg() + payload_kg;
+        if let Some(props) = DELTA_V_MAP.surface_properties(launch_from) {
+            if props.has_atmosphere && total_mass > 0.0 {
+                let net_accel = self.group_thrust_n(0) / total_mass - props.gravity_m_s2;
+                if net_accel > 0.0 {
+                    loads.max_q_pa = props.atmosphere_density * net_accel
+                        * ATMOSPHERE_SCALE_HEIGHT_M / std::f64::consts::E;
+                }
+            }
+        }
+
+        let masses_above = self.masses_above(payload_kg);
+        for (gi, group) in self.stage_groups.iter().enumerate() {
+            let mass_above = masses_above[gi];
+            let flow = |s: &Stage| s.engine.mass_flow_rate() * s.engine_count as f64;
+
+            for stage in group.iter().filter(|s| !s.engine.can_throttle()) {
+                let stage_flow = flow(stage);
+                if stage_flow <= 0.0 {
+                    continue;
+                }
+                let burnout_s = stage.propellant_mass_kg / stage_flow;
+                let mut mass = mass_above;
+                let mut thrust = 0.0;
+                for other in group {
+                    let other_flow = flow(other);
+                    let burned = other_flow * burnout_s;
+                    mass += other.dry_mass_kg() + (other.propellant_mass_kg - burned).max(0.0);
+                    // Stages that outlast (or tie) this one are still firing.
+                    if other_flow > 0.0 && other.propellant_mass_kg >= burned - 1e-6 {
+                        thrust += other.total_thrust_n();
+                    }
+                }
+                if mass > 0.0 {
+                    let accel_g = thrust / mass / crate::engine::G0;
+                    loads.peak_unthrottled_accel_g = loads.peak_unthrottled_accel_g.max(accel_g);
+                }
+            }
+        }
+
+        loads
+    }
+
     /// Delta-v for a single stage group, accounting for phased parallel burnout.
     ///
     /// When multiple stages fire in parallel, they may have different burn times.
@@ -201,25 +485,153 @@ impl RocketDesign {
             .any(|s| s.engine.is_low_thrust())
     }
 
+    /// True if any stage burns solid propellant — solid motors trigger
+    /// environmental reviews at the launch site.
+    pub fn uses_solid_motors(&self) -> bool {
+        self.stage_groups.iter().flatten()
+            .any(|s| s.engine.is_solid())
+    }
+
+    /// True if a launch abort system is fitted to any stage.
+    pub fn has_launch_abort_system(&self) -> bool {
+        self.stage_groups.iter().flatten()
+            .any(|s| s.launch_abort_system)
+    }
+
+    /// True if any stage flies the given engine.
+    pub fn uses_engine(&self, engine_id: crate::engine::EngineId) -> bool {
+        self.stage_groups.iter().flatten()
+            .any(|s| s.engine.id == engine_id)
+    }
+
+    /// Longest coast leg (in days) the design can fly: the limit of its
+    /// final stage group, which has to survive every coast to make the
+    /// burns at the far end.
+    pub fn max_coast_days(&self) -> u32 {
+        self.stage_groups.last()
+            .and_then(|g| g.iter().map(|s| s.coast_limit_days()).min())
+            .unwrap_or(u32::MAX)
+    }
+
     /// Total delta-v across all stage groups for a given payload.
     /// Each group's "payload" is everything above it: upper groups + actual payload.
+    /// Includes what each stage boundary's staging mode gains or loses.
     pub fn total_delta_v(&self, payload_kg: f64) -> f64 {
-        let n = self.stage_groups.len();
-        let mut total_dv = 0.0;
+        let stages: f64 = self.masses_above(payload_kg).into_iter()
+            .enumerate()
+            .map(|(gi, above)| self.group_delta_v(gi, above))
+            .sum();
+        (stages + self.staging_delta_v()).max(0.0)
+    }
 
-        // Work from top to bottom to accumulate payload masses, then bottom to top for dv
-        // First, compute the dry+wet mass of each group above
-        for gi in 0..n {
-            let payload_above: f64 = self.stage_groups[gi + 1..].iter()
-                .flat_map(|g| g.iter())
-                .map(|s| s.wet_mass_kg())
-                .sum::<f64>()
-                + payload_kg;
+    /// How each stage boundary separates, bottom first: one entry per
+    /// group above the first, read from that group's core stage.
+    pub fn staging_modes(&self) -> Vec<StagingMode> {
+        (1..self.stage_groups.len()).map(|gi| self.staging_into(gi)).collect()
+    }
 
-            total_dv += self.group_delta_v(gi, payload_above);
+    /// How group `gi` separates from the group below it.
+    pub fn staging_into(&self, gi: usize) -> StagingMode {
+        self.stage_groups.get(gi)
+            .and_then(|g| g.first())
+            .map_or(StagingMode::Cold, |s| s.staging)
+    }
+
+    /// Number of hot-staged boundaries in the stack.
+    pub fn hot_staged_boundaries(&self) -> usize {
+        self.staging_modes().into_iter().filter(|m| *m == StagingMode::Hot).count()
+    }
+
+    /// Delta-v gained (hot) or lost to coasting (cold) across every
+    /// stage boundary.
+    pub fn staging_delta_v(&self) -> f64 {
+        self.staging_modes().into_iter().map(StagingMode::delta_v_adjustment).sum()
+    }
+
+    /// Mass each stage group lifts: the wet mass of every group above
+    /// it plus `payload_kg`. One pass from the top down, so per-group
+    /// queries don't re-sum the stack.
+    pub fn masses_above(&self, payload_kg: f64) -> Vec<f64> {
+        let mut above = vec![0.0; self.stage_groups.len()];
+        let mut running = payload_kg;
+        for (gi, group) in self.stage_groups.iter().enumerate().rev() {
+            above[gi] = running;
+            running += group.iter().map(|s| s.wet_mass_kg()).sum::<f64>();
         }
+        above
+    }
+
+    /// Fraction of delta-v held back by the performance reserve (zero
+    /// for legacy designs).
+    pub fn reserve_fraction(&self) -> f64 {
+        self.performance_reserve.unwrap_or(0.0)
+    }
+
+    /// Whether launches of this design draw a performance dispersion.
+    /// Every design does except legacy ones with no reserve set.
+    pub fn is_dispersed(&self) -> bool {
+        self.performance_reserve.is_some()
+    }
+
+    /// Delta-v available for mission planning: the total less the
+    /// design's performance reserve.
+    pub fn usable_delta_v(&self, payload_kg: f64) -> f64 {
+        self.total_delta_v(payload_kg) * (1.0 - self.reserve_fraction())
+    }
+
+    /// This design with every liquid stage's tanks `share` full.
+    pub fn with_propellant_load(&self, share: f64) -> RocketDesign {
+        let mut loaded = self.clone();
+        for stage in loaded.stage_groups.iter_mut().flatten() {
+            if !stage.engine.is_solid() && !stage.engine.is_solar_sail() {
+                stage.propellant_mass_kg *= share.clamp(0.0, 1.0);
+            }
+        }
+        loaded
+    }
+
+    /// Every stage's propellant, solid and liquid.
+    pub fn total_propellant_mass_kg(&self) -> f64 {
+        self.stage_groups.iter().flatten().map(|s| s.propellant_mass_kg).sum()
+    }
+
+    /// What it costs to fill this design's liquid tanks. Solid
+    /// propellant is paid for when the motor is built.
+    pub fn liquid_propellant_cost(&self) -> f64 {
+        self.stage_groups.iter().flatten()
+            .filter(|s| !s.engine.is_solid())
+            .map(|s| s.propellant_mass_kg * s.engine.propellant_cost_per_kg())
+            .sum()
+    }
 
-        total_dv
+    /// The smallest share of its liquid tanks, no less than
+    /// `min_share`, that still gives `required_dv` of usable delta-v
+    /// with `payload_kg` aboard — and that a lighter stack doesn't fly
+    /// past its structural limits any worse than full tanks would.
+    /// None if even full tanks fall short.
+    pub fn fill_share_for(&self, payload_kg: f64, required_dv: f64, min_share: f64) -> Option<f64> {
+        let full_risk = self.structural_loads(payload_kg, "earth_surface").failure_risk();
+        let enough = |share: f64| {
+            let loaded = self.with_propellant_load(share);
+            loaded.usable_delta_v(payload_kg) >= required_dv
+                && loaded.structural_loads(payload_kg, "earth_surface").failure_risk() <= full_risk
+        };
+        if self.usable_delta_v(payload_kg) < required_dv {
+            return None;
+        }
+        let (mut lo, mut hi) = (min_share.clamp(0.0, 1.0), 1.0);
+        if enough(lo) {
+            return Some(lo);
+        }
+        for _ in 0..30 {
+            let mid = (lo + hi) / 2.0;
+            if enough(mid) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Some(hi)
     }
 
     /// Create a Rocket instance from this design at a given location with a payload.
@@ -262,7 +674,15 @@ impl RocketDesign {
 /// 3. All stages fire for that duration; apply Tsiolkovsky for the mass change
 /// 4. Jettison the depleted stage(s), reducing total mass
 /// 5. Repeat until all stages are depleted
+///
+/// Crossfed boosters (see `Stage::crossfeed`) pool their tanks: while the
+/// pool lasts, the core and every crossfed booster burn from it, so the
+/// core's own tank stays full and the crossfed boosters all empty — and
+/// separate — together.
 fn phased_parallel_delta_v(stages: &[Stage], payload_above_kg: f64) -> f64 {
+    let flow = |i: usize| stages[i].engine.mass_flow_rate() * stages[i].engine_count as f64;
+    let crossfed = |i: usize| i > 0 && stages[i].crossfeed && !stages[0].crossfeed;
+
     // Working state: (index, remaining_propellant_kg)
     let mut remaining: Vec<(usize, f64)> = stages.iter()
         .enumerate()
@@ -278,13 +698,29 @@ fn phased_parallel_delta_v(stages: &[Stage], payload_above_kg: f64) -> f64 {
             .sum();
         let m_initial = payload_above_kg + stages_mass;
 
+        // Crossfeed pool, and which stages are drawing from it this phase
+        let pool: f64 = remaining.iter()
+            .filter(|(i, _)| crossfed(*i))
+            .map(|(_, prop)| prop)
+            .sum();
+        let feeding = pool > 1e-6 && remaining.iter().any(|(i, _)| *i == 0);
+        let draws_pool = |i: usize| feeding && (i == 0 || crossfed(i));
+        let pool_flow: f64 = remaining.iter()
+            .filter(|(i, _)| draws_pool(*i))
+            .map(|(i, _)| flow(*i))
+            .sum();
+
         // Find the shortest remaining burn time among active stages
         let min_burn_time = remaining.iter()
+            .filter(|(i, _)| !draws_pool(*i))
             .map(|(i, prop)| {
-                let flow = stages[*i].engine.mass_flow_rate() * stages[*i].engine_count as f64;
-                if flow <= 0.0 { f64::INFINITY } else { prop / flow }
+                let f = flow(*i);
+                if f <= 0.0 { f64::INFINITY } else { prop / f }
             })
-            .fold(f64::INFINITY, f64::min);
+            .fold(
+                if pool_flow > 0.0 { pool / pool_flow } else { f64::INFINITY },
+                f64::min,
+            );
 
         if min_burn_time <= 0.0 || min_burn_time.is_infinite() {
             break;
@@ -292,10 +728,7 @@ fn phased_parallel_delta_v(stages: &[Stage], payload_above_kg: f64) -> f64 {
 
         // Total propellant consumed in this phase
         let prop_consumed: f64 = remaining.iter()
-            .map(|(i, _)| {
-                let flow = stages[*i].engine.mass_flow_rate() * stages[*i].engine_count as f64;
-                flow * min_burn_time
-            })
+            .map(|(i, _)| flow(*i) * min_burn_time)
             .sum();
 
         // Compute effective exhaust velocity for this phase
@@ -304,7 +737,7 @@ fn phased_parallel_delta_v(stages: &[Stage], payload_above_kg: f64) -> f64 {
             .map(|(i, _)| stages[*i].total_thrust_n())
             .sum();
         let total_flow: f64 = remaining.iter()
-            .map(|(i, _)| stages[*i].engine.mass_flow_rate() * stages[*i].engine_count as f64)
+            .map(|(i, _)| flow(*i))
             .sum();
         let ve_eff = if total_flow > 0.0 { total_thrust / total_flow } else { 0.0 };
 
@@ -315,11 +748,18 @@ fn phased_parallel_delta_v(stages: &[Stage], payload_above_kg: f64) -> f64 {
 
         total_dv += ve_eff * (m_initial / m_final).ln();
 
-        // Update remaining propellant, remove depleted stages
+        // Update remaining propellant, remove depleted stages. Pool draw
+        // comes out of the crossfed tanks in proportion to what they hold.
+        let pool_drawn = pool_flow * min_burn_time;
         remaining = remaining.into_iter()
             .filter_map(|(i, prop)| {
-                let flow = stages[i].engine.mass_flow_rate() * stages[i].engine_count as f64;
-                let new_prop = prop - flow * min_burn_time;
+                let new_prop = if feeding && crossfed(i) {
+                    prop - pool_drawn * prop / pool
+                } else if draws_pool(i) {
+                    prop
+                } else {
+                    prop - flow(i) * min_burn_time
+                };
                 if new_prop > 1e-6 {
                     Some((i, new_prop))
                 } else {
@@ -428,9 +868,17 @@ impl Rocket {
             } else if active_stages.len() > 1 {
                 total += phased_parallel_delta_v(&active_stages, payload_above);
             }
+            if !active_stages.is_empty() && self.group_attached(gi + 1) {
+                total += design.staging_into(gi + 1).delta_v_adjustment();
+            }
         }
 
-        total
+        total.max(0.0)
+    }
+
+    /// True if any stage of group `gi` is still attached.
+    fn group_attached(&self, gi: usize) -> bool {
+        self.stage_states.get(gi).is_some_and(|ss| ss.iter().any(|s| s.attached))
     }
 
     /// Burn through stage groups sequentially to achieve target delta-v.
@@ -497,6 +945,14 @@ impl Rocket {
                 }
                 groups_burned.push(gi);
                 groups_jettisoned.push(gi);
+
+                // Staging mid-burn: a hot start carries the burn on, a
+                // cold coast loses some of it.
+                if self.group_attached(gi + 1) {
+                    let adjustment = design.staging_into(gi + 1).delta_v_adjustment();
+                    dv_achieved += adjustment;
+                    dv_remaining -= adjustment;
+                }
             }
         }
 
@@ -602,15 +1058,40 @@ impl Rocket {
 
         let prop_used = prop_needed.min(total_prop).max(0.0);
 
-        // Distribute consumed propellant proportionally by mass flow rate
+        // Distribute consumed propellant proportionally by mass flow rate.
+        // The core's and crossfed boosters' shares come out of the
+        // crossfed tanks first; only the shortfall drains the core.
+        let group = &design.stage_groups[gi];
+        let crossfed = |si: usize| si > 0 && group[si].crossfeed && !group[0].crossfeed;
+        let core_active = active_indices.first() == Some(&0);
+        let mut pool_demand = 0.0;
         for &si in &active_indices {
-            let stage = &design.stage_groups[gi][si];
+            let stage = &group[si];
             let flow = stage.engine.mass_flow_rate() * stage.engine_count as f64;
             let fraction = if total_flow > 0.0 { flow / total_flow } else { 0.0 };
             let consumed = prop_used * fraction;
+            if core_active && (si == 0 || crossfed(si)) {
+                pool_demand += consumed;
+                continue;
+            }
             self.stage_states[gi][si].propellant_remaining_kg =
                 (self.stage_states[gi][si].propellant_remaining_kg - consumed).max(0.0);
         }
+        if pool_demand > 0.0 {
+            let pool: f64 = active_indices.iter()
+                .filter(|&&si| crossfed(si))
+                .map(|&si| self.stage_states[gi][si].propellant_remaining_kg)
+                .sum();
+            let from_pool = pool_demand.min(pool);
+            for &si in active_indices.iter().filter(|&&si| crossfed(si)) {
+                let ss = &mut self.stage_states[gi][si];
+                ss.propellant_remaining_kg =
+                    (ss.propellant_remaining_kg - from_pool * ss.propellant_remaining_kg / pool).max(0.0);
+            }
+            let core = &mut self.stage_states[gi][0];
+            core.propellant_remaining_kg =
+                (core.propellant_remaining_kg - (pool_demand - from_pool)).max(0.0);
+        }
 
         // Compute actual dv achieved
         let mf_actual = m0 - prop_used;
@@ -950,17 +1431,13 @@ pub fn compute_stage_stats(
     };
 
     let mut results = Vec::with_capacity(n);
+    // Mass above each group: upper groups + payload
+    let masses_above = design.masses_above(payload_kg);
 
     for gi in 0..n {
         let group = &design.stage_groups[gi];
         let (thrust, flow, prop) = stage_params[gi];
-
-        // Mass above this group: upper groups + payload
-        let payload_above: f64 = design.stage_groups[gi + 1..].iter()
-            .flat_map(|g| g.iter())
-            .map(|s| s.wet_mass_kg())
-            .sum::<f64>()
-            + payload_kg;
+        let payload_above = masses_above[gi];
 
         let group_wet: f64 = group.iter().map(|s| s.wet_mass_kg()).sum();
         let group_dry: f64 = group.iter().map(|s| s.dry_mass_kg()).sum();
@@ -1075,6 +1552,11 @@ mod tests {
             propellant_mass_kg: 50_000.0, structural_mass_kg: 3_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -1082,12 +1564,18 @@ mod tests {
             propellant_mass_kg: 10_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let rocket = RocketDesign {
             id: RocketDesignId(1),
             name: "TwoStager".into(),
             stage_groups: vec![vec![s1.clone()], vec![s2.clone()]],
+            performance_reserve: Some(0.0),
         };
 
         let payload = 1_000.0;
@@ -1099,12 +1587,28 @@ mod tests {
         let s1_payload = s2.wet_mass_kg() + payload;
         let s1_dv = s1.delta_v(s1_payload);
 
-        let expected = s1_dv + s2_dv;
+        // Cold staging coasts between the two burns.
+        let expected = s1_dv + s2_dv - crate::stage::COLD_STAGING_COAST_LOSS_DV;
         assert!(
             (total_dv - expected).abs() < 1.0,
             "total_dv={}, expected={} (s1_dv={}, s2_dv={})",
             total_dv, expected, s1_dv, s2_dv
         );
+        assert_eq!(rocket.masses_above(payload), vec![s1_payload, payload]);
+
+        // Hot staging skips the coast and gains on top; a burn through
+        // the boundary sees the same.
+        let mut hot = rocket.clone();
+        hot.stage_groups[1][0].staging = StagingMode::Hot;
+        assert_eq!(hot.hot_staged_boundaries(), 1);
+        let gain = crate::stage::COLD_STAGING_COAST_LOSS_DV + crate::stage::HOT_STAGING_DV_GAIN;
+        assert!((hot.total_delta_v(payload) - total_dv - gain).abs() < 1e-6);
+        let mut cold_vehicle = rocket.instantiate(RocketId(1), "earth_surface", payload);
+        let mut hot_vehicle = hot.instantiate(RocketId(2), "earth_surface", payload);
+        let target = s1_dv + 500.0;
+        cold_vehicle.burn_sequential(&rocket, target, 0.0);
+        hot_vehicle.burn_sequential(&hot, target, 0.0);
+        assert!(hot_vehicle.remaining_delta_v(&hot) > cold_vehicle.remaining_delta_v(&rocket) + gain - 1.0);
     }
 
     // --- Parallel burnout tests ---
@@ -1121,12 +1625,18 @@ mod tests {
             propellant_mass_kg: 20_000.0, structural_mass_kg: 1_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let rocket = RocketDesign {
             id: RocketDesignId(1),
             name: "TwinBooster".into(),
             stage_groups: vec![vec![stage.clone(), stage.clone()]],
+            performance_reserve: Some(0.0),
         };
 
         let payload = 2_000.0;
@@ -1158,6 +1668,11 @@ mod tests {
             propellant_mass_kg: 100_000.0, structural_mass_kg: 5_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let srb = Stage {
             id: StageId(2), name: "SRB".into(),
@@ -1165,12 +1680,18 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let rocket = RocketDesign {
             id: RocketDesignId(1),
             name: "CorePlusSRBs".into(),
             stage_groups: vec![vec![core.clone(), srb.clone(), srb.clone()]],
+            performance_reserve: Some(0.0),
         };
 
         let payload = 5_000.0;
@@ -1200,6 +1721,11 @@ mod tests {
             propellant_mass_kg: 80_000.0, structural_mass_kg: 4_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let srb = Stage {
             id: StageId(2), name: "SRB".into(),
@@ -1207,6 +1733,11 @@ mod tests {
             propellant_mass_kg: 20_000.0, structural_mass_kg: 1_500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let payload = 10_000.0;
@@ -1229,6 +1760,109 @@ mod tests {
         );
     }
 
+    fn crossfeed_stage(id: u64, engine: EngineDesign, engine_count: u32, prop: f64, dry: f64) -> Stage {
+        Stage {
+            id: StageId(id), name: format!("S{}", id),
+            engine, engine_count,
+            propellant_mass_kg: prop, structural_mass_kg: dry,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: true,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_crossfeed_keeps_core_full_through_booster_burn() {
+        // Three identical liquid cores, boosters crossfed: all three engines
+        // drain the boosters' tanks, the boosters drop together, then the
+        // core burns its full load alone.
+        let engine = kerolox_engine(1, 800_000.0, 400.0, 300.0);
+        let core = Stage { crossfeed: false, ..crossfeed_stage(1, engine.clone(), 1, 60_000.0, 4_000.0) };
+        let booster = crossfeed_stage(2, engine.clone(), 1, 60_000.0, 4_000.0);
+        let payload = 5_000.0;
+
+        let plain = phased_parallel_delta_v(
+            &[core.clone(), Stage { crossfeed: false, ..booster.clone() }, Stage { crossfeed: false, ..booster.clone() }],
+            payload,
+        );
+        let crossfed = phased_parallel_delta_v(&[core.clone(), booster.clone(), booster.clone()], payload);
+
+        let ve = engine.exhaust_velocity();
+        let m0 = 3.0 * core.wet_mass_kg() + payload;
+        let expected = ve * (m0 / (m0 - 120_000.0)).ln()
+            + ve * ((core.wet_mass_kg() + payload) / (core.dry_mass_kg() + payload)).ln();
+        assert!((crossfed - expected).abs() < 1.0, "crossfed={}, expected={}", crossfed, expected);
+        assert!(crossfed > plain, "crossfeed {} should beat plain parallel {}", crossfed, plain);
+    }
+
+    #[test]
+    fn test_drop_tank_feeds_core() {
+        let engine = kerolox_engine(1, 800_000.0, 400.0, 300.0);
+        let core = Stage { crossfeed: false, ..crossfeed_stage(1, engine.clone(), 1, 40_000.0, 3_000.0) };
+        let tank = crossfeed_stage(2, engine.clone(), 0, 20_000.0, 1_000.0);
+        let payload = 2_000.0;
+
+        let dv = phased_parallel_delta_v(&[core.clone(), tank.clone()], payload);
+
+        let ve = engine.exhaust_velocity();
+        let m0 = core.wet_mass_kg() + tank.wet_mass_kg() + payload;
+        let expected = ve * (m0 / (m0 - 20_000.0)).ln()
+            + ve * ((core.wet_mass_kg() + payload) / (core.dry_mass_kg() + payload)).ln();
+        assert!((dv - expected).abs() < 1.0, "dv={}, expected={}", dv, expected);
+        assert!(dv > core.delta_v(payload));
+    }
+
+    #[test]
+    fn test_crossfeed_burn_draws_booster_tanks_first() {
+        let engine = kerolox_engine(1, 800_000.0, 400.0, 300.0);
+        let core = Stage { crossfeed: false, ..crossfeed_stage(1, engine.clone(), 1, 60_000.0, 4_000.0) };
+        let booster = crossfeed_stage(2, engine.clone(), 1, 60_000.0, 4_000.0);
+        let design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Crossfed".into(),
+            stage_groups: vec![vec![core, booster.clone(), booster]],
+            performance_reserve: Some(0.0),
+        };
+        assert!(design.validate().is_empty(), "{:?}", design.validate());
+
+        let full_dv = design.total_delta_v(1_000.0);
+        let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
+        assert!((rocket.remaining_delta_v(&design) - full_dv).abs() < 1.0);
+
+        rocket.burn_sequential(&design, 500.0, 0.0);
+        let states = &rocket.stage_states[0];
+        assert_eq!(states[0].propellant_remaining_kg, 60_000.0);
+        assert!(states[1].propellant_remaining_kg < 60_000.0);
+        assert!((states[1].propellant_remaining_kg - states[2].propellant_remaining_kg).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_crossfeed() {
+        let engine = kerolox_engine(1, 800_000.0, 400.0, 300.0);
+        let core = Stage { crossfeed: false, ..crossfeed_stage(1, engine.clone(), 1, 60_000.0, 4_000.0) };
+        let design = |group: Vec<Stage>| RocketDesign {
+            id: RocketDesignId(1),
+            name: "Bad".into(),
+            stage_groups: vec![group],
+            performance_reserve: Some(0.0),
+        };
+
+        let crossfed_core = design(vec![crossfeed_stage(1, engine.clone(), 1, 60_000.0, 4_000.0)]);
+        assert!(crossfed_core.validate().iter().any(|e| e.contains("core cannot crossfeed")));
+
+        let srb = crossfeed_stage(2, solid_engine(2, 1_000_000.0, 200.0, 250.0), 1, 10_000.0, 1_000.0);
+        let solid = design(vec![core.clone(), srb]);
+        assert!(solid.validate().iter().any(|e| e.contains("solid propellant")));
+
+        let bare = Stage { crossfeed: false, ..crossfeed_stage(2, engine.clone(), 0, 10_000.0, 1_000.0) };
+        let engineless = design(vec![core, bare]);
+        assert!(engineless.validate().iter().any(|e| e.contains("no engines")));
+    }
+
     // --- Multi-group tests ---
 
     #[test]
@@ -1243,6 +1877,11 @@ mod tests {
             propellant_mass_kg: 100_000.0, structural_mass_kg: 5_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let srb = Stage {
             id: StageId(2), name: "SRB".into(),
@@ -1250,13 +1889,23 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let upper = Stage {
             id: StageId(3), name: "Upper".into(),
             engine: upper_engine, engine_count: 1,
             propellant_mass_kg: 15_000.0, structural_mass_kg: 800.0,
-            fairing: Some(Fairing { mass_kg: 200.0, diameter_m: 4.0 }),
+            fairing: Some(Fairing { mass_kg: 200.0, diameter_m: 4.0, recover: false }),
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let rocket = RocketDesign {
@@ -1266,6 +1915,7 @@ mod tests {
                 vec![core, srb.clone(), srb],
                 vec![upper],
             ],
+            performance_reserve: Some(0.0),
         };
 
         assert!(rocket.validate().is_empty());
@@ -1287,6 +1937,11 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -1294,12 +1949,18 @@ mod tests {
             propellant_mass_kg: 8_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
 
         let payload = 1_000.0;
@@ -1323,12 +1984,18 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
 
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
@@ -1352,6 +2019,11 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -1359,12 +2031,18 @@ mod tests {
             propellant_mass_kg: 8_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
 
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
@@ -1386,12 +2064,18 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
 
         // wet = structural(2000) + engine(250) + prop(30000) = 32250
@@ -1404,6 +2088,7 @@ mod tests {
             id: RocketDesignId(1),
             name: "Empty".into(),
             stage_groups: vec![],
+            performance_reserve: Some(0.0),
         };
         assert!(!design.validate().is_empty());
 
@@ -1411,10 +2096,146 @@ mod tests {
             id: RocketDesignId(2),
             name: "EmptyGroup".into(),
             stage_groups: vec![vec![]],
+            performance_reserve: Some(0.0),
         };
         assert!(!design2.validate().is_empty());
     }
 
+    #[test]
+    fn test_structural_loads_flag_unthrottled_solid_burnout() {
+        // 2 MN on a 2.5 t burnout mass is ~80 g. A solid can't throttle
+        // back, so it fails validation; the same stage on a liquid engine
+        // is fine (it throttles down as the tanks empty).
+        let solid_stage = Stage {
+            id: StageId(1), name: "Kick".into(),
+            engine: solid_engine(1, 2_000_000.0, 500.0, 250.0), engine_count: 1,
+            propellant_mass_kg: 20_000.0, structural_mass_kg: 2_000.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let mut design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Kick".into(),
+            stage_groups: vec![vec![solid_stage]],
+            performance_reserve: Some(0.0),
+        };
+        let loads = design.structural_loads(0.0, "leo");
+        assert_eq!(loads.max_q_pa, 0.0, "no max-Q without an atmosphere");
+        assert!(loads.peak_unthrottled_accel_g > MAX_UNTHROTTLED_ACCEL_G);
+        assert!(design.validate().iter().any(|e| e.contains("burnout acceleration")));
+
+        design.stage_groups[0][0].engine = kerolox_engine(1, 2_000_000.0, 500.0, 250.0);
+        assert_eq!(design.structural_loads(0.0, "leo").peak_unthrottled_accel_g, 0.0);
+    }
+
+    #[test]
+    fn test_structural_loads_srb_burnout_counts_fuelled_core() {
+        // SRBs burn out while the core still holds most of its propellant,
+        // so burnout acceleration must include the core's remaining mass.
+        let core = Stage {
+            id: StageId(1), name: "Core".into(),
+            engine: kerolox_engine(1, 1_000_000.0, 1_000.0, 300.0), engine_count: 1,
+            propellant_mass_kg: 200_000.0, structural_mass_kg: 10_000.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let srb = Stage {
+            id: StageId(2), name: "SRB".into(),
+            engine: solid_engine(2, 1_000_000.0, 500.0, 250.0), engine_count: 1,
+            propellant_mass_kg: 20_000.0, structural_mass_kg: 2_000.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Heavy".into(),
+            stage_groups: vec![vec![core, srb]],
+            performance_reserve: Some(0.0),
+        };
+        let loads = design.structural_loads(0.0, "earth_surface");
+        // ~2 MN on ~200 t at SRB burnout — roughly 1 g, well in limits.
+        assert!(loads.peak_unthrottled_accel_g < 1.5,
+            "got {:.2} g", loads.peak_unthrottled_accel_g);
+        assert!(loads.violations().is_empty());
+        assert_eq!(loads.failure_risk(), 0.0);
+    }
+
+    #[test]
+    fn test_structural_loads_max_q_scales_with_liftoff_twr() {
+        let stage = |thrust: f64| Stage {
+            id: StageId(1), name: "S1".into(),
+            engine: kerolox_engine(1, thrust, 1_000.0, 300.0), engine_count: 1,
+            propellant_mass_kg: 95_000.0, structural_mass_kg: 4_000.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        // 100 t stack: TWR ~1.5 is comfortable, TWR ~4 overloads the airframe.
+        let gentle = RocketDesign {
+            id: RocketDesignId(1), name: "Gentle".into(),
+            stage_groups: vec![vec![stage(1_500_000.0)]],
+            performance_reserve: Some(0.0),
+        };
+        let brutal = RocketDesign {
+            id: RocketDesignId(2), name: "Brutal".into(),
+            stage_groups: vec![vec![stage(4_000_000.0)]],
+            performance_reserve: Some(0.0),
+        };
+        let q_gentle = gentle.structural_loads(0.0, "earth_surface").max_q_pa;
+        let q_brutal = brutal.structural_loads(0.0, "earth_surface").max_q_pa;
+        assert!(q_gentle > 0.0 && q_gentle < MAX_Q_LIMIT_PA, "got {q_gentle:.0}");
+        assert!(q_brutal > MAX_Q_LIMIT_PA, "got {q_brutal:.0}");
+        assert!(gentle.validate().is_empty());
+        assert!(brutal.validate().iter().any(|e| e.contains("Max-Q")));
+        // Payload slows the climb through the dense air.
+        assert!(brutal.structural_loads(200_000.0, "earth_surface").max_q_pa < q_brutal);
+    }
+
+    #[test]
+    fn test_usable_delta_v_holds_back_reserve() {
+        let stage = Stage {
+            id: StageId(1), name: "S1".into(),
+            engine: kerolox_engine(1, 1_000_000.0, 500.0, 300.0), engine_count: 1,
+            propellant_mass_kg: 50_000.0, structural_mass_kg: 2_000.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let mut design = RocketDesign {
+            id: RocketDesignId(1), name: "R".into(),
+            stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
+        };
+        let total = design.total_delta_v(1_000.0);
+        assert_eq!(design.usable_delta_v(1_000.0), total);
+        design.performance_reserve = Some(0.05);
+        assert!((design.usable_delta_v(1_000.0) - total * 0.95).abs() < 1e-6);
+        assert_eq!(design.total_delta_v(1_000.0), total, "reserve doesn't change physics");
+    }
+
     #[test]
     fn test_multi_stage_available_in_group() {
         // Two different stages in the same group (e.g., ion + lander)
@@ -1441,6 +2262,11 @@ mod tests {
             propellant_mass_kg: 200.0, structural_mass_kg: 100.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let lander_stage = Stage {
             id: StageId(11), name: "Lander".into(),
@@ -1448,12 +2274,18 @@ mod tests {
             propellant_mass_kg: 5_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "IonLander".into(),
             stage_groups: vec![vec![ion_stage, lander_stage]],
+            performance_reserve: Some(0.0),
         };
 
         assert!(design.validate().is_empty());
@@ -1477,6 +2309,11 @@ mod tests {
             propellant_mass_kg: 80_000.0, structural_mass_kg: 3_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -1484,12 +2321,18 @@ mod tests {
             propellant_mass_kg: 15_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
 
         let stats = compute_stage_stats(&design, 1_000.0, "earth_surface");
@@ -1521,11 +2364,17 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design_single = RocketDesign {
             id: RocketDesignId(1),
             name: "Single".into(),
             stage_groups: vec![vec![s1_single]],
+            performance_reserve: Some(0.0),
         };
 
         // 3 engine first stage
@@ -1535,11 +2384,17 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design_triple = RocketDesign {
             id: RocketDesignId(2),
             name: "Triple".into(),
             stage_groups: vec![vec![s1_triple]],
+            performance_reserve: Some(0.0),
         };
 
         let stats_single = compute_stage_stats(&design_single, 1_000.0, "earth_surface");
@@ -1561,11 +2416,17 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
 
         let stats = compute_stage_stats(&design, 1_000.0, "lunar_surface");
@@ -1579,6 +2440,7 @@ mod tests {
             id: RocketDesignId(1),
             name: "Empty".into(),
             stage_groups: vec![],
+            performance_reserve: Some(0.0),
         };
         let stats = compute_stage_stats(&design, 1_000.0, "earth_surface");
         assert!(stats.is_empty());
@@ -1597,12 +2459,18 @@ mod tests {
             propellant_mass_kg: 30_000.0, structural_mass_kg: 2_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
 
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
@@ -1628,6 +2496,11 @@ mod tests {
             propellant_mass_kg: 50_000.0, structural_mass_kg: 3_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -1635,12 +2508,18 @@ mod tests {
             propellant_mass_kg: 10_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "TwoStager".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         };
 
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
@@ -1675,12 +2554,18 @@ mod tests {
             propellant_mass_kg: 10_000.0, structural_mass_kg: 1_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
 
         let design = RocketDesign {
             id: RocketDesignId(1),
             name: "Test".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
 
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 1_000.0);
@@ -1702,6 +2587,11 @@ mod tests {
             engine_count: 1,
             propellant_mass_kg: 50_000.0, structural_mass_kg: 3_000.0,
             fairing: None, power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         if panel_w > 0.0 {
             s1.power_sources.push(PowerSource::new_solar_panel(panel_w));
@@ -1712,6 +2602,7 @@ mod tests {
         RocketDesign {
             id: RocketDesignId(1), name: "Powered".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -1775,12 +2666,18 @@ mod tests {
             structural_mass_kg: 100.0, // tiny bus, low housekeeping
             fairing: None,
             power_sources: vec![PowerSource::new_rtg(RtgClass::Cassini)],
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         // small battery for bookkeeping
         s1.power_sources.push(PowerSource::new_battery(0.5));
         let design = RocketDesign {
             id: RocketDesignId(1), name: "Probe".into(),
             stage_groups: vec![vec![s1]],
+            performance_reserve: Some(0.0),
         };
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 0.0);
         for _ in 0..1000 {
@@ -1811,6 +2708,11 @@ mod tests {
             engine_count: 1,
             propellant_mass_kg: 1_000.0, structural_mass_kg: 100.0,
             fairing: None, power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         if panel_w > 0.0 {
             stage.power_sources.push(PowerSource::new_solar_panel(panel_w));
@@ -1818,6 +2720,7 @@ mod tests {
         RocketDesign {
             id: RocketDesignId(1), name: "Ion".into(),
             stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -1891,10 +2794,16 @@ mod tests {
             structural_mass_kg: 500.0,
             fairing: None,
             power_sources: vec![PowerSource::new_fuel_cell(fuel_cell_w)],
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         RocketDesign {
             id: RocketDesignId(1), name: "HydroloxCell".into(),
             stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
         }
     }
 
@@ -1928,10 +2837,16 @@ mod tests {
             structural_mass_kg: 200.0,
             fairing: None,
             power_sources: vec![PowerSource::new_fuel_cell(1_000.0)],
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let design = RocketDesign {
             id: RocketDesignId(1), name: "IonCell".into(),
             stage_groups: vec![vec![stage]],
+            performance_reserve: Some(0.0),
         };
         let mut rocket = design.instantiate(RocketId(1), "earth_surface", 0.0);
         let prop_before = rocket.stage_states[0][0].propellant_remaining_kg;
@@ -1967,4 +2882,35 @@ mod tests {
         assert!(t_3au < nominal * 0.3,
             "3 AU should be heavily derated, got {} of nominal {}", t_3au, nominal);
     }
+
+    #[test]
+    fn test_diff_finds_changed_stages_and_rejects_new_layouts() {
+        let stage = |id: u64, structural: f64| Stage {
+            id: StageId(id), name: format!("S{}", id),
+            engine: kerolox_engine(id, 500_000.0, 300.0, 300.0), engine_count: 1,
+            propellant_mass_kg: 20_000.0, structural_mass_kg: structural,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        };
+        let old = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Old".into(),
+            stage_groups: vec![vec![stage(1, 2_000.0)], vec![stage(2, 400.0)]],
+            performance_reserve: Some(0.0),
+        };
+        let mut new = old.clone();
+        new.name = "Renamed".into();
+        assert!(old.diff(&new).unwrap().is_empty(), "names aren't hardware");
+
+        new.stage_groups[1][0].structural_mass_kg = 450.0;
+        assert_eq!(old.diff(&new).unwrap().changed_stages, vec![(1, 0)]);
+
+        new.stage_groups[0].push(stage(3, 1_000.0));
+        assert!(old.diff(&new).is_none());
+    }
 }
@@ -1,13 +1,15 @@
 use std::collections::HashSet;
+use std::fmt;
 
 use rand::rngs::StdRng;
 use serde::{Serialize, Deserialize};
 
 use crate::balance;
-use crate::balance_config::BalanceConfig;
+use crate::balance_config::{BalanceConfig, CertificationConfig, LaunchAbortConfig};
 use crate::flaw::{self, Flaw};
 use crate::location::DELTA_V_MAP;
 use crate::rocket::RocketDesign;
+use crate::software_project::{SoftwareLink, SoftwareProjectId};
 
 /// Unique identifier for a rocket project.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -21,6 +23,45 @@ pub enum RocketDesignStatus {
     Revising { remaining_indices: Vec<usize>, work_completed: f64 },
 }
 
+/// Certification of a frozen design revision for crewed and
+/// national-security work. Lapses as soon as the design is revised.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Certification {
+    #[default]
+    Uncertified,
+    /// Teams are working through the certification review. Testing
+    /// cycles pause until it finishes.
+    InReview { revision: u32, work_completed: f64, work_required: f64 },
+    Certified { revision: u32 },
+}
+
+/// Why a design can't enter certification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CertificationError {
+    /// The project index doesn't exist.
+    ProjectMissing,
+    /// Only designs in testing (not in design or mid-revision) can be frozen.
+    NotFrozen,
+    /// Not enough consecutive successful flights of the current revision.
+    FlightRecord { streak: u32, required: u32 },
+    /// Already in review or certified.
+    AlreadyStarted,
+}
+
+impl fmt::Display for CertificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertificationError::ProjectMissing => write!(f, "No such rocket design"),
+            CertificationError::NotFrozen => write!(f, "Design must be in testing to certify"),
+            CertificationError::FlightRecord { streak, required } => write!(
+                f, "Needs {} consecutive successful flights of this revision ({} so far)",
+                required, streak,
+            ),
+            CertificationError::AlreadyStarted => write!(f, "Certification already underway"),
+        }
+    }
+}
+
 /// A rocket design project with workflow state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RocketProject {
@@ -37,6 +78,48 @@ pub struct RocketProject {
     /// Cumulative work spent in testing (persists across revisions).
     #[serde(default)]
     pub cumulative_testing_work: f64,
+    #[serde(default)]
+    pub certification: Certification,
+    /// Consecutive fully successful flights of the current revision.
+    #[serde(default)]
+    pub success_streak: u32,
+    /// The flight software lineage this design flies, if any.
+    #[serde(default)]
+    pub software: Option<SoftwareLink>,
+    /// Rockets of this design launched, whatever the outcome.
+    #[serde(default)]
+    pub flights: u32,
+    /// Contract payments earned by this design's flights.
+    #[serde(default)]
+    pub flight_revenue: f64,
+    /// Build cost of the rockets those flights used up.
+    #[serde(default)]
+    pub flown_build_cost: f64,
+    /// Set on a variant branch (e.g. "Block 2") forked from another
+    /// project; None on a mainline design.
+    #[serde(default)]
+    pub branch: Option<RocketBranch>,
+    /// Pad-abort tests the design's abort system has passed.
+    #[serde(default)]
+    pub pad_aborts_passed: u32,
+    /// Revision that last had a passed pad abort counted toward its
+    /// certification streak.
+    #[serde(default)]
+    pub pad_abort_credited_revision: Option<u32>,
+    /// The checklist run before committing one of these to launch.
+    #[serde(default)]
+    pub commit_criteria: crate::commit_criteria::LaunchCommitCriteria,
+    /// What happens to the last stage after the mission.
+    #[serde(default)]
+    pub disposal: crate::disposal::UpperStageDisposal,
+}
+
+/// Where a branch forked from its parent project.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RocketBranch {
+    pub parent: RocketProjectId,
+    /// The parent's revision at the fork; history up to it is shared.
+    pub forked_at_revision: u32,
 }
 
 /// Events generated by rocket project work.
@@ -46,6 +129,11 @@ pub enum RocketWorkEvent {
     TestingCycleComplete,
     FlawDiscovered { flaw_description: String },
     RevisionComplete,
+    CertificationComplete,
+    /// Adaptation of the linked flight software is done.
+    SoftwareAdapted,
+    /// A near-miss in flight left telemetry hints on hidden flaws.
+    AnomalyDetected { hints: Vec<String> },
 }
 
 impl RocketProject {
@@ -55,8 +143,7 @@ impl RocketProject {
         design: RocketDesign,
         balance_cfg: &BalanceConfig,
     ) -> Self {
-        let (total_stages, unique_engines, max_parallel) = design_stats(&design);
-        let complexity = balance::rocket_complexity(total_stages, unique_engines, max_parallel);
+        let complexity = design_complexity(&design);
         let work_required = balance_cfg.work.rocket_design_work_required(complexity);
 
         RocketProject {
@@ -72,15 +159,67 @@ impl RocketProject {
             complexity,
             nre_cost: 0.0,
             cumulative_testing_work: 0.0,
+            certification: Certification::Uncertified,
+            success_streak: 0,
+            software: None,
+            flights: 0,
+            flight_revenue: 0.0,
+            flown_build_cost: 0.0,
+            branch: None,
+            pad_aborts_passed: 0,
+            pad_abort_credited_revision: None,
+            commit_criteria: Default::default(),
+            disposal: Default::default(),
         }
     }
 
-    /// Apply one day of work. Returns any completed work events.
-    pub fn apply_daily_work(&mut self, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<RocketWorkEvent> {
+    /// Fork this design into a variant branch `project_id`. The branch
+    /// shares the design and flaw history up to now — same flaws, known
+    /// ones still known — and starts with `testing_carryover` of this
+    /// project's test time. None unless the design is in Testing.
+    pub fn fork(&self, project_id: RocketProjectId, name: String, testing_carryover: f64) -> Option<Self> {
+        if !matches!(self.status, RocketDesignStatus::Testing { .. }) {
+            return None;
+        }
+        let mut design = self.design.clone();
+        design.id = crate::rocket::RocketDesignId(project_id.0);
+        design.name = name;
+        Some(RocketProject {
+            project_id,
+            design,
+            status: RocketDesignStatus::Testing { work_completed: 0.0 },
+            flaws: self.flaws.clone(),
+            revision: self.revision,
+            teams_assigned: 0,
+            complexity: self.complexity,
+            nre_cost: 0.0,
+            cumulative_testing_work: self.cumulative_testing_work * testing_carryover,
+            certification: Certification::Uncertified,
+            success_streak: 0,
+            software: self.software.clone(),
+            flights: 0,
+            flight_revenue: 0.0,
+            flown_build_cost: 0.0,
+            branch: Some(RocketBranch { parent: self.project_id, forked_at_revision: self.revision }),
+            pad_aborts_passed: self.pad_aborts_passed,
+            pad_abort_credited_revision: None,
+            commit_criteria: self.commit_criteria.clone(),
+            disposal: self.disposal,
+        })
+    }
+
+    /// Apply one day of work, done at `efficiency` of a full day's.
+    /// Returns any completed work events.
+    pub fn apply_daily_work(&mut self, efficiency: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<RocketWorkEvent> {
         if self.teams_assigned == 0 {
             return Vec::new();
         }
-        let work = crate::team::effective_work_rate(self.teams_assigned);
+        let work = crate::team::effective_work_rate(self.teams_assigned) * efficiency;
+        self.apply_work(work, rng, next_flaw_id, balance_cfg)
+    }
+
+    /// Apply `work` units of work to whatever stage the project is in.
+    pub fn apply_work(&mut self, work: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig) -> Vec<RocketWorkEvent> {
         let mut events = Vec::new();
 
         match &mut self.status {
@@ -88,17 +227,41 @@ impl RocketProject {
                 *work_completed += work;
                 if *work_completed >= *work_required {
                     self.flaws = flaw::generate_rocket_flaws(self.complexity, rng, next_flaw_id, &balance_cfg.flaws);
+                    self.flaws.extend(flaw::generate_hot_staging_flaws(
+                        self.design.hot_staged_boundaries(), rng, next_flaw_id, &balance_cfg.flaws,
+                    ));
                     let flaw_count = self.flaws.len() as u32;
                     self.status = RocketDesignStatus::Testing { work_completed: 0.0 };
                     events.push(RocketWorkEvent::DesignComplete { flaw_count });
                 }
             }
+            RocketDesignStatus::Testing { .. }
+                if self.software.as_ref().is_some_and(|l| l.adaptation_remaining > 0.0) =>
+            {
+                if let Some(link) = &mut self.software {
+                    link.adaptation_remaining = (link.adaptation_remaining - work).max(0.0);
+                    if link.adaptation_remaining == 0.0 {
+                        events.push(RocketWorkEvent::SoftwareAdapted);
+                    }
+                }
+            }
+            RocketDesignStatus::Testing { .. } if matches!(self.certification, Certification::InReview { .. }) => {
+                if let Certification::InReview { revision, work_completed, work_required } = &mut self.certification {
+                    *work_completed += work;
+                    if *work_completed >= *work_required {
+                        self.certification = Certification::Certified { revision: *revision };
+                        events.push(RocketWorkEvent::CertificationComplete);
+                    }
+                }
+            }
             RocketDesignStatus::Testing { work_completed } => {
                 *work_completed += work;
                 self.cumulative_testing_work += work;
                 while *work_completed >= balance_cfg.work.testing_cycle_work {
                     *work_completed -= balance_cfg.work.testing_cycle_work;
-                    let discovered = flaw::roll_discoveries_with_rng(&mut self.flaws, rng);
+                    let discovered = flaw::roll_discoveries_with_rng(
+                        &mut self.flaws, rng, balance_cfg.flaws.anomaly_discovery_multiplier,
+                    );
                     for idx in discovered {
                         events.push(RocketWorkEvent::FlawDiscovered {
                             flaw_description: self.flaws[idx].description.clone(),
@@ -109,9 +272,13 @@ impl RocketProject {
             }
             RocketDesignStatus::Revising { remaining_indices, work_completed } => {
                 *work_completed += work;
-                while *work_completed >= balance_cfg.work.flaw_revision_work && !remaining_indices.is_empty() {
-                    *work_completed -= balance_cfg.work.flaw_revision_work;
-                    let fi = remaining_indices.remove(0);
+                while let Some(&fi) = remaining_indices.first() {
+                    let fix_work = self.flaws[fi].fix_work(balance_cfg.work.flaw_revision_work);
+                    if *work_completed < fix_work {
+                        break;
+                    }
+                    *work_completed -= fix_work;
+                    remaining_indices.remove(0);
                     self.flaws.remove(fi);
                     events.push(RocketWorkEvent::RevisionComplete);
                     for idx in remaining_indices.iter_mut() {
@@ -144,6 +311,10 @@ impl RocketProject {
             return false;
         }
         self.revision += 1;
+        // A new revision thaws the design: certification and its
+        // flight record start over.
+        self.certification = Certification::Uncertified;
+        self.success_streak = 0;
         self.status = RocketDesignStatus::Revising {
             remaining_indices: discovered_indices,
             work_completed: 0.0,
@@ -151,6 +322,85 @@ impl RocketProject {
         true
     }
 
+    /// The linked software lineage, once adaptation is done. Until then
+    /// the design flies as if it had no software.
+    pub fn flight_software(&self) -> Option<SoftwareProjectId> {
+        self.software.as_ref()
+            .filter(|l| l.adaptation_remaining == 0.0)
+            .map(|l| l.software_id)
+    }
+
+    /// True if the current revision is certified.
+    pub fn is_certified(&self) -> bool {
+        matches!(self.certification, Certification::Certified { revision } if revision == self.revision)
+    }
+
+    /// Freeze the current revision and open its certification review.
+    pub fn start_certification(&mut self, cfg: &CertificationConfig) -> Result<(), CertificationError> {
+        if !matches!(self.status, RocketDesignStatus::Testing { .. }) {
+            return Err(CertificationError::NotFrozen);
+        }
+        if self.certification != Certification::Uncertified {
+            return Err(CertificationError::AlreadyStarted);
+        }
+        if self.success_streak < cfg.required_successes {
+            return Err(CertificationError::FlightRecord {
+                streak: self.success_streak,
+                required: cfg.required_successes,
+            });
+        }
+        self.certification = Certification::InReview {
+            revision: self.revision,
+            work_completed: 0.0,
+            work_required: cfg.review_work,
+        };
+        Ok(())
+    }
+
+    /// Record a flight of hardware built to `revision`. Flights of older
+    /// revisions don't count toward the current one. A failure breaks
+    /// the streak and revokes any certification; returns true if it did.
+    pub fn record_flight(&mut self, revision: u32, success: bool) -> bool {
+        if revision != self.revision {
+            return false;
+        }
+        if success {
+            self.success_streak += 1;
+            return false;
+        }
+        self.success_streak = 0;
+        let revoked = self.certification != Certification::Uncertified;
+        self.certification = Certification::Uncertified;
+        revoked
+    }
+
+    /// Chance the design's abort system pulls a crew clear of a
+    /// failing booster, proven out by pad-abort tests.
+    pub fn abort_success_chance(&self, cfg: &LaunchAbortConfig) -> f64 {
+        (cfg.abort_success_chance + cfg.pad_abort_gain * self.pad_aborts_passed as f64)
+            .min(cfg.max_abort_success_chance)
+    }
+
+    /// Record a pad-abort test. A pass raises the abort chance and, the
+    /// first time for each revision, counts as a successful flight
+    /// toward certification.
+    pub fn record_pad_abort(&mut self, passed: bool) {
+        if !passed {
+            return;
+        }
+        self.pad_aborts_passed += 1;
+        if self.pad_abort_credited_revision != Some(self.revision) {
+            self.pad_abort_credited_revision = Some(self.revision);
+            self.success_streak += 1;
+        }
+    }
+
+    /// Record near-miss hints (flaw index, hint) from a flight.
+    pub fn record_anomalies(&mut self, hints: Vec<(usize, String)>) -> Option<RocketWorkEvent> {
+        let hints = flaw::record_anomalies(&mut self.flaws, hints);
+        (!hints.is_empty()).then_some(RocketWorkEvent::AnomalyDetected { hints })
+    }
+
     /// Number of discovered flaws.
     pub fn discovered_flaw_count(&self) -> usize {
         self.flaws.iter().filter(|f| f.discovered).count()
@@ -167,10 +417,22 @@ impl RocketProject {
             _ => "Thoroughly Tested",
         }
     }
+
+    /// `testing_level` as a localizable `testing.<tier>` label.
+    pub fn testing_level_text(&self, balance_cfg: &BalanceConfig) -> crate::locale::Text {
+        crate::locale::Text::label("testing", self.testing_level(balance_cfg))
+    }
+}
+
+/// Integration complexity of a rocket design — the mean number of
+/// flaws its project starts testing with.
+pub fn design_complexity(design: &RocketDesign) -> u32 {
+    let (total_stages, unique_engines, max_parallel, crossfeed_groups) = design_stats(design);
+    balance::rocket_complexity(total_stages, unique_engines, max_parallel, crossfeed_groups)
 }
 
 /// Extract design statistics for complexity calculation.
-fn design_stats(design: &RocketDesign) -> (u32, u32, u32) {
+fn design_stats(design: &RocketDesign) -> (u32, u32, u32, u32) {
     let total_stages: u32 = design.stage_groups.iter()
         .map(|g| g.len() as u32)
         .sum();
@@ -188,14 +450,64 @@ fn design_stats(design: &RocketDesign) -> (u32, u32, u32) {
         .max()
         .unwrap_or(1);
 
-    (total_stages, unique_engines, max_parallel)
+    let crossfeed_groups = design.stage_groups.iter()
+        .filter(|g| g.iter().any(|s| s.crossfeed))
+        .count() as u32;
+
+    (total_stages, unique_engines, max_parallel, crossfeed_groups)
+}
+
+/// A design's program P&L: what it cost to develop and build against
+/// what its flights earned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignProfitability {
+    pub rocket_project_id: RocketProjectId,
+    pub rocket_name: String,
+    /// Contract payments from its flights.
+    pub revenue: f64,
+    /// The design's own engineering spend. Engine NRE is carried by the
+    /// engine programs, which several designs may share.
+    pub nre: f64,
+    /// Build cost of every rocket made to this design, flown or not.
+    pub production_cost: f64,
+    pub rockets_built: u32,
+    pub flights: u32,
+    /// Build cost of the rockets that flew.
+    pub flown_build_cost: f64,
+}
+
+impl DesignProfitability {
+    /// Revenue less NRE and every rocket built.
+    pub fn net(&self) -> f64 {
+        self.revenue - self.nre - self.production_cost
+    }
+
+    /// Mean revenue less mean build cost per flight flown. None
+    /// before the first flight.
+    pub fn per_flight_margin(&self) -> Option<f64> {
+        (self.flights > 0).then(|| (self.revenue - self.flown_build_cost) / self.flights as f64)
+    }
+
+    /// Flights needed at the current margin to pay back the NRE.
+    /// None if the design hasn't flown or loses money on each flight.
+    pub fn break_even_flights(&self) -> Option<u32> {
+        let margin = self.per_flight_margin()?;
+        (margin > 0.0).then(|| (self.nre / margin).ceil() as u32)
+    }
 }
 
 /// Compute the maximum payload mass (in kg) that a rocket design can deliver
 /// to a given destination. Returns 0.0 if the destination is unreachable.
 ///
-/// Uses binary search over payload mass.
+/// Uses binary search over payload mass, planning against the design's
+/// usable delta-v (its performance reserve is held back).
 pub fn max_payload_to(design: &RocketDesign, from: &str, to: &str) -> f64 {
+    max_payload_with_extra_dv(design, from, to, 0.0)
+}
+
+/// [`max_payload_to`] for a trip that costs `extra_dv` more than the
+/// map says — a launch site's plane change, say.
+pub fn max_payload_with_extra_dv(design: &RocketDesign, from: &str, to: &str, extra_dv: f64) -> f64 {
     // First check if the destination is reachable at all (with 0 payload).
     // Use the stage-aware planner so rockets with mixed thrust classes get
     // the right per-edge dv (e.g. ion stages use spiral costs).
@@ -205,8 +517,8 @@ pub fn max_payload_to(design: &RocketDesign, from: &str, to: &str) -> f64 {
         return 0.0;
     }
 
-    let required_dv = path.unwrap().1;
-    let available_dv = design.total_delta_v(0.0);
+    let required_dv = path.unwrap().1 + extra_dv;
+    let available_dv = design.usable_delta_v(0.0);
     if available_dv < required_dv {
         return 0.0;
     }
@@ -216,13 +528,13 @@ pub fn max_payload_to(design: &RocketDesign, from: &str, to: &str) -> f64 {
     let mut hi = rocket_mass * 2.0; // generous upper bound
 
     // First find an upper bound where we can't make it
-    while design.total_delta_v(hi) >= required_dv && hi < 1_000_000.0 {
+    while design.usable_delta_v(hi) >= required_dv && hi < 1_000_000.0 {
         hi *= 2.0;
     }
 
     for _ in 0..50 {
         let mid = (lo + hi) / 2.0;
-        let dv = design.total_delta_v(mid);
+        let dv = design.usable_delta_v(mid);
         if dv >= required_dv {
             lo = mid;
         } else {
@@ -318,6 +630,11 @@ mod tests {
             propellant_mass_kg: 50_000.0, structural_mass_kg: 3_000.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let s2 = Stage {
             id: StageId(2), name: "S2".into(),
@@ -325,21 +642,28 @@ mod tests {
             propellant_mass_kg: 10_000.0, structural_mass_kg: 500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         RocketDesign {
             id: crate::rocket::RocketDesignId(1),
             name: "TestRocket".into(),
             stage_groups: vec![vec![s1], vec![s2]],
+            performance_reserve: Some(0.0),
         }
     }
 
     #[test]
     fn test_design_stats() {
         let design = simple_two_stage_design();
-        let (total, unique, max_par) = design_stats(&design);
+        let (total, unique, max_par, crossfeed) = design_stats(&design);
         assert_eq!(total, 2);
         assert_eq!(unique, 2);
         assert_eq!(max_par, 1);
+        assert_eq!(crossfeed, 0);
     }
 
     #[test]
@@ -366,7 +690,7 @@ mod tests {
 
         let mut all_events = Vec::new();
         for _ in 0..(work_needed as u32 + 10) {
-            let events = proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            let events = proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
             all_events.extend(events);
         }
 
@@ -384,7 +708,7 @@ mod tests {
 
         // Advance to testing
         for _ in 0..200 {
-            proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
         }
 
         // Clear any generated flaws and add controlled test flaws
@@ -397,6 +721,8 @@ mod tests {
             discovery_probability: 0.5,
             discovered: true,
             trigger: crate::flaw::FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         });
         proj.flaws.push(Flaw {
             id: crate::flaw::FlawId(901),
@@ -406,6 +732,8 @@ mod tests {
             discovery_probability: 0.3,
             discovered: true,
             trigger: crate::flaw::FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
         });
 
         assert_eq!(proj.flaws.len(), 2);
@@ -413,7 +741,7 @@ mod tests {
         assert!(proj.start_revision());
 
         for _ in 0..50 {
-            proj.apply_daily_work(&mut rng, &mut next_flaw_id, &bal());
+            proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal());
         }
 
         assert_eq!(proj.flaws.len(), 0);
@@ -422,6 +750,75 @@ mod tests {
         assert!(matches!(proj.status, RocketDesignStatus::Testing { .. }));
     }
 
+    #[test]
+    fn test_certification_needs_streak_and_lapses_on_revision() {
+        let cfg = bal().certification;
+        let mut proj = RocketProject::new(RocketProjectId(1), simple_two_stage_design(), &bal());
+        assert_eq!(proj.start_certification(&cfg), Err(CertificationError::NotFrozen));
+        proj.status = RocketDesignStatus::Testing { work_completed: 0.0 };
+
+        // A failure resets the streak; old-revision flights don't count.
+        proj.record_flight(0, true);
+        proj.record_flight(0, false);
+        for _ in 0..cfg.required_successes - 1 {
+            proj.record_flight(0, true);
+        }
+        proj.revision = 1;
+        proj.record_flight(0, true);
+        proj.revision = 0;
+        assert!(matches!(proj.start_certification(&cfg),
+            Err(CertificationError::FlightRecord { .. })));
+        proj.record_flight(0, true);
+        assert_eq!(proj.start_certification(&cfg), Ok(()));
+
+        proj.teams_assigned = 4;
+        let mut rng = test_rng();
+        let mut next_flaw_id = 0u64;
+        let mut done = false;
+        for _ in 0..(cfg.review_work as u32 + 10) {
+            done |= proj.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal()).iter()
+                .any(|e| matches!(e, RocketWorkEvent::CertificationComplete));
+        }
+        assert!(done);
+        assert!(proj.is_certified());
+
+        proj.flaws.push(Flaw {
+            id: crate::flaw::FlawId(900),
+            description: "Test flaw".into(),
+            consequence: crate::flaw::FlawConsequence::StageLoss,
+            activation_chance: 0.1,
+            discovery_probability: 0.5,
+            discovered: true,
+            trigger: crate::flaw::FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Combustion,
+            anomaly: None,
+        });
+        assert!(proj.start_revision());
+        assert!(!proj.is_certified());
+        assert_eq!(proj.success_streak, 0);
+    }
+
+    #[test]
+    fn test_pad_aborts_raise_abort_chance_and_credit_certification_once() {
+        let cfg = bal().launch_abort;
+        let mut proj = RocketProject::new(RocketProjectId(1), simple_two_stage_design(), &bal());
+        assert_eq!(proj.abort_success_chance(&cfg), cfg.abort_success_chance);
+
+        proj.record_pad_abort(false);
+        assert_eq!((proj.pad_aborts_passed, proj.success_streak), (0, 0));
+        proj.record_pad_abort(true);
+        proj.record_pad_abort(true);
+        assert_eq!(proj.pad_aborts_passed, 2);
+        assert_eq!(proj.success_streak, 1, "one pad abort per revision counts toward certification");
+        assert_eq!(proj.abort_success_chance(&cfg), cfg.abort_success_chance + 2.0 * cfg.pad_abort_gain);
+
+        proj.revision = 1;
+        proj.record_pad_abort(true);
+        assert_eq!(proj.success_streak, 2);
+        proj.pad_aborts_passed = 100;
+        assert_eq!(proj.abort_success_chance(&cfg), cfg.max_abort_success_chance);
+    }
+
     #[test]
     fn test_max_payload_to_leo() {
         let design = simple_two_stage_design();
@@ -471,4 +868,30 @@ mod tests {
                 leo_payload, gto_payload);
         }
     }
+
+    #[test]
+    fn test_profitability_margin_and_break_even() {
+        let mut p = DesignProfitability {
+            rocket_project_id: RocketProjectId(1),
+            rocket_name: "R".into(),
+            revenue: 0.0,
+            nre: 30.0,
+            production_cost: 20.0,
+            rockets_built: 2,
+            flights: 0,
+            flown_build_cost: 0.0,
+        };
+        assert_eq!(p.per_flight_margin(), None);
+        assert_eq!(p.break_even_flights(), None);
+
+        p.flights = 1;
+        p.flown_build_cost = 10.0;
+        p.revenue = 8.0;
+        assert_eq!(p.per_flight_margin(), Some(-2.0));
+        assert_eq!(p.break_even_flights(), None, "never pays back at a loss");
+
+        p.revenue = 17.0;
+        assert_eq!(p.break_even_flights(), Some(5));
+        assert_eq!(p.net(), -33.0);
+    }
 }
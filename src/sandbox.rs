@@ -0,0 +1,58 @@
+//! Sandbox editing. Content creators and testers can set up a
+//! situation directly instead of playing their way to it: spawn money,
+//! grant testing work, finish designs, put hardware in stock and jump
+//! flights ahead. The edits are `GameState::sandbox_*` methods, only
+//! allowed when the balance turns `sandbox.enabled` on, and the first
+//! one marks the game as sandbox for good so its results can't pass
+//! for earned ones. Importing a design from the company library (see
+//! [`crate::library`]) is one too.
+
+use std::fmt;
+
+use crate::design_file::DesignImportError;
+use crate::engine_project::EngineProjectId;
+use crate::rocket_project::RocketProjectId;
+
+/// The design project a sandbox edit works on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxTarget {
+    Engine(EngineProjectId),
+    Rocket(RocketProjectId),
+}
+
+/// Why a sandbox edit was refused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SandboxError {
+    /// The game's balance doesn't allow sandbox edits.
+    Disabled,
+    NoSuchProject,
+    /// Design work is already done.
+    NotInDesign,
+    /// Testing work only goes to a design being tested.
+    NotTesting,
+    /// Hardware can only be stocked from a finished design.
+    DesignIncomplete,
+    NoSuchFlight,
+    /// Only flights still on their way can be moved.
+    NotInTransit,
+    /// The library keeps only the stats of engines.
+    StatsOnly,
+    /// An archived design didn't import into this game.
+    Import(DesignImportError),
+}
+
+impl fmt::Display for SandboxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SandboxError::Disabled => write!(f, "sandbox edits are off for this game"),
+            SandboxError::NoSuchProject => write!(f, "no such design"),
+            SandboxError::NotInDesign => write!(f, "the design is already complete"),
+            SandboxError::NotTesting => write!(f, "the design isn't in testing"),
+            SandboxError::DesignIncomplete => write!(f, "the design isn't complete yet"),
+            SandboxError::NoSuchFlight => write!(f, "no such flight"),
+            SandboxError::NotInTransit => write!(f, "the flight isn't in transit"),
+            SandboxError::StatsOnly => write!(f, "only the stats of this design were archived"),
+            SandboxError::Import(e) => write!(f, "{}", e),
+        }
+    }
+}
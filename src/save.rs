@@ -3,11 +3,21 @@ use std::io;
 use std::path::{Path, PathBuf};
 
 use crate::game_state::GameState;
+use crate::library::Library;
 
 /// List saved games as (company_name, full_path), sorted by modification time (newest first).
 pub fn list_saves() -> Vec<(String, PathBuf)> {
-    let dir = save_dir();
-    let Ok(entries) = fs::read_dir(&dir) else {
+    list_json_files(&save_dir())
+}
+
+/// List exported design files as (design_name, full_path), newest first.
+pub fn list_design_files() -> Vec<(String, PathBuf)> {
+    list_json_files(&designs_dir())
+}
+
+/// The `.json` files in `dir` as (file stem, full_path), newest first.
+fn list_json_files(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
         return Vec::new();
     };
     let mut saves: Vec<(String, PathBuf, std::time::SystemTime)> = entries
@@ -74,10 +84,57 @@ pub fn save_dir() -> std::path::PathBuf {
 
 /// Build a save file path for a company name.
 pub fn save_path(company_name: &str) -> std::path::PathBuf {
-    let sanitized: String = company_name.chars()
+    save_dir().join(format!("{}.json", sanitize(company_name)))
+}
+
+/// Directory exported design files are written to and imported from.
+pub fn designs_dir() -> std::path::PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    Path::new(&home).join(".rocket_tycoon").join("designs")
+}
+
+/// Build a design file path for a rocket design name.
+pub fn design_path(design_name: &str) -> std::path::PathBuf {
+    designs_dir().join(format!("{}.json", sanitize(design_name)))
+}
+
+/// Write an exported design file, creating the directory if needed.
+pub fn write_design_file(json: &str, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, json)
+}
+
+/// The company library file, kept beside the saves so it outlives any
+/// one game.
+pub fn library_path() -> std::path::PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    Path::new(&home).join(".rocket_tycoon").join("library.json")
+}
+
+/// Load the company library. A library that doesn't exist yet is empty.
+pub fn load_library(path: &Path) -> io::Result<Library> {
+    match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Library::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Write the company library, creating the directory if needed.
+pub fn save_library(library: &Library, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(library).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
         .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
-        .collect();
-    save_dir().join(format!("{}.json", sanitized))
+        .collect()
 }
 
 #[cfg(test)]
@@ -159,10 +216,16 @@ mod tests {
                 propellant_mass_kg: 100.0, structural_mass_kg: 10.0,
                 fairing: None,
                 power_sources: Vec::new(),
+                crossfeed: false,
+                long_coast_kit: false,
+                landing_kit: false,
+                launch_abort_system: false,
+                staging: Default::default(),
             };
             RocketDesign {
                 id: RocketDesignId(id), name: name.into(),
                 stage_groups: vec![vec![stage]],
+                performance_reserve: Some(0.0),
             }
         };
         let csm_design = make_design(1, "CSM");
@@ -177,6 +240,7 @@ mod tests {
             nested_payloads: vec![],
             rocket_project_id: RocketProjectId(2),
             name: "LEM".into(),
+            health: crate::servicing::FULL_HEALTH,
         };
         state.spacecraft.push(Spacecraft {
             id: crate::game_state::SpacecraftId(1),
@@ -186,6 +250,7 @@ mod tests {
             location: "lunar_orbit".into(),
             rocket_project_id: RocketProjectId(1),
             payloads: vec![lem_payload],
+            health: crate::servicing::FULL_HEALTH,
         });
 
         save_game(&state, &path).expect("save failed");
@@ -0,0 +1,142 @@
+//! Science missions. A data-return contract isn't paid off on arrival:
+//! the payload starts a data campaign, and the customer pays for the
+//! data as it comes down. Each day of the campaign earns in proportion
+//! to how much of the asset's data the ground can hear, and the stream
+//! stops early if the asset dies first.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::ScienceConfig;
+use crate::contract::{Contract, MarketId};
+use crate::rocket_project::RocketProjectId;
+
+/// A science asset returning data for a delivered contract.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScienceMission {
+    pub contract_name: String,
+    pub market_id: MarketId,
+    pub location_id: String,
+    /// The rocket lineage that flew it, credited with the data income.
+    /// None when a launch provider flew it.
+    pub rocket_project_id: Option<RocketProjectId>,
+    /// What a day of data is worth with full coverage.
+    pub daily_value: f64,
+    /// Days of the data campaign still to run.
+    pub days_left: u32,
+    /// Days until the asset fails.
+    pub lifetime_days: u32,
+    /// Paid for data so far.
+    pub returned: f64,
+}
+
+/// How a day of a science mission went.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScienceDay {
+    /// Data came down and was paid for.
+    Returned(f64),
+    /// The campaign's last day of data came down.
+    Complete(f64),
+    /// The asset failed before the campaign finished.
+    Lost,
+}
+
+impl ScienceMission {
+    /// Start `contract`'s campaign, worth `value` at full coverage over
+    /// `months`, drawing how long the asset will last.
+    pub fn new(
+        contract: &Contract,
+        rocket_project_id: Option<RocketProjectId>,
+        value: f64,
+        months: u32,
+        rng: &mut StdRng,
+        cfg: &ScienceConfig,
+    ) -> Self {
+        let days = months.max(1) * 30;
+        let min = cfg.min_lifetime_months * 30;
+        let max = (cfg.max_lifetime_months * 30).max(min);
+        ScienceMission {
+            contract_name: contract.name.clone(),
+            market_id: contract.market_id,
+            location_id: contract.destination.clone(),
+            rocket_project_id,
+            daily_value: value / days as f64,
+            days_left: days,
+            lifetime_days: rng.gen_range(min..=max),
+            returned: 0.0,
+        }
+    }
+
+    /// Run one day with `coverage` of the data heard.
+    pub fn day(&mut self, coverage: f64) -> ScienceDay {
+        if self.lifetime_days == 0 {
+            return ScienceDay::Lost;
+        }
+        self.lifetime_days -= 1;
+        self.days_left = self.days_left.saturating_sub(1);
+        let paid = self.daily_value * coverage;
+        self.returned += paid;
+        if self.days_left == 0 {
+            ScienceDay::Complete(paid)
+        } else {
+            ScienceDay::Returned(paid)
+        }
+    }
+}
+
+/// Share of an asset's data heard at `location_id`: low orbits only
+/// talk to the ground during passes, and a relay constellation hears
+/// everything.
+pub fn coverage(location_id: &str, relayed: bool, cfg: &ScienceConfig) -> f64 {
+    if relayed {
+        cfg.relay_coverage
+    } else if matches!(location_id, "leo" | "sso") {
+        cfg.low_orbit_coverage
+    } else {
+        cfg.deep_space_coverage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mission(location_id: &str, lifetime_days: u32) -> ScienceMission {
+        ScienceMission {
+            contract_name: "Probe".into(),
+            market_id: MarketId(2),
+            location_id: location_id.into(),
+            rocket_project_id: None,
+            daily_value: 1_000.0,
+            days_left: 360,
+            lifetime_days,
+            returned: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_data_streams_until_complete_or_lost() {
+        let cfg = ScienceConfig::default();
+        let mut long = mission("l2", 1_000);
+        let mut total = 0.0;
+        loop {
+            match long.day(0.5) {
+                ScienceDay::Returned(paid) => total += paid,
+                ScienceDay::Complete(paid) => { total += paid; break; }
+                ScienceDay::Lost => panic!("asset died mid-campaign"),
+            }
+        }
+        assert!((total - 180_000.0).abs() < 1e-6, "half coverage hears half the data");
+        assert_eq!(long.returned, total);
+
+        let mut short = mission("leo", 2);
+        assert!(matches!(short.day(1.0), ScienceDay::Returned(_)));
+        assert!(matches!(short.day(1.0), ScienceDay::Returned(_)));
+        assert_eq!(short.day(1.0), ScienceDay::Lost);
+
+        assert_eq!(coverage("leo", false, &cfg), cfg.low_orbit_coverage);
+        assert_eq!(coverage("l2", false, &cfg), cfg.deep_space_coverage);
+        assert_eq!(coverage("leo", true, &cfg), cfg.relay_coverage);
+    }
+}
@@ -0,0 +1,136 @@
+//! On-orbit servicing: asset wear and the kits that put it right.
+//!
+//! ISRU plants and parked spacecraft wear out day by day. A plant's
+//! output falls with its health and stops at zero; a spacecraft at
+//! zero health can't fly. A servicing kit launched to the asset's
+//! location restores it to full health on arrival, and a kit sent to a
+//! spacecraft can also carry propellant to top its tanks up.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::ServicingConfig;
+use crate::calendar::GameDate;
+use crate::game_state::SpacecraftId;
+use crate::rocket::{Rocket, RocketDesign};
+
+/// Full health, and what new assets start at.
+pub const FULL_HEALTH: f64 = 1.0;
+
+pub(crate) fn full_health() -> f64 {
+    FULL_HEALTH
+}
+
+/// What a servicing kit is bound for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServiceTarget {
+    /// Every ISRU plant at a site.
+    IsruPlants { location: String },
+    /// One parked spacecraft.
+    Spacecraft { id: SpacecraftId, location: String },
+}
+
+impl ServiceTarget {
+    pub fn location(&self) -> &str {
+        match self {
+            ServiceTarget::IsruPlants { location } | ServiceTarget::Spacecraft { location, .. } => location,
+        }
+    }
+}
+
+/// Repair hardware, plus any propellant for a spacecraft's tanks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServicingKit {
+    pub target: ServiceTarget,
+    /// Display name of the asset, as of launch.
+    pub asset_name: String,
+    pub propellant_kg: f64,
+    pub mass_kg: f64,
+    pub cost: f64,
+}
+
+impl ServicingKit {
+    pub fn new(target: ServiceTarget, asset_name: String, propellant_kg: f64, cfg: &ServicingConfig) -> Self {
+        let propellant_kg = propellant_kg.max(0.0);
+        ServicingKit {
+            target,
+            asset_name,
+            propellant_kg,
+            mass_kg: cfg.kit_mass_kg + propellant_kg * cfg.tankage_factor,
+            cost: cfg.kit_cost + propellant_kg * cfg.propellant_cost_per_kg,
+        }
+    }
+}
+
+/// Why a servicing mission couldn't launch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServicingError {
+    /// The target asset doesn't exist (any more).
+    NoSuchAsset,
+    /// The asset is already at full health and needs no propellant.
+    NothingToService,
+    RocketMissing,
+    PadLeased { lessee: String, free_on: GameDate },
+    NotCompliant(crate::regulation::ComplianceIssue),
+    CantAfford { cost: f64 },
+}
+
+impl fmt::Display for ServicingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServicingError::NoSuchAsset => write!(f, "Nothing there to service"),
+            ServicingError::NothingToService => write!(f, "Already in full health"),
+            ServicingError::RocketMissing => write!(f, "No rocket selected"),
+            ServicingError::PadLeased { lessee, free_on } =>
+                write!(f, "Pad leased to {} — free again on {}", lessee, free_on),
+            ServicingError::NotCompliant(issue) => write!(f, "{}", issue),
+            ServicingError::CantAfford { cost } =>
+                write!(f, "A servicing kit costs {}", crate::resources::format_money(*cost)),
+        }
+    }
+}
+
+/// One day of wear on an asset's health.
+pub fn wear(health: f64, per_day: f64) -> f64 {
+    (health - per_day).max(0.0)
+}
+
+/// Room left in `rocket`'s liquid stages, in kg.
+pub fn tank_room_kg(design: &RocketDesign, rocket: &Rocket) -> f64 {
+    let mut room = 0.0;
+    crate::infrastructure::for_each_liquid_stage(design, &mut rocket.clone(), |stage, state| {
+        room += (stage.propellant_mass_kg - state.propellant_remaining_kg).max(0.0);
+    });
+    room
+}
+
+/// Pour up to `kg` of propellant into `rocket`'s liquid stages in each
+/// stage's own mix, lowest stage first. Returns kg loaded.
+pub fn top_up(design: &RocketDesign, rocket: &mut Rocket, kg: f64) -> f64 {
+    let mut left = kg;
+    crate::infrastructure::for_each_liquid_stage(design, rocket, |stage, state| {
+        let fill = (stage.propellant_mass_kg - state.propellant_remaining_kg).max(0.0).min(left);
+        state.propellant_remaining_kg += fill;
+        left -= fill;
+    });
+    kg - left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kit_mass_and_cost_grow_with_propellant_and_wear_floors_at_zero() {
+        let cfg = ServicingConfig::default();
+        let target = ServiceTarget::IsruPlants { location: "lunar_surface".into() };
+        let bare = ServicingKit::new(target.clone(), "Plant".into(), 0.0, &cfg);
+        let fueled = ServicingKit::new(target, "Plant".into(), 1_000.0, &cfg);
+        assert_eq!(bare.mass_kg, cfg.kit_mass_kg);
+        assert!(fueled.mass_kg > bare.mass_kg + 1_000.0);
+        assert!(fueled.cost > bare.cost);
+        assert_eq!(wear(0.0005, 0.001), 0.0);
+        assert!((wear(FULL_HEALTH, 0.25) - 0.75).abs() < 1e-12);
+    }
+}
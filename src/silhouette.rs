@@ -0,0 +1,254 @@
+//! A rocket's outline to scale, for drawing it. Each stage becomes a
+//! stack of cylinders and cones sized from its tanks (see
+//! `Stage::diameter_m`): cores stack up the middle, boosters strap to
+//! either side of their core with a nose cone on top, adapters taper
+//! between cores of different widths, and the fairing caps the stack.
+//! Heights are measured up from the bottom of the first group and
+//! sideways from the vehicle's axis. Built from the design on demand;
+//! nothing here is saved.
+
+use serde::Serialize;
+
+use crate::rocket::RocketDesign;
+
+/// Nose cone length over its base diameter.
+const NOSE_FINENESS: f64 = 1.0;
+
+/// Fairing barrel length over its diameter.
+const FAIRING_BARREL_FINENESS: f64 = 1.5;
+
+/// Shortest adapter between cores of different widths.
+const MIN_ADAPTER_LENGTH_M: f64 = 0.5;
+
+/// What a segment of the outline is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SegmentKind {
+    /// Tanks and engines.
+    Tank,
+    /// A taper between cores of different widths.
+    Adapter,
+    /// The fairing's barrel.
+    Fairing,
+    Nose,
+}
+
+/// One cylinder or cone of the outline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Segment {
+    pub kind: SegmentKind,
+    /// The stage it belongs to: (group, index in the group).
+    pub stage: (usize, usize),
+    /// Its axis's distance from the vehicle's; boosters are off to the
+    /// side, left negative.
+    pub x_m: f64,
+    pub bottom_m: f64,
+    pub length_m: f64,
+    pub bottom_diameter_m: f64,
+    /// Equal to the bottom for a cylinder; zero at a nose's tip.
+    pub top_diameter_m: f64,
+}
+
+impl Segment {
+    pub fn top_m(&self) -> f64 {
+        self.bottom_m + self.length_m
+    }
+
+    pub fn is_cone(&self) -> bool {
+        self.bottom_diameter_m != self.top_diameter_m
+    }
+
+    /// Diameter at height `y`, None outside the segment.
+    pub fn diameter_at(&self, y: f64) -> Option<f64> {
+        if y < self.bottom_m || y > self.top_m() {
+            return None;
+        }
+        let t = if self.length_m > 0.0 { (y - self.bottom_m) / self.length_m } else { 0.0 };
+        Some(self.bottom_diameter_m + (self.top_diameter_m - self.bottom_diameter_m) * t)
+    }
+}
+
+/// Where a booster is strapped to its core: the forward attach point
+/// on the core's skin.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BoosterAttachment {
+    pub stage: (usize, usize),
+    pub x_m: f64,
+    pub y_m: f64,
+}
+
+/// The whole outline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Silhouette {
+    pub segments: Vec<Segment>,
+    pub attachments: Vec<BoosterAttachment>,
+    /// Nozzles to nose tip.
+    pub height_m: f64,
+    /// Outer edge to outer edge, boosters included.
+    pub width_m: f64,
+}
+
+impl Silhouette {
+    pub fn of(design: &RocketDesign) -> Self {
+        let mut segments = Vec::new();
+        let mut attachments = Vec::new();
+        let mut y = 0.0;
+        let mut below: Option<f64> = None;
+        let mut capped = false;
+        let taper = |segments: &mut Vec<Segment>, stage, y: &mut f64, from: f64, to: f64| {
+            if (from - to).abs() < 1e-9 {
+                return;
+            }
+            let length_m = (from - to).abs().max(MIN_ADAPTER_LENGTH_M);
+            segments.push(Segment {
+                kind: SegmentKind::Adapter, stage, x_m: 0.0, bottom_m: *y, length_m,
+                bottom_diameter_m: from, top_diameter_m: to,
+            });
+            *y += length_m;
+        };
+        for (gi, group) in design.stage_groups.iter().enumerate() {
+            let Some(core) = group.first() else { continue };
+            let d = core.diameter_m();
+            if let Some(from) = below {
+                taper(&mut segments, (gi, 0), &mut y, from, d);
+            }
+            let core_length = core.length_m();
+            for (si, booster) in group.iter().enumerate().skip(1) {
+                // Boosters pair up left and right; later pairs go
+                // outboard of the first.
+                let bd = booster.diameter_m();
+                let side = if si % 2 == 1 { 1.0 } else { -1.0 };
+                let ring = (si - 1) / 2;
+                let x_m = side * (d / 2.0 + bd / 2.0 + ring as f64 * bd);
+                let length_m = booster.length_m();
+                segments.push(Segment {
+                    kind: SegmentKind::Tank, stage: (gi, si), x_m, bottom_m: y, length_m,
+                    bottom_diameter_m: bd, top_diameter_m: bd,
+                });
+                segments.push(Segment {
+                    kind: SegmentKind::Nose, stage: (gi, si), x_m, bottom_m: y + length_m,
+                    length_m: bd * NOSE_FINENESS, bottom_diameter_m: bd, top_diameter_m: 0.0,
+                });
+                attachments.push(BoosterAttachment {
+                    stage: (gi, si),
+                    x_m: side * d / 2.0,
+                    y_m: y + length_m.min(core_length),
+                });
+            }
+            segments.push(Segment {
+                kind: SegmentKind::Tank, stage: (gi, 0), x_m: 0.0, bottom_m: y, length_m: core_length,
+                bottom_diameter_m: d, top_diameter_m: d,
+            });
+            y += core_length;
+            below = Some(d);
+            capped = false;
+            if let Some(fairing) = &core.fairing {
+                let fd = fairing.diameter_m;
+                taper(&mut segments, (gi, 0), &mut y, d, fd);
+                let length_m = fd * FAIRING_BARREL_FINENESS;
+                segments.push(Segment {
+                    kind: SegmentKind::Fairing, stage: (gi, 0), x_m: 0.0, bottom_m: y, length_m,
+                    bottom_diameter_m: fd, top_diameter_m: fd,
+                });
+                y += length_m;
+                below = Some(fd);
+                if gi + 1 == design.stage_groups.len() {
+                    segments.push(Segment {
+                        kind: SegmentKind::Nose, stage: (gi, 0), x_m: 0.0, bottom_m: y,
+                        length_m: fd * NOSE_FINENESS, bottom_diameter_m: fd, top_diameter_m: 0.0,
+                    });
+                    capped = true;
+                }
+            }
+        }
+        if let (Some(d), false) = (below, capped) {
+            let gi = design.stage_groups.iter().rposition(|g| !g.is_empty()).unwrap_or(0);
+            segments.push(Segment {
+                kind: SegmentKind::Nose, stage: (gi, 0), x_m: 0.0, bottom_m: y,
+                length_m: d * NOSE_FINENESS, bottom_diameter_m: d, top_diameter_m: 0.0,
+            });
+        }
+        let height_m = segments.iter().map(Segment::top_m).fold(0.0, f64::max);
+        let width_m = 2.0 * segments.iter()
+            .map(|s| s.x_m.abs() + s.bottom_diameter_m.max(s.top_diameter_m) / 2.0)
+            .fold(0.0, f64::max);
+        Silhouette { segments, attachments, height_m, width_m }
+    }
+
+    /// True if the point (`x`, `y`) is inside the outline.
+    pub fn covers(&self, x: f64, y: f64) -> bool {
+        self.segments.iter().any(|s| s.diameter_at(y).is_some_and(|d| (x - s.x_m).abs() <= d / 2.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{EngineCycle, EngineDesign, EngineId, PropellantFraction};
+    use crate::propellant::Propellant;
+    use crate::rocket::RocketDesignId;
+    use crate::stage::{Fairing, Stage, StageId, StagingMode};
+
+    fn stage(id: u64, propellant_mass_kg: f64) -> Stage {
+        Stage {
+            id: StageId(id),
+            name: format!("S{}", id),
+            engine: EngineDesign {
+                id: EngineId(1),
+                name: "Kestrel".into(),
+                cycle: EngineCycle::GasGenerator,
+                thrust_n: 1_000_000.0,
+                mass_kg: 500.0,
+                isp_s: 300.0,
+                exit_pressure_pa: 100_000.0,
+                needs_atmosphere: false,
+                propellant_mix: vec![
+                    PropellantFraction { propellant: Propellant::LOX, mass_fraction: 0.725 },
+                    PropellantFraction { propellant: Propellant::RP1, mass_fraction: 0.275 },
+                ],
+                power_draw_w: 0.0,
+            },
+            engine_count: 1,
+            propellant_mass_kg,
+            structural_mass_kg: 1_500.0,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: StagingMode::Cold,
+        }
+    }
+
+    #[test]
+    fn test_outline_stacks_cores_and_straps_on_boosters() {
+        // A 4 m core with two 1.5 m boosters under a 2 m upper stage in
+        // a 4 m fairing.
+        let core = stage(1, 400_000.0);
+        let booster = stage(2, 20_000.0);
+        let mut upper = stage(4, 50_000.0);
+        upper.fairing = Some(Fairing { mass_kg: 200.0, diameter_m: 4.0, recover: false });
+        let (d, bd, ud) = (core.diameter_m(), booster.diameter_m(), upper.diameter_m());
+        assert_eq!((d, bd, ud), (4.0, 1.5, 2.0));
+        let design = RocketDesign {
+            id: RocketDesignId(1),
+            name: "Falcon".into(),
+            stage_groups: vec![vec![core.clone(), booster.clone(), booster.clone()], vec![upper.clone()]],
+            performance_reserve: Some(0.0),
+        };
+        let s = Silhouette::of(&design);
+
+        let kinds: Vec<_> = s.segments.iter().map(|s| s.kind).collect();
+        use SegmentKind as K;
+        assert_eq!(kinds, vec![K::Tank, K::Nose, K::Tank, K::Nose, K::Tank, K::Adapter, K::Tank, K::Adapter, K::Fairing, K::Nose]);
+        assert_eq!((s.segments[0].x_m, s.segments[2].x_m), (2.75, -2.75), "boosters either side");
+        assert_eq!(s.attachments.len(), 2);
+        assert_eq!(s.attachments[0].y_m, booster.length_m());
+        assert_eq!(s.width_m, 4.0 + 2.0 * bd);
+        let expected = core.length_m() + 2.0 + upper.length_m() + 2.0 + 6.0 + 4.0;
+        assert!((s.height_m - expected).abs() < 1e-9);
+
+        assert!(s.covers(0.0, 1.0) && s.covers(2.75, 1.0));
+        assert!(!s.covers(1.9, s.height_m - 0.5), "the nose narrows to its tip");
+    }
+}
@@ -1,11 +1,15 @@
 //! Shared headless-run harness used by the `simulate` binary and the
 //! integration tests in `tests/`, so both measure the game identically.
+//!
+//! `run_seed` plays a whole run under a policy. `Harness` is the same
+//! day loop exposed step by step, for tests that script their own
+//! actions between advances.
 
 use crate::balance_config::BalanceConfig;
 use crate::calendar::GameDate;
 use crate::event::GameEvent;
 use crate::game_state::GameState;
-use crate::policy::CompanyPolicy;
+use crate::policy::{CompanyPolicy, NullPolicy};
 
 /// Cumulative event tallies for one run. Launch attempts/outcomes are
 /// tallied from events because `launch_history` only records
@@ -98,6 +102,57 @@ impl RunSummary {
     }
 }
 
+/// A headless game advanced one day at a time. Script player actions
+/// by calling the usual `GameState`/`Company` methods on `game` between
+/// advances; the tally counts everything that happens either way.
+pub struct Harness {
+    pub seed: u64,
+    pub game: GameState,
+    pub tally: Tally,
+}
+
+impl Harness {
+    pub fn new(seed: u64, balance: &BalanceConfig) -> Self {
+        Harness {
+            seed,
+            game: GameState::with_balance("SimCorp".into(), seed, balance.clone()),
+            tally: Tally::default(),
+        }
+    }
+
+    /// One day: `policy` acts, then the game advances.
+    pub fn step(&mut self, policy: &mut dyn CompanyPolicy) {
+        let log_before = self.game.event_log.total_pushed();
+        policy.act(&mut self.game);
+        self.game.advance_day();
+        // Tally from the event log so policy-initiated events (launches
+        // happen during act(), not advance_day) are counted too.
+        let new_events = (self.game.event_log.total_pushed() - log_before) as usize;
+        for (_, e) in self.game.event_log.recent(new_events) {
+            self.tally.record_one(e);
+        }
+    }
+
+    /// Advance `days` with no player actions beyond what's scripted.
+    pub fn advance_days(&mut self, days: u32) {
+        for _ in 0..days {
+            self.step(&mut NullPolicy);
+        }
+    }
+
+    /// Hand the company to `policy` for `days`.
+    pub fn run_policy(&mut self, policy: &mut dyn CompanyPolicy, days: u32) {
+        for _ in 0..days {
+            self.step(policy);
+        }
+    }
+
+    /// Current metrics as a CSV row (see `CSV_HEADER`).
+    pub fn metric_row(&self) -> String {
+        metric_row(self.seed, &self.game, &self.tally)
+    }
+}
+
 /// Simulate one seed for `years` under `policy`, calling `monthly`
 /// with a metric row on day 1 of every month (plus the starting day).
 pub fn run_seed(
@@ -107,29 +162,21 @@ pub fn run_seed(
     policy: &mut dyn CompanyPolicy,
     mut monthly: impl FnMut(&str),
 ) -> RunSummary {
-    let mut gs = GameState::with_balance("SimCorp".into(), seed, balance.clone());
-    let start = gs.date;
+    let mut h = Harness::new(seed, balance);
+    let start = h.game.date;
     let end = GameDate::new(start.year + years, start.month, start.day);
 
-    let mut tally = Tally::default();
-    let mut min_money = gs.player_company.money;
+    let mut min_money = h.game.player_company.money;
     // Money at each January 1st, for year-over-year profitability.
-    let mut jan_money: Vec<(u32, f64)> = vec![(start.year, gs.player_company.money)];
+    let mut jan_money: Vec<(u32, f64)> = vec![(start.year, h.game.player_company.money)];
 
-    monthly(&metric_row(seed, &gs, &tally));
-    while gs.date < end {
-        let log_before = gs.event_log.total_pushed();
-        policy.act(&mut gs);
-        gs.advance_day();
-        // Tally from the event log so policy-initiated events (launches
-        // happen during act(), not advance_day) are counted too.
-        let new_events = (gs.event_log.total_pushed() - log_before) as usize;
-        for (_, e) in gs.event_log.recent(new_events) {
-            tally.record_one(e);
-        }
+    monthly(&h.metric_row());
+    while h.game.date < end {
+        h.step(policy);
+        let gs = &h.game;
         min_money = min_money.min(gs.player_company.money);
         if gs.date.day == 1 {
-            monthly(&metric_row(seed, &gs, &tally));
+            monthly(&h.metric_row());
             if gs.date.month == 1 {
                 jan_money.push((gs.date.year, gs.player_company.money));
             }
@@ -143,11 +190,11 @@ pub fn run_seed(
     RunSummary {
         seed,
         start_year: start.year,
-        final_money: gs.player_company.money,
+        final_money: h.game.player_company.money,
         min_money,
-        bankrupt: gs.player_company.money < 0.0,
-        launches: tally.launch_attempts as usize,
-        successes: tally.launch_successes as usize,
+        bankrupt: h.game.player_company.money < 0.0,
+        launches: h.tally.launch_attempts as usize,
+        successes: h.tally.launch_successes as usize,
         first_profitable_year,
     }
 }
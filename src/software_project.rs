@@ -0,0 +1,222 @@
+//! Flight software lineage — the guidance, navigation and commanding
+//! code a rocket flies. Developed once per design family and reused:
+//! a rocket links to a lineage and pays a small adaptation effort
+//! instead of writing its own. Runs the same `InDesign → Testing ⇄
+//! Revising` workflow as the hardware projects, but its flaws live on
+//! the lineage, so a patch reaches every linked design (and every
+//! vehicle already on the shelf — software loads at the pad).
+
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::{BalanceConfig, SoftwareConfig};
+use crate::flaw::{self, Flaw, FlawConsequence, FlawId, FlawTrigger, Subsystem};
+
+/// Unique identifier for a flight software lineage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SoftwareProjectId(pub u64);
+
+/// Workflow status of a software lineage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SoftwareDesignStatus {
+    InDesign { work_completed: f64, work_required: f64 },
+    Testing { work_completed: f64 },
+    Revising { remaining_indices: Vec<usize>, work_completed: f64 },
+}
+
+/// A rocket's link to the software lineage it flies. Adaptation work
+/// is done by the rocket's own teams before its testing resumes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareLink {
+    pub software_id: SoftwareProjectId,
+    pub adaptation_remaining: f64,
+}
+
+/// A flight software lineage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoftwareDesign {
+    pub project_id: SoftwareProjectId,
+    pub name: String,
+    pub status: SoftwareDesignStatus,
+    pub flaws: Vec<Flaw>,
+    pub revision: u32,
+    pub teams_assigned: u32,
+    /// Cumulative engineering salary spent on this lineage (NRE).
+    pub nre_cost: f64,
+    /// Cumulative work spent in testing (persists across revisions).
+    /// Drives maturity.
+    pub cumulative_testing_work: f64,
+}
+
+/// Events generated by software work.
+#[derive(Debug, Clone)]
+pub enum SoftwareWorkEvent {
+    DesignComplete { flaw_count: u32 },
+    TestingCycleComplete,
+    FlawDiscovered { flaw_description: String },
+    RevisionComplete,
+}
+
+impl SoftwareDesign {
+    pub fn new(project_id: SoftwareProjectId, name: String, cfg: &SoftwareConfig) -> Self {
+        SoftwareDesign {
+            project_id,
+            name,
+            status: SoftwareDesignStatus::InDesign {
+                work_completed: 0.0,
+                work_required: cfg.design_work,
+            },
+            flaws: Vec::new(),
+            revision: 0,
+            teams_assigned: 0,
+            nre_cost: 0.0,
+            cumulative_testing_work: 0.0,
+        }
+    }
+
+    /// Apply one day of work, done at `efficiency` of a full day's.
+    /// Returns any completed work events.
+    pub fn apply_daily_work(
+        &mut self, efficiency: f64, rng: &mut StdRng, next_flaw_id: &mut u64, balance_cfg: &BalanceConfig,
+    ) -> Vec<SoftwareWorkEvent> {
+        if self.teams_assigned == 0 {
+            return Vec::new();
+        }
+        let work = crate::team::effective_work_rate(self.teams_assigned) * efficiency;
+        let mut events = Vec::new();
+
+        match &mut self.status {
+            SoftwareDesignStatus::InDesign { work_completed, work_required } => {
+                *work_completed += work;
+                if *work_completed >= *work_required {
+                    let cfg = &balance_cfg.software;
+                    self.flaws = flaw::generate_software_flaws(
+                        cfg.flaw_count_mean, cfg.flaw_activation_scale, rng, next_flaw_id, &balance_cfg.flaws,
+                    );
+                    let flaw_count = self.flaws.len() as u32;
+                    self.status = SoftwareDesignStatus::Testing { work_completed: 0.0 };
+                    events.push(SoftwareWorkEvent::DesignComplete { flaw_count });
+                }
+            }
+            SoftwareDesignStatus::Testing { work_completed } => {
+                *work_completed += work;
+                self.cumulative_testing_work += work;
+                while *work_completed >= balance_cfg.work.testing_cycle_work {
+                    *work_completed -= balance_cfg.work.testing_cycle_work;
+                    let discovered = flaw::roll_discoveries_with_rng(
+                        &mut self.flaws, rng, balance_cfg.flaws.anomaly_discovery_multiplier,
+                    );
+                    for idx in discovered {
+                        events.push(SoftwareWorkEvent::FlawDiscovered {
+                            flaw_description: self.flaws[idx].description.clone(),
+                        });
+                    }
+                    events.push(SoftwareWorkEvent::TestingCycleComplete);
+                }
+            }
+            SoftwareDesignStatus::Revising { remaining_indices, work_completed } => {
+                *work_completed += work;
+                while let Some(&fi) = remaining_indices.first() {
+                    let fix_work = self.flaws[fi].fix_work(balance_cfg.work.flaw_revision_work);
+                    if *work_completed < fix_work {
+                        break;
+                    }
+                    *work_completed -= fix_work;
+                    remaining_indices.remove(0);
+                    self.flaws.remove(fi);
+                    events.push(SoftwareWorkEvent::RevisionComplete);
+                    for idx in remaining_indices.iter_mut() {
+                        if *idx > fi {
+                            *idx -= 1;
+                        }
+                    }
+                }
+                if remaining_indices.is_empty() {
+                    let leftover = *work_completed;
+                    self.status = SoftwareDesignStatus::Testing { work_completed: leftover };
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Start patching all discovered flaws.
+    pub fn start_revision(&mut self) -> bool {
+        if !matches!(self.status, SoftwareDesignStatus::Testing { .. }) {
+            return false;
+        }
+        let discovered_indices: Vec<usize> = self.flaws.iter()
+            .enumerate()
+            .filter(|(_, f)| f.discovered)
+            .map(|(i, _)| i)
+            .collect();
+        if discovered_indices.is_empty() {
+            return false;
+        }
+        self.revision += 1;
+        self.status = SoftwareDesignStatus::Revising {
+            remaining_indices: discovered_indices,
+            work_completed: 0.0,
+        };
+        true
+    }
+
+    /// Number of discovered flaws.
+    pub fn discovered_flaw_count(&self) -> usize {
+        self.flaws.iter().filter(|f| f.discovered).count()
+    }
+
+    /// Maturity in [0, 1]: testing hours logged against the configured
+    /// bar. Code still in design has none.
+    pub fn maturity(&self, cfg: &SoftwareConfig) -> f64 {
+        if matches!(self.status, SoftwareDesignStatus::InDesign { .. }) {
+            return 0.0;
+        }
+        (self.cumulative_testing_work / cfg.maturity_testing_work).min(1.0)
+    }
+}
+
+/// The extra guidance risk of flying software at `maturity` (0 for
+/// none at all), as a pseudo-flaw for the launch sim. None once mature.
+pub fn immaturity_flaw(maturity: f64, cfg: &SoftwareConfig) -> Option<Flaw> {
+    let activation_chance = cfg.immature_guidance_risk * (1.0 - maturity);
+    (activation_chance > 0.0).then(|| Flaw {
+        id: FlawId(u64::MAX),
+        description: "Immature flight software misjudges insertion".into(),
+        consequence: FlawConsequence::PerformanceDegradation(cfg.immature_degradation),
+        activation_chance,
+        discovery_probability: 0.0,
+        discovered: true,
+        trigger: FlawTrigger::PerFlight,
+        subsystem: Subsystem::GncSoftware,
+        anomaly: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_software_matures_through_testing() {
+        let bal = BalanceConfig::default();
+        let cfg = &bal.software;
+        let mut sw = SoftwareDesign::new(SoftwareProjectId(1), "FSW".into(), cfg);
+        sw.teams_assigned = 4;
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next_flaw_id = 0u64;
+        assert_eq!(sw.maturity(cfg), 0.0);
+        assert!(immaturity_flaw(sw.maturity(cfg), cfg).is_some());
+
+        let mut days = 0;
+        while sw.maturity(cfg) < 1.0 && days < 500 {
+            sw.apply_daily_work(1.0, &mut rng, &mut next_flaw_id, &bal);
+            days += 1;
+        }
+        assert_eq!(sw.maturity(cfg), 1.0);
+        assert!(immaturity_flaw(sw.maturity(cfg), cfg).is_none());
+        assert!(sw.flaws.iter().all(|f| f.trigger == FlawTrigger::PerFlight));
+    }
+}
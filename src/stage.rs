@@ -1,17 +1,111 @@
 use serde::{Serialize, Deserialize};
 
+use crate::balance_config::ResourcePrices;
 use crate::engine::EngineDesign;
 use crate::power::PowerSource;
+use crate::resources;
 
 /// Unique identifier for a stage.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct StageId(pub u64);
 
+/// Longest unpowered coast (in days) a chemical stage survives without a
+/// long-coast kit: batteries run flat and cryogens boil off after a GTO
+/// transfer's worth of drifting.
+pub const BASE_COAST_LIMIT_DAYS: u32 = 1;
+
+/// Longest coast a stage fitted with a long-coast kit can make before it
+/// must be spent. Long enough for a Hohmann transfer to Mars.
+pub const LONG_COAST_KIT_LIMIT_DAYS: u32 = 240;
+
+/// Fixed mass of a long-coast kit (extra batteries, avionics heaters).
+const LONG_COAST_KIT_BASE_KG: f64 = 150.0;
+
+/// Tank insulation added by a long-coast kit, per kg of propellant.
+const LONG_COAST_KIT_INSULATION_PER_KG: f64 = 0.01;
+
+/// Mass of a landing kit as a fraction of the stage's structure: legs,
+/// grid fins and the propellant held back for the landing burn.
+const LANDING_KIT_STRUCTURE_FRACTION: f64 = 0.12;
+
+/// Mass of a launch abort system: the escape tower's solid motors,
+/// structure and separation hardware.
+pub const LAUNCH_ABORT_SYSTEM_KG: f64 = 3_000.0;
+
+/// Delta-v a cold-staged boundary costs: the coast while the spent
+/// stage falls away and the next one settles its propellant, spent
+/// fighting gravity with no engine lit.
+pub const COLD_STAGING_COAST_LOSS_DV: f64 = 25.0;
+
+/// Delta-v a hot-staged boundary gains: no coast, and the upper engines
+/// are already pushing while the lower stage tails off.
+pub const HOT_STAGING_DV_GAIN: f64 = 40.0;
+
+/// Length over diameter of a stage's tanks. Stages are sized to hold
+/// their propellant at this slenderness.
+const TANK_FINENESS_RATIO: f64 = 8.0;
+
+/// Tank diameters come in standard sizes this far apart, so stages of
+/// similar size share tooling.
+pub const TANK_DIAMETER_STEP_M: f64 = 0.5;
+
+/// Narrowest tank a stage is built with.
+const MIN_TANK_DIAMETER_M: f64 = 1.0;
+
+/// Engines and thrust structure below the tanks.
+const ENGINE_SECTION_LENGTH_M: f64 = 2.0;
+
+/// How a stage group separates from the spent group below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StagingMode {
+    /// Separate, coast, then light the upper engines. Safe, but the
+    /// coast costs delta-v.
+    #[default]
+    Cold,
+    /// Light the upper engines while still attached, venting through the
+    /// interstage. Saves the coast but adds a separation event prone to
+    /// its own flaws.
+    Hot,
+}
+
+impl StagingMode {
+    /// Effect of separating this way on a design's delta-v.
+    pub fn delta_v_adjustment(self) -> f64 {
+        match self {
+            StagingMode::Cold => -COLD_STAGING_COAST_LOSS_DV,
+            StagingMode::Hot => HOT_STAGING_DV_GAIN,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StagingMode::Cold => "cold",
+            StagingMode::Hot => "hot",
+        }
+    }
+}
+
 /// A payload fairing that sits on top of a stage.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Fairing {
     pub mass_kg: f64,
     pub diameter_m: f64,
+    /// Fly a recovery operation for the halves after separation;
+    /// recovered fairings are refurbished for later builds of the design.
+    #[serde(default)]
+    pub recover: bool,
+}
+
+impl Fairing {
+    /// A fairing sized to enclose `payload_kg`: a fixed separation
+    /// system plus shell area that grows with the payload it covers.
+    pub fn sized_for(payload_kg: f64) -> Self {
+        Fairing {
+            mass_kg: 500.0 + 0.1 * payload_kg,
+            diameter_m: (2.0 + payload_kg / 5_000.0).min(7.0),
+            recover: false,
+        }
+    }
 }
 
 /// A rocket stage: structural mass, engines, propellant, optional fairing,
@@ -33,16 +127,101 @@ pub struct Stage {
     /// added power get a tiny battery synthesised at instantiate time.
     #[serde(default)]
     pub power_sources: Vec<PowerSource>,
+    /// Booster plumbed to feed its propellant to the group's core (the
+    /// first stage), so the core stays full until the boosters separate.
+    /// A crossfeed stage with no engines is a drop tank.
+    #[serde(default)]
+    pub crossfeed: bool,
+    /// Extra batteries and tank insulation that let the stage survive a
+    /// multi-day coast and relight at the far end.
+    #[serde(default)]
+    pub long_coast_kit: bool,
+    /// Landing legs, grid fins and a reserve for the landing burn, so a
+    /// first stage can fly home and be reused (see
+    /// [`crate::booster_reuse`]).
+    #[serde(default)]
+    pub landing_kit: bool,
+    /// Escape tower over the crew cabin. Lets a crew ride clear of a
+    /// failing booster (see `LaunchAbortConfig`).
+    #[serde(default)]
+    pub launch_abort_system: bool,
+    /// How this stage's group separates from the group below. Read from
+    /// the group's core (first) stage; meaningless on the bottom group.
+    #[serde(default)]
+    pub staging: StagingMode,
 }
 
 impl Stage {
+    /// True if `other` is built the same: engines, tankage, structure,
+    /// fairing, power and kit. Ids and names don't count.
+    pub fn same_hardware(&self, other: &Stage) -> bool {
+        let fairing = |s: &Stage| s.fairing.as_ref().map(|f| (f.mass_kg, f.diameter_m, f.recover));
+        let power = |s: &Stage| s.power_sources.iter().map(|p| p.mass_kg).collect::<Vec<_>>();
+        self.engine.id == other.engine.id
+            && self.engine_count == other.engine_count
+            && self.propellant_mass_kg == other.propellant_mass_kg
+            && self.structural_mass_kg == other.structural_mass_kg
+            && fairing(self) == fairing(other)
+            && power(self) == power(other)
+            && self.crossfeed == other.crossfeed
+            && self.long_coast_kit == other.long_coast_kit
+            && self.landing_kit == other.landing_kit
+            && self.launch_abort_system == other.launch_abort_system
+            && self.staging == other.staging
+    }
+
     /// Dry mass: structural mass + all engines + fairing (if present)
-    /// + power sources.
+    /// + power sources + long-coast kit + landing kit + abort system.
     pub fn dry_mass_kg(&self) -> f64 {
         let engine_mass = self.engine.mass_kg * self.engine_count as f64;
         let fairing_mass = self.fairing.as_ref().map_or(0.0, |f| f.mass_kg);
         let power_mass: f64 = self.power_sources.iter().map(|p| p.mass_kg).sum();
         self.structural_mass_kg + engine_mass + fairing_mass + power_mass
+            + self.long_coast_kit_mass_kg() + self.landing_kit_mass_kg()
+            + self.launch_abort_system_mass_kg()
+    }
+
+    /// Material cost of building this stage, given what one of its
+    /// engines costs: engines, tankage and kits, assembly, fairing and
+    /// abort system.
+    pub fn material_cost(&self, engine_unit_cost: f64, prices: &ResourcePrices) -> f64 {
+        let fairing = self.fairing.as_ref()
+            .map_or(0.0, |f| resources::fairing_material_cost(f.mass_kg, prices));
+        engine_unit_cost * self.engine_count as f64
+            + resources::tank_material_cost(self.structural_mass_kg + self.long_coast_kit_mass_kg() + self.landing_kit_mass_kg(), prices)
+            + resources::stage_assembly_cost(prices)
+            + fairing
+            + resources::abort_system_material_cost(self.launch_abort_system_mass_kg(), prices)
+    }
+
+    /// Mass of the launch abort system, or 0 when none is fitted.
+    pub fn launch_abort_system_mass_kg(&self) -> f64 {
+        if self.launch_abort_system { LAUNCH_ABORT_SYSTEM_KG } else { 0.0 }
+    }
+
+    /// Mass of the long-coast kit, or 0 when none is fitted.
+    pub fn long_coast_kit_mass_kg(&self) -> f64 {
+        if !self.long_coast_kit {
+            return 0.0;
+        }
+        LONG_COAST_KIT_BASE_KG + self.propellant_mass_kg * LONG_COAST_KIT_INSULATION_PER_KG
+    }
+
+    /// Mass of the landing kit, or 0 when none is fitted.
+    pub fn landing_kit_mass_kg(&self) -> f64 {
+        if self.landing_kit { self.structural_mass_kg * LANDING_KIT_STRUCTURE_FRACTION } else { 0.0 }
+    }
+
+    /// Longest coast in days this stage can make between burns. Electric
+    /// and sail stages are built to run for months and have no limit.
+    pub fn coast_limit_days(&self) -> u32 {
+        if self.engine.is_low_thrust() {
+            u32::MAX
+        } else if self.long_coast_kit {
+            LONG_COAST_KIT_LIMIT_DAYS
+        } else {
+            BASE_COAST_LIMIT_DAYS
+        }
     }
 
     /// Steady-state housekeeping draw in watts. Approximates ~1 W per 10 kg
@@ -60,6 +239,30 @@ impl Stage {
         self.dry_mass_kg() + self.propellant_mass_kg
     }
 
+    /// Volume of propellant in m³, from each propellant's share of the
+    /// load and its density.
+    pub fn tank_volume_m3(&self) -> f64 {
+        self.engine.propellant_mix.iter()
+            .map(|f| self.propellant_mass_kg * f.mass_fraction / (f.propellant.density_kg_per_l() * 1000.0))
+            .sum()
+    }
+
+    /// Tank diameter in meters: the standard size nearest to holding the
+    /// propellant at the usual slenderness.
+    pub fn diameter_m(&self) -> f64 {
+        let ideal = (4.0 * self.tank_volume_m3() / (std::f64::consts::PI * TANK_FINENESS_RATIO)).cbrt();
+        ((ideal / TANK_DIAMETER_STEP_M).round() * TANK_DIAMETER_STEP_M).max(MIN_TANK_DIAMETER_M)
+    }
+
+    /// Length in meters: the tanks at `diameter_m` plus the engine
+    /// section below them. Fairings are measured separately.
+    pub fn length_m(&self) -> f64 {
+        let radius = self.diameter_m() / 2.0;
+        let tanks = self.tank_volume_m3() / (std::f64::consts::PI * radius * radius);
+        let engines = if self.engine_count > 0 { ENGINE_SECTION_LENGTH_M } else { 0.0 };
+        tanks + engines
+    }
+
     /// Total thrust from all engines on this stage (Newtons).
     pub fn total_thrust_n(&self) -> f64 {
         self.engine.thrust_n * self.engine_count as f64
@@ -74,6 +277,23 @@ impl Stage {
         self.propellant_mass_kg / flow_rate
     }
 
+    /// Why this stage can't crossfeed its propellant to `core`, if it can't.
+    /// Solids can't be plumbed across, and the core's engines can only
+    /// burn the propellants they were designed for.
+    pub fn crossfeed_problem(&self, core: &Stage) -> Option<&'static str> {
+        let mix = &self.engine.propellant_mix;
+        let core_mix = &core.engine.propellant_mix;
+        if !self.engine.can_throttle() {
+            Some("solid propellant cannot be crossfed")
+        } else if mix.len() != core_mix.len()
+            || !mix.iter().all(|f| core_mix.iter().any(|c| c.propellant == f.propellant))
+        {
+            Some("propellant does not match the core")
+        } else {
+            None
+        }
+    }
+
     /// Delta-v this stage provides, given a payload mass sitting above it.
     /// Uses the Tsiolkovsky rocket equation: dv = Ve * ln(m0 / mf)
     /// where m0 = wet + payload, mf = dry + payload.
@@ -121,6 +341,11 @@ mod tests {
             structural_mass_kg: 1_500.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: StagingMode::Cold,
         }
     }
 
@@ -134,7 +359,7 @@ mod tests {
     #[test]
     fn test_dry_mass_with_fairing() {
         let mut s = test_stage();
-        s.fairing = Some(Fairing { mass_kg: 200.0, diameter_m: 4.0 });
+        s.fairing = Some(Fairing { mass_kg: 200.0, diameter_m: 4.0, recover: false });
         assert_eq!(s.dry_mass_kg(), 2200.0);
     }
 
@@ -187,6 +412,66 @@ mod tests {
         assert!((dv - expected).abs() < 1.0, "expected {}, got {}", expected, dv);
     }
 
+    #[test]
+    fn test_long_coast_kit_adds_mass_and_coast() {
+        let mut s = test_stage();
+        assert_eq!(s.coast_limit_days(), BASE_COAST_LIMIT_DAYS);
+        s.long_coast_kit = true;
+        // structural 1500 + engine 500 + kit 150 + 1% of 20t propellant
+        assert_eq!(s.dry_mass_kg(), 2350.0);
+        assert_eq!(s.coast_limit_days(), LONG_COAST_KIT_LIMIT_DAYS);
+    }
+
+    #[test]
+    fn test_landing_kit_adds_mass() {
+        let mut s = test_stage();
+        s.landing_kit = true;
+        assert!((s.dry_mass_kg() - (2000.0 + 1_500.0 * LANDING_KIT_STRUCTURE_FRACTION)).abs() < 1e-9);
+        assert!(!s.same_hardware(&test_stage()));
+    }
+
+    #[test]
+    fn test_launch_abort_system_adds_mass() {
+        let mut s = test_stage();
+        s.launch_abort_system = true;
+        assert_eq!(s.dry_mass_kg(), 2000.0 + LAUNCH_ABORT_SYSTEM_KG);
+        assert!(!s.same_hardware(&test_stage()));
+    }
+
+    #[test]
+    fn test_crossfeed_problem() {
+        let core = test_stage();
+        assert_eq!(test_stage().crossfeed_problem(&core), None);
+
+        let mut hydrolox = test_stage();
+        hydrolox.engine.propellant_mix[1].propellant = Propellant::LH2;
+        assert!(hydrolox.crossfeed_problem(&core).is_some());
+
+        let mut solid = test_stage();
+        solid.engine.propellant_mix = vec![
+            PropellantFraction { propellant: Propellant::SolidMix, mass_fraction: 1.0 },
+        ];
+        assert!(solid.crossfeed_problem(&core).is_some());
+    }
+
+    #[test]
+    fn test_tanks_grow_longer_before_wider() {
+        // 20 t of kerolox is about 19 m³: a 1.5 m tank 11 m long.
+        let s = test_stage();
+        assert!((s.tank_volume_m3() - 19.4).abs() < 0.1);
+        assert_eq!(s.diameter_m(), 1.5);
+        assert!((s.length_m() - 13.0).abs() < 0.1);
+
+        let mut longer = test_stage();
+        longer.propellant_mass_kg *= 1.2;
+        assert_eq!(longer.diameter_m(), 1.5, "a bigger load snaps to the same standard size");
+        assert!(longer.length_m() > s.length_m());
+
+        let mut drop_tank = test_stage();
+        drop_tank.engine_count = 0;
+        assert!((s.length_m() - drop_tank.length_m() - 2.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_more_payload_less_delta_v() {
         let s = test_stage();
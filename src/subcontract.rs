@@ -0,0 +1,142 @@
+//! Subcontracting launches. A contract the company can't fly itself
+//! can be handed to an outside launch provider: the provider flies it
+//! on its own schedule for a fee, the customer pays the company as
+//! usual, and the company carries none of the flight risk — and gets
+//! only a little of the credit. Each provider has a few slots a year,
+//! and every booking raises its price until demand eases.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::balance_config::SubcontractConfig;
+use crate::calendar::GameDate;
+use crate::contract::Contract;
+
+/// An outside launch company that takes subcontracted payloads.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaunchProvider {
+    pub name: String,
+    /// Destinations it flies to.
+    pub destinations: Vec<String>,
+    /// Fee as a share of the customer's price when demand is slack.
+    pub base_price: f64,
+    /// Current share: raised by each booking, easing back monthly.
+    pub price: f64,
+    pub slots_per_year: u32,
+    pub booked_this_year: u32,
+    /// Days from booking to the provider's launch.
+    pub lead_days: u32,
+}
+
+impl LaunchProvider {
+    fn new(name: &str, destinations: &[&str], base_price: f64, slots_per_year: u32, lead_days: u32) -> Self {
+        LaunchProvider {
+            name: name.into(),
+            destinations: destinations.iter().map(|d| d.to_string()).collect(),
+            base_price,
+            price: base_price,
+            slots_per_year,
+            booked_this_year: 0,
+            lead_days,
+        }
+    }
+
+    pub fn serves(&self, destination: &str) -> bool {
+        self.destinations.iter().any(|d| d == destination)
+    }
+
+    pub fn slots_left(&self) -> u32 {
+        self.slots_per_year.saturating_sub(self.booked_this_year)
+    }
+
+    /// The fee to fly `contract`.
+    pub fn quote(&self, contract: &Contract) -> f64 {
+        (contract.payment * self.price / 10_000.0).round() * 10_000.0
+    }
+
+    /// Take a slot; the next customer pays more.
+    pub fn book(&mut self, cfg: &SubcontractConfig) {
+        self.booked_this_year += 1;
+        self.price += cfg.price_step;
+    }
+
+    /// A month's easing of demand back toward the base price.
+    pub fn ease(&mut self, cfg: &SubcontractConfig) {
+        self.price -= (self.price - self.base_price) * cfg.monthly_price_recovery;
+    }
+
+    /// A new year's manifest opens.
+    pub fn new_year(&mut self) {
+        self.booked_this_year = 0;
+    }
+}
+
+/// The providers on the market at the start of a game.
+pub fn default_providers() -> Vec<LaunchProvider> {
+    vec![
+        LaunchProvider::new("Kestrel Orbital", &["leo", "sso"], 0.80, 4, 60),
+        LaunchProvider::new("Continental Heavy Lift", &["leo", "sso", "meo", "gto", "geo"], 0.95, 2, 120),
+        LaunchProvider::new("Ariadne Spaceflight", &["leo", "gto", "geo", "lunar_orbit"], 1.05, 1, 180),
+    ]
+}
+
+/// A contract handed to a provider, settled when the provider flies it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subcontract {
+    pub contract: Contract,
+    pub provider: String,
+    pub fee: f64,
+    pub launch_date: GameDate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubcontractError {
+    NoSuchContract,
+    NoSuchProvider,
+    /// The contract is already aboard one of the company's flights.
+    AlreadyFlying,
+    DestinationNotServed,
+    /// The provider's slots for the year are taken.
+    FullyBooked,
+    /// The provider couldn't launch before the contract's deadline.
+    TooLate { launch_date: GameDate },
+    CannotAfford { fee: f64 },
+}
+
+impl fmt::Display for SubcontractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubcontractError::NoSuchContract => write!(f, "No such accepted contract"),
+            SubcontractError::NoSuchProvider => write!(f, "No such provider"),
+            SubcontractError::AlreadyFlying => write!(f, "Contract is already on a flight"),
+            SubcontractError::DestinationNotServed => write!(f, "Provider doesn't fly there"),
+            SubcontractError::FullyBooked => write!(f, "Provider is fully booked this year"),
+            SubcontractError::TooLate { launch_date } =>
+                write!(f, "Provider's earliest launch ({}) misses the deadline", launch_date),
+            SubcontractError::CannotAfford { fee } =>
+                write!(f, "Can't afford the {} fee", crate::resources::format_money(*fee)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookings_raise_the_price_and_use_up_slots() {
+        let cfg = SubcontractConfig::default();
+        let mut provider = default_providers().remove(0);
+        let base = provider.price;
+        let slots = provider.slots_left();
+        provider.book(&cfg);
+        assert_eq!(provider.slots_left(), slots - 1);
+        assert!((provider.price - base - cfg.price_step).abs() < 1e-9);
+
+        provider.ease(&cfg);
+        assert!(provider.price > base && provider.price < base + cfg.price_step);
+        provider.new_year();
+        assert_eq!(provider.slots_left(), slots);
+    }
+}
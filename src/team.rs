@@ -1,17 +1,75 @@
 use serde::{Serialize, Deserialize};
 
+use crate::balance_config::CostsConfig;
+
 // Salaries and hiring costs live in `balance_config::CostsConfig`.
 
 /// Unique identifier for a team (engineering or manufacturing).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TeamId(pub u64);
 
+/// Morale a team settles back to between shocks, in [0, 1].
+pub const NEUTRAL_MORALE: f64 = 0.7;
+
+fn neutral_morale() -> f64 {
+    NEUTRAL_MORALE
+}
+
+/// What moved a team's morale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MoraleCause {
+    LaunchSuccess,
+    LaunchFailure,
+    /// Weeks of crunch wear a team down.
+    Crunch,
+    /// A colleague was let go.
+    Firing,
+    /// Payroll went out with the company in the red.
+    MissedPayroll,
+}
+
+impl std::fmt::Display for MoraleCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MoraleCause::LaunchSuccess => "launch success",
+            MoraleCause::LaunchFailure => "launch failure",
+            MoraleCause::Crunch => "crunch",
+            MoraleCause::Firing => "layoffs",
+            MoraleCause::MissedPayroll => "late paychecks",
+        })
+    }
+}
+
 /// An engineering team that can be assigned to engine/rocket design projects.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EngineeringTeam {
     pub id: TeamId,
     pub name: String,
     pub monthly_salary: f64,
+    /// 0 (ready to quit) to 1 (fired up). Drifts back toward
+    /// [`NEUTRAL_MORALE`]; below it the company gets less work done.
+    #[serde(default = "neutral_morale")]
+    pub morale: f64,
+    /// Sent home on reduced pay; can't be assigned until recalled.
+    #[serde(default)]
+    pub furloughed: bool,
+    /// Days left getting back up to speed after a furlough.
+    #[serde(default)]
+    pub ramp_up_days: u32,
+    /// Days worked, furloughs excluded. Drives the raises the team
+    /// asks for.
+    #[serde(default)]
+    pub experience_days: u32,
+    /// A raise the team has asked for and the player hasn't answered.
+    #[serde(default)]
+    pub raise_demand: Option<RaiseDemand>,
+}
+
+/// A monthly raise a team wants, and when it stops waiting for an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RaiseDemand {
+    pub monthly_raise: f64,
+    pub deadline: crate::calendar::GameDate,
 }
 
 impl EngineeringTeam {
@@ -20,16 +78,147 @@ impl EngineeringTeam {
             id,
             name,
             monthly_salary,
+            morale: NEUTRAL_MORALE,
+            furloughed: false,
+            ramp_up_days: 0,
+            experience_days: 0,
+            raise_demand: None,
+        }
+    }
+
+    /// Whole years the team has worked.
+    pub fn experience_years(&self) -> u32 {
+        self.experience_days / 365
+    }
+
+    /// The monthly raise the team asks for at its pay talks: a share
+    /// of salary per year of experience, capped.
+    pub fn raise_ask(&self, cfg: &crate::balance_config::PayrollConfig) -> f64 {
+        let share = (cfg.raise_per_experience_year * self.experience_years() as f64).min(cfg.max_raise);
+        self.monthly_salary * share
+    }
+
+    /// What the team costs this month: full salary, or the furlough
+    /// share of it.
+    pub fn payroll(&self, costs: &CostsConfig) -> f64 {
+        if self.furloughed {
+            self.monthly_salary * costs.furlough_salary_fraction
+        } else {
+            self.monthly_salary
+        }
+    }
+
+    /// `days` days of morale drifting back toward neutral.
+    pub fn recover_morale(&mut self, days: u32, cfg: &crate::balance_config::MoraleConfig) {
+        let gap = NEUTRAL_MORALE - self.morale;
+        let recovery = cfg.recovery_per_day * days as f64;
+        if gap.abs() <= recovery {
+            self.morale = NEUTRAL_MORALE;
+        } else {
+            self.morale += recovery.copysign(gap);
+        }
+    }
+
+    /// Share of a normal day's work the team gets done, from morale
+    /// and any ramp-up after a furlough.
+    pub fn efficiency(&self, cfg: &crate::balance_config::MoraleConfig) -> f64 {
+        let ramp = if self.ramp_up_days > 0 { cfg.ramp_up_efficiency } else { 1.0 };
+        morale_efficiency(self.morale, cfg) * ramp
+    }
+
+    /// Daily chance this team resigns: zero at or above the attrition
+    /// threshold, rising linearly to the full rate at zero morale.
+    pub fn attrition_chance(&self, cfg: &crate::balance_config::MoraleConfig) -> f64 {
+        if self.morale >= cfg.attrition_threshold || cfg.attrition_threshold <= 0.0 {
+            return 0.0;
+        }
+        cfg.attrition_per_day * (1.0 - self.morale / cfg.attrition_threshold)
+    }
+}
+
+/// Share of a normal day's work teams at `morale` get done: full at
+/// or above neutral, falling off below it.
+pub fn morale_efficiency(morale: f64, cfg: &crate::balance_config::MoraleConfig) -> f64 {
+    (1.0 - cfg.efficiency_slope * (NEUTRAL_MORALE - morale).max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Headcount of a manufacturing team. Medium is the standard crew the
+/// salary and hiring costs are quoted for; small and large teams scale
+/// both by the factors in `CostsConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TeamSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl TeamSize {
+    /// Largest first.
+    pub const ALL: [TeamSize; 3] = [TeamSize::Large, TeamSize::Medium, TeamSize::Small];
+
+    /// Workers, in standard crews.
+    pub fn crew(self, costs: &CostsConfig) -> f64 {
+        match self {
+            TeamSize::Small => costs.small_team_crew,
+            TeamSize::Medium => 1.0,
+            TeamSize::Large => costs.large_team_crew,
+        }
+    }
+
+    /// Salary and hiring cost against a standard crew's.
+    pub fn salary_factor(self, costs: &CostsConfig) -> f64 {
+        match self {
+            TeamSize::Small => costs.small_team_salary,
+            TeamSize::Medium => 1.0,
+            TeamSize::Large => costs.large_team_salary,
+        }
+    }
+
+    pub fn monthly_salary(self, costs: &CostsConfig) -> f64 {
+        costs.manufacturing_monthly_salary * self.salary_factor(costs)
+    }
+
+    pub fn hiring_cost(self, costs: &CostsConfig) -> f64 {
+        costs.manufacturing_hiring_cost * self.salary_factor(costs)
+    }
+
+    /// The next size up: what two teams of this size merge into.
+    pub fn larger(self) -> Option<TeamSize> {
+        match self {
+            TeamSize::Small => Some(TeamSize::Medium),
+            TeamSize::Medium => Some(TeamSize::Large),
+            TeamSize::Large => None,
+        }
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            TeamSize::Small => 'S',
+            TeamSize::Medium => 'M',
+            TeamSize::Large => 'L',
         }
     }
 }
 
+impl std::fmt::Display for TeamSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TeamSize::Small => "small",
+            TeamSize::Medium => "medium",
+            TeamSize::Large => "large",
+        })
+    }
+}
+
 /// A manufacturing team that can be assigned to manufacturing orders.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManufacturingTeam {
     pub id: TeamId,
     pub name: String,
     pub monthly_salary: f64,
+    #[serde(default)]
+    pub size: TeamSize,
 }
 
 impl ManufacturingTeam {
@@ -38,8 +227,63 @@ impl ManufacturingTeam {
             id,
             name,
             monthly_salary,
+            size: TeamSize::Medium,
         }
     }
+
+    /// A team of `size`, paid accordingly.
+    pub fn sized(id: TeamId, name: String, size: TeamSize, costs: &CostsConfig) -> Self {
+        ManufacturingTeam {
+            size,
+            ..ManufacturingTeam::new(id, name, size.monthly_salary(costs))
+        }
+    }
+}
+
+/// How many teams of each size are on a manufacturing order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TeamMix {
+    pub small: u32,
+    pub medium: u32,
+    pub large: u32,
+}
+
+impl TeamMix {
+    pub fn of(self, size: TeamSize) -> u32 {
+        match size {
+            TeamSize::Small => self.small,
+            TeamSize::Medium => self.medium,
+            TeamSize::Large => self.large,
+        }
+    }
+
+    pub fn total(self) -> u32 {
+        self.small + self.medium + self.large
+    }
+
+    /// Combined headcount, in standard crews.
+    pub fn crew(self, costs: &CostsConfig) -> f64 {
+        TeamSize::ALL.iter().map(|&s| self.of(s) as f64 * s.crew(costs)).sum()
+    }
+
+    pub fn monthly_salary(self, costs: &CostsConfig) -> f64 {
+        TeamSize::ALL.iter().map(|&s| self.of(s) as f64 * s.monthly_salary(costs)).sum()
+    }
+
+    /// Only medium teams: the mix sizes don't change anything.
+    pub fn is_standard(self) -> bool {
+        self.small == 0 && self.large == 0
+    }
+}
+
+impl std::fmt::Display for TeamMix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = TeamSize::ALL.iter()
+            .filter(|&&s| self.of(s) > 0)
+            .map(|&s| format!("{}{}", self.of(s), s.letter()))
+            .collect();
+        write!(f, "{}", parts.join("+"))
+    }
 }
 
 /// Calculate effective work rate for multiple engineering teams on one project.
@@ -54,10 +298,16 @@ pub fn manufacturing_work_rate(num_teams: u32) -> f64 {
     (num_teams as f64).powf(0.85)
 }
 
+/// Work rate of a mix of team sizes on one order. Headcount is what
+/// scales, so a large team works like two standard crews on the order
+/// — without the salary of two.
+pub fn manufacturing_team_efficiency(mix: TeamMix, costs: &CostsConfig) -> f64 {
+    mix.crew(costs).powf(0.85)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::balance_config::CostsConfig;
 
     #[test]
     fn test_new_engineering_team() {
@@ -77,6 +327,19 @@ mod tests {
         assert_eq!(team.monthly_salary, costs.manufacturing_monthly_salary);
     }
 
+    #[test]
+    fn test_morale_efficiency_and_attrition() {
+        let cfg = crate::balance_config::MoraleConfig::default();
+        assert_eq!(morale_efficiency(NEUTRAL_MORALE, &cfg), 1.0);
+        assert_eq!(morale_efficiency(1.0, &cfg), 1.0, "no bonus above neutral");
+        assert!(morale_efficiency(0.3, &cfg) < morale_efficiency(0.5, &cfg));
+
+        let mut team = EngineeringTeam::new(TeamId(1), "Alpha".into(), 1.0);
+        assert_eq!(team.attrition_chance(&cfg), 0.0);
+        team.morale = 0.0;
+        assert_eq!(team.attrition_chance(&cfg), cfg.attrition_per_day);
+    }
+
     #[test]
     fn test_effective_work_rate() {
         assert!((effective_work_rate(0) - 0.0).abs() < 0.001);
@@ -95,6 +358,17 @@ mod tests {
         assert!(mfg_4 > eng_4, "Mfg rate {} should exceed eng rate {} at 4 teams", mfg_4, eng_4);
     }
 
+    #[test]
+    fn test_team_mix_efficiency_scales_with_headcount() {
+        let costs = CostsConfig::default();
+        let two_medium = TeamMix { medium: 2, ..Default::default() };
+        let one_large = TeamMix { large: 1, ..Default::default() };
+        assert!((manufacturing_team_efficiency(two_medium, &costs) - manufacturing_work_rate(2)).abs() < 1e-9);
+        assert!((manufacturing_team_efficiency(one_large, &costs) - manufacturing_work_rate(2)).abs() < 1e-9);
+        assert!(one_large.monthly_salary(&costs) < two_medium.monthly_salary(&costs));
+        assert_eq!(TeamMix { small: 2, large: 1, ..Default::default() }.to_string(), "1L+2S");
+    }
+
     #[test]
     fn test_default_manufacturing_costs() {
         let costs = CostsConfig::default();
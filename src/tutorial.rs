@@ -0,0 +1,173 @@
+//! The first-game tutorial. A fixed script of steps that walks a new
+//! player from an empty company to a first launch: design an engine,
+//! put a team on it, design a rocket, build one and fly it. Each step
+//! says what to do, where to do it and how the game will know it's
+//! done; the game fills in a `TutorialProgress` each day and the
+//! tutorial moves on as steps are met, in order.
+//!
+//! The tutorial rides in the save, so a game quit halfway picks up on
+//! the same step. Frontends only read `Tutorial::current` and the
+//! step's `highlight`; none of the script lives in them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::GameDate;
+
+/// A screen a frontend can point the player at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Screen {
+    Engines,
+    Rockets,
+    Manufacturing,
+    Launches,
+}
+
+/// Where to look for a step: the screen, and the key that does it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight {
+    pub screen: Screen,
+    pub key: &'static str,
+}
+
+/// One step of the script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TutorialStep {
+    DesignEngine,
+    AssignTeam,
+    DesignRocket,
+    BuildRocket,
+    Launch,
+}
+
+/// How far the company has got on everything the script asks for; the
+/// game fills this in each day for `TutorialStep::met` to check.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TutorialProgress {
+    /// Engine designs saved, drafts not counted.
+    pub engine_designs: u32,
+    /// Engineering teams working on a project.
+    pub teams_assigned: u32,
+    pub rocket_designs: u32,
+    /// Rocket builds ordered, in stock or flown.
+    pub rocket_builds: u32,
+    pub launches: u32,
+}
+
+impl TutorialStep {
+    /// The script, in order.
+    pub const ALL: [TutorialStep; 5] = [
+        TutorialStep::DesignEngine,
+        TutorialStep::AssignTeam,
+        TutorialStep::DesignRocket,
+        TutorialStep::BuildRocket,
+        TutorialStep::Launch,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            TutorialStep::DesignEngine => "Design an engine",
+            TutorialStep::AssignTeam => "Put a team to work",
+            TutorialStep::DesignRocket => "Design a rocket",
+            TutorialStep::BuildRocket => "Build the rocket",
+            TutorialStep::Launch => "Launch",
+        }
+    }
+
+    /// What has to be true for the step to count as done.
+    pub fn condition(&self) -> &'static str {
+        match self {
+            TutorialStep::DesignEngine => "Save a new engine design.",
+            TutorialStep::AssignTeam => "Assign an engineering team to a design so work starts on it.",
+            TutorialStep::DesignRocket => "Save a rocket design built around your engines.",
+            TutorialStep::BuildRocket => "Order a build of the rocket from manufacturing.",
+            TutorialStep::Launch => "Fly a rocket, for a contract or as a test launch.",
+        }
+    }
+
+    pub fn highlight(&self) -> Highlight {
+        let (screen, key) = match self {
+            TutorialStep::DesignEngine => (Screen::Engines, "N"),
+            TutorialStep::AssignTeam => (Screen::Engines, "+"),
+            TutorialStep::DesignRocket => (Screen::Rockets, "N"),
+            TutorialStep::BuildRocket => (Screen::Rockets, "O"),
+            TutorialStep::Launch => (Screen::Launches, "L"),
+        };
+        Highlight { screen, key }
+    }
+
+    pub fn met(&self, p: &TutorialProgress) -> bool {
+        match self {
+            TutorialStep::DesignEngine => p.engine_designs > 0,
+            TutorialStep::AssignTeam => p.teams_assigned > 0,
+            TutorialStep::DesignRocket => p.rocket_designs > 0,
+            TutorialStep::BuildRocket => p.rocket_builds > 0,
+            TutorialStep::Launch => p.launches > 0,
+        }
+    }
+}
+
+/// A tutorial being played.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tutorial {
+    pub started: GameDate,
+    /// Date each step was met on, in order; the current step is
+    /// `completed_on.len()`.
+    pub completed_on: Vec<GameDate>,
+}
+
+impl Tutorial {
+    pub fn new(started: GameDate) -> Self {
+        Tutorial { started, completed_on: Vec::new() }
+    }
+
+    /// The step being worked on, or None once the script is done.
+    pub fn current(&self) -> Option<TutorialStep> {
+        TutorialStep::ALL.get(self.completed_on.len()).copied()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Check the current step against `progress` and move on past every
+    /// step already met, returning those steps. Several can finish on
+    /// the same day: a player who found their own way ahead isn't made
+    /// to wait.
+    pub fn advance(&mut self, progress: &TutorialProgress, date: GameDate) -> Vec<TutorialStep> {
+        let mut done = Vec::new();
+        while let Some(step) = self.current().filter(|s| s.met(progress)) {
+            self.completed_on.push(date);
+            done.push(step);
+        }
+        done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_are_met_in_order() {
+        let day = GameDate::new(2030, 1, 1);
+        let mut t = Tutorial::new(day);
+        let mut progress = TutorialProgress { rocket_designs: 1, ..Default::default() };
+        assert!(t.advance(&progress, day).is_empty(), "a rocket design doesn't skip the engine");
+        assert_eq!(t.current(), Some(TutorialStep::DesignEngine));
+        assert_eq!(t.current().unwrap().highlight(), Highlight { screen: Screen::Engines, key: "N" });
+
+        progress.engine_designs = 1;
+        progress.teams_assigned = 1;
+        let later = GameDate::new(2030, 2, 1);
+        assert_eq!(t.advance(&progress, later),
+            vec![TutorialStep::DesignEngine, TutorialStep::AssignTeam, TutorialStep::DesignRocket]);
+        assert_eq!(t.completed_on, vec![later; 3]);
+        assert_eq!(t.current(), Some(TutorialStep::BuildRocket));
+
+        progress.rocket_builds = 1;
+        progress.launches = 1;
+        assert_eq!(t.advance(&progress, later).len(), 2);
+        assert!(t.is_finished());
+        assert!(t.advance(&progress, later).is_empty(), "a finished tutorial stays finished");
+    }
+}
@@ -1,7 +1,11 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Gauge, List, ListItem, Paragraph};
 
-use crate::contract::{self, Contract};
+use crate::advisor::Severity;
+use crate::astronaut::AstronautStatus;
+use crate::contract::{self, Contract, ContractPricing};
+use crate::gov_program::ProgramStatus;
+use crate::disposal::StageFate;
 use crate::engine::EngineCycle;
 use crate::engine_project::{EngineDesignStatus, EngineSource};
 use crate::game_state::Company;
@@ -11,7 +15,11 @@ use crate::event::EventImportance;
 use crate::flaw::{Flaw, FlawConsequence, FlawTrigger};
 use crate::launch::LaunchOutcome;
 use crate::location::DELTA_V_MAP;
+use crate::regulation::LicenseClass;
+use crate::reputation::ReputationSegment;
 use crate::rocket;
+use crate::tutorial::{Screen, TutorialStep};
+use crate::units::NumberFormat;
 use crate::ui::{App, FocusedPane, InputMode, RocketDesignerState, Tab};
 
 /// Deduplicated list of destinations served by the player's currently-active
@@ -38,8 +46,7 @@ fn relevant_destinations(game: &crate::game_state::GameState) -> Vec<&str> {
 }
 
 fn format_dv(dv: f64) -> String {
-    if dv.is_infinite() { "∞".to_string() }
-    else { format!("{:.0} m/s", dv) }
+    crate::units::delta_v(dv, &NumberFormat::ENGLISH)
 }
 
 /// Electrical power in human-readable units. Switches to kW above
@@ -60,32 +67,24 @@ fn format_power_w(w: f64) -> String {
 /// Engine thrust in human-readable units. Ion thrusters live in the
 /// 1 N range; chemical engines in kN; superheavy boosters in MN.
 fn format_thrust_n(n: f64) -> String {
-    if n.abs() >= 1_000_000.0 {
-        format!("{:.2} MN", n / 1_000_000.0)
-    } else if n.abs() >= 10_000.0 {
-        format!("{:.0} kN", n / 1_000.0)
-    } else if n.abs() >= 1_000.0 {
-        format!("{:.1} kN", n / 1_000.0)
-    } else if n.abs() >= 10.0 {
-        format!("{:.0} N", n)
-    } else {
-        format!("{:.1} N", n)
-    }
+    crate::units::thrust(n, &NumberFormat::ENGLISH)
 }
 
 /// Mass in kilograms with thousands-separator commas. Reactor masses
 /// hit five+ figures at scale 1.0; the unspaced number is hard to read.
 fn format_kg(kg: f64) -> String {
-    let int = kg.round() as i64;
-    let sign = if int < 0 { "-" } else { "" };
-    let mut digits = int.unsigned_abs().to_string();
-    // Insert commas every 3 digits from the right.
-    let mut i = digits.len();
-    while i > 3 {
-        i -= 3;
-        digits.insert(i, ',');
-    }
-    format!("{}{} kg", sign, digits)
+    crate::units::mass_kg(kg, &NumberFormat::ENGLISH)
+}
+
+/// Asset health colour: red when worn out, yellow when getting there.
+fn health_style(health: f64) -> Style {
+    if health <= 0.0 {
+        Style::default().fg(Color::Red)
+    } else if health < 0.25 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    }
 }
 
 fn format_flaw_rate(flaw: &Flaw) -> String {
@@ -95,6 +94,18 @@ fn format_flaw_rate(flaw: &Flaw) -> String {
     }
 }
 
+/// Telemetry hints on still-hidden flaws, one line each.
+fn anomaly_lines(flaws: &[Flaw]) -> Vec<Line<'static>> {
+    flaws.iter()
+        .filter(|f| !f.discovered)
+        .filter_map(|f| f.anomaly.as_ref())
+        .map(|hint| Line::from(Span::styled(
+            format!("        ? {}", hint),
+            Style::default().fg(Color::Yellow),
+        )))
+        .collect()
+}
+
 /// Draw the entire application frame.
 pub fn draw(frame: &mut Frame, app: &App) {
     let size = frame.area();
@@ -148,13 +159,39 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         speed_str,
         econ_str,
     );
-    let block = Block::default()
+    let mut title = match &game.challenge {
+        Some(run) => format!(" Rocket Tycoon — {} ", run),
+        None => " Rocket Tycoon ".to_string(),
+    };
+    if game.sandbox {
+        title.push_str("[sandbox] ");
+    }
+    let mut block = Block::default()
         .borders(Borders::ALL)
-        .title(" Rocket Tycoon ");
+        .title(title);
+    if let Some((n, step)) = game.tutorial.as_ref().and_then(|t| t.current().map(|s| (t.completed_on.len() + 1, s))) {
+        let highlight = step.highlight();
+        block = block.title_bottom(Span::styled(
+            format!(" Tutorial {}/{}: {} — {} [{}] ",
+                n, TutorialStep::ALL.len(),
+                step.condition(), screen_name(highlight.screen), highlight.key),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
     let paragraph = Paragraph::new(text).block(block);
     frame.render_widget(paragraph, area);
 }
 
+/// The tab a tutorial screen lives on.
+fn screen_name(screen: Screen) -> &'static str {
+    match screen {
+        Screen::Engines => Tab::Engines.name(),
+        Screen::Rockets => Tab::Rockets.name(),
+        Screen::Manufacturing => Tab::Manufacturing.name(),
+        Screen::Launches => Tab::Launches.name(),
+    }
+}
+
 fn draw_main_area(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -175,13 +212,25 @@ fn draw_sidebar(frame: &mut Frame, app: &App, area: Rect) {
         Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
     };
 
+    // The tab the tutorial's current step happens on gets a marker.
+    let tutorial_tab = app.game.tutorial.as_ref()
+        .and_then(|t| t.current())
+        .map(|step| match step.highlight().screen {
+            Screen::Engines => Tab::Engines,
+            Screen::Rockets => Tab::Rockets,
+            Screen::Manufacturing => Tab::Manufacturing,
+            Screen::Launches => Tab::Launches,
+        });
     let items: Vec<ListItem> = Tab::ALL.iter().enumerate().map(|(i, tab)| {
         let style = if i == app.active_tab {
             highlight_style
+        } else if tutorial_tab == Some(*tab) {
+            Style::default().fg(Color::Magenta)
         } else {
             Style::default().fg(Color::DarkGray)
         };
-        ListItem::new(format!(" {} ", tab.name())).style(style)
+        let mark = if tutorial_tab == Some(*tab) { "▸" } else { " " };
+        ListItem::new(format!("{}{} ", mark, tab.name())).style(style)
     }).collect();
 
     let border_style = if app.focused_pane == FocusedPane::Sidebar {
@@ -217,7 +266,7 @@ fn draw_content(frame: &mut Frame, app: &App, area: Rect) {
 
 fn draw_overview(frame: &mut Frame, app: &App, area: Rect, border_style: Style) {
     let game = &app.game;
-    let lines = vec![
+    let mut lines = vec![
         Line::from(format!("  Company:  {}", game.player_company.name)),
         Line::from(format!("  Founded:  {}", game.start_date)),
         Line::from(format!("  Today:    {}", game.date)),
@@ -225,7 +274,10 @@ fn draw_overview(frame: &mut Frame, app: &App, area: Rect, border_style: Style)
         Line::from(""),
         Line::from(format!("  Money:    {}", format_money(game.player_company.money))),
         Line::from(""),
-        Line::from(format!("  Eng. teams:      {}", game.player_company.team_count())),
+        Line::from(format!("  Eng. teams:      {}  (morale {:.0}%{})",
+            game.player_company.team_count(),
+            100.0 * game.player_company.average_morale(),
+            if game.player_company.crunch { ", crunch" } else { "" })),
         Line::from(format!("  Mfg. teams:      {}", game.player_company.manufacturing_teams.len())),
         Line::from(format!("  Engine projects: {}", game.player_company.engine_projects.len())),
         Line::from(format!("  Rocket projects: {}", game.player_company.rocket_projects.len())),
@@ -235,7 +287,9 @@ fn draw_overview(frame: &mut Frame, app: &App, area: Rect, border_style: Style)
             game.available_contracts.len(),
             game.player_company.active_contracts.len())),
         Line::from(format!("  Launches:        {}", game.player_company.launch_history.len())),
-        Line::from(format!("  Reputation:      {:.0}", game.player_company.reputation.total())),
+        Line::from(format!("  Reputation:      {:.0}  ({})",
+            game.player_company.reputation.total(),
+            reputation_breakdown(&game.player_company.reputation, &game.balance.reputation))),
         Line::from(""),
         {
             let econ = &game.economy;
@@ -253,6 +307,19 @@ fn draw_overview(frame: &mut Frame, app: &App, area: Rect, border_style: Style)
         Line::from(""),
         Line::from(format!("  Seed:  {}", game.seed.seed())),
     ];
+    let hints = game.advisor_hints();
+    if !hints.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from("  Advisor"));
+        for hint in hints {
+            let color = match hint.severity {
+                Severity::Critical => Color::Red,
+                Severity::Warning => Color::Yellow,
+                Severity::Info => Color::Gray,
+            };
+            lines.push(Line::from(Span::styled(format!("  • {}", hint.message), Style::default().fg(color))));
+        }
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -271,6 +338,30 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         Line::from(format!("  Engine Projects ({})", visible_engines.len())),
         Line::from("  ─────────────────────────────────────────────"),
     ];
+    if !company.teams.is_empty() {
+        let morale: Vec<String> = company.teams.iter()
+            .map(|t| {
+                let status = match (t.furloughed, t.ramp_up_days) {
+                    (true, _) => format!("{} furloughed", t.name),
+                    (false, 0) => format!("{} {:.0}%", t.name, 100.0 * t.morale),
+                    (false, days) => format!("{} {:.0}% ({}d ramp-up)", t.name, 100.0 * t.morale, days),
+                };
+                match t.raise_demand {
+                    Some(d) => format!("{} [asks +{}/mo]", status, format_money(d.monthly_raise)),
+                    None => status,
+                }
+            })
+            .collect();
+        let color = if company.average_morale() < app.game.balance.morale.attrition_threshold {
+            Color::Red
+        } else {
+            Color::Gray
+        };
+        lines.push(Line::from(Span::styled(
+            format!("  Morale{}: {}", if company.crunch { " (crunch)" } else { "" }, morale.join("  ")),
+            Style::default().fg(color),
+        )));
+    }
     let mut gauges: Vec<GaugeInfo> = Vec::new();
 
     if visible_engines.is_empty() {
@@ -281,7 +372,7 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         let selected = i == app.selected_item;
         let marker = if selected { "▶" } else { " " };
 
-        let status_str = match &project.status {
+        let mut status_str = match &project.status {
             EngineDesignStatus::Proposed { .. } => unreachable!("filtered above"),
             EngineDesignStatus::InDesign { .. } => "In Design".to_string(),
             EngineDesignStatus::Testing { .. } =>
@@ -290,6 +381,10 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                 format!("Revising {} flaw(s), {} improvement(s)",
                     remaining_flaw_indices.len(), remaining_improvement_indices.len()),
         };
+        let review = crate::external_review::ReviewTarget::Engine(project.project_id);
+        if let Some(days) = app.game.player_company.external_review_days(review) {
+            status_str.push_str(&format!("  · external review {}d", days));
+        }
 
         let line_text = format!(
             "  {} {} (Rev {})  {}",
@@ -317,11 +412,16 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                     fill_color: Color::Green, text_width, right_aligned: false,
                 });
             }
-            EngineDesignStatus::Revising { work_completed, .. } => {
-                let ratio = work_completed / 30.0;
+            EngineDesignStatus::Revising { work_completed, remaining_flaw_indices, .. } => {
+                // The next flaw's fix work depends on its subsystem.
+                let base = app.game.balance.work.flaw_revision_work;
+                let step = remaining_flaw_indices.first()
+                    .and_then(|&fi| project.flaws.get(fi))
+                    .map_or(base, |f| f.fix_work(base));
+                let ratio = work_completed / step;
                 gauges.push(GaugeInfo {
                     line_index: line_idx, ratio,
-                    label: format!("{:.0}/30", work_completed),
+                    label: format!("{:.0}/{:.0}", work_completed, step),
                     fill_color: Color::Rgb(180, 130, 0), text_width, right_aligned: false,
                 });
             }
@@ -373,6 +473,22 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                 project.scale,
                 power_str,
             )));
+            if let Some(heritage) = &project.heritage {
+                let parent = company.engine_projects.iter()
+                    .find(|ep| ep.project_id == heritage.parent)
+                    .map_or("retired design", |ep| ep.design.name.as_str());
+                lines.push(Line::from(Span::styled(
+                    format!("      Variant of {}", parent),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            if let Some(issue) = &project.fundamental_issue {
+                lines.push(Line::from(Span::styled(
+                    format!("      Fundamental issue: {} — scale to {:.1}× or change cycle",
+                        issue.description, app.game.balance.combustion_instability.onset_scale),
+                    Style::default().fg(Color::Red),
+                )));
+            }
 
             // Show inventory count for engines in Testing or later
             if matches!(project.status, EngineDesignStatus::Testing { .. }) {
@@ -398,8 +514,8 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                         };
                         lines.push(Line::from(Span::styled(
                             format!(
-                                "        ▲ {}: {} ({})",
-                                flaw.description, consequence_str, format_flaw_rate(flaw),
+                                "        ▲ [{}] {}: {} ({})",
+                                flaw.subsystem, flaw.description, consequence_str, format_flaw_rate(flaw),
                             ),
                             Style::default().fg(Color::Red),
                         )));
@@ -407,6 +523,8 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                 }
             }
 
+            lines.extend(anomaly_lines(&project.flaws));
+
             // Show improvements
             let pending: Vec<_> = project.improvements.iter().filter(|i| !i.actualized).collect();
             let actualized: Vec<_> = project.improvements.iter().filter(|i| i.actualized).collect();
@@ -479,8 +597,8 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                     };
                     lines.push(Line::from(Span::styled(
                         format!(
-                            "        ▲ {}: {} ({:.0}%/flight)",
-                            flaw.description, consequence_str, flaw.activation_chance * 100.0,
+                            "        ▲ [{}] {}: {} ({:.0}%/flight)",
+                            flaw.subsystem, flaw.description, consequence_str, flaw.activation_chance * 100.0,
                         ),
                         Style::default().fg(Color::Red),
                     )));
@@ -490,9 +608,9 @@ fn draw_engines_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
     }
 
     lines.push(Line::from(""));
-    let mut controls = vec!["[N] New design", "[B] Contract 3rd-party"];
+    let mut controls = vec!["[N] New design", "[B] Contract 3rd-party", "[L] Library"];
     if !company.engine_projects.is_empty() {
-        controls.extend_from_slice(&["[+] Add team", "[-] Remove team", "[R] Revise", "[O] Order build", "[E] Hire eng team"]);
+        controls.extend_from_slice(&["[+] Add team", "[-] Remove team", "[R] Revise", "[O] Order build", "[V] Variant", "[U] External review", "[E] Hire eng team", "[F] Fire eng team", "[H] Furlough", "[K] Recall", "[A/D] Grant/deny raise", "[C] Crunch", "[Shift+L] Archive", "[T] Plan teams"]);
     }
     lines.push(Line::from(Span::styled(
         format!("  {}", controls.join("  ")),
@@ -578,8 +696,11 @@ fn draw_reactors_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
                     fill_color: Color::Green, text_width, right_aligned: false,
                 });
             }
-            ReactorDesignStatus::Revising { work_completed, .. } => {
-                let revision_work = app.game.balance.work.flaw_revision_work;
+            ReactorDesignStatus::Revising { work_completed, remaining_flaw_indices, .. } => {
+                let base = app.game.balance.work.flaw_revision_work;
+                let revision_work = remaining_flaw_indices.first()
+                    .and_then(|&fi| project.flaws.get(fi))
+                    .map_or(base, |f| f.fix_work(base));
                 let ratio = work_completed / revision_work;
                 gauges.push(GaugeInfo {
                     line_index: line_idx, ratio,
@@ -638,8 +759,8 @@ fn draw_reactors_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
                         };
                         lines.push(Line::from(Span::styled(
                             format!(
-                                "        ▲ {}: {} ({})",
-                                flaw.description, consequence_str, format_flaw_rate(flaw),
+                                "        ▲ [{}] {}: {} ({})",
+                                flaw.subsystem, flaw.description, consequence_str, format_flaw_rate(flaw),
                             ),
                             Style::default().fg(Color::Red),
                         )));
@@ -729,14 +850,23 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         let selected = i == app.selected_item;
         let marker = if selected { "▶" } else { " " };
 
-        let status_str = match &project.status {
+        let mut status_str = match &project.status {
             rocket_project::RocketDesignStatus::InDesign { .. } =>
                 "In Design".to_string(),
+            rocket_project::RocketDesignStatus::Testing { .. }
+                if matches!(project.certification, rocket_project::Certification::InReview { .. }) =>
+                "Certification review".to_string(),
+            rocket_project::RocketDesignStatus::Testing { .. } if project.is_certified() =>
+                format!("Certified  {}", project.testing_level(&app.game.balance)),
             rocket_project::RocketDesignStatus::Testing { .. } =>
                 format!("Testing  {}", project.testing_level(&app.game.balance)),
             rocket_project::RocketDesignStatus::Revising { remaining_indices, .. } =>
                 format!("Revising {} flaw(s)", remaining_indices.len()),
         };
+        let review = crate::external_review::ReviewTarget::Rocket(project.project_id);
+        if let Some(days) = company.external_review_days(review) {
+            status_str.push_str(&format!("  · external review {}d", days));
+        }
 
         let auto_target = company.auto_build_targets.get(&project.project_id).copied().unwrap_or(0);
         let auto_suffix = if !selected && auto_target > 0 {
@@ -761,6 +891,19 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                     fill_color: Color::Rgb(0, 140, 140), text_width, right_aligned: false,
                 });
             }
+            rocket_project::RocketDesignStatus::Testing { .. }
+                if matches!(project.certification, rocket_project::Certification::InReview { .. }) =>
+            {
+                if let rocket_project::Certification::InReview { work_completed, work_required, .. } =
+                    &project.certification
+                {
+                    gauges.push(GaugeInfo {
+                        line_index: line_idx, ratio: work_completed / work_required,
+                        label: format!("{:.0}/{:.0}", work_completed, work_required),
+                        fill_color: Color::Rgb(120, 90, 200), text_width, right_aligned: false,
+                    });
+                }
+            }
             rocket_project::RocketDesignStatus::Testing { work_completed } => {
                 let ratio = work_completed / 30.0;
                 gauges.push(GaugeInfo {
@@ -769,11 +912,15 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                     fill_color: Color::Green, text_width, right_aligned: false,
                 });
             }
-            rocket_project::RocketDesignStatus::Revising { work_completed, .. } => {
-                let ratio = work_completed / 30.0;
+            rocket_project::RocketDesignStatus::Revising { work_completed, remaining_indices } => {
+                let base = app.game.balance.work.flaw_revision_work;
+                let step = remaining_indices.first()
+                    .and_then(|&fi| project.flaws.get(fi))
+                    .map_or(base, |f| f.fix_work(base));
+                let ratio = work_completed / step;
                 gauges.push(GaugeInfo {
                     line_index: line_idx, ratio,
-                    label: format!("{:.0}/30", work_completed),
+                    label: format!("{:.0}/{:.0}", work_completed, step),
                     fill_color: Color::Rgb(180, 130, 0), text_width, right_aligned: false,
                 });
             }
@@ -799,6 +946,63 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                 "      {} stages, {} engines    Teams: {}    Complexity: {}",
                 total_stages, total_engines, project.teams_assigned, project.complexity,
             )));
+            if !project.is_certified() {
+                lines.push(Line::from(format!(
+                    "      Certification: {}/{} consecutive successes this revision",
+                    project.success_streak, app.game.balance.certification.required_successes,
+                )));
+            }
+            if project.design.has_launch_abort_system() {
+                lines.push(Line::from(format!(
+                    "      Abort system: {:.0}% success, {} pad-abort test(s) passed",
+                    100.0 * project.abort_success_chance(&app.game.balance.launch_abort),
+                    project.pad_aborts_passed,
+                )));
+            }
+            let software = project.software.as_ref().and_then(|link| {
+                company.software_projects.iter()
+                    .find(|sw| sw.project_id == link.software_id)
+                    .map(|sw| (link, sw))
+            });
+            match software {
+                Some((link, sw)) => {
+                    let cfg = &app.game.balance.software;
+                    let status = match &sw.status {
+                        crate::software_project::SoftwareDesignStatus::InDesign { work_completed, work_required } =>
+                            format!("in development {:.0}%", work_completed / work_required * 100.0),
+                        crate::software_project::SoftwareDesignStatus::Testing { .. } =>
+                            format!("{:.0}% mature", sw.maturity(cfg) * 100.0),
+                        crate::software_project::SoftwareDesignStatus::Revising { remaining_indices, .. } =>
+                            format!("patching {} bug(s)", remaining_indices.len()),
+                    };
+                    let adapting = if link.adaptation_remaining > 0.0 {
+                        format!(", adapting ({:.0} work left)", link.adaptation_remaining)
+                    } else {
+                        String::new()
+                    };
+                    lines.push(Line::from(format!(
+                        "      Flight software: {} Rev {} — {}{}    Teams: {}",
+                        sw.name, sw.revision, status, adapting, sw.teams_assigned,
+                    )));
+                    for flaw in sw.flaws.iter().filter(|f| f.discovered) {
+                        lines.push(Line::from(Span::styled(
+                            format!(
+                                "        ▲ [{}] {} ({})",
+                                flaw.subsystem, flaw.description, format_flaw_rate(flaw),
+                            ),
+                            Style::default().fg(Color::Red),
+                        )));
+                    }
+                    lines.extend(anomaly_lines(&sw.flaws));
+                }
+                None => lines.push(Line::from(Span::styled(
+                    format!(
+                        "      Flight software: none — {:.0}% guidance risk per flight",
+                        app.game.balance.software.immature_guidance_risk * 100.0,
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ))),
+            }
 
             // Show engines used per stage group
             let mut seen_engines: Vec<(String, u32)> = Vec::new();
@@ -871,8 +1075,8 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                         };
                         lines.push(Line::from(Span::styled(
                             format!(
-                                "        ▲ {}: {} ({})",
-                                flaw.description, consequence_str, format_flaw_rate(flaw),
+                                "        ▲ [{}] {}: {} ({})",
+                                flaw.subsystem, flaw.description, consequence_str, format_flaw_rate(flaw),
                             ),
                             Style::default().fg(Color::Red),
                         )));
@@ -880,6 +1084,39 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
                 }
             }
 
+            lines.extend(anomaly_lines(&project.flaws));
+
+            // Known engine flaws, shared with every design on that engine
+            let engine_flaws = company.known_engine_flaws(&project.design);
+            if !engine_flaws.is_empty() {
+                lines.push(Line::from(format!("      Engine flaws: {} known", engine_flaws.len())));
+                for (engine_name, flaw) in &engine_flaws {
+                    lines.push(Line::from(Span::styled(
+                        format!(
+                            "        ▲ {} [{}] {} ({})",
+                            engine_name, flaw.subsystem, flaw.description, format_flaw_rate(flaw),
+                        ),
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+            }
+
+            // Lineage: where this branch forked, and its own branches
+            if let Some(branch) = &project.branch {
+                let parent = company.rocket_projects.iter()
+                    .find(|rp| rp.project_id == branch.parent)
+                    .map_or("retired design", |rp| rp.design.name.as_str());
+                lines.push(Line::from(format!(
+                    "      Branch of {} at rev {}", parent, branch.forked_at_revision,
+                )));
+            }
+            let branches: Vec<&str> = company.rocket_branches(project.project_id).into_iter()
+                .map(|i| company.rocket_projects[i].design.name.as_str())
+                .collect();
+            if !branches.is_empty() {
+                lines.push(Line::from(format!("      Branches: {}", branches.join(", "))));
+            }
+
             // Inventory count
             let built = company.manufacturing.inventory.rocket_count(project.project_id);
             if built > 0 {
@@ -893,16 +1130,19 @@ fn draw_rockets_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
             } else {
                 lines.push(Line::from("      Auto-build: off"));
             }
+            lines.push(Line::from(format!("      Upper stage disposal: {}", project.disposal)));
         }
     }
 
     lines.push(Line::from(""));
-    let mut controls = vec!["[N] New design"];
+    let mut controls = vec!["[N] New design", "[I] Import newest design file", "[L] Library"];
     if !company.rocket_projects.is_empty() {
         controls.extend_from_slice(&[
             "[+] Add team", "[-] Remove team",
-            "[R] Revise", "[O] Order build", "[m] Auto-build",
-            "[Shift+M] Modify", "[E] Hire eng team",
+            "[R] Revise", "[C] Certify", "[A] Pad abort test", "[O] Order build", "[m] Auto-build", "[K] Commit criteria", "[D] Disposal",
+            "[W] Software", "[>/<] Software team", "[P] Patch",
+            "[Shift+M] Modify", "[B] Branch", "[G] Merge", "[X] Retire", "[J] Next in family",
+            "[U] External review", "[Shift+E] Export", "[E] Hire eng team", "[Shift+L] Archive",
         ]);
     }
     lines.push(Line::from(Span::styled(
@@ -964,8 +1204,10 @@ fn draw_manufacturing_tab(frame: &mut Frame, app: &App, area: Rect, border_style
 
         let status_str = if order.waiting_for_prerequisites {
             format!("Waiting  Teams: {}", order.teams_assigned)
-        } else {
+        } else if order.team_mix().is_standard() {
             format!("Teams: {}", order.teams_assigned)
+        } else {
+            format!("Teams: {} ({})", order.teams_assigned, order.team_mix())
         };
 
         let line_text = format!(
@@ -981,6 +1223,10 @@ fn draw_manufacturing_tab(frame: &mut Frame, app: &App, area: Rect, border_style
                 ManufacturingOrderType::Engine { .. } => Color::Cyan,
                 ManufacturingOrderType::Stage { .. } => Color::Blue,
                 ManufacturingOrderType::RocketIntegration { .. } => Color::Magenta,
+                ManufacturingOrderType::Retrofit { .. }
+                | ManufacturingOrderType::Recertification { .. }
+                | ManufacturingOrderType::BoosterInspection { .. }
+                | ManufacturingOrderType::PadAbortTest { .. } => Color::Yellow,
             };
             gauges.push(GaugeInfo {
                 line_index: line_idx,
@@ -1024,18 +1270,99 @@ fn draw_manufacturing_tab(frame: &mut Frame, app: &App, area: Rect, border_style
         if !mfg.inventory.stages.is_empty() {
             lines.push(Line::from(format!("    Stages: {}", mfg.inventory.stages.len())));
         }
+        let reuse_cfg = &app.game.balance.booster_reuse;
+        for stage in &mfg.inventory.stages {
+            let Some(life) = &stage.booster else { continue };
+            let advice = life.retirement_advice(reuse_cfg);
+            let wear = if life.wear_revealed {
+                format!("wear {:.1}x", life.wear_rate)
+            } else {
+                "wear unknown".to_string()
+            };
+            let text = format!(
+                "    Booster: {}  {} flights, {} refurbs, {}, risk {:.1}% — {}",
+                stage.stage_name, life.flights, life.refurbished_on.len(), wear,
+                life.estimated_failure_chance(reuse_cfg) * 100.0, advice,
+            );
+            let color = match advice {
+                crate::booster_reuse::RetirementAdvice::Fly => Color::Green,
+                crate::booster_reuse::RetirementAdvice::Inspect => Color::Yellow,
+                crate::booster_reuse::RetirementAdvice::Retire => Color::Red,
+            };
+            lines.push(Line::from(Span::styled(text, Style::default().fg(color))));
+        }
         if !mfg.inventory.rockets.is_empty() {
             for rocket_inv in &mfg.inventory.rockets {
-                lines.push(Line::from(format!(
-                    "    Rocket: {} Rev {}", rocket_inv.rocket_name, rocket_inv.revision
-                )));
+                let text = format!("    Rocket: {} Rev {}", rocket_inv.rocket_name, rocket_inv.revision);
+                if rocket_inv.expired {
+                    lines.push(Line::from(Span::styled(
+                        format!("{}  (past shelf life)", text),
+                        Style::default().fg(Color::Red),
+                    )));
+                } else {
+                    lines.push(Line::from(text));
+                }
             }
         }
+        let expired = mfg.inventory.engines.iter().filter(|e| e.expired).count()
+            + mfg.inventory.stages.iter().filter(|s| s.expired).count()
+            + mfg.inventory.rockets.iter().filter(|r| r.expired).count();
+        if expired > 0 {
+            lines.push(Line::from(Span::styled(
+                format!("    {} item(s) past shelf life — [C] to recertify", expired),
+                Style::default().fg(Color::Red),
+            )));
+        }
+        lines.push(Line::from(format!(
+            "    Storage fees: {}/month",
+            format_money(company.monthly_storage_cost(&app.game.balance.storage)),
+        )));
+    }
+    let recovered_fairings: u32 = mfg.recovered_fairings.values().sum();
+    if recovered_fairings > 0 {
+        lines.push(Line::from(format!("    Recovered fairings: {}", recovered_fairings)));
+    }
+    let commonality = company.commonality_analysis(&app.game.balance);
+    if !commonality.families.is_empty() {
+        let cfg = &app.game.balance.commonality;
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!(
+            "  Common stages (-{:.0}% work, -{:.0}% material, shared learning):",
+            cfg.work_discount * 100.0, cfg.material_discount * 100.0,
+        )));
+        for family in &commonality.families {
+            let members: Vec<String> = family.stages.iter()
+                .filter_map(|s| {
+                    let rp = company.rocket_projects.iter().find(|rp| rp.project_id == s.rocket_project_id)?;
+                    Some(format!("{} S{}", rp.design.name, s.group_index + 1))
+                })
+                .collect();
+            lines.push(Line::from(format!(
+                "    {} {:.1} m: {}  ({} built)",
+                family.engine_name, family.key.diameter_m(), members.join(", "), family.builds,
+            )));
+        }
+    }
+    let fleet = &company.recovery_fleet;
+    if !fleet.assets.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!(
+            "  Recovery fleet ({}/month):",
+            format_money(fleet.monthly_upkeep(&app.game.balance.recovery_fleet)),
+        )));
+        for asset in &fleet.assets {
+            let status = asset.status(app.game.date);
+            let color = if asset.available(app.game.date) { Color::Green } else { Color::Yellow };
+            lines.push(Line::from(Span::styled(
+                format!("    {}: {}", asset.name, status),
+                Style::default().fg(color),
+            )));
+        }
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "  [B] Buy floor space ($5M)  [+] Add mfg team  [-] Remove mfg team  [M] Hire mfg team",
+        "  [B] Buy floor space ($5M)  [+] Add mfg team  [-] Remove mfg team  [M] Hire mfg team  [Shift+S/L] Hire small/large  [J] Merge teams  [V] Resize last team  [C] Recertify  [I] Inspect booster  [X] Retire booster  [G/D] Buy landing zone/drone ship  [O] Send out drone ship  [Z] Scrap old engines  [P] Plan capacity",
         Style::default().fg(Color::Cyan),
     )));
 
@@ -1078,6 +1405,17 @@ fn check_contract_readiness(contract: &Contract, company: &Company) -> ContractR
     ContractReadiness::Impossible
 }
 
+/// "Commercial 40 · Government 35 · Public 20" for reputation readouts.
+fn reputation_breakdown(
+    rep: &crate::reputation::Reputation,
+    cfg: &crate::balance_config::ReputationConfig,
+) -> String {
+    ReputationSegment::ALL.iter()
+        .map(|s| format!("{} {:.0}", s.display_name(), rep.segment(*s, cfg)))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
 fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Style) {
     let game = &app.game;
     let available = &game.available_contracts;
@@ -1086,7 +1424,8 @@ fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: St
 
     let mut lines = vec![
         Line::from(Span::styled(
-            format!("  Reputation: {:.0}", rep),
+            format!("  Reputation: {:.0}  ({})", rep,
+                reputation_breakdown(&game.player_company.reputation, &game.balance.reputation)),
             Style::default().fg(Color::Cyan),
         )),
         Line::from(""),
@@ -1106,6 +1445,7 @@ fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: St
         lines.push(Line::from("  (none available — wait for next month)"));
     } else {
         // Show contracts grouped by market
+        let capability = game.player_company.capability_profile();
         for market in &active_markets {
             let market_contracts: Vec<(usize, &Contract)> = available.iter()
                 .enumerate()
@@ -1114,7 +1454,13 @@ fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: St
             if market_contracts.is_empty() { continue; }
 
             // Market header with modifier info
-            let mut header = format!("  ── {} ──", market.name);
+            let mut header = format!("  ── {} ──  {} rep {:.0}",
+                market.name,
+                market.reputation_segment.display_name(),
+                game.player_company.reputation.segment(market.reputation_segment, &game.balance.reputation));
+            if market.requires_certification {
+                header.push_str("  (certified designs only)");
+            }
             for modifier in &market.modifiers {
                 header.push_str(&format!("  ({})", modifier.description));
             }
@@ -1148,15 +1494,32 @@ fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: St
                     } else {
                         ""
                     };
-                    format!("{}{}  →{}  {:.0} kg  {}  bids close {}  by {}{}",
+                    // Beyond anything flown yet, once something has.
+                    let stretch_tag = if !capability.is_empty() && capability.is_stretch(c) {
+                        "  ▲stretch"
+                    } else {
+                        ""
+                    };
+                    format!("{}{}  →{}  {:.0} kg{}  {}  bids close {}  by {}{}{}{}{}",
                         marker, c.name, dest_name,
-                        c.payload_kg, bid_status, bid_by, c.deadline, rep_tag)
+                        c.payload_kg, rate_tag(c), bid_status, bid_by, c.deadline, pricing_tag(c), rep_tag, stretch_tag,
+                        export_tag(c, game))
                 } else {
-                    format!("{}{}  →{}  {:.0} kg  {}  by {}",
+                    format!("{}{}  →{}  {:.0} kg{}  {}  by {}{}{}{}",
                         marker, c.name, dest_name,
-                        c.payload_kg, format_money(c.payment), c.deadline)
+                        c.payload_kg, rate_tag(c), format_money(c.payment), c.deadline, pricing_tag(c), accuracy_tag(c),
+                        export_tag(c, game))
                 };
                 lines.push(Line::from(Span::styled(text, style)));
+                if i == app.selected_item {
+                    if let Some(r) = &c.reward {
+                        lines.push(Line::from(Span::styled(
+                            format!("      ${:.0}/kg going rate × {:.2} deadline × {:.2} demand × {:.2} relationship",
+                                r.base_per_kg, r.deadline_mult, r.demand_mult, r.relationship_mult),
+                            Style::default().fg(Color::DarkGray),
+                        )));
+                    }
+                }
             }
         }
 
@@ -1214,22 +1577,115 @@ fn draw_contracts_tab(frame: &mut Frame, app: &App, area: Rect, border_style: St
                 }
             };
             lines.push(Line::from(Span::styled(
-                format!("{}{}  →{}  {:.0} kg  {}  by {}",
+                format!("{}{}  →{}  {:.0} kg  {}  by {}{}{}{}{}",
                     marker, c.name, dest_name,
-                    c.payload_kg, format_money(c.payment), c.deadline),
+                    c.payload_kg, format_money(c.payment), c.deadline, pricing_tag(c), accuracy_tag(c),
+                    export_tag(c, game), payload_tag(c, game)),
                 style,
             )));
         }
     }
 
+    let subcontracts = &game.player_company.subcontracts;
+    if !subcontracts.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  ── Subcontracted ──",
+            Style::default().fg(Color::DarkGray),
+        )));
+        for s in subcontracts {
+            lines.push(Line::from(Span::styled(
+                format!("    {}  →{}  {} launches {}  (fee {})",
+                    s.contract.name, contract::destination_display_name(&s.contract.destination),
+                    s.provider, s.launch_date, format_money(s.fee)),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+    }
+
+    if !game.science_missions.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  ── Returning data ──",
+            Style::default().fg(Color::DarkGray),
+        )));
+        for m in &game.science_missions {
+            lines.push(Line::from(Span::styled(
+                format!("    {}  at {}  {} returned  {} days left  {:.0}% coverage",
+                    m.contract_name, contract::destination_display_name(&m.location_id),
+                    format_money(m.returned), m.days_left,
+                    game.science_coverage(&m.location_id) * 100.0),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(" Contracts  [B] Bid / Accept  [R] Bid Rules  [P] Programs  [H] History ");
+        .title(" Contracts  [B] Bid / Accept  [R] Bid Rules  [P] Programs  [G] Gov  [H] History  [O] Outlook  [U] Subcontract ");
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, area);
 }
 
+/// Row suffix for contracts not paid in full on arrival: cost-plus
+/// shows its fee and the date the fee depends on launching by, a
+/// science mission how much waits on the data it returns.
+fn pricing_tag(c: &Contract) -> String {
+    match &c.pricing {
+        ContractPricing::CostPlus { fee_fraction, launch_by, .. } =>
+            format!("  cost-plus {:.0}% fee if launched by {}", fee_fraction * 100.0, launch_by),
+        ContractPricing::DataReturn { upfront_share, months } =>
+            format!("  science: {:.0}% on arrival, rest for {} months of data",
+                upfront_share * 100.0, months),
+        ContractPricing::FixedPrice => String::new(),
+    }
+}
+
+/// Price per kg for comparison shopping: the market's rate for an open
+/// solicitation (the customer's own budget stays hidden), the agreed
+/// price otherwise.
+fn rate_tag(c: &Contract) -> String {
+    match (&c.reward, c.is_solicitation()) {
+        (Some(r), true) => format!(" ~${:.0}/kg", r.market_per_kg()),
+        (None, true) => String::new(),
+        (_, false) => format!(" ${:.0}/kg", c.payment_per_kg()),
+    }
+}
+
+/// Row suffix for contracts that specify how accurately the payload
+/// has to be inserted.
+fn accuracy_tag(c: &Contract) -> String {
+    c.insertion_tolerance
+        .map(|t| format!("  insertion ±{:.0} m/s", t))
+        .unwrap_or_default()
+}
+
+/// When a customer's payload reaches the pad, while it hasn't yet.
+fn payload_tag(c: &Contract, game: &crate::game_state::GameState) -> String {
+    match game.player_company.customer_payloads.get(c.id) {
+        Some(p) if p.ready_on > game.date && p.slipped_days > 0 =>
+            format!("  payload ready {} (slipped {}d)", p.ready_on, p.slipped_days),
+        Some(p) if p.ready_on > game.date => format!("  payload ready {}", p.ready_on),
+        _ => String::new(),
+    }
+}
+
+/// Row suffix for export-controlled payloads: whether the player may
+/// compete for one, or when an accepted one's paperwork clears.
+fn export_tag(c: &Contract, game: &crate::game_state::GameState) -> String {
+    if !c.export_controlled {
+        return String::new();
+    }
+    let compliance = &game.player_company.compliance;
+    match compliance.export_filing(c.id) {
+        Some(f) if f.ready_on > game.date => format!("  export papers clear {}", f.ready_on),
+        Some(_) => "  export papers cleared".to_string(),
+        None if compliance.export_eligible(game.date) => "  export-controlled".to_string(),
+        None => "  export-controlled (needs clearance)".to_string(),
+    }
+}
+
 fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Style) {
     let game = &app.game;
     let rockets = &game.player_company.manufacturing.inventory.rockets;
@@ -1238,7 +1694,7 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
 
     // Show inventory rockets ready for launch
     lines.push(Line::from(Span::styled(
-        "  ── Ready Rockets ──",
+        "  ── Ready Rockets ──  [B] Press briefing  [W] Livestream  [%] Propellant load",
         Style::default().fg(Color::DarkGray),
     )));
 
@@ -1257,13 +1713,49 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
             let payload_info = game.player_company.rocket_projects.iter()
                 .find(|rp| rp.project_id == r.rocket_project_id)
                 .map(|rp| {
-                    let leo = rocket_project::max_payload_to(&rp.design, "earth_surface", "leo");
-                    format!("  LEO: {}", format_mass(leo))
+                    let design = r.design.as_ref().unwrap_or(&rp.design);
+                    let leo = rocket_project::max_payload_to(design, "earth_surface", "leo");
+                    let stale = if r.revision < rp.revision {
+                        format!("  [Rev {} out]", rp.revision)
+                    } else {
+                        String::new()
+                    };
+                    format!("  LEO: {}{}", format_mass(leo), stale)
                 })
                 .unwrap_or_default();
 
+            let prep_info = game.player_company.flight_preparation(r.item_id)
+                .map(|p| if p.is_complete() {
+                    format!("  validated: {} ≤{}", p.plan.destination, format_mass(p.plan.payload_kg))
+                } else {
+                    format!("  integration {:.0}%", 100.0 * p.work_completed / p.work_required)
+                })
+                .unwrap_or_default();
+
+            let crew_info = game.player_company.astronauts.assignments.iter()
+                .find(|c| c.rocket_item_id == r.item_id)
+                .map(|c| format!("  crew: {}", c.crew.len()))
+                .unwrap_or_default();
+
+            let press_info = game.player_company.marketing.press_event(r.item_id)
+                .map(|p| format!("  {} booked", p.kind))
+                .unwrap_or_default();
+
+            let load_info = match r.propellant_load {
+                crate::rocket::PropellantLoad::Full => String::new(),
+                load => format!("  prop: {}", load.label()),
+            };
+
+            let pad_info = if game.check_pad_fits(r.item_id).is_err() {
+                "  [too heavy for pad]"
+            } else {
+                ""
+            };
+
             lines.push(Line::from(Span::styled(
-                format!("{}{} (Rev {}){}", marker, r.rocket_name, r.revision, payload_info),
+                format!("{}{} (Rev {}){}{}{}{}{}{}",
+                    marker, r.rocket_name, r.revision, payload_info, prep_info, crew_info, press_info, load_info,
+                    pad_info),
                 style,
             )));
         }
@@ -1271,6 +1763,240 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
 
     lines.push(Line::from(""));
 
+    // Pad calendar: signed leases block the player's launches.
+    lines.push(Line::from(Span::styled(
+        "  ── Pad Calendar ──  [A] Accept offer  [X] Cancel next lease  [Y] Move pad  [P] Convert pad",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let site = &game.player_company.launch_site;
+    let here = site.site_location();
+    let plane_changes: Vec<String> = ["sso", "meo", "geo"].iter()
+        .map(|dest| format!("{} {:+.0} m/s", contract::destination_display_name(dest), game.plane_change_dv(dest)))
+        .collect();
+    lines.push(Line::from(format!(
+        "  Site: {} ({:.1}°N, up to {:.0}° inclination)  plane changes: {}",
+        here.name, here.latitude_deg, here.max_inclination_deg, plane_changes.join(", "),
+    )));
+    let cfg = &game.balance.launch_sites;
+    let other = site.integration.other();
+    lines.push(Line::from(format!(
+        "  Integration: {} (up to {:.0} t, pad flow {}d + {}d/stage)  convert to {}: {}",
+        site.integration, site.integration.max_liftoff_kg(cfg) / 1000.0,
+        site.integration.pad_flow_days(0, cfg), site.integration.pad_flow_days(1, cfg) - site.integration.pad_flow_days(0, cfg),
+        other, format_money(other.conversion_cost(cfg)),
+    )));
+    if let Some(c) = site.conversion {
+        lines.push(Line::from(Span::styled(
+            format!("  Converting to {} integration until {} — launches blocked", c.to, c.ready_on),
+            Style::default().fg(Color::Yellow),
+        )));
+    } else if let Some(until) = site.pad_flow_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Readying the pad for the next vehicle until {}", until),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    if let Some(lease) = site.lease_on(game.date) {
+        lines.push(Line::from(Span::styled(
+            format!("  Pad leased to {} until {} — launches blocked", lease.lessee, lease.end()),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    for lease in site.leases.iter().filter(|l| l.start > game.date) {
+        lines.push(Line::from(format!(
+            "  {} — {} days from {}  {}/day",
+            lease.lessee, lease.days, lease.start, format_money(lease.daily_rate),
+        )));
+    }
+    for offer in &game.pad_lease_offers {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  Offer: {} — {} days from {}  {}/day",
+                offer.lessee, offer.days, offer.start, format_money(offer.daily_rate),
+            ),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    if site.leases.is_empty() && game.pad_lease_offers.is_empty() {
+        lines.push(Line::from("  (no leases or offers)"));
+    }
+
+    lines.push(Line::from(""));
+
+    // Regulator: licenses, reviews, and the site's cadence limit.
+    lines.push(Line::from(Span::styled(
+        "  ── Regulatory ──  [G] Apply for license  [S] Security clearance",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let compliance = &game.player_company.compliance;
+    let license_parts: Vec<String> = [LicenseClass::EarthOrbit, LicenseClass::Cislunar, LicenseClass::DeepSpace]
+        .iter()
+        .map(|&class| {
+            let status = if compliance.has_license(class) {
+                "✓".to_string()
+            } else if let Some(app) = compliance.application(class) {
+                format!("pending {}", app.ready_on)
+            } else {
+                "—".to_string()
+            };
+            format!("{} {}", class, status)
+        })
+        .collect();
+    lines.push(Line::from(format!("  Licenses: {}", license_parts.join(", "))));
+    let clearance = if compliance.security_clearance {
+        "held".to_string()
+    } else if let Some(ready_on) = compliance.clearance_ready_on {
+        format!("upgrade ready {}", ready_on)
+    } else {
+        "—".to_string()
+    };
+    lines.push(Line::from(format!("  Security clearance: {}", clearance)));
+    if let Some(until) = compliance.export_ban_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Barred from export-controlled contracts until {}", until),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    if let Some(ready_on) = site.rebuild_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Pad destroyed — rebuilding until {}", ready_on),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    if let Some(until) = compliance.stand_down_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Mishap investigation stand-down until {} — launches blocked", until),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    if let Some(until) = compliance.review_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Environmental review until {} — launches blocked", until),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    let cfg = &game.balance.regulation;
+    let recent = site.recent_launches.iter()
+        .filter(|d| d.add_days(cfg.cadence_window_days) > game.date)
+        .count();
+    lines.push(Line::from(format!(
+        "  Cadence: {}/{} launches in {} days",
+        recent, cfg.max_launches_per_window, cfg.cadence_window_days,
+    )));
+
+    lines.push(Line::from(""));
+
+    // Astronaut corps: who can fly, and any stand-down after a loss.
+    lines.push(Line::from(Span::styled(
+        "  ── Astronaut Corps ──  [H] Hire  [C] Assign crew",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let corps = &game.player_company.astronauts;
+    if let Some(until) = corps.stand_down_until.filter(|&until| game.date < until) {
+        lines.push(Line::from(Span::styled(
+            format!("  Stand-down after crew loss until {} — teams at reduced efficiency", until),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    if corps.astronauts.is_empty() {
+        lines.push(Line::from("  (no astronauts)"));
+    }
+    for a in &corps.astronauts {
+        let status = match &a.status {
+            AstronautStatus::Training { days_remaining } => format!("training, {} days left", days_remaining),
+            AstronautStatus::Ready if a.can_fly(&game.balance.astronauts) => "ready".to_string(),
+            AstronautStatus::Ready => "resting".to_string(),
+            AstronautStatus::Flying => "flying".to_string(),
+            AstronautStatus::Lost { date } => format!("lost {}", date),
+        };
+        let style = if matches!(a.status, AstronautStatus::Lost { .. }) {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {} — {}  skill {:.0}%  fatigue {:.0}%  {} flights",
+                a.name, status, 100.0 * a.skill, 100.0 * a.fatigue, a.career.len(),
+            ),
+            style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+
+    // Off-Earth infrastructure: depots and the ISRU plants filling them.
+    lines.push(Line::from(Span::styled(
+        "  ── Infrastructure ──  [I] ISRU plant  [L] Depot designs  [O] Refuel  [T] Auto top-up  [M] Service",
+        Style::default().fg(Color::DarkGray),
+    )));
+    if game.infrastructure.is_empty() {
+        lines.push(Line::from("  (nothing built off Earth)"));
+    }
+    for (infra, report) in game.infrastructure.iter().zip(game.depot_reports()) {
+        lines.push(Line::from(format!(
+            "  {} — {} {} / {}  {} plant(s), {}/day",
+            contract::destination_display_name(&report.location),
+            infra.depot.design.name,
+            format_kg(report.total_kg),
+            format_kg(report.capacity_kg),
+            infra.isru_plants.len(),
+            format_kg(infra.isru_output_kg_per_day()),
+        )));
+        if let Some(health) = infra.lowest_plant_health() {
+            lines.push(Line::from(Span::styled(
+                format!("    plant health {:.0}% (most worn)", 100.0 * health),
+                health_style(health),
+            )));
+        }
+        if !report.inventories.is_empty() {
+            let stock: Vec<String> = report.inventories.iter()
+                .map(|(p, kg)| format!("{} {}", p.display_name(), format_kg(*kg)))
+                .collect();
+            lines.push(Line::from(format!(
+                "    {}  (+{}/day, -{} boiloff)",
+                stock.join(", "),
+                format_kg(report.fill_rate_kg_per_day),
+                format_kg(report.boiloff_kg_last_day),
+            )));
+        }
+        let budget = report.power;
+        let short = budget.isru_fraction < 1.0 || budget.cooling_fraction < 1.0;
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    power {:.0}/{:.0} kW  ISRU at {:.0}%  cooling {:.0}%  boiloff {:.2}%/day",
+                budget.demand_kw(), budget.supply_kw,
+                100.0 * budget.isru_fraction, 100.0 * budget.cooling_fraction,
+                100.0 * budget.boiloff_per_day,
+            ),
+            if short { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) },
+        )));
+        for inbound in &report.inbound {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "    inbound: {} in {} days, {} aboard",
+                    inbound.rocket_name, inbound.eta_days, format_kg(inbound.propellant_kg),
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for order in &report.transfer_orders {
+            let name = game.spacecraft.iter()
+                .find(|sc| sc.id == order.spacecraft_id)
+                .map_or("?", |sc| sc.name.as_str());
+            let what = match order.direction {
+                crate::infrastructure::TransferDirection::Fill => "auto top-up",
+                crate::infrastructure::TransferDirection::Offload => "auto offload",
+            };
+            lines.push(Line::from(Span::styled(
+                format!("    {}: {}", name, what),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+
     // In-flight rockets
     lines.push(Line::from(Span::styled(
         "  ── In Flight ──",
@@ -1324,10 +2050,22 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
             lines.push(Line::from(vec![
                 Span::raw("      "),
                 Span::styled(format!("{}  ", leg_progress), Style::default().fg(Color::DarkGray)),
-                Span::styled(format!("ETA: {}  ", eta_str), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("ETA: {} ({:.0}% flown)  ", eta_str, flight.progress() * 100.0),
+                    Style::default().fg(Color::DarkGray)),
                 Span::styled(format!("Δv: {}", format_dv(remaining_dv)), Style::default().fg(Color::DarkGray)),
             ]));
 
+            // Telemetry: the latest in-transit anomaly, if any.
+            if let Some(last) = flight.telemetry.last() {
+                lines.push(Line::from(Span::styled(
+                    format!("      Telemetry: {} anomal{}, last {} on leg {}: {}",
+                        flight.telemetry.len(),
+                        if flight.telemetry.len() == 1 { "y" } else { "ies" },
+                        last.kind.description(), last.leg + 1, last.response),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+
             // Per-stage dv breakdown (for multi-stage rockets)
             if flight.design.stage_groups.len() > 1 {
                 let mut stage_parts = Vec::new();
@@ -1406,6 +2144,46 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
 
     lines.push(Line::from(""));
 
+    // Constellations and their replacement plans.
+    lines.push(Line::from(Span::styled(
+        "  ── Constellations ──  [N] Form from newest spacecraft  [J] Auto-replace",
+        Style::default().fg(Color::DarkGray),
+    )));
+    if game.constellations.is_empty() {
+        lines.push(Line::from("  (no constellations)"));
+    }
+    let tasks = game.replacement_tasks();
+    for c in &game.constellations {
+        let operational = game.constellation_operational(c);
+        let style = if operational < c.required as usize {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {} @ {}  {}/{} working  {:.0}%  {}/day{}",
+                c.name,
+                contract::destination_display_name(&c.location),
+                operational, c.required,
+                100.0 * c.completeness(operational),
+                crate::resources::format_money(c.daily_revenue(operational, &game.balance.constellation)),
+                if c.auto_replace { "  [auto-replace]" } else { "" },
+            ),
+            style,
+        )));
+        let mine: Vec<_> = tasks.iter().filter(|t| t.constellation_id == c.id).collect();
+        if let Some(first) = mine.first() {
+            let more = if mine.len() > 1 { format!(" (+{} more)", mine.len() - 1) } else { String::new() };
+            lines.push(Line::from(Span::styled(
+                format!("    replacement needed by {}{}", first.needed_by, more),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+
     // Spacecraft in orbit/on surface
     lines.push(Line::from(Span::styled(
         "  ── Spacecraft ──",
@@ -1423,6 +2201,7 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
                 Span::styled("  ◆ ", Style::default().fg(Color::Green)),
                 Span::raw(format!("{} @ {}  ", sc.name, loc_name)),
                 Span::styled(format!("Δv: {}", format_dv(dv)), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("  health {:.0}%", 100.0 * sc.health), health_style(sc.health)),
             ];
             // Show current stage group if not on the final one
             let total_groups = sc.design.stage_groups.len();
@@ -1510,6 +2289,13 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
                         format!("contract ({:.0} kg)", payload_kg),
                     crate::flight::Payload::TestMass { mass_kg } =>
                         format!("test mass ({:.0} kg)", mass_kg),
+                    crate::flight::Payload::IsruPlant { design, deploy_at } => format!(
+                        "{} → {}", design.name, contract::destination_display_name(deploy_at)),
+                    crate::flight::Payload::Depot { design, deploy_at, .. } => format!(
+                        "{} → {}", design.name, contract::destination_display_name(deploy_at)),
+                    crate::flight::Payload::ServicingKit(kit) => format!(
+                        "servicing kit for {} → {}",
+                        kit.asset_name, contract::destination_display_name(kit.target.location())),
                 }).collect();
                 lines.push(Line::from(Span::styled(
                     format!("      Carrying: {}", parts.join(", ")),
@@ -1538,17 +2324,34 @@ fn draw_launches_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Sty
                 LaunchOutcome::PartialFailure { .. } => Span::styled("PARTIAL", Style::default().fg(Color::Yellow)),
                 LaunchOutcome::Failure { .. } => Span::styled("FAILURE", Style::default().fg(Color::Red)),
             };
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::raw(format!("  {} {} →{} ", record.launch_date, record.rocket_name, dest_name)),
                 outcome_str,
-            ]));
+            ];
+            if !record.transit_anomalies.is_empty() {
+                spans.push(Span::styled(
+                    format!("  ({} transit anomal{})", record.transit_anomalies.len(),
+                        if record.transit_anomalies.len() == 1 { "y" } else { "ies" }),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            let debris = record.stage_disposals.iter()
+                .filter(|d| matches!(d.fate, StageFate::Debris { .. } | StageFate::Downrange { cleared: false, .. }))
+                .count();
+            if debris > 0 {
+                spans.push(Span::styled(
+                    format!("  ({} stage{} off-nominal)", debris, if debris == 1 { "" } else { "s" }),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
     }
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(" Launches [L]aunch [K]eep [F]ly [D]ock [U]ndock [P]lan ");
+        .title(" Launches [L]aunch [K]eep [F]ly [D]ock [U]ndock [P]lan [V]alidate [R]etrofit [E]ngines out [Z] Scrap ");
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, area);
 }
@@ -1558,7 +2361,7 @@ fn draw_finance_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
     let company = &game.player_company;
     let financials = &company.monthly_financials;
 
-    let salary = company.monthly_salary_cost();
+    let salary = company.monthly_salary_cost(&game.balance.costs);
     let runway = if salary > 0.0 && company.money > 0.0 {
         format!("{:.0} months", company.money / salary)
     } else if salary <= 0.0 {
@@ -1572,9 +2375,124 @@ fn draw_finance_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         Line::from(format!("  Monthly Salary: {}", format_money(salary))),
         Line::from(format!("  Runway: {}", runway)),
         Line::from(format!("  Reputation: {:.0}", company.reputation.total())),
-        Line::from(""),
     ];
 
+    // Cash-flow forecast: a weekly sparkline and the low point.
+    let days = game.balance.forecast.horizon_days;
+    let forecast = game.cash_forecast(days);
+    if let Some((low_on, low)) = forecast.low_point() {
+        let weekly: Vec<f64> = forecast.daily.iter().step_by(7).map(|&(_, m)| m).collect();
+        lines.push(Line::from(Span::styled(
+            format!("  Forecast ({} d): {}  low {} on {}", days, sparkline(&weekly), format_money(low), low_on),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    if let Some(date) = forecast.insolvent_on {
+        lines.push(Line::from(Span::styled(
+            format!("  Projected to run out of money on {}", date),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    // Valuation and listing
+    let valuation = game.valuation();
+    lines.push(Line::from(format!(
+        "  Valuation: {}  (assets {}, designs {}, backlog {}, fame {})",
+        format_money(valuation.total()),
+        format_money(valuation.assets),
+        format_money(valuation.designs),
+        format_money(valuation.backlog),
+        format_money_signed(valuation.fame),
+    )));
+    let history: Vec<f64> = company.valuation_history.iter().rev().take(24).rev()
+        .map(|r| r.value)
+        .collect();
+    if history.len() > 1 {
+        lines.push(Line::from(Span::styled(
+            format!("  Trend (24 mo): {}", sparkline(&history)),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    match &company.listing {
+        Some(listing) => lines.push(Line::from(format!(
+            "  Public since {} — quarterly target {}, sentiment {:.0}%{}",
+            listing.ipo_date,
+            format_money(listing.quarterly_target),
+            listing.sentiment * 100.0,
+            if listing.missed_quarters > 0 {
+                format!(", {} missed", listing.missed_quarters)
+            } else {
+                String::new()
+            },
+        ))),
+        None => lines.push(Line::from(Span::styled(
+            format!("  Private — [I] IPO (needs {} valuation)",
+                format_money(game.balance.valuation.min_ipo_valuation)),
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    // Media
+    let marketing = &company.marketing;
+    lines.push(Line::from(format!(
+        "  Media sentiment: {:+.0}%  (contract rates {:+.1}%){}  [M] PR campaign ({})",
+        100.0 * marketing.sentiment,
+        100.0 * (marketing.contract_rate_mult(&game.balance.marketing) - 1.0),
+        if marketing.pr_active() {
+            format!("  PR campaign: {} days left", marketing.pr_days_remaining)
+        } else {
+            String::new()
+        },
+        format_money(game.balance.marketing.pr_campaign_cost),
+    )));
+    if let Some(crisis) = &marketing.crisis {
+        let cfg = &game.balance.pad_failure;
+        lines.push(Line::from(Span::styled(
+            format!("  PRESS CRISIS over {} — answer by {}:  [T] Transparent briefing ({})  [P] Spin ({})",
+                crisis.rocket_name, crisis.respond_by,
+                format_money(cfg.transparent_cost), format_money(cfg.spin_cost)),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    lines.push(Line::from(format!(
+        "  Pad insurance: {}  [U] Toggle ({}/mo, covers {:.0}% of a rebuild)",
+        if company.launch_site.insured { "covered" } else { "none" },
+        format_money(game.balance.pad_failure.insurance_premium_per_month),
+        game.balance.pad_failure.insurance_coverage * 100.0,
+    )));
+    let sentiment: Vec<f64> = marketing.sentiment_history.iter().map(|(_, s)| 100.0 * s).collect();
+    if sentiment.len() > 1 {
+        lines.push(Line::from(Span::styled(
+            format!("  Media trend ({} mo): {}", sentiment.len(), sparkline(&sentiment)),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+
+    // Fixed assets
+    let assets = &company.assets;
+    lines.push(Line::from(format!(
+        "  Facilities: {} on the books, {} depreciated  [V] Sell newest floor block",
+        format_money(assets.book_value()),
+        format_money(assets.accumulated_depreciation),
+    )));
+
+    // Security and espionage
+    let esp = &game.balance.espionage;
+    lines.push(Line::from(format!(
+        "  Security: level {}/{} ({}/mo)  [E] Change",
+        company.security_level,
+        esp.max_security_level,
+        format_money(company.security_level as f64 * esp.security_cost_per_level),
+    )));
+    if let Some(offer) = &game.leak_offer {
+        lines.push(Line::from(Span::styled(
+            format!("  Leak on offer: {} — {} until {}  [B] Buy",
+                offer.description(), format_money(offer.price), offer.expires),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    lines.push(Line::from(""));
+
     // Reputation breakdown — only show non-zero factors
     let rep = &company.reputation;
     let factors: Vec<(&str, f64)> = vec![
@@ -1582,6 +2500,7 @@ fn draw_finance_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         ("Lost Payload", rep.lost_payload_factor),
         ("Drought", rep.drought_factor),
         ("Expiry", rep.expiry_factor),
+        ("Earnings", rep.earnings_factor),
     ];
     let active_factors: Vec<_> = factors.iter().filter(|(_, v)| v.abs() > 0.05).collect();
     if !active_factors.is_empty() {
@@ -1599,6 +2518,49 @@ fn draw_finance_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         lines.push(Line::from(""));
     }
 
+    // Department budgets: this month's spend against the cap, and how
+    // last month closed.
+    lines.push(Line::from(Span::styled(
+        "  ── Budgets ──  [D] Department  [C] Cap",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let budgets = &company.budgets;
+    let last = budgets.last_month();
+    for (i, &dept) in crate::budget::Department::ALL.iter().enumerate() {
+        let marker = if i == app.budget_department { ">" } else { " " };
+        let cap = budgets.limit(dept).map_or("uncapped".to_string(), format_money);
+        let spent = budgets.spent(dept);
+        let over = budgets.limit(dept).is_some_and(|c| spent > c);
+        let mut spans = vec![
+            Span::styled(
+                format!(" {}{:<14} {} of {}", marker, dept.name(), format_money(spent), cap),
+                Style::default().fg(if over { Color::Red } else { Color::White }),
+            ),
+        ];
+        if let Some(m) = last {
+            let last_cap = m.limits[i].map_or("—".to_string(), format_money);
+            spans.push(Span::styled(
+                format!("   last month {} of {}", format_money(m.spent[i]), last_cap),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+
+    // Last closed quarter
+    let (qy, q) = game.date.previous_quarter();
+    if let Some((income, expenses)) = company.quarter_financials(qy, q) {
+        let net = income - expenses;
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {}: income {}, expenses {}, net ",
+                crate::calendar::quarter_label(qy, q), format_money(income), format_money(expenses))),
+            Span::styled(format_money_signed(net),
+                Style::default().fg(if net >= 0.0 { Color::Green } else { Color::Red })),
+        ]));
+        lines.push(Line::from(""));
+    }
+
     // Monthly financials
     lines.push(Line::from(Span::styled(
         "  ── Monthly Financials ──",
@@ -1667,6 +2629,40 @@ fn draw_finance_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Styl
         }
     }
 
+    // Program P&L section
+    let programs: Vec<_> = company.program_profitability().into_iter()
+        .filter(|p| p.rockets_built > 0)
+        .collect();
+    if !programs.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  ── Program P&L ──",
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.push(Line::from("  Design            Revenue      Net          Margin/Flt   Flown  Break-even"));
+        lines.push(Line::from("  ─────────────────────────────────────────────────────────────────────────"));
+        for p in &programs {
+            let name = if p.rocket_name.len() > 18 {
+                format!("{}…", &p.rocket_name[..17])
+            } else {
+                p.rocket_name.clone()
+            };
+            let margin = p.per_flight_margin().map_or("—".to_string(), format_money_signed);
+            let break_even = match (p.per_flight_margin(), p.break_even_flights()) {
+                (None, _) => "—".to_string(),
+                (Some(_), None) => "never".to_string(),
+                (Some(_), Some(n)) => format!("{} flights", n),
+            };
+            let net = p.net();
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<18} {:>12} ", name, format_money(p.revenue))),
+                Span::styled(format!("{:>12}", format_money_signed(net)),
+                    Style::default().fg(if net >= 0.0 { Color::Green } else { Color::Red })),
+                Span::raw(format!(" {:>12} {:>6}  {}", margin, p.flights, break_even)),
+            ]));
+        }
+    }
+
     // Engine Costs section
     let any_engines = !company.engine_projects.is_empty()
         || !company.contracted_engines.is_empty();
@@ -1750,10 +2746,18 @@ fn draw_events_tab(frame: &mut Frame, app: &App, area: Rect, border_style: Style
         })
         .collect();
 
+    let rules = &app.game.pause_rules;
+    let mark = |on: bool| if on { "✓" } else { "·" };
+    let money = rules.money_below
+        .map_or("off".to_string(), |floor| format!("< {}", format_money(floor)));
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(" Events ");
+        .title(format!(
+            " Events — pause on: [A]rrivals {} [F]laws {} [O]rders {} [M]oney {} ",
+            mark(rules.flight_arrived), mark(rules.flaw_discovered),
+            mark(rules.order_complete), money,
+        ));
     let list = List::new(items).block(block);
     frame.render_widget(list, area);
 }
@@ -1784,7 +2788,7 @@ fn draw_help_bar(frame: &mut Frame, app: &App, area: Rect) {
     } else if !matches!(app.input_mode, InputMode::Normal) {
         " [Enter] Confirm  [Esc] Cancel  [↑↓] Select ".to_string()
     } else {
-        " [Space] Pause/Unpause  [1-3] Speed  [←→] Pane  [↑↓] Select  [S] Save  [Q] Quit ".to_string()
+        " [Space] Pause/Unpause  [1-3] Speed  [4] Skip month  [←→] Pane  [↑↓] Select  [S] Save  [?] Codex  [Q] Quit ".to_string()
     };
     let style = if app.status_message.is_some() {
         Style::default().fg(Color::Green)
@@ -1809,13 +2813,24 @@ fn draw_rocket_designer_full(frame: &mut Frame, app: &App, state: &RocketDesigne
         ])
         .split(area);
 
-    draw_rocket_designer_content(frame, app, state, outer[0]);
+    // A to-scale profile beside the stats once there's a stage to draw.
+    match app.design_preview.current() {
+        Some(preview) if !preview.silhouette.segments.is_empty() => {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(40), Constraint::Length(20)])
+                .split(outer[0]);
+            draw_rocket_designer_content(frame, app, state, columns[0]);
+            draw_silhouette(frame, &preview.silhouette, columns[1]);
+        }
+        _ => draw_rocket_designer_content(frame, app, state, outer[0]),
+    }
 
     // Help bar for designer
     let help_text = if let Some(ref msg) = app.status_message {
         format!(" {} ", msg)
     } else {
-        " [Enter] Edit  [←→] Engines  [+/-] Prop  [A] Add  [I] Ins  [B] Booster  [W] Power  [X] Rem  [P] Payload  [L] Site  [M] Mission  [D] Done  [Esc] Cancel ".to_string()
+        " [Enter] Edit  [←→] Engines  [+/-] Prop  [A] Add  [I] Ins  [B] Booster  [W] Power  [X] Rem  [P] Payload  [L] Site  [M] Mission  [F] Crossfeed  [H] Hot staging  [K] Coast kit  [G] Landing kit  [T] Abort tower  [V] Fairing  [R] Reserve  [D] Done  [Esc] Cancel ".to_string()
     };
     let style = if app.status_message.is_some() {
         Style::default().fg(Color::Green)
@@ -1827,6 +2842,35 @@ fn draw_rocket_designer_full(frame: &mut Frame, app: &App, state: &RocketDesigne
     frame.render_widget(paragraph, outer[1]);
 }
 
+/// The designer's rocket outline, filled cell by cell. Terminal cells
+/// are about twice as tall as wide, so a cell covers twice the meters
+/// down as across.
+fn draw_silhouette(frame: &mut Frame, silhouette: &crate::silhouette::Silhouette, area: Rect) {
+    let cols = area.width.saturating_sub(2) as usize;
+    let rows = area.height.saturating_sub(3) as usize;
+    if cols == 0 || rows == 0 {
+        return;
+    }
+    let m_per_col = (silhouette.width_m / cols as f64).max(silhouette.height_m / (2 * rows) as f64);
+    let mut lines: Vec<Line> = (0..rows).map(|row| {
+        let y = (rows - row) as f64 * 2.0 * m_per_col - m_per_col;
+        let text: String = (0..cols).map(|col| {
+            let x = (col as f64 + 0.5 - cols as f64 / 2.0) * m_per_col;
+            if silhouette.covers(x, y) { '█' } else { ' ' }
+        }).collect();
+        Line::from(text)
+    }).collect();
+    lines.push(Line::from(Span::styled(
+        format!("{:.0} × {:.1} m", silhouette.height_m, silhouette.width_m),
+        Style::default().fg(Color::DarkGray),
+    )));
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Profile ")
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesignerState, area: Rect) {
     let mut lines = Vec::new();
 
@@ -1838,16 +2882,12 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
 
     lines.push(Line::from(""));
     lines.push(Line::from(format!(
-        "  Launch: {}    Payload: {:.0} kg",
-        launch_display, state.payload_kg,
+        "  Launch: {}    Payload: {:.0} kg    Reserve: {:.0}%",
+        launch_display, state.payload_kg, state.performance_reserve * 100.0,
     )));
 
     // Build a temporary RocketDesign to compute stats
-    let temp_design = rocket::RocketDesign {
-        id: rocket::RocketDesignId(0),
-        name: state.rocket_name.clone(),
-        stage_groups: state.stage_groups.clone(),
-    };
+    let temp_design = state.preview_design();
 
     // Mission line: required dv / available dv / margin / ETA. Required
     // dv and the route are derived from the stage-aware path planner so
@@ -1881,8 +2921,15 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
                     launch_display, destination_display),
                 Style::default().fg(Color::Red),
             )),
+            crate::path_planning::MissionPlan::CoastTooLong { max_coast_days, required_days } => Line::from(Span::styled(
+                format!("  Mission: {} → {}    UNREACHABLE — {}-day coast, final stage survives {} (fit a long-coast kit)",
+                    launch_display, destination_display, required_days, max_coast_days),
+                Style::default().fg(Color::Red),
+            )),
             crate::path_planning::MissionPlan::Reachable { path, dv: required_dv } => {
-                let available_dv = temp_design.total_delta_v(state.payload_kg);
+                // Plan against usable Δv — the reserve is held back for
+                // launch dispersions.
+                let available_dv = temp_design.usable_delta_v(state.payload_kg);
                 let margin = available_dv - required_dv;
                 let eta_days: u32 = path.windows(2)
                     .filter_map(|w| DELTA_V_MAP.transfer(w[0], w[1]))
@@ -1949,7 +2996,25 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
                 }
                 _ => "",
             };
-            let engine_label = format!("{}{}", stage.engine.name, tag);
+            let feed_tag = match (stage.crossfeed, stage.engine_count) {
+                (false, _) => "",
+                (true, 0) => "[drop]",
+                (true, _) => "[XF]",
+            };
+            let kit_tag = match (stage.long_coast_kit, stage.landing_kit) {
+                (true, true) => "[LC][LK]",
+                (true, false) => "[LC]",
+                (false, true) => "[LK]",
+                (false, false) => "",
+            };
+            let las_tag = if stage.launch_abort_system { "[LAS]" } else { "" };
+            let hot_tag = if gi > 0 && si == 0 && stage.staging == crate::stage::StagingMode::Hot { "[HOT]" } else { "" };
+            let fairing_tag = match &stage.fairing {
+                Some(f) if f.recover => "[FR]",
+                Some(_) => "[F]",
+                None => "",
+            };
+            let engine_label = format!("{}{}{}{}{}{}{}", stage.engine.name, tag, feed_tag, kit_tag, las_tag, hot_tag, fairing_tag);
 
             // Compute burn time: propellant_mass / (mass_flow_rate * engine_count)
             let burn_str = if stage.engine.is_solar_sail() {
@@ -2018,6 +3083,15 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
                 style,
             )));
 
+            if stage.crossfeed && si > 0 {
+                if let Some(problem) = stage.crossfeed_problem(&group[0]) {
+                    lines.push(Line::from(Span::styled(
+                        format!("       ⚠ Crossfeed invalid: {}", problem),
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+            }
+
             // Per-stage power summary (compact)
             if !stage.power_sources.is_empty() {
                 let supply: f64 = stage.power_sources.iter()
@@ -2119,6 +3193,12 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
             "  Total mass: {}",
             format_mass(total_mass),
         )));
+        if let Some(preview) = app.design_preview.current() {
+            lines.push(Line::from(format!(
+                "  Stack: {:.1} m tall, {:.1} m wide",
+                preview.silhouette.height_m, preview.max_diameter_m,
+            )));
+        }
         // Initial acceleration: stage 0 firing at 1 AU with all stages
         // attached and full propellant. Captures the power derate so
         // ion designs read low.
@@ -2130,6 +3210,74 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
             format_accel(initial_accel),
         )));
 
+        // Structural loads at the designer's payload / launch site. Past
+        // the ratings the stack risks breaking up on ascent.
+        let loads = temp_design.structural_loads(state.payload_kg, state.launch_from);
+        if loads.max_q_pa > 0.0 || loads.peak_unthrottled_accel_g > 0.0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  Max-Q: {:.0} kPa    Unthrottled burnout: {:.1} g",
+                    loads.max_q_pa / 1000.0, loads.peak_unthrottled_accel_g,
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for problem in loads.violations() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  ▲ {} ({:.0}% breakup risk)",
+                    problem, loads.failure_risk() * 100.0,
+                ),
+                Style::default().fg(Color::Red),
+            )));
+        }
+
+        // Dev builds: flag delta-v model regressions on whatever is
+        // on the bench.
+        if cfg!(debug_assertions) {
+            for broken in temp_design.validate_invariants() {
+                lines.push(Line::from(Span::styled(
+                    format!("  [dev] invariant broken: {}", broken),
+                    Style::default().fg(Color::Magenta),
+                )));
+            }
+        }
+
+        // Known flaw risk by subsystem: discovered, unfixed engine flaws
+        // (and the project's own, when modifying). Hidden flaws stay hidden.
+        let project = match state.mode {
+            crate::ui::DesignerMode::Modify { project_id } => app.game.player_company
+                .rocket_projects.iter()
+                .find(|rp| rp.project_id == project_id),
+            crate::ui::DesignerMode::New => None,
+        };
+        let rocket_flaws: &[crate::flaw::Flaw] = project.map_or(&[], |rp| rp.flaws.as_slice());
+        let risks = app.game.player_company.known_flaw_risk(&temp_design, rocket_flaws);
+        if !risks.is_empty() {
+            let parts: Vec<String> = risks.iter()
+                .map(|(sub, p)| format!("{} {:.1}%", sub, p * 100.0))
+                .collect();
+            lines.push(Line::from(Span::styled(
+                format!("  Known flaw risk: {}", parts.join("  ")),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+
+        // Estimated risk by launch event, hidden flaws included: where
+        // this rocket is most likely to fail, and how sure we are.
+        let estimates = app.game.player_company.estimated_flaw_risk(&temp_design, project, &app.game.balance);
+        let parts: Vec<String> = estimates.iter()
+            .filter(|r| r.risk >= 0.001)
+            .take(4)
+            .map(|r| format!("{} {:.1}% ({:.0}% sure)", r.event(), r.risk * 100.0, r.confidence * 100.0))
+            .collect();
+        if !parts.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  Estimated risk: {}", parts.join("  ")),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
         // Electrical summary. Read-only for now; editing UI is a follow-up.
         // Compute supply at takeoff (1 AU) and housekeeping demand across
         // attached stages; show whether designs balance.
@@ -2204,10 +3352,55 @@ fn draw_rocket_designer_content(frame: &mut Frame, app: &App, state: &RocketDesi
     frame.render_widget(paragraph, area);
 }
 
-// ==========================================
-// Modal overlays (engine design flow + rocket sub-modals)
-// ==========================================
-
+// ==========================================
+// Modal overlays (engine design flow + rocket sub-modals)
+// ==========================================
+
+/// Launch-day countdown: the clock, where the count stands, and the
+/// latest calls.
+fn draw_countdown_modal(frame: &mut Frame, app: &App, area: Rect) {
+    use crate::countdown::CountdownPhase;
+
+    let Some(cd) = &app.game.countdown else { return };
+    let (phase, phase_style) = match &cd.phase {
+        CountdownPhase::Tanking => ("Tanking".to_string(), Style::default().fg(Color::Cyan)),
+        CountdownPhase::Terminal => ("Terminal count".to_string(), Style::default().fg(Color::Green)),
+        CountdownPhase::Hold(reason) => (format!("HOLD — {}", reason), Style::default().fg(Color::Red)),
+        CountdownPhase::Liftoff => ("Liftoff".to_string(), Style::default().fg(Color::Green)),
+        CountdownPhase::Scrubbed { reason } => (format!("Scrubbed — {}", reason), Style::default().fg(Color::Red)),
+    };
+    let window = app.game.balance.countdown.window_min;
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(format!(
+            "  {} → {}",
+            cd.rocket_name, contract::destination_display_name(&cd.destination),
+        )),
+        Line::from(Span::styled(
+            format!("  T-{:02}:{:02}:00", cd.t_minus_min / 60, cd.t_minus_min % 60),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(format!("  {}", phase), phase_style)),
+        Line::from(format!("  Window: {} of {} min left", window.saturating_sub(cd.elapsed_min), window)),
+        Line::from(""),
+    ];
+    let visible = (area.height as usize).saturating_sub(lines.len() + 5);
+    for (t_minus, signal) in cd.log.iter().rev().take(visible).rev() {
+        lines.push(Line::from(format!("  T-{:>3}  {}", t_minus, signal)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  [H] hold  [R] resume  [C] recycle  [F] +10 min  [X/Esc] scrub",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Launch Countdown ")
+        .style(Style::default().fg(Color::Yellow));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
     let modal_area = centered_rect(60, 50, area);
     frame.render_widget(Clear, modal_area);
@@ -2401,6 +3594,392 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
             let paragraph = Paragraph::new(lines).block(block);
             frame.render_widget(paragraph, modal_area);
         }
+        InputMode::MarketReport { report, scroll } => {
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!(
+                    "  Customer payloads and prices now and in {} years, against your best",
+                    report.years_ahead,
+                )),
+                Line::from("  flight-ready design (↑/↓ scroll, Esc closes):"),
+                Line::from(""),
+                Line::from(format!(
+                    "  World prices: launches {:+.0}%  propellant {:+.0}%  vendor engines {:+.0}%  customers {:+.0}%",
+                    (report.world.launch - 1.0) * 100.0, (report.world.propellant - 1.0) * 100.0,
+                    (report.world.engines - 1.0) * 100.0, (report.world.demand - 1.0) * 100.0,
+                )),
+                Line::from(""),
+            ];
+            let behind = report.falling_behind().count();
+            if behind > 0 {
+                lines.push(Line::from(Span::styled(
+                    format!("  Your fleet is falling behind {} destination{}", behind, if behind == 1 { "" } else { "s" }),
+                    Style::default().fg(Color::Red),
+                )));
+                lines.push(Line::from(""));
+            }
+            let visible = (modal_area.height as usize).saturating_sub(lines.len() + 2);
+            for o in report.outlooks.iter().skip(*scroll).take(visible.max(1)) {
+                let market: String = o.market_name.chars().take(18).collect();
+                let (now, then) = (o.covered_now(), o.covered_then());
+                let color = if then < now {
+                    Color::Red
+                } else if now == 0.0 {
+                    Color::DarkGray
+                } else {
+                    Color::Green
+                };
+                let demand = if o.demand.is_saturated() {
+                    format!("saturated, prices {:+.0}%", (o.demand.rate_mult - 1.0) * 100.0)
+                } else if o.demand.rate_mult > 1.0 {
+                    format!("underserved, prices {:+.0}%", (o.demand.rate_mult - 1.0) * 100.0)
+                } else {
+                    "balanced".to_string()
+                };
+                lines.push(Line::from(format!(
+                    "  {:<18} →{:<10} {:>6.0}-{:<6.0} kg → {:>6.0}-{:<6.0} kg  ${:.0}/kg → ${:.0}/kg  fly {:.0}% → {:.0}%  {}",
+                    market, o.display_name,
+                    o.payload_now_kg.0, o.payload_now_kg.1, o.payload_then_kg.0, o.payload_then_kg.1,
+                    o.rate_per_kg_now, o.rate_per_kg_then, now * 100.0, then * 100.0, demand,
+                )).style(Style::default().fg(color)));
+            }
+            if report.outlooks.is_empty() {
+                lines.push(Line::from("  (no active markets)"));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Market Outlook ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::Codex { codex, section, scroll } => {
+            let tabs: Vec<Span> = CODEX_SECTIONS.iter().enumerate().flat_map(|(i, name)| {
+                let style = if i == *section {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                [Span::raw("  "), Span::styled(*name, style)]
+            }).collect();
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(tabs),
+                Line::from("  (←/→ section, ↑/↓ scroll, Esc closes)").style(Style::default().fg(Color::DarkGray)),
+                Line::from(""),
+            ];
+            let visible = (modal_area.height as usize).saturating_sub(lines.len() + 2).max(1);
+            lines.extend(codex_lines(codex, *section).into_iter().skip(*scroll).take(visible).map(Line::from));
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Codex ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::Library { library, selected } => {
+            let import_hint = if app.game.balance.sandbox.enabled { "  [I] Import rocket (sandbox)" } else { "" };
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!("  Designs archived across all your games (↑/↓ select, [X] remove{}, Esc closes):", import_hint)),
+                Line::from(""),
+            ];
+            let visible = (modal_area.height as usize).saturating_sub(lines.len() + 4).max(1);
+            let skip = selected.saturating_sub(visible - 1);
+            for (i, entry) in library.entries.iter().enumerate().skip(skip).take(visible) {
+                let style = if i == *selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(Span::styled(format!("  {}", entry.summary()), style)));
+            }
+            if let Some(entry) = library.entries.get(*selected) {
+                lines.push(Line::from(""));
+                lines.push(Line::from(format!(
+                    "  Archived {}: {} known flaws open, {:.0} testing work",
+                    entry.archived_on, entry.known_flaws, entry.testing_work,
+                )));
+            } else {
+                lines.push(Line::from("  (empty — [Shift+L] on the Engines or Rockets tab archives a design)"));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Company Library ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::DepotDesigns { selected } => {
+            use crate::depot_project::DepotDesignStatus;
+            let company = &app.game.player_company;
+            let cfg = &app.game.balance.depot_design;
+            let mut lines = vec![
+                Line::from(""),
+                Line::from("  [N] New  [E] Edit  [+/-] Teams  [R] Revise  [Enter] Launch on selected rocket  [Esc] Close"),
+                Line::from(""),
+            ];
+            for (i, p) in company.depot_projects.iter().enumerate() {
+                let status = match &p.status {
+                    DepotDesignStatus::Proposed => "draft".to_string(),
+                    DepotDesignStatus::InDesign { work_completed, work_required } =>
+                        format!("design {:.0}%", 100.0 * work_completed / work_required.max(1.0)),
+                    DepotDesignStatus::Testing { .. } => format!("testing, {} flaw(s) found", p.discovered_flaw_count()),
+                    DepotDesignStatus::Revising { remaining_indices, .. } =>
+                        format!("revising {} flaw(s)", remaining_indices.len()),
+                };
+                let style = if i == *selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "  {} {:<16} {:<26} {} team(s)  {}  {}  {} flown",
+                        if i == *selected { "▶" } else { " " },
+                        p.design.name, status, p.teams_assigned,
+                        format_kg(p.design.mass_kg(cfg)), format_money(p.design.unit_cost(cfg)), p.launched,
+                    ),
+                    style,
+                )));
+            }
+            if company.depot_projects.is_empty() {
+                lines.push(Line::from("  (no depot designs yet)"));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Depot Designs ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::DepotEditor { project_id, cursor } => {
+            let Some(p) = app.game.player_company.find_depot_project(*project_id) else { return };
+            let cfg = &app.game.balance.depot_design;
+            let isru = &app.game.balance.isru;
+            let d = &p.design;
+            let rows = [
+                ("Capacity", format_kg(d.capacity_kg)),
+                ("Insulation", format!("{:.0}% of heat leak blocked", 100.0 * d.insulation)),
+                ("Docking ports", d.docking_ports.to_string()),
+                ("Solar arrays", format!("{:.0} kW at 1 AU", d.solar_array_kw_at_1au)),
+                ("Cryocoolers", format!("{:.1} kW lift", d.cryocooler_kw)),
+            ];
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!("  {}  (↑/↓ spec, ←/→ change, D done, Esc cancel)", d.name)),
+                Line::from(""),
+            ];
+            for (i, (label, value)) in rows.iter().enumerate() {
+                let style = if i == *cursor {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                lines.push(Line::from(Span::styled(
+                    format!("  {} {:<14} {}", if i == *cursor { "▶" } else { " " }, label, value),
+                    style,
+                )));
+            }
+            // Full of cryogenics at 1 AU with nothing else drawing power.
+            let budget = d.budget(1.0, d.capacity_kg, 0.0, isru);
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "  Mass {}   Unit cost {}   Design work {:.0}",
+                format_kg(d.mass_kg(cfg)), format_money(d.unit_cost(cfg)),
+                crate::depot_project::depot_design_work_required(d, cfg),
+            )));
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  Full at 1 AU: cooling {:.1}/{:.0} kW, {:.0}% held, boiloff {:.2}%/day",
+                    budget.cooling_demand_kw, budget.supply_kw,
+                    100.0 * budget.cooling_fraction, 100.0 * budget.boiloff_per_day,
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Depot Designer ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::TeamAllocation { allocation } => {
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!(
+                    "  {} free teams at {:.0}% efficiency  ({}Esc closes)",
+                    allocation.teams, allocation.efficiency * 100.0,
+                    if allocation.changes() { "Enter applies, " } else { "" },
+                )),
+                Line::from(""),
+            ];
+            for s in &allocation.slots {
+                let style = match s.suggested.cmp(&s.current) {
+                    std::cmp::Ordering::Greater => Style::default().fg(Color::Green),
+                    std::cmp::Ordering::Less => Style::default().fg(Color::Red),
+                    std::cmp::Ordering::Equal => Style::default(),
+                };
+                lines.push(Line::from(Span::styled(
+                    format!("  {:<24} {:<9} ×{:.1}  {:>2} → {:>2}", s.name, s.phase, s.weight, s.current, s.suggested),
+                    style,
+                )));
+            }
+            if allocation.slots.is_empty() {
+                lines.push(Line::from("  (no designs in progress)"));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "  Weighted work/day: {:.2} now, {:.2} suggested",
+                allocation.current_value(), allocation.suggested_value(),
+            )));
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Team Allocation ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::CapacityPlanner { project_index, rockets_per_month } => {
+            let company = &app.game.player_company;
+            let Some(plan) = company.capacity_plan(*project_index, *rockets_per_month, &app.game.balance) else { return };
+            let gap_style = |gap: u32| Style::default().fg(if gap > 0 { Color::Red } else { Color::Green });
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!(
+                    "  {}  at {:.2} a month  (↑/↓ design, ←/→ cadence, Esc closes)",
+                    plan.rocket_name, plan.rockets_per_month,
+                )),
+                Line::from(""),
+                Line::from(format!(
+                    "  Work per vehicle: {:.0} team-days   Order to rollout: {:.0} days",
+                    plan.work_per_rocket, plan.lead_time_days,
+                )),
+                Line::from(format!(
+                    "  Mfg teams:   need {:>3}  have {:>3}",
+                    plan.teams_needed, plan.teams_have,
+                )).style(gap_style(plan.team_gap())),
+                Line::from(format!(
+                    "  Floor space: need {:>3}  have {:>3}",
+                    plan.floor_space_needed, plan.floor_space_have,
+                )).style(gap_style(plan.floor_space_gap())),
+                Line::from(format!(
+                    "  Materials: {} a month", format_money(plan.material_cost_per_month),
+                )),
+                Line::from(""),
+                Line::from("  Engine production:"),
+            ];
+            for line in &plan.engine_lines {
+                let source = if line.bought_in {
+                    "bought in".to_string()
+                } else {
+                    format!("{:.0} team-days each", line.work_days_each)
+                };
+                lines.push(Line::from(format!(
+                    "    {:<24} {:>5.1} a month  {}", line.engine_name, line.per_month, source,
+                )));
+            }
+            lines.push(Line::from(""));
+            if plan.is_covered() {
+                lines.push(Line::from("  The factory can sustain this cadence.")
+                    .style(Style::default().fg(Color::Green)));
+            } else {
+                lines.push(Line::from(format!(
+                    "  Short {} team{} and {} floor space: {} to expand, +{} a month in salary",
+                    plan.team_gap(), if plan.team_gap() == 1 { "" } else { "s" }, plan.floor_space_gap(),
+                    format_money(plan.expansion_cost), format_money(plan.extra_monthly_salary),
+                )).style(Style::default().fg(Color::Red)));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Capacity Planner ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::Subcontract { contract_index, selected } => {
+            let Some(c) = app.game.player_company.active_contracts.get(*contract_index) else { return };
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!(
+                    "  {}  →{}  {:.0} kg  {}  by {}",
+                    c.name, contract::destination_display_name(&c.destination),
+                    c.payload_kg, format_money(c.payment), c.deadline,
+                )),
+                Line::from("  The provider flies it for a fee; the customer still pays you."),
+                Line::from("  (↑/↓ select, Enter books, Esc closes)"),
+                Line::from(""),
+            ];
+            for (i, p) in app.game.launch_providers.iter().enumerate() {
+                let marker = if i == *selected { "▶ " } else { "  " };
+                let launch = app.game.date.add_days(p.lead_days);
+                let status = if !p.serves(&c.destination) {
+                    "doesn't fly there".to_string()
+                } else if p.slots_left() == 0 {
+                    "fully booked".to_string()
+                } else if launch > c.deadline {
+                    format!("can't launch before {}", launch)
+                } else {
+                    format!("fee {}  launches {}", format_money(p.quote(c)), launch)
+                };
+                let available = status.starts_with("fee");
+                lines.push(Line::from(format!(
+                    "  {marker}{:<24} {} slot{} left  {}",
+                    p.name, p.slots_left(), if p.slots_left() == 1 { "" } else { "s" }, status,
+                )).style(Style::default().fg(if available { Color::Green } else { Color::DarkGray })));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Subcontract Launch ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
+        InputMode::CommitCriteria { project_index, selected } => {
+            use crate::commit_criteria::Criterion;
+
+            let Some(rp) = app.game.player_company.rocket_projects.get(*project_index) else { return };
+            let criteria = &rp.commit_criteria;
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(format!("  {}: checked before every launch", rp.design.name)),
+                Line::from(""),
+            ];
+            for (i, c) in Criterion::ALL.iter().enumerate() {
+                let limit = match c {
+                    Criterion::EngineTesting => (criteria.min_engine_testing_cycles > 0)
+                        .then(|| format!("engines ≥ {} test cycles", criteria.min_engine_testing_cycles)),
+                    Criterion::HiddenFlaws => criteria.max_hidden_flaws
+                        .map(|m| format!("≤ {:.1} estimated unfound flaws", m)),
+                    Criterion::Weather => criteria.max_weather_no_go
+                        .map(|m| format!("forecast no-go ≤ {:.0}%", m * 100.0)),
+                    Criterion::FlightReserve => criteria.min_flight_reserve
+                        .map(|m| format!("≥ {:.0}% delta-v left after the mission", m * 100.0)),
+                };
+                let marker = if i == *selected { " ▶ " } else { "   " };
+                let style = if limit.is_some() { Style::default().fg(Color::White) } else { Style::default().fg(Color::DarkGray) };
+                lines.push(Line::from(Span::styled(
+                    format!("{}{:<16} {}", marker, c.to_string(), limit.unwrap_or_else(|| "off".into())),
+                    style,
+                )));
+            }
+            let marker = if *selected == Criterion::ALL.len() { " ▶ " } else { "   " };
+            lines.push(Line::from(format!(
+                "{}{:<16} {}",
+                marker, "On a no-go",
+                if criteria.enforced { "refuse the launch" } else { "warn only" },
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "  [↑/↓] select  [Enter] on/off  [←/→] adjust  [Esc] close",
+                Style::default().fg(Color::DarkGray),
+            )));
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Launch Commit Criteria ")
+                .style(Style::default().fg(Color::Yellow));
+            frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+        }
         InputMode::Campaigns { selected } => {
             let mut lines = vec![
                 Line::from(""),
@@ -2460,6 +4039,48 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
             let paragraph = Paragraph::new(lines).block(block);
             frame.render_widget(paragraph, modal_area);
         }
+        InputMode::GovPrograms { selected } => {
+            let mut lines = vec![
+                Line::from(""),
+                Line::from("  Government programs pay a monthly subsidy for meeting"),
+                Line::from("  capability milestones on time; a missed deadline claws"),
+                Line::from("  the subsidy back. Enter/C commits, ↑/↓ select, Esc closes."),
+                Line::from(""),
+            ];
+            for (i, p) in app.game.gov_programs.iter().enumerate() {
+                let marker = if i == *selected { "▶ " } else { "  " };
+                let (status, color) = match &p.status {
+                    ProgramStatus::Announced { respond_by } =>
+                        (format!("open — commit by {}", respond_by), Color::Yellow),
+                    ProgramStatus::Committed => ("committed".to_string(), Color::Cyan),
+                    ProgramStatus::Completed => ("complete".to_string(), Color::Green),
+                    ProgramStatus::Failed => ("failed".to_string(), Color::Red),
+                };
+                lines.push(Line::from(format!(
+                    "  {marker}{:<32} {}/mo  {}/{} months paid  {}",
+                    p.name, format_money(p.monthly_subsidy), p.months_paid, p.term_months, status,
+                )).style(Style::default().fg(color)));
+                for m in &p.milestones {
+                    let state = match m.met_on {
+                        Some(date) => format!("met {}", date),
+                        None => format!("due {}", m.deadline),
+                    };
+                    lines.push(Line::from(format!(
+                        "        {:>6.0} kg →{:<18} {}",
+                        m.payload_kg, contract::destination_display_name(&m.destination), state,
+                    )));
+                }
+            }
+            if app.game.gov_programs.is_empty() {
+                lines.push(Line::from("  (no programs announced yet — announcements appear in Events)"));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(" Government Programs ")
+                .style(Style::default().fg(Color::Yellow));
+            let paragraph = Paragraph::new(lines).block(block);
+            frame.render_widget(paragraph, modal_area);
+        }
         InputMode::CampaignBidEntry { campaign_id, buffer, .. } => {
             let (name, missions) = app.game.active_campaigns.iter()
                 .find(|c| c.id == *campaign_id)
@@ -2565,6 +4186,27 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
                 Line::from(format!("  Payload mass: {}", format_mass(payload_mass))),
                 Line::from(""),
             ];
+            if let Some(checklist) = app.game
+                .launch_checklist(*rocket_item_id, &destination_for_summary, payload_mass)
+                .filter(|c| !c.checks.is_empty())
+            {
+                lines.push(Line::from(Span::styled(
+                    if checklist.enforced { "  ── Commit Criteria (enforced) ──" } else { "  ── Commit Criteria ──" },
+                    Style::default().fg(Color::DarkGray),
+                )));
+                for check in &checklist.checks {
+                    let (mark, style) = if check.pass {
+                        ("GO   ", Style::default().fg(Color::Green))
+                    } else {
+                        ("NO-GO", Style::default().fg(Color::Red))
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("   {} ", mark), style),
+                        Span::raw(format!("{}: {}", check.criterion, check.detail)),
+                    ]));
+                }
+                lines.push(Line::from(""));
+            }
 
             let mut row = 0usize;
 
@@ -2579,12 +4221,15 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
                     let dest_name = contract::destination_display_name(&c.destination);
                     let style = if *cursor == row {
                         Style::default().fg(Color::Yellow)
+                    } else if !app.game.player_company.customer_payloads.is_ready(c.id, app.game.date) {
+                        Style::default().fg(Color::DarkGray)
                     } else {
                         Style::default()
                     };
                     lines.push(Line::from(Span::styled(
-                        format!("{}{} {} → {} ({:.0} kg, {})",
-                            mark, check, c.name, dest_name, c.payload_kg, format_money(c.payment)),
+                        format!("{}{} {} → {} ({:.0} kg, {}){}",
+                            mark, check, c.name, dest_name, c.payload_kg, format_money(c.payment),
+                            payload_tag(c, &app.game)),
                         style,
                     )));
                     row += 1;
@@ -2627,7 +4272,7 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
             }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "  [Space] toggle  [Enter] launch  [Esc] cancel",
+                "  [Space] toggle  [Enter] launch  [O] launch-day countdown  [Esc] cancel",
                 Style::default().fg(Color::DarkGray),
             )));
 
@@ -2638,6 +4283,9 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
             let paragraph = Paragraph::new(lines).block(block);
             frame.render_widget(paragraph, modal_area);
         }
+        InputMode::Countdown => {
+            draw_countdown_modal(frame, app, modal_area);
+        }
         InputMode::LaunchResult { record } => {
             let mut lines = vec![
                 Line::from(""),
@@ -2671,8 +4319,9 @@ fn draw_modal(frame: &mut Frame, app: &App, area: Rect) {
                     Style::default().fg(Color::Red),
                 )));
                 for flaw in &record.flaws_activated {
-                    lines.push(Line::from(format!("    {} ({}): {}",
-                        flaw.engine_name, flaw.consequence, flaw.flaw_description)));
+                    lines.push(Line::from(format!("    {} at {} ({}): {}",
+                        flaw.engine_name, flaw.subsystem.launch_event(), flaw.consequence,
+                        flaw.flaw_description)));
                 }
                 lines.push(Line::from(""));
             }
@@ -3179,6 +4828,22 @@ fn draw_engine_editor_modal(
         " Complexity: {}    Work: {:.0} / {:.0}",
         ep.complexity, work_completed, work_required,
     )));
+    let instability = &app.game.balance.combustion_instability;
+    let risk = crate::engine_project::instability_risk(ep.design.cycle, ep.scale, &app.game.balance);
+    if risk > 0.0 {
+        lines.push(Line::from(Span::styled(
+            format!(" Past {:.1}× the chamber turns unstable: +{:.0}% work, ~{:.1} more flaws",
+                instability.onset_scale, risk * instability.work_per_risk * 100.0,
+                risk * instability.flaws_per_risk),
+            Style::default().fg(Color::LightRed),
+        )));
+    }
+    if let Some(issue) = &ep.fundamental_issue {
+        lines.push(Line::from(Span::styled(
+            format!(" {} — scale to {:.1}× or change cycle", issue.description, instability.onset_scale),
+            Style::default().fg(Color::Red),
+        )));
+    }
 
     lines.push(Line::from(""));
     let footer = if standalone {
@@ -3246,7 +4911,8 @@ fn draw_reactor_editor_modal(
     // reputation, mark the current pick, and annotate the row's tail
     // with the next reputation gate so the player sees what they're
     // working toward.
-    let reputation = app.game.player_company.reputation.total();
+    let reputation = app.game.player_company.reputation
+        .segment(ReputationSegment::Government, &app.game.balance.reputation);
     let enrichment_segments: Vec<String> = EnrichmentLevel::ALL.iter().map(|lvl| {
         let mark_l = if *lvl == rp.design.enrichment { "[" } else { " " };
         let mark_r = if *lvl == rp.design.enrichment { "]" } else { " " };
@@ -3254,7 +4920,7 @@ fn draw_reactor_editor_modal(
     }).collect();
     let next_gate_hint = EnrichmentLevel::ALL.iter()
         .find(|lvl| !lvl.available_at(reputation, &app.game.balance.reputation))
-        .map(|lvl| format!("(next: {} at {:.0} rep, you have {:.0})",
+        .map(|lvl| format!("(next: {} at {:.0} gov't rep, you have {:.0})",
             lvl.display_name(), lvl.min_reputation(&app.game.balance.reputation), reputation))
         .unwrap_or_else(|| "(all enrichments unlocked)".into());
     let enrichment_row = format!(
@@ -3465,6 +5131,17 @@ fn format_accel(a_m_s2: f64) -> String {
     }
 }
 
+/// One block character per value, scaled between the series' min and max.
+fn sparkline(values: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let span = (max - min).max(1.0);
+    values.iter()
+        .map(|v| BARS[(((v - min) / span) * 7.0).round() as usize])
+        .collect()
+}
+
 fn format_money_signed(amount: f64) -> String {
     if amount >= 0.0 {
         format!("+{}", format_money(amount))
@@ -3473,6 +5150,49 @@ fn format_money_signed(amount: f64) -> String {
     }
 }
 
+/// The codex's sections, in the order ←/→ steps through them.
+pub const CODEX_SECTIONS: [&str; 4] = ["Engine cycles", "Destinations", "Flaws", "Formulas"];
+
+/// One codex section as text lines.
+pub fn codex_lines(codex: &crate::codex::Codex, section: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    match section {
+        0 => for c in &codex.cycles {
+            lines.push(format!("  {} (complexity {})", c.name, c.complexity));
+            lines.push(format!("    {}", c.summary));
+            for v in &c.variants {
+                let isp_sl = if v.vacuum_only { "vacuum only".to_string() } else { format!("{:.0} s SL", v.isp_sl_s) };
+                lines.push(format!("    {:<10} {:>9.1} kN  {:>5.0} s vac  {:<11}  {:>7.0} kg  TWR {:.0}",
+                    v.preset.name(), v.thrust_n / 1000.0, v.isp_vac_s, isp_sl, v.mass_kg, v.twr));
+            }
+            lines.push(String::new());
+        },
+        1 => {
+            lines.push(format!("  From Earth's surface; drag for a {:.0} t rocket.", crate::codex::REFERENCE_LIFTOFF_KG / 1000.0));
+            lines.push(String::new());
+            for d in &codex.destinations {
+                let route: Vec<&str> = d.route.iter()
+                    .map(|id| crate::location::DELTA_V_MAP.location(id).map_or(*id, |l| l.short_name))
+                    .collect();
+                lines.push(format!("  {:<22} {:>6.0} m/s  {:>4} days  {}", d.name, d.delta_v, d.transit_days, route.join(" → ")));
+            }
+        }
+        2 => for f in &codex.flaw_categories {
+            lines.push(format!("  {} — bites at {}, fixes take {:.1}× the usual work", f.name, f.launch_event, f.fix_work_factor));
+            lines.push(format!("    {}", f.description));
+            lines.push(format!("    Near miss: {}", f.symptom));
+            lines.push(String::new());
+        },
+        _ => for f in &codex.formulas {
+            lines.push(format!("  {}:  {}", f.name, f.expression));
+            lines.push(format!("    {}", f.explanation));
+            lines.push(format!("    e.g. {}", f.example));
+            lines.push(String::new());
+        },
+    }
+    lines
+}
+
 pub fn format_money(amount: f64) -> String {
     crate::resources::format_money(amount)
 }
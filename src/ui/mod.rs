@@ -14,10 +14,12 @@ use ratatui::prelude::*;
 use crate::engine::{EngineCycle, EngineDesign};
 use crate::engine_project::{EngineDesignStatus, EngineSource, PropellantPreset};
 use crate::game_state::{GameSpeed, GameState};
+use crate::reputation::ReputationSegment;
 use crate::location::DELTA_V_MAP;
 use crate::rocket_project::RocketDesignStatus;
 use crate::save;
 use crate::stage::{Stage, StageId};
+use crate::team::TeamSize;
 use crate::structure;
 
 /// Which pane has keyboard focus.
@@ -113,6 +115,8 @@ pub struct RocketDesignerState {
     /// designer is cancelled, and to promote them to `InDesign` when
     /// the rocket is committed.
     pub created_engine_projects: Vec<crate::engine_project::EngineProjectId>,
+    /// Flight performance reserve carried onto the design.
+    pub performance_reserve: f64,
 }
 
 impl RocketDesignerState {
@@ -129,6 +133,7 @@ impl RocketDesignerState {
             launch_from: "earth_surface",
             destination: "leo",
             created_engine_projects: Vec::new(),
+            performance_reserve: crate::rocket::DEFAULT_PERFORMANCE_RESERVE,
         }
     }
 
@@ -162,9 +167,88 @@ impl RocketDesignerState {
             launch_from: "earth_surface",
             destination: "leo",
             created_engine_projects: Vec::new(),
+            // Legacy designs pick up the default reserve once modified.
+            performance_reserve: project.design.performance_reserve
+                .unwrap_or(crate::rocket::DEFAULT_PERFORMANCE_RESERVE),
         }
     }
 
+    /// The design as it stands, for stats and previews.
+    pub fn preview_design(&self) -> crate::rocket::RocketDesign {
+        crate::rocket::RocketDesign {
+            id: crate::rocket::RocketDesignId(0),
+            name: self.rocket_name.clone(),
+            stage_groups: self.stage_groups.clone(),
+            performance_reserve: Some(self.performance_reserve),
+        }
+    }
+
+    /// Step the performance reserve to the next preset, wrapping.
+    fn cycle_performance_reserve(&mut self) {
+        const PRESETS: [f64; 5] = [0.01, 0.02, 0.03, 0.05, 0.08];
+        let next = PRESETS.iter()
+            .position(|&p| p > self.performance_reserve + 1e-9)
+            .unwrap_or(0);
+        self.performance_reserve = PRESETS[next];
+    }
+
+    /// Toggle crossfeed on the selected booster. Returns the status
+    /// message to show, whether or not the toggle took.
+    fn toggle_crossfeed(&mut self) -> String {
+        let gi = self.selected_group;
+        let si = self.selected_inner;
+        let group = &mut self.stage_groups[gi];
+        if si == 0 {
+            return "Core stage can't crossfeed — select a booster".into();
+        }
+        if group[si].crossfeed {
+            if group[si].engine_count == 0 {
+                return "Drop tank needs crossfeed — add an engine first".into();
+            }
+            group[si].crossfeed = false;
+            return format!("{} crossfeed off", group[si].name);
+        }
+        if let Some(problem) = group[si].crossfeed_problem(&group[0]) {
+            return format!("Can't crossfeed: {}", problem);
+        }
+        group[si].crossfeed = true;
+        format!("{} now feeds {}", group[si].name, group[0].name)
+    }
+
+    /// Switch the selected group between cold and hot staging off the
+    /// group below. Returns the status message to show.
+    fn toggle_staging(&mut self) -> String {
+        use crate::stage::StagingMode;
+        let gi = self.selected_group;
+        if gi == 0 {
+            return "Bottom stage has nothing to separate from — select an upper stage".into();
+        }
+        let core = &mut self.stage_groups[gi][0];
+        core.staging = match core.staging {
+            StagingMode::Cold => StagingMode::Hot,
+            StagingMode::Hot => StagingMode::Cold,
+        };
+        match core.staging {
+            StagingMode::Hot => format!(
+                "{}: hot staging (+{:.0} m/s, adds a separation flaw risk)",
+                core.name, crate::stage::HOT_STAGING_DV_GAIN,
+            ),
+            StagingMode::Cold => format!(
+                "{}: cold staging (coast costs {:.0} m/s)",
+                core.name, crate::stage::COLD_STAGING_COAST_LOSS_DV,
+            ),
+        }
+    }
+
+    /// First invalid crossfeed in the design (e.g. after an engine swap
+    /// left a booster on different propellant than its core).
+    fn crossfeed_problem(&self) -> Option<String> {
+        self.stage_groups.iter()
+            .flat_map(|g| g.iter().skip(1).filter(|s| s.crossfeed)
+                .filter_map(|s| s.crossfeed_problem(&g[0]).map(|p| format!("{}: {}", s.name, p))))
+            .next()
+    }
+
     /// True when the designer is in Modify mode.
     pub fn is_modify(&self) -> bool {
         matches!(self.mode, DesignerMode::Modify { .. })
@@ -274,6 +358,11 @@ impl RocketDesignerState {
     pub fn remove_inner(&mut self, gi: usize, si: usize) {
         self.stage_groups[gi].remove(si);
         self.engine_sources[gi].remove(si);
+        // A promoted booster becomes the core, which has nothing to feed.
+        if let Some(core) = self.stage_groups[gi].first_mut() {
+            core.crossfeed = false;
+            core.engine_count = core.engine_count.max(1);
+        }
     }
 }
 
@@ -294,7 +383,8 @@ const PROPELLANT_STEP_BURN_SECONDS: f64 = 10.0;
 /// Compute thrust-scaled propellant step size for inline adjustments.
 /// Rounded to nearest 100 kg, min 100 kg.
 fn propellant_step(engine: &EngineDesign, engine_count: u32) -> f64 {
-    let raw = engine.mass_flow_rate() * engine_count as f64 * PROPELLANT_STEP_BURN_SECONDS;
+    // Drop tanks have no engines; step them as if they had one.
+    let raw = engine.mass_flow_rate() * engine_count.max(1) as f64 * PROPELLANT_STEP_BURN_SECONDS;
     (raw / 100.0).round().max(1.0) * 100.0
 }
 
@@ -328,7 +418,7 @@ fn sync_stages_to_projects(state: &mut RocketDesignerState, company: &crate::gam
                     // (e.g. Kerolox → Ion) doesn't strand a kerolox-
                     // sized tank on an ion engine.
                     stage.propellant_mass_kg = stage.engine.mass_flow_rate()
-                        * stage.engine_count as f64
+                        * stage.engine_count.max(1) as f64
                         * NEW_STAGE_BURN_SECONDS;
                 }
             }
@@ -422,6 +512,30 @@ pub enum InputMode {
     BidRules { selected: usize },
     /// Browsing observed award outcomes (price-discovery history).
     AwardHistory { scroll: usize },
+    /// Reading the market outlook, computed when opened.
+    MarketReport { report: crate::market_trend::MarketReport, scroll: usize },
+    /// Browsing the company library, loaded from disk when opened.
+    Library { library: crate::library::Library, selected: usize },
+    /// Reviewing a suggested spread of engineering teams, computed when
+    /// opened.
+    TeamAllocation { allocation: crate::allocation::Allocation },
+    /// Browsing the company's depot designs.
+    DepotDesigns { selected: usize },
+    /// Editing a depot design's specs. Cursor: 0 = capacity,
+    /// 1 = insulation, 2 = docking ports, 3 = solar arrays,
+    /// 4 = cryocoolers.
+    DepotEditor { project_id: crate::depot_project::DepotProjectId, cursor: usize },
+    /// Reading the encyclopedia, one section at a time.
+    Codex { codex: crate::codex::Codex, section: usize, scroll: usize },
+    /// Sizing the factory for `rockets_per_month` of rocket project
+    /// `project_index`.
+    CapacityPlanner { project_index: usize, rockets_per_month: f64 },
+    /// Choosing an outside provider to fly accepted contract
+    /// `contract_index`.
+    Subcontract { contract_index: usize, selected: usize },
+    /// Editing a rocket design's launch commit criteria. Rows are the
+    /// criteria in `Criterion::ALL` order, then enforcement.
+    CommitCriteria { project_index: usize, selected: usize },
     /// Browsing anchor-customer programs; Enter/B on a soliciting one
     /// opens block-bid entry. Auto-opens when a liftable program is
     /// announced (the announcement pauses the game).
@@ -433,6 +547,9 @@ pub enum InputMode {
         selected: usize,
         buffer: String,
     },
+    /// Browsing government programs; Enter/C commits to an announced
+    /// one.
+    GovPrograms { selected: usize },
     /// Persistent rocket designer screen.
     RocketDesigner { state: Box<RocketDesignerState> },
     /// Per-stage power-source editor opened from the rocket designer.
@@ -487,6 +604,9 @@ pub enum InputMode {
         /// Row in the merged manifest (contracts then spacecraft).
         cursor: usize,
     },
+    /// Following a launch-day countdown (`game.countdown`) to liftoff
+    /// or a scrub.
+    Countdown,
     /// Showing launch result.
     LaunchResult {
         record: crate::launch::LaunchRecord,
@@ -609,6 +729,10 @@ pub struct App {
     pub selected_item: usize,
     /// Speed before entering a modal, so we can restore on exit.
     pub pre_modal_speed: Option<GameSpeed>,
+    /// Department whose budget the Finance tab is editing.
+    pub budget_department: usize,
+    /// The rocket designer's design as last exported, for previews.
+    pub design_preview: crate::design_resource::DesignWatcher,
 }
 
 /// Compute reachable destinations using the stage-aware path planner.
@@ -652,6 +776,11 @@ fn apply_picked_engine_to_designer(
         structural_mass_kg: 0.0,
         fairing: None,
         power_sources: Vec::new(),
+        crossfeed: false,
+        long_coast_kit: false,
+        landing_kit: false,
+        launch_abort_system: false,
+        staging: Default::default(),
     };
     state.next_stage_id += 1;
 
@@ -754,7 +883,9 @@ impl App {
             status_message: None,
             input_mode: InputMode::Normal,
             selected_item: 0,
+            budget_department: 0,
             pre_modal_speed: None,
+            design_preview: Default::default(),
         }
     }
 
@@ -786,45 +917,36 @@ impl App {
         spacecraft_picks: Vec<bool>,
         spacecraft_item_ids: Vec<crate::manufacturing::InventoryItemId>,
     ) {
-        use crate::game_state::ManifestError;
-
-        let contract_indices: Vec<usize> = contract_picks.iter().enumerate()
-            .filter(|(_, picked)| **picked)
-            .map(|(i, _)| i)
-            .collect();
-        let picked_spacecraft: Vec<crate::manufacturing::InventoryItemId> =
-            spacecraft_picks.iter().enumerate()
-                .filter(|(_, picked)| **picked)
-                .map(|(i, _)| spacecraft_item_ids[i])
-                .collect();
+        let (contract_indices, picked_spacecraft) =
+            manifest_picks(&contract_picks, &spacecraft_picks, &spacecraft_item_ids);
 
+        // An advisory no-go still flies, with a warning.
+        let warning = match self.game
+            .check_commit_criteria(rocket_item_id, &contract_indices, &picked_spacecraft)
+        {
+            Ok(checklist) => commit_warning(&checklist),
+            Err(e) => {
+                self.status_message = Some(manifest_error_message(e));
+                return;
+            }
+        };
         let (destination, payloads) = match self.game
             .build_launch_payloads(&contract_indices, &picked_spacecraft)
         {
             Ok(dp) => dp,
-            Err(ManifestError::ConflictingDestinations { first, second }) => {
-                self.status_message = Some(format!(
-                    "Picked contracts have different destinations ({} vs {}). Untoggle one.",
-                    first, second,
-                ));
-                return;
-            }
-            Err(ManifestError::SpacecraftMissing) => {
-                self.status_message = Some("Spacecraft payload no longer in inventory.".into());
-                return;
-            }
-            Err(ManifestError::PayloadProjectMissing) => {
-                self.status_message = Some("Payload rocket project not found.".into());
+            Err(e) => {
+                self.status_message = Some(manifest_error_message(e));
                 return;
             }
         };
 
         match self.game.launch_rocket(rocket_item_id, &destination, payloads, persist) {
             Some((_events, Some(record))) => {
+                self.status_message = warning;
                 self.input_mode = InputMode::LaunchResult { record };
             }
             Some((_events, None)) => {
-                self.status_message = Some("Flight departed — in transit".into());
+                self.status_message = Some(warning.unwrap_or_else(|| "Flight departed — in transit".into()));
                 self.exit_modal();
             }
             None => {
@@ -834,6 +956,51 @@ impl App {
         }
     }
 
+    /// Start a launch-day countdown on the picked manifest and switch
+    /// the modal to follow it.
+    fn begin_manifest_countdown(
+        &mut self,
+        rocket_item_id: crate::manufacturing::InventoryItemId,
+        persist: bool,
+        contract_picks: Vec<bool>,
+        spacecraft_picks: Vec<bool>,
+        spacecraft_item_ids: Vec<crate::manufacturing::InventoryItemId>,
+    ) {
+        let (contract_indices, picked_spacecraft) =
+            manifest_picks(&contract_picks, &spacecraft_picks, &spacecraft_item_ids);
+        let warning = self.game.check_commit_criteria(rocket_item_id, &contract_indices, &picked_spacecraft)
+            .ok()
+            .and_then(|checklist| commit_warning(&checklist));
+        match self.game.begin_countdown(rocket_item_id, &contract_indices, &picked_spacecraft, persist) {
+            Ok(()) => {
+                self.status_message = warning;
+                self.input_mode = InputMode::Countdown;
+            }
+            Err(e) => self.status_message = Some(manifest_error_message(e)),
+        }
+    }
+
+    /// Run the countdown `minutes` on, and leave the modal for the
+    /// launch result or a scrub.
+    fn tick_countdown(&mut self, minutes: u32) {
+        use crate::countdown::CountdownOutcome;
+
+        match self.game.step_countdown(minutes).1 {
+            CountdownOutcome::Counting => {}
+            CountdownOutcome::Launched(_events, Some(record)) => {
+                self.input_mode = InputMode::LaunchResult { record };
+            }
+            CountdownOutcome::Launched(_events, None) => {
+                self.status_message = Some("Flight departed — in transit".into());
+                self.exit_modal();
+            }
+            CountdownOutcome::Scrubbed(evt) => {
+                self.status_message = Some(evt.to_string());
+                self.exit_modal();
+            }
+        }
+    }
+
     pub fn current_tab(&self) -> Tab {
         Tab::ALL[self.active_tab]
     }
@@ -857,6 +1024,7 @@ impl App {
 
     fn main_loop(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
         let mut last_tick = Instant::now();
+        let mut last_count = Instant::now();
 
         while self.running {
             terminal.draw(|frame| draw::draw(frame, self))?;
@@ -877,6 +1045,15 @@ impl App {
                 }
             }
 
+            // A launch-day countdown runs a minute per step while its
+            // modal is up; the game day stays paused.
+            if matches!(self.input_mode, InputMode::Countdown)
+                && last_count.elapsed() >= Duration::from_millis(100)
+            {
+                self.tick_countdown(1);
+                last_count = Instant::now();
+            }
+
             // Auto-advance when not paused
             if self.game.speed != GameSpeed::Paused && last_tick.elapsed() >= tick_rate {
                 let day_events = self.game.advance_day();
@@ -910,6 +1087,22 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyCode) {
+        self.dispatch_key(key);
+        self.observe_designer();
+    }
+
+    /// Re-export the design open in the rocket designer so a preview
+    /// picks up the change; forget it once the designer closes.
+    fn observe_designer(&mut self) {
+        match &self.input_mode {
+            InputMode::RocketDesigner { state } => {
+                self.design_preview.observe(&state.preview_design());
+            }
+            _ => self.design_preview.clear(),
+        }
+    }
+
+    fn dispatch_key(&mut self, key: KeyCode) {
         // Check if we're in an input mode first
         if !matches!(self.input_mode, InputMode::Normal) {
             self.handle_input_mode_key(key);
@@ -925,7 +1118,9 @@ impl App {
             KeyCode::Char('1') => self.game.set_speed(GameSpeed::Normal),
             KeyCode::Char('2') => self.game.set_speed(GameSpeed::Fast),
             KeyCode::Char('3') => self.game.set_speed(GameSpeed::VeryFast),
+            KeyCode::Char('4') => self.skip_to_next_month(),
             KeyCode::Char('s') => self.save_game(),
+            KeyCode::Char('?') => self.enter_modal(InputMode::Codex { codex: crate::codex::codex(), section: 0, scroll: 0 }),
 
             KeyCode::Left => self.focused_pane = FocusedPane::Sidebar,
             KeyCode::Right => self.focused_pane = FocusedPane::Content,
@@ -948,6 +1143,8 @@ impl App {
             Tab::Manufacturing => self.handle_manufacturing_key(key),
             Tab::Contracts => self.handle_contracts_key(key),
             Tab::Launches => self.handle_launches_key(key),
+            Tab::Finance => self.handle_finance_key(key),
+            Tab::Events => self.handle_events_key(key),
             _ => {}
         }
     }
@@ -1104,6 +1301,38 @@ impl App {
                     self.status_message = Some("Must be in Testing to order build".into());
                 }
             }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                // Buy an external review of the selected engine.
+                use crate::external_review::ReviewTarget;
+                let Some(id) = real_idx.map(|i| self.game.player_company.engine_projects[i].project_id) else {
+                    return;
+                };
+                self.status_message = Some(match self.game.order_external_review(ReviewTarget::Engine(id)) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('L') => {
+                let entry = real_idx.and_then(|idx| self.game.archive_engine(idx));
+                self.archive_to_library(entry);
+            }
+            KeyCode::Char('l') => self.open_library(),
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                let allocation = self.game.suggest_team_allocation();
+                self.enter_modal(InputMode::TeamAllocation { allocation });
+            }
+            KeyCode::Char('v') => {
+                // Derive a 1.5× variant from the selected engine's lineage
+                let Some(idx) = real_idx else { return };
+                let scale = self.game.player_company.engine_projects[idx].scale * 1.5;
+                match self.game.player_company.start_engine_variant(idx, scale, &self.game.balance) {
+                    Some(evt) => {
+                        self.status_message = Some(evt.to_string());
+                        self.game.event_log.push(self.game.date, evt);
+                    }
+                    None => self.status_message = Some("Finish the design before deriving a variant".into()),
+                }
+            }
             KeyCode::Char('r') => {
                 // Revise all discovered flaws and actualize pending improvements
                 if let Some(idx) = real_idx {
@@ -1124,16 +1353,151 @@ impl App {
                     self.status_message = Some(format!("Hired {}", name));
                 }
             }
+            KeyCode::Char('f') => {
+                self.status_message = Some(match self.game.fire_team() {
+                    Some(evt) => evt.to_string(),
+                    None => "No engineering teams to let go".into(),
+                });
+            }
+            KeyCode::Char('h') => {
+                self.status_message = Some(match self.game.furlough_team() {
+                    Some(evt) => evt.to_string(),
+                    None => "No idle engineering teams to furlough".into(),
+                });
+            }
+            KeyCode::Char('k') => {
+                self.status_message = Some(match self.game.recall_team() {
+                    Some(evt) => evt.to_string(),
+                    None => "No teams on furlough".into(),
+                });
+            }
+            KeyCode::Char('a') | KeyCode::Char('d') => {
+                // Answer the first team waiting on a raise
+                let Some(index) = self.game.pending_raise_index() else {
+                    self.status_message = Some("No raise requests pending".into());
+                    return;
+                };
+                let evt = if key == KeyCode::Char('a') {
+                    self.game.accept_raise(index)
+                } else {
+                    self.game.refuse_raise(index)
+                };
+                self.status_message = evt.map(|e| e.to_string());
+            }
+            KeyCode::Char('c') => {
+                let on = self.game.toggle_crunch();
+                self.status_message = Some(if on { "Engineering is in crunch" } else { "Crunch over" }.into());
+            }
             _ => {}
         }
     }
 
     fn handle_rockets_key(&mut self, key: KeyCode) {
         match key {
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.status_message = Some(
+                    match self.game.player_company.fork_rocket_project(self.selected_item, &self.game.balance) {
+                        Some(evt) => {
+                            self.game.event_log.push(self.game.date, evt.clone());
+                            evt.to_string()
+                        }
+                        None => "Only a design in Testing can be branched".into(),
+                    },
+                );
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.status_message = Some(match self.game.player_company.merge_rocket_branch(self.selected_item) {
+                    Some(evt) => {
+                        self.game.event_log.push(self.game.date, evt.clone());
+                        self.selected_item = self.selected_item.saturating_sub(1);
+                        evt.to_string()
+                    }
+                    None => "Merge needs both designs in Testing and no branch hardware".into(),
+                });
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.status_message = Some(match self.game.player_company.retire_rocket_branch(self.selected_item) {
+                    Some(evt) => {
+                        self.game.event_log.push(self.game.date, evt.clone());
+                        self.selected_item = self.selected_item.saturating_sub(1);
+                        evt.to_string()
+                    }
+                    None => "Only a branch with no hardware can be retired".into(),
+                });
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                // Buy an external review of the selected rocket design.
+                use crate::external_review::ReviewTarget;
+                let Some(id) = self.game.player_company.rocket_projects.get(self.selected_item)
+                    .map(|rp| rp.project_id) else {
+                    return;
+                };
+                self.status_message = Some(match self.game.order_external_review(ReviewTarget::Rocket(id)) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('j') | KeyCode::Char('J') => {
+                // Switch to the next design in the selected one's family:
+                // the mainline first, then its branches in fork order.
+                let company = &self.game.player_company;
+                let Some(rp) = company.rocket_projects.get(self.selected_item) else { return };
+                let root = rp.branch.as_ref().map_or(rp.project_id, |b| b.parent);
+                let mut family: Vec<usize> = company.rocket_projects.iter()
+                    .position(|p| p.project_id == root)
+                    .into_iter()
+                    .collect();
+                family.extend(company.rocket_branches(root));
+                if let Some(pos) = family.iter().position(|&i| i == self.selected_item) {
+                    self.selected_item = family[(pos + 1) % family.len()];
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Char('K')
+                if self.selected_item < self.game.player_company.rocket_projects.len() => {
+                    self.enter_modal(InputMode::CommitCriteria { project_index: self.selected_item, selected: 0 });
+                }
+            KeyCode::Char('d') | KeyCode::Char('D')
+                if self.selected_item < self.game.player_company.rocket_projects.len() => {
+                    let rp = &mut self.game.player_company.rocket_projects[self.selected_item];
+                    rp.disposal = rp.disposal.toggled();
+                    self.status_message = Some(format!("{}: upper stage disposal set to {}", rp.design.name, rp.disposal));
+                }
             KeyCode::Char('n') => {
                 // Start new rocket design flow
                 self.enter_modal(InputMode::RocketName { buffer: String::new() });
             }
+            KeyCode::Char('L') => {
+                let entry = self.game.archive_rocket(self.selected_item);
+                self.archive_to_library(entry);
+            }
+            KeyCode::Char('l') => self.open_library(),
+            KeyCode::Char('E') => {
+                let Some(json) = self.game.export_design(self.selected_item) else {
+                    self.status_message = Some("Design flies an engine that can't be exported".into());
+                    return;
+                };
+                let path = save::design_path(&self.game.player_company.rocket_projects[self.selected_item].design.name);
+                self.status_message = Some(match save::write_design_file(&json, &path) {
+                    Ok(()) => format!("Exported to {}", path.display()),
+                    Err(e) => format!("Export failed: {}", e),
+                });
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                let Some((name, path)) = save::list_design_files().into_iter().next() else {
+                    self.status_message = Some(format!("No design files in {}", save::designs_dir().display()));
+                    return;
+                };
+                let result = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| self.game.import_design(&json).map_err(|e| e.to_string()));
+                self.status_message = Some(match result {
+                    Ok(index) => {
+                        self.selected_item = index;
+                        format!("Imported {}", name)
+                    }
+                    Err(e) => format!("Import of {} failed: {}", name, e),
+                });
+            }
             KeyCode::Char('+') | KeyCode::Char('=') => {
                 if self.game.player_company.add_team_to_rocket_project(self.selected_item) {
                     self.status_message = Some("Team assigned".into());
@@ -1153,6 +1517,65 @@ impl App {
                     self.status_message = Some(format!("Revising {} flaw(s)", count));
                 }
             }
+            KeyCode::Char('c') => {
+                match self.game.start_certification(self.selected_item) {
+                    Ok(evt) => self.status_message = Some(format!("{}", evt)),
+                    Err(e) => self.status_message = Some(format!("{}", e)),
+                }
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                // Order a pad-abort test of the design's abort system
+                match self.game.player_company.order_pad_abort_test(self.selected_item, &self.game.balance) {
+                    Some((_, evt)) => {
+                        self.status_message = Some(evt.to_string());
+                        self.game.event_log.push(self.game.date, evt);
+                    }
+                    None => self.status_message = Some(
+                        "Needs an abort system, a design in testing and no test queued".into()),
+                }
+            }
+            KeyCode::Char('w') => {
+                let company = &mut self.game.player_company;
+                match company.adopt_flight_software(self.selected_item, &self.game.balance) {
+                    Some((id, evt)) => {
+                        let name = company.software_projects.iter()
+                            .find(|sw| sw.project_id == id)
+                            .map(|sw| sw.name.clone())
+                            .unwrap_or_default();
+                        if let Some(evt) = evt {
+                            self.game.event_log.push(self.game.date, evt);
+                        }
+                        self.status_message = Some(format!("Adapting {}", name));
+                    }
+                    None => self.status_message = Some("Design already has flight software".into()),
+                }
+            }
+            KeyCode::Char('>') => {
+                let company = &mut self.game.player_company;
+                let assigned = company.rocket_software_index(self.selected_item)
+                    .is_some_and(|si| company.add_team_to_software_project(si));
+                self.status_message = Some(if assigned {
+                    "Team assigned to flight software".into()
+                } else {
+                    "No free team or no flight software".into()
+                });
+            }
+            KeyCode::Char('<') => {
+                let company = &mut self.game.player_company;
+                if company.rocket_software_index(self.selected_item)
+                    .is_some_and(|si| company.remove_team_from_software_project(si))
+                {
+                    self.status_message = Some("Team removed from flight software".into());
+                }
+            }
+            KeyCode::Char('p') => {
+                let company = &mut self.game.player_company;
+                if let Some(count) = company.rocket_software_index(self.selected_item)
+                    .and_then(|si| company.start_software_revision(si))
+                {
+                    self.status_message = Some(format!("Patching {} bug(s)", count));
+                }
+            }
             KeyCode::Char('e') => {
                 let team_num = self.game.player_company.team_count() + 1;
                 let name = format!("Team {}", team_num);
@@ -1204,10 +1627,19 @@ impl App {
 
     fn handle_manufacturing_key(&mut self, key: KeyCode) {
         match key {
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                let Some(project_index) = flight_ready_projects(&self.game).first().copied() else {
+                    self.status_message = Some("No flight-ready design to plan for".into());
+                    return;
+                };
+                self.enter_modal(InputMode::CapacityPlanner { project_index, rockets_per_month: 1.0 });
+            }
             KeyCode::Char('b') => {
                 // Buy floor space
-                let cost = self.game.player_company.buy_floor_space(1, &self.game.balance);
-                self.status_message = Some(format!("Ordered 1 floor space unit ({})", crate::ui::draw::format_money(cost)));
+                self.status_message = Some(match self.game.player_company.buy_floor_space(1, &self.game.balance) {
+                    Some(cost) => format!("Ordered 1 floor space unit ({})", crate::ui::draw::format_money(cost)),
+                    None => "Facilities budget spent for this month".into(),
+                });
             }
             KeyCode::Char('+') | KeyCode::Char('=') => {
                 if self.game.player_company.add_team_to_manufacturing_order(self.selected_item) {
@@ -1223,14 +1655,134 @@ impl App {
                     self.status_message = Some("Mfg team removed".into());
                 }
             }
-            KeyCode::Char('m') => {
+            KeyCode::Char('z') => {
+                // Scrap the first engine built to a superseded revision
+                let company = &self.game.player_company;
+                let stale = company.manufacturing.inventory.engines.iter()
+                    .find(|e| match e.source {
+                        EngineSource::PlayerDesign(id) => company.find_engine_project(id)
+                            .is_some_and(|ep| e.revision < ep.revision),
+                        EngineSource::Contracted(_) => false,
+                    })
+                    .map(|e| e.item_id);
+                match stale.and_then(|id| self.game.scrap_inventory_item(id)) {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("No superseded engines in stock".into()),
+                }
+            }
+            KeyCode::Char('c') => {
+                // Recertify the first expired item not already queued
+                let inventory = &self.game.player_company.manufacturing.inventory;
+                let expired: Vec<_> = inventory.engines.iter().filter(|e| e.expired).map(|e| e.item_id)
+                    .chain(inventory.stages.iter().filter(|s| s.expired).map(|s| s.item_id))
+                    .chain(inventory.rockets.iter().filter(|r| r.expired).map(|r| r.item_id))
+                    .collect();
+                let ordered = expired.into_iter().find_map(|id|
+                    self.game.player_company.order_recertification(id, &self.game.balance));
+                match ordered {
+                    Some((_, evt)) => {
+                        self.status_message = Some(evt.to_string());
+                        self.game.event_log.push(self.game.date, evt);
+                    }
+                    None => self.status_message = Some("Nothing to recertify".into()),
+                }
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                // Inspect the first landed booster whose wear is unknown,
+                // those the advice flags first
+                let cfg = &self.game.balance.booster_reuse;
+                let mut boosters: Vec<_> = self.game.player_company.manufacturing.inventory.stages.iter()
+                    .filter_map(|s| s.booster.as_ref().map(|b| (s.item_id, b.retirement_advice(cfg))))
+                    .collect();
+                boosters.sort_by_key(|(_, advice)| *advice != crate::booster_reuse::RetirementAdvice::Inspect);
+                let ordered = boosters.into_iter().find_map(|(id, _)|
+                    self.game.player_company.order_booster_inspection(id, &self.game.balance));
+                match ordered {
+                    Some((_, evt)) => {
+                        self.status_message = Some(evt.to_string());
+                        self.game.event_log.push(self.game.date, evt);
+                    }
+                    None => self.status_message = Some("No booster to inspect".into()),
+                }
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') | KeyCode::Char('d') | KeyCode::Char('D') => {
+                let kind = match key {
+                    KeyCode::Char('g') | KeyCode::Char('G') => crate::recovery_fleet::RecoveryAssetKind::LandingZone,
+                    _ => crate::recovery_fleet::RecoveryAssetKind::DroneShip,
+                };
+                self.status_message = Some(match self.game.buy_recovery_asset(kind) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                // Send out the first drone ship in port
+                let in_port = self.game.player_company.recovery_fleet.assets.iter()
+                    .find(|a| a.kind == crate::recovery_fleet::RecoveryAssetKind::DroneShip && a.on_station_from.is_none())
+                    .map(|a| a.id);
+                self.status_message = Some(match in_port.map(|id| self.game.send_out_drone_ship(id)) {
+                    Some(Ok(evt)) => evt.to_string(),
+                    Some(Err(e)) => e.to_string(),
+                    None => "No drone ship in port".into(),
+                });
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                // Retire the first landed booster advised to retire
+                let cfg = &self.game.balance.booster_reuse;
+                let worn = self.game.player_company.manufacturing.inventory.stages.iter()
+                    .find(|s| s.booster.as_ref().is_some_and(|b|
+                        b.retirement_advice(cfg) == crate::booster_reuse::RetirementAdvice::Retire))
+                    .map(|s| s.item_id);
+                match worn.and_then(|id| self.game.scrap_inventory_item(id)) {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("No booster due for retirement".into()),
+                }
+            }
+            KeyCode::Char('m') | KeyCode::Char('S') | KeyCode::Char('L') => {
+                let size = match key {
+                    KeyCode::Char('S') => TeamSize::Small,
+                    KeyCode::Char('L') => TeamSize::Large,
+                    _ => TeamSize::Medium,
+                };
                 let team_num = self.game.player_company.manufacturing_teams.len() + 1;
                 let name = format!("Mfg Team {}", team_num);
-                if let Some(evt) = self.game.player_company.hire_manufacturing_team(name.clone(), &self.game.balance) {
+                if let Some(evt) = self.game.player_company.hire_sized_manufacturing_team(name.clone(), size, &self.game.balance) {
                     self.game.event_log.push(self.game.date, evt);
-                    self.status_message = Some(format!("Hired {}", name));
+                    self.status_message = Some(format!("Hired {} ({})", name, size));
                 }
             }
+            KeyCode::Char('j') => {
+                // Merge the first two idle teams of the same size
+                let company = &self.game.player_company;
+                let pair = TeamSize::ALL.into_iter().rev()
+                    .filter(|&size| size.larger().is_some()
+                        && company.unassigned_manufacturing_teams_of_size(size) >= 2)
+                    .find_map(|size| {
+                        let mut idx = company.manufacturing_teams.iter().enumerate()
+                            .filter(|(_, t)| t.size == size)
+                            .map(|(i, _)| i);
+                        Some((idx.next()?, idx.next()?))
+                    });
+                self.status_message = Some(match pair.and_then(|(a, b)|
+                    self.game.player_company.merge_manufacturing_teams(a, b, &self.game.balance))
+                {
+                    Some(name) => format!("Teams merged into {}", name),
+                    None => "No two idle mfg teams of the same size to merge".into(),
+                });
+            }
+            KeyCode::Char('v') => {
+                // Grow the last-hired team one size, wrapping large back to small
+                let Some(index) = self.game.player_company.manufacturing_teams.len().checked_sub(1) else {
+                    self.status_message = Some("No mfg teams to convert".into());
+                    return;
+                };
+                let from = self.game.player_company.manufacturing_teams[index].size;
+                let to = from.larger().unwrap_or(TeamSize::Small);
+                self.status_message = Some(match self.game.player_company.convert_manufacturing_team(index, to, &self.game.balance) {
+                    Some(cost) => format!("Converted to a {} team ({})", to, crate::ui::draw::format_money(cost)),
+                    None => "Team is busy or Manufacturing budget is spent".into(),
+                });
+            }
             _ => {}
         }
     }
@@ -1243,6 +1795,15 @@ impl App {
                     self.status_message = Some("Already accepted".into());
                     return;
                 }
+                let market_id = self.game.available_contracts[self.selected_item].market_id;
+                if self.game.certification_blocks_market(market_id) {
+                    self.status_message = Some("Customer requires a certified design".into());
+                    return;
+                }
+                if self.game.export_blocks_contract(&self.game.available_contracts[self.selected_item]) {
+                    self.status_message = Some("Export-controlled: needs a security clearance and no active ban".into());
+                    return;
+                }
                 if self.game.available_contracts[self.selected_item].is_solicitation() {
                     // Sealed bid: open the price-entry modal, seeded
                     // with any pending bid so it can be revised.
@@ -1268,9 +1829,156 @@ impl App {
             KeyCode::Char('h') | KeyCode::Char('H') => {
                 self.enter_modal(InputMode::AwardHistory { scroll: 0 });
             }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                let report = self.game.market_report();
+                self.enter_modal(InputMode::MarketReport { report, scroll: 0 });
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                let Some(contract_index) = self.selected_item.checked_sub(self.game.available_contracts.len())
+                    .filter(|&i| i < self.game.player_company.active_contracts.len())
+                else {
+                    self.status_message = Some("Select an accepted contract to subcontract".into());
+                    return;
+                };
+                self.enter_modal(InputMode::Subcontract { contract_index, selected: 0 });
+            }
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 self.enter_modal(InputMode::Campaigns { selected: 0 });
             }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.enter_modal(InputMode::GovPrograms { selected: 0 });
+            }
+            _ => {}
+        }
+    }
+
+    /// Toggle the auto-pause rules shown in the Events tab title.
+    fn handle_events_key(&mut self, key: KeyCode) {
+        const MONEY_FLOORS: [Option<f64>; 5] =
+            [None, Some(5_000_000.0), Some(10_000_000.0), Some(25_000_000.0), Some(50_000_000.0)];
+        let rules = &mut self.game.pause_rules;
+        let (what, on) = match key {
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                rules.flight_arrived = !rules.flight_arrived;
+                ("arrivals", rules.flight_arrived)
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                rules.flaw_discovered = !rules.flaw_discovered;
+                ("flaws", rules.flaw_discovered)
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                rules.order_complete = !rules.order_complete;
+                ("finished orders", rules.order_complete)
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                let next = MONEY_FLOORS.iter()
+                    .position(|&f| f == rules.money_below)
+                    .map_or(0, |i| (i + 1) % MONEY_FLOORS.len());
+                rules.money_below = MONEY_FLOORS[next];
+                self.status_message = Some(match rules.money_below {
+                    Some(floor) => format!("Pause when money drops below {}",
+                        crate::resources::format_money(floor)),
+                    None => "Money pause off".into(),
+                });
+                return;
+            }
+            _ => return,
+        };
+        self.status_message = Some(format!(
+            "Pause on {}: {}", what, if on { "on" } else { "off" },
+        ));
+    }
+
+    fn handle_finance_key(&mut self, key: KeyCode) {
+        use crate::budget::Department;
+        const BUDGET_CAPS: [Option<f64>; 7] = [
+            None, Some(1_000_000.0), Some(2_000_000.0), Some(5_000_000.0),
+            Some(10_000_000.0), Some(20_000_000.0), Some(50_000_000.0),
+        ];
+        match key {
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                let newest = self.game.player_company.assets.assets.iter().rev()
+                    .find(|a| matches!(a.kind, crate::assets::AssetKind::FloorSpace { .. }))
+                    .map(|a| a.id);
+                self.status_message = Some(match newest.and_then(|id| self.game.sell_asset(id)) {
+                    Some(evt) => evt.to_string(),
+                    None => "No idle floor space to sell".into(),
+                });
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.budget_department = (self.budget_department + 1) % Department::ALL.len();
+                self.status_message = Some(format!(
+                    "Editing {} budget", Department::ALL[self.budget_department].name(),
+                ));
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                let department = Department::ALL[self.budget_department];
+                let budgets = &mut self.game.player_company.budgets;
+                let next = BUDGET_CAPS.iter()
+                    .position(|&c| c == budgets.limit(department))
+                    .map_or(0, |i| (i + 1) % BUDGET_CAPS.len());
+                budgets.set_limit(department, BUDGET_CAPS[next]);
+                self.status_message = Some(match BUDGET_CAPS[next] {
+                    Some(cap) => format!("{} budget {}/mo", department.name(),
+                        crate::resources::format_money(cap)),
+                    None => format!("{} budget uncapped", department.name()),
+                });
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                if self.game.player_company.listing.is_some() {
+                    self.status_message = Some("Already public".into());
+                    return;
+                }
+                match self.game.ipo() {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some(format!(
+                        "Valuation too low to IPO (need {})",
+                        crate::resources::format_money(self.game.balance.valuation.min_ipo_valuation),
+                    )),
+                }
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                // Cycle the security tier, wrapping back to none.
+                let max = self.game.balance.espionage.max_security_level;
+                let level = self.game.player_company.security_level;
+                self.game.set_security_level(if level >= max { 0 } else { level + 1 });
+                self.status_message = Some(format!(
+                    "Security level {}", self.game.player_company.security_level,
+                ));
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                self.status_message = Some(match self.game.start_pr_campaign() {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') | KeyCode::Char('p') | KeyCode::Char('P') => {
+                use crate::pad_failure::CrisisResponse;
+                let response = if matches!(key, KeyCode::Char('t') | KeyCode::Char('T')) {
+                    CrisisResponse::Transparent
+                } else {
+                    CrisisResponse::Spin
+                };
+                self.status_message = Some(match self.game.respond_to_press_crisis(response) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                let insured = !self.game.player_company.launch_site.insured;
+                self.game.set_pad_insurance(insured);
+                self.status_message = Some(if insured { "Pad insured" } else { "Pad insurance dropped" }.into());
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                if self.game.leak_offer.is_none() {
+                    self.status_message = Some("No leaked data on offer".into());
+                    return;
+                }
+                match self.game.buy_leak() {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("No engine in testing to use the data".into()),
+                }
+            }
             _ => {}
         }
     }
@@ -1337,6 +2045,305 @@ impl App {
                     }),
                 });
             }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                // Retrofit the selected rocket to its design's current revision
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                match self.game.player_company.order_retrofit(item_id, &self.game.balance) {
+                    Some((_, evt)) => {
+                        self.status_message = Some(evt.to_string());
+                        self.game.event_log.push(self.game.date, evt);
+                    }
+                    None => self.status_message = Some("Nothing to retrofit".into()),
+                }
+            }
+            KeyCode::Char('z') | KeyCode::Char('Z') | KeyCode::Char('e') | KeyCode::Char('E') => {
+                // Scrap the selected rocket, or strip its engines first.
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let evt = if matches!(key, KeyCode::Char('e') | KeyCode::Char('E')) {
+                    self.game.strip_rocket_engines(item_id)
+                } else {
+                    self.game.scrap_inventory_item(item_id)
+                };
+                if let Some(evt) = evt {
+                    self.status_message = Some(evt.to_string());
+                }
+                let remaining = self.game.player_company.manufacturing.inventory.rockets.len();
+                self.selected_item = self.selected_item.min(remaining.saturating_sub(1));
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                // Validate the selected rocket against the first active contract.
+                let company = &self.game.player_company;
+                let Some(item_id) = company.manufacturing.inventory.rockets.get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let Some((destination, payload_kg)) = company.active_contracts.first()
+                    .map(|c| (c.destination.clone(), c.payload_kg)) else {
+                    self.status_message = Some("No active contract to validate against".into());
+                    return;
+                };
+                match self.game.start_integration_campaign(item_id, &destination, payload_kg) {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("No free engineering team".into()),
+                }
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') | KeyCode::Char('w') | KeyCode::Char('W') => {
+                // Book a press briefing ('b') or livestream ('w') for the
+                // selected rocket's launch.
+                use crate::marketing::PressKind;
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let kind = if matches!(key, KeyCode::Char('w') | KeyCode::Char('W')) {
+                    PressKind::Livestream
+                } else {
+                    PressKind::Briefing
+                };
+                self.status_message = Some(match self.game.schedule_press_event(item_id, kind) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                // Sign the first posted pad lease offer.
+                match self.game.accept_pad_lease(0) {
+                    Ok(evt) => self.status_message = Some(evt.to_string()),
+                    Err(e) => self.status_message = Some(e.to_string()),
+                }
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                // Apply for the next license class not yet held or pending.
+                use crate::regulation::LicenseClass;
+                let compliance = &self.game.player_company.compliance;
+                let next = [LicenseClass::Cislunar, LicenseClass::DeepSpace].into_iter()
+                    .find(|&c| !compliance.has_license(c) && compliance.application(c).is_none());
+                match next.and_then(|class| self.game.apply_for_license(class)) {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("All licenses held or pending".into()),
+                }
+            }
+            KeyCode::Char('S') => {
+                // Lowercase 's' saves; the clearance upgrade is capital only.
+                match self.game.upgrade_security_clearance() {
+                    Some(evt) => self.status_message = Some(evt.to_string()),
+                    None => self.status_message = Some("Security clearance held or under way".into()),
+                }
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                let n = self.game.player_company.astronauts.astronauts.len() + 1;
+                let name = format!("Astronaut {}", n);
+                match self.game.player_company.hire_astronaut(name.clone(), &self.game.balance) {
+                    Some(evt) => {
+                        self.game.event_log.push(self.game.date, evt);
+                        self.status_message = Some(format!("Hired {}", name));
+                    }
+                    None => self.status_message = Some("Testing budget spent for this month".into()),
+                }
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                // Crew the selected rocket with the first astronauts fit to fly.
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let cfg = &self.game.balance.astronauts;
+                let corps = &self.game.player_company.astronauts;
+                let crew: Vec<_> = corps.astronauts.iter()
+                    .filter(|a| a.can_fly(cfg))
+                    .filter(|a| !corps.assignments.iter()
+                        .any(|c| c.rocket_item_id != item_id && c.crew.contains(&a.id)))
+                    .take(cfg.crew_size as usize)
+                    .map(|a| a.id)
+                    .collect();
+                match self.game.assign_crew(item_id, crew) {
+                    Ok(evt) => self.status_message = Some(evt.to_string()),
+                    Err(e) => self.status_message = Some(e.to_string()),
+                }
+            }
+            KeyCode::Char('L') => {
+                // Lowercase 'l' launches; depot designs are capital only.
+                self.enter_modal(InputMode::DepotDesigns { selected: 0 });
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                // Launch an ISRU plant on the selected rocket to the first ISRU site.
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let Some(site) = self.game.balance.isru.sites.first().map(|s| s.location.clone()) else {
+                    self.status_message = Some("No ISRU sites configured".into());
+                    return;
+                };
+                match self.game.launch_isru_plant(item_id, &site) {
+                    Ok((_events, Some(record))) => {
+                        self.enter_modal(InputMode::LaunchResult { record });
+                    }
+                    Ok((_events, None)) => {
+                        self.status_message = Some("ISRU plant departed — in transit".into());
+                    }
+                    Err(e) => self.status_message = Some(e.to_string()),
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                // Form a constellation around the newest spacecraft not
+                // already in one, sized to its design's craft at its location.
+                let Some(sc) = self.game.spacecraft.iter().rev().find(|sc| !self.game.constellations.iter()
+                    .any(|c| c.location == sc.location && c.rocket_project_id == sc.rocket_project_id))
+                else {
+                    self.status_message = Some("Every spacecraft is already in a constellation".into());
+                    return;
+                };
+                let (location, project_id) = (sc.location.clone(), sc.rocket_project_id);
+                let name = format!("{} Constellation", sc.design.name);
+                let required = self.game.spacecraft.iter()
+                    .filter(|s| s.location == location && s.rocket_project_id == project_id)
+                    .count() as u32;
+                self.status_message = Some(match self.game.form_constellation(name, &location, project_id, required) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('j') | KeyCode::Char('J') => {
+                // Toggle auto-replace on every constellation.
+                if self.game.constellations.is_empty() {
+                    self.status_message = Some("No constellations".into());
+                    return;
+                }
+                let on = self.game.constellations.iter().any(|c| !c.auto_replace);
+                let ids: Vec<_> = self.game.constellations.iter().map(|c| c.id).collect();
+                for id in ids {
+                    self.game.set_constellation_auto_replace(id, on);
+                }
+                self.status_message = Some(format!("Constellation auto-replace {}", if on { "on" } else { "off" }));
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                // Send a servicing kit on the selected rocket to the most worn asset.
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let Some((target, propellant_kg)) = self.game.most_worn_asset() else {
+                    self.status_message = Some("Nothing needs servicing".into());
+                    return;
+                };
+                match self.game.launch_servicing_mission(item_id, target, propellant_kg) {
+                    Ok((_events, Some(record))) => {
+                        self.enter_modal(InputMode::LaunchResult { record });
+                    }
+                    Ok((_events, None)) => {
+                        self.status_message = Some("Servicing mission departed — in transit".into());
+                    }
+                    Err(e) => self.status_message = Some(e.to_string()),
+                }
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                // Refuel every spacecraft parked at a depot.
+                let mut refueled = 0;
+                for i in 0..self.game.spacecraft.len() {
+                    if self.game.refuel_spacecraft(i).is_ok() {
+                        refueled += 1;
+                    }
+                }
+                self.status_message = Some(match refueled {
+                    0 => "No spacecraft could refuel".to_string(),
+                    n => format!("Refueled {} spacecraft", n),
+                });
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                // Toggle standing top-up orders for spacecraft parked at depots.
+                use crate::infrastructure::TransferDirection;
+                if !self.game.transfer_orders.is_empty() {
+                    let n = self.game.transfer_orders.len();
+                    self.game.transfer_orders.clear();
+                    self.status_message = Some(format!("Cancelled {} transfer order(s)", n));
+                    return;
+                }
+                let parked: Vec<_> = self.game.spacecraft.iter()
+                    .filter(|sc| self.game.infrastructure_at(&sc.location).is_some())
+                    .map(|sc| sc.id)
+                    .collect();
+                for id in &parked {
+                    let _ = self.game.schedule_transfer(*id, TransferDirection::Fill);
+                }
+                self.status_message = Some(match parked.len() {
+                    0 => "No spacecraft parked at a depot".to_string(),
+                    n => format!("Auto top-up on for {} spacecraft", n),
+                });
+            }
+            KeyCode::Char('%') => {
+                // Cycle the selected rocket's propellant load.
+                use crate::rocket::PropellantLoad;
+                let Some(rocket) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let (item_id, name) = (rocket.item_id, rocket.rocket_name.clone());
+                const SHARES: [f64; 5] = [0.9, 0.75, 0.6, 0.45, 0.3];
+                let next = match rocket.propellant_load {
+                    PropellantLoad::Full => PropellantLoad::AutoFill,
+                    PropellantLoad::AutoFill => PropellantLoad::Partial(SHARES[0]),
+                    PropellantLoad::Partial(share) => SHARES.iter()
+                        .find(|&&s| s < share - 1e-9)
+                        .map_or(PropellantLoad::Full, |&s| PropellantLoad::Partial(s)),
+                };
+                self.game.set_propellant_load(item_id, next);
+                let load = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map_or(next, |r| r.propellant_load);
+                self.status_message = Some(format!("{}: propellant load {}", name, load.label()));
+            }
+            KeyCode::Char('P') => {
+                self.status_message = Some(match self.game.convert_pad() {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                // Move the pad to the next site in the catalog.
+                let sites = crate::launch_site::SITE_LOCATIONS;
+                let here = sites.iter()
+                    .position(|s| s.id == self.game.player_company.launch_site.location)
+                    .unwrap_or(0);
+                let next = sites[(here + 1) % sites.len()].id;
+                self.status_message = Some(match self.game.relocate_launch_site(next) {
+                    Ok(evt) => evt.to_string(),
+                    Err(e) => e.to_string(),
+                });
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                // Cancel the next lease that hasn't started yet.
+                let date = self.game.date;
+                let next = self.game.player_company.launch_site.leases.iter()
+                    .position(|l| l.start > date);
+                match next.and_then(|i| self.game.cancel_pad_lease(i)) {
+                    Some(lease) => {
+                        self.status_message = Some(format!("Cancelled pad lease to {}", lease.lessee));
+                    }
+                    None => self.status_message = Some("No upcoming lease to cancel".into()),
+                }
+            }
             KeyCode::Char('l') | KeyCode::Enter
             | KeyCode::Char('k') | KeyCode::Char('K') => {
                 // 'k'/'K' = keep: the carrier becomes a Spacecraft at the
@@ -1568,88 +2575,256 @@ impl App {
                     _ => {}
                 }
             }
-            InputMode::BidEntry { contract_index, buffer } => {
+            InputMode::BidEntry { contract_index, buffer } => {
+                match key {
+                    KeyCode::Esc => { self.exit_modal(); }
+                    KeyCode::Enter => {
+                        let index = *contract_index;
+                        let parsed = buffer.trim().parse::<f64>();
+                        self.exit_modal();
+                        match parsed {
+                            Ok(m) if m > 0.0 => {
+                                let bid = m * 1_000_000.0;
+                                if let Some(evt) = self.game.place_bid(index, bid) {
+                                    self.status_message = Some(format!("{}", evt));
+                                } else {
+                                    self.status_message = Some("Could not place bid".into());
+                                }
+                            }
+                            _ => {
+                                self.status_message = Some("Bid must be a positive number of $M".into());
+                            }
+                        }
+                    }
+                    KeyCode::Backspace => { buffer.pop(); }
+                    KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
+                        buffer.push(c);
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::BidRules { selected } => {
+                let market_ids: Vec<crate::contract::MarketId> = self.game.markets.iter()
+                    .filter(|m| m.active)
+                    .map(|m| m.id)
+                    .collect();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('r') | KeyCode::Char('R') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *selected = selected.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        if *selected + 1 < market_ids.len() {
+                            *selected += 1;
+                        }
+                    }
+                    KeyCode::Char(' ') | KeyCode::Enter => {
+                        if let Some(&id) = market_ids.get(*selected) {
+                            let rule = self.game.player_company.bid_rules
+                                .entry(id).or_default();
+                            rule.enabled = !rule.enabled;
+                        }
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        if let Some(&id) = market_ids.get(*selected) {
+                            let rule = self.game.player_company.bid_rules
+                                .entry(id).or_default();
+                            rule.margin = (rule.margin - 0.25).max(0.0);
+                        }
+                    }
+                    KeyCode::Right | KeyCode::Char('l') => {
+                        if let Some(&id) = market_ids.get(*selected) {
+                            let rule = self.game.player_company.bid_rules
+                                .entry(id).or_default();
+                            rule.margin = (rule.margin + 0.25).min(9.0);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::AwardHistory { scroll } => {
+                let len = self.game.award_history.len();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('H') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *scroll = scroll.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j')
+                        if *scroll + 1 < len => {
+                            *scroll += 1;
+                        }
+                    _ => {}
+                }
+            }
+            InputMode::MarketReport { report, scroll } => {
+                let len = report.outlooks.len();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('o') | KeyCode::Char('O') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *scroll = scroll.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j')
+                        if *scroll + 1 < len => {
+                            *scroll += 1;
+                        }
+                    _ => {}
+                }
+            }
+            InputMode::Codex { codex, section, scroll } => {
+                let sections = draw::CODEX_SECTIONS.len();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('?') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        *section = (*section + sections - 1) % sections;
+                        *scroll = 0;
+                    }
+                    KeyCode::Right | KeyCode::Tab | KeyCode::Char('l') => {
+                        *section = (*section + 1) % sections;
+                        *scroll = 0;
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *scroll = scroll.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j')
+                        if *scroll + 1 < draw::codex_lines(codex, *section).len() => {
+                            *scroll += 1;
+                        }
+                    _ => {}
+                }
+            }
+            InputMode::Library { library, selected } => {
+                match key {
+                    KeyCode::Esc | KeyCode::Char('l') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *selected = selected.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j')
+                        if *selected + 1 < library.entries.len() => {
+                            *selected += 1;
+                        }
+                    KeyCode::Char('x') | KeyCode::Char('X') => {
+                        let Some(entry) = library.remove(*selected) else { return };
+                        *selected = (*selected).min(library.entries.len().saturating_sub(1));
+                        self.status_message = Some(match save::save_library(library, &save::library_path()) {
+                            Ok(()) => format!("Removed {} Rev {} from the library", entry.name, entry.revision),
+                            Err(e) => format!("Library save failed: {}", e),
+                        });
+                    }
+                    KeyCode::Char('i') | KeyCode::Char('I') => {
+                        let Some(entry) = library.entries.get(*selected) else { return };
+                        self.status_message = Some(match self.game.sandbox_import_archived(entry) {
+                            Ok(evt) => evt.to_string(),
+                            Err(e) => format!("Can't import: {}", e),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::DepotDesigns { selected } => {
+                let selected = *selected;
+                self.handle_depot_designs_key(key, selected);
+            }
+            InputMode::DepotEditor { project_id, cursor } => {
+                let (project_id, cursor) = (*project_id, *cursor);
+                self.handle_depot_editor_key(key, project_id, cursor);
+            }
+            InputMode::TeamAllocation { allocation } => {
                 match key {
-                    KeyCode::Esc => { self.exit_modal(); }
-                    KeyCode::Enter => {
-                        let index = *contract_index;
-                        let parsed = buffer.trim().parse::<f64>();
+                    KeyCode::Esc | KeyCode::Char('t') | KeyCode::Char('T') => {
                         self.exit_modal();
-                        match parsed {
-                            Ok(m) if m > 0.0 => {
-                                let bid = m * 1_000_000.0;
-                                if let Some(evt) = self.game.place_bid(index, bid) {
-                                    self.status_message = Some(format!("{}", evt));
-                                } else {
-                                    self.status_message = Some("Could not place bid".into());
-                                }
-                            }
-                            _ => {
-                                self.status_message = Some("Bid must be a positive number of $M".into());
-                            }
-                        }
                     }
-                    KeyCode::Backspace => { buffer.pop(); }
-                    KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
-                        buffer.push(c);
+                    KeyCode::Enter if allocation.changes() => {
+                        let applied = self.game.apply_team_allocation();
+                        self.status_message = Some(format!(
+                            "Teams reassigned: {:.1} → {:.1} weighted work/day",
+                            applied.current_value(), applied.suggested_value(),
+                        ));
+                        self.exit_modal();
                     }
                     _ => {}
                 }
             }
-            InputMode::BidRules { selected } => {
-                let market_ids: Vec<crate::contract::MarketId> = self.game.markets.iter()
-                    .filter(|m| m.active)
-                    .map(|m| m.id)
-                    .collect();
+            InputMode::CapacityPlanner { project_index, rockets_per_month } => {
+                let ready = flight_ready_projects(&self.game);
+                let pos = ready.iter().position(|&i| i == *project_index).unwrap_or(0);
                 match key {
-                    KeyCode::Esc | KeyCode::Char('r') | KeyCode::Char('R') => {
+                    KeyCode::Esc | KeyCode::Char('p') | KeyCode::Char('P') => {
                         self.exit_modal();
                     }
                     KeyCode::Up | KeyCode::Char('k') => {
-                        *selected = selected.saturating_sub(1);
+                        *project_index = ready[pos.saturating_sub(1)];
                     }
                     KeyCode::Down | KeyCode::Char('j') => {
-                        if *selected + 1 < market_ids.len() {
-                            *selected += 1;
-                        }
-                    }
-                    KeyCode::Char(' ') | KeyCode::Enter => {
-                        if let Some(&id) = market_ids.get(*selected) {
-                            let rule = self.game.player_company.bid_rules
-                                .entry(id).or_default();
-                            rule.enabled = !rule.enabled;
-                        }
+                        *project_index = ready[(pos + 1).min(ready.len() - 1)];
                     }
-                    KeyCode::Left | KeyCode::Char('h') => {
-                        if let Some(&id) = market_ids.get(*selected) {
-                            let rule = self.game.player_company.bid_rules
-                                .entry(id).or_default();
-                            rule.margin = (rule.margin - 0.25).max(0.0);
-                        }
+                    KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('+') => {
+                        *rockets_per_month += CADENCE_STEP;
                     }
-                    KeyCode::Right | KeyCode::Char('l') => {
-                        if let Some(&id) = market_ids.get(*selected) {
-                            let rule = self.game.player_company.bid_rules
-                                .entry(id).or_default();
-                            rule.margin = (rule.margin + 0.25).min(9.0);
-                        }
+                    KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('-') => {
+                        *rockets_per_month = (*rockets_per_month - CADENCE_STEP).max(CADENCE_STEP);
                     }
                     _ => {}
                 }
             }
-            InputMode::AwardHistory { scroll } => {
-                let len = self.game.award_history.len();
+            InputMode::Subcontract { contract_index, selected } => {
+                let (contract_index, providers) = (*contract_index, self.game.launch_providers.len());
                 match key {
-                    KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('H') => {
+                    KeyCode::Esc | KeyCode::Char('u') | KeyCode::Char('U') => {
                         self.exit_modal();
                     }
                     KeyCode::Up | KeyCode::Char('k') => {
-                        *scroll = scroll.saturating_sub(1);
+                        *selected = selected.saturating_sub(1);
                     }
                     KeyCode::Down | KeyCode::Char('j')
-                        if *scroll + 1 < len => {
-                            *scroll += 1;
+                        if *selected + 1 < providers => {
+                            *selected += 1;
+                        }
+                    KeyCode::Enter => {
+                        let provider_index = *selected;
+                        let result = self.game.subcontract_launch(contract_index, provider_index);
+                        self.status_message = Some(match result {
+                            Ok(evt) => {
+                                self.exit_modal();
+                                evt.to_string()
+                            }
+                            Err(e) => e.to_string(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::CommitCriteria { project_index, selected } => {
+                use crate::commit_criteria::Criterion;
+
+                let Some(rp) = self.game.player_company.rocket_projects.get_mut(*project_index) else {
+                    self.exit_modal();
+                    return;
+                };
+                let criteria = &mut rp.commit_criteria;
+                let row = Criterion::ALL.get(*selected).copied();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('k') | KeyCode::Char('K') => self.exit_modal(),
+                    KeyCode::Up => *selected = selected.saturating_sub(1),
+                    KeyCode::Down if *selected < Criterion::ALL.len() => *selected += 1,
+                    KeyCode::Enter | KeyCode::Char(' ') => match row {
+                        Some(c) => criteria.toggle(c),
+                        None => criteria.enforced = !criteria.enforced,
+                    },
+                    KeyCode::Left | KeyCode::Right => {
+                        if let Some(c) = row {
+                            criteria.adjust(c, key == KeyCode::Right);
                         }
+                    }
                     _ => {}
                 }
             }
@@ -1693,6 +2868,30 @@ impl App {
                     _ => {}
                 }
             }
+            InputMode::GovPrograms { selected } => {
+                let len = self.game.gov_programs.len();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('g') | KeyCode::Char('G') => {
+                        self.exit_modal();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        *selected = selected.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if *selected + 1 < len => {
+                        *selected += 1;
+                    }
+                    KeyCode::Enter | KeyCode::Char('c') | KeyCode::Char('C') => {
+                        let Some(id) = self.game.gov_programs.get(*selected).map(|p| p.id) else {
+                            return;
+                        };
+                        self.status_message = Some(match self.game.commit_gov_program(id) {
+                            Some(evt) => format!("{}", evt),
+                            None => "Program isn't open for commitment".into(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
             InputMode::CampaignBidEntry { campaign_id, selected, buffer } => {
                 match key {
                     KeyCode::Esc => {
@@ -1812,9 +3011,40 @@ impl App {
                             contract_picks, spacecraft_picks, spacecraft_item_ids,
                         );
                     }
+                    KeyCode::Char('o') => {
+                        let contract_picks = contract_picks.clone();
+                        let spacecraft_picks = spacecraft_picks.clone();
+                        let spacecraft_item_ids = spacecraft_item_ids.clone();
+                        self.begin_manifest_countdown(
+                            rocket_item_id, persist,
+                            contract_picks, spacecraft_picks, spacecraft_item_ids,
+                        );
+                    }
                     _ => {}
                 }
             }
+            InputMode::Countdown => {
+                let refused = match key {
+                    KeyCode::Char('h') => self.game.hold_countdown().is_none(),
+                    KeyCode::Char('r') => self.game.resume_countdown().is_none(),
+                    KeyCode::Char('c') => self.game.recycle_countdown().is_none(),
+                    KeyCode::Char('f') => {
+                        self.tick_countdown(10);
+                        false
+                    }
+                    KeyCode::Esc | KeyCode::Char('x') => {
+                        if let Some(evt) = self.game.scrub_countdown("called off by the launch director") {
+                            self.status_message = Some(evt.to_string());
+                        }
+                        self.exit_modal();
+                        false
+                    }
+                    _ => false,
+                };
+                if refused {
+                    self.status_message = Some("Not from here in the count.".into());
+                }
+            }
             InputMode::LaunchResult { .. } => {
                 // Any key dismisses the result
                 match key {
@@ -1845,6 +3075,10 @@ impl App {
                     }
                     KeyCode::Enter => {
                         let sc = &self.game.spacecraft[selected];
+                        if sc.health <= 0.0 {
+                            self.status_message = Some(format!("{} is worn out — service it first", sc.name));
+                            return;
+                        }
                         let remaining_dv = sc.remaining_delta_v();
                         // Use the live sum of carried payload masses rather than
                         // the cached `payload_mass_kg`, which may be stale if
@@ -2259,6 +3493,11 @@ impl App {
                         stage.engine_count -= 1;
                         stage.propellant_mass_kg *= stage.engine_count as f64 / old_count as f64;
                         recompute_structural_masses(&mut state.stage_groups);
+                    } else if stage.engine_count == 1 && stage.crossfeed {
+                        // Crossfed booster without engines becomes a
+                        // drop tank; keep its propellant load.
+                        stage.engine_count = 0;
+                        recompute_structural_masses(&mut state.stage_groups);
                     }
                 }
                 self.input_mode = InputMode::RocketDesigner { state };
@@ -2275,7 +3514,9 @@ impl App {
                     if stage.engine_count < 9 {
                         let old_count = stage.engine_count;
                         stage.engine_count += 1;
-                        stage.propellant_mass_kg *= stage.engine_count as f64 / old_count as f64;
+                        if old_count > 0 {
+                            stage.propellant_mass_kg *= stage.engine_count as f64 / old_count as f64;
+                        }
                         recompute_structural_masses(&mut state.stage_groups);
                     }
                 }
@@ -2461,16 +3702,112 @@ impl App {
                     state, target: LocationPickerTarget::MissionDestination, locations, selected,
                 };
             }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                state.cycle_performance_reserve();
+                self.status_message = Some(format!(
+                    "Performance reserve: {:.0}% of Δv", state.performance_reserve * 100.0));
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                // Cycle the payload fairing on the top stage:
+                // none → expendable → recovered → none
+                let payload_kg = state.payload_kg;
+                if let Some(stage) = state.stage_groups.last_mut().and_then(|g| g.first_mut()) {
+                    stage.fairing = match &stage.fairing {
+                        None => Some(crate::stage::Fairing::sized_for(payload_kg)),
+                        Some(f) if !f.recover => Some(crate::stage::Fairing { recover: true, ..f.clone() }),
+                        Some(_) => None,
+                    };
+                    self.status_message = Some(match &stage.fairing {
+                        None => "Fairing removed".into(),
+                        Some(f) if f.recover => format!(
+                            "Fairing {:.0} kg, recovered after flight", f.mass_kg),
+                        Some(f) => format!("Fairing {:.0} kg, expendable", f.mass_kg),
+                    });
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('k') | KeyCode::Char('K') => {
+                // Toggle a long-coast kit on the selected stage
+                if !state.on_add_slot() {
+                    let gi = state.selected_group;
+                    let si = state.selected_inner;
+                    let stage = &mut state.stage_groups[gi][si];
+                    stage.long_coast_kit = !stage.long_coast_kit;
+                    self.status_message = Some(if stage.long_coast_kit {
+                        format!("{}: long-coast kit fitted (+{:.0} kg, coasts up to {} days)",
+                            stage.name, stage.long_coast_kit_mass_kg(),
+                            crate::stage::LONG_COAST_KIT_LIMIT_DAYS)
+                    } else {
+                        format!("{}: long-coast kit removed", stage.name)
+                    });
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                // Toggle a landing kit on the selected first-group stage
+                if !state.on_add_slot() {
+                    let gi = state.selected_group;
+                    let si = state.selected_inner;
+                    let stage = &mut state.stage_groups[gi][si];
+                    if gi != 0 {
+                        self.status_message = Some("Only first-group stages can fly home".into());
+                    } else {
+                        stage.landing_kit = !stage.landing_kit;
+                        self.status_message = Some(if stage.landing_kit {
+                            format!("{}: landing kit fitted (+{:.0} kg), lands for reuse",
+                                stage.name, stage.landing_kit_mass_kg())
+                        } else {
+                            format!("{}: landing kit removed", stage.name)
+                        });
+                    }
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                // Toggle a launch abort tower on the selected stage
+                if !state.on_add_slot() {
+                    let gi = state.selected_group;
+                    let si = state.selected_inner;
+                    let stage = &mut state.stage_groups[gi][si];
+                    stage.launch_abort_system = !stage.launch_abort_system;
+                    self.status_message = Some(if stage.launch_abort_system {
+                        format!("{}: launch abort system fitted (+{:.0} kg)",
+                            stage.name, stage.launch_abort_system_mass_kg())
+                    } else {
+                        format!("{}: launch abort system removed", stage.name)
+                    });
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                // Toggle crossfeed on the selected booster
+                if !state.on_add_slot() {
+                    self.status_message = Some(state.toggle_crossfeed());
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                // Toggle hot staging off the group below
+                if !state.on_add_slot() {
+                    self.status_message = Some(state.toggle_staging());
+                }
+                self.input_mode = InputMode::RocketDesigner { state };
+            }
             KeyCode::Char('d') | KeyCode::Char('D') => {
                 // Done — finalize design
                 if state.stage_groups.is_empty() {
                     self.status_message = Some("Must add at least one stage".into());
                     self.input_mode = InputMode::RocketDesigner { state };
+                } else if let Some(problem) = state.crossfeed_problem() {
+                    self.status_message = Some(format!("Invalid crossfeed — {}", problem));
+                    self.input_mode = InputMode::RocketDesigner { state };
                 } else if let DesignerMode::Modify { project_id } = state.mode {
                     // Modify mode: rewrite the existing project's
                     // stages and roll for a new flaw.
                     let stage_groups = state.stage_groups.clone();
                     self.exit_modal();
+                    self.game.set_performance_reserve(project_id, state.performance_reserve);
                     if let Some(evt) = self.game.apply_rocket_modification(project_id, stage_groups) {
                         let summary = format!("{}", evt);
                         self.game.event_log.push(self.game.date, evt);
@@ -2509,7 +3846,7 @@ impl App {
                             self.game.player_company.delete_proposed_engine(*id);
                         }
                     }
-                    self.create_rocket_project(name, stage_groups);
+                    self.create_rocket_project(name, stage_groups, state.performance_reserve);
                 }
             }
             KeyCode::Esc => {
@@ -2814,6 +4151,136 @@ impl App {
         }
     }
 
+    /// Depot design list: ↑/↓ select, N starts a draft, E edits one
+    /// still in design, +/- move teams, R revises, Enter launches the
+    /// selected design on the selected rocket to the first location
+    /// with infrastructure (or the first ISRU site).
+    fn handle_depot_designs_key(&mut self, key: KeyCode, mut selected: usize) {
+        let count = self.game.player_company.depot_projects.len();
+        selected = selected.min(count.saturating_sub(1));
+        match key {
+            KeyCode::Esc => {
+                self.exit_modal();
+                return;
+            }
+            KeyCode::Up | KeyCode::Char('k') => selected = selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') if selected + 1 < count => selected += 1,
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                let name = format!("Depot Mk{}", count + 1);
+                let project_id = self.game.player_company.start_proposed_depot(name, &self.game.balance);
+                self.input_mode = InputMode::DepotEditor { project_id, cursor: 0 };
+                return;
+            }
+            KeyCode::Char('e') | KeyCode::Char('E')
+                if self.game.player_company.depot_projects.get(selected).is_some_and(|p| p.editable()) =>
+            {
+                let project_id = self.game.player_company.depot_projects[selected].project_id;
+                self.input_mode = InputMode::DepotEditor { project_id, cursor: 0 };
+                return;
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') if selected < count => {
+                self.status_message = Some("Specs are frozen once testing starts".into());
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.status_message = Some(if self.game.player_company.add_team_to_depot_project(selected) {
+                    "Team assigned".into()
+                } else {
+                    "No free teams".into()
+                });
+            }
+            KeyCode::Char('-') => {
+                self.status_message = Some(if self.game.player_company.remove_team_from_depot_project(selected) {
+                    "Team removed".into()
+                } else {
+                    "No team to remove".into()
+                });
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.status_message = Some(match self.game.player_company.start_depot_revision(selected) {
+                    Some(n) => format!("Revising {} flaw(s)", n),
+                    None => "Nothing to revise (needs a depot in testing with discovered flaws)".into(),
+                });
+            }
+            KeyCode::Enter => {
+                let Some(item_id) = self.game.player_company.manufacturing.inventory.rockets
+                    .get(self.selected_item)
+                    .map(|r| r.item_id) else {
+                    self.status_message = Some("No rocket selected".into());
+                    return;
+                };
+                let site = self.game.infrastructure.first().map(|i| i.location.clone())
+                    .or_else(|| self.game.balance.isru.sites.first().map(|s| s.location.clone()));
+                let Some(site) = site else {
+                    self.status_message = Some("Nowhere to put a depot".into());
+                    return;
+                };
+                match self.game.launch_depot(item_id, selected, &site) {
+                    Ok((_events, Some(record))) => {
+                        self.input_mode = InputMode::LaunchResult { record };
+                        return;
+                    }
+                    Ok((_events, None)) => {
+                        self.status_message = Some("Depot departed — in transit".into());
+                        self.exit_modal();
+                        return;
+                    }
+                    Err(e) => self.status_message = Some(e.to_string()),
+                }
+            }
+            _ => {}
+        }
+        self.input_mode = InputMode::DepotDesigns { selected };
+    }
+
+    /// Depot editor: ↑/↓ pick a spec, ←/→ change it, D commits a draft
+    /// (or keeps the edits to one in design), Esc drops a draft. Both
+    /// go back to the depot list.
+    fn handle_depot_editor_key(
+        &mut self,
+        key: KeyCode,
+        project_id: crate::depot_project::DepotProjectId,
+        mut cursor: usize,
+    ) {
+        const ROW_COUNT: usize = 5;
+        let company = &mut self.game.player_company;
+        let Some(index) = company.depot_projects.iter().position(|p| p.project_id == project_id) else {
+            self.input_mode = InputMode::DepotDesigns { selected: 0 };
+            return;
+        };
+        match key {
+            KeyCode::Esc => {
+                company.delete_proposed_depot(project_id);
+                let selected = index.min(company.depot_projects.len().saturating_sub(1));
+                self.input_mode = InputMode::DepotDesigns { selected };
+                return;
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                if let Some(evt) = company.promote_proposed_depot(project_id, &self.game.balance) {
+                    self.status_message = Some(evt.to_string());
+                    self.game.event_log.push(self.game.date, evt);
+                }
+                self.input_mode = InputMode::DepotDesigns { selected: index };
+                return;
+            }
+            KeyCode::Up => cursor = cursor.saturating_sub(1),
+            KeyCode::Down => cursor = (cursor + 1).min(ROW_COUNT - 1),
+            KeyCode::Left | KeyCode::Right => {
+                let step = if key == KeyCode::Right { 1.0 } else { -1.0 };
+                let mut design = company.depot_projects[index].design.clone();
+                match cursor {
+                    0 => design.capacity_kg += step * 25_000.0,
+                    1 => design.insulation = ((design.insulation + step * 0.1) * 10.0).round() / 10.0,
+                    2 => design.docking_ports = design.docking_ports.saturating_add_signed(step as i32),
+                    3 => design.solar_array_kw_at_1au += step * 10.0,
+                    _ => design.cryocooler_kw = ((design.cryocooler_kw + step * 0.1) * 10.0).round() / 10.0,
+                }
+                company.depot_projects[index].apply_edit(design, &self.game.balance.depot_design);
+            }
+            _ => {}
+        }
+        self.input_mode = InputMode::DepotEditor { project_id, cursor };
+    }
+
     /// Reactor editor key handler. Cursor: 0 = Name, 1 = Scale,
     /// 2 = Enrichment. Left/Right adjusts the scalar on Scale (×√2)
     /// or cycles through reputation-unlocked enrichments. Enter opens
@@ -2901,7 +4368,8 @@ impl App {
                 // even if reputation has since fallen below the gate,
                 // so a player who built an HEU reactor doesn't get the
                 // editor refusing to display HEU when re-opened later.
-                let reputation = self.game.player_company.reputation.total();
+                let reputation = self.game.player_company.reputation
+                    .segment(ReputationSegment::Government, &self.game.balance.reputation);
                 let mut levels = available_enrichments(reputation, &self.game.balance.reputation);
                 if !levels.contains(&enrichment) {
                     levels.push(enrichment);
@@ -3284,7 +4752,12 @@ impl App {
     }
 
     /// Create a rocket project from the designer flow.
-    fn create_rocket_project(&mut self, name: String, stage_groups: Vec<Vec<Stage>>) {
+    fn create_rocket_project(
+        &mut self,
+        name: String,
+        stage_groups: Vec<Vec<Stage>>,
+        performance_reserve: f64,
+    ) {
         use crate::rocket::{RocketDesign, RocketDesignId};
 
         let design_id = RocketDesignId(self.game.player_company.next_rocket_project_id);
@@ -3292,6 +4765,7 @@ impl App {
             id: design_id,
             name: name.clone(),
             stage_groups,
+            performance_reserve: Some(performance_reserve),
         };
 
         if let Some(evt) = self.game.player_company.start_rocket_project(design, &self.game.balance) {
@@ -3300,6 +4774,53 @@ impl App {
         }
     }
 
+    /// Fast-forward to the 1st of next month, stopping early on the
+    /// Events tab if something needs the player.
+    fn skip_to_next_month(&mut self) {
+        let days = self.game.date.days_in_month() - self.game.date.day + 1;
+        let skip = self.game.fast_forward(days);
+        if skip.interrupted {
+            if let Some(idx) = Tab::ALL.iter().position(|t| matches!(t, Tab::Events)) {
+                self.active_tab = idx;
+            }
+            let reason = skip.events.iter().rev()
+                .find(|e| e.importance() >= crate::event::EventImportance::Notable)
+                .map(|e| e.to_string())
+                .unwrap_or_default();
+            self.status_message = Some(format!("Stopped after {} days: {}", skip.days, reason));
+        } else {
+            self.status_message = Some(format!("Skipped {} days", skip.days));
+        }
+    }
+
+    /// Freeze a design into the company library on disk.
+    fn archive_to_library(&mut self, entry: Option<crate::library::LibraryEntry>) {
+        let Some(entry) = entry else {
+            self.status_message = Some("Only a finished design can be archived".into());
+            return;
+        };
+        let path = save::library_path();
+        self.status_message = Some(match save::load_library(&path) {
+            Ok(mut library) => {
+                let name = format!("{} Rev {}", entry.name, entry.revision);
+                let replaced = library.archive(entry);
+                match save::save_library(&library, &path) {
+                    Ok(()) if replaced => format!("Updated {} in the library", name),
+                    Ok(()) => format!("Archived {} to the library", name),
+                    Err(e) => format!("Library save failed: {}", e),
+                }
+            }
+            Err(e) => format!("Library unreadable: {}", e),
+        });
+    }
+
+    fn open_library(&mut self) {
+        match save::load_library(&save::library_path()) {
+            Ok(library) => self.enter_modal(InputMode::Library { library, selected: 0 }),
+            Err(e) => self.status_message = Some(format!("Library unreadable: {}", e)),
+        }
+    }
+
     fn save_game(&mut self) {
         let path = save::save_path(&self.game.player_company.name);
         match save::save_game(&self.game, &path) {
@@ -3353,6 +4874,11 @@ mod sync_tests {
             structural_mass_kg: 100.0,
             fairing: None,
             power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
         };
         let mut state = RocketDesignerState {
             mode: DesignerMode::New,
@@ -3366,6 +4892,7 @@ mod sync_tests {
             launch_from: "lc-39",
             destination: "leo",
             created_engine_projects: Vec::new(),
+            performance_reserve: 0.0,
         };
 
         // Player opens the editor, switches cycle to ElectricPropulsion.
@@ -3436,6 +4963,8 @@ mod reactor_render_tests {
             discovery_probability: 0.5,
             discovered: true,
             trigger: FlawTrigger::PerFlight,
+            subsystem: crate::flaw::Subsystem::Power,
+            anomaly: None,
         });
         project.improvements.push(ReactorImprovement {
             description: "Compact reactor core design".into(),
@@ -3862,3 +5391,73 @@ mod engine_pane_tests {
             "cancelling should delete the Proposed draft");
     }
 }
+
+/// Step the capacity planner's cadence moves by, in rockets a month.
+const CADENCE_STEP: f64 = 0.25;
+
+/// Indices of the rocket projects that can be built.
+fn flight_ready_projects(game: &GameState) -> Vec<usize> {
+    game.player_company.rocket_projects.iter().enumerate()
+        .filter(|(_, rp)| matches!(rp.status, crate::rocket_project::RocketDesignStatus::Testing { .. }))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Indices of the picked contracts and item ids of the picked
+/// spacecraft in a launch manifest.
+fn manifest_picks(
+    contract_picks: &[bool],
+    spacecraft_picks: &[bool],
+    spacecraft_item_ids: &[crate::manufacturing::InventoryItemId],
+) -> (Vec<usize>, Vec<crate::manufacturing::InventoryItemId>) {
+    let contract_indices = contract_picks.iter().enumerate()
+        .filter(|(_, picked)| **picked)
+        .map(|(i, _)| i)
+        .collect();
+    let picked_spacecraft = spacecraft_picks.iter().enumerate()
+        .filter(|(_, picked)| **picked)
+        .map(|(i, _)| spacecraft_item_ids[i])
+        .collect();
+    (contract_indices, picked_spacecraft)
+}
+
+/// Status-line text for a manifest that can't fly.
+fn manifest_error_message(e: crate::game_state::ManifestError) -> String {
+    use crate::game_state::ManifestError;
+
+    match e {
+        ManifestError::ConflictingDestinations { first, second } => format!(
+            "Picked contracts have different destinations ({} vs {}). Untoggle one.",
+            first, second,
+        ),
+        ManifestError::SpacecraftMissing => "Spacecraft payload no longer in inventory.".into(),
+        ManifestError::PayloadProjectMissing => "Payload rocket project not found.".into(),
+        ManifestError::NotCompliant(issue) => format!("{}.", issue),
+        ManifestError::PadLeased { lessee, free_on } => format!(
+            "Pad leased to {} — free again on {}.", lessee, free_on,
+        ),
+        ManifestError::RocketMissing => "Launch vehicle no longer in inventory.".into(),
+        ManifestError::CountdownUnderway => "Another countdown already has the pad.".into(),
+        ManifestError::CommitCriteriaNotMet(failed) => format!(
+            "No-go on launch commit criteria: {}.", criteria_list(&failed),
+        ),
+        ManifestError::PayloadNotReady { contract_name, ready_on } => format!(
+            "{} payload not at the pad until {}.", contract_name, ready_on,
+        ),
+        ManifestError::TooLargeForPad { liftoff_kg, max_kg } => format!(
+            "Vehicle too heavy for the pad ({:.0} t at liftoff, max {:.0} t).",
+            liftoff_kg / 1000.0, max_kg / 1000.0,
+        ),
+    }
+}
+
+fn criteria_list(criteria: &[crate::commit_criteria::Criterion]) -> String {
+    criteria.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Status-line warning for flying against an advisory checklist.
+fn commit_warning(checklist: &crate::commit_criteria::LaunchChecklist) -> Option<String> {
+    (!checklist.all_go()).then(|| format!(
+        "Going against commit criteria: {}", criteria_list(&checklist.failures()),
+    ))
+}
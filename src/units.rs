@@ -0,0 +1,188 @@
+//! Number and unit formatting shared by every front end: money, mass,
+//! delta-v, thrust, and percentages, with locale-aware separators.
+//!
+//! These are the functions a UI binding exposes (as static functions on
+//! its utility class) so a front end never reimplements them; the
+//! terminal UI and `resources::format_money` go through them too. Each
+//! takes a [`NumberFormat`]; [`NumberFormat::ENGLISH`] reproduces the
+//! game's historical output exactly.
+
+use serde::{Deserialize, Serialize};
+
+use crate::locale::LocaleTable;
+
+/// Digit-group and decimal separators for one locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberFormat {
+    pub thousands_sep: char,
+    pub decimal_sep: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::ENGLISH
+    }
+}
+
+impl NumberFormat {
+    pub const ENGLISH: NumberFormat = NumberFormat { thousands_sep: ',', decimal_sep: '.' };
+
+    /// Separators from a locale table's `number.thousands_separator` and
+    /// `number.decimal_separator` keys (first character of each), falling
+    /// back to English for either one missing.
+    pub fn from_locale(table: &LocaleTable) -> Self {
+        let sep = |key: &str, default: char| {
+            table.get(key).and_then(|s| s.chars().next()).unwrap_or(default)
+        };
+        NumberFormat {
+            thousands_sep: sep("number.thousands_separator", ','),
+            decimal_sep: sep("number.decimal_separator", '.'),
+        }
+    }
+
+    /// `value` to `decimals` places with grouped thousands:
+    /// `number(1234567.891, 2)` → "1,234,567.89".
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let plain = format!("{:.*}", decimals, value.abs());
+        let (int, frac) = match plain.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (plain.as_str(), None),
+        };
+        let mut out = String::with_capacity(plain.len() + int.len() / 3 + 1);
+        // "-0" would read oddly, so only a value that rounds to
+        // something nonzero keeps its sign.
+        if value < 0.0 && plain.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            out.push('-');
+        }
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i).is_multiple_of(3) {
+                out.push(self.thousands_sep);
+            }
+            out.push(c);
+        }
+        if let Some(frac) = frac {
+            out.push(self.decimal_sep);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Fixed decimals without digit grouping (scaled values like "1.5").
+    fn decimal(&self, value: f64, decimals: usize) -> String {
+        let s = format!("{:.*}", decimals, value);
+        if self.decimal_sep == '.' { s } else { s.replace('.', &self.decimal_sep.to_string()) }
+    }
+}
+
+/// Compact money: "$950", "$12K", "$1.5M", "$2.1B".
+pub fn money(amount: f64, f: &NumberFormat) -> String {
+    let sign = if amount < 0.0 { "-" } else { "" };
+    let a = amount.abs();
+    if a >= 1_000_000_000.0 {
+        format!("{sign}${}B", f.decimal(a / 1_000_000_000.0, 1))
+    } else if a >= 1_000_000.0 {
+        format!("{sign}${}M", f.decimal(a / 1_000_000.0, 1))
+    } else if a >= 1_000.0 {
+        format!("{sign}${}K", f.decimal(a / 1_000.0, 0))
+    } else {
+        format!("{sign}${}", f.decimal(a, 0))
+    }
+}
+
+/// Money to the dollar: "$1,234,567".
+pub fn money_exact(amount: f64, f: &NumberFormat) -> String {
+    let n = amount.round();
+    let digits = f.number(n.abs(), 0);
+    if n < 0.0 { format!("-${digits}") } else { format!("${digits}") }
+}
+
+/// Mass in kilograms to the kilogram: "12,500 kg".
+pub fn mass_kg(kg: f64, f: &NumberFormat) -> String {
+    format!("{} kg", f.number(kg.round(), 0))
+}
+
+/// Mass scaled for reading: kilograms below a tonne, tonnes above
+/// ("850 kg", "12.5 t", "1,200 t").
+pub fn mass(kg: f64, f: &NumberFormat) -> String {
+    if kg.abs() >= 100_000.0 {
+        format!("{} t", f.number(kg / 1_000.0, 0))
+    } else if kg.abs() >= 1_000.0 {
+        format!("{} t", f.decimal(kg / 1_000.0, 1))
+    } else {
+        format!("{} kg", f.decimal(kg, 0))
+    }
+}
+
+/// Delta-v: m/s below 10 km/s, km/s above ("9400 m/s", "12.35 km/s").
+pub fn delta_v(m_s: f64, f: &NumberFormat) -> String {
+    if m_s.is_infinite() {
+        "∞".to_string()
+    } else if m_s.abs() >= 10_000.0 {
+        format!("{} km/s", f.decimal(m_s / 1_000.0, 2))
+    } else {
+        format!("{} m/s", f.decimal(m_s, 0))
+    }
+}
+
+/// Thrust: ion thrusters in N, chemical engines in kN, superheavy
+/// boosters in MN.
+pub fn thrust(n: f64, f: &NumberFormat) -> String {
+    let a = n.abs();
+    if a >= 1_000_000.0 {
+        format!("{} MN", f.decimal(n / 1_000_000.0, 2))
+    } else if a >= 10_000.0 {
+        format!("{} kN", f.decimal(n / 1_000.0, 0))
+    } else if a >= 1_000.0 {
+        format!("{} kN", f.decimal(n / 1_000.0, 1))
+    } else if a >= 10.0 {
+        format!("{} N", f.decimal(n, 0))
+    } else {
+        format!("{} N", f.decimal(n, 1))
+    }
+}
+
+/// A fraction as a percentage: `percent(0.125, 1)` → "12.5%".
+pub fn percent(fraction: f64, decimals: usize, f: &NumberFormat) -> String {
+    format!("{}%", f.decimal(fraction * 100.0, decimals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DE: NumberFormat = NumberFormat { thousands_sep: '.', decimal_sep: ',' };
+
+    #[test]
+    fn test_english_matches_historical_output() {
+        let en = NumberFormat::ENGLISH;
+        assert_eq!(money(950.0, &en), "$950");
+        assert_eq!(money(12_400.0, &en), "$12K");
+        assert_eq!(money(1_500_000.0, &en), "$1.5M");
+        assert_eq!(money(-2_100_000_000.0, &en), "-$2.1B");
+        assert_eq!(money_exact(-1_234_567.4, &en), "-$1,234,567");
+        assert_eq!(mass_kg(12_500.0, &en), "12,500 kg");
+        assert_eq!(delta_v(9_400.0, &en), "9400 m/s");
+        assert_eq!(delta_v(f64::INFINITY, &en), "∞");
+        assert_eq!(thrust(0.25, &en), "0.2 N");
+        assert_eq!(thrust(845_000.0, &en), "845 kN");
+        assert_eq!(thrust(7_600_000.0, &en), "7.60 MN");
+    }
+
+    #[test]
+    fn test_scaled_units_and_locale_separators() {
+        assert_eq!(mass(850.0, &DE), "850 kg");
+        assert_eq!(mass(12_500.0, &DE), "12,5 t");
+        assert_eq!(mass(1_200_000.0, &DE), "1.200 t");
+        assert_eq!(delta_v(12_345.0, &DE), "12,35 km/s");
+        assert_eq!(percent(0.125, 1, &DE), "12,5%");
+        assert_eq!(money_exact(1_234_567.0, &DE), "$1.234.567");
+        assert_eq!(DE.number(-0.001, 2), "0,00");
+
+        let table = LocaleTable::from_toml_str(
+            "[number]\nthousands_separator = \" \"\ndecimal_separator = \",\"",
+        ).unwrap();
+        let fr = NumberFormat::from_locale(&table);
+        assert_eq!(fr.number(1_234_567.891, 2), "1 234 567,89");
+        assert_eq!(NumberFormat::from_locale(&LocaleTable::default()), NumberFormat::ENGLISH);
+    }
+}
@@ -0,0 +1,121 @@
+//! Company valuation and the public listing.
+//!
+//! Valuation is an appraisal, not a market price: cash, resale value
+//! of hardware and the book value of facilities, design NRE, the contract backlog, and
+//! fame (reputation). Once listed, the market's sentiment scales it,
+//! and quarterly earnings are judged against the target set at IPO.
+
+use serde::{Serialize, Deserialize};
+
+use crate::balance_config::BalanceConfig;
+use crate::calendar::GameDate;
+use crate::company::Company;
+use crate::engine_project::EngineDesignStatus;
+use crate::rocket_project::RocketDesignStatus;
+
+/// A valuation broken down by source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valuation {
+    pub cash: f64,
+    /// Resale value of inventory hardware and facilities on the books.
+    pub assets: f64,
+    /// Share of the NRE sunk into finished designs.
+    pub designs: f64,
+    /// Discounted value of accepted, unflown contracts.
+    pub backlog: f64,
+    /// Reputation priced in (negative when infamous).
+    pub fame: f64,
+    /// Market sentiment multiplier (1.0 when private).
+    pub sentiment: f64,
+}
+
+impl Valuation {
+    /// Appraise a company under the given balance.
+    pub fn appraise(company: &Company, balance: &BalanceConfig) -> Valuation {
+        let cfg = &balance.valuation;
+        let inv = &company.manufacturing.inventory;
+        let hardware: f64 = inv.engines.iter().map(|e| e.build_cost).sum::<f64>()
+            + inv.stages.iter().map(|s| s.build_cost).sum::<f64>()
+            + inv.rockets.iter().map(|r| r.build_cost).sum::<f64>();
+        let facilities = company.assets.book_value();
+
+        let engine_nre: f64 = company.engine_projects.iter()
+            .filter(|ep| matches!(ep.status,
+                EngineDesignStatus::Testing { .. } | EngineDesignStatus::Revising { .. }))
+            .map(|ep| ep.nre_cost)
+            .sum();
+        let rocket_nre: f64 = company.rocket_projects.iter()
+            .filter(|rp| matches!(rp.status,
+                RocketDesignStatus::Testing { .. } | RocketDesignStatus::Revising { .. }))
+            .map(|rp| rp.nre_cost)
+            .sum();
+
+        let backlog: f64 = company.active_contracts.iter().map(|c| c.payment).sum();
+
+        Valuation {
+            cash: company.money,
+            assets: (hardware + facilities) * cfg.asset_resale_fraction,
+            designs: (engine_nre + rocket_nre) * cfg.design_value_fraction,
+            backlog: backlog * cfg.backlog_fraction,
+            fame: company.reputation.total() * cfg.value_per_reputation,
+            sentiment: company.listing.as_ref().map_or(1.0, |l| l.sentiment),
+        }
+    }
+
+    /// Total valuation, floored at zero.
+    pub fn total(&self) -> f64 {
+        ((self.cash + self.assets + self.designs + self.backlog + self.fame) * self.sentiment)
+            .max(0.0)
+    }
+}
+
+/// One point of valuation history (recorded monthly).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValuationRecord {
+    pub date: GameDate,
+    pub value: f64,
+}
+
+/// The company's state as a publicly traded firm.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicListing {
+    pub ipo_date: GameDate,
+    /// Cash raised at IPO.
+    pub raised: f64,
+    /// Net quarterly earnings the market expects.
+    pub quarterly_target: f64,
+    /// Valuation multiplier: knocked down by misses, recovers on beats.
+    pub sentiment: f64,
+    /// Consecutive missed quarters.
+    pub missed_quarters: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed::GameSeed;
+
+    #[test]
+    fn test_valuation_prices_fame_and_sentiment() {
+        let balance = BalanceConfig::default();
+        let mut company = Company::new("Test".into(), 10_000_000.0, &GameSeed::new(1), &balance);
+        let base = Valuation::appraise(&company, &balance);
+        assert_eq!(base.cash, company.money);
+        assert_eq!(base.sentiment, 1.0);
+
+        company.reputation.success_factor = 10.0;
+        let famous = Valuation::appraise(&company, &balance);
+        assert!((famous.total() - base.total()
+            - 10.0 * balance.valuation.value_per_reputation).abs() < 1.0);
+
+        company.listing = Some(PublicListing {
+            ipo_date: GameDate::default_start(),
+            raised: 0.0,
+            quarterly_target: 0.0,
+            sentiment: 0.5,
+            missed_quarters: 0,
+        });
+        let listed = Valuation::appraise(&company, &balance);
+        assert!((listed.total() - famous.total() * 0.5).abs() < 1.0);
+    }
+}
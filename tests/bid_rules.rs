@@ -78,6 +78,11 @@ fn inject_contract(gs: &mut GameState, id: u64, name: &str, market_id: MarketId)
         bid_deadline: Some(gs.date.add_days(5)),
         budget_ceiling: 50_000_000.0,
         player_bid: None,
+        pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     });
     gs.available_contracts.len() - 1
 }
@@ -338,6 +343,11 @@ fn accepted_unflown_contract_reserves_stock() {
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     });
     let idx = inject_contract(&mut gs, 1, "Rideshare A", MARKET_RIDESHARE);
 
@@ -235,6 +235,11 @@ fn accept_refuses_solicitations_and_bid_refuses_prepriced() {
         bid_deadline: None,
         budget_ceiling: 0.0,
         player_bid: None,
+        pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     });
     let pre_priced_idx = gs.available_contracts.len() - 1;
 
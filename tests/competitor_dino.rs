@@ -55,6 +55,11 @@ fn inject_geo_solicitation(
         bid_deadline: Some(bid_close),
         budget_ceiling: ceiling,
         player_bid: None,
+        pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+        title: None,
+        insertion_tolerance: None,
+        export_controlled: false,
+        reward: None,
     });
     gs.available_contracts.len() - 1
 }
@@ -567,3 +572,62 @@ fn disabled_competitor_never_appears() {
         );
     }
 }
+
+// ---------------------------------------------------------------
+// 10. A losing proposal costs its engineering time; a flight record
+//     outweighs a sliver of price.
+// ---------------------------------------------------------------
+
+#[test]
+fn losing_bid_writes_off_proposal_and_record_tips_close_awards() {
+    use rocket_tycoon::bidding;
+
+    let seed = 110;
+    let mut balance = BalanceConfig::default();
+    balance.bidding.proposal_team_days = 5.0;
+    let mut gs = GameState::with_balance("Test".into(), seed, balance);
+    let bid_close = gs.date.add_days(5);
+    let idx = inject_geo_solicitation(&mut gs, 9010, "ProposalSat", bid_close, 300_000_000.0);
+    gs.place_bid(idx, 290_000_000.0).unwrap();
+    let money = gs.player_company.money;
+
+    let events = advance_through(&mut gs, bid_close, 30);
+    let cost = bidding::proposal_cost(&gs.balance.bidding, gs.balance.costs.engineering_monthly_salary);
+    assert!(cost > 0.0);
+    assert!(
+        events.iter().any(|e| matches!(e,
+            GameEvent::ProposalWrittenOff { contract_name, cost: c } if contract_name == "ProposalSat" && *c == cost)),
+        "seed {seed}: the losing bid should write off its proposal, got {events:?}",
+    );
+    assert!(gs.player_company.money <= money - cost, "seed {seed}: the write-off is paid");
+
+    // Same price as DinoSoar plus a hair: a long success record wins it.
+    let bid_close = gs.date.add_days(5);
+    let idx = inject_geo_solicitation(&mut gs, 9011, "RecordSat", bid_close, 300_000_000.0);
+    let dino_bid = gs.competitors[0]
+        .compute_bid(&gs.available_contracts[idx], &gs.balance, &gs.seed)
+        .expect("seed 110: DinoSoar should bid");
+    gs.competitors[0].company.launch_history.clear();
+    gs.player_company.reputation = gs.competitors[0].company.reputation.clone();
+    for _ in 0..20 {
+        gs.player_company.launch_history.push(rocket_tycoon::launch::LaunchRecord {
+            launch_date: gs.date,
+            rocket_name: "Workhorse".into(),
+            contract_id: None,
+            destination: "leo".into(),
+            payload_kg: 1_000.0,
+            outcome: rocket_tycoon::launch::LaunchOutcome::Success,
+            flaws_activated: Vec::new(),
+            transit_anomalies: Vec::new(),
+            stage_disposals: Vec::new(),
+        });
+    }
+    gs.place_bid(idx, dino_bid * 1.01).unwrap();
+    let events = advance_through(&mut gs, bid_close, 30);
+    assert!(
+        events.iter().any(|e| matches!(e,
+            GameEvent::ContractAwarded { contract_name, .. } if contract_name == "RecordSat")),
+        "seed {seed}: the proven flyer should take a near-tie, got {events:?}",
+    );
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::ProposalWrittenOff { .. })));
+}
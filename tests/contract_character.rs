@@ -12,10 +12,11 @@ use rand::rngs::StdRng;
 use rocket_tycoon::balance_config::BalanceConfig;
 use rocket_tycoon::calendar::GameDate;
 use rocket_tycoon::contract::{
-    default_archetypes, generate_market_contracts, Contract, ContractStatus,
+    default_archetypes, generate_market_contracts, CapabilityProfile, Contract, ContractStatus,
     MARKET_COTS, MARKET_GOV_SCIENCE,
 };
 use rocket_tycoon::game_state::GameState;
+use rocket_tycoon::market_trend::MarketTrend;
 
 fn mcfg() -> rocket_tycoon::balance_config::MarketsConfig {
     BalanceConfig::default().markets
@@ -52,7 +53,7 @@ fn per_market_deadline_windows_honored() {
             // Check each batch against its own issue date, so a
             // deadline can't hide behind a neighboring month's window.
             for c in generate_market_contracts(
-                &mut market, &mut rng, &mut next_id, date, 1.0, &markets_cfg,
+                &mut market, &mut rng, &mut next_id, date, 1.0, MarketTrend::default(), &markets_cfg, &CapabilityProfile::default(),
             ) {
                 let span = date.days_until(&c.deadline);
                 assert!(
@@ -92,7 +93,7 @@ fn global_deadline_fallback_used_when_unset() {
     let mut rng = StdRng::seed_from_u64(11);
     let mut next_id = 1u64;
     let contracts = generate_market_contracts(
-        &mut market, &mut rng, &mut next_id, current_date, 1.0, &markets_cfg,
+        &mut market, &mut rng, &mut next_id, current_date, 1.0, MarketTrend::default(), &markets_cfg, &CapabilityProfile::default(),
     );
 
     assert!(
@@ -199,6 +200,11 @@ fn expiry_applies_market_severity_end_to_end() {
             bid_deadline: None,
             budget_ceiling: 0.0,
             player_bid: None,
+            pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+            title: None,
+            insertion_tolerance: None,
+            export_controlled: false,
+            reward: None,
         });
         gs.advance_day();
 
@@ -227,6 +233,11 @@ fn expiry_applies_market_severity_end_to_end() {
             bid_deadline: None,
             budget_ceiling: 0.0,
             player_bid: None,
+            pricing: rocket_tycoon::contract::ContractPricing::FixedPrice,
+            title: None,
+            insertion_tolerance: None,
+            export_controlled: false,
+            reward: None,
         });
         gs.advance_day();
 
@@ -0,0 +1,120 @@
+//! Property tests over randomly built designs: the delta-v model's
+//! invariants (`RocketDesign::validate_invariants`) must hold for any
+//! stack the designer could assemble, not just the hand-picked ones in
+//! the unit tests.
+
+use proptest::prelude::*;
+
+use rocket_tycoon::balance_config::ResourcePrices;
+use rocket_tycoon::engine::{EngineCycle, EngineDesign, EngineId, PropellantFraction};
+use rocket_tycoon::propellant::Propellant;
+use rocket_tycoon::rocket::{RocketDesign, RocketDesignId};
+use rocket_tycoon::stage::{Stage, StageId};
+
+fn engine(thrust_n: f64, mass_kg: f64, isp_s: f64, solid: bool) -> EngineDesign {
+    let propellant_mix = if solid {
+        vec![PropellantFraction { propellant: Propellant::SolidMix, mass_fraction: 1.0 }]
+    } else {
+        vec![
+            PropellantFraction { propellant: Propellant::LOX, mass_fraction: 0.725 },
+            PropellantFraction { propellant: Propellant::RP1, mass_fraction: 0.275 },
+        ]
+    };
+    EngineDesign {
+        id: EngineId(1),
+        name: "E".into(),
+        cycle: if solid { EngineCycle::PressureFed } else { EngineCycle::GasGenerator },
+        thrust_n,
+        mass_kg,
+        isp_s,
+        exit_pressure_pa: 70_000.0,
+        needs_atmosphere: false,
+        propellant_mix,
+        power_draw_w: 0.0,
+    }
+}
+
+prop_compose! {
+    fn arb_stage()(
+        thrust in 50_000.0..5_000_000.0f64,
+        engine_mass in 100.0..5_000.0f64,
+        isp in 200.0..460.0f64,
+        solid in any::<bool>(),
+        engine_count in 1u32..9,
+        propellant in 0.0..400_000.0f64,
+        structure in 100.0..30_000.0f64,
+    ) -> Stage {
+        Stage {
+            id: StageId(1),
+            name: "S".into(),
+            engine: engine(thrust, engine_mass, isp, solid),
+            engine_count,
+            propellant_mass_kg: propellant,
+            structural_mass_kg: structure,
+            fairing: None,
+            power_sources: Vec::new(),
+            crossfeed: false,
+            long_coast_kit: false,
+            landing_kit: false,
+            launch_abort_system: false,
+            staging: Default::default(),
+        }
+    }
+}
+
+prop_compose! {
+    /// Parallel stages sharing one engine, so the propellant invariant
+    /// (which only binds within a single Isp) gets exercised too.
+    fn arb_one_engine_group()(
+        core in arb_stage(),
+        boosters in prop::collection::vec((1u32..9, 0.0..400_000.0f64, 100.0..30_000.0f64), 0..3),
+    ) -> Vec<Stage> {
+        let mut group = vec![core.clone()];
+        for (engine_count, propellant, structure) in boosters {
+            group.push(Stage {
+                engine_count,
+                propellant_mass_kg: propellant,
+                structural_mass_kg: structure,
+                ..core.clone()
+            });
+        }
+        group
+    }
+}
+
+prop_compose! {
+    fn arb_design()(
+        stage_groups in prop::collection::vec(prop_oneof![
+            prop::collection::vec(arb_stage(), 1..4),
+            arb_one_engine_group(),
+        ], 1..4),
+        performance_reserve in 0.0..0.3f64,
+    ) -> RocketDesign {
+        RocketDesign {
+            id: RocketDesignId(1),
+            name: "Prop".into(),
+            stage_groups,
+            performance_reserve: Some(performance_reserve),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn delta_v_model_invariants_hold(design in arb_design()) {
+        let broken = design.validate_invariants();
+        prop_assert!(broken.is_empty(), "{:?}", broken);
+    }
+
+    #[test]
+    fn another_engine_never_cheapens_a_stage(stage in arb_stage(), engine_unit_cost in 0.0..50_000_000.0f64) {
+        let prices = ResourcePrices::default();
+        let more_engines = Stage { engine_count: stage.engine_count + 1, ..stage.clone() };
+        prop_assert!(more_engines.material_cost(engine_unit_cost, &prices) >= stage.material_cost(engine_unit_cost, &prices));
+    }
+
+    #[test]
+    fn payload_never_adds_delta_v(design in arb_design(), payload in 0.0..50_000.0f64) {
+        prop_assert!(design.total_delta_v(payload) <= design.total_delta_v(0.0) + 1e-6);
+    }
+}
@@ -0,0 +1,41 @@
+//! Scripted headless runs through `sim::Harness`: act on the game
+//! between advances and check the outcome.
+
+use rocket_tycoon::balance_config::BalanceConfig;
+use rocket_tycoon::calendar::GameDate;
+use rocket_tycoon::policy::policy_by_name;
+use rocket_tycoon::sim::{run_seed, Harness};
+
+/// A harness driven by a policy matches `run_seed` with that policy.
+#[test]
+fn harness_matches_run_seed() {
+    let balance = BalanceConfig::default();
+    let mut policy = policy_by_name("basic").expect("basic policy exists");
+    let summary = run_seed(11, 1, &balance, policy.as_mut(), |_| {});
+
+    let mut policy = policy_by_name("basic").expect("basic policy exists");
+    let mut h = Harness::new(11, &balance);
+    let start = h.game.date;
+    let days = start.days_until(&GameDate::new(start.year + 1, start.month, start.day));
+    h.run_policy(policy.as_mut(), days);
+    assert_eq!(h.game.player_company.money, summary.final_money);
+    assert_eq!(h.tally.launch_attempts as usize, summary.launches);
+}
+
+/// Two identical seeds, one with teams hired up front: after a quarter
+/// the staffed company has paid for them.
+#[test]
+fn scripted_hiring_burns_salary() {
+    let balance = BalanceConfig::default();
+    let mut idle = Harness::new(3, &balance);
+    let mut staffed = Harness::new(3, &balance);
+    for i in 0..3 {
+        staffed.game.player_company.hire_team(format!("Team {i}"), &balance);
+    }
+    idle.advance_days(90);
+    staffed.advance_days(90);
+
+    assert!(staffed.game.player_company.money < idle.game.player_company.money);
+    assert_eq!(staffed.tally.launch_attempts, 0);
+    assert!(staffed.metric_row().starts_with("3,"));
+}
@@ -1,16 +1,18 @@
 //! M1 Task 4: determinism smoke test + metric-band regression tests.
 //!
 //! Bands are set around the measured baseline (basic policy, default
-//! balance, 200 seeds × 8 years, 2026-07, re-measured after campaign
-//! redesign Task 4 — block-bid campaigns live in GEO Comsats / COTS /
-//! LEO Constellation, the bot bids blocks at its standing margin, and
-//! DinoSoar contests them): 0/200 bankrupt, 4–31 launches per seed
-//! (avg 13.7; the tail above the old 22 is seeds that win a
-//! multi-mission program), per-seed success ≥ 75%, aggregate success
-//! 95.4%, min money $71.9M, 195/200 seeds end above starting money,
-//! 200/200 have a first profitable year (latest start+6). Campaigns
-//! read as a mild positive: extra launches and profit on the seeds
-//! that land a block, identical floors everywhere else. The 2026-07
+//! balance, 200 seeds × 8 years, 2026-10, re-measured after wage
+//! inflation, pay renegotiation and customer payload readiness;
+//! block-bid campaigns live in GEO Comsats / COTS / LEO
+//! Constellation, the bot bids blocks at its standing margin, and
+//! DinoSoar contests them): 0/200 bankrupt, 3–23 launches per seed
+//! (avg 13.4), per-seed success ≥ 81%, aggregate success 96.3%, min
+//! money $36.2M (seed 146, which only ever flies three missions; see
+//! the floor band below for why that's half the old $71.9M),
+//! 189/200 seeds end above starting money, 199/200 have a first
+//! profitable year (latest start+6). Campaigns read as a mild
+//! positive: extra launches and profit on the seeds that land a
+//! block, identical floors everywhere else. The 2026-07
 //! margin sweep (see policy.rs DEFAULT_BID_MARGIN) is still the
 //! context: an uncontested small-payload market rewards ever-higher
 //! margins, so these bands lock a chosen honest posture, not an
@@ -57,9 +59,22 @@ fn assert_bands(summaries: &[RunSummary]) {
 
     for s in summaries {
         assert!(!s.bankrupt, "seed {}: went bankrupt (final ${:.0})", s.seed, s.final_money);
+        // The floor is set by the worst of 200 seeds, one where the
+        // bot's lone BLV-1 finds almost nothing it can fly (146 now, 131
+        // and 189 before it), so its money runs down with overhead and
+        // its minimum is its final balance. Every change since the
+        // $71.9M baseline that costs money or lift lands on that seed in
+        // full with no revenue against it: the 2% performance reserve
+        // took the floor to $59.6M, payloads outgrowing the BLV-1 to
+        // $38.9M, and wage inflation with raises to $36.2M ($45.7M
+        // without them). Pay alone can't win back the old $65M band,
+        // and raising starting money would only move the profitability
+        // bar, so the band keeps the old 10% headroom under the idle
+        // floor. Flying seeds sit higher (next lowest: seed 110 at
+        // $37.8M after early failures).
         assert!(
-            s.min_money > 65_000_000.0,
-            "seed {}: money dipped below $65M (min ${:.0}, baseline min $71.9M)",
+            s.min_money > 32_500_000.0,
+            "seed {}: money dipped below $32.5M (min ${:.0}, baseline min $36.2M)",
             s.seed, s.min_money,
         );
         if s.final_money > starting_money {
@@ -67,14 +82,14 @@ fn assert_bands(summaries: &[RunSummary]) {
         }
         assert!(
             (3..=38).contains(&s.launches),
-            "seed {}: {} launches outside band 3..=38 (baseline 4..=31; the \
-             top is a seed that wins a big block program)",
+            "seed {}: {} launches outside band 3..=38 (baseline 3..=23; \
+             the headroom is for seeds that win a big block program)",
             s.seed, s.launches,
         );
         let rate = s.successes as f64 / s.launches as f64;
         assert!(
             rate >= 0.70,
-            "seed {}: launch success rate {:.0}% below 70% (baseline min 75%; \
+            "seed {}: launch success rate {:.0}% below 70% (baseline min 81%; \
              low-launch seeds make this floor noisy)",
             s.seed, rate * 100.0,
         );
@@ -90,22 +105,27 @@ fn assert_bands(summaries: &[RunSummary]) {
         successes += s.successes;
     }
 
-    // Fleet-level bands (baseline 195/200 end above starting money,
-    // 200/200 have a profitable year).
+    // Fleet-level bands (baseline 189/200 end above starting money,
+    // 199/200 have a profitable year). Wage inflation and raises cost
+    // a seed about $12M over the run on average (190 seeds end ahead
+    // without them). Customer payloads come ready 10-40% of the way
+    // from signing to the deadline, so slips mostly land in slack:
+    // they cost the fleet about 3% of its final money and no seed its
+    // profit.
     let n = summaries.len() as f64;
     assert!(
         profitable as f64 / n >= 0.90,
-        "only {profitable}/{n} seeds profitable after run (band >= 90%, baseline 97.5%)",
+        "only {profitable}/{n} seeds profitable after run (band >= 90%, baseline 94.5%)",
     );
     assert!(
         with_fpy as f64 / n >= 0.95,
-        "only {with_fpy}/{n} seeds ever had a profitable year (band >= 95%, baseline 100%)",
+        "only {with_fpy}/{n} seeds ever had a profitable year (band >= 95%, baseline 99.5%)",
     );
 
     let aggregate = successes as f64 / launches as f64;
     assert!(
         aggregate >= 0.93,
-        "aggregate launch success rate {:.1}% below 93% (baseline 95.4%)",
+        "aggregate launch success rate {:.1}% below 93% (baseline 96.3%)",
         aggregate * 100.0,
     );
 }